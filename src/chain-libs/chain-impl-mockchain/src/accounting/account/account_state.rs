@@ -113,7 +113,7 @@ impl<Extra> AccountState<Extra> {
 
     pub fn new_reward(epoch: Epoch, v: Value, extra: Extra) -> Self {
         let mut st = Self::new(v, extra);
-        st.last_rewards.add_for(epoch, v);
+        st.last_rewards = LastRewards { epoch, reward: v };
         st
     }
 
@@ -158,7 +158,7 @@ impl<Extra: Clone> AccountState<Extra> {
         let new_value = (self.value + v)?;
         let mut st = self.clone();
         st.value = new_value;
-        st.last_rewards.add_for(e, v);
+        st.last_rewards.add_for(e, v)?;
         Ok(st)
     }
 
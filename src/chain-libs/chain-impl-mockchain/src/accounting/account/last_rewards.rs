@@ -1,5 +1,5 @@
 use crate::date::Epoch;
-use crate::value::Value;
+use crate::value::{Checked, Value, ValueError};
 
 /// Last rewards associated with a state
 ///
@@ -34,14 +34,15 @@ impl LastRewards {
     ///
     /// This should never be used with an epoch less than the last set epoch,
     /// as it would means the rewards system is rewarding something from a past state.
-    pub fn add_for(&mut self, epoch: Epoch, value: Value) {
+    pub fn add_for(&mut self, epoch: Epoch, value: Value) -> Result<(), ValueError> {
         assert!(epoch >= self.epoch);
         if self.epoch == epoch {
-            self.reward = (self.reward + value).unwrap()
+            self.reward = self.reward.add_with::<Checked>(value)?;
         } else {
             self.epoch = epoch;
             self.reward = value;
         }
+        Ok(())
     }
 }
 
@@ -57,7 +58,7 @@ mod tests {
             epoch: 0,
             reward: Value(50),
         };
-        last_rewards.add_for(1, value_to_add);
+        last_rewards.add_for(1, value_to_add).unwrap();
         assert_eq!(
             last_rewards.reward,
             Value(100),
@@ -75,7 +76,7 @@ mod tests {
             epoch: 1,
             reward: Value(50),
         };
-        last_rewards.add_for(epoch, value_to_add);
+        last_rewards.add_for(epoch, value_to_add).unwrap();
         assert_eq!(
             last_rewards.reward,
             Value(150),
@@ -94,11 +95,10 @@ mod tests {
             epoch: 2,
             reward: Value::zero(),
         };
-        last_rewards.add_for(epoch, value_to_add);
+        last_rewards.add_for(epoch, value_to_add).unwrap();
     }
 
     #[test]
-    #[should_panic]
     pub fn add_for_value_overflow() {
         let value_to_add = Value(std::u64::MAX);
         let epoch = 0;
@@ -106,6 +106,7 @@ mod tests {
             epoch: 0,
             reward: Value(std::u64::MAX),
         };
-        last_rewards.add_for(epoch, value_to_add);
+        assert!(last_rewards.add_for(epoch, value_to_add).is_err());
+        assert_eq!(last_rewards.reward, Value(std::u64::MAX));
     }
 }
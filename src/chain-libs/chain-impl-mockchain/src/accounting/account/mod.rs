@@ -112,6 +112,40 @@ impl<ID: Clone + Eq + Hash, Extra: Clone> Ledger<ID, Extra> {
         self.0.lookup(account).ok_or(LedgerError::NonExistent)
     }
 
+    /// Get the next valid spending counter of the account in every lane.
+    ///
+    /// Any of them can be used in the next transaction spending from the account,
+    /// after which only the counter of the lane used is incremented. Using
+    /// different lanes allows to build successive transactions independently.
+    pub fn get_spending_counters(
+        &self,
+        account: &ID,
+    ) -> Result<[SpendingCounter; SpendingCounterIncreasing::LANES], LedgerError> {
+        self.get_state(account)
+            .map(|state| state.spending.get_valid_counters())
+    }
+
+    /// Get the next valid spending counter of the account in the given lane
+    pub fn get_spending_counter(
+        &self,
+        account: &ID,
+        lane: usize,
+    ) -> Result<SpendingCounter, LedgerError> {
+        Ok(self
+            .get_state(account)?
+            .spending
+            .get_valid_counter_in_lane(lane)?)
+    }
+
+    /// Iterate over the next valid spending counters of all the accounts
+    pub fn spending_counters(
+        &self,
+    ) -> impl Iterator<Item = (&ID, [SpendingCounter; SpendingCounterIncreasing::LANES])> {
+        self.0
+            .iter()
+            .map(|(id, state)| (id, state.spending.get_valid_counters()))
+    }
+
     /// Remove an account from this ledger
     ///
     /// If the account still have value > 0, then error
@@ -265,6 +299,15 @@ impl<ID: Clone + Eq + Hash, Extra: Clone> Ledger<ID, Extra> {
     pub fn iter(&self) -> Iter<'_, ID, Extra> {
         Iter(self.0.iter())
     }
+
+    /// Number of accounts, in constant time
+    pub fn len(&self) -> usize {
+        self.0.size()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
 }
 
 impl<ID: Clone + Eq + Hash + Debug, Extra: Clone + Debug> Debug for Ledger<ID, Extra> {
@@ -743,6 +786,48 @@ mod tests {
         }
     }
 
+    #[test]
+    pub fn spending_counters_follow_spending_lane() {
+        let id = TestGen::identifier();
+        let ledger = Ledger::new()
+            .add_account(id.clone(), Value(100), ())
+            .unwrap();
+
+        let counters = ledger.get_spending_counters(&id).unwrap();
+        for (lane, counter) in counters.iter().enumerate() {
+            assert_eq!(counter.lane(), lane);
+            assert_eq!(counter.unlaned_counter(), 0);
+        }
+
+        let lane = 3;
+        let counter = ledger.get_spending_counter(&id, lane).unwrap();
+        let ledger = ledger.remove_value(&id, counter, Value(10)).unwrap();
+
+        assert_eq!(
+            ledger.get_spending_counter(&id, lane).unwrap(),
+            counter.increment()
+        );
+        assert_eq!(
+            ledger.get_spending_counter(&id, 0).unwrap(),
+            counters[0],
+            "other lanes should not be affected"
+        );
+        assert_eq!(
+            ledger.spending_counters().collect::<Vec<_>>(),
+            vec![(&id, ledger.get_spending_counters(&id).unwrap())]
+        );
+        assert!(matches!(
+            ledger.get_spending_counter(&id, SpendingCounterIncreasing::LANES),
+            Err(LedgerError::SpendingCounterError(
+                spending::Error::InvalidLane(_)
+            ))
+        ));
+        assert!(matches!(
+            ledger.get_spending_counters(&TestGen::identifier()),
+            Err(LedgerError::NonExistent)
+        ));
+    }
+
     #[test]
     pub fn add_value_or_account_test() {
         let ledger = Ledger::new();
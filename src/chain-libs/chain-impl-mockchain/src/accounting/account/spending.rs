@@ -10,6 +10,11 @@ pub enum Error {
     InvalidLaneValue(usize, usize),
     #[error("Invalid lane: {0} or counter: {1}, expected lane < (1 << LANES_BITS), counter < (1 << UNLANES_BITS)")]
     InvalidLaneOrCounter(usize, u32),
+    #[error(
+        "Invalid lane: {0}, expected lane < {}",
+        SpendingCounterIncreasing::LANES
+    )]
+    InvalidLane(usize),
 }
 
 /// Simple strategy to spend from multiple increasing counters
@@ -54,6 +59,14 @@ impl SpendingCounterIncreasing {
         self.nexts
     }
 
+    /// next valid counter in the given lane
+    pub fn get_valid_counter_in_lane(&self, lane: usize) -> Result<SpendingCounter, Error> {
+        self.nexts
+            .get(lane)
+            .copied()
+            .ok_or(Error::InvalidLane(lane))
+    }
+
     /// try to match the lane of the counter in argument, if it doesn't match
     /// an error reported.
     ///
@@ -9,6 +9,7 @@ use std::slice;
 
 mod builder;
 mod header;
+mod reader;
 
 #[cfg(any(test, feature = "property-test-api"))]
 pub mod test;
@@ -22,6 +23,8 @@ pub use crate::header::{
 
 pub use builder::builder;
 
+pub use reader::{BlockReadError, BlockReader};
+
 pub use crate::header::{BlockVersion, ChainLength};
 
 pub use crate::date::{BlockDate, BlockDateParseError, Epoch, SlotId};
@@ -112,38 +115,7 @@ impl Serialize for Block {
 
 impl Deserialize for Block {
     fn deserialize<R: std::io::Read>(codec: &mut Codec<R>) -> Result<Self, ReadError> {
-        let header = Header::deserialize(codec)?;
-        let mut remaining_content_size = header.block_content_size() as usize;
-        let mut contents = ContentsBuilder::new();
-
-        while remaining_content_size > 0 {
-            let message = Fragment::deserialize(codec)?;
-            let message_size = message.serialized_size();
-
-            if message_size > remaining_content_size {
-                return Err(ReadError::StructureInvalid(format!(
-                    "{} bytes remaining according to the header but got a fragment of size {}",
-                    message_size, remaining_content_size
-                )));
-            }
-
-            contents.push(message);
-
-            remaining_content_size -= message_size;
-        }
-
-        let contents: Contents = contents.into();
-        let (content_hash, _content_size) = contents.compute_hash_size();
-
-        if header.block_content_hash() != content_hash {
-            return Err(ReadError::InvalidData(format!(
-                "Inconsistent block content hash in header: block {} header {}",
-                content_hash,
-                header.block_content_hash()
-            )));
-        }
-
-        Ok(Block { header, contents })
+        BlockReader::unbounded().read(codec).map_err(Into::into)
     }
 }
 
@@ -0,0 +1,112 @@
+use super::{Block, BlockContentHash, Contents, ContentsBuilder, Header};
+use crate::fragment::Fragment;
+use chain_core::{
+    packer::Codec,
+    property::{Deserialize, ReadError, Serialize},
+};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum BlockReadError {
+    #[error("invalid block header")]
+    Header(#[source] ReadError),
+    #[error("block of {size} bytes according to its header, above the maximum of {max} bytes")]
+    TooBig { size: usize, max: usize },
+    #[error("invalid fragment at index {index}")]
+    Fragment {
+        index: usize,
+        #[source]
+        source: ReadError,
+    },
+    #[error("fragment at index {index} has a size of {actual} bytes, but only {expected} bytes of content remain according to the header")]
+    FragmentSize {
+        index: usize,
+        expected: usize,
+        actual: usize,
+    },
+    #[error("inconsistent block content hash: header {expected}, content {actual}")]
+    ContentHash {
+        expected: BlockContentHash,
+        actual: BlockContentHash,
+    },
+}
+
+impl From<BlockReadError> for ReadError {
+    fn from(err: BlockReadError) -> Self {
+        match err {
+            BlockReadError::Header(err) => err,
+            BlockReadError::ContentHash { .. } => ReadError::InvalidData(err.to_string()),
+            err => ReadError::StructureInvalid(err.to_string()),
+        }
+    }
+}
+
+/// Reads blocks fragment by fragment, refusing the blocks above a maximum
+/// size before reading their content.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlockReader {
+    max_block_size: usize,
+}
+
+impl BlockReader {
+    /// Reader of the blocks of at most `max_block_size` bytes, header included
+    pub fn new(max_block_size: usize) -> Self {
+        BlockReader { max_block_size }
+    }
+
+    /// Reader of blocks of any size, for blocks coming from a trusted source
+    pub fn unbounded() -> Self {
+        Self::new(usize::MAX)
+    }
+
+    pub fn max_block_size(&self) -> usize {
+        self.max_block_size
+    }
+
+    pub fn read<R: std::io::Read>(&self, codec: &mut Codec<R>) -> Result<Block, BlockReadError> {
+        let header = Header::deserialize(codec).map_err(BlockReadError::Header)?;
+        let mut remaining_content_size = header.block_content_size() as usize;
+        let size = header.size().get().saturating_add(remaining_content_size);
+        if size > self.max_block_size {
+            return Err(BlockReadError::TooBig {
+                size,
+                max: self.max_block_size,
+            });
+        }
+
+        let mut contents = ContentsBuilder::new();
+        let mut index = 0;
+        while remaining_content_size > 0 {
+            let fragment = Fragment::deserialize(codec)
+                .map_err(|source| BlockReadError::Fragment { index, source })?;
+            let fragment_size = fragment.serialized_size();
+            if fragment_size > remaining_content_size {
+                return Err(BlockReadError::FragmentSize {
+                    index,
+                    expected: remaining_content_size,
+                    actual: fragment_size,
+                });
+            }
+            contents.push(fragment);
+            remaining_content_size -= fragment_size;
+            index += 1;
+        }
+
+        let contents: Contents = contents.into();
+        let (content_hash, _content_size) = contents.compute_hash_size();
+        if header.block_content_hash() != content_hash {
+            return Err(BlockReadError::ContentHash {
+                expected: header.block_content_hash(),
+                actual: content_hash,
+            });
+        }
+
+        Ok(Block::new_unchecked(header, contents))
+    }
+}
+
+impl Default for BlockReader {
+    fn default() -> Self {
+        Self::unbounded()
+    }
+}
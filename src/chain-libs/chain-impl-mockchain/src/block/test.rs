@@ -1,5 +1,5 @@
 #[cfg(test)]
-use crate::block::Header;
+use crate::block::{BlockReadError, BlockReader, Header};
 #[cfg(test)]
 use crate::header::HeaderDesc;
 #[cfg(test)]
@@ -59,6 +59,29 @@ quickcheck! {
 
         (content_hash != header.block_content_hash() || content_size != header.block_content_size()) == block.is_err()
     }
+
+    fn block_reader_max_size(block: Block) -> bool {
+        let bytes = block.serialize_as_vec().unwrap();
+        let read = |max| BlockReader::new(max).read(&mut Codec::new(bytes.as_slice()));
+
+        read(bytes.len()).is_ok()
+            && matches!(
+                read(bytes.len() - 1),
+                Err(BlockReadError::TooBig { size, max }) if size == bytes.len() && max == bytes.len() - 1
+            )
+    }
+
+    fn block_reader_reports_truncated_fragment(block: Block) -> TestResult {
+        if block.contents.is_empty() {
+            return TestResult::discard();
+        }
+        let mut bytes = block.serialize_as_vec().unwrap();
+        bytes.pop();
+
+        let last = block.contents.len() - 1;
+        let res = BlockReader::unbounded().read(&mut Codec::new(bytes.as_slice()));
+        TestResult::from_bool(matches!(res, Err(BlockReadError::Fragment { index, .. }) if index == last))
+    }
 }
 
 #[cfg(test)]
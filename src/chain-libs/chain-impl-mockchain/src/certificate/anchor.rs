@@ -0,0 +1,186 @@
+use crate::{
+    certificate::CertificateSlice,
+    transaction::{Payload, PayloadAuthData, PayloadData, PayloadSlice},
+};
+use chain_core::{
+    packer::Codec,
+    property::{DeserializeFromSlice, ReadError, Serialize, WriteError},
+};
+use thiserror::Error;
+use typed_bytes::{ByteArray, ByteBuilder};
+
+use std::marker::PhantomData;
+
+pub const ANCHOR_COMMITMENT_SIZE: usize = 32;
+pub const ANCHOR_TAG_MAX_SIZE: usize = 64;
+
+pub type AnchorCommitment = [u8; ANCHOR_COMMITMENT_SIZE];
+
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum AnchorError {
+    #[error("anchor tag cannot be empty")]
+    EmptyTag,
+    #[error("anchor tag is {0} bytes long, maximum is {ANCHOR_TAG_MAX_SIZE}")]
+    TagTooLong(usize),
+}
+
+/// Commitment to some external data (e.g. the state hash of an off-chain
+/// database), recorded on-chain for auditability.
+///
+/// The anchor does not modify the ledger state, the transaction carrying
+/// it only pays the certificate fees.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Anchor {
+    commitment: AnchorCommitment,
+    tag: Option<Box<[u8]>>,
+}
+
+impl Anchor {
+    pub fn new(commitment: AnchorCommitment, tag: Option<Vec<u8>>) -> Result<Self, AnchorError> {
+        if let Some(tag) = &tag {
+            if tag.is_empty() {
+                return Err(AnchorError::EmptyTag);
+            }
+            if tag.len() > ANCHOR_TAG_MAX_SIZE {
+                return Err(AnchorError::TagTooLong(tag.len()));
+            }
+        }
+        Ok(Self {
+            commitment,
+            tag: tag.map(Vec::into_boxed_slice),
+        })
+    }
+
+    pub fn commitment(&self) -> &AnchorCommitment {
+        &self.commitment
+    }
+
+    /// Free form tag used by the external system to identify what is anchored
+    pub fn tag(&self) -> Option<&[u8]> {
+        self.tag.as_deref()
+    }
+
+    fn tag_len(&self) -> u8 {
+        self.tag.as_ref().map_or(0, |tag| tag.len() as u8)
+    }
+
+    pub fn serialize_in(&self, bb: ByteBuilder<Self>) -> ByteBuilder<Self> {
+        bb.bytes(&self.commitment)
+            .u8(self.tag_len())
+            .bytes(self.tag().unwrap_or(&[]))
+    }
+
+    pub fn serialize(&self) -> ByteArray<Self> {
+        self.serialize_in(ByteBuilder::new()).finalize()
+    }
+}
+
+/* Auth/Payload ************************************************************* */
+
+impl Payload for Anchor {
+    const HAS_DATA: bool = true;
+    const HAS_AUTH: bool = false;
+    type Auth = ();
+
+    fn payload_data(&self) -> PayloadData<Self> {
+        PayloadData(
+            self.serialize_in(ByteBuilder::new())
+                .finalize_as_vec()
+                .into(),
+            PhantomData,
+        )
+    }
+
+    fn payload_auth_data(_: &Self::Auth) -> PayloadAuthData<Self> {
+        PayloadAuthData(Box::new([]), PhantomData)
+    }
+
+    fn payload_to_certificate_slice(p: PayloadSlice<'_, Self>) -> Option<CertificateSlice<'_>> {
+        Some(CertificateSlice::from(p))
+    }
+}
+
+/* Ser/De ******************************************************************* */
+
+impl Serialize for Anchor {
+    fn serialized_size(&self) -> usize {
+        ANCHOR_COMMITMENT_SIZE + Codec::u8_size() + self.tag_len() as usize
+    }
+
+    fn serialize<W: std::io::Write>(&self, codec: &mut Codec<W>) -> Result<(), WriteError> {
+        codec.put_bytes(&self.commitment)?;
+        codec.put_u8(self.tag_len())?;
+        codec.put_bytes(self.tag().unwrap_or(&[]))
+    }
+}
+
+impl DeserializeFromSlice for Anchor {
+    fn deserialize_from_slice(codec: &mut Codec<&[u8]>) -> Result<Self, ReadError> {
+        let mut commitment = [0; ANCHOR_COMMITMENT_SIZE];
+        codec.copy_to_slice(&mut commitment)?;
+        let tag_len = codec.get_u8()? as usize;
+        if tag_len > ANCHOR_TAG_MAX_SIZE {
+            return Err(ReadError::SizeTooBig(ANCHOR_TAG_MAX_SIZE, tag_len));
+        }
+        let tag = if tag_len == 0 {
+            None
+        } else {
+            Some(codec.get_bytes(tag_len)?)
+        };
+        Self::new(commitment, tag).map_err(|e| ReadError::StructureInvalid(e.to_string()))
+    }
+}
+
+#[cfg(any(test, feature = "property-test-api"))]
+mod tests {
+    use super::*;
+    #[cfg(test)]
+    use crate::testing::serialization::serialization_bijection;
+    #[cfg(test)]
+    use quickcheck::TestResult;
+    use quickcheck::{Arbitrary, Gen};
+
+    impl Arbitrary for Anchor {
+        fn arbitrary<G: Gen>(g: &mut G) -> Self {
+            let mut commitment = [0; ANCHOR_COMMITMENT_SIZE];
+            g.fill_bytes(&mut commitment);
+            let tag_len = usize::arbitrary(g) % (ANCHOR_TAG_MAX_SIZE + 1);
+            let tag = if tag_len == 0 {
+                None
+            } else {
+                Some(
+                    std::iter::repeat_with(|| u8::arbitrary(g))
+                        .take(tag_len)
+                        .collect(),
+                )
+            };
+            Self::new(commitment, tag).unwrap()
+        }
+    }
+
+    quickcheck! {
+        fn anchor_serialization_bijection(b: Anchor) -> TestResult {
+            serialization_bijection(b)
+        }
+    }
+
+    #[test]
+    fn anchor_tag_size_is_limited() {
+        assert_eq!(
+            Anchor::new([0; ANCHOR_COMMITMENT_SIZE], Some(vec![])),
+            Err(AnchorError::EmptyTag)
+        );
+        assert_eq!(
+            Anchor::new(
+                [0; ANCHOR_COMMITMENT_SIZE],
+                Some(vec![0; ANCHOR_TAG_MAX_SIZE + 1])
+            ),
+            Err(AnchorError::TagTooLong(ANCHOR_TAG_MAX_SIZE + 1))
+        );
+        assert!(Anchor::new(
+            [0; ANCHOR_COMMITMENT_SIZE],
+            Some(vec![0; ANCHOR_TAG_MAX_SIZE])
+        )
+        .is_ok());
+    }
+}
@@ -1,6 +1,8 @@
+mod anchor;
 mod delegation;
 mod evm_mapping;
 mod mint_token;
+mod multisig_declaration_update;
 mod pool;
 mod update_proposal;
 mod update_vote;
@@ -22,9 +24,15 @@ pub use self::vote_tally::{
     DecryptedPrivateTally, DecryptedPrivateTallyError, DecryptedPrivateTallyProposal, TallyProof,
     VoteTally, VoteTallyPayload,
 };
+pub use anchor::{
+    Anchor, AnchorCommitment, AnchorError, ANCHOR_COMMITMENT_SIZE, ANCHOR_TAG_MAX_SIZE,
+};
 pub use delegation::{OwnerStakeDelegation, StakeDelegation};
 pub use evm_mapping::EvmMapping;
 pub use mint_token::MintToken;
+pub use multisig_declaration_update::{
+    MultisigDeclarationUpdate, MultisigDeclarationUpdateSignature,
+};
 pub use pool::{
     GenesisPraosLeaderHash, IndexSignatures, ManagementThreshold, PoolId, PoolOwnersSigned,
     PoolPermissions, PoolRegistration, PoolRegistrationHash, PoolRetirement, PoolSignature,
@@ -46,6 +54,8 @@ pub enum CertificateSlice<'a> {
     UpdateVote(PayloadSlice<'a, UpdateVote>),
     MintToken(PayloadSlice<'a, MintToken>),
     EvmMapping(PayloadSlice<'a, EvmMapping>),
+    Anchor(PayloadSlice<'a, Anchor>),
+    MultisigDeclarationUpdate(PayloadSlice<'a, MultisigDeclarationUpdate>),
 }
 
 impl<'a> From<PayloadSlice<'a, StakeDelegation>> for CertificateSlice<'a> {
@@ -119,6 +129,18 @@ impl<'a> From<PayloadSlice<'a, EvmMapping>> for CertificateSlice<'a> {
     }
 }
 
+impl<'a> From<PayloadSlice<'a, Anchor>> for CertificateSlice<'a> {
+    fn from(payload: PayloadSlice<'a, Anchor>) -> CertificateSlice<'a> {
+        CertificateSlice::Anchor(payload)
+    }
+}
+
+impl<'a> From<PayloadSlice<'a, MultisigDeclarationUpdate>> for CertificateSlice<'a> {
+    fn from(payload: PayloadSlice<'a, MultisigDeclarationUpdate>) -> CertificateSlice<'a> {
+        CertificateSlice::MultisigDeclarationUpdate(payload)
+    }
+}
+
 impl<'a> CertificateSlice<'a> {
     pub fn into_owned(self) -> Certificate {
         match self {
@@ -138,6 +160,10 @@ impl<'a> CertificateSlice<'a> {
             CertificateSlice::UpdateVote(c) => Certificate::UpdateVote(c.into_payload()),
             CertificateSlice::MintToken(c) => Certificate::MintToken(c.into_payload()),
             CertificateSlice::EvmMapping(c) => Certificate::EvmMapping(c.into_payload()),
+            CertificateSlice::Anchor(c) => Certificate::Anchor(c.into_payload()),
+            CertificateSlice::MultisigDeclarationUpdate(c) => {
+                Certificate::MultisigDeclarationUpdate(c.into_payload())
+            }
         }
     }
 }
@@ -156,6 +182,8 @@ pub enum CertificatePayload {
     UpdateVote(PayloadData<UpdateVote>),
     MintToken(PayloadData<MintToken>),
     EvmMapping(PayloadData<EvmMapping>),
+    Anchor(PayloadData<Anchor>),
+    MultisigDeclarationUpdate(PayloadData<MultisigDeclarationUpdate>),
 }
 
 impl CertificatePayload {
@@ -173,6 +201,8 @@ impl CertificatePayload {
             CertificatePayload::UpdateVote(payload) => payload.borrow().into(),
             CertificatePayload::MintToken(payload) => payload.borrow().into(),
             CertificatePayload::EvmMapping(payload) => payload.borrow().into(),
+            CertificatePayload::Anchor(payload) => payload.borrow().into(),
+            CertificatePayload::MultisigDeclarationUpdate(payload) => payload.borrow().into(),
         }
     }
 }
@@ -212,6 +242,10 @@ impl<'a> From<&'a Certificate> for CertificatePayload {
             Certificate::EvmMapping(payload) => {
                 CertificatePayload::EvmMapping(payload.payload_data())
             }
+            Certificate::Anchor(payload) => CertificatePayload::Anchor(payload.payload_data()),
+            Certificate::MultisigDeclarationUpdate(payload) => {
+                CertificatePayload::MultisigDeclarationUpdate(payload.payload_data())
+            }
         }
     }
 }
@@ -231,6 +265,8 @@ pub enum Certificate {
     UpdateVote(UpdateVote),
     MintToken(MintToken),
     EvmMapping(EvmMapping),
+    Anchor(Anchor),
+    MultisigDeclarationUpdate(MultisigDeclarationUpdate),
 }
 
 impl From<StakeDelegation> for Certificate {
@@ -305,6 +341,18 @@ impl From<EvmMapping> for Certificate {
     }
 }
 
+impl From<Anchor> for Certificate {
+    fn from(anchor: Anchor) -> Self {
+        Self::Anchor(anchor)
+    }
+}
+
+impl From<MultisigDeclarationUpdate> for Certificate {
+    fn from(update: MultisigDeclarationUpdate) -> Self {
+        Self::MultisigDeclarationUpdate(update)
+    }
+}
+
 impl Certificate {
     pub fn need_auth(&self) -> bool {
         match self {
@@ -320,6 +368,10 @@ impl Certificate {
             Certificate::UpdateVote(_) => <UpdateVote as Payload>::HAS_AUTH,
             Certificate::MintToken(_) => <MintToken as Payload>::HAS_AUTH,
             Certificate::EvmMapping(_) => <EvmMapping as Payload>::HAS_AUTH,
+            Certificate::Anchor(_) => <Anchor as Payload>::HAS_AUTH,
+            Certificate::MultisigDeclarationUpdate(_) => {
+                <MultisigDeclarationUpdate as Payload>::HAS_AUTH
+            }
         }
     }
 }
@@ -340,6 +392,10 @@ pub enum SignedCertificate {
     UpdateProposal(UpdateProposal, <UpdateProposal as Payload>::Auth),
     UpdateVote(UpdateVote, <UpdateVote as Payload>::Auth),
     EvmMapping(EvmMapping, <EvmMapping as Payload>::Auth),
+    MultisigDeclarationUpdate(
+        MultisigDeclarationUpdate,
+        <MultisigDeclarationUpdate as Payload>::Auth,
+    ),
 }
 
 #[cfg(test)]
@@ -363,6 +419,8 @@ mod tests {
             Certificate::UpdateVote(_) => true,
             Certificate::MintToken(_) => false,
             Certificate::EvmMapping(_) => true,
+            Certificate::Anchor(_) => false,
+            Certificate::MultisigDeclarationUpdate(_) => true,
         };
         TestResult::from_bool(certificate.need_auth() == expected_result)
     }
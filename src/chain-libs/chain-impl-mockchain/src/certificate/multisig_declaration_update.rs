@@ -0,0 +1,260 @@
+use crate::{
+    certificate::CertificateSlice,
+    key::{deserialize_public_key, deserialize_signature, Hash},
+    multisig::{DeclElement, Declaration, DeclarationError, Identifier, Index},
+    transaction::{
+        Payload, PayloadAuthData, PayloadData, PayloadSlice, SingleAccountBindingSignature,
+        TransactionBindingAuthData,
+    },
+};
+use chain_core::{
+    packer::Codec,
+    property::{Deserialize, DeserializeFromSlice, ReadError, Serialize, WriteError},
+};
+use chain_crypto::{Ed25519, PublicKey, Verification};
+use typed_bytes::{ByteArray, ByteBuilder};
+
+use std::marker::PhantomData;
+
+/// Replace the declaration (owners and threshold) of an existing multisig account.
+///
+/// The account keeps its identifier, which is derived from its initial declaration,
+/// so the funds stay in place while the owners are rotated. The certificate needs to
+/// be signed by enough owners of the current declaration to meet its threshold.
+///
+/// Only declarations made of owner key hashes are supported, as sub declarations
+/// cannot be used to sign yet.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MultisigDeclarationUpdate {
+    id: Identifier,
+    declaration: Declaration,
+}
+
+/// Signatures of the owners of the current declaration, along with their index in it
+/// and their public key, as the declaration only contains the hashes of the keys.
+#[derive(Debug, Clone)]
+pub struct MultisigDeclarationUpdateSignature {
+    pub signatures: Vec<(Index, PublicKey<Ed25519>, SingleAccountBindingSignature)>,
+}
+
+impl MultisigDeclarationUpdate {
+    pub fn new(id: Identifier, declaration: Declaration) -> Result<Self, DeclarationError> {
+        declaration.is_valid()?;
+        if declaration
+            .owners()
+            .iter()
+            .any(|owner| matches!(owner, DeclElement::Sub(_)))
+        {
+            return Err(DeclarationError::SubNotImplemented);
+        }
+        Ok(Self { id, declaration })
+    }
+
+    pub fn id(&self) -> &Identifier {
+        &self.id
+    }
+
+    pub fn declaration(&self) -> &Declaration {
+        &self.declaration
+    }
+
+    fn owner_hashes(&self) -> impl ExactSizeIterator<Item = Hash> + '_ {
+        self.declaration.owners().iter().map(DeclElement::to_hash)
+    }
+
+    pub fn serialize_in(&self, bb: ByteBuilder<Self>) -> ByteBuilder<Self> {
+        bb.bytes(self.id.as_ref())
+            .u8(self.declaration.threshold() as u8)
+            .iter8(self.owner_hashes(), |bb, owner| bb.bytes(owner.as_ref()))
+    }
+
+    pub fn serialize(&self) -> ByteArray<Self> {
+        self.serialize_in(ByteBuilder::new()).finalize()
+    }
+}
+
+impl MultisigDeclarationUpdateSignature {
+    pub fn serialize_in(&self, bb: ByteBuilder<Self>) -> ByteBuilder<Self> {
+        bb.iter8(&mut self.signatures.iter(), |bb, (i, pk, s)| {
+            bb.u8(i.to_usize() as u8)
+                .bytes(pk.as_ref())
+                .bytes(s.as_ref())
+        })
+    }
+
+    /// Verify the cryptographic signatures and that every owner signed only once.
+    ///
+    /// Whether the signers are owners of the account and meet its threshold is
+    /// checked against the current declaration by the multisig ledger.
+    pub fn verify<'a>(&self, verify_data: &TransactionBindingAuthData<'a>) -> Verification {
+        if self.signatures.is_empty() {
+            return Verification::Failed;
+        }
+
+        let mut indices: Vec<_> = self.signatures.iter().map(|(i, _, _)| *i).collect();
+        indices.sort();
+        indices.dedup();
+        if indices.len() != self.signatures.len() {
+            return Verification::Failed;
+        }
+
+        for (_, pk, sig) in self.signatures.iter() {
+            if sig.verify_slice(pk, verify_data) == Verification::Failed {
+                return Verification::Failed;
+            }
+        }
+        Verification::Success
+    }
+
+    pub fn signers(&self) -> Vec<(Index, PublicKey<Ed25519>)> {
+        self.signatures
+            .iter()
+            .map(|(i, pk, _)| (*i, pk.clone()))
+            .collect()
+    }
+}
+
+/* Auth/Payload ************************************************************* */
+
+impl Payload for MultisigDeclarationUpdate {
+    const HAS_DATA: bool = true;
+    const HAS_AUTH: bool = true;
+    type Auth = MultisigDeclarationUpdateSignature;
+
+    fn payload_data(&self) -> PayloadData<Self> {
+        PayloadData(
+            self.serialize_in(ByteBuilder::new())
+                .finalize_as_vec()
+                .into(),
+            PhantomData,
+        )
+    }
+
+    fn payload_auth_data(auth: &Self::Auth) -> PayloadAuthData<Self> {
+        PayloadAuthData(
+            auth.serialize_in(ByteBuilder::new())
+                .finalize_as_vec()
+                .into(),
+            PhantomData,
+        )
+    }
+
+    fn payload_to_certificate_slice(p: PayloadSlice<'_, Self>) -> Option<CertificateSlice<'_>> {
+        Some(CertificateSlice::from(p))
+    }
+}
+
+/* Ser/De ******************************************************************* */
+
+impl Serialize for MultisigDeclarationUpdate {
+    fn serialized_size(&self) -> usize {
+        self.id.as_ref().len()
+            + Codec::u8_size()
+            + Codec::u8_size()
+            + self
+                .owner_hashes()
+                .map(|owner| owner.as_ref().len())
+                .sum::<usize>()
+    }
+
+    fn serialize<W: std::io::Write>(&self, codec: &mut Codec<W>) -> Result<(), WriteError> {
+        codec.put_bytes(self.id.as_ref())?;
+        codec.put_u8(self.declaration.threshold() as u8)?;
+        codec.put_u8(self.declaration.total() as u8)?;
+        for owner in self.owner_hashes() {
+            owner.serialize(codec)?;
+        }
+        Ok(())
+    }
+}
+
+impl DeserializeFromSlice for MultisigDeclarationUpdate {
+    fn deserialize_from_slice(codec: &mut Codec<&[u8]>) -> Result<Self, ReadError> {
+        let id = Identifier(Hash::deserialize(codec)?);
+        let threshold = codec.get_u8()?;
+        let owners_nb = codec.get_u8()? as usize;
+        let owners = (0..owners_nb)
+            .map(|_| Hash::deserialize(codec).map(DeclElement::Owner))
+            .collect::<Result<Vec<_>, _>>()?;
+        let declaration = Declaration::new(threshold, owners)
+            .map_err(|e| ReadError::StructureInvalid(e.to_string()))?;
+        Ok(Self { id, declaration })
+    }
+}
+
+impl DeserializeFromSlice for MultisigDeclarationUpdateSignature {
+    fn deserialize_from_slice(codec: &mut Codec<&[u8]>) -> Result<Self, ReadError> {
+        let sigs_nb = codec.get_u8()? as usize;
+        if sigs_nb == 0 {
+            return Err(ReadError::StructureInvalid(
+                "multisig declaration update signature with 0 signatures".to_string(),
+            ));
+        }
+        let mut signatures = Vec::with_capacity(sigs_nb);
+        for _ in 0..sigs_nb {
+            let index = Index::from_u8(codec.get_u8()?)
+                .ok_or_else(|| ReadError::StructureInvalid("invalid index".to_string()))?;
+            let pk = deserialize_public_key(codec)?;
+            let sig = deserialize_signature(codec)?;
+            signatures.push((index, pk, SingleAccountBindingSignature(sig)))
+        }
+        Ok(Self { signatures })
+    }
+}
+
+#[cfg(any(test, feature = "property-test-api"))]
+mod tests {
+    use super::*;
+    #[cfg(test)]
+    use crate::testing::serialization::serialization_bijection;
+    use chain_crypto::KeyPair;
+    #[cfg(test)]
+    use quickcheck::TestResult;
+    use quickcheck::{Arbitrary, Gen};
+
+    impl Arbitrary for MultisigDeclarationUpdate {
+        fn arbitrary<G: Gen>(g: &mut G) -> Self {
+            let owners_nb = 2 + usize::arbitrary(g) % 7;
+            let threshold = 1 + u8::arbitrary(g) % owners_nb as u8;
+            let owners = std::iter::repeat_with(|| DeclElement::Owner(Arbitrary::arbitrary(g)))
+                .take(owners_nb)
+                .collect();
+            let declaration = Declaration::new(threshold, owners).unwrap();
+            Self::new(Arbitrary::arbitrary(g), declaration).unwrap()
+        }
+    }
+
+    impl Arbitrary for MultisigDeclarationUpdateSignature {
+        fn arbitrary<G: Gen>(g: &mut G) -> Self {
+            let signatures_nb = 1 + u8::arbitrary(g) % 8;
+            let signatures = (0..signatures_nb)
+                .map(|i| {
+                    let keys: KeyPair<Ed25519> = Arbitrary::arbitrary(g);
+                    (
+                        Index::from_u8(i).unwrap(),
+                        keys.public_key().clone(),
+                        Arbitrary::arbitrary(g),
+                    )
+                })
+                .collect();
+            Self { signatures }
+        }
+    }
+
+    quickcheck! {
+        fn multisig_declaration_update_bijection(b: MultisigDeclarationUpdate) -> TestResult {
+            serialization_bijection(b)
+        }
+    }
+
+    #[test]
+    fn multisig_declaration_update_rejects_sub_declarations() {
+        let owner = || DeclElement::Owner(Hash::hash_bytes(&[1, 2, 3]));
+        let sub = Declaration::new(1, vec![owner(), owner()]).unwrap();
+        let declaration = Declaration::new(1, vec![owner(), DeclElement::Sub(sub)]).unwrap();
+        assert_eq!(
+            MultisigDeclarationUpdate::new(Identifier::from([0; 32]), declaration),
+            Err(DeclarationError::SubNotImplemented)
+        );
+    }
+}
@@ -152,8 +152,15 @@ impl Arbitrary for PoolRegistration {
 
 impl Arbitrary for TreasuryGovernanceAction {
     fn arbitrary<G: Gen>(g: &mut G) -> Self {
-        TreasuryGovernanceAction::TransferToRewards {
-            value: Arbitrary::arbitrary(g),
+        if bool::arbitrary(g) {
+            TreasuryGovernanceAction::TransferToRewards {
+                value: Arbitrary::arbitrary(g),
+            }
+        } else {
+            TreasuryGovernanceAction::TransferToAccount {
+                value: Arbitrary::arbitrary(g),
+                destination: Arbitrary::arbitrary(g),
+            }
         }
     }
 }
@@ -310,7 +317,7 @@ impl Arbitrary for TallyProof {
 
 impl Arbitrary for Certificate {
     fn arbitrary<G: Gen>(g: &mut G) -> Self {
-        let option = u8::arbitrary(g) % 11;
+        let option = u8::arbitrary(g) % 13;
         match option {
             0 => Certificate::StakeDelegation(Arbitrary::arbitrary(g)),
             1 => Certificate::OwnerStakeDelegation(Arbitrary::arbitrary(g)),
@@ -323,6 +330,8 @@ impl Arbitrary for Certificate {
             8 => Certificate::UpdateProposal(Arbitrary::arbitrary(g)),
             9 => Certificate::UpdateVote(Arbitrary::arbitrary(g)),
             10 => Certificate::MintToken(Arbitrary::arbitrary(g)),
+            11 => Certificate::Anchor(Arbitrary::arbitrary(g)),
+            12 => Certificate::MultisigDeclarationUpdate(Arbitrary::arbitrary(g)),
             _ => panic!("unimplemented"),
         }
     }
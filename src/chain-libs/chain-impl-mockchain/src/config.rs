@@ -93,6 +93,9 @@ pub enum ConfigParam {
     EvmConfiguration(Config),
     #[cfg(feature = "evm")]
     EvmEnvironment(EvmEnvSettings),
+    MaxFragmentSize(u32),
+    VotingPowerSnapshot(bool),
+    ScriptAddresses(bool),
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -109,6 +112,8 @@ pub enum RewardParams {
         epoch_start: Epoch,
         epoch_rate: NonZeroU32,
     },
+    /// The same contribution every epoch from the start epoch
+    Fixed { constant: u64, epoch_start: Epoch },
 }
 
 #[cfg(feature = "evm")]
@@ -180,6 +185,12 @@ pub enum Tag {
     #[cfg(feature = "evm")]
     #[strum(to_string = "evm-environment-params")]
     EvmEnvironment = 31,
+    #[strum(to_string = "max-fragment-size")]
+    MaxFragmentSize = 32,
+    #[strum(to_string = "voting-power-snapshot")]
+    VotingPowerSnapshot = 33,
+    #[strum(to_string = "script-addresses")]
+    ScriptAddresses = 34,
 }
 
 impl Tag {
@@ -215,6 +226,9 @@ impl Tag {
             30 => Some(Tag::EvmConfiguration),
             #[cfg(feature = "evm")]
             31 => Some(Tag::EvmEnvironment),
+            32 => Some(Tag::MaxFragmentSize),
+            33 => Some(Tag::VotingPowerSnapshot),
+            34 => Some(Tag::ScriptAddresses),
             _ => None,
         }
     }
@@ -255,6 +269,9 @@ impl<'a> From<&'a ConfigParam> for Tag {
             ConfigParam::EvmConfiguration(_) => Tag::EvmConfiguration,
             #[cfg(feature = "evm")]
             ConfigParam::EvmEnvironment(_) => Tag::EvmEnvironment,
+            ConfigParam::MaxFragmentSize(_) => Tag::MaxFragmentSize,
+            ConfigParam::VotingPowerSnapshot(_) => Tag::VotingPowerSnapshot,
+            ConfigParam::ScriptAddresses(_) => Tag::ScriptAddresses,
         }
     }
 }
@@ -346,6 +363,15 @@ impl DeserializeFromSlice for ConfigParam {
             Tag::EvmEnvironment => {
                 ConfigParamVariant::from_payload(bytes).map(ConfigParam::EvmEnvironment)
             }
+            Tag::MaxFragmentSize => {
+                ConfigParamVariant::from_payload(bytes).map(ConfigParam::MaxFragmentSize)
+            }
+            Tag::VotingPowerSnapshot => {
+                ConfigParamVariant::from_payload(bytes).map(ConfigParam::VotingPowerSnapshot)
+            }
+            Tag::ScriptAddresses => {
+                ConfigParamVariant::from_payload(bytes).map(ConfigParam::ScriptAddresses)
+            }
         }
         .map_err(Into::into)
     }
@@ -385,6 +411,9 @@ impl Serialize for ConfigParam {
                 ConfigParam::EvmConfiguration(data) => data.to_payload().len(),
                 #[cfg(feature = "evm")]
                 ConfigParam::EvmEnvironment(data) => data.to_payload().len(),
+                ConfigParam::MaxFragmentSize(data) => data.to_payload().len(),
+                ConfigParam::VotingPowerSnapshot(data) => data.to_payload().len(),
+                ConfigParam::ScriptAddresses(data) => data.to_payload().len(),
             }
     }
 
@@ -421,6 +450,9 @@ impl Serialize for ConfigParam {
             ConfigParam::EvmConfiguration(data) => data.to_payload(),
             #[cfg(feature = "evm")]
             ConfigParam::EvmEnvironment(data) => data.to_payload(),
+            ConfigParam::MaxFragmentSize(data) => data.to_payload(),
+            ConfigParam::VotingPowerSnapshot(data) => data.to_payload(),
+            ConfigParam::ScriptAddresses(data) => data.to_payload(),
         };
         let taglen = TagLen::new(tag, bytes.len()).ok_or_else(|| {
             io::Error::new(
@@ -509,6 +541,10 @@ impl ConfigParamVariant for RewardParams {
                 .u64(ratio.denominator.get())
                 .u32(*epoch_start)
                 .u32(epoch_rate.get()),
+            RewardParams::Fixed {
+                constant,
+                epoch_start,
+            } => ByteBuilder::new().u8(3).u64(*constant).u32(*epoch_start),
         };
         bb.finalize_as_vec()
     }
@@ -548,6 +584,14 @@ impl ConfigParamVariant for RewardParams {
                     epoch_rate: erate,
                 })
             }
+            3 => {
+                let constant = codec.get_be_u64()?;
+                let epoch_start = codec.get_be_u32()?;
+                Ok(RewardParams::Fixed {
+                    constant,
+                    epoch_start,
+                })
+            }
             _ => Err(Error::InvalidTag),
         }
     }
@@ -934,20 +978,30 @@ mod test {
 
     impl Arbitrary for RewardParams {
         fn arbitrary<G: Gen>(g: &mut G) -> Self {
-            if bool::arbitrary(g) {
-                RewardParams::Linear {
+            match u8::arbitrary(g) % 3 {
+                0 => RewardParams::Linear {
                     constant: Arbitrary::arbitrary(g),
                     ratio: Arbitrary::arbitrary(g),
                     epoch_start: Arbitrary::arbitrary(g),
                     epoch_rate: NonZeroU32::new(20).unwrap(),
-                }
-            } else {
-                RewardParams::Halving {
+                },
+                1 => RewardParams::Halving {
                     constant: Arbitrary::arbitrary(g),
-                    ratio: Arbitrary::arbitrary(g),
+                    // a halving ratio above 1 is refused in block0
+                    ratio: {
+                        let ratio = Ratio::arbitrary(g);
+                        Ratio {
+                            numerator: ratio.numerator.min(ratio.denominator.get()),
+                            ..ratio
+                        }
+                    },
                     epoch_start: Arbitrary::arbitrary(g),
                     epoch_rate: NonZeroU32::new(20).unwrap(),
-                }
+                },
+                _ => RewardParams::Fixed {
+                    constant: Arbitrary::arbitrary(g),
+                    epoch_start: Arbitrary::arbitrary(g),
+                },
             }
         }
     }
@@ -964,7 +1018,7 @@ mod test {
 
     impl Arbitrary for ConfigParam {
         fn arbitrary<G: Gen>(g: &mut G) -> Self {
-            match u8::arbitrary(g) % 30 {
+            match u8::arbitrary(g) % 33 {
                 0 => ConfigParam::Block0Date(Arbitrary::arbitrary(g)),
                 1 => ConfigParam::Discrimination(Arbitrary::arbitrary(g)),
                 2 => ConfigParam::ConsensusVersion(Arbitrary::arbitrary(g)),
@@ -1001,10 +1055,13 @@ mod test {
                 27 => ConfigParam::RemoveCommitteeId(Arbitrary::arbitrary(g)),
                 28 => ConfigParam::PerCertificateFees(Arbitrary::arbitrary(g)),
                 29 => ConfigParam::TransactionMaxExpiryEpochs(Arbitrary::arbitrary(g)),
+                30 => ConfigParam::MaxFragmentSize(Arbitrary::arbitrary(g)),
+                31 => ConfigParam::VotingPowerSnapshot(Arbitrary::arbitrary(g)),
+                32 => ConfigParam::ScriptAddresses(Arbitrary::arbitrary(g)),
                 #[cfg(feature = "evm")]
-                30 => ConfigParam::EvmConfiguration(Arbitrary::arbitrary(g)),
+                33 => ConfigParam::EvmConfiguration(Arbitrary::arbitrary(g)),
                 #[cfg(feature = "evm")]
-                31 => ConfigParam::EvmEnvironment(Arbitrary::arbitrary(g)),
+                34 => ConfigParam::EvmEnvironment(Arbitrary::arbitrary(g)),
                 _ => unreachable!(),
             }
         }
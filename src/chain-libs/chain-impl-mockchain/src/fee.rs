@@ -1,6 +1,6 @@
 use crate::certificate::CertificateSlice;
 use crate::transaction as tx;
-use crate::value::Value;
+use crate::value::{Saturating, Value};
 use std::num::NonZeroU64;
 
 /// Linear fee using the basic affine formula
@@ -103,8 +103,8 @@ pub trait FeeAlgorithm {
 
     fn calculate(&self, cert: Option<CertificateSlice>, inputs: u8, outputs: u8) -> Value {
         self.baseline()
-            .saturating_add(self.fees_for_inputs_outputs(inputs, outputs))
-            .saturating_add(cert.map_or(Value::zero(), |c| self.fees_for_certificate(c)))
+            .add_with::<Saturating>(self.fees_for_inputs_outputs(inputs, outputs))
+            .add_with::<Saturating>(cert.map_or(Value::zero(), |c| self.fees_for_certificate(c)))
     }
 
     fn calculate_tx<P: tx::Payload>(&self, tx: &tx::TransactionSlice<P>) -> Value {
@@ -41,6 +41,8 @@ pub enum Fragment {
     MintToken(Transaction<certificate::MintToken>),
     Evm(EvmTransaction),
     EvmMapping(Transaction<certificate::EvmMapping>),
+    Anchor(Transaction<certificate::Anchor>),
+    MultisigDeclarationUpdate(Transaction<certificate::MultisigDeclarationUpdate>),
 }
 
 impl PartialEq for Fragment {
@@ -69,6 +71,8 @@ pub(super) enum FragmentTag {
     MintToken = 13,
     Evm = 14,
     EvmMapping = 15,
+    Anchor = 16,
+    MultisigDeclarationUpdate = 17,
 }
 
 impl FragmentTag {
@@ -90,6 +94,8 @@ impl FragmentTag {
             13 => Some(FragmentTag::MintToken),
             14 => Some(FragmentTag::Evm),
             15 => Some(FragmentTag::EvmMapping),
+            16 => Some(FragmentTag::Anchor),
+            17 => Some(FragmentTag::MultisigDeclarationUpdate),
             _ => None,
         }
     }
@@ -115,6 +121,8 @@ impl Fragment {
             Fragment::MintToken(_) => FragmentTag::MintToken,
             Fragment::Evm(_) => FragmentTag::Evm,
             Fragment::EvmMapping(_) => FragmentTag::EvmMapping,
+            Fragment::Anchor(_) => FragmentTag::Anchor,
+            Fragment::MultisigDeclarationUpdate(_) => FragmentTag::MultisigDeclarationUpdate,
         }
     }
 
@@ -188,6 +196,10 @@ impl Deserialize for Fragment {
             Some(FragmentTag::EvmMapping) => {
                 Transaction::deserialize(&mut codec).map(Fragment::EvmMapping)
             }
+            Some(FragmentTag::Anchor) => Transaction::deserialize(&mut codec).map(Fragment::Anchor),
+            Some(FragmentTag::MultisigDeclarationUpdate) => {
+                Transaction::deserialize(&mut codec).map(Fragment::MultisigDeclarationUpdate)
+            }
             None => Err(ReadError::UnknownTag(tag as u32)),
         }
     }
@@ -214,6 +226,8 @@ impl Serialize for Fragment {
                 Fragment::MintToken(mint_token) => mint_token.serialized_size(),
                 Fragment::Evm(deployment) => deployment.serialized_size(),
                 Fragment::EvmMapping(evm_mapping) => evm_mapping.serialized_size(),
+                Fragment::Anchor(anchor) => anchor.serialized_size(),
+                Fragment::MultisigDeclarationUpdate(update) => update.serialized_size(),
             }
             + Codec::u32_size()
     }
@@ -239,6 +253,8 @@ impl Serialize for Fragment {
             Fragment::MintToken(mint_token) => mint_token.serialize(&mut tmp)?,
             Fragment::Evm(deployment) => deployment.serialize(&mut tmp)?,
             Fragment::EvmMapping(evm_mapping) => evm_mapping.serialize(&mut tmp)?,
+            Fragment::Anchor(anchor) => anchor.serialize(&mut tmp)?,
+            Fragment::MultisigDeclarationUpdate(update) => update.serialize(&mut tmp)?,
         };
         let bytes = tmp.into_inner();
         codec.put_be_u32(bytes.len() as u32)?;
@@ -6,6 +6,7 @@ use crate::{
     date::{BlockDate, Epoch},
     header::{Header, HeaderDesc, Proof},
     leadership::{Error, ErrorKind, Verification},
+    ledger::Ledger,
     setting::ActiveSlotsCoeff,
     stake::{PercentStake, PoolsState, Stake, StakeDistribution},
 };
@@ -123,6 +124,27 @@ impl LeadershipData {
         }
     }
 
+    /// Dates of the epoch at which the pool is eligible to lead, evaluated
+    /// slot by slot the same way a node decides whether to produce a block.
+    pub fn leader_schedule(
+        &self,
+        pool_id: &PoolId,
+        vrf_key: &SecretKey<RistrettoGroup2HashDh>,
+        slots_per_epoch: u32,
+    ) -> Result<Vec<BlockDate>, Error> {
+        let mut schedule = Vec::new();
+        for slot_id in 0..slots_per_epoch {
+            let date = BlockDate {
+                epoch: self.epoch,
+                slot_id,
+            };
+            if self.leader(pool_id, vrf_key, date)?.is_some() {
+                schedule.push(date);
+            }
+        }
+        Ok(schedule)
+    }
+
     pub(crate) fn verify(&self, block_header: &Header) -> Verification {
         if block_header.block_date().epoch != self.epoch {
             return Verification::Failure(Error::new_(
@@ -220,6 +242,29 @@ impl LeadershipData {
     }
 }
 
+/// Computes the dates of `epoch` at which the pool is eligible to lead,
+/// given the stake distribution and settings of the ledger state and the
+/// nonce of the epoch.
+///
+/// The result is deterministic, so it can be used to predict or check the
+/// leadership logs of a node running with the pool's VRF key.
+pub fn leader_schedule(
+    ledger: &Ledger,
+    epoch: Epoch,
+    epoch_nonce: PraosNonce,
+    pool_id: &PoolId,
+    vrf_key: &SecretKey<RistrettoGroup2HashDh>,
+) -> Result<Vec<BlockDate>, Error> {
+    LeadershipData::new(
+        epoch,
+        ledger.get_stake_distribution(),
+        ledger.delegation().clone(),
+        epoch_nonce,
+        ledger.settings().active_slots_coeff,
+    )
+    .leader_schedule(pool_id, vrf_key, ledger.era().slots_per_epoch())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -345,6 +390,75 @@ mod tests {
         assert!(invalid_leader.unwrap().is_none());
     }
 
+    #[test]
+    pub fn leader_schedule_matches_slot_evaluation() {
+        let leader_election_parameters = LeaderElectionParameters::new();
+
+        let cb = ConfigBuilder::new()
+            .with_slots_per_epoch(leader_election_parameters.slots_per_epoch)
+            .with_active_slots_coeff(leader_election_parameters.active_slots_coeff_as_milli());
+
+        let mut ledger = LedgerBuilder::from_config(cb)
+            .build()
+            .expect("cannot build test ledger")
+            .ledger;
+
+        let pools: Pools = std::iter::from_fn(|| {
+            let (pool_id, pool_vrf_private_key) = make_pool(&mut ledger);
+            Some((
+                pool_id,
+                (pool_vrf_private_key, 0, leader_election_parameters.value),
+            ))
+        })
+        .take(leader_election_parameters.pools_count)
+        .collect();
+
+        let selection = make_leadership_with_pools(&ledger, &pools);
+
+        for (pool_id, (pool_vrf_private_key, _, _)) in pools.iter() {
+            let schedule = selection
+                .leader_schedule(
+                    pool_id,
+                    pool_vrf_private_key,
+                    leader_election_parameters.slots_per_epoch,
+                )
+                .unwrap();
+
+            let expected: Vec<BlockDate> = (0..leader_election_parameters.slots_per_epoch)
+                .map(|slot_id| BlockDate { epoch: 0, slot_id })
+                .filter(|date| {
+                    selection
+                        .leader(pool_id, pool_vrf_private_key, *date)
+                        .unwrap()
+                        .is_some()
+                })
+                .collect();
+
+            assert!(!schedule.is_empty());
+            assert_eq!(schedule, expected);
+        }
+    }
+
+    #[test]
+    pub fn leader_schedule_of_pool_without_stake_is_empty() {
+        let mut ledger = LedgerBuilder::from_config(ConfigBuilder::new())
+            .build()
+            .expect("cannot build test ledger")
+            .ledger;
+        let (pool_id, pool_vrf_private_key) = make_pool(&mut ledger);
+
+        let schedule = leader_schedule(
+            &ledger,
+            0,
+            ledger.settings.consensus_nonce.clone(),
+            &pool_id,
+            &pool_vrf_private_key,
+        )
+        .unwrap();
+
+        assert!(schedule.is_empty());
+    }
+
     #[test]
     pub fn test_leader_election_is_consistent_with_stake_distribution() {
         let leader_election_parameters = LeaderElectionParameters::new();
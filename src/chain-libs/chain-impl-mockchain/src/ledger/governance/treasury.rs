@@ -1,8 +1,9 @@
-use crate::{ledger::governance::GovernanceAcceptanceCriteria, value::Value};
+use crate::{account, ledger::governance::GovernanceAcceptanceCriteria, value::Value};
 use chain_core::{
     packer::Codec,
-    property::{Deserialize, ReadError},
+    property::{Deserialize, DeserializeFromSlice, ReadError},
 };
+use chain_crypto::{AsymmetricPublicKey, Ed25519};
 use imhamt::Hamt;
 use std::collections::hash_map::DefaultHasher;
 use typed_bytes::ByteBuilder;
@@ -10,13 +11,22 @@ use typed_bytes::ByteBuilder;
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub enum TreasuryGovernanceAction {
     NoOp,
-    TransferToRewards { value: Value },
+    TransferToRewards {
+        value: Value,
+    },
+    /// move value from the treasury to the given account, creating the
+    /// account if it does not exist yet
+    TransferToAccount {
+        value: Value,
+        destination: account::Identifier,
+    },
 }
 
 #[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
 pub enum TreasuryGovernanceActionType {
     NoOp,
     TransferToRewards,
+    TransferToAccount,
 }
 
 #[derive(Default, Clone, Eq, PartialEq)]
@@ -32,6 +42,7 @@ impl TreasuryGovernanceAction {
         match self {
             Self::NoOp => TreasuryGovernanceActionType::NoOp,
             Self::TransferToRewards { .. } => TreasuryGovernanceActionType::TransferToRewards,
+            Self::TransferToAccount { .. } => TreasuryGovernanceActionType::TransferToAccount,
         }
     }
 
@@ -39,6 +50,9 @@ impl TreasuryGovernanceAction {
         match self {
             Self::NoOp => bb.u8(0),
             Self::TransferToRewards { value } => bb.u8(1).u64(value.0),
+            Self::TransferToAccount { value, destination } => {
+                bb.u8(2).u64(value.0).bytes(destination.as_ref().as_ref())
+            }
         }
     }
 }
@@ -98,6 +112,13 @@ impl Deserialize for TreasuryGovernanceAction {
                 let value = Value::deserialize(codec)?;
                 Ok(Self::TransferToRewards { value })
             }
+            2 => {
+                let value = Value::deserialize(codec)?;
+                let bytes = codec.get_bytes(Ed25519::PUBLIC_KEY_SIZE)?;
+                let destination =
+                    account::Identifier::deserialize_from_slice(&mut Codec::new(bytes.as_slice()))?;
+                Ok(Self::TransferToAccount { value, destination })
+            }
             t => Err(ReadError::UnknownTag(t as u32)),
         }
     }
@@ -107,16 +128,21 @@ impl Deserialize for TreasuryGovernanceAction {
 mod tests {
 
     use super::{TreasuryGovernance, TreasuryGovernanceAction, TreasuryGovernanceActionType};
-    use crate::{ledger::governance::GovernanceAcceptanceCriteria, value::Value, vote::Choice};
+    use crate::{
+        account, ledger::governance::GovernanceAcceptanceCriteria, value::Value, vote::Choice,
+    };
+    use chain_core::{packer::Codec, property::Deserialize};
     use quickcheck::{Arbitrary, Gen};
     use quickcheck_macros::quickcheck;
+    use typed_bytes::ByteBuilder;
 
     impl Arbitrary for TreasuryGovernanceActionType {
         fn arbitrary<G: Gen>(g: &mut G) -> Self {
-            let option = u8::arbitrary(g) % 2;
+            let option = u8::arbitrary(g) % 3;
             match option {
                 0 => TreasuryGovernanceActionType::NoOp,
                 1 => TreasuryGovernanceActionType::TransferToRewards,
+                2 => TreasuryGovernanceActionType::TransferToAccount,
                 _ => unreachable!(),
             }
         }
@@ -133,6 +159,22 @@ mod tests {
         );
     }
 
+    #[quickcheck]
+    pub fn treasury_governance_transfer_to_account_serialization_bijection(
+        value: Value,
+        destination: account::Identifier,
+    ) -> bool {
+        let action = TreasuryGovernanceAction::TransferToAccount { value, destination };
+        assert_eq!(
+            action.to_type(),
+            TreasuryGovernanceActionType::TransferToAccount
+        );
+
+        let bytes = action.serialize_in(ByteBuilder::new()).finalize_as_vec();
+        let decoded = TreasuryGovernanceAction::deserialize(&mut Codec::new(bytes.as_slice()));
+        decoded.map(|decoded| decoded == action).unwrap_or(false)
+    }
+
     #[test]
     pub fn treasury_governance_set_default_acceptance_criteria() {
         let mut governance = TreasuryGovernance::new();
@@ -1,8 +1,78 @@
 use super::ledger::Ledger;
+use crate::accounting::account::AccountState;
+use crate::certificate::{PoolId, VotePlanId};
+use crate::fragment::FragmentId;
+use crate::stake::PoolState;
+use crate::transaction::Output;
 use crate::value::Value;
+use crate::vote::VotePlanManager;
+use crate::{account, legacy, multisig};
+use chain_addr::Address;
+use std::mem::size_of;
+
+/// Size of the state held by a ledger, see [`Ledger::stats`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct LedgerStats {
+    /// number of unspent transaction outputs
+    pub utxos: usize,
+    /// number of unspent legacy transaction outputs
+    pub old_utxos: usize,
+    pub accounts: usize,
+    pub multisig_accounts: usize,
+    pub multisig_declarations: usize,
+    pub stake_pools: usize,
+    pub vote_plans: usize,
+    /// approximate memory footprint of the entries above, in bytes.
+    ///
+    /// This is the inline size of the keys and values of the entries: the
+    /// trie nodes and the data the values hold on the heap are not counted,
+    /// nor are the entries shared with previous versions of the ledger. It is
+    /// meant to follow the growth of the state, not to measure the memory
+    /// used by the process.
+    pub approximate_size: usize,
+}
+
+fn entries_size<K, V>(count: usize) -> usize {
+    count * (size_of::<K>() + size_of::<V>())
+}
 
 impl Ledger {
-    pub fn stats(&self) -> Vec<String> {
+    /// Counts the entries of the ledger state.
+    ///
+    /// The counts are maintained by the collections of the state as they are
+    /// updated, so this is constant time and can be called on every block.
+    pub fn stats(&self) -> LedgerStats {
+        let utxos = self.utxos.len();
+        let old_utxos = self.oldutxos.len();
+        let accounts = self.accounts.len();
+        let multisig_accounts = self.multisig.accounts_len();
+        let multisig_declarations = self.multisig.declarations_len();
+        let stake_pools = self.delegation.stake_pools.size();
+        let vote_plans = self.votes.plans.size();
+
+        let approximate_size = entries_size::<FragmentId, Output<Address>>(utxos)
+            + entries_size::<FragmentId, Output<legacy::OldAddress>>(old_utxos)
+            + entries_size::<account::Identifier, AccountState<()>>(accounts)
+            + entries_size::<multisig::Identifier, AccountState<()>>(multisig_accounts)
+            + entries_size::<multisig::Identifier, multisig::Declaration>(multisig_declarations)
+            + entries_size::<PoolId, PoolState>(stake_pools)
+            + entries_size::<VotePlanId, VotePlanManager>(vote_plans);
+
+        LedgerStats {
+            utxos,
+            old_utxos,
+            accounts,
+            multisig_accounts,
+            multisig_declarations,
+            stake_pools,
+            vote_plans,
+            approximate_size,
+        }
+    }
+
+    /// Describes the number and total value of the entries of each kind
+    /// of funds held by the ledger.
+    pub fn info(&self) -> Vec<String> {
         let Ledger {
             utxos,
             oldutxos,
@@ -22,6 +92,7 @@ impl Ledger {
             #[cfg(feature = "evm")]
             evm,
             token_totals: _,
+            rewards_report: _,
         } = self;
 
         let stats = vec![
@@ -72,6 +143,7 @@ impl Ledger {
             #[cfg(feature = "evm")]
                 evm: evm1,
             token_totals: token_totals1,
+            rewards_report: rewards_report1,
         } = self;
 
         let Ledger {
@@ -93,6 +165,7 @@ impl Ledger {
             #[cfg(feature = "evm")]
                 evm: evm2,
             token_totals: token_totals2,
+            rewards_report: rewards_report2,
         } = other;
 
         let info = vec![
@@ -114,6 +187,7 @@ impl Ledger {
             #[cfg(feature = "evm")]
             evm1.info_eq(evm2),
             format!("token-totals: {}", token_totals1 == token_totals2),
+            format!("rewards-report: {}", rewards_report1 == rewards_report2),
         ];
 
         info
@@ -1,7 +1,7 @@
 use super::governance::Governance;
 use super::ledger::{Error, Ledger, LedgerStaticParameters};
 use super::pots::{self, Pots};
-use super::LeadersParticipationRecord;
+use super::{LeadersParticipationRecord, RewardsReport};
 use crate::certificate::{VotePlan, VotePlanId};
 use crate::chaintypes::ChainLength;
 use crate::config::ConfigParam;
@@ -50,6 +50,7 @@ pub enum Entry<'a> {
     StakePool((&'a crate::certificate::PoolId, &'a crate::stake::PoolState)),
     LeaderParticipation((&'a crate::certificate::PoolId, &'a u32)),
     VotePlan(&'a VotePlan),
+    RewardsReport(&'a RewardsReport),
 }
 
 #[derive(Clone)]
@@ -81,6 +82,7 @@ pub enum EntryOwned {
     StakePool((crate::certificate::PoolId, crate::stake::PoolState)),
     LeaderParticipation((crate::certificate::PoolId, u32)),
     VotePlan(VotePlan),
+    RewardsReport(RewardsReport),
     StopEntry,
 }
 
@@ -125,6 +127,7 @@ impl EntryOwned {
                 Some(Entry::LeaderParticipation((pool_id, participation)))
             }
             EntryOwned::VotePlan(vote_plan) => Some(Entry::VotePlan(vote_plan)),
+            EntryOwned::RewardsReport(report) => Some(Entry::RewardsReport(report)),
             EntryOwned::StopEntry => None,
         }
     }
@@ -159,6 +162,7 @@ enum IterState<'a> {
     Pots(pots::Entries<'a>),
     LeaderParticipations(imhamt::HamtIter<'a, crate::certificate::PoolId, u32>),
     VotePlan(imhamt::HamtIter<'a, VotePlanId, VotePlanManager>),
+    RewardsReport,
     Done,
 }
 
@@ -255,11 +259,18 @@ impl<'a> Iterator for LedgerIterator<'a> {
             },
             IterState::VotePlan(iter) => match iter.next() {
                 None => {
-                    self.state = IterState::Done;
+                    self.state = IterState::RewardsReport;
                     self.next()
                 }
                 Some((_, plan_manager)) => Some(Entry::VotePlan(plan_manager.plan())),
             },
+            IterState::RewardsReport => {
+                self.state = IterState::Done;
+                match self.ledger.rewards_report() {
+                    None => self.next(),
+                    Some(report) => Some(Entry::RewardsReport(report)),
+                }
+            }
             IterState::Done => None,
         }
     }
@@ -294,6 +305,7 @@ impl<'a> std::iter::FromIterator<Entry<'a>> for Result<Ledger, Error> {
         #[cfg(feature = "evm")]
         let evm = evm::Ledger::new();
         let token_totals = TokenTotals::default();
+        let mut rewards_report = None;
 
         for entry in iter {
             match entry {
@@ -351,6 +363,9 @@ impl<'a> std::iter::FromIterator<Entry<'a>> for Result<Ledger, Error> {
                         )
                         .unwrap();
                 }
+                Entry::RewardsReport(report) => {
+                    rewards_report = Some(Arc::new(report.clone()));
+                }
             }
         }
 
@@ -375,6 +390,7 @@ impl<'a> std::iter::FromIterator<Entry<'a>> for Result<Ledger, Error> {
             #[cfg(feature = "evm")]
             evm,
             token_totals,
+            rewards_report,
         };
         #[cfg(feature = "evm")]
         let ledger = ledger.set_evm_block0().set_evm_environment();
@@ -479,6 +495,12 @@ mod tests {
                 Entry::VotePlan(plan) => {
                     println!("VotePlan {}", plan.to_id());
                 }
+                Entry::RewardsReport(report) => {
+                    println!(
+                        "RewardsReport {} {} {} {}",
+                        report.epoch, report.info.drawn, report.info.fees, report.info.treasury
+                    );
+                }
             }
         }
     }
@@ -7,7 +7,7 @@ use super::evm;
 use super::governance::{Governance, ParametersGovernanceAction, TreasuryGovernanceAction};
 use super::leaderlog::LeadersParticipationRecord;
 use super::pots::Pots;
-use super::reward_info::{EpochRewardsInfo, RewardsInfoParameters};
+use super::reward_info::{EpochRewardsInfo, RewardsInfoParameters, RewardsReport};
 use super::token_distribution::{TokenDistribution, TokenTotals};
 
 use crate::certificate::MintToken;
@@ -29,7 +29,7 @@ use crate::treasury::Treasury;
 use crate::update::UpdateState;
 use crate::value::*;
 use crate::vote::{VotePlanLedger, VotePlanLedgerError, VotePlanStatus};
-use crate::{account, certificate, legacy, multisig, setting, stake, update, utxo};
+use crate::{account, certificate, legacy, multisig, script, setting, stake, update, utxo};
 use crate::{
     certificate::{
         BftLeaderBindingSignature, OwnerStakeDelegation, PoolId, UpdateProposal, UpdateProposalId,
@@ -38,6 +38,7 @@ use crate::{
     chaineval::ConsensusEvalContext,
 };
 use chain_addr::{Address, Discrimination, Kind};
+use chain_core::property::Serialize as _;
 use chain_crypto::Verification;
 #[cfg(feature = "evm")]
 use chain_evm::state::ByteCode;
@@ -84,6 +85,7 @@ pub struct Ledger {
     #[cfg(feature = "evm")]
     pub(crate) evm: evm::Ledger,
     pub(crate) token_totals: TokenTotals,
+    pub(crate) rewards_report: Option<Arc<RewardsReport>>,
 }
 
 #[derive(Debug, Clone)]
@@ -92,6 +94,14 @@ pub struct ApplyBlockLedger {
     block_date: BlockDate,
 }
 
+/// Outcome of a transaction which passed [`Ledger::check_transaction`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FeeAndBalanceReport {
+    pub inputs: Value,
+    pub outputs: Value,
+    pub fee: Value,
+}
+
 // Dummy implementation of Debug for Ledger
 impl std::fmt::Debug for Ledger {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -157,6 +167,10 @@ pub enum Block0Error {
     HasVoteTally,
     #[error("EvmMapping are not valid in the block0")]
     HasEvmMapping,
+    #[error("Multisig declaration updates are not valid in the block0")]
+    HasMultisigDeclarationUpdate,
+    #[error("Invalid reward parameters in the initial fragment")]
+    InvalidRewardParams(#[source] rewards::RewardPolicyError),
 }
 
 pub type OutputOldAddress = Output<legacy::OldAddress>;
@@ -171,12 +185,26 @@ pub enum Error {
     UtxoValueNotMatching { expected: Value, value: Value },
     #[error("Invalid UTxO")]
     UtxoError(#[from] utxo::Error),
+    #[error("The UTxO {utxo:?} is time-locked until {locked_until}")]
+    UtxoTimeLocked {
+        utxo: UtxoPointer,
+        locked_until: BlockDate,
+    },
+    #[error("Time-locked outputs are only supported for UTxO addresses")]
+    TimeLockedOutputNotUtxo,
     #[error("Transaction with invalid signature")]
     UtxoInvalidSignature {
         utxo: UtxoPointer,
         output: OutputAddress,
         witness: Witness,
     },
+    #[error("Transaction with invalid script witness")]
+    ScriptInvalidWitness {
+        utxo: UtxoPointer,
+        output: OutputAddress,
+        #[source]
+        reason: script::ScriptError,
+    },
     #[error("Old Transaction with invalid signature")]
     OldUtxoInvalidSignature {
         utxo: UtxoPointer,
@@ -255,6 +283,8 @@ pub enum Error {
     },
     #[error("Wrong block content size, received {actual} bytes but max is {max} bytes")]
     InvalidContentSize { actual: u32, max: u32 },
+    #[error("Fragment too large, received {actual} bytes but max is {max} bytes")]
+    FragmentTooLarge { actual: usize, max: u32 },
     #[error("Wrong block content hash, received {actual} but expected {expected}")]
     InvalidContentHash {
         actual: BlockContentHash,
@@ -264,6 +294,8 @@ pub enum Error {
     IncompleteLedger,
     #[error("Ledger pot value invalid: {error}")]
     PotValueInvalid { error: ValueError },
+    #[error("Invalid value while distributing the rewards: {error}")]
+    RewardsValueInvalid { error: ValueError },
     #[error("Pool registration with no owner")]
     PoolRegistrationHasNoOwner,
     #[error("Pool registration with too many owners")]
@@ -300,12 +332,14 @@ pub enum Error {
     UpdateNotAllowedYet,
     #[error("Voting error")]
     VotePlan(#[from] VotePlanLedgerError),
-    #[error("Scripts addresses are not yet supported by the system")]
+    #[error("Scripts addresses are not enabled by the ledger settings")]
     ScriptsAddressNotAllowedYet,
     #[error("Protocol update proposal payload signature failed")]
     UpdateProposalSignatureFailed,
     #[error("Protocol update vote payload signature failed")]
     UpdateVoteSignatureFailed,
+    #[error("Multisig declaration update payload signature failed")]
+    MultisigDeclarationUpdateSignatureFailed,
     #[error("minting policy violation")]
     MintingPolicyViolation(#[from] MintingPolicyViolation),
     #[error("evm transactions are disabled, the node was built without the 'evm' feature")]
@@ -344,6 +378,7 @@ impl Ledger {
             #[cfg(feature = "evm")]
             evm: evm::Ledger::new(),
             token_totals: TokenTotals::default(),
+            rewards_report: None,
         };
         #[cfg(not(feature = "evm"))]
         {
@@ -436,6 +471,10 @@ impl Ledger {
                     Block0Error::InitialMessageNoConsensusLeaderId,
                 ));
             }
+            settings
+                .reward_params()
+                .validate()
+                .map_err(|error| Error::Block0(Block0Error::InvalidRewardParams(error)))?;
             Ledger::empty(settings, static_params, era, pots)
         };
 
@@ -528,6 +567,13 @@ impl Ledger {
                         return Err(Error::DisabledEvmTransactions);
                     }
                 }
+                Fragment::Anchor(tx) => {
+                    // anchors do not change the ledger state
+                    check::valid_block0_cert_transaction(&tx.as_slice())?;
+                }
+                Fragment::MultisigDeclarationUpdate(_) => {
+                    return Err(Error::Block0(Block0Error::HasMultisigDeclarationUpdate));
+                }
             }
         }
 
@@ -577,25 +623,31 @@ impl Ledger {
     ///
     /// * Reset the leaders log
     /// * Distribute the contribution (rewards + fees) to pools and their delegatees
+    /// * Keep the distribution details as the ledger rewards report
     pub fn distribute_rewards(
         &self,
         distribution: &StakeDistribution,
         rewards_info_params: RewardsInfoParameters,
     ) -> Result<(Self, EpochRewardsInfo), Error> {
         let mut new_ledger = self.clone();
-        let mut rewards_info = EpochRewardsInfo::new(rewards_info_params);
+        let epoch = new_ledger.date.epoch + 1;
+        // the report kept in the ledger is always complete, the caller only
+        // gets the details it asked for
+        let mut report = RewardsReport::new(epoch);
 
         if self.leaders_log.total() == 0 {
+            let rewards_info = report.info.with_params(rewards_info_params);
+            new_ledger.rewards_report = Some(Arc::new(report));
             return Ok((new_ledger, rewards_info));
         }
 
+        let rewards_info = &mut report.info;
+
         let treasury_initial_value = new_ledger.pots.treasury_value();
 
         // grab the total contribution in the system
         // with all the stake pools and start rewarding them
 
-        let epoch = new_ledger.date.epoch + 1;
-
         let system_info = rewards::SystemInformation {
             declared_stake: distribution.get_total_stake(),
         };
@@ -606,7 +658,7 @@ impl Ledger {
             &system_info,
         );
 
-        let drawn = new_ledger.pots.draw_reward(expected_epoch_reward);
+        let drawn = new_ledger.pots.draw_reward(expected_epoch_reward)?;
 
         // set basic reward info
         {
@@ -621,7 +673,9 @@ impl Ledger {
         // to the treasury directly
         match self.settings.fees_goes_to {
             setting::FeesGoesTo::Rewards => {
-                total_reward = (total_reward + new_ledger.pots.siphon_fees()).unwrap();
+                total_reward = total_reward
+                    .add_with::<Checked>(new_ledger.pots.siphon_fees())
+                    .map_err(|error| Error::RewardsValueInvalid { error })?;
             }
             setting::FeesGoesTo::Treasury => {
                 let fees = new_ledger.pots.siphon_fees();
@@ -631,7 +685,8 @@ impl Ledger {
 
         // Take treasury cut
         total_reward = {
-            let treasury_distr = rewards::tax_cut(total_reward, &self.settings.treasury_params())?;
+            let treasury_distr = rewards::tax_cut(total_reward, &self.settings.treasury_params())
+                .map_err(|error| Error::RewardsValueInvalid { error })?;
             new_ledger.pots.treasury_add(treasury_distr.taxed)?;
             treasury_distr.after_tax
         };
@@ -660,12 +715,17 @@ impl Ledger {
             for (pool_id, pool_blocks) in leaders_log.iter() {
                 // possibly cap the reward for a given pool.
                 // if this is capped, then the overflow amount is send to treasury
-                let pool_total_reward_uncapped = reward_unit.parts.scale(*pool_blocks).unwrap();
+                let pool_total_reward_uncapped = reward_unit
+                    .parts
+                    .scale_with::<Checked>(*pool_blocks)
+                    .map_err(|error| Error::RewardsValueInvalid { error })?;
                 let pool_total_reward = match pool_capper {
                     None => pool_total_reward_uncapped,
                     Some(pool_cap) => {
                         let actual_pool_total = std::cmp::min(pool_cap, pool_total_reward_uncapped);
-                        let forfeited = (pool_total_reward_uncapped - actual_pool_total).unwrap();
+                        let forfeited = pool_total_reward_uncapped
+                            .sub_with::<Checked>(actual_pool_total)
+                            .map_err(|error| Error::RewardsValueInvalid { error })?;
                         new_ledger.pots.treasury_add(forfeited)?;
                         actual_pool_total
                     }
@@ -674,7 +734,7 @@ impl Ledger {
                 match distribution.to_pools.get(pool_id) {
                     Some(pool_distribution) => {
                         new_ledger.distribute_poolid_rewards(
-                            &mut rewards_info,
+                            rewards_info,
                             epoch,
                             pool_id,
                             pool_total_reward,
@@ -694,10 +754,15 @@ impl Ledger {
             }
         }
 
-        let treasury_added_value =
-            (new_ledger.pots.treasury_value() - treasury_initial_value).unwrap();
+        let treasury_added_value = new_ledger
+            .pots
+            .treasury_value()
+            .sub_with::<Checked>(treasury_initial_value)
+            .map_err(|error| Error::RewardsValueInvalid { error })?;
         rewards_info.set_treasury(treasury_added_value);
 
+        let rewards_info = report.info.with_params(rewards_info_params);
+        new_ledger.rewards_report = Some(Arc::new(report));
         Ok((new_ledger, rewards_info))
     }
 
@@ -717,7 +782,8 @@ impl Ledger {
             Some(ref reg) => reg,
         };
 
-        let distr = rewards::tax_cut(total_reward, &reg.rewards).unwrap();
+        let distr = rewards::tax_cut(total_reward, &reg.rewards)
+            .map_err(|error| Error::RewardsValueInvalid { error })?;
 
         reward_info.set_stake_pool(pool_id, distr.taxed, distr.after_tax);
         self.delegation
@@ -733,7 +799,9 @@ impl Ledger {
                         distr.taxed,
                         (),
                     )?;
-                    reward_info.add_to_account(single_account, distr.taxed);
+                    reward_info
+                        .add_to_account(single_account, distr.taxed)
+                        .map_err(|error| Error::RewardsValueInvalid { error })?;
                 }
                 AccountIdentifier::Multi(_multi_account) => unimplemented!(),
             },
@@ -745,7 +813,9 @@ impl Ledger {
                         self.accounts =
                             self.accounts
                                 .add_rewards_to_account(&id, epoch, splitted.parts, ())?;
-                        reward_info.add_to_account(&id, splitted.parts);
+                        reward_info
+                            .add_to_account(&id, splitted.parts)
+                            .map_err(|error| Error::RewardsValueInvalid { error })?;
                     }
                     // pool owners 0 get potentially an extra sweetener of value 1 to #owners - 1
                     if splitted.remaining > Value::zero() {
@@ -756,14 +826,18 @@ impl Ledger {
                             splitted.remaining,
                             (),
                         )?;
-                        reward_info.add_to_account(&id, splitted.remaining);
+                        reward_info
+                            .add_to_account(&id, splitted.remaining)
+                            .map_err(|error| Error::RewardsValueInvalid { error })?;
                     }
                 } else {
                     let id = reg.owners[0].clone().into();
                     self.accounts =
                         self.accounts
                             .add_rewards_to_account(&id, epoch, distr.taxed, ())?;
-                    reward_info.add_to_account(&id, distr.taxed);
+                    reward_info
+                        .add_to_account(&id, distr.taxed)
+                        .map_err(|error| Error::RewardsValueInvalid { error })?;
                 }
             }
         }
@@ -774,11 +848,15 @@ impl Ledger {
             for (account, stake) in distribution.stake.accounts.iter() {
                 let ps = PercentStake::new(*stake, distribution.stake.total);
                 let r = ps.scale_value(distr.after_tax);
-                leftover_reward = (leftover_reward - r).unwrap();
+                leftover_reward = leftover_reward
+                    .sub_with::<Checked>(r)
+                    .map_err(|error| Error::RewardsValueInvalid { error })?;
                 self.accounts = self
                     .accounts
                     .add_rewards_to_account(account, epoch, r, ())?;
-                reward_info.add_to_account(account, r);
+                reward_info
+                    .add_to_account(account, r)
+                    .map_err(|error| Error::RewardsValueInvalid { error })?;
             }
         }
 
@@ -826,6 +904,8 @@ impl Ledger {
         new_ledger.updates = updates;
         new_ledger.settings = settings;
 
+        new_ledger.take_stake_snapshots(block_date);
+
         Ok(ApplyBlockLedger {
             ledger: new_ledger,
             block_date,
@@ -873,6 +953,13 @@ impl Ledger {
     /// of block to the current context.
     ///
     pub fn apply_fragment(&self, content: &Fragment, block_date: BlockDate) -> Result<Self, Error> {
+        if let Some(max) = self.settings.max_fragment_size {
+            let actual = content.serialized_size();
+            if actual > max as usize {
+                return Err(Error::FragmentTooLarge { actual, max });
+            }
+        }
+
         let mut new_ledger = self.clone();
 
         let fragment_id = content.hash();
@@ -1080,6 +1167,21 @@ impl Ledger {
                     return Err(Error::DisabledEvmTransactions);
                 }
             }
+            Fragment::Anchor(tx) => {
+                // only the fees are collected, the anchor itself does not change the
+                // ledger state and is just recorded in the block for external audit
+                let tx = tx.as_slice();
+                (new_ledger, _) = new_ledger.apply_transaction(&fragment_id, &tx, block_date)?;
+            }
+            Fragment::MultisigDeclarationUpdate(tx) => {
+                let tx = tx.as_slice();
+                (new_ledger, _) = new_ledger.apply_transaction(&fragment_id, &tx, block_date)?;
+                new_ledger = new_ledger.apply_multisig_declaration_update(
+                    &tx.payload().into_payload(),
+                    &tx.transaction_binding_auth_data(),
+                    tx.payload_auth().into_payload_auth(),
+                )?;
+            }
         }
 
         Ok(new_ledger)
@@ -1099,12 +1201,35 @@ impl Ledger {
         check::valid_transaction_date(&self.settings, tx.valid_until(), cur_date)?;
         let fee = calculate_fee(tx, &self.settings.linear_fees);
         tx.verify_strictly_balanced(fee)?;
-        self = self.apply_tx_inputs(tx)?;
+        self = self.apply_tx_inputs(tx, cur_date)?;
         self = self.apply_tx_outputs(*fragment_id, tx.outputs())?;
         self = self.apply_tx_fee(fee)?;
         Ok((self, fee))
     }
 
+    /// Performs all the checks of [`apply_transaction`](Self::apply_transaction)
+    /// (witnesses, spending counters, balance and validity) and reports the
+    /// transaction fee and balance, leaving this ledger untouched.
+    pub fn check_transaction<'a, Extra>(
+        &self,
+        fragment_id: &FragmentId,
+        tx: &TransactionSlice<'a, Extra>,
+        cur_date: BlockDate,
+    ) -> Result<FeeAndBalanceReport, Error>
+    where
+        Extra: Payload,
+        LinearFee: FeeAlgorithm,
+    {
+        let (_, fee) = self.clone().apply_transaction(fragment_id, tx, cur_date)?;
+        Ok(FeeAndBalanceReport {
+            inputs: tx.total_input().map_err(BalanceError::InputsTotalFailed)?,
+            outputs: tx
+                .total_output()
+                .map_err(BalanceError::OutputsTotalFailed)?,
+            fee,
+        })
+    }
+
     pub fn apply_update(mut self, update: &UpdateProposal) -> Result<Self, Error> {
         self.settings = self.settings.try_apply(update.changes())?;
         Ok(self)
@@ -1178,9 +1303,21 @@ impl Ledger {
         }
 
         self.votes = self.votes.add_vote_plan(cur_date, vote_plan, committee)?;
+        // a vote plan starting in the block it is created in
+        self.take_stake_snapshots(cur_date);
         Ok(self)
     }
 
+    /// freeze the voting power of the vote plans starting at the given date,
+    /// when the votes are weighted by a stake snapshot
+    fn take_stake_snapshots(&mut self, block_date: BlockDate) {
+        if self.settings.voting_power_snapshot {
+            self.votes = self
+                .votes
+                .take_stake_snapshots(block_date, self.token_distribution());
+        }
+    }
+
     pub fn apply_vote_cast(
         mut self,
         account_id: account::Identifier,
@@ -1233,6 +1370,14 @@ impl Ledger {
                     let value = self.pots.draw_treasury(value);
                     self.pots.rewards_add(value)?;
                 }
+                VoteAction::Treasury {
+                    action: TreasuryGovernanceAction::TransferToAccount { value, destination },
+                } => {
+                    let value = self.pots.draw_treasury(value);
+                    self.accounts = self
+                        .accounts
+                        .add_value_or_account(&destination, value, ())?;
+                }
                 VoteAction::Parameters { action } => {
                     self.governance.parameters.logs_register(action);
                 }
@@ -1376,6 +1521,21 @@ impl Ledger {
         Ok(self)
     }
 
+    pub fn apply_multisig_declaration_update<'a>(
+        mut self,
+        update: &certificate::MultisigDeclarationUpdate,
+        auth_data: &TransactionBindingAuthData<'a>,
+        sig: certificate::MultisigDeclarationUpdateSignature,
+    ) -> Result<Self, Error> {
+        if sig.verify(auth_data) != Verification::Success {
+            return Err(Error::MultisigDeclarationUpdateSignatureFailed);
+        }
+        self.multisig =
+            self.multisig
+                .update_declaration(update.id(), &sig.signers(), update.declaration())?;
+        Ok(self)
+    }
+
     #[cfg(feature = "evm")]
     pub fn apply_map_accounts<'a>(
         mut self,
@@ -1536,12 +1696,13 @@ impl Ledger {
     fn apply_tx_inputs<Extra: Payload>(
         mut self,
         tx: &TransactionSlice<Extra>,
+        cur_date: BlockDate,
     ) -> Result<Self, Error> {
         let sign_data_hash = tx.transaction_sign_data_hash();
         for (input, witness) in tx.inputs_and_witnesses().iter() {
             match input.to_enum() {
                 InputEnum::UtxoInput(utxo) => {
-                    self = self.apply_input_to_utxo(&sign_data_hash, &utxo, &witness)?
+                    self = self.apply_input_to_utxo(&sign_data_hash, &utxo, &witness, cur_date)?
                 }
                 InputEnum::AccountInput(account_id, value) => {
                     match match_identifier_witness(&account_id, &witness)? {
@@ -1602,6 +1763,10 @@ impl Ledger {
                     }
                     new_utxos.push((index as u8, output.clone()));
                 }
+                Kind::Account(_) | Kind::Multisig(_) if output.locked_until.is_some() => {
+                    // the lock is tracked per output, which only exists for UTxOs
+                    return Err(Error::TimeLockedOutputNotUtxo);
+                }
                 Kind::Account(identifier) => {
                     // don't have a way to make a newtype ref from the ref so .clone()
                     let account = identifier.clone().into();
@@ -1611,10 +1776,13 @@ impl Ledger {
                     let identifier = multisig::Identifier::from(*identifier);
                     self.multisig = self.multisig.add_value(&identifier, output.value)?;
                 }
-                Kind::Script(_identifier) => {
-                    // TODO: scripts address kinds are not yet supported
+                Kind::Script(_) if !self.settings.script_addresses => {
                     return Err(Error::ScriptsAddressNotAllowedYet);
                 }
+                Kind::Script(_) => {
+                    // the script is only revealed when spending the output
+                    new_utxos.push((index as u8, output.clone()));
+                }
             }
         }
         if !new_utxos.is_empty() {
@@ -1648,6 +1816,7 @@ impl Ledger {
         sign_data_hash: &TransactionSignDataHash,
         utxo: &UtxoPointer,
         witness: &Witness,
+        cur_date: BlockDate,
     ) -> Result<Self, Error> {
         match witness {
             Witness::Account(_, _) => Err(Error::ExpectingUtxoWitness),
@@ -1692,25 +1861,18 @@ impl Ledger {
                 Ok(self)
             }
             Witness::Utxo(signature) => {
-                let (new_utxos, associated_output) =
-                    self.utxos.remove(&utxo.transaction_id, utxo.output_index)?;
-                self.utxos = new_utxos;
-                if utxo.value != associated_output.value {
-                    return Err(Error::UtxoValueNotMatching {
-                        expected: utxo.value,
-                        value: associated_output.value,
-                    });
-                }
+                let associated_output = self.spend_utxo(utxo, cur_date)?;
 
                 let data_to_verify = WitnessUtxoData::new(
                     &self.static_params.block0_initial_hash,
                     sign_data_hash,
                     WitnessUtxoVersion::Normal,
                 );
-                let verified = signature.verify(
-                    associated_output.address.public_key().unwrap(),
-                    &data_to_verify,
-                );
+                let verified = match associated_output.address.public_key() {
+                    Some(public_key) => signature.verify(public_key, &data_to_verify),
+                    // outputs locked by a script can only be spent with a script witness
+                    None => chain_crypto::Verification::Failed,
+                };
                 if verified == chain_crypto::Verification::Failed {
                     return Err(Error::UtxoInvalidSignature {
                         utxo: *utxo,
@@ -1720,13 +1882,68 @@ impl Ledger {
                 };
                 Ok(self)
             }
+            Witness::Script(_) if !self.settings.script_addresses => {
+                Err(Error::ScriptsAddressNotAllowedYet)
+            }
+            Witness::Script(script_witness) => {
+                let associated_output = self.spend_utxo(utxo, cur_date)?;
+
+                let data_to_verify =
+                    WitnessScriptData::new(&self.static_params.block0_initial_hash, sign_data_hash);
+                let result = match associated_output.address.kind() {
+                    Kind::Script(identifier) => script_witness.verify(identifier, &data_to_verify),
+                    _ => Err(script::ScriptError::IdentifierMismatch),
+                };
+                if let Err(reason) = result {
+                    return Err(Error::ScriptInvalidWitness {
+                        utxo: *utxo,
+                        output: associated_output,
+                        reason,
+                    });
+                }
+                Ok(self)
+            }
+        }
+    }
+
+    /// Removes the spent UTxO, checking that it matches the input and that
+    /// it is not time-locked
+    fn spend_utxo(
+        &mut self,
+        utxo: &UtxoPointer,
+        cur_date: BlockDate,
+    ) -> Result<OutputAddress, Error> {
+        let (new_utxos, associated_output) =
+            self.utxos.remove(&utxo.transaction_id, utxo.output_index)?;
+        self.utxos = new_utxos;
+        if utxo.value != associated_output.value {
+            return Err(Error::UtxoValueNotMatching {
+                expected: utxo.value,
+                value: associated_output.value,
+            });
+        }
+
+        if let Some(locked_until) = associated_output.locked_until {
+            if !associated_output.is_spendable_at(cur_date) {
+                return Err(Error::UtxoTimeLocked {
+                    utxo: *utxo,
+                    locked_until,
+                });
+            }
         }
+        Ok(associated_output)
     }
 
     pub fn remaining_rewards(&self) -> Value {
         self.pots.rewards
     }
 
+    /// Rewards distributed at the last epoch transition, or `None` if no
+    /// distribution happened yet
+    pub fn rewards_report(&self) -> Option<&RewardsReport> {
+        self.rewards_report.as_deref()
+    }
+
     pub fn treasury_value(&self) -> Value {
         self.pots.treasury.value()
     }
@@ -1790,10 +2007,7 @@ fn apply_old_declaration(
     assert!(decl.addrs.len() < 255);
     let mut outputs = Vec::with_capacity(decl.addrs.len());
     for (i, d) in decl.addrs.iter().enumerate() {
-        let output = Output {
-            address: d.0.clone(),
-            value: d.1,
-        };
+        let output = Output::from_address(d.0.clone(), d.1);
         outputs.push((i as u8, output))
     }
     utxos = utxos.add(fragment_id, &outputs)?;
@@ -1823,7 +2037,7 @@ fn match_identifier_witness<'a>(
 ) -> Result<MatchingIdentifierWitness<'a>, Error> {
     match witness {
         Witness::OldUtxo(..) => Err(Error::ExpectingAccountWitness),
-        Witness::Utxo(_) => Err(Error::ExpectingAccountWitness),
+        Witness::Utxo(_) | Witness::Script(_) => Err(Error::ExpectingAccountWitness),
         Witness::Account(nonce, sig) => {
             // refine account to a single account identifier
             let account = account
@@ -2041,7 +2255,7 @@ mod tests {
             (Witness::Utxo(_), Err(_)) => TestResult::passed(),
             (Witness::Account(_, _), Ok(_)) => TestResult::passed(),
             (Witness::Account(_, _), Err(_)) => TestResult::error("unexpected error"),
-            (Witness::Multisig(_, _), _) => TestResult::discard(),
+            (Witness::Multisig(_, _), _) | (Witness::Script(_), _) => TestResult::discard(),
         }
     }
 
@@ -2217,7 +2431,12 @@ mod tests {
             .unwrap();
 
         let inner_ledger: Ledger = test_ledger.into();
-        let result = inner_ledger.apply_input_to_utxo(&sign_data_hash, &utxo_pointer, &witness);
+        let result = inner_ledger.apply_input_to_utxo(
+            &sign_data_hash,
+            &utxo_pointer,
+            &witness,
+            BlockDate::first(),
+        );
         match (witness, result) {
             (Witness::OldUtxo(..), Ok(_)) => TestResult::error("expecting error, but got success"),
             (Witness::OldUtxo(..), Err(_)) => TestResult::passed(),
@@ -2227,7 +2446,7 @@ mod tests {
                 TestResult::error("expecting error, but got success")
             }
             (Witness::Account(_, _), Err(_)) => TestResult::passed(),
-            (Witness::Multisig(_, _), _) => TestResult::discard(),
+            (Witness::Multisig(_, _), _) | (Witness::Script(_), _) => TestResult::discard(),
         }
     }
 
@@ -2252,6 +2471,7 @@ mod tests {
             &sign_data_hash,
             &utxo_pointer,
             &signed_tx.witnesses().iter().next().unwrap(),
+            BlockDate::first(),
         );
         assert!(result.is_ok())
     }
@@ -2276,6 +2496,7 @@ mod tests {
             &sign_data_hash,
             &utxo_pointer,
             &signed_tx.witnesses().iter().next().unwrap(),
+            BlockDate::first(),
         );
         assert!(result.is_err())
     }
@@ -2981,4 +3202,55 @@ mod tests {
             .apply_transaction(test_tx.get_fragment(), BlockDate::first())
             .is_err());
     }
+
+    fn stake_snapshot_taken_at_vote_start(voting_power_snapshot: bool) -> bool {
+        use crate::testing::{
+            scenario::{prepare_scenario, proposal, vote_plan, wallet},
+            VoteTestGen,
+        };
+
+        let (test_ledger, _controller) = prepare_scenario()
+            .with_config(ConfigBuilder::new().with_voting_power_snapshot(voting_power_snapshot))
+            .with_initials(vec![wallet("Alice")
+                .with(1_000)
+                .owns("stake_pool")
+                .committee_member()])
+            .with_vote_plans(vec![vote_plan("fund1")
+                .owner("Alice")
+                .vote_phases(1, 2, 3)
+                .with_proposal(&mut proposal(VoteTestGen::external_proposal_id()))])
+            .build()
+            .unwrap();
+        let ledger = test_ledger.ledger;
+        let vote_start = BlockDate {
+            epoch: 1,
+            slot_id: 0,
+        };
+        assert_eq!(ledger.votes.plans.iter().count(), 1);
+        assert!(ledger
+            .votes
+            .plans
+            .iter()
+            .all(|(_, plan)| plan.needs_stake_snapshot(vote_start)));
+
+        let applied = ledger
+            .begin_block(ledger.chain_length().increase(), vote_start)
+            .unwrap();
+        applied
+            .ledger
+            .votes
+            .plans
+            .iter()
+            .all(|(_, plan)| !plan.needs_stake_snapshot(vote_start))
+    }
+
+    #[test]
+    fn begin_block_takes_stake_snapshot_when_enabled() {
+        assert!(stake_snapshot_taken_at_vote_start(true));
+    }
+
+    #[test]
+    fn begin_block_skips_stake_snapshot_when_disabled() {
+        assert!(!stake_snapshot_taken_at_vote_start(false));
+    }
 }
@@ -10,13 +10,15 @@ pub mod ledger;
 mod pots;
 pub mod recovery;
 mod reward_info;
+pub mod selection;
 pub mod token_distribution;
 
+pub use info::LedgerStats;
 pub use iter::*;
 pub use leaderlog::LeadersParticipationRecord;
 pub use ledger::*;
 pub use pots::Pots;
-pub use reward_info::{EpochRewardsInfo, RewardsInfoParameters};
+pub use reward_info::{EpochRewardsInfo, RewardsInfoParameters, RewardsReport};
 
 #[cfg(test)]
 pub mod tests;
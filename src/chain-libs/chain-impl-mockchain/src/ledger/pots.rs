@@ -1,6 +1,6 @@
 use crate::ledger::Error;
 use crate::treasury::Treasury;
-use crate::value::{Value, ValueError};
+use crate::value::{Checked, Value, ValueError};
 use std::cmp;
 use std::fmt::Debug;
 
@@ -121,11 +121,13 @@ impl Pots {
     }
 
     /// Draw rewards from the pot
-    #[must_use]
-    pub fn draw_reward(&mut self, expected_reward: Value) -> Value {
+    pub fn draw_reward(&mut self, expected_reward: Value) -> Result<Value, Error> {
         let to_draw = cmp::min(self.rewards, expected_reward);
-        self.rewards = (self.rewards - to_draw).unwrap();
-        to_draw
+        self.rewards = self
+            .rewards
+            .sub_with::<Checked>(to_draw)
+            .map_err(|error| Error::PotValueInvalid { error })?;
+        Ok(to_draw)
     }
 
     /// Draw rewards from the pot
@@ -245,7 +247,7 @@ mod tests {
         }
 
         let before_reward = pots.rewards;
-        let to_draw = pots.draw_reward(expected_reward);
+        let to_draw = pots.draw_reward(expected_reward).unwrap();
         let draw_reward = cmp::min(before_reward, expected_reward);
         if to_draw != draw_reward {
             TestResult::error(format!(
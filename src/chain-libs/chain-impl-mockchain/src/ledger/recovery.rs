@@ -49,7 +49,7 @@ use crate::date::BlockDate;
 use crate::fragment::FragmentId;
 use crate::header::{ChainLength, HeaderId};
 use crate::key::serialize_public_key;
-use crate::ledger::{Globals, Ledger, LedgerStaticParameters};
+use crate::ledger::{Globals, Ledger, LedgerStaticParameters, RewardsReport};
 use crate::legacy;
 use crate::multisig::{DeclElement, Declaration};
 use crate::stake::{PoolLastRewards, PoolState};
@@ -686,6 +686,52 @@ fn unpack_pool_last_rewards(codec: &mut Codec<&[u8]>) -> Result<PoolLastRewards,
     })
 }
 
+fn pack_rewards_report<W: std::io::Write>(
+    report: &RewardsReport,
+    codec: &mut Codec<W>,
+) -> Result<(), WriteError> {
+    codec.put_be_u32(report.epoch)?;
+    codec.put_be_u64(report.info.drawn.0)?;
+    codec.put_be_u64(report.info.fees.0)?;
+    codec.put_be_u64(report.info.treasury.0)?;
+    codec.put_be_u64(report.info.stake_pools.len() as u64)?;
+    for (pool_id, (owned, distributed)) in report.info.stake_pools.iter() {
+        pack_digestof(pool_id, codec)?;
+        codec.put_be_u64(owned.0)?;
+        codec.put_be_u64(distributed.0)?;
+    }
+    codec.put_be_u64(report.info.accounts.len() as u64)?;
+    for (identifier, value) in report.info.accounts.iter() {
+        pack_account_identifier(identifier, codec)?;
+        codec.put_be_u64(value.0)?;
+    }
+    Ok(())
+}
+
+fn unpack_rewards_report(codec: &mut Codec<&[u8]>) -> Result<RewardsReport, ReadError> {
+    let mut report = RewardsReport::new(codec.get_be_u32()?);
+    report.info.drawn = Value(codec.get_be_u64()?);
+    report.info.fees = Value(codec.get_be_u64()?);
+    report.info.treasury = Value(codec.get_be_u64()?);
+    let stake_pools_count = codec.get_be_u64()?;
+    for _ in 0..stake_pools_count {
+        let pool_id = unpack_digestof(codec)?;
+        let owned = Value(codec.get_be_u64()?);
+        let distributed = Value(codec.get_be_u64()?);
+        report
+            .info
+            .stake_pools
+            .insert(pool_id, (owned, distributed));
+    }
+    let accounts_count = codec.get_be_u64()?;
+    for _ in 0..accounts_count {
+        let identifier = unpack_account_identifier(codec)?;
+        let value = Value(codec.get_be_u64()?);
+        report.info.accounts.insert(identifier, value);
+    }
+    Ok(report)
+}
+
 fn pack_pool_state<W: std::io::Write>(
     pool_state: &PoolState,
     codec: &mut Codec<W>,
@@ -808,6 +854,14 @@ where
 {
     address_packer(&output.address, codec)?;
     codec.put_be_u64(output.value.0)?;
+    match output.locked_until {
+        None => codec.put_u8(0)?,
+        Some(locked_until) => {
+            codec.put_u8(1)?;
+            codec.put_be_u32(locked_until.epoch)?;
+            codec.put_be_u32(locked_until.slot_id)?;
+        }
+    }
     Ok(())
 }
 
@@ -820,7 +874,25 @@ where
 {
     let address = address_unpacker(codec)?;
     let value = Value(codec.get_be_u64()?);
-    Ok(Output { address, value })
+    let locked_until = match codec.get_u8()? {
+        0 => None,
+        1 => {
+            let epoch = codec.get_be_u32()?;
+            let slot_id = codec.get_be_u32()?;
+            Some(BlockDate { epoch, slot_id })
+        }
+        code => {
+            return Err(ReadError::InvalidData(format!(
+                "Invalid output time lock tag {}",
+                code
+            )))
+        }
+    };
+    Ok(Output {
+        address,
+        value,
+        locked_until,
+    })
 }
 
 fn pack_old_addr<W: std::io::Write>(
@@ -1012,6 +1084,7 @@ enum EntrySerializeCode {
     StakePool = 9,
     LeaderParticipation = 10,
     VotePlan = 11,
+    RewardsReport = 12,
     SerializationEnd = 99,
 }
 
@@ -1030,6 +1103,7 @@ impl EntrySerializeCode {
             9 => Some(EntrySerializeCode::StakePool),
             10 => Some(EntrySerializeCode::LeaderParticipation),
             11 => Some(EntrySerializeCode::VotePlan),
+            12 => Some(EntrySerializeCode::RewardsReport),
             99 => Some(EntrySerializeCode::SerializationEnd),
             _ => None,
         }
@@ -1095,6 +1169,10 @@ fn pack_entry<W: std::io::Write>(
             codec.put_u8(EntrySerializeCode::VotePlan as u8)?;
             pack_vote_plan(vote_plan, codec)?;
         }
+        Entry::RewardsReport(report) => {
+            codec.put_u8(EntrySerializeCode::RewardsReport as u8)?;
+            pack_rewards_report(report, codec)?;
+        }
     }
     Ok(())
 }
@@ -1153,6 +1231,9 @@ fn unpack_entry_owned(codec: &mut Codec<&[u8]>) -> Result<EntryOwned, ReadError>
             let vote_plan = unpack_vote_plan(codec)?;
             Ok(EntryOwned::VotePlan(vote_plan))
         }
+        EntrySerializeCode::RewardsReport => {
+            Ok(EntryOwned::RewardsReport(unpack_rewards_report(codec)?))
+        }
         EntrySerializeCode::SerializationEnd => Ok(EntryOwned::StopEntry),
     }
 }
@@ -1326,6 +1407,30 @@ pub mod test {
         assert_eq!(last_rewards, deserialize_last_rewards);
     }
 
+    #[test]
+    pub fn rewards_report_pack_unpack_bijection() {
+        let pool_id = StakePoolBuilder::new().build().id();
+        let account = crate::account::Identifier::from(
+            chain_crypto::SecretKey::<chain_crypto::Ed25519>::generate(rand_core::OsRng)
+                .to_public(),
+        );
+        let mut report = RewardsReport::new(4);
+        report.info.drawn = Value(100);
+        report.info.fees = Value(10);
+        report.info.treasury = Value(11);
+        report.info.set_stake_pool(&pool_id, Value(20), Value(79));
+        report.info.add_to_account(&account, Value(79)).unwrap();
+
+        let vec = Vec::new();
+        let mut codec = Codec::new(vec);
+        pack_rewards_report(&report, &mut codec).unwrap();
+
+        let inner = codec.into_inner();
+        let mut codec = Codec::new(inner.as_slice());
+        let other_report = unpack_rewards_report(&mut codec).unwrap();
+        assert_eq!(report, other_report);
+    }
+
     #[test]
     pub fn pots_entry_pack_unpack_bijection() {
         for entry_value in [
@@ -1402,19 +1507,26 @@ pub mod test {
 
     #[test]
     pub fn output_pack_unpack_bijection() {
-        let output: Output<()> = Output {
-            address: (),
-            value: Value(1000),
-        };
-
-        let vec = Vec::new();
-        let mut codec = Codec::new(vec);
-        pack_output(&output, &mut |_, _| Ok(()), &mut codec).unwrap();
+        for output in [
+            Output::from_address((), Value(1000)),
+            Output::time_locked(
+                (),
+                Value(1000),
+                BlockDate {
+                    epoch: 2,
+                    slot_id: 10,
+                },
+            ),
+        ] {
+            let vec = Vec::new();
+            let mut codec = Codec::new(vec);
+            pack_output(&output, &mut |_, _| Ok(()), &mut codec).unwrap();
 
-        let inner = codec.into_inner();
-        let mut codec = Codec::new(inner.as_slice());
-        let other_output = unpack_output(&mut |_| Ok(()), &mut codec).unwrap();
-        assert_eq!(output, other_output);
+            let inner = codec.into_inner();
+            let mut codec = Codec::new(inner.as_slice());
+            let other_output = unpack_output(&mut |_| Ok(()), &mut codec).unwrap();
+            assert_eq!(output, other_output);
+        }
     }
 
     #[test]
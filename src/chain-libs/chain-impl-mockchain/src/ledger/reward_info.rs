@@ -1,6 +1,7 @@
 use crate::account;
 use crate::certificate::PoolId;
-use crate::value::Value;
+use crate::date::Epoch;
+use crate::value::{Checked, Value, ValueError};
 use std::collections::BTreeMap;
 use std::default::Default;
 
@@ -8,7 +9,7 @@ use std::default::Default;
 ///
 /// By default, only the really cheap basic information will be added,
 /// but for specific it's possible to get extra information that cost.
-#[derive(Default, Debug, Clone, Copy)]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
 pub struct RewardsInfoParameters {
     report_stake_pools: bool,
     report_accounts: bool,
@@ -29,7 +30,7 @@ impl RewardsInfoParameters {
 /// only filled up if the reward info parameters
 /// report_stake_pools and report_accounts (respectively)
 /// are turned on.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct EpochRewardsInfo {
     /// Params used
     pub params: RewardsInfoParameters,
@@ -69,14 +70,59 @@ impl EpochRewardsInfo {
         }
     }
 
-    pub fn add_to_account(&mut self, account: &account::Identifier, value: Value) {
+    pub fn add_to_account(
+        &mut self,
+        account: &account::Identifier,
+        value: Value,
+    ) -> Result<(), ValueError> {
         if self.params.report_accounts {
             let ent = self.accounts.entry(account.clone()).or_default();
-            *ent = (*ent + value).unwrap()
+            *ent = ent.add_with::<Checked>(value)?;
         }
+        Ok(())
     }
 
-    pub fn total(&self) -> Value {
-        (self.drawn + self.fees).unwrap()
+    pub fn total(&self) -> Result<Value, ValueError> {
+        self.drawn.add_with::<Checked>(self.fees)
+    }
+
+    /// Copy of this information, keeping only the details requested by `params`
+    pub(crate) fn with_params(&self, params: RewardsInfoParameters) -> Self {
+        EpochRewardsInfo {
+            params,
+            drawn: self.drawn,
+            fees: self.fees,
+            treasury: self.treasury,
+            stake_pools: if params.report_stake_pools {
+                self.stake_pools.clone()
+            } else {
+                BTreeMap::new()
+            },
+            accounts: if params.report_accounts {
+                self.accounts.clone()
+            } else {
+                BTreeMap::new()
+            },
+        }
+    }
+}
+
+/// Rewards distributed at the last epoch transition, kept in the ledger state
+/// so the reward history can be queried without recomputing the distribution.
+///
+/// The stake pools and accounts details are always reported.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RewardsReport {
+    /// Epoch started by the distribution
+    pub epoch: Epoch,
+    pub info: EpochRewardsInfo,
+}
+
+impl RewardsReport {
+    pub fn new(epoch: Epoch) -> Self {
+        RewardsReport {
+            epoch,
+            info: EpochRewardsInfo::new(RewardsInfoParameters::report_all()),
+        }
     }
 }
@@ -0,0 +1,148 @@
+//! Selection of the fragments to include in a block.
+//!
+//! The candidates are applied one by one on the ledger, so that fragments
+//! spending the same inputs or using the same spending counter are never
+//! selected together: once one of them is applied, the others fail.
+
+use super::{Error, Ledger};
+use crate::{
+    date::BlockDate,
+    fee::{FeeAlgorithm, LinearFee},
+    fragment::Fragment,
+    value::Value,
+};
+use chain_core::property::Serialize as _;
+
+/// Selects the fragments to include in a block from a set of candidates,
+/// favouring those paying the highest fees.
+///
+/// The candidates are tried in decreasing order of fee, those with the
+/// same fee being kept in the order given. Candidates failing to apply are
+/// tried again once the other ones have been applied, until no more can be
+/// added, so that a fragment depending on another one, like a transaction
+/// using the next spending counter of an account, is selected no matter
+/// the order of the candidates. The resulting selection is maximal: none
+/// of the rejected candidates can be applied on the resulting ledger.
+pub struct FragmentSelector {
+    block_date: BlockDate,
+    max_size: Option<u32>,
+}
+
+/// The outcome of [`FragmentSelector::select`].
+pub struct FragmentSelection {
+    /// The ledger with the selected fragments applied.
+    pub ledger: Ledger,
+    /// The selected fragments, in the order they were applied.
+    pub selected: Vec<Fragment>,
+    /// The candidates which do not fit in the space left in the block.
+    pub left_over: Vec<Fragment>,
+    /// The candidates which cannot be applied, along with the error
+    /// of their last attempt.
+    pub rejected: Vec<(Fragment, Error)>,
+}
+
+impl FragmentSelector {
+    /// Creates a selector for a block of the given date. The size of the
+    /// selection is limited to the block content maximum size of the
+    /// ledger settings.
+    pub fn new(block_date: BlockDate) -> Self {
+        FragmentSelector {
+            block_date,
+            max_size: None,
+        }
+    }
+
+    /// Limits the total serialized size of the selected fragments below
+    /// the block content maximum size, e.g. to leave space for other
+    /// fragments.
+    pub fn with_max_size(mut self, max_size: u32) -> Self {
+        self.max_size = Some(max_size);
+        self
+    }
+
+    pub fn select<I>(&self, ledger: &Ledger, candidates: I) -> FragmentSelection
+    where
+        I: IntoIterator<Item = Fragment>,
+    {
+        let fees = &ledger.settings().linear_fees;
+        let mut pending: Vec<_> = candidates
+            .into_iter()
+            .map(|fragment| (fragment_fee(fees, &fragment), fragment))
+            .collect();
+        // stable sort keeps the given order for equal fees
+        pending.sort_by(|(fee_a, _), (fee_b, _)| fee_b.cmp(fee_a));
+
+        let block_content_max_size = ledger.settings().block_content_max_size;
+        let mut space_left = self.max_size.map_or(block_content_max_size, |max| {
+            max.min(block_content_max_size)
+        }) as usize;
+        let mut ledger = ledger.clone();
+        let mut selected = Vec::new();
+        let mut left_over = Vec::new();
+        let mut rejected = Vec::new();
+
+        loop {
+            let mut failed = Vec::new();
+            let mut progress = false;
+            for (fee, fragment) in pending {
+                let size = fragment.serialized_size();
+                // the space left only decreases, so it will not fit later
+                if size > space_left {
+                    left_over.push(fragment);
+                    continue;
+                }
+                match ledger.apply_fragment(&fragment, self.block_date) {
+                    Ok(new_ledger) => {
+                        ledger = new_ledger;
+                        space_left -= size;
+                        selected.push(fragment);
+                        progress = true;
+                    }
+                    Err(error) => failed.push((fee, fragment, error)),
+                }
+            }
+
+            if !progress || failed.is_empty() {
+                rejected.extend(
+                    failed
+                        .into_iter()
+                        .map(|(_, fragment, error)| (fragment, error)),
+                );
+                break;
+            }
+            pending = failed
+                .into_iter()
+                .map(|(fee, fragment, _)| (fee, fragment))
+                .collect();
+        }
+
+        FragmentSelection {
+            ledger,
+            selected,
+            left_over,
+            rejected,
+        }
+    }
+}
+
+/// The fee paid by the fragment according to the fee settings.
+pub fn fragment_fee(fees: &LinearFee, fragment: &Fragment) -> Value {
+    match fragment {
+        Fragment::Initial(_) | Fragment::OldUtxoDeclaration(_) | Fragment::Evm(_) => Value::zero(),
+        Fragment::Transaction(tx) => fees.calculate_tx(&tx.as_slice()),
+        Fragment::OwnerStakeDelegation(tx) => fees.calculate_tx(&tx.as_slice()),
+        Fragment::StakeDelegation(tx) => fees.calculate_tx(&tx.as_slice()),
+        Fragment::PoolRegistration(tx) => fees.calculate_tx(&tx.as_slice()),
+        Fragment::PoolRetirement(tx) => fees.calculate_tx(&tx.as_slice()),
+        Fragment::PoolUpdate(tx) => fees.calculate_tx(&tx.as_slice()),
+        Fragment::UpdateProposal(tx) => fees.calculate_tx(&tx.as_slice()),
+        Fragment::UpdateVote(tx) => fees.calculate_tx(&tx.as_slice()),
+        Fragment::VotePlan(tx) => fees.calculate_tx(&tx.as_slice()),
+        Fragment::VoteCast(tx) => fees.calculate_tx(&tx.as_slice()),
+        Fragment::VoteTally(tx) => fees.calculate_tx(&tx.as_slice()),
+        Fragment::MintToken(tx) => fees.calculate_tx(&tx.as_slice()),
+        Fragment::EvmMapping(tx) => fees.calculate_tx(&tx.as_slice()),
+        Fragment::Anchor(tx) => fees.calculate_tx(&tx.as_slice()),
+        Fragment::MultisigDeclarationUpdate(tx) => fees.calculate_tx(&tx.as_slice()),
+    }
+}
@@ -11,6 +11,7 @@ use crate::{
     },
     value::{Value, ValueError::NegativeAmount},
 };
+use chain_core::property::Serialize as _;
 
 #[test]
 pub fn apply_block_increases_leaders_log() {
@@ -202,3 +203,42 @@ pub fn apply_block_above_max_content_size() {
         ledger.apply_block(block)
     );
 }
+
+#[test]
+pub fn apply_block_with_fragment_above_max_fragment_size() {
+    let max_fragment_size = 100;
+    let (mut ledger, controller) = prepare_scenario()
+        .with_config(ConfigBuilder::new().with_max_fragment_size(max_fragment_size))
+        .with_initials(vec![
+            wallet("Alice").with(1_000).owns("stake_pool"),
+            wallet("Bob").with(1_000),
+        ])
+        .build()
+        .unwrap();
+    let alice = controller.wallet("Alice").unwrap();
+    let bob = controller.wallet("Bob").unwrap();
+    let stake_pool = controller.stake_pool("stake_pool").unwrap();
+    let date = BlockDate {
+        epoch: 1,
+        slot_id: 0,
+    };
+
+    let fragment_factory = FragmentFactory::from_ledger(&ledger);
+    let fragment = fragment_factory.transaction(&alice, &bob, &mut ledger, 10);
+    let fragment_size = fragment.serialized_size();
+
+    let block = GenesisPraosBlockBuilder::new()
+        .with_date(date)
+        .with_fragment(fragment)
+        .with_chain_length(ChainLength(0))
+        .with_parent_id(ledger.block0_hash)
+        .build(&stake_pool, ledger.era());
+
+    assert_err!(
+        LedgerError::FragmentTooLarge {
+            actual: fragment_size,
+            max: max_fragment_size
+        },
+        ledger.apply_block(block)
+    );
+}
@@ -0,0 +1,72 @@
+#![cfg(test)]
+
+use crate::{
+    certificate::{Anchor, Certificate},
+    fee::LinearFee,
+    ledger::ledger::Error,
+    testing::{
+        builders::TestTxCertBuilder, data::Wallet, verifiers::LedgerStateVerifier, ConfigBuilder,
+        LedgerBuilder,
+    },
+    transaction::BalanceError::NotBalanced,
+    value::*,
+};
+
+fn anchor() -> Certificate {
+    Anchor::new([1; 32], Some(b"vit-ss".to_vec()))
+        .unwrap()
+        .into()
+}
+
+#[test]
+pub fn anchor_only_collects_fees() {
+    let alice = Wallet::from_value(Value(100));
+    let mut test_ledger =
+        LedgerBuilder::from_config(ConfigBuilder::new().with_fee(LinearFee::new(1, 2, 3)))
+            .faucets_wallets(vec![&alice])
+            .build()
+            .unwrap();
+
+    let fragment = TestTxCertBuilder::new(test_ledger.block0_hash, test_ledger.fee())
+        .make_transaction(
+            test_ledger.date(),
+            &[alice.clone()],
+            &anchor(),
+            Default::default(),
+        );
+
+    test_ledger
+        .apply_fragment(&fragment, test_ledger.date())
+        .expect("anchor should be accepted");
+
+    // 1 constant + 2 per input + 3 per certificate
+    let ledger_verifier = LedgerStateVerifier::new(test_ledger.into());
+    ledger_verifier
+        .account(alice.as_account_data())
+        .has_value(&Value(94));
+    ledger_verifier.pots().has_fee_equals_to(&Value(6));
+}
+
+#[test]
+pub fn anchor_not_enough_fee() {
+    let alice = Wallet::from_value(Value(100));
+    let mut test_ledger =
+        LedgerBuilder::from_config(ConfigBuilder::new().with_fee(LinearFee::new(1, 2, 3)))
+            .faucets_wallets(vec![&alice])
+            .build()
+            .unwrap();
+
+    let fragment = TestTxCertBuilder::new(test_ledger.block0_hash, LinearFee::new(0, 0, 0))
+        .make_transaction(test_ledger.date(), &[alice], &anchor(), Default::default());
+
+    assert_eq!(
+        test_ledger
+            .apply_fragment(&fragment, test_ledger.date())
+            .err()
+            .unwrap(),
+        Error::TransactionBalanceInvalid(NotBalanced {
+            inputs: Value(0),
+            outputs: Value(6)
+        })
+    );
+}
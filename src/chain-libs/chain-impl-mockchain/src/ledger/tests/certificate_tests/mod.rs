@@ -1,3 +1,5 @@
+pub mod anchor;
+pub mod multisig;
 pub mod pool_registration;
 pub mod pool_update;
 pub mod tokens;
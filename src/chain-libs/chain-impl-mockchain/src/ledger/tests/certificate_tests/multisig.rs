@@ -0,0 +1,145 @@
+#![cfg(test)]
+
+use crate::{
+    certificate::{Certificate, MultisigDeclarationUpdate},
+    fee::LinearFee,
+    fragment::Fragment,
+    ledger::ledger::Error,
+    multisig::{DeclElement, Declaration, Identifier, LedgerError},
+    testing::{
+        builders::TestTxCertBuilder, data::Wallet, verifiers::LedgerStateVerifier, ConfigBuilder,
+        LedgerBuilder, TestLedger,
+    },
+    value::*,
+};
+
+fn declaration(threshold: u8, owners: &[&Wallet]) -> Declaration {
+    let owners = owners
+        .iter()
+        .map(|owner| DeclElement::from_publickey(&owner.public_key()))
+        .collect();
+    Declaration::new(threshold, owners).unwrap()
+}
+
+fn ledger_with_multisig(funder: &Wallet, declaration: &Declaration) -> (TestLedger, Identifier) {
+    let mut test_ledger =
+        LedgerBuilder::from_config(ConfigBuilder::new().with_fee(LinearFee::new(1, 2, 3)))
+            .faucets_wallets(vec![funder])
+            .build()
+            .unwrap();
+    test_ledger.ledger.multisig = test_ledger
+        .ledger
+        .multisig
+        .add_account(declaration)
+        .unwrap();
+    (test_ledger, declaration.to_identifier())
+}
+
+fn update_fragment(
+    test_ledger: &TestLedger,
+    funder: &Wallet,
+    id: Identifier,
+    new_declaration: Declaration,
+    signers: &[&Wallet],
+) -> Fragment {
+    let certificate: Certificate = MultisigDeclarationUpdate::new(id, new_declaration)
+        .unwrap()
+        .into();
+    TestTxCertBuilder::new(test_ledger.block0_hash, test_ledger.fee())
+        .make_transaction_different_signers(
+            test_ledger.date(),
+            funder,
+            signers.iter().copied(),
+            &certificate,
+            Default::default(),
+        )
+}
+
+#[test]
+pub fn multisig_owners_rotation() {
+    let mut funder = Wallet::from_value(Value(100));
+    let [alice, bob, clarice, david, eve] =
+        ["alice", "bob", "clarice", "david", "eve"].map(|alias| Wallet::new(alias, Value(0)));
+
+    let initial = declaration(2, &[&alice, &bob, &clarice]);
+    let (mut test_ledger, id) = ledger_with_multisig(&funder, &initial);
+
+    let rotated = declaration(2, &[&david, &eve]);
+    let fragment = update_fragment(
+        &test_ledger,
+        &funder,
+        id.clone(),
+        rotated.clone(),
+        &[&alice, &bob],
+    );
+    test_ledger
+        .apply_fragment(&fragment, test_ledger.date())
+        .expect("update signed by the threshold of the current owners should be accepted");
+    funder.confirm_transaction();
+
+    assert_eq!(test_ledger.ledger.multisig.declaration(&id), Some(&rotated));
+    LedgerStateVerifier::new(test_ledger.clone().into())
+        .pots()
+        .has_fee_equals_to(&Value(6));
+
+    // the previous owners cannot update the account anymore
+    let fragment = update_fragment(&test_ledger, &funder, id, initial, &[&alice, &bob]);
+    assert_eq!(
+        test_ledger
+            .apply_fragment(&fragment, test_ledger.date())
+            .err()
+            .unwrap(),
+        Error::Multisig(LedgerError::IdentifierMismatch)
+    );
+}
+
+#[test]
+pub fn multisig_update_below_threshold() {
+    let funder = Wallet::from_value(Value(100));
+    let [alice, bob, clarice] =
+        ["alice", "bob", "clarice"].map(|alias| Wallet::new(alias, Value(0)));
+
+    let initial = declaration(2, &[&alice, &bob, &clarice]);
+    let (mut test_ledger, id) = ledger_with_multisig(&funder, &initial);
+
+    let fragment = update_fragment(
+        &test_ledger,
+        &funder,
+        id.clone(),
+        declaration(1, &[&alice, &bob]),
+        &[&alice],
+    );
+    assert_eq!(
+        test_ledger
+            .apply_fragment(&fragment, test_ledger.date())
+            .err()
+            .unwrap(),
+        Error::Multisig(LedgerError::ThresholdNotMet)
+    );
+    assert_eq!(test_ledger.ledger.multisig.declaration(&id), Some(&initial));
+}
+
+#[test]
+pub fn multisig_update_signed_by_non_owner() {
+    let funder = Wallet::from_value(Value(100));
+    let [alice, bob, clarice, mallory] =
+        ["alice", "bob", "clarice", "mallory"].map(|alias| Wallet::new(alias, Value(0)));
+
+    let initial = declaration(2, &[&alice, &bob, &clarice]);
+    let (mut test_ledger, id) = ledger_with_multisig(&funder, &initial);
+
+    let fragment = update_fragment(
+        &test_ledger,
+        &funder,
+        id,
+        declaration(2, &[&mallory, &bob]),
+        &[&mallory, &bob],
+    );
+    assert_eq!(
+        test_ledger
+            .apply_fragment(&fragment, test_ledger.date())
+            .err()
+            .unwrap(),
+        Error::Multisig(LedgerError::IdentifierMismatch)
+    );
+}
@@ -2,7 +2,7 @@
 
 use crate::{
     chaintypes::ConsensusType,
-    config::ConfigParam,
+    config::{ConfigParam, RewardParams},
     date::BlockDate,
     fragment::{config::ConfigParams, Fragment},
     ledger::{
@@ -13,6 +13,7 @@ use crate::{
         Ledger,
     },
     milli::Milli,
+    rewards::{Ratio, RewardPolicyError},
     testing::{
         arbitrary::{AccountStatesVerifier, ArbitraryValidTransactionData, UtxoVerifier},
         builders::{OldAddressBuilder, TestTxBuilder},
@@ -20,11 +21,13 @@ use crate::{
         ledger::{ConfigBuilder, LedgerBuilder},
         TestGen,
     },
+    value::Value,
 };
 
 use chain_addr::Discrimination;
 use quickcheck::TestResult;
 use quickcheck_macros::quickcheck;
+use std::num::{NonZeroU32, NonZeroU64};
 
 #[quickcheck]
 pub fn ledger_accepts_correct_transaction(
@@ -366,6 +369,40 @@ pub fn ledger_new_no_bft_leader() {
     );
 }
 
+#[test]
+pub fn ledger_new_invalid_reward_params() {
+    let leader_pair = TestGen::leader_pair();
+    let header_id = TestGen::hash();
+    let mut ie = ConfigParams::new();
+    ie.push(ConfigParam::Discrimination(Discrimination::Test));
+    ie.push(ConfigParam::AddBftLeader(leader_pair.id()));
+    ie.push(ConfigParam::Block0Date(crate::config::Block0Date(0)));
+    ie.push(ConfigParam::SlotDuration(10u8));
+    ie.push(ConfigParam::SlotsPerEpoch(10u32));
+    ie.push(ConfigParam::KesUpdateSpeed(3600));
+    ie.push(ConfigParam::RewardParams(RewardParams::Halving {
+        constant: 100,
+        ratio: Ratio {
+            numerator: 3,
+            denominator: NonZeroU64::new(2).unwrap(),
+        },
+        epoch_start: 0,
+        epoch_rate: NonZeroU32::new(1).unwrap(),
+    }));
+
+    assert_eq!(
+        Ledger::new(header_id, vec![&Fragment::Initial(ie)])
+            .err()
+            .unwrap(),
+        Block0(Block0Error::InvalidRewardParams(
+            RewardPolicyError::HalvingRatioAboveOne {
+                numerator: 3,
+                denominator: NonZeroU64::new(2).unwrap(),
+            }
+        ))
+    );
+}
+
 #[quickcheck]
 pub fn wrong_fragment_at_block0(fragment: Fragment) -> TestResult {
     match fragment {
@@ -375,6 +412,7 @@ pub fn wrong_fragment_at_block0(fragment: Fragment) -> TestResult {
         Fragment::PoolRegistration(_) => return TestResult::discard(),
         Fragment::VotePlan(_) => return TestResult::discard(),
         Fragment::Evm(_) => return TestResult::discard(),
+        Fragment::Anchor(_) => return TestResult::discard(),
         _ => (),
     };
 
@@ -390,3 +428,29 @@ pub fn wrong_fragment_at_block0(fragment: Fragment) -> TestResult {
 
     TestResult::from_bool(Ledger::new(header_id, vec![&Fragment::Initial(ie), &fragment]).is_err())
 }
+
+#[test]
+pub fn ledger_stats_count_state_entries() {
+    let funds = vec![
+        AddressDataValue::utxo(Discrimination::Test, Value(100)),
+        AddressDataValue::utxo(Discrimination::Test, Value(200)),
+        AddressDataValue::account(Discrimination::Test, Value(300)),
+    ];
+    let small = LedgerBuilder::from_config(ConfigBuilder::new())
+        .initial_fund(&funds[2])
+        .build()
+        .unwrap();
+    let large = LedgerBuilder::from_config(ConfigBuilder::new())
+        .initial_funds(&funds)
+        .build()
+        .unwrap();
+
+    let stats = large.ledger.stats();
+    assert_eq!(stats.utxos, 2);
+    assert_eq!(stats.old_utxos, 0);
+    assert_eq!(stats.accounts, 1);
+    assert_eq!(stats.multisig_accounts, 0);
+    assert_eq!(stats.multisig_declarations, 0);
+    assert_eq!(stats.vote_plans, 0);
+    assert!(stats.approximate_size > small.ledger.stats().approximate_size);
+}
@@ -2,20 +2,25 @@
 
 use crate::{
     accounting::account::{LedgerError::NonExistent, SpendingCounter},
+    config::ConfigParam,
     date::BlockDate,
+    fee::LinearFee,
+    fragment::{ConfigParams, Fragment},
     ledger::{
         self,
         check::{TxValidityError, TxVerifyError},
         Error::{Account, InvalidTransactionValidity, TransactionMalformed},
+        FeeAndBalanceReport,
     },
+    script::key_list::KeyList,
     testing::{
         data::{AddressData, AddressDataValue},
-        ConfigBuilder, LedgerBuilder, TestTxBuilder,
+        ConfigBuilder, LedgerBuilder, TestLedger, TestTxBuilder,
     },
     transaction::*,
     value::*,
 };
-use chain_addr::Discrimination;
+use chain_addr::{Address, Discrimination, Kind};
 
 #[test]
 pub fn transaction_fail_when_255_outputs() {
@@ -26,10 +31,7 @@ pub fn transaction_fail_when_255_outputs() {
 
     // random output repeated 255 times.
     let receiver = AddressData::utxo(Discrimination::Test);
-    let output = Output {
-        address: receiver.address,
-        value: Value(1),
-    };
+    let output = Output::from_address(receiver.address, Value(1));
     let outputs: Vec<_> = std::iter::repeat(output).take(255).collect();
 
     let fragment = TestTxBuilder::new(test_ledger.block0_hash)
@@ -53,10 +55,7 @@ pub fn transaction_fail_when_validity_out_of_range() {
         .expect("cannot build test ledger");
 
     let receiver = AddressData::utxo(Discrimination::Test);
-    let output = Output {
-        address: receiver.address,
-        value: Value(1),
-    };
+    let output = Output::from_address(receiver.address, Value(1));
     let outputs = [output];
 
     let valid_until = Some(BlockDate {
@@ -92,10 +91,7 @@ pub fn transaction_fail_when_validity_too_far() {
     .expect("cannot build test ledger");
 
     let receiver = AddressData::utxo(Discrimination::Test);
-    let output = Output {
-        address: receiver.address,
-        value: Value(1),
-    };
+    let output = Output::from_address(receiver.address, Value(1));
 
     let valid_until = BlockDate {
         epoch: MAX_EXPIRY_EPOCHS as u32 + 1,
@@ -210,3 +206,194 @@ pub fn repeated_account_transaction() {
         .apply_transaction(fragment, BlockDate::first())
         .is_err());
 }
+
+#[test]
+pub fn time_locked_utxo_cannot_be_spent_before_lock_date() {
+    let mut test_ledger = LedgerBuilder::from_config(ConfigBuilder::new())
+        .faucet_value(Value(1000))
+        .build()
+        .expect("cannot build test ledger");
+
+    let locked_until = BlockDate {
+        epoch: 0,
+        slot_id: 10,
+    };
+    let locked = AddressDataValue::utxo(Discrimination::Test, Value(1000));
+    let receiver = AddressDataValue::utxo(Discrimination::Test, Value(0));
+
+    let output = Output::time_locked(locked.address_data().address, locked.value, locked_until);
+    let fragment = TestTxBuilder::new(test_ledger.block0_hash)
+        .move_to_outputs_from_faucet(&mut test_ledger, &[output])
+        .get_fragment();
+    assert!(test_ledger
+        .apply_transaction(fragment, BlockDate::first())
+        .is_ok());
+
+    let fragment = TestTxBuilder::new(test_ledger.block0_hash)
+        .move_all_funds(&mut test_ledger, &locked, &receiver)
+        .get_fragment();
+    assert_err_match!(
+        ledger::Error::UtxoTimeLocked { .. },
+        test_ledger.apply_transaction(fragment.clone(), BlockDate::first())
+    );
+    assert!(test_ledger
+        .apply_transaction(fragment, locked_until)
+        .is_ok());
+}
+
+#[test]
+pub fn time_locked_account_output_is_rejected() {
+    let mut test_ledger = LedgerBuilder::from_config(ConfigBuilder::new())
+        .faucet_value(Value(1000))
+        .build()
+        .expect("cannot build test ledger");
+
+    let receiver = AddressData::account(Discrimination::Test);
+    let output = Output::time_locked(
+        receiver.address,
+        Value(1000),
+        BlockDate {
+            epoch: 0,
+            slot_id: 10,
+        },
+    );
+    let fragment = TestTxBuilder::new(test_ledger.block0_hash)
+        .move_to_outputs_from_faucet(&mut test_ledger, &[output])
+        .get_fragment();
+
+    assert_err!(
+        ledger::Error::TimeLockedOutputNotUtxo,
+        test_ledger.apply_transaction(fragment, BlockDate::first())
+    );
+}
+
+#[test]
+pub fn check_transaction_does_not_modify_ledger() {
+    let mut test_ledger =
+        LedgerBuilder::from_config(ConfigBuilder::new().with_fee(LinearFee::new(10, 1, 0)))
+            .faucet_value(Value(1000))
+            .build()
+            .expect("cannot build test ledger");
+
+    let receiver = AddressData::utxo(Discrimination::Test);
+    let tx = TestTxBuilder::new(test_ledger.block0_hash).move_from_faucet(
+        &mut test_ledger,
+        &receiver.address,
+        Value(1000),
+    );
+    let fragment_id = tx.get_fragment_id();
+
+    assert_eq!(
+        test_ledger
+            .ledger
+            .check_transaction(&fragment_id, &tx.as_slice(), BlockDate::first()),
+        Ok(FeeAndBalanceReport {
+            inputs: Value(1000),
+            outputs: Value(988),
+            fee: Value(12),
+        })
+    );
+
+    test_ledger
+        .apply_transaction(tx.clone().get_fragment(), BlockDate::first())
+        .expect("checked transaction should still be applicable");
+
+    assert!(test_ledger
+        .ledger
+        .check_transaction(&fragment_id, &tx.as_slice(), BlockDate::first())
+        .is_err());
+}
+
+fn script_address(list: &KeyList) -> Address {
+    Address(Discrimination::Test, Kind::Script(list.identifier()))
+}
+
+fn spend_script_utxo(
+    test_ledger: &TestLedger,
+    list: &KeyList,
+    signer: &AddressData,
+    receiver: &AddressData,
+) -> Fragment {
+    let address = script_address(list);
+    let utxo = test_ledger
+        .utxos()
+        .find(|entry| entry.output.address == address)
+        .expect("no utxo for the script address");
+    let fee = test_ledger.fee().fees_for_inputs_outputs(1u8, 1u8);
+    let outputs = [Output::from_address(
+        receiver.address.clone(),
+        (utxo.output.value - fee).unwrap(),
+    )];
+    let inputs = [Input::from_utxo_entry(utxo)];
+
+    let tx_builder = TxBuilder::new()
+        .set_nopayload()
+        .set_expiry_date(BlockDate::first().next_epoch())
+        .set_ios(&inputs, &outputs);
+    let witness = Witness::new_script(
+        &test_ledger.block0_hash,
+        &tx_builder.get_auth_data_for_witness().hash(),
+        |data| list.witness(&[(0, signer.private_key().sign(data))]),
+    );
+    Fragment::Transaction(tx_builder.set_witnesses(&[witness]).set_payload_auth(&()))
+}
+
+fn script_ledger(list: &KeyList) -> TestLedger {
+    LedgerBuilder::from_config(ConfigBuilder::new().with_script_addresses(true))
+        .prefill_address(script_address(list), Value(1000))
+        .build()
+        .expect("cannot build test ledger")
+}
+
+#[test]
+pub fn script_utxo_is_spent_when_script_addresses_are_enabled() {
+    let signer = AddressData::utxo(Discrimination::Test);
+    let receiver = AddressData::utxo(Discrimination::Test);
+    let list = KeyList::new(1, vec![signer.public_key()]).unwrap();
+    let mut test_ledger = script_ledger(&list);
+
+    let fragment = spend_script_utxo(&test_ledger, &list, &signer, &receiver);
+    assert!(test_ledger
+        .apply_transaction(fragment, BlockDate::first())
+        .is_ok());
+    assert!(test_ledger.find_utxo_for_address(&receiver).is_some());
+}
+
+#[test]
+pub fn script_utxo_is_not_spent_when_script_addresses_are_disabled() {
+    let signer = AddressData::utxo(Discrimination::Test);
+    let receiver = AddressData::utxo(Discrimination::Test);
+    let list = KeyList::new(1, vec![signer.public_key()]).unwrap();
+    let mut test_ledger = script_ledger(&list);
+
+    // as an update proposal turning the setting off would
+    let mut changes = ConfigParams::new();
+    changes.push(ConfigParam::ScriptAddresses(false));
+    test_ledger.ledger.settings = test_ledger.ledger.settings.try_apply(&changes).unwrap();
+
+    let fragment = spend_script_utxo(&test_ledger, &list, &signer, &receiver);
+    assert_err!(
+        ledger::Error::ScriptsAddressNotAllowedYet,
+        test_ledger.apply_transaction(fragment, BlockDate::first())
+    );
+}
+
+#[test]
+pub fn script_output_is_rejected_when_script_addresses_are_disabled() {
+    let mut test_ledger = LedgerBuilder::from_config(ConfigBuilder::new())
+        .faucet_value(Value(1000))
+        .build()
+        .expect("cannot build test ledger");
+
+    let signer = AddressData::utxo(Discrimination::Test);
+    let list = KeyList::new(1, vec![signer.public_key()]).unwrap();
+    let output = Output::from_address(script_address(&list), Value(1000));
+    let fragment = TestTxBuilder::new(test_ledger.block0_hash)
+        .move_to_outputs_from_faucet(&mut test_ledger, &[output])
+        .get_fragment();
+
+    assert_err!(
+        ledger::Error::ScriptsAddressNotAllowedYet,
+        test_ledger.apply_transaction(fragment, BlockDate::first())
+    );
+}
@@ -57,6 +57,18 @@ impl<'a> TokenDistribution<'a, TokenIdentifier> {
             .get_state(account)
             .map(|account_state| account_state.tokens.lookup(self.token).copied())
     }
+
+    /// Accounts holding the token, with the value they hold
+    pub fn accounts(&self) -> impl Iterator<Item = (&account::Identifier, Value)> + '_ {
+        self.account_ledger
+            .iter()
+            .filter_map(move |(account, account_state)| {
+                account_state
+                    .tokens
+                    .lookup(self.token)
+                    .map(|value| (account, *value))
+            })
+    }
 }
 
 #[derive(Clone, PartialEq, Eq, Default)]
@@ -60,10 +60,20 @@ pub struct Declaration {
 }
 
 impl Declaration {
+    pub fn new(threshold: u8, owners: Vec<DeclElement>) -> Result<Self, DeclarationError> {
+        let declaration = Declaration { threshold, owners };
+        declaration.is_valid()?;
+        Ok(declaration)
+    }
+
     pub fn threshold(&self) -> usize {
         self.threshold as usize
     }
 
+    pub fn owners(&self) -> &[DeclElement] {
+        &self.owners
+    }
+
     pub fn total(&self) -> usize {
         self.owners.len()
     }
@@ -1,8 +1,11 @@
-use imhamt::{Hamt, HamtIter, InsertError, RemoveError};
+use chain_crypto::{Ed25519, PublicKey};
+use imhamt::{Hamt, HamtIter, InsertError, RemoveError, ReplaceError};
 use std::collections::hash_map::DefaultHasher;
 use thiserror::Error;
 
 use super::declaration::{Declaration, DeclarationError, Identifier};
+use super::index::Index;
+use super::witness::verify_identifier_threshold;
 use crate::accounting::account::{self, DelegationType, Iter, SpendingCounter};
 use crate::value::{Value, ValueError};
 
@@ -44,6 +47,12 @@ impl From<RemoveError> for LedgerError {
     }
 }
 
+impl From<ReplaceError> for LedgerError {
+    fn from(_: ReplaceError) -> Self {
+        LedgerError::DoesntExist
+    }
+}
+
 impl Ledger {
     /// Create a new empty account ledger
     pub fn new() -> Self {
@@ -91,6 +100,35 @@ impl Ledger {
         })
     }
 
+    /// Replace the declaration of an existing multisig account, e.g. to rotate its
+    /// owners or change its threshold.
+    ///
+    /// The account keeps its identifier (derived from the initial declaration) and
+    /// its funds. The `signers` need to meet the threshold of the current declaration.
+    pub fn update_declaration(
+        &self,
+        identifier: &Identifier,
+        signers: &[(Index, PublicKey<Ed25519>)],
+        declaration: &Declaration,
+    ) -> Result<Self, LedgerError> {
+        let current = self
+            .declarations
+            .lookup(identifier)
+            .ok_or(LedgerError::DoesntExist)?;
+        verify_identifier_threshold(current, signers)?;
+        declaration.is_valid()?;
+
+        let (new_decls, _) = self.declarations.replace(identifier, declaration.clone())?;
+        Ok(Self {
+            accounts: self.accounts.clone(),
+            declarations: new_decls,
+        })
+    }
+
+    pub fn declaration(&self, identifier: &Identifier) -> Option<&Declaration> {
+        self.declarations.lookup(identifier)
+    }
+
     pub fn add_value(&self, identifier: &Identifier, value: Value) -> Result<Self, LedgerError> {
         let new_accounts = self.accounts.add_value(identifier, value)?;
         Ok(Self {
@@ -107,6 +145,16 @@ impl Ledger {
         self.declarations.iter()
     }
 
+    /// Number of multisig accounts, in constant time
+    pub fn accounts_len(&self) -> usize {
+        self.accounts.len()
+    }
+
+    /// Number of multisig declarations, in constant time
+    pub fn declarations_len(&self) -> usize {
+        self.declarations.size()
+    }
+
     /// If the account doesn't exist, or that the value would become negative, errors out.
     pub fn remove_value(
         &self,
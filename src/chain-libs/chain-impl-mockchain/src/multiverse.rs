@@ -58,6 +58,13 @@ impl<State> Ref<State> {
     }
 }
 
+/// Answers ancestry queries between blocks, usually backed by the block storage.
+/// Used by the garbage collection to tell stale forks from the main chain.
+pub trait AncestryOracle {
+    /// Whether `ancestor` is `descendant` itself or one of its ancestors
+    fn is_ancestor(&self, ancestor: &HeaderId, descendant: &HeaderId) -> bool;
+}
+
 enum GcEntry<State> {
     Retained(Arc<State>),
     Collectable(Weak<State>),
@@ -126,6 +133,33 @@ impl<State> Multiverse<State> {
     ///
     /// `cut_to_depth` - the depth from starting which branches would be removed.
     pub fn gc(&mut self, cut_to_depth: u32) {
+        self.collect_states(cut_to_depth, |_| true, |_| false)
+    }
+
+    /// Same as [`Multiverse::gc`], but the states kept in the gaps must be ancestors
+    /// of `tip`, so that the old states of stale forks are collected as well.
+    /// States listed in `roots` are always kept.
+    ///
+    /// As for `gc`, states pinned by `Ref` values are never removed.
+    pub fn gc_with_ancestry<O: AncestryOracle>(
+        &mut self,
+        cut_to_depth: u32,
+        tip: &HeaderId,
+        roots: &[HeaderId],
+        oracle: &O,
+    ) {
+        self.collect_states(
+            cut_to_depth,
+            |k| oracle.is_ancestor(k, tip),
+            |k| roots.contains(k),
+        )
+    }
+
+    fn collect_states<F, R>(&mut self, cut_to_depth: u32, on_main_chain: F, is_root: R)
+    where
+        F: Fn(&HeaderId) -> bool,
+        R: Fn(&HeaderId) -> bool,
+    {
         let longest_chain = match self.states_by_chain_length.keys().next_back() {
             Some(len) => *len,
             None => return,
@@ -135,10 +169,6 @@ impl<State> Multiverse<State> {
             let mut scan_length = ChainLength(0);
             let mut to_keep = ChainLength(0);
 
-            // Keep states close to the current longest
-            // chain. FIXME: we should keep only the state that is
-            // an ancestor of the current longest chain. However,
-            // checking ancestry requires access to BlockStore.
             let states_by_hash = &mut self.states_by_hash;
             while let Some((&chain_length, hashes)) = self
                 .states_by_chain_length
@@ -147,29 +177,31 @@ impl<State> Multiverse<State> {
             {
                 // Keep states in gaps that get exponentially smaller
                 // as they get closer to the longest chain.
-                let keep = if chain_length >= to_keep {
+                let keep_length = chain_length >= to_keep;
+                if keep_length {
                     to_keep = ChainLength(chain_length.0 + (longest_chain.0 - chain_length.0) / 2);
-                    true
-                } else {
-                    // Keep states that are kept alive by Ref values.
-                    hashes.retain(|k| {
-                        use std::collections::hash_map::Entry::*;
-
-                        match states_by_hash.entry(*k) {
-                            Occupied(mut entry) => {
-                                if entry.get_mut().collect() {
-                                    entry.remove();
-                                    false
-                                } else {
-                                    true
-                                }
+                }
+
+                // Keep states that are kept alive by Ref values.
+                hashes.retain(|k| {
+                    use std::collections::hash_map::Entry::*;
+
+                    if is_root(k) || (keep_length && on_main_chain(k)) {
+                        return true;
+                    }
+                    match states_by_hash.entry(*k) {
+                        Occupied(mut entry) => {
+                            if entry.get_mut().collect() {
+                                entry.remove();
+                                false
+                            } else {
+                                true
                             }
-                            Vacant(_) => panic!("dangling state index entry"),
                         }
-                    });
-                    !hashes.is_empty()
-                };
-                if !keep {
+                        Vacant(_) => panic!("dangling state index entry"),
+                    }
+                });
+                if hashes.is_empty() {
                     self.states_by_chain_length.remove(&chain_length);
                 }
                 scan_length = chain_length.increase();
@@ -194,7 +226,7 @@ impl<S> Default for Multiverse<S> {
 
 #[cfg(test)]
 mod test {
-    use super::{Multiverse, Ref};
+    use super::{AncestryOracle, Multiverse, Ref};
     use crate::{
         block::{self, Block, Contents, ContentsBuilder},
         chaintypes::{ChainLength, ConsensusType, HeaderId},
@@ -215,6 +247,24 @@ mod test {
 
     const SUFFIX_TO_KEEP: u32 = 50;
 
+    /// Parent of each block, standing for the block storage
+    struct Parents(HashMap<HeaderId, HeaderId>);
+
+    impl AncestryOracle for Parents {
+        fn is_ancestor(&self, ancestor: &HeaderId, descendant: &HeaderId) -> bool {
+            let mut current = descendant;
+            loop {
+                if current == ancestor {
+                    return true;
+                }
+                match self.0.get(current) {
+                    Some(parent) => current = parent,
+                    None => return false,
+                }
+            }
+        }
+    }
+
     /// Get the chain state at block 'k' from memory if present;
     /// otherwise reconstruct it by reading blocks from storage and
     /// applying them to the nearest ancestor state that we do have.
@@ -453,4 +503,62 @@ mod test {
             "second fork length incorrect"
         );
     }
+
+    #[test]
+    pub fn remove_stale_fork_with_ancestry() {
+        const NUM_BLOCK_PER_EPOCH: u32 = 1000;
+        let mut multiverse = Multiverse::new();
+        let slot_duration = 10u8;
+        let era = era(slot_duration, NUM_BLOCK_PER_EPOCH);
+        let leader = leader();
+        let genesis_block = genesis_block(&leader, slot_duration, NUM_BLOCK_PER_EPOCH);
+        let mut date = BlockDate::first();
+        let genesis_state =
+            Ledger::new(genesis_block.header().id(), genesis_block.contents().iter()).unwrap();
+        let genesis_id = genesis_block.header().id();
+        let _root = multiverse.add(genesis_id, genesis_state.clone());
+
+        let mut parents = Parents(HashMap::new());
+        let mut state = genesis_state;
+        let mut _ref = None;
+        let mut parent = genesis_id;
+        let mut ids = vec![];
+        let mut fork_point = None;
+        for _ in 0..200 {
+            date = date.next(&era);
+            let block = build_bft_block(&parent, date, state.chain_length.increase(), &leader);
+            state = apply_block(&state, &block);
+            let block_id = block.header().id();
+            parents.0.insert(block_id, parent);
+            _ref = Some(multiverse.add(block_id, state.clone()));
+            ids.push(block_id);
+            parent = block_id;
+            if state.chain_length().0 == 99 {
+                fork_point = Some((block_id, state.clone(), date));
+            }
+        }
+
+        // with 200 states and 50 kept at the end, states at length 0 and 100
+        // are the only old ones kept by distance
+        let (fork_parent, fork_state, fork_date) = fork_point.unwrap();
+        let fork_block = build_bft_block(
+            &fork_parent,
+            fork_date.next(&era).next(&era),
+            fork_state.chain_length.increase(),
+            &leader,
+        );
+        let fork_id = fork_block.header().id();
+        parents.0.insert(fork_id, fork_parent);
+        multiverse.add(fork_id, apply_block(&fork_state, &fork_block));
+
+        multiverse.gc(SUFFIX_TO_KEEP);
+        assert!(multiverse.get(&fork_id).is_some());
+        assert!(multiverse.get(&ids[99]).is_some());
+
+        let tip = *ids.last().unwrap();
+        multiverse.gc_with_ancestry(SUFFIX_TO_KEEP, &tip, &[genesis_id], &parents);
+        assert!(multiverse.get(&fork_id).is_none());
+        assert!(multiverse.get(&ids[99]).is_some());
+        assert!(multiverse.get(&genesis_id).is_some());
+    }
 }
@@ -4,14 +4,28 @@ use crate::value::{Value, ValueError};
 use chain_core::packer::Codec;
 use chain_core::property::ReadError;
 use std::num::{NonZeroU32, NonZeroU64};
+use thiserror::Error;
 use typed_bytes::ByteBuilder;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum CompoundingType {
+    /// The same contribution every epoch
+    Fixed,
     Linear,
     Halvening,
 }
 
+impl CompoundingType {
+    /// Formula computing the contribution of each epoch for this type
+    pub fn policy(&self) -> &'static dyn RewardPolicy {
+        match self {
+            CompoundingType::Fixed => &FixedReward,
+            CompoundingType::Linear => &LinearDecay,
+            CompoundingType::Halvening => &Halving,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(
     any(test, feature = "property-test-api"),
@@ -145,6 +159,11 @@ pub struct Parameters {
 }
 
 impl Parameters {
+    /// Checks that the parameters make sense for their formula
+    pub fn validate(&self) -> Result<(), RewardPolicyError> {
+        self.compounding_type.policy().validate(self)
+    }
+
     pub fn zero() -> Self {
         Parameters {
             initial_value: 0,
@@ -158,6 +177,95 @@ impl Parameters {
     }
 }
 
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum RewardPolicyError {
+    #[error("the ratio of the halving reward formula is {numerator}/{denominator}, it cannot be greater than 1")]
+    HalvingRatioAboveOne {
+        numerator: u64,
+        denominator: NonZeroU64,
+    },
+}
+
+/// Formula of the contribution drawn from the reward pot each epoch,
+/// selected by the compounding type of the reward parameters.
+///
+/// The contribution computed by the policy is still bounded by the drawing
+/// limit and by the remaining rewards pot.
+pub trait RewardPolicy: Send + Sync {
+    /// Contribution of the given epoch, which is not before the start epoch
+    /// of the parameters
+    fn contribution(&self, epoch: Epoch, params: &Parameters) -> Value;
+
+    /// Checks the parameters before they are used by the chain
+    fn validate(&self, _params: &Parameters) -> Result<(), RewardPolicyError> {
+        Ok(())
+    }
+}
+
+/// Number of reduction phases elapsed at the given epoch
+fn zone(epoch: Epoch, params: &Parameters) -> u64 {
+    ((epoch - params.epoch_start) / params.epoch_rate.get()) as u64
+}
+
+/// The initial value every epoch
+pub struct FixedReward;
+
+impl RewardPolicy for FixedReward {
+    fn contribution(&self, _epoch: Epoch, params: &Parameters) -> Value {
+        Value(params.initial_value)
+    }
+}
+
+/// The initial value reduced by the ratio at each phase, down to zero
+pub struct LinearDecay;
+
+impl RewardPolicy for LinearDecay {
+    fn contribution(&self, epoch: Epoch, params: &Parameters) -> Value {
+        // C - rratio * (#epoch / erate)
+        let rr = &params.compounding_ratio;
+        let reduce_by =
+            (rr.numerator as u128 * zone(epoch, params) as u128) / rr.denominator.get() as u128;
+        Value((params.initial_value as u128).saturating_sub(reduce_by) as u64)
+    }
+}
+
+/// The initial value multiplied by the ratio at each phase
+pub struct Halving;
+
+impl RewardPolicy for Halving {
+    fn contribution(&self, epoch: Epoch, params: &Parameters) -> Value {
+        // mathematical formula is : C * rratio ^ (#epoch / erate)
+        // although we perform it as a for loop, with the rationale
+        // that it allow for integer computation and that the reduce_epoch_rate
+        // should prevent growth to large amount of zones
+        let rr = &params.compounding_ratio;
+        const SCALE: u128 = 1_000_000_000_000_000_000;
+
+        let mut acc = params.initial_value as u128 * SCALE;
+        for _ in 0..zone(epoch, params) {
+            if acc == 0 {
+                break;
+            }
+            acc *= rr.numerator as u128;
+            acc /= rr.denominator.get() as u128;
+        }
+
+        Value((acc / SCALE) as u64)
+    }
+
+    /// A ratio above 1 would make the contribution grow without bounds
+    fn validate(&self, params: &Parameters) -> Result<(), RewardPolicyError> {
+        let rr = &params.compounding_ratio;
+        if rr.numerator > rr.denominator.get() {
+            return Err(RewardPolicyError::HalvingRatioAboveOne {
+                numerator: rr.numerator,
+                denominator: rr.denominator,
+            });
+        }
+        Ok(())
+    }
+}
+
 /// A value distributed between tax and remaining
 #[derive(Debug, Clone)]
 pub struct TaxDistribution {
@@ -185,35 +293,7 @@ pub fn rewards_contribution_calculation(
         return Value::zero();
     }
 
-    let zone = ((epoch - params.epoch_start) / params.epoch_rate.get()) as u64;
-    let drawn = match params.compounding_type {
-        CompoundingType::Linear => {
-            // C - rratio * (#epoch / erate)
-            let rr = &params.compounding_ratio;
-            let reduce_by = (rr.numerator * zone) / rr.denominator.get();
-            if params.initial_value >= reduce_by {
-                Value(params.initial_value - reduce_by)
-            } else {
-                Value::zero()
-            }
-        }
-        CompoundingType::Halvening => {
-            // mathematical formula is : C * rratio ^ (#epoch / erate)
-            // although we perform it as a for loop, with the rationale
-            // that it allow for integer computation and that the reduce_epoch_rate
-            // should prevent growth to large amount of zones
-            let rr = &params.compounding_ratio;
-            const SCALE: u128 = 1_000_000_000_000_000_000;
-
-            let mut acc = params.initial_value as u128 * SCALE;
-            for _ in 0..zone {
-                acc *= rr.numerator as u128;
-                acc /= rr.denominator.get() as u128;
-            }
-
-            Value((acc / SCALE) as u64)
-        }
-    };
+    let drawn = params.compounding_type.policy().contribution(epoch, params);
 
     match params.reward_drawing_limit_max {
         Limit::None => drawn,
@@ -361,6 +441,59 @@ mod tests {
         );
     }
 
+    #[quickcheck]
+    fn fixed_reward_is_the_same_every_epoch(mut params: Parameters, epoch: Epoch) -> TestResult {
+        if epoch < params.epoch_start {
+            return TestResult::discard();
+        }
+        params.compounding_type = CompoundingType::Fixed;
+        params.reward_drawing_limit_max = Limit::None;
+        let system_info = SystemInformation {
+            declared_stake: Stake::from_value(Value(100)),
+        };
+        TestResult::from_bool(
+            rewards_contribution_calculation(epoch, &params, &system_info)
+                == Value(params.initial_value),
+        )
+    }
+
+    #[test]
+    fn linear_decay_does_not_overflow() {
+        let params = Parameters {
+            initial_value: 100,
+            compounding_ratio: Ratio {
+                numerator: u64::MAX,
+                denominator: NonZeroU64::new(1).unwrap(),
+            },
+            compounding_type: CompoundingType::Linear,
+            epoch_rate: NonZeroU32::new(1).unwrap(),
+            epoch_start: 0,
+            reward_drawing_limit_max: Limit::None,
+            pool_participation_capping: None,
+        };
+        assert_eq!(LinearDecay.contribution(10, &params), Value::zero());
+    }
+
+    #[test]
+    fn halving_ratio_above_one_is_rejected() {
+        let mut params = Parameters::zero();
+        params.compounding_type = CompoundingType::Halvening;
+        params.compounding_ratio = Ratio {
+            numerator: 3,
+            denominator: NonZeroU64::new(2).unwrap(),
+        };
+        assert_eq!(
+            params.validate(),
+            Err(RewardPolicyError::HalvingRatioAboveOne {
+                numerator: 3,
+                denominator: NonZeroU64::new(2).unwrap(),
+            })
+        );
+
+        params.compounding_ratio.numerator = 2;
+        assert_eq!(params.validate(), Ok(()));
+    }
+
     impl Arbitrary for TaxType {
         fn arbitrary<G: Gen>(gen: &mut G) -> Self {
             let fixed = Arbitrary::arbitrary(gen);
@@ -413,10 +546,11 @@ mod tests {
 
     impl Arbitrary for CompoundingType {
         fn arbitrary<G: Gen>(g: &mut G) -> Self {
-            let option: u8 = u8::arbitrary(g) % 2;
+            let option: u8 = u8::arbitrary(g) % 3;
             match option {
                 0 => CompoundingType::Linear,
-                2 => CompoundingType::Halvening,
+                1 => CompoundingType::Halvening,
+                2 => CompoundingType::Fixed,
                 _ => unreachable!(),
             }
         }
@@ -0,0 +1,230 @@
+//! N-of-M list of keys: the outputs are spent with the signatures of at
+//! least N of the M keys of the list.
+//!
+//! Unlike the multisig accounts, the list is not declared in the ledger
+//! beforehand, it is revealed by the witness spending the output.
+//!
+//! The script is the threshold followed by the public keys, and the proof
+//! is the signatures each prefixed with the index of the key in the list,
+//! in strictly increasing order of index.
+use super::{Identifier, ScriptError, ScriptKind, ScriptVerifier, Witness};
+use crate::key::{SpendingPublicKey, SpendingSignature};
+use crate::transaction::WitnessScriptData;
+use chain_crypto::{Ed25519, PublicKey, Signature, Verification};
+
+const KEY_SIZE: usize = 32;
+const SIGNATURE_SIZE: usize = 64;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyList {
+    threshold: u8,
+    keys: Vec<SpendingPublicKey>,
+}
+
+impl KeyList {
+    /// The threshold must be between 1 and the number of keys, of at most
+    /// 255 keys
+    pub fn new(threshold: u8, keys: Vec<SpendingPublicKey>) -> Result<Self, ScriptError> {
+        if threshold == 0 || threshold as usize > keys.len() || keys.len() > u8::MAX as usize {
+            return Err(ScriptError::MalformedScript);
+        }
+        Ok(KeyList { threshold, keys })
+    }
+
+    pub fn threshold(&self) -> usize {
+        self.threshold as usize
+    }
+
+    pub fn keys(&self) -> &[SpendingPublicKey] {
+        &self.keys
+    }
+
+    pub fn to_script(&self) -> Vec<u8> {
+        let mut script = Vec::with_capacity(1 + self.keys.len() * KEY_SIZE);
+        script.push(self.threshold);
+        for key in &self.keys {
+            script.extend_from_slice(key.as_ref());
+        }
+        script
+    }
+
+    pub fn from_script(script: &[u8]) -> Result<Self, ScriptError> {
+        let (threshold, keys) = script.split_first().ok_or(ScriptError::MalformedScript)?;
+        if keys.len() % KEY_SIZE != 0 {
+            return Err(ScriptError::MalformedScript);
+        }
+        let keys = keys
+            .chunks(KEY_SIZE)
+            .map(PublicKey::from_binary)
+            .collect::<Result<_, _>>()
+            .map_err(|_| ScriptError::MalformedScript)?;
+        Self::new(*threshold, keys)
+    }
+
+    /// Identifier of the script address locking outputs to this list
+    pub fn identifier(&self) -> Identifier {
+        super::identifier(ScriptKind::KEY_LIST, &self.to_script())
+    }
+
+    /// Witness made of the signatures of the keys at the given indices
+    pub fn witness(&self, signatures: &[(u8, SpendingSignature<WitnessScriptData>)]) -> Witness {
+        let mut signatures = signatures.to_vec();
+        signatures.sort_by_key(|(index, _)| *index);
+        let mut proof = Vec::with_capacity(signatures.len() * (1 + SIGNATURE_SIZE));
+        for (index, signature) in signatures {
+            proof.push(index);
+            proof.extend_from_slice(signature.as_ref());
+        }
+        Witness::new(ScriptKind::KEY_LIST, self.to_script(), proof)
+    }
+}
+
+pub struct KeyListVerifier;
+
+impl ScriptVerifier for KeyListVerifier {
+    fn verify(
+        &self,
+        script: &[u8],
+        proof: &[u8],
+        data: &WitnessScriptData,
+    ) -> Result<(), ScriptError> {
+        let list = KeyList::from_script(script)?;
+        if proof.len() % (1 + SIGNATURE_SIZE) != 0 {
+            return Err(ScriptError::MalformedProof);
+        }
+
+        let mut signed = 0;
+        let mut previous = None;
+        for chunk in proof.chunks(1 + SIGNATURE_SIZE) {
+            let index = chunk[0];
+            if previous.map_or(false, |previous| index <= previous) {
+                return Err(ScriptError::MalformedProof);
+            }
+            previous = Some(index);
+
+            let key = list
+                .keys
+                .get(index as usize)
+                .ok_or(ScriptError::MalformedProof)?;
+            let signature = Signature::<WitnessScriptData, Ed25519>::from_binary(&chunk[1..])
+                .map_err(|_| ScriptError::MalformedProof)?;
+            if signature.verify(key, data) == Verification::Failed {
+                return Err(ScriptError::NotSatisfied);
+            }
+            signed += 1;
+        }
+
+        if signed < list.threshold() {
+            return Err(ScriptError::NotSatisfied);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transaction::{TransactionSignData, TransactionSignDataHash};
+    use crate::{key::Hash, testing::data::AddressData};
+    use chain_addr::Discrimination;
+    use proptest::prop_assume;
+    use test_strategy::proptest;
+
+    fn witness_data(block0: &[u8]) -> WitnessScriptData {
+        let sign_data: TransactionSignData = vec![1, 2, 3].into();
+        WitnessScriptData::new(
+            &Hash::hash_bytes(block0),
+            &TransactionSignDataHash::digest(&sign_data),
+        )
+    }
+
+    fn signers(total: u8) -> Vec<AddressData> {
+        (0..total)
+            .map(|_| AddressData::utxo(Discrimination::Test))
+            .collect()
+    }
+
+    fn key_list(threshold: u8, signers: &[AddressData]) -> KeyList {
+        let keys = signers.iter().map(|signer| signer.public_key()).collect();
+        KeyList::new(threshold, keys).unwrap()
+    }
+
+    #[proptest]
+    fn script_roundtrip(#[strategy(1u8..=8)] total: u8, #[strategy(1u8..=8)] threshold: u8) {
+        prop_assume!(threshold <= total);
+        let list = key_list(threshold, &signers(total));
+        assert_eq!(KeyList::from_script(&list.to_script()).unwrap(), list);
+    }
+
+    #[proptest]
+    fn verifies_only_when_threshold_is_met(
+        #[strategy(1u8..=8)] total: u8,
+        #[strategy(1u8..=8)] threshold: u8,
+        signing: [bool; 8],
+    ) {
+        prop_assume!(threshold <= total);
+        let signers = signers(total);
+        let list = key_list(threshold, &signers);
+        let data = witness_data(&[4, 5, 6]);
+
+        let signatures: Vec<_> = signers
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| signing[*index])
+            .map(|(index, signer)| (index as u8, signer.private_key().sign(&data)))
+            .collect();
+        let witness = list.witness(&signatures);
+
+        let result = witness.verify(&list.identifier(), &data);
+        if signatures.len() >= threshold as usize {
+            assert_eq!(result, Ok(()));
+        } else {
+            assert_eq!(result, Err(ScriptError::NotSatisfied));
+        }
+    }
+
+    #[proptest]
+    fn rejects_signatures_of_other_data(
+        #[strategy(1u8..=8)] total: u8,
+        #[strategy(0u8..8)] wrong: u8,
+    ) {
+        prop_assume!(wrong < total);
+        let signers = signers(total);
+        let list = key_list(total, &signers);
+        let data = witness_data(&[4, 5, 6]);
+        let other_data = witness_data(&[7, 8, 9]);
+
+        let signatures: Vec<_> = signers
+            .iter()
+            .enumerate()
+            .map(|(index, signer)| {
+                let signed = if index as u8 == wrong {
+                    &other_data
+                } else {
+                    &data
+                };
+                (index as u8, signer.private_key().sign(signed))
+            })
+            .collect();
+        let witness = list.witness(&signatures);
+
+        assert_eq!(
+            witness.verify(&list.identifier(), &data),
+            Err(ScriptError::NotSatisfied)
+        );
+    }
+
+    #[proptest]
+    fn rejects_another_list(#[strategy(2u8..=8)] total: u8) {
+        let signers = signers(total);
+        let list = key_list(1, &signers);
+        let other = key_list(1, &signers[..1]);
+        let data = witness_data(&[4, 5, 6]);
+
+        let witness = list.witness(&[(0, signers[0].private_key().sign(&data))]);
+        assert_eq!(
+            witness.verify(&other.identifier(), &data),
+            Err(ScriptError::IdentifierMismatch)
+        );
+    }
+}
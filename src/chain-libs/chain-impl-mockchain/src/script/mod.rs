@@ -0,0 +1,192 @@
+//! Outputs locked by scripts.
+//!
+//! An output sent to a [`Kind::Script`](chain_addr::Kind::Script) address is
+//! locked by the identifier of a script, and is spent with a [`Witness`]
+//! revealing the script along with a proof that its conditions are met.
+//!
+//! The ledger only checks that the revealed script matches the identifier of
+//! the address, then hands the proof over to the [`ScriptVerifier`] of the
+//! kind of the script: supporting a new kind of script only takes a new
+//! verifier, registered in [`verifier`].
+//!
+//! Script addresses are refused by the ledger unless they are enabled with
+//! [`ConfigParam::ScriptAddresses`](crate::config::ConfigParam::ScriptAddresses).
+pub mod key_list;
+
+use crate::transaction::WitnessScriptData;
+use chain_core::{
+    packer::Codec,
+    property::{DeserializeFromSlice, ReadError, Serialize, WriteError},
+};
+use chain_crypto::Blake2b256;
+use thiserror::Error;
+
+/// Identifier of a script, as found in a script address
+pub type Identifier = [u8; 32];
+
+/// Kind of a script, selecting the verifier of its witnesses
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ScriptKind(pub u8);
+
+impl ScriptKind {
+    /// N-of-M list of keys, see [`key_list`](self::key_list)
+    pub const KEY_LIST: Self = ScriptKind(1);
+}
+
+impl std::fmt::Display for ScriptKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum ScriptError {
+    #[error("no verifier for the scripts of kind {0}")]
+    UnknownKind(ScriptKind),
+    #[error("the script does not match the identifier of the address")]
+    IdentifierMismatch,
+    #[error("malformed script")]
+    MalformedScript,
+    #[error("malformed proof")]
+    MalformedProof,
+    #[error("the proof does not satisfy the script")]
+    NotSatisfied,
+}
+
+/// Verifies the witnesses of the scripts of one kind
+pub trait ScriptVerifier: Send + Sync {
+    /// Checks that the proof satisfies the script, for the transaction
+    /// identified by the witness data
+    fn verify(
+        &self,
+        script: &[u8],
+        proof: &[u8],
+        data: &WitnessScriptData,
+    ) -> Result<(), ScriptError>;
+}
+
+/// Verifier of the scripts of the given kind, `None` if the kind is not
+/// supported
+pub fn verifier(kind: ScriptKind) -> Option<&'static dyn ScriptVerifier> {
+    match kind {
+        ScriptKind::KEY_LIST => Some(&key_list::KeyListVerifier),
+        _ => None,
+    }
+}
+
+/// Identifier of the script of the given kind
+pub fn identifier(kind: ScriptKind, script: &[u8]) -> Identifier {
+    let mut bytes = Vec::with_capacity(1 + script.len());
+    bytes.push(kind.0);
+    bytes.extend_from_slice(script);
+    *Blake2b256::new(&bytes).as_hash_bytes()
+}
+
+/// Witness spending an output locked by a script
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Witness {
+    kind: ScriptKind,
+    script: Vec<u8>,
+    proof: Vec<u8>,
+}
+
+impl Witness {
+    pub fn new(kind: ScriptKind, script: Vec<u8>, proof: Vec<u8>) -> Self {
+        Witness {
+            kind,
+            script,
+            proof,
+        }
+    }
+
+    pub fn kind(&self) -> ScriptKind {
+        self.kind
+    }
+
+    pub fn script(&self) -> &[u8] {
+        &self.script
+    }
+
+    pub fn proof(&self) -> &[u8] {
+        &self.proof
+    }
+
+    pub fn identifier(&self) -> Identifier {
+        identifier(self.kind, &self.script)
+    }
+
+    /// Checks that the witness unlocks the outputs of the script address
+    /// with the given identifier
+    pub fn verify(
+        &self,
+        identifier: &Identifier,
+        data: &WitnessScriptData,
+    ) -> Result<(), ScriptError> {
+        if &self.identifier() != identifier {
+            return Err(ScriptError::IdentifierMismatch);
+        }
+        verifier(self.kind)
+            .ok_or(ScriptError::UnknownKind(self.kind))?
+            .verify(&self.script, &self.proof, data)
+    }
+}
+
+impl Serialize for Witness {
+    fn serialized_size(&self) -> usize {
+        Codec::u8_size()
+            + Codec::u16_size()
+            + self.script.len()
+            + Codec::u16_size()
+            + self.proof.len()
+    }
+
+    fn serialize<W: std::io::Write>(&self, codec: &mut Codec<W>) -> Result<(), WriteError> {
+        codec.put_u8(self.kind.0)?;
+        for bytes in [&self.script, &self.proof] {
+            let len = u16::try_from(bytes.len()).map_err(|_| {
+                WriteError::IoError(std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    "script witness field larger than 65535 bytes",
+                ))
+            })?;
+            codec.put_be_u16(len)?;
+            codec.put_bytes(bytes)?;
+        }
+        Ok(())
+    }
+}
+
+impl DeserializeFromSlice for Witness {
+    fn deserialize_from_slice(codec: &mut Codec<&[u8]>) -> Result<Self, ReadError> {
+        let kind = ScriptKind(codec.get_u8()?);
+        let len = codec.get_be_u16()? as usize;
+        let script = codec.get_bytes(len)?;
+        let len = codec.get_be_u16()? as usize;
+        let proof = codec.get_bytes(len)?;
+        Ok(Witness::new(kind, script, proof))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_strategy::proptest;
+
+    #[proptest]
+    fn witness_serialization_roundtrip(kind: u8, script: Vec<u8>, proof: Vec<u8>) {
+        let witness = Witness::new(ScriptKind(kind), script, proof);
+        let bytes = witness.serialize_as_vec().unwrap();
+        assert_eq!(bytes.len(), witness.serialized_size());
+        let decoded = Witness::deserialize_from_slice(&mut Codec::new(bytes.as_slice())).unwrap();
+        assert_eq!(decoded, witness);
+    }
+
+    #[proptest]
+    fn identifier_depends_on_the_kind(kind: u8, other: u8, script: Vec<u8>) {
+        let witness = Witness::new(ScriptKind(kind), script.clone(), Vec::new());
+        assert_eq!(
+            witness.identifier() == identifier(ScriptKind(other), &script),
+            kind == other
+        );
+    }
+}
@@ -44,6 +44,15 @@ pub struct Settings {
     pub pool_participation_capping: Option<(NonZeroU32, NonZeroU32)>,
     pub committees: Arc<[CommitteeId]>,
     pub transaction_max_expiry_epochs: u8,
+    /// Maximum serialized size of a single fragment. When not set, fragments
+    /// are only limited by the block content maximum size.
+    pub max_fragment_size: Option<u32>,
+    /// Weight the votes with the stake the accounts had when the vote plan
+    /// started, instead of their stake at the time of the vote and tally.
+    pub voting_power_snapshot: bool,
+    /// Accept outputs to script addresses and the script witnesses spending
+    /// them.
+    pub script_addresses: bool,
     #[cfg(feature = "evm")]
     pub evm_config: chain_evm::Config,
     #[cfg(feature = "evm")]
@@ -135,6 +144,9 @@ impl Settings {
             pool_participation_capping: None,
             committees: Arc::new([]),
             transaction_max_expiry_epochs: 1,
+            max_fragment_size: None,
+            voting_power_snapshot: false,
+            script_addresses: false,
             #[cfg(feature = "evm")]
             evm_config: chain_evm::Config::default(),
             #[cfg(feature = "evm")]
@@ -245,6 +257,15 @@ impl Settings {
                 ConfigParam::TransactionMaxExpiryEpochs(max_expiry_epochs) => {
                     new_state.transaction_max_expiry_epochs = *max_expiry_epochs;
                 }
+                ConfigParam::MaxFragmentSize(max_fragment_size) => {
+                    new_state.max_fragment_size = Some(*max_fragment_size);
+                }
+                ConfigParam::VotingPowerSnapshot(voting_power_snapshot) => {
+                    new_state.voting_power_snapshot = *voting_power_snapshot;
+                }
+                ConfigParam::ScriptAddresses(script_addresses) => {
+                    new_state.script_addresses = *script_addresses;
+                }
                 #[cfg(feature = "evm")]
                 ConfigParam::EvmConfiguration(evm_config_params) => {
                     new_state.evm_config = *evm_config_params;
@@ -297,6 +318,15 @@ impl Settings {
             Some(p) => params.push(ConfigParam::TreasuryParams(*p)),
             None => (),
         };
+        if let Some(max_fragment_size) = self.max_fragment_size {
+            params.push(ConfigParam::MaxFragmentSize(max_fragment_size));
+        }
+        if self.voting_power_snapshot {
+            params.push(ConfigParam::VotingPowerSnapshot(true));
+        }
+        if self.script_addresses {
+            params.push(ConfigParam::ScriptAddresses(true));
+        }
         #[cfg(feature = "evm")]
         params.push(ConfigParam::EvmConfiguration(self.evm_config));
         #[cfg(feature = "evm")]
@@ -307,6 +337,14 @@ impl Settings {
         params
     }
 
+    /// Maximum serialized size a fragment can have to be included in a block
+    pub fn fragment_max_size(&self) -> u32 {
+        self.max_fragment_size
+            .map_or(self.block_content_max_size, |max_fragment_size| {
+                max_fragment_size.min(self.block_content_max_size)
+            })
+    }
+
     pub fn treasury_params(&self) -> TaxType {
         self.treasury_params.unwrap_or_else(rewards::TaxType::zero)
     }
@@ -345,6 +383,18 @@ impl Settings {
                 reward_drawing_limit_max,
                 pool_participation_capping,
             },
+            Some(RewardParams::Fixed {
+                constant,
+                epoch_start,
+            }) => rewards::Parameters {
+                initial_value: constant,
+                compounding_ratio: rewards::Ratio::zero(),
+                compounding_type: rewards::CompoundingType::Fixed,
+                epoch_start,
+                epoch_rate: NonZeroU32::new(u32::MAX).unwrap(),
+                reward_drawing_limit_max,
+                pool_participation_capping,
+            },
         }
     }
 }
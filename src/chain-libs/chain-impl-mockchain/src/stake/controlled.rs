@@ -40,15 +40,12 @@ impl StakeControl {
                     // single or multisig account are not present in utxos
                     panic!("internal error: accounts in utxo")
                 }
-                Kind::Script(_) => {
-                    // scripts are not present in utxo
-                    panic!("internal error: script in utxo")
-                }
                 Kind::Group(_spending_key, account_key) => {
                     let identifier = account_key.clone().into();
                     sc.add_to(identifier, stake)
                 }
-                Kind::Single(_) => sc.add_unassigned(stake),
+                // script outputs have no stake key, like single addresses
+                Kind::Single(_) | Kind::Script(_) => sc.add_unassigned(stake),
             }
         })
     }
@@ -96,10 +96,7 @@ impl TestTxBuilder {
             test_ledger.find_utxo_for_address(&faucet.clone().into()),
             value,
         )];
-        let outputs = vec![Output {
-            address: destination.clone(),
-            value: output_value,
-        }];
+        let outputs = vec![Output::from_address(destination.clone(), output_value)];
         let tx_builder = TxBuilder::new()
             .set_payload(&NoExtra)
             .set_expiry_date(BlockDate::first().next_epoch())
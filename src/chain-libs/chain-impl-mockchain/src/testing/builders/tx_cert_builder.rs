@@ -1,7 +1,8 @@
 use crate::{
     certificate::{
-        BftLeaderBindingSignature, Certificate, CertificatePayload, EvmMapping, PoolOwnersSigned,
-        PoolSignature, TallyProof, UpdateProposal, UpdateVote, VotePlan, VotePlanProof, VoteTally,
+        BftLeaderBindingSignature, Certificate, CertificatePayload, EvmMapping,
+        MultisigDeclarationUpdateSignature, PoolOwnersSigned, PoolSignature, TallyProof,
+        UpdateProposal, UpdateVote, VotePlan, VotePlanProof, VoteTally,
     },
     chaintypes::HeaderId,
     date::BlockDate,
@@ -10,6 +11,7 @@ use crate::{
     fragment::Fragment,
     key::EitherEd25519SecretKey,
     ledger::ledger::OutputAddress,
+    multisig::Index,
     testing::{data::Wallet, make_witness, make_witness_with_lane},
     transaction::{
         AccountBindingSignature, Input, Payload, SetAuthData, SetTtl,
@@ -255,6 +257,31 @@ impl TestTxCertBuilder {
                 let tx = builder.set_payload_auth(&signature);
                 Fragment::EvmMapping(tx)
             }
+            Certificate::Anchor(anchor) => {
+                let builder = self.set_initial_ios(
+                    valid_until,
+                    TxBuilder::new().set_payload(anchor),
+                    funder,
+                    inputs,
+                    outputs,
+                    make_witness,
+                );
+                let tx = builder.set_payload_auth(&());
+                Fragment::Anchor(tx)
+            }
+            Certificate::MultisigDeclarationUpdate(update) => {
+                let builder = self.set_initial_ios(
+                    valid_until,
+                    TxBuilder::new().set_payload(update),
+                    funder,
+                    inputs,
+                    outputs,
+                    make_witness,
+                );
+                let signature = multisig_declaration_update_sign(&keys, &builder);
+                let tx = builder.set_payload_auth(&signature);
+                Fragment::MultisigDeclarationUpdate(tx)
+            }
         }
     }
 
@@ -387,6 +414,24 @@ pub fn evm_mapping_sign(
     SingleAccountBindingSignature::new(&auth_data, |d| key.sign_slice(d.0))
 }
 
+/// Sign with the given keys, which are expected to be the owners of the current
+/// declaration in the order of the declaration
+pub fn multisig_declaration_update_sign<P: Payload>(
+    keys: &[EitherEd25519SecretKey],
+    builder: &TxBuilderState<SetAuthData<P>>,
+) -> MultisigDeclarationUpdateSignature {
+    let auth_data = builder.get_auth_data();
+    let signatures = keys
+        .iter()
+        .enumerate()
+        .map(|(i, key)| {
+            let sig = SingleAccountBindingSignature::new(&auth_data, |d| key.sign_slice(d.0));
+            (Index::from_u8(i as u8).unwrap(), key.to_public(), sig)
+        })
+        .collect();
+    MultisigDeclarationUpdateSignature { signatures }
+}
+
 /// this struct can create any transaction including not valid one
 /// in order to test robustness of ledger
 pub struct FaultTolerantTxCertBuilder {
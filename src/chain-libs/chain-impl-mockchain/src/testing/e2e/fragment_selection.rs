@@ -0,0 +1,114 @@
+use crate::{
+    fee::LinearFee,
+    ledger::selection::FragmentSelector,
+    testing::{
+        ledger::ConfigBuilder,
+        scenario::{prepare_scenario, wallet},
+    },
+};
+
+#[test]
+pub fn conflicting_transactions_are_not_selected_together() {
+    let (mut ledger, controller) = prepare_scenario()
+        .with_config(ConfigBuilder::new().with_fee(LinearFee::new(1, 1, 1)))
+        .with_initials(vec![
+            wallet("Alice").with(1_000),
+            wallet("Bob").with(1_000),
+            wallet("Clarice").with(1_000),
+        ])
+        .build()
+        .unwrap();
+    let alice = controller.wallet("Alice").unwrap();
+    let bob = controller.wallet("Bob").unwrap();
+    let clarice = controller.wallet("Clarice").unwrap();
+
+    // both use the same spending counter of Alice
+    let to_bob = controller
+        .fragment_factory()
+        .transaction(&alice, &bob, &mut ledger, 100);
+    let to_clarice = controller
+        .fragment_factory()
+        .transaction(&alice, &clarice, &mut ledger, 100);
+
+    let selection = FragmentSelector::new(ledger.date())
+        .select(&ledger.ledger, vec![to_bob.clone(), to_clarice]);
+
+    assert_eq!(selection.selected, vec![to_bob]);
+    assert_eq!(selection.rejected.len(), 1);
+    assert!(selection.left_over.is_empty());
+}
+
+#[test]
+pub fn dependent_transactions_are_selected_in_any_order() {
+    let (mut ledger, controller) = prepare_scenario()
+        .with_config(ConfigBuilder::new().with_fee(LinearFee::new(1, 1, 1)))
+        .with_initials(vec![wallet("Alice").with(1_000), wallet("Bob").with(1_000)])
+        .build()
+        .unwrap();
+    let mut alice = controller.wallet("Alice").unwrap();
+    let bob = controller.wallet("Bob").unwrap();
+
+    let first = controller
+        .fragment_factory()
+        .transaction(&alice, &bob, &mut ledger, 100);
+    alice.confirm_transaction();
+    let second = controller
+        .fragment_factory()
+        .transaction(&alice, &bob, &mut ledger, 100);
+
+    let selection = FragmentSelector::new(ledger.date())
+        .select(&ledger.ledger, vec![second.clone(), first.clone()]);
+
+    assert_eq!(selection.selected, vec![first, second]);
+    assert!(selection.rejected.is_empty());
+}
+
+#[test]
+pub fn higher_fee_fragment_wins_conflict() {
+    let (mut ledger, controller) = prepare_scenario()
+        .with_config(ConfigBuilder::new().with_fee(LinearFee::new(1, 1, 10)))
+        .with_initials(vec![
+            wallet("Alice").with(1_000),
+            wallet("Bob").with(1_000).owns("stake_pool"),
+        ])
+        .build()
+        .unwrap();
+    let alice = controller.wallet("Alice").unwrap();
+    let bob = controller.wallet("Bob").unwrap();
+    let stake_pool = controller.stake_pool("stake_pool").unwrap();
+
+    let transaction = controller
+        .fragment_factory()
+        .transaction(&alice, &bob, &mut ledger, 100);
+    let delegation = controller
+        .fragment_factory()
+        .delegation(ledger.date(), &alice, &stake_pool);
+
+    let selection = FragmentSelector::new(ledger.date())
+        .select(&ledger.ledger, vec![transaction, delegation.clone()]);
+
+    assert_eq!(selection.selected, vec![delegation]);
+    assert_eq!(selection.rejected.len(), 1);
+}
+
+#[test]
+pub fn fragments_over_max_size_are_left_over() {
+    let (mut ledger, controller) = prepare_scenario()
+        .with_config(ConfigBuilder::new().with_fee(LinearFee::new(1, 1, 1)))
+        .with_initials(vec![wallet("Alice").with(1_000), wallet("Bob").with(1_000)])
+        .build()
+        .unwrap();
+    let alice = controller.wallet("Alice").unwrap();
+    let bob = controller.wallet("Bob").unwrap();
+
+    let transaction = controller
+        .fragment_factory()
+        .transaction(&alice, &bob, &mut ledger, 100);
+
+    let selection = FragmentSelector::new(ledger.date())
+        .with_max_size(1)
+        .select(&ledger.ledger, vec![transaction.clone()]);
+
+    assert!(selection.selected.is_empty());
+    assert_eq!(selection.left_over, vec![transaction]);
+}
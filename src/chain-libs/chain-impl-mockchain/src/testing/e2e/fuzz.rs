@@ -0,0 +1,25 @@
+use crate::testing::{
+    fuzz::{fuzz_ledger, FragmentFuzzer},
+    ledger::ConfigBuilder,
+};
+
+#[test]
+pub fn ledger_does_not_panic_on_edge_case_fragments() {
+    for seed in 0..4 {
+        fuzz_ledger(seed, 25);
+    }
+}
+
+#[test]
+pub fn fragment_fuzzer_is_deterministic() {
+    let mut first = FragmentFuzzer::new(42);
+    let mut second = FragmentFuzzer::new(42);
+    let test_ledger = first.ledger(ConfigBuilder::new());
+
+    for _ in 0..10 {
+        let expected = first.next_fragment(&test_ledger);
+        let actual = second.next_fragment(&test_ledger);
+        assert_eq!(expected.case, actual.case);
+        assert_eq!(expected.fragment, actual.fragment);
+    }
+}
@@ -3,6 +3,8 @@ pub mod evm_mapping;
 #[cfg(feature = "evm")]
 pub mod evm_transaction;
 pub mod fees;
+pub mod fragment_selection;
+pub mod fuzz;
 pub mod management_threshold;
 pub mod mint_token;
 pub mod owner_delegation;
@@ -1,7 +1,9 @@
 use crate::{
+    account,
     certificate::PoolId,
     config::RewardParams,
     fee::LinearFee,
+    ledger::Ledger,
     rewards::Ratio,
     testing::{
         builders::StakePoolBuilder,
@@ -136,6 +138,55 @@ pub fn rewards_owners_split() {
         .has_value(&Value(1003));
 }
 
+#[test]
+pub fn rewards_report_kept_in_ledger() {
+    let (mut ledger, controller) = prepare_scenario()
+        .with_config(
+            ConfigBuilder::new()
+                .with_rewards(Value(100))
+                .with_treasury(Value(0))
+                .with_rewards_params(RewardParams::Linear {
+                    constant: 10,
+                    ratio: Ratio {
+                        numerator: 1,
+                        denominator: NonZeroU64::new(1).unwrap(),
+                    },
+                    epoch_start: 0,
+                    epoch_rate: NonZeroU32::new(1).unwrap(),
+                }),
+        )
+        .with_initials(vec![wallet("Alice").with(1_000).owns("stake_pool")])
+        .with_stake_pools(vec![stake_pool("stake_pool").tax_ratio(1, 1)])
+        .build()
+        .unwrap();
+
+    let stake_pool = controller.stake_pool("stake_pool").unwrap();
+    let alice = controller.wallet("Alice").unwrap();
+
+    assert!(ledger.ledger.rewards_report().is_none());
+
+    assert!(ledger.apply_empty_praos_block(&stake_pool).is_ok());
+    ledger.distribute_rewards().unwrap();
+
+    let report = ledger.ledger.rewards_report().unwrap();
+    assert_eq!(report.epoch, 1);
+    assert_eq!(report.info.drawn, Value(9));
+    assert_eq!(
+        report.info.stake_pools.get(&stake_pool.id()),
+        Some(&(Value(9), Value::zero()))
+    );
+    assert_eq!(
+        report
+            .info
+            .accounts
+            .get(&account::Identifier::from(alice.public_key())),
+        Some(&Value(9))
+    );
+
+    let restored: Result<Ledger, _> = ledger.ledger.iter().collect();
+    assert!(restored.unwrap() == ledger.ledger);
+}
+
 #[test]
 pub fn rewards_owners_uneven_split() {
     let (mut ledger, controller) = prepare_scenario()
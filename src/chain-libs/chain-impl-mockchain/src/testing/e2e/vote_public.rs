@@ -4,6 +4,7 @@ use crate::{
     fee::{LinearFee, PerCertificateFee, PerVoteCertificateFee},
     header::BlockDate,
     testing::{
+        data::AddressData,
         ledger::ConfigBuilder,
         scenario::{prepare_scenario, proposal, vote_plan, wallet},
         verifiers::LedgerStateVerifier,
@@ -11,6 +12,7 @@ use crate::{
     value::Value,
     vote::Choice,
 };
+use chain_addr::Discrimination;
 use core::num::NonZeroU64;
 
 const ALICE: &str = "Alice";
@@ -76,6 +78,126 @@ pub fn vote_cast_action_transfer_to_rewards() {
         .has_remaining_rewards_equals_to(&Value(1100));
 }
 
+#[test]
+pub fn vote_cast_action_transfer_to_account() {
+    let favorable = Choice::new(1);
+    let destination = AddressData::account(Discrimination::Test);
+
+    let voting_token = TokenName::try_from(vec![0u8; TOKEN_NAME_MAX_SIZE]).unwrap();
+    let (mut ledger, controller) = prepare_scenario()
+        .with_config(
+            ConfigBuilder::new()
+                .with_fee(LinearFee::new(1, 1, 1))
+                .with_treasury(Value(1000)),
+        )
+        .with_initials(vec![wallet(ALICE)
+            .with(1_000)
+            .with_token(voting_token, 1_000)
+            .owns(STAKE_POOL)
+            .committee_member()])
+        .with_vote_plans(vec![vote_plan(VOTE_PLAN)
+            .owner(ALICE)
+            .consecutive_epoch_dates()
+            .with_proposal(
+                proposal(VoteTestGen::external_proposal_id())
+                    .options(3)
+                    .action_transfer_to_account(100, destination.to_id()),
+            )])
+        .build()
+        .unwrap();
+
+    let mut alice = controller.wallet(ALICE).unwrap();
+    let vote_plan = controller.vote_plan(VOTE_PLAN).unwrap();
+    let proposal = vote_plan.proposal(0);
+
+    controller
+        .cast_vote_public(&alice, &vote_plan, &proposal.id(), favorable, &mut ledger)
+        .unwrap();
+    alice.confirm_transaction();
+
+    ledger.fast_forward_to(BlockDate {
+        epoch: 1,
+        slot_id: 1,
+    });
+
+    controller
+        .tally_vote_public(&alice, &vote_plan, &mut ledger)
+        .unwrap();
+
+    let mut verifier = LedgerStateVerifier::new(ledger.into());
+    verifier
+        .info("treasury value is transferred to the destination account")
+        .account_has_expected_balance(destination, Value(100));
+    verifier.pots().has_treasury_equals_to(&Value(900));
+}
+
+#[test]
+pub fn vote_cast_tally_50_percent_transfer_to_account_rejected() {
+    let rejection = Choice::new(2);
+    let destination = AddressData::account(Discrimination::Test);
+
+    let voting_token = TokenName::try_from(vec![0u8; TOKEN_NAME_MAX_SIZE]).unwrap();
+    let (mut ledger, controller) = prepare_scenario()
+        .with_config(
+            ConfigBuilder::new()
+                .with_fee(LinearFee::new(1, 1, 1))
+                .with_treasury(Value(1000)),
+        )
+        .with_initials(vec![
+            wallet(ALICE)
+                .with(1_000)
+                .with_token(voting_token.clone(), 1_000)
+                .owns(STAKE_POOL)
+                .committee_member(),
+            wallet(BOB)
+                .with(1_000)
+                .with_token(voting_token, 1_000)
+                .delegates_to(STAKE_POOL)
+                .committee_member(),
+        ])
+        .with_vote_plans(vec![vote_plan(VOTE_PLAN)
+            .owner(ALICE)
+            .consecutive_epoch_dates()
+            .with_proposal(
+                proposal(VoteTestGen::external_proposal_id())
+                    .options(3)
+                    .action_transfer_to_account(100, destination.to_id()),
+            )])
+        .build()
+        .unwrap();
+
+    let mut alice = controller.wallet(ALICE).unwrap();
+    let mut bob = controller.wallet(BOB).unwrap();
+
+    let vote_plan = controller.vote_plan(VOTE_PLAN).unwrap();
+    let proposal = vote_plan.proposal(0);
+
+    controller
+        .cast_vote_public(&alice, &vote_plan, &proposal.id(), rejection, &mut ledger)
+        .unwrap();
+    alice.confirm_transaction();
+    controller
+        .cast_vote_public(&bob, &vote_plan, &proposal.id(), rejection, &mut ledger)
+        .unwrap();
+    bob.confirm_transaction();
+
+    ledger.fast_forward_to(BlockDate {
+        epoch: 1,
+        slot_id: 1,
+    });
+
+    controller
+        .tally_vote_public(&bob, &vote_plan, &mut ledger)
+        .unwrap();
+
+    let mut verifier = LedgerStateVerifier::new(ledger.into());
+    verifier
+        .info("treasury is left untouched")
+        .pots()
+        .has_treasury_equals_to(&Value(1000));
+    verifier.account(destination).does_not_exist();
+}
+
 #[test]
 pub fn vote_cast_action_action_parameters_no_op() {
     let favorable = Choice::new(1);
@@ -0,0 +1,357 @@
+//! Seeded generation of well formed fragments sitting on the edges of the
+//! ledger rules, to check the ledger never panics whatever it is given.
+//!
+//! The same seed always generates the same sequence of fragments, so a
+//! failure found with a random seed can be replayed.
+use crate::{
+    certificate::{
+        Anchor, Certificate, PoolPermissions, PoolRegistration, Proposal, Proposals, PushProposal,
+        VoteAction, VotePlan, ANCHOR_COMMITMENT_SIZE, ANCHOR_TAG_MAX_SIZE,
+    },
+    chaintypes::HeaderId,
+    date::BlockDate,
+    fee::{FeeAlgorithm, LinearFee},
+    fragment::Fragment,
+    key::{EitherEd25519SecretKey, GenesisPraosLeader},
+    ledger::check::{
+        CHECK_POOL_REG_MAXIMUM_OPERATORS, CHECK_POOL_REG_MAXIMUM_OWNERS, CHECK_TX_MAXIMUM_INPUTS,
+        CHECK_TX_MAXIMUM_OUTPUTS,
+    },
+    rewards::{Ratio, TaxType},
+    testing::{
+        builders::{TestTxCertBuilder, WitnessMode},
+        data::{AddressData, AddressDataValue, Wallet},
+        ledger::{ConfigBuilder, LedgerBuilder, TestLedger},
+    },
+    tokens::{
+        identifier::TokenIdentifier,
+        name::{TokenName, TOKEN_NAME_MAX_SIZE},
+        policy_hash::{PolicyHash, POLICY_HASH_SIZE},
+    },
+    transaction::{AccountIdentifier, NoExtra, Output, TxBuilder, Witness},
+    value::Value,
+    vote,
+};
+use chain_addr::{Address, Discrimination, Kind};
+use chain_crypto::{
+    digest::DigestOf, Ed25519, Ed25519Extended, RistrettoGroup2HashDh, SecretKey, SumEd25519_12,
+};
+use chain_time::DurationSeconds;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha20Rng;
+use rand_core::RngCore;
+use std::{
+    num::NonZeroU64,
+    panic::{self, AssertUnwindSafe},
+};
+use typed_bytes::ByteBuilder;
+
+/// Initial funds of each of the generated wallets
+const WALLET_VALUE: u64 = 1_000_000;
+
+/// Kind of edge the generated fragment sits on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FuzzCase {
+    /// transaction with the maximum number of inputs, each with its witness
+    MaxWitnesses,
+    /// transaction with outputs of zero value next to outputs of one
+    ZeroValueOutputs,
+    /// transaction with outputs whose total overflows
+    OverflowingOutputs,
+    /// vote plan with the maximum number of proposals and options
+    HugeVotePlan,
+    /// stake pool registration with the maximum number of owners and operators
+    HugePoolRegistration,
+    /// anchor with the longest tag
+    MaxAnchor,
+}
+
+impl FuzzCase {
+    pub const ALL: [FuzzCase; 6] = [
+        FuzzCase::MaxWitnesses,
+        FuzzCase::ZeroValueOutputs,
+        FuzzCase::OverflowingOutputs,
+        FuzzCase::HugeVotePlan,
+        FuzzCase::HugePoolRegistration,
+        FuzzCase::MaxAnchor,
+    ];
+}
+
+#[derive(Debug, Clone)]
+pub struct FuzzFragment {
+    pub case: FuzzCase,
+    pub fragment: Fragment,
+    /// indexes of the wallets spending from their account in the fragment
+    spenders: Vec<usize>,
+}
+
+/// Deterministic generator of edge case fragments, spending from its own
+/// set of wallets. The wallets have to be funded in the ledger the
+/// fragments are applied to, see [`FragmentFuzzer::ledger`].
+pub struct FragmentFuzzer {
+    rng: ChaCha20Rng,
+    wallets: Vec<Wallet>,
+}
+
+impl FragmentFuzzer {
+    pub fn new(seed: u64) -> Self {
+        let mut rng = ChaCha20Rng::seed_from_u64(seed);
+        let wallets = (0..CHECK_TX_MAXIMUM_INPUTS)
+            .map(|_| {
+                let secret_key = SecretKey::<Ed25519Extended>::generate(&mut rng);
+                let address = Address(Discrimination::Test, Kind::Account(secret_key.to_public()));
+                Wallet::from_address_data_value(AddressDataValue::new(
+                    AddressData::new(
+                        EitherEd25519SecretKey::Extended(secret_key),
+                        Default::default(),
+                        address,
+                    ),
+                    Value(WALLET_VALUE),
+                ))
+            })
+            .collect();
+        Self { rng, wallets }
+    }
+
+    pub fn wallets(&self) -> &[Wallet] {
+        &self.wallets
+    }
+
+    /// Ledger funding the wallets of the fuzzer
+    pub fn ledger(&self, config: ConfigBuilder) -> TestLedger {
+        let outputs: Vec<Output<Address>> = self.wallets.iter().map(Wallet::make_output).collect();
+        outputs
+            .chunks(CHECK_TX_MAXIMUM_OUTPUTS.into())
+            .fold(LedgerBuilder::from_config(config), |builder, outputs| {
+                builder.prefill_outputs(outputs)
+            })
+            .build()
+            .expect("cannot build the fuzzing ledger")
+    }
+
+    pub fn next_fragment(&mut self, test_ledger: &TestLedger) -> FuzzFragment {
+        let case = FuzzCase::ALL[self.rng.gen_range(0..FuzzCase::ALL.len())];
+        self.fragment(case, test_ledger)
+    }
+
+    pub fn fragment(&mut self, case: FuzzCase, test_ledger: &TestLedger) -> FuzzFragment {
+        let block0_hash = test_ledger.block0_hash;
+        let fee = test_ledger.fee();
+        match case {
+            FuzzCase::MaxWitnesses => {
+                let spenders: Vec<usize> = (0..self.wallets.len()).collect();
+                let values: Vec<u64> = spenders
+                    .iter()
+                    .map(|_| self.rng.gen_range(1..=WALLET_VALUE))
+                    .collect();
+                let total = values.iter().sum::<u64>();
+                let output =
+                    total.saturating_sub(fee.calculate(None, CHECK_TX_MAXIMUM_INPUTS, 1).0);
+                let outputs = [(self.random_wallet(), output)];
+                self.transaction(case, &block0_hash, &spenders, &values, &outputs)
+            }
+            FuzzCase::ZeroValueOutputs => {
+                let spender = self.random_wallet();
+                let value = fee.calculate(None, 1, 3).0 + 1;
+                let outputs = [
+                    (self.random_wallet(), 0),
+                    (self.random_wallet(), 1),
+                    (spender, 0),
+                ];
+                self.transaction(case, &block0_hash, &[spender], &[value], &outputs)
+            }
+            FuzzCase::OverflowingOutputs => {
+                let spender = self.random_wallet();
+                let value = self.rng.gen_range(1..=WALLET_VALUE);
+                let outputs = [(self.random_wallet(), u64::MAX), (self.random_wallet(), 1)];
+                self.transaction(case, &block0_hash, &[spender], &[value], &outputs)
+            }
+            FuzzCase::HugeVotePlan => {
+                let funder = self.random_wallet();
+                let certificate = self.huge_vote_plan().into();
+                self.certificate(case, &block0_hash, fee, &[funder], &certificate)
+            }
+            FuzzCase::HugePoolRegistration => {
+                let owners: Vec<usize> = (0..CHECK_POOL_REG_MAXIMUM_OWNERS).collect();
+                let certificate = self.huge_pool_registration(&owners).into();
+                self.certificate(case, &block0_hash, fee, &owners, &certificate)
+            }
+            FuzzCase::MaxAnchor => {
+                let funder = self.random_wallet();
+                let mut commitment = [0u8; ANCHOR_COMMITMENT_SIZE];
+                self.rng.fill_bytes(&mut commitment);
+                let tag = self.bytes(ANCHOR_TAG_MAX_SIZE);
+                let certificate = Anchor::new(commitment, Some(tag))
+                    .expect("anchor tag of the maximum size")
+                    .into();
+                self.certificate(case, &block0_hash, fee, &[funder], &certificate)
+            }
+        }
+    }
+
+    /// Records the fragment was accepted, the next fragments of the
+    /// spending wallets have to use their next spending counter
+    pub fn confirm(&mut self, fuzz_fragment: &FuzzFragment) {
+        for spender in &fuzz_fragment.spenders {
+            self.wallets[*spender].confirm_transaction();
+        }
+    }
+
+    fn random_wallet(&mut self) -> usize {
+        self.rng.gen_range(0..self.wallets.len())
+    }
+
+    fn bytes(&mut self, len: usize) -> Vec<u8> {
+        let mut bytes = vec![0u8; len];
+        self.rng.fill_bytes(&mut bytes);
+        bytes
+    }
+
+    /// `outputs` are the indexes of the receiving wallets and the values
+    fn transaction(
+        &self,
+        case: FuzzCase,
+        block0_hash: &HeaderId,
+        spenders: &[usize],
+        values: &[u64],
+        outputs: &[(usize, u64)],
+    ) -> FuzzFragment {
+        let inputs: Vec<_> = spenders
+            .iter()
+            .zip(values)
+            .map(|(spender, value)| self.wallets[*spender].make_input_with_value(Value(*value)))
+            .collect();
+        let outputs: Vec<_> = outputs
+            .iter()
+            .map(|(receiver, value)| self.wallets[*receiver].make_output_with_value(Value(*value)))
+            .collect();
+        let builder = TxBuilder::new()
+            .set_payload(&NoExtra)
+            .set_expiry_date(BlockDate::first().next_epoch())
+            .set_ios(&inputs, &outputs);
+        let witnesses: Vec<Witness> = spenders
+            .iter()
+            .map(|spender| {
+                self.wallets[*spender]
+                    .clone()
+                    .make_witness(block0_hash, builder.get_auth_data_for_witness())
+            })
+            .collect();
+        let tx = builder.set_witnesses(&witnesses).set_payload_auth(&());
+        FuzzFragment {
+            case,
+            fragment: Fragment::Transaction(tx),
+            spenders: spenders.to_vec(),
+        }
+    }
+
+    /// The first signer funds the transaction
+    fn certificate(
+        &self,
+        case: FuzzCase,
+        block0_hash: &HeaderId,
+        fee: LinearFee,
+        signers: &[usize],
+        certificate: &Certificate,
+    ) -> FuzzFragment {
+        let fragment = TestTxCertBuilder::new(*block0_hash, fee).make_transaction(
+            BlockDate::first().next_epoch(),
+            signers.iter().map(|signer| &self.wallets[*signer]),
+            certificate,
+            WitnessMode::Default,
+        );
+        FuzzFragment {
+            case,
+            fragment,
+            spenders: signers[..1].to_vec(),
+        }
+    }
+
+    fn huge_vote_plan(&mut self) -> VotePlan {
+        let mut proposals = Proposals::new();
+        while !proposals.full() {
+            let external_id = DigestOf::digest_byteslice(
+                &ByteBuilder::new()
+                    .bytes(&self.bytes(32))
+                    .finalize()
+                    .as_byteslice(),
+            );
+            // the maximum number of options of a proposal
+            let options = vote::Options::new_length(16).unwrap();
+            let proposal = Proposal::new(external_id, options, VoteAction::OffChain);
+            assert_eq!(PushProposal::Success, proposals.push(proposal));
+        }
+
+        let mut policy_hash = [0u8; POLICY_HASH_SIZE];
+        self.rng.fill_bytes(&mut policy_hash);
+        VotePlan::new(
+            BlockDate::from_epoch_slot_id(1, 0),
+            BlockDate::from_epoch_slot_id(2, 0),
+            BlockDate::from_epoch_slot_id(3, 0),
+            proposals,
+            vote::PayloadType::Public,
+            Vec::new(),
+            TokenIdentifier {
+                policy_hash: PolicyHash::from(policy_hash),
+                token_name: TokenName::try_from(self.bytes(TOKEN_NAME_MAX_SIZE)).unwrap(),
+            },
+        )
+    }
+
+    fn huge_pool_registration(&mut self, owners: &[usize]) -> PoolRegistration {
+        let operators = (0..CHECK_POOL_REG_MAXIMUM_OPERATORS)
+            .map(|_| SecretKey::<Ed25519>::generate(&mut self.rng).to_public())
+            .collect();
+        let management_threshold = self.rng.gen_range(1..=owners.len() as u8);
+        PoolRegistration {
+            serial: self.rng.gen(),
+            owners: owners
+                .iter()
+                .map(|owner| self.wallets[*owner].public_key())
+                .collect(),
+            operators,
+            start_validity: DurationSeconds::from(u64::from(self.rng.gen::<u32>())).into(),
+            permissions: PoolPermissions::new(management_threshold),
+            rewards: TaxType {
+                fixed: Value(u64::MAX),
+                ratio: Ratio {
+                    numerator: 1,
+                    denominator: NonZeroU64::new(1).unwrap(),
+                },
+                max_limit: NonZeroU64::new(u64::MAX),
+            },
+            reward_account: Some(AccountIdentifier::Single(
+                self.wallets[owners[0]].public_key().into(),
+            )),
+            keys: GenesisPraosLeader {
+                kes_public_key: SecretKey::<SumEd25519_12>::generate(&mut self.rng).to_public(),
+                vrf_public_key: SecretKey::<RistrettoGroup2HashDh>::generate(&mut self.rng)
+                    .to_public(),
+            },
+        }
+    }
+}
+
+/// Applies `iterations` fragments generated from the seed to a ledger,
+/// panicking with the seed and the iteration if applying one panics.
+/// Rejected fragments are expected, the ledger just has to return an error.
+pub fn fuzz_ledger(seed: u64, iterations: usize) {
+    let mut fuzzer = FragmentFuzzer::new(seed);
+    let mut test_ledger = fuzzer.ledger(ConfigBuilder::new().with_fee(LinearFee::new(1, 1, 1)));
+
+    for iteration in 0..iterations {
+        let fuzz_fragment = fuzzer.next_fragment(&test_ledger);
+        let date = test_ledger.date();
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            test_ledger.apply_fragment(&fuzz_fragment.fragment, date)
+        }));
+        match result {
+            Ok(Ok(())) => fuzzer.confirm(&fuzz_fragment),
+            Ok(Err(_)) => {}
+            Err(_) => panic!(
+                "ledger panicked applying a {:?} fragment (seed {}, iteration {})",
+                fuzz_fragment.case, seed, iteration
+            ),
+        }
+    }
+}
@@ -59,6 +59,9 @@ pub struct ConfigBuilder {
     consensus_version: ConsensusVersion,
     pool_capping_ratio: Ratio,
     transaction_max_expiry_epochs: Option<u8>,
+    max_fragment_size: Option<u32>,
+    voting_power_snapshot: bool,
+    script_addresses: bool,
     #[cfg(feature = "evm")]
     evm_params: chain_evm::Config,
 }
@@ -103,6 +106,9 @@ impl ConfigBuilder {
             block0_date: Block0Date(0),
             consensus_version: ConsensusVersion::Bft,
             transaction_max_expiry_epochs: None,
+            max_fragment_size: None,
+            voting_power_snapshot: false,
+            script_addresses: false,
             #[cfg(feature = "evm")]
             evm_params: chain_evm::Config::default(),
         }
@@ -218,6 +224,21 @@ impl ConfigBuilder {
         self
     }
 
+    pub fn with_max_fragment_size(mut self, max_fragment_size: u32) -> Self {
+        self.max_fragment_size = Some(max_fragment_size);
+        self
+    }
+
+    pub fn with_voting_power_snapshot(mut self, voting_power_snapshot: bool) -> Self {
+        self.voting_power_snapshot = voting_power_snapshot;
+        self
+    }
+
+    pub fn with_script_addresses(mut self, script_addresses: bool) -> Self {
+        self.script_addresses = script_addresses;
+        self
+    }
+
     #[cfg(feature = "evm")]
     pub fn with_evm_params(mut self, params: chain_evm::Config) -> Self {
         self.evm_params = params;
@@ -283,6 +304,18 @@ impl ConfigBuilder {
             ));
         }
 
+        if let Some(max_fragment_size) = self.max_fragment_size {
+            ie.push(ConfigParam::MaxFragmentSize(max_fragment_size));
+        }
+
+        if self.voting_power_snapshot {
+            ie.push(ConfigParam::VotingPowerSnapshot(true));
+        }
+
+        if self.script_addresses {
+            ie.push(ConfigParam::ScriptAddresses(true));
+        }
+
         for committee_id in self.committees_ids {
             ie.push(ConfigParam::AddCommitteeId(committee_id));
         }
@@ -360,7 +393,7 @@ impl LedgerBuilder {
 
     // add a fragment that pre-fill the address with a specific value at ledger start
     pub fn prefill_address(self, address: Address, value: Value) -> Self {
-        self.prefill_output(Output { address, value })
+        self.prefill_output(Output::from_address(address, value))
     }
 
     pub fn prefill_output(self, output: Output<Address>) -> Self {
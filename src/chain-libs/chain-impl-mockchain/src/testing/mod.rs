@@ -3,6 +3,7 @@ pub mod builders;
 pub mod data;
 #[cfg(test)]
 pub mod e2e;
+pub mod fuzz;
 mod gen;
 pub mod ledger;
 pub mod scenario;
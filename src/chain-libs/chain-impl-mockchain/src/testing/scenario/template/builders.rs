@@ -1,4 +1,5 @@
 use super::{StakePoolTemplate, WalletTemplate};
+use crate::account::Identifier;
 use crate::certificate::VoteAction;
 use crate::key::EitherEd25519SecretKey;
 use crate::ledger::governance::{ParametersGovernanceAction, TreasuryGovernanceAction};
@@ -400,6 +401,16 @@ impl ProposalDefBuilder {
         self
     }
 
+    pub fn action_transfer_to_account(&mut self, value: u64, destination: Identifier) -> &mut Self {
+        self.action_type = VoteAction::Treasury {
+            action: TreasuryGovernanceAction::TransferToAccount {
+                value: Value(value),
+                destination,
+            },
+        };
+        self
+    }
+
     pub fn action_parameters_no_op(&mut self) -> &mut Self {
         self.action_type = VoteAction::Parameters {
             action: ParametersGovernanceAction::NoOp,
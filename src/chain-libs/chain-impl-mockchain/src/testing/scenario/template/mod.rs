@@ -216,6 +216,9 @@ impl VotePlanDef {
                     TreasuryGovernanceAction::TransferToRewards { value } => {
                         proposal_builder.action_rewards_add(value.0);
                     }
+                    TreasuryGovernanceAction::TransferToAccount { value, destination } => {
+                        proposal_builder.action_transfer_to_account(value.0, destination.clone());
+                    }
                     TreasuryGovernanceAction::NoOp => {
                         unimplemented!();
                     }
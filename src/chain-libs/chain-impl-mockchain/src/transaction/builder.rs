@@ -140,8 +140,7 @@ impl<P> TxBuilderState<SetIOs<P>> {
         self.tstruct.outputs = self.current_pos();
 
         for o in outputs {
-            self.data.extend_from_slice(&o.address.to_bytes());
-            self.data.extend_from_slice(&o.value.bytes());
+            self.data.extend_from_slice(&o.bytes());
         }
 
         TxBuilderState {
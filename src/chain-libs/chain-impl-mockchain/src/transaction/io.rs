@@ -113,7 +113,7 @@ impl InputOutputBuilder {
         if self.outputs.len() == 255 {
             return Err(Error::TxTooManyOutputs);
         }
-        self.outputs.push(Output { address, value });
+        self.outputs.push(Output::from_address(address, value));
         Ok(())
     }
 
@@ -250,7 +250,7 @@ impl InputOutputBuilder {
                 match self.balance(fee) {
                     Ok(Balance::Positive(value)) => {
                         let _ = self.outputs.pop();
-                        let output = Output { address, value };
+                        let output = Output::from_address(address, value);
                         self.outputs.push(output.clone());
                         Ok((Balance::Zero, vec![output], self.build()))
                     }
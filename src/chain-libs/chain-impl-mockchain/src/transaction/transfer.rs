@@ -1,3 +1,4 @@
+use crate::date::BlockDate;
 use crate::legacy::OldAddress;
 use crate::value::*;
 use chain_core::{
@@ -5,31 +6,108 @@ use chain_core::{
     property::{Deserialize, ReadError},
 };
 
+/// Bit set in the first byte of a serialized output address to signal
+/// that the output is time-locked. The bit is never set for a valid
+/// address kind, so outputs without a time lock keep their encoding.
+const OUTPUT_TIME_LOCK_FLAG: u8 = 0b0100_0000;
+
 /// Information how tokens are spent.
 /// A value of tokens is sent to the address.
+///
+/// When `locked_until` is set, the output cannot be spent by a transaction
+/// applied before the given date.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Output<Address> {
     pub address: Address,
     pub value: Value,
+    pub locked_until: Option<BlockDate>,
 }
 
 impl<Address> Output<Address> {
     pub fn from_address(address: Address, value: Value) -> Self {
-        Output { address, value }
+        Output {
+            address,
+            value,
+            locked_until: None,
+        }
+    }
+
+    pub fn time_locked(address: Address, value: Value, locked_until: BlockDate) -> Self {
+        Output {
+            address,
+            value,
+            locked_until: Some(locked_until),
+        }
+    }
+
+    /// Check the output time lock (if any) is released at the given date
+    pub fn is_spendable_at(&self, date: BlockDate) -> bool {
+        self.locked_until
+            .map_or(true, |locked_until| date >= locked_until)
     }
 }
 
-impl<Address: Deserialize> Deserialize for Output<Address> {
+impl Output<chain_addr::Address> {
+    pub fn bytes(&self) -> Vec<u8> {
+        let mut bytes = self.address.to_bytes();
+        if let Some(locked_until) = self.locked_until {
+            bytes[0] |= OUTPUT_TIME_LOCK_FLAG;
+            bytes.extend_from_slice(&self.value.bytes());
+            bytes.extend_from_slice(&locked_until.epoch.to_be_bytes());
+            bytes.extend_from_slice(&locked_until.slot_id.to_be_bytes());
+        } else {
+            bytes.extend_from_slice(&self.value.bytes());
+        }
+        bytes
+    }
+}
+
+fn address_size(kind: u8) -> Result<usize, ReadError> {
+    use chain_addr::*;
+    match kind {
+        ADDR_KIND_SINGLE => Ok(ADDR_SIZE_SINGLE),
+        ADDR_KIND_GROUP => Ok(ADDR_SIZE_GROUP),
+        ADDR_KIND_ACCOUNT => Ok(ADDR_SIZE_ACCOUNT),
+        ADDR_KIND_MULTISIG => Ok(ADDR_SIZE_MULTISIG),
+        ADDR_KIND_SCRIPT => Ok(ADDR_SIZE_SCRIPT),
+        n => Err(ReadError::UnknownTag(n as u32)),
+    }
+}
+
+impl Deserialize for Output<chain_addr::Address> {
     fn deserialize<R: std::io::Read>(codec: &mut Codec<R>) -> Result<Self, ReadError> {
-        let address = Address::deserialize(codec)?;
+        let header = codec.get_u8()?;
+        let time_locked = header & OUTPUT_TIME_LOCK_FLAG != 0;
+        let header = header & !OUTPUT_TIME_LOCK_FLAG;
+
+        let mut address_bytes = vec![header];
+        address_bytes.extend(codec.get_bytes(address_size(header & 0b0111_1111)? - 1)?);
+        let address = chain_addr::Address::from_bytes(&address_bytes)
+            .map_err(|e| ReadError::StructureInvalid(e.to_string()))?;
+
         let value = Value::deserialize(codec)?;
-        Ok(Output { address, value })
+        let locked_until = if time_locked {
+            let epoch = codec.get_be_u32()?;
+            let slot_id = codec.get_be_u32()?;
+            Some(BlockDate { epoch, slot_id })
+        } else {
+            None
+        };
+        Ok(Output {
+            address,
+            value,
+            locked_until,
+        })
     }
 }
 
 impl std::fmt::Display for Output<chain_addr::Address> {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "{}.{}", self.address.base32(), self.value)
+        write!(f, "{}.{}", self.address.base32(), self.value)?;
+        if let Some(locked_until) = self.locked_until {
+            write!(f, "@{}", locked_until)?;
+        }
+        Ok(())
     }
 }
 
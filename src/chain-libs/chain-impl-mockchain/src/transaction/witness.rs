@@ -6,6 +6,7 @@ use crate::key::{
     SpendingSignature,
 };
 use crate::multisig;
+use crate::script;
 use chain_core::{
     packer::Codec,
     property::{Deserialize, DeserializeFromSlice, ReadError, Serialize, WriteError},
@@ -28,6 +29,7 @@ pub enum Witness {
         Signature<WitnessUtxoData, Ed25519>,
     ),
     Multisig(account::SpendingCounter, multisig::Witness),
+    Script(script::Witness),
 }
 
 impl PartialEq for Witness {
@@ -38,6 +40,7 @@ impl PartialEq for Witness {
                 n1 == n2 && s1.as_ref() == s2.as_ref()
             }
             (Witness::Multisig(n1, s1), Witness::Multisig(n2, s2)) => n1 == n2 && s1 == s2,
+            (Witness::Script(s1), Witness::Script(s2)) => s1 == s2,
             (Witness::OldUtxo(p1, c1, s1), Witness::OldUtxo(p2, c2, s2)) => {
                 s1.as_ref() == s2.as_ref() && c1 == c2 && p1 == p2
             }
@@ -54,6 +57,7 @@ impl std::fmt::Display for Witness {
             Witness::Account(_, _) => write!(f, "Account Witness"),
             Witness::OldUtxo(..) => write!(f, "Old UTxO Witness"),
             Witness::Multisig(_, _) => write!(f, "Multisig Witness"),
+            Witness::Script(_) => write!(f, "Script Witness"),
         }
     }
 }
@@ -141,6 +145,22 @@ impl AsRef<[u8]> for WitnessMultisigData {
     }
 }
 
+pub struct WitnessScriptData(Vec<u8>);
+
+impl WitnessScriptData {
+    pub fn new(block0: &HeaderId, transaction_id: &TransactionSignDataHash) -> Self {
+        let mut v = Vec::with_capacity(65);
+        witness_data_common(&mut v, WITNESS_TAG_SCRIPT, block0, transaction_id);
+        Self(v)
+    }
+}
+
+impl AsRef<[u8]> for WitnessScriptData {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_ref()
+    }
+}
+
 impl Witness {
     /// Creates new `Witness` value.
     pub fn new_utxo<F>(block0: &HeaderId, sign_data_hash: &TransactionSignDataHash, sign: F) -> Self
@@ -179,6 +199,18 @@ impl Witness {
         Witness::Account(spending_counter, sig)
     }
 
+    pub fn new_script<F>(
+        block0: &HeaderId,
+        sign_data_hash: &TransactionSignDataHash,
+        prove: F,
+    ) -> Self
+    where
+        F: FnOnce(&WitnessScriptData) -> script::Witness,
+    {
+        let wsd = WitnessScriptData::new(block0, sign_data_hash);
+        Witness::Script(prove(&wsd))
+    }
+
     pub fn to_bytes(&self) -> Vec<u8> {
         self.serialize_as_vec()
             .expect("memory serialize is expected to just work")
@@ -189,6 +221,7 @@ const WITNESS_TAG_OLDUTXO: u8 = 0u8;
 const WITNESS_TAG_UTXO: u8 = 1u8;
 const WITNESS_TAG_ACCOUNT: u8 = 2u8;
 const WITNESS_TAG_MULTISIG: u8 = 3u8;
+const WITNESS_TAG_SCRIPT: u8 = 4u8;
 
 impl Serialize for Witness {
     fn serialized_size(&self) -> usize {
@@ -201,6 +234,7 @@ impl Serialize for Witness {
             Witness::Multisig(_, msig) => {
                 Codec::u8_size() + Codec::u32_size() + msig.serialized_size()
             }
+            Witness::Script(script) => Codec::u8_size() + script.serialized_size(),
         }
     }
 
@@ -226,6 +260,10 @@ impl Serialize for Witness {
                 codec.put_be_u32((*nonce).into())?;
                 msig.serialize(codec)
             }
+            Witness::Script(script) => {
+                codec.put_u8(WITNESS_TAG_SCRIPT)?;
+                script.serialize(codec)
+            }
         }
     }
 }
@@ -250,6 +288,9 @@ impl DeserializeFromSlice for Witness {
                 let msig = multisig::Witness::deserialize_from_slice(codec)?;
                 Ok(Witness::Multisig(nonce, msig))
             }
+            WITNESS_TAG_SCRIPT => {
+                script::Witness::deserialize_from_slice(codec).map(Witness::Script)
+            }
             i => Err(ReadError::UnknownTag(i as u32)),
         }
     }
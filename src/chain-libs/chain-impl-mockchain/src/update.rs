@@ -1,6 +1,7 @@
 //use crate::certificate::{verify_certificate, HasPublicKeys, SignatureRaw};
 use crate::certificate::{UpdateProposal, UpdateProposalId, UpdateVote, UpdateVoterId};
 use crate::date::BlockDate;
+use crate::rewards::RewardPolicyError;
 use crate::setting::{ActiveSlotsCoeffError, Settings};
 use imhamt::Hamt;
 use std::collections::{hash_map::DefaultHasher, HashMap};
@@ -27,7 +28,11 @@ impl UpdateState {
         let proposer_id = proposal.proposer_id();
 
         // Only proposal.changes() validation without mutating of the 'settings' variable
-        settings.try_apply(proposal.changes())?;
+        settings
+            .try_apply(proposal.changes())?
+            .reward_params()
+            .validate()
+            .map_err(Error::InvalidRewardParams)?;
 
         if !settings.bft_leaders.contains(proposer_id) {
             return Err(Error::BadProposer(proposal_id, proposer_id.clone()));
@@ -164,6 +169,7 @@ pub enum Error {
     ReadOnlySetting,
     BadBftSlotsRatio(crate::milli::Milli),
     BadConsensusGenesisPraosActiveSlotsCoeff(ActiveSlotsCoeffError),
+    InvalidRewardParams(RewardPolicyError),
 }
 impl std::fmt::Display for Error {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -222,6 +228,9 @@ impl std::fmt::Display for Error {
                 "Cannot set consensus genesis praos active slots coefficient: {}",
                 err
             ),
+            Error::InvalidRewardParams(err) => {
+                write!(f, "Cannot set the reward parameters: {}", err)
+            }
         }
     }
 }
@@ -244,8 +253,9 @@ mod tests {
     use crate::testing::serialization::serialization_bijection;
     #[cfg(test)]
     use crate::{
-        config::ConfigParam,
+        config::{ConfigParam, RewardParams},
         fragment::config::ConfigParams,
+        rewards::Ratio,
         testing::{data::LeaderPair, TestGen},
     };
     #[cfg(test)]
@@ -255,6 +265,8 @@ mod tests {
     use quickcheck::{Arbitrary, Gen};
     use quickcheck_macros::quickcheck;
     use std::iter;
+    #[cfg(test)]
+    use std::num::{NonZeroU32, NonZeroU64};
 
     impl Arbitrary for UpdateProposalState {
         fn arbitrary<G: Gen>(g: &mut G) -> Self {
@@ -323,6 +335,44 @@ mod tests {
         );
     }
 
+    #[test]
+    fn apply_proposal_with_invalid_reward_params_should_return_error() {
+        let proposal_id = TestGen::hash();
+        let block_date = BlockDate::first();
+        let ratio = Ratio {
+            numerator: 3,
+            denominator: NonZeroU64::new(2).unwrap(),
+        };
+        let config_param = ConfigParam::RewardParams(RewardParams::Halving {
+            constant: 100,
+            ratio,
+            epoch_start: 0,
+            epoch_rate: NonZeroU32::new(1).unwrap(),
+        });
+
+        let leaders = TestGen::leaders_pairs()
+            .take(5)
+            .collect::<Vec<LeaderPair>>();
+        let settings = TestGen::settings(leaders.clone());
+
+        assert_eq!(
+            apply_update_proposal(
+                UpdateState::new(),
+                proposal_id,
+                config_param,
+                &leaders[0],
+                &settings,
+                block_date,
+            ),
+            Err(Error::InvalidRewardParams(
+                RewardPolicyError::HalvingRatioAboveOne {
+                    numerator: ratio.numerator,
+                    denominator: ratio.denominator,
+                }
+            ))
+        );
+    }
+
     #[test]
     fn apply_duplicated_proposal_should_return_error() {
         // data
@@ -76,9 +76,12 @@ impl<OutAddress: Clone> TransactionUnspents<OutAddress> {
     }
 }
 
-/// Ledger of UTXO
+/// Ledger of UTXO, with the number of unspent outputs it holds
 #[derive(Clone, PartialEq, Eq)]
-pub struct Ledger<OutAddress>(Hamt<DefaultHasher, FragmentId, TransactionUnspents<OutAddress>>);
+pub struct Ledger<OutAddress>(
+    Hamt<DefaultHasher, FragmentId, TransactionUnspents<OutAddress>>,
+    usize,
+);
 
 pub struct Iter<'a, V> {
     hamt_iter: HamtIter<'a, FragmentId, TransactionUnspents<V>>,
@@ -147,6 +150,11 @@ impl<OutAddress> Ledger<OutAddress> {
     pub fn is_empty(&self) -> bool {
         self.0.is_empty()
     }
+
+    /// Number of unspent outputs, in constant time
+    pub fn len(&self) -> usize {
+        self.1
+    }
 }
 
 impl<'a, V> Iterator for Values<'a, V> {
@@ -202,7 +210,7 @@ impl<OutAddress: Clone> Default for Ledger<OutAddress> {
 impl<OutAddress: Clone> Ledger<OutAddress> {
     /// Create a new empty UTXO Ledger
     pub fn new() -> Self {
-        Ledger(Hamt::new())
+        Ledger(Hamt::new(), 0)
     }
 
     /// Add new outputs associated with a specific transaction
@@ -217,7 +225,7 @@ impl<OutAddress: Clone> Ledger<OutAddress> {
         assert!(outs.len() < 255);
         let b = TransactionUnspents::from_outputs(outs);
         let next = self.0.insert(*tid, b)?;
-        Ok(Ledger(next))
+        Ok(Ledger(next, self.1 + outs.len()))
     }
 
     /// Spend a specific index from the transaction
@@ -233,9 +241,9 @@ impl<OutAddress: Clone> Ledger<OutAddress> {
         }?;
 
         if treemap.0.is_empty() {
-            Ok((Ledger(self.0.remove(tid)?), output))
+            Ok((Ledger(self.0.remove(tid)?, self.1 - 1), output))
         } else {
-            Ok((Ledger(self.0.replace(tid, treemap)?.0), output))
+            Ok((Ledger(self.0.replace(tid, treemap)?.0, self.1 - 1), output))
         }
     }
 
@@ -258,10 +266,11 @@ impl<OutAddress: Clone> Ledger<OutAddress> {
             }
         }?;
 
+        let len = self.1 - outputs.len();
         if treemap.0.is_empty() {
-            Ok((Ledger(self.0.remove(tid)?), outputs))
+            Ok((Ledger(self.0.remove(tid)?, len), outputs))
         } else {
-            Ok((Ledger(self.0.replace(tid, treemap)?.0), outputs))
+            Ok((Ledger(self.0.replace(tid, treemap)?.0, len), outputs))
         }
     }
 }
@@ -405,6 +414,24 @@ mod tests {
         }
     }
 
+    #[quickcheck]
+    pub fn ledger_len_counts_unspent_outputs(initial_utxos: ArbitraryUtxos) -> TestResult {
+        let mut ledger = initial_utxos.fill(Ledger::new());
+        if ledger.len() != ledger.iter().count() {
+            return TestResult::error("wrong number of outputs after adding them");
+        }
+
+        for (key, value) in initial_utxos.0 {
+            for (index, _) in value.to_vec() {
+                ledger = ledger.remove(&key, index).unwrap().0;
+                if ledger.len() != ledger.iter().count() {
+                    return TestResult::error("wrong number of outputs after removing one");
+                }
+            }
+        }
+        TestResult::from_bool(ledger.len() == 0 && ledger.is_empty())
+    }
+
     #[quickcheck]
     pub fn ledger_iter_values_correctly(initial_utxos: ArbitraryUtxos) -> TestResult {
         let mut ledger = Ledger::new();
@@ -31,7 +31,34 @@ impl Value {
     where
         I: Iterator<Item = Self>,
     {
-        values.fold(Ok(Value::zero()), |acc, v| acc? + v)
+        Self::sum_with::<Checked, _>(values)
+    }
+
+    /// Adds the values, the result on overflow being decided by the policy
+    #[inline]
+    pub fn add_with<P: OverflowPolicy>(self, other: Self) -> P::Output {
+        P::add(self, other)
+    }
+
+    /// Subtracts the values, the result on underflow being decided by the policy
+    #[inline]
+    pub fn sub_with<P: OverflowPolicy>(self, other: Self) -> P::Output {
+        P::sub(self, other)
+    }
+
+    /// Multiplies the value by n, the result on overflow being decided by the policy
+    #[inline]
+    pub fn scale_with<P: OverflowPolicy>(self, n: u32) -> P::Output {
+        P::scale(self, n)
+    }
+
+    /// Sums the values, the result on overflow being decided by the policy
+    pub fn sum_with<P, I>(values: I) -> P::Output
+    where
+        P: OverflowPolicy,
+        I: Iterator<Item = Self>,
+    {
+        P::sum(values)
     }
 
     #[inline]
@@ -39,6 +66,11 @@ impl Value {
         Value(self.0.saturating_add(other.0))
     }
 
+    #[inline]
+    pub fn saturating_sub(self, other: Self) -> Self {
+        Value(self.0.saturating_sub(other.0))
+    }
+
     #[inline]
     pub fn checked_add(self, other: Self) -> Result<Self, ValueError> {
         self.0
@@ -76,6 +108,70 @@ impl Value {
     }
 }
 
+/// What happens when the result of an arithmetic operation on values does
+/// not fit in a `Value`, either above `u64::MAX` or below zero.
+///
+/// The operations of the ledger state should use [`Checked`], so that an
+/// overflow is reported as an error instead of a panic; [`Saturating`] is
+/// only fit for informational amounts, like the fees of a fragment before
+/// they are checked against the balance.
+pub trait OverflowPolicy {
+    type Output;
+
+    fn add(a: Value, b: Value) -> Self::Output;
+    fn sub(a: Value, b: Value) -> Self::Output;
+    fn scale(a: Value, n: u32) -> Self::Output;
+    fn sum<I: Iterator<Item = Value>>(values: I) -> Self::Output;
+}
+
+/// Fails with a [`ValueError`] when the result does not fit.
+#[derive(Debug, Clone, Copy)]
+pub enum Checked {}
+
+/// Clamps the result to zero or `u64::MAX`.
+#[derive(Debug, Clone, Copy)]
+pub enum Saturating {}
+
+impl OverflowPolicy for Checked {
+    type Output = Result<Value, ValueError>;
+
+    fn add(a: Value, b: Value) -> Self::Output {
+        a.checked_add(b)
+    }
+
+    fn sub(a: Value, b: Value) -> Self::Output {
+        a.checked_sub(b)
+    }
+
+    fn scale(a: Value, n: u32) -> Self::Output {
+        a.scale(n)
+    }
+
+    fn sum<I: Iterator<Item = Value>>(mut values: I) -> Self::Output {
+        values.try_fold(Value::zero(), Value::checked_add)
+    }
+}
+
+impl OverflowPolicy for Saturating {
+    type Output = Value;
+
+    fn add(a: Value, b: Value) -> Self::Output {
+        a.saturating_add(b)
+    }
+
+    fn sub(a: Value, b: Value) -> Self::Output {
+        a.saturating_sub(b)
+    }
+
+    fn scale(a: Value, n: u32) -> Self::Output {
+        Value(a.0.saturating_mul(n as u64))
+    }
+
+    fn sum<I: Iterator<Item = Value>>(values: I) -> Self::Output {
+        values.fold(Value::zero(), Value::saturating_add)
+    }
+}
+
 #[derive(Debug, Error, Clone, PartialEq, Eq)]
 pub enum ValueError {
     #[error("Value cannot be negative")]
@@ -88,12 +184,13 @@ pub enum ValueError {
     FromSliceTooBig,
 }
 
+/// Saturates on overflow, use [`Value::sum`] for a checked sum.
 impl Sum for Value {
     fn sum<I>(iter: I) -> Self
     where
         I: Iterator<Item = Self>,
     {
-        iter.fold(Value::zero(), Self::saturating_add)
+        Value::sum_with::<Saturating, _>(iter)
     }
 }
 
@@ -163,3 +260,59 @@ impl From<Value> for Stake {
         Stake::from_value(value)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_strategy::proptest;
+
+    #[proptest]
+    fn checked_add_fails_only_on_overflow(a: Value, b: Value) {
+        let exact = a.0 as u128 + b.0 as u128;
+        match a.add_with::<Checked>(b) {
+            Ok(sum) => assert_eq!(sum.0 as u128, exact),
+            Err(error) => {
+                assert_eq!(error, ValueError::Overflow);
+                assert!(exact > u64::MAX as u128);
+            }
+        }
+    }
+
+    #[proptest]
+    fn checked_sub_fails_only_when_negative(a: Value, b: Value) {
+        match a.sub_with::<Checked>(b) {
+            Ok(difference) => assert_eq!(difference.0 + b.0, a.0),
+            Err(error) => {
+                assert_eq!(error, ValueError::NegativeAmount);
+                assert!(a < b);
+            }
+        }
+    }
+
+    #[proptest]
+    fn saturating_agrees_with_checked(a: Value, b: Value, n: u32) {
+        assert_eq!(
+            a.add_with::<Saturating>(b),
+            a.add_with::<Checked>(b).unwrap_or(Value(u64::MAX))
+        );
+        assert_eq!(
+            a.sub_with::<Saturating>(b),
+            a.sub_with::<Checked>(b).unwrap_or_else(|_| Value::zero())
+        );
+        assert_eq!(
+            a.scale_with::<Saturating>(n),
+            a.scale_with::<Checked>(n).unwrap_or(Value(u64::MAX))
+        );
+    }
+
+    #[proptest]
+    fn sum_fails_only_on_overflow(values: Vec<Value>) {
+        let exact: u128 = values.iter().map(|v| v.0 as u128).sum();
+        match Value::sum(values.iter().copied()) {
+            Ok(sum) => assert_eq!(sum.0 as u128, exact),
+            Err(_) => assert!(exact > u64::MAX as u128),
+        }
+        let saturated: Value = values.into_iter().sum();
+        assert_eq!(saturated.0 as u128, exact.min(u64::MAX as u128));
+    }
+}
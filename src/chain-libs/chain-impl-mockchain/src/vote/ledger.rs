@@ -4,10 +4,14 @@ use crate::{
     certificate::{TallyProof, VoteAction, VoteCast, VotePlan, VotePlanId, VoteTally},
     date::BlockDate,
     ledger::governance::Governance,
-    vote::{CommitteeId, PayloadType, VoteError, VotePlanManager},
+    tokens::identifier::TokenIdentifier,
+    vote::{CommitteeId, PayloadType, StakeSnapshot, VoteError, VotePlanManager},
 };
 use imhamt::{Hamt, InsertError, UpdateError};
-use std::collections::{hash_map::DefaultHasher, HashSet};
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap, HashSet},
+    sync::Arc,
+};
 use thiserror::Error;
 
 #[derive(Clone, PartialEq, Eq)]
@@ -129,6 +133,39 @@ impl VotePlanLedger {
         }
     }
 
+    /// freeze the voting power of the vote plans whose vote has started at
+    /// the given date and that have no snapshot yet
+    ///
+    /// the vote plans sharing a voting token share the same snapshot.
+    #[must_use = "This function does not modify the object, the result contains the resulted new version of the vote plan ledger"]
+    pub fn take_stake_snapshots(
+        &self,
+        block_date: BlockDate,
+        token_distribution: TokenDistribution<()>,
+    ) -> Self {
+        let mut snapshots: HashMap<TokenIdentifier, Arc<StakeSnapshot>> = HashMap::new();
+        let plans = self
+            .plans
+            .iter()
+            .filter(|(_, manager)| manager.needs_stake_snapshot(block_date))
+            .fold(self.plans.clone(), |plans, (id, manager)| {
+                let voting_token = manager.plan().voting_token();
+                let snapshot = snapshots
+                    .entry(voting_token.clone())
+                    .or_insert_with(|| {
+                        Arc::new(StakeSnapshot::new(
+                            &token_distribution.clone().token(voting_token),
+                        ))
+                    })
+                    .clone();
+                plans
+                    .replace_with(id, |manager| manager.with_stake_snapshot(snapshot))
+                    .expect("the vote plan is in the ledger")
+            });
+
+        Self { plans }
+    }
+
     /// apply the committee result for the associated vote plan
     ///
     /// # Errors
@@ -8,8 +8,10 @@ use crate::{
     },
     rewards::Ratio,
     stake::Stake,
-    tokens::identifier::TokenIdentifier,
-    vote::{self, CommitteeId, Options, Tally, TallyResult, VotePlanStatus, VoteProposalStatus},
+    vote::{
+        self, CommitteeId, Options, StakeSnapshot, Tally, TallyResult, VotePlanStatus,
+        VoteProposalStatus, VotingPower,
+    },
 };
 use crate::{
     certificate::DecryptedPrivateTallyProposal,
@@ -35,6 +37,7 @@ pub struct VotePlanManager {
     plan: Arc<VotePlan>,
     committee: Arc<HashSet<CommitteeId>>,
     proposal_managers: ProposalManagers,
+    stake_snapshot: Option<Arc<StakeSnapshot>>,
 }
 
 #[derive(Clone, Eq, PartialEq, Debug)]
@@ -184,7 +187,7 @@ impl ProposalManager {
         &self,
         identifier: account::Identifier,
         payload: ValidatedPayload,
-        token_distribution: &TokenDistribution<TokenIdentifier>,
+        voting_power: &dyn VotingPower,
     ) -> Result<Self, VoteError> {
         // Part of DDoS protection: do not record a new ballot if the account already voted for this
         // proposal. This protects the system from flooding in a system with cheap/free voting
@@ -194,13 +197,7 @@ impl ProposalManager {
             .insert(identifier.clone(), ())
             .map_err(|_| VoteError::AlreadyVoted)?;
 
-        let tally = if let Some(stake) = token_distribution
-            .get_account(&identifier)
-            // we ignore the error since at this point we know that the account exists, since it
-            // was verified with the input.
-            .ok()
-            .flatten()
-        {
+        let tally = if let Some(stake) = voting_power.account(&identifier) {
             match (self.tally.clone(), payload) {
                 (IncrementalTally::Public(mut result), ValidatedPayload::Public(choice)) => {
                     result.add_vote(choice, stake)?;
@@ -309,7 +306,7 @@ impl ProposalManager {
     #[must_use = "Compute the PublicTally in a new ProposalManager, does not modify self"]
     pub fn public_tally<F>(
         &self,
-        token_distribution: &TokenDistribution<TokenIdentifier>,
+        voting_power: &dyn VotingPower,
         governance: &Governance,
         mut f: F,
     ) -> Result<Self, TallyError>
@@ -323,7 +320,7 @@ impl ProposalManager {
             }
         };
 
-        if self.check(token_distribution.get_total().into(), governance, results) {
+        if self.check(voting_power.total().into(), governance, results) {
             f(&self.action)
         }
 
@@ -340,7 +337,7 @@ impl ProposalManager {
         committee_pks: &[committee::MemberPublicKey],
         decrypted_proposal: &DecryptedPrivateTallyProposal,
         governance: &Governance,
-        token_distribution: &TokenDistribution<TokenIdentifier>,
+        voting_power: &dyn VotingPower,
         mut f: F,
     ) -> Result<Self, TallyError>
     where
@@ -377,7 +374,7 @@ impl ProposalManager {
                 })?;
         }
 
-        if self.check(token_distribution.get_total().into(), governance, &result) {
+        if self.check(voting_power.total().into(), governance, &result) {
             f(&self.action);
         }
 
@@ -529,12 +526,11 @@ impl ProposalManagers {
         &self,
         identifier: account::Identifier,
         vote_cast: ValidatedVoteCast,
-        token_distribution: &TokenDistribution<TokenIdentifier>,
+        voting_power: &dyn VotingPower,
     ) -> Result<Self, VoteError> {
         let proposal_index = vote_cast.proposal_index;
         if let Some(manager) = self.managers().get(proposal_index) {
-            let updated_manager =
-                manager.vote(identifier, vote_cast.payload, token_distribution)?;
+            let updated_manager = manager.vote(identifier, vote_cast.payload, voting_power)?;
             // only clone the array if it does make sens to do so:
             //
             // * the index exist
@@ -551,7 +547,7 @@ impl ProposalManagers {
 
     pub fn public_tally<F>(
         &self,
-        token_distribution: &TokenDistribution<TokenIdentifier>,
+        voting_power: &dyn VotingPower,
         governance: &Governance,
         mut f: F,
     ) -> Result<Self, TallyError>
@@ -562,11 +558,7 @@ impl ProposalManagers {
             Self::Public { managers } => {
                 let mut proposals = Vec::with_capacity(managers.len());
                 for proposal in managers.iter() {
-                    proposals.push(proposal.public_tally(
-                        token_distribution,
-                        governance,
-                        &mut f,
-                    )?);
+                    proposals.push(proposal.public_tally(voting_power, governance, &mut f)?);
                 }
                 Ok(Self::Public {
                     managers: proposals,
@@ -616,7 +608,7 @@ impl ProposalManagers {
         committee_pks: &[committee::MemberPublicKey],
         decrypted_tally: &DecryptedPrivateTally,
         governance: &Governance,
-        token_distribution: &TokenDistribution<TokenIdentifier>,
+        voting_power: &dyn VotingPower,
         mut f: F,
     ) -> Result<Self, VoteError>
     where
@@ -636,7 +628,7 @@ impl ProposalManagers {
                         committee_pks,
                         decrypted_proposal,
                         governance,
-                        token_distribution,
+                        voting_power,
                         &mut f,
                     )?);
                 }
@@ -664,6 +656,7 @@ impl VotePlanManager {
             plan: Arc::new(plan),
             proposal_managers,
             committee: Arc::new(committee),
+            stake_snapshot: None,
         }
     }
 
@@ -736,6 +729,34 @@ impl VotePlanManager {
         self.committee_set().contains(id)
     }
 
+    /// return true if the voting power has to be frozen at the given date,
+    /// i.e. the vote has started and no snapshot was taken yet
+    pub fn needs_stake_snapshot(&self, date: BlockDate) -> bool {
+        self.stake_snapshot.is_none() && self.can_vote(date)
+    }
+
+    /// freeze the voting power of the accounts for the rest of the vote
+    /// plan: the votes and the tally will use it instead of the stake of the
+    /// accounts at the time they are applied
+    #[must_use = "Set the snapshot in a new VotePlanManager, does not modify self"]
+    pub fn with_stake_snapshot(&self, stake_snapshot: Arc<StakeSnapshot>) -> Self {
+        Self {
+            stake_snapshot: Some(stake_snapshot),
+            ..self.clone()
+        }
+    }
+
+    fn with_voting_power<R>(
+        &self,
+        token_distribution: TokenDistribution<()>,
+        f: impl FnOnce(&dyn VotingPower) -> R,
+    ) -> R {
+        match &self.stake_snapshot {
+            Some(stake_snapshot) => f(stake_snapshot.as_ref()),
+            None => f(&token_distribution.token(self.plan.voting_token())),
+        }
+    }
+
     /// attempt to apply the vote to one of the proposals
     ///
     /// If the given identifier already had a vote, the previous vote will
@@ -779,17 +800,16 @@ impl VotePlanManager {
 
         let vote = self.proposal_managers.validate_vote(&identifier, cast)?;
 
-        let proposal_managers = self.proposal_managers.vote(
-            identifier,
-            vote,
-            &token_distribution.token(self.plan.voting_token()),
-        )?;
+        let proposal_managers = self.with_voting_power(token_distribution, |voting_power| {
+            self.proposal_managers.vote(identifier, vote, voting_power)
+        })?;
 
         Ok(Self {
             proposal_managers,
             plan: Arc::clone(&self.plan),
             id: self.id.clone(),
             committee: Arc::clone(&self.committee),
+            stake_snapshot: self.stake_snapshot.clone(),
         })
     }
 
@@ -819,17 +839,17 @@ impl VotePlanManager {
             return Err(TallyError::InvalidPrivacy.into());
         }
 
-        let proposal_managers = self.proposal_managers.public_tally(
-            &token_distribution.token(self.plan.voting_token()),
-            governance,
-            f,
-        )?;
+        let proposal_managers = self.with_voting_power(token_distribution, |voting_power| {
+            self.proposal_managers
+                .public_tally(voting_power, governance, f)
+        })?;
 
         Ok(Self {
             proposal_managers,
             plan: Arc::clone(&self.plan),
             id: self.id.clone(),
             committee: Arc::clone(&self.committee),
+            stake_snapshot: self.stake_snapshot.clone(),
         })
     }
 
@@ -858,18 +878,21 @@ impl VotePlanManager {
 
         let committee_pks = self.plan.committee_public_keys();
 
-        let proposal_managers = self.proposal_managers.finalize_private_tally(
-            committee_pks,
-            decrypted_tally,
-            governance,
-            &token_distribution.token(self.plan.voting_token()),
-            f,
-        )?;
+        let proposal_managers = self.with_voting_power(token_distribution, |voting_power| {
+            self.proposal_managers.finalize_private_tally(
+                committee_pks,
+                decrypted_tally,
+                governance,
+                voting_power,
+                f,
+            )
+        })?;
         Ok(Self {
             proposal_managers,
             plan: Arc::clone(&self.plan),
             id: self.id.clone(),
             committee: Arc::clone(&self.committee),
+            stake_snapshot: self.stake_snapshot.clone(),
         })
     }
 }
@@ -1117,6 +1140,64 @@ mod tests {
         assert!(action_hit)
     }
 
+    #[test]
+    pub fn vote_plan_manager_vote_weighted_by_stake_snapshot() {
+        let favorable = Choice::new(1);
+        let vote_plan = VotePlan::new(
+            BlockDate::from_epoch_slot_id(1, 0),
+            BlockDate::from_epoch_slot_id(2, 0),
+            BlockDate::from_epoch_slot_id(3, 0),
+            VoteTestGen::proposals(1),
+            PayloadType::Public,
+            Vec::new(),
+            TokenIdentifier {
+                policy_hash: PolicyHash::from([0u8; POLICY_HASH_SIZE]),
+                token_name: TokenName::try_from(vec![0u8; TOKEN_NAME_MAX_SIZE]).unwrap(),
+            },
+        );
+        let identifier = TestGen::identifier();
+        let account_ledger_with = |value: u64| {
+            account::Ledger::default()
+                .add_account(identifier.clone(), Value(0), ())
+                .unwrap()
+                .token_add(&identifier, vote_plan.voting_token().clone(), Value(value))
+                .unwrap()
+        };
+        let token_totals = TokenTotals::default();
+        let account_ledger_at_start = account_ledger_with(100);
+        let account_ledger_at_vote = account_ledger_with(1_000);
+
+        let mut vote_plan_manager = VotePlanManager::new(vote_plan.clone(), HashSet::new());
+        assert!(!vote_plan_manager.needs_stake_snapshot(BlockDate::from_epoch_slot_id(0, 10)));
+        assert!(vote_plan_manager.needs_stake_snapshot(BlockDate::from_epoch_slot_id(1, 0)));
+
+        let stake_snapshot = StakeSnapshot::new(
+            &TokenDistribution::new(&token_totals, &account_ledger_at_start)
+                .token(vote_plan.voting_token()),
+        );
+        vote_plan_manager = vote_plan_manager.with_stake_snapshot(Arc::new(stake_snapshot));
+        assert!(!vote_plan_manager.needs_stake_snapshot(BlockDate::from_epoch_slot_id(1, 10)));
+
+        let vote_cast = VoteCast::new(
+            vote_plan.to_id(),
+            0,
+            VoteTestGen::vote_cast_payload_for(&favorable),
+        );
+        vote_plan_manager = vote_plan_manager
+            .vote(
+                BlockDate::from_epoch_slot_id(1, 10),
+                identifier.clone(),
+                vote_cast,
+                TokenDistribution::new(&token_totals, &account_ledger_at_vote),
+            )
+            .unwrap();
+
+        match &vote_plan_manager.statuses().proposals[0].tally {
+            Tally::Public { result } => assert_eq!(result.results()[1], 100.into()),
+            _ => unreachable!(),
+        }
+    }
+
     #[test]
     pub fn vote_plan_manager_tally_invalid_committee_public() {
         let blank = Choice::new(0);
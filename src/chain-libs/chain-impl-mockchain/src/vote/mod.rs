@@ -9,6 +9,7 @@ mod ledger;
 mod manager;
 mod payload;
 mod privacy;
+mod snapshot;
 mod status;
 mod tally;
 
@@ -19,6 +20,7 @@ pub use self::{
     manager::{ValidatedPayload, VoteError, VotePlanManager},
     payload::{EncryptedVote, Payload, PayloadType, ProofOfCorrectVote, TryFromIntError},
     privacy::encrypt_vote,
+    snapshot::{StakeSnapshot, VotingPower},
     status::{VotePlanStatus, VoteProposalStatus},
     tally::{PrivateTallyState, Tally, TallyError, TallyResult, Weight},
 };
@@ -0,0 +1,64 @@
+use crate::{
+    account, ledger::token_distribution::TokenDistribution, tokens::identifier::TokenIdentifier,
+    value::Value,
+};
+use imhamt::Hamt;
+use std::collections::hash_map::DefaultHasher;
+
+/// The voting power of the accounts, in the voting token of a vote plan
+pub trait VotingPower {
+    /// the total voting power, the governance criteria of the tally are
+    /// checked against it
+    fn total(&self) -> Value;
+
+    /// the voting power of the given account, `None` if it does not hold
+    /// the voting token
+    fn account(&self, account: &account::Identifier) -> Option<Value>;
+}
+
+/// Live voting power, read from the ledger at the time of the vote and of
+/// the tally
+impl VotingPower for TokenDistribution<'_, TokenIdentifier> {
+    fn total(&self) -> Value {
+        self.get_total()
+    }
+
+    fn account(&self, account: &account::Identifier) -> Option<Value> {
+        // we ignore the error since at this point we know that the account exists, since it
+        // was verified with the input.
+        self.get_account(account).ok().flatten()
+    }
+}
+
+/// Voting power of the accounts frozen at the start of a vote plan
+///
+/// Value moved to an account after the snapshot does not give it any more
+/// voting power, and the governance criteria are checked against the total
+/// at the time of the snapshot.
+#[derive(Clone, PartialEq, Eq)]
+pub struct StakeSnapshot {
+    total: Value,
+    accounts: Hamt<DefaultHasher, account::Identifier, Value>,
+}
+
+impl StakeSnapshot {
+    pub fn new(token_distribution: &TokenDistribution<TokenIdentifier>) -> Self {
+        Self {
+            total: token_distribution.get_total(),
+            accounts: token_distribution
+                .accounts()
+                .map(|(account, value)| (account.clone(), value))
+                .collect(),
+        }
+    }
+}
+
+impl VotingPower for StakeSnapshot {
+    fn total(&self) -> Value {
+        self.total
+    }
+
+    fn account(&self, account: &account::Identifier) -> Option<Value> {
+        self.accounts.lookup(account).copied()
+    }
+}
@@ -1,4 +1,12 @@
+use std::{env, path::PathBuf};
+
 fn main() {
-    tonic_build::compile_protos("proto/node.proto").unwrap();
-    tonic_build::compile_protos("proto/watch.proto").unwrap();
+    let mut builder = tonic_build::configure();
+    if env::var_os("CARGO_FEATURE_REFLECTION").is_some() {
+        let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+        builder = builder.file_descriptor_set_path(out_dir.join("chain_network_descriptor.bin"));
+    }
+    builder
+        .compile(&["proto/node.proto", "proto/watch.proto"], &["proto"])
+        .unwrap();
 }
@@ -1,5 +1,5 @@
 use super::PushStream;
-use crate::data::{Block, BlockEvent, BlockId, BlockIds, Header, Peer};
+use crate::data::{Block, BlockEvent, BlockId, BlockIds, Header, Peer, TipUpdate};
 use crate::error::Error;
 use async_trait::async_trait;
 use futures::prelude::*;
@@ -13,6 +13,18 @@ pub trait BlockService {
     /// accepted by this node.
     async fn tip(&self) -> Result<Header, Error>;
 
+    /// The type of an asynchronous stream that provides tip updates in
+    /// response to `tip_subscription` method.
+    type TipSubscriptionStream: Stream<Item = Result<TipUpdate, Error>> + Send + Sync;
+
+    /// Serves a subscription to changes of the blockchain tip.
+    /// Resolves to a stream of tip updates, starting with the current tip,
+    /// to send to the remote client peer.
+    async fn tip_subscription(
+        &self,
+        subscriber: Peer,
+    ) -> Result<Self::TipSubscriptionStream, Error>;
+
     /// The type of an asynchronous stream that provides blocks in
     /// response to `get_blocks` method.
     type GetBlocksStream: Stream<Item = Result<Block, Error>> + Send + Sync;
@@ -1,5 +1,5 @@
 use super::PushStream;
-use crate::data::{Fragment, FragmentIds, Peer};
+use crate::data::{Fragment, FragmentEvent, FragmentIds, Peer};
 use crate::error::Error;
 use async_trait::async_trait;
 use futures::prelude::*;
@@ -29,4 +29,18 @@ pub trait FragmentService {
         subscriber: Peer,
         stream: PushStream<Fragment>,
     ) -> Result<Self::SubscriptionStream, Error>;
+
+    /// The type of outbound asynchronous streams returned by the
+    /// `fragment_subscription_with_ack` method.
+    type AckSubscriptionStream: Stream<Item = Result<FragmentEvent, Error>> + Send + Sync;
+
+    /// Same as `fragment_subscription`, except that the outbound stream
+    /// also carries an acknowledgment for each fragment received on the
+    /// inbound stream, once the fragment has been processed.
+    /// Implementations not supporting it can fail with `Code::Unimplemented`.
+    async fn fragment_subscription_with_ack(
+        &self,
+        subscriber: Peer,
+        stream: PushStream<Fragment>,
+    ) -> Result<Self::AckSubscriptionStream, Error>;
 }
@@ -0,0 +1,47 @@
+use crate::data::{CallId, Peer};
+
+use std::time::Duration;
+
+/// A request served by the node, as reported to [`Metrics`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Request {
+    /// Name of the method of the node service, e.g. `PullBlocks`.
+    pub service: &'static str,
+    /// Identifier of the call, as sent by the client or generated by
+    /// the server if the client did not send one.
+    pub call_id: CallId,
+    /// The remote peer which made the request.
+    pub peer: Peer,
+}
+
+/// Statistics of a request, reported to [`Metrics`] when it is finished.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RequestStats {
+    /// Time elapsed since the request was started.
+    pub duration: Duration,
+    /// Total size of the messages received from the peer.
+    pub bytes_received: u64,
+    /// Total size of the messages sent to the peer.
+    pub bytes_sent: u64,
+}
+
+/// Hooks to collect network statistics of the node server per peer.
+///
+/// The server calls `request_started` when it starts serving a request, and
+/// `request_finished` once the request is over. For streaming requests,
+/// the request is over when both the inbound and the outbound streams have
+/// been dropped, so the statistics cover all messages exchanged with the peer
+/// in the request. Sizes are those of the encoded protobuf messages,
+/// excluding gRPC framing and compression.
+///
+/// The hooks are called from the tasks serving the requests, so they
+/// should not block.
+pub trait Metrics: Send + Sync + 'static {
+    /// Called when the server starts serving a request.
+    fn request_started(&self, request: &Request) {
+        let _ = request;
+    }
+
+    /// Called when the server is done with a request.
+    fn request_finished(&self, request: &Request, stats: &RequestStats);
+}
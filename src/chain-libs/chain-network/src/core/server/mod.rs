@@ -1,12 +1,16 @@
 mod block;
 mod fragment;
 mod gossip;
+mod metrics;
 mod node;
 mod push;
+mod score;
 
 pub use block::BlockService;
 pub use fragment::FragmentService;
 pub use gossip::GossipService;
+pub use metrics::{Metrics, Request, RequestStats};
+pub use score::{Misbehavior, PeerScore, QuarantineDecision};
 
 pub use node::Node;
 
@@ -21,7 +21,8 @@ pub trait Node: Send + Sync + 'static {
     type GossipService: GossipService + Send + Sync;
 
     /// Implements node handshake. The server returns the ID of the genesis
-    /// block and its own node ID, authenticated with the signature of `nonce`.
+    /// block and its own node ID, authenticated with the signature of `nonce`,
+    /// along with the capabilities it supports.
     async fn handshake(&self, peer: Peer, nonce: &[u8]) -> Result<HandshakeResponse, Error>;
 
     /// Handles client ID authentication.
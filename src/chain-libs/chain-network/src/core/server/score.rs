@@ -0,0 +1,32 @@
+use crate::data::Peer;
+
+/// Misbehavior of a peer detected by the server while serving its requests.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Misbehavior {
+    /// The peer pushed a block or a header that the node rejected as invalid.
+    InvalidBlock,
+    /// The peer sent a request that does not follow the protocol,
+    /// e.g. a malformed identifier or a bad authentication signature.
+    ProtocolError,
+}
+
+/// What the server should do with a peer after a misbehavior is recorded.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QuarantineDecision {
+    /// Keep serving the peer.
+    Keep,
+    /// The peer is quarantined: the failed request is answered with
+    /// `PermissionDenied`, and the node is expected to refuse further
+    /// requests from the peer for as long as the quarantine lasts.
+    Quarantine,
+}
+
+/// Hook to score peers on the misbehavior detected by the server.
+///
+/// The hook is called from the tasks serving the requests, so it
+/// should not block.
+pub trait PeerScore: Send + Sync + 'static {
+    /// Records a misbehavior of the peer and decides whether the peer
+    /// should be quarantined.
+    fn record(&self, peer: &Peer, misbehavior: Misbehavior) -> QuarantineDecision;
+}
@@ -3,8 +3,10 @@ mod block;
 mod header;
 mod id;
 mod subscription;
+mod tip;
 
 pub use block::Block;
 pub use header::Header;
 pub use id::{try_ids_from_iter, BlockId, BlockIds};
 pub use subscription::{BlockEvent, ChainPullRequest};
+pub use tip::TipUpdate;
@@ -0,0 +1,11 @@
+use super::BlockId;
+
+/// A lightweight notification of a change of the tip in the node's chain,
+/// carrying just enough to follow the chain without fetching headers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TipUpdate {
+    /// The identifier of the tip block.
+    pub id: BlockId,
+    /// The chain length of the tip block.
+    pub chain_length: u32,
+}
@@ -1,6 +1,8 @@
 #[allow(clippy::module_inception)]
 mod fragment;
 mod id;
+mod subscription;
 
 pub use fragment::Fragment;
 pub use id::{try_ids_from_iter, FragmentId, FragmentIds};
+pub use subscription::{FragmentAck, FragmentEvent, FragmentRejectionReason, FragmentStatus};
@@ -0,0 +1,39 @@
+use super::{Fragment, FragmentId};
+
+/// An event sent from server to client over the fragment subscription
+/// stream with acknowledgments.
+pub enum FragmentEvent {
+    /// A fragment created or accepted by the server.
+    Fragment(Fragment),
+    /// Acknowledgment of a fragment previously sent by the client.
+    Ack(FragmentAck),
+}
+
+/// Outcome of processing a fragment received over the subscription stream.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FragmentAck {
+    pub id: FragmentId,
+    pub status: FragmentStatus,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FragmentStatus {
+    /// The fragment has been accepted into the mempool.
+    Accepted,
+    /// The fragment has been rejected, resending it as is will not help
+    /// unless the reason is transient, e.g. `PoolOverflow`.
+    Rejected(FragmentRejectionReason),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FragmentRejectionReason {
+    /// The reason is not known to this version of the protocol.
+    Unspecified,
+    AlreadyInLog,
+    Invalid,
+    PreviousInvalid,
+    PoolOverflow,
+    Expired,
+    ValidForTooLong,
+    TooLarge,
+}
@@ -0,0 +1,144 @@
+use super::Node;
+use crate::error::{Code, Error};
+
+/// Version of the gossip envelope produced by this implementation.
+pub const GOSSIP_VERSION: u16 = 1;
+
+/// Versioned envelope of a gossip node description.
+///
+/// The serialized form starts with the node description prefixed with its
+/// length as a big-endian `u16`, which is the format of nodes that do not
+/// use envelopes. It may be followed by the advertised version as a
+/// big-endian `u16` and a sequence of sections, each encoded as a one-byte
+/// tag, a big-endian `u16` length and the content.
+///
+/// Decoding is tolerant: data after the node description is optional,
+/// sections are kept as opaque bytes for the application to interpret
+/// or ignore, and a truncated trailer is discarded, so gossip from nodes
+/// of other versions can still be used.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GossipEnvelope {
+    version: Option<u16>,
+    payload: Box<[u8]>,
+    sections: Vec<(u8, Box<[u8]>)>,
+}
+
+impl GossipEnvelope {
+    /// Creates an envelope of the current version for the node description.
+    pub fn new<B: Into<Box<[u8]>>>(payload: B) -> Self {
+        GossipEnvelope {
+            version: Some(GOSSIP_VERSION),
+            payload: payload.into(),
+            sections: Vec::new(),
+        }
+    }
+
+    /// Adds a section to the envelope.
+    pub fn with_section<B: Into<Box<[u8]>>>(mut self, tag: u8, content: B) -> Self {
+        self.sections.push((tag, content.into()));
+        self
+    }
+
+    /// The version advertised by the node that produced the gossip,
+    /// `None` if the gossip was produced without an envelope.
+    #[inline]
+    pub fn version(&self) -> Option<u16> {
+        self.version
+    }
+
+    /// The node description.
+    #[inline]
+    pub fn payload(&self) -> &[u8] {
+        &self.payload
+    }
+
+    /// The content of the first section with the given tag, if any.
+    pub fn section(&self, tag: u8) -> Option<&[u8]> {
+        self.sections
+            .iter()
+            .find(|(t, _)| *t == tag)
+            .map(|(_, content)| &content[..])
+    }
+
+    /// Tags of the sections in the envelope, in order.
+    pub fn section_tags(&self) -> impl Iterator<Item = u8> + '_ {
+        self.sections.iter().map(|(tag, _)| *tag)
+    }
+
+    /// Serializes the envelope into a gossip node.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the payload or a section is longer than `u16::MAX` bytes.
+    pub fn encode(&self) -> Node {
+        let mut bytes = Vec::with_capacity(self.payload.len() + 4);
+        put_chunk(&mut bytes, &self.payload);
+        if let Some(version) = self.version {
+            bytes.extend_from_slice(&version.to_be_bytes());
+            for (tag, content) in &self.sections {
+                bytes.push(*tag);
+                put_chunk(&mut bytes, content);
+            }
+        }
+        Node::from_bytes(bytes)
+    }
+
+    /// Deserializes a gossip node, which may have been produced by a node
+    /// of a different version or without an envelope.
+    ///
+    /// Fails only if the node description itself cannot be read.
+    pub fn decode(node: &Node) -> Result<Self, Error> {
+        let mut bytes = node.as_bytes();
+        let payload = get_chunk(&mut bytes).ok_or_else(|| {
+            Error::new(
+                Code::InvalidArgument,
+                "gossip node description is truncated",
+            )
+        })?;
+
+        let version = get_u16(&mut bytes);
+        let mut sections = Vec::new();
+        if version.is_some() {
+            while let Some((&tag, mut rest)) = bytes.split_first() {
+                match get_chunk(&mut rest) {
+                    Some(content) => sections.push((tag, content.into())),
+                    // a truncated section is dropped along with anything after it
+                    None => break,
+                }
+                bytes = rest;
+            }
+        }
+
+        Ok(GossipEnvelope {
+            version,
+            payload: payload.into(),
+            sections,
+        })
+    }
+}
+
+fn put_chunk(bytes: &mut Vec<u8>, content: &[u8]) {
+    let len = u16::try_from(content.len()).expect("gossip data is too long");
+    bytes.extend_from_slice(&len.to_be_bytes());
+    bytes.extend_from_slice(content);
+}
+
+fn get_u16(bytes: &mut &[u8]) -> Option<u16> {
+    if bytes.len() < 2 {
+        return None;
+    }
+    let (value, rest) = bytes.split_at(2);
+    *bytes = rest;
+    Some(u16::from_be_bytes([value[0], value[1]]))
+}
+
+fn get_chunk<'a>(bytes: &mut &'a [u8]) -> Option<&'a [u8]> {
+    let mut rest = *bytes;
+    let len = get_u16(&mut rest)? as usize;
+    if rest.len() < len {
+        return None;
+    }
+    let (content, rest) = rest.split_at(len);
+    *bytes = rest;
+    Some(content)
+}
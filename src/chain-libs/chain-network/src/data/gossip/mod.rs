@@ -1,3 +1,5 @@
+mod envelope;
 mod node;
 
+pub use envelope::{GossipEnvelope, GOSSIP_VERSION};
 pub use node::{Gossip, Node, Nodes};
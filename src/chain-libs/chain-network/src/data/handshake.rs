@@ -1,8 +1,82 @@
 use super::block::BlockId;
 use super::p2p::AuthenticatedNodeId;
 
+use std::fmt;
+use std::ops::{BitAnd, BitOr};
+
+/// Set of optional protocol features supported by a node.
+///
+/// Capabilities are exchanged during the handshake, so that a node only
+/// uses the features supported by both ends of the connection. Nodes which
+/// predate the capability negotiation report an empty set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Capabilities(u64);
+
+impl Capabilities {
+    /// Gzip compression of block streams.
+    pub const BLOCK_COMPRESSION: Self = Capabilities(1);
+    /// The `Peers` method.
+    pub const PEERS: Self = Capabilities(1 << 1);
+    /// The `TipSubscription` method.
+    pub const TIP_SUBSCRIPTION: Self = Capabilities(1 << 2);
+    /// The `FragmentSubscriptionWithAck` method.
+    pub const FRAGMENT_ACK: Self = Capabilities(1 << 3);
+
+    /// All the capabilities implemented by this version of the crate.
+    pub const SUPPORTED: Self = Capabilities(
+        Self::BLOCK_COMPRESSION.0 | Self::PEERS.0 | Self::TIP_SUBSCRIPTION.0 | Self::FRAGMENT_ACK.0,
+    );
+
+    pub const fn empty() -> Self {
+        Capabilities(0)
+    }
+
+    /// Unknown bits are kept, so that capabilities introduced by newer
+    /// versions of the protocol can be passed through.
+    pub const fn from_bits(bits: u64) -> Self {
+        Capabilities(bits)
+    }
+
+    pub const fn bits(self) -> u64 {
+        self.0
+    }
+
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl BitOr for Capabilities {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Capabilities(self.0 | rhs.0)
+    }
+}
+
+impl BitAnd for Capabilities {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self {
+        Capabilities(self.0 & rhs.0)
+    }
+}
+
+impl fmt::Display for Capabilities {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:#x}", self.0)
+    }
+}
+
 pub struct HandshakeResponse {
     pub block0_id: BlockId,
     pub auth: AuthenticatedNodeId,
     pub nonce: Box<[u8]>,
+    /// Capabilities of the server. On the client side, this is the
+    /// negotiated set, supported by both the client and the server.
+    pub capabilities: Capabilities,
 }
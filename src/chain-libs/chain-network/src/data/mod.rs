@@ -1,11 +1,13 @@
 pub mod block;
+mod call_id;
 pub mod fragment;
 pub mod gossip;
 mod handshake;
 pub mod p2p;
 
-pub use block::{Block, BlockEvent, BlockId, BlockIds, Header};
-pub use fragment::{Fragment, FragmentId, FragmentIds};
+pub use block::{Block, BlockEvent, BlockId, BlockIds, Header, TipUpdate};
+pub use call_id::CallId;
+pub use fragment::{Fragment, FragmentAck, FragmentEvent, FragmentId, FragmentIds};
 pub use gossip::Gossip;
-pub use handshake::HandshakeResponse;
+pub use handshake::{Capabilities, HandshakeResponse};
 pub use p2p::{AuthenticatedNodeId, NodeId, NodeKeyPair, Peer};
@@ -1,4 +1,4 @@
-use std::{error, fmt};
+use std::{error, fmt, time::Duration};
 
 /// Common error codes for network protocol requests.
 ///
@@ -15,6 +15,70 @@ pub enum Code {
     Unimplemented,
     Internal,
     Unavailable,
+    DeadlineExceeded,
+    ResourceExhausted,
+}
+
+/// What an error is attributed to, telling apart the failures that are
+/// worth retrying from those the remote party is to blame for.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum ErrorDomain {
+    /// The peer is overloaded or limits its resources, the request can be
+    /// retried later.
+    Overload,
+    /// The request or the data sent violate the protocol.
+    Protocol,
+    /// The peer failed to process a valid request.
+    Internal,
+}
+
+/// Structured information on an error, passed along with the error code
+/// to the other side of the connection.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ErrorDetails {
+    domain: Option<ErrorDomain>,
+    retry_after: Option<Duration>,
+    item_id: Option<Box<[u8]>>,
+}
+
+impl ErrorDetails {
+    pub fn new(domain: ErrorDomain) -> Self {
+        ErrorDetails {
+            domain: Some(domain),
+            ..Default::default()
+        }
+    }
+
+    /// Suggests a delay before retrying the request.
+    pub fn with_retry_after(self, retry_after: Duration) -> Self {
+        ErrorDetails {
+            retry_after: Some(retry_after),
+            ..self
+        }
+    }
+
+    /// Identifies the item the error is about, such as a block or
+    /// a fragment.
+    pub fn with_item_id<Id: AsRef<[u8]>>(self, id: Id) -> Self {
+        ErrorDetails {
+            item_id: Some(id.as_ref().into()),
+            ..self
+        }
+    }
+
+    /// The domain of the error, `None` if it is not known to this
+    /// protocol implementation.
+    pub fn domain(&self) -> Option<ErrorDomain> {
+        self.domain
+    }
+
+    pub fn retry_after(&self) -> Option<Duration> {
+        self.retry_after
+    }
+
+    pub fn item_id(&self) -> Option<&[u8]> {
+        self.item_id.as_deref()
+    }
 }
 
 /// Represents errors that can be returned by the node protocol implementation.
@@ -22,6 +86,7 @@ pub enum Code {
 pub struct Error {
     code: Code,
     source: Box<dyn error::Error + Send + Sync>,
+    details: Option<ErrorDetails>,
 }
 
 impl Error {
@@ -32,6 +97,15 @@ impl Error {
         Error {
             code,
             source: source.into(),
+            details: None,
+        }
+    }
+
+    /// Attaches structured details to the error.
+    pub fn with_details(self, details: ErrorDetails) -> Self {
+        Error {
+            details: Some(details),
+            ..self
         }
     }
 
@@ -42,6 +116,20 @@ impl Error {
     pub fn code(&self) -> Code {
         self.code
     }
+
+    pub fn details(&self) -> Option<&ErrorDetails> {
+        self.details.as_ref()
+    }
+
+    /// Returns true if the request failed because the peer is overloaded,
+    /// so that it can be retried later, possibly after the delay
+    /// suggested in the details.
+    pub fn is_overload(&self) -> bool {
+        match self.details.as_ref().and_then(ErrorDetails::domain) {
+            Some(domain) => domain == ErrorDomain::Overload,
+            None => self.code == Code::ResourceExhausted,
+        }
+    }
 }
 
 impl error::Error for Error {
@@ -62,6 +150,8 @@ impl fmt::Display for Error {
             Code::Unimplemented => "not implemented",
             Code::Internal => "internal processing error",
             Code::Unavailable => "the service is unavailable",
+            Code::DeadlineExceeded => "the operation did not complete in time",
+            Code::ResourceExhausted => "the resources allowed for the request are exhausted",
         };
         write!(f, "{} ({})", msg, self.source)
     }
@@ -74,10 +164,9 @@ pub enum HandshakeError {
     /// Error occurred with the protocol request.
     #[error("{0}")]
     Rpc(#[source] Error),
-    /// The protocol version reported by the server is not supported.
-    /// Carries the reported version in a human-readable form.
-    #[error("unsupported protocol version {0}")]
-    UnsupportedVersion(Box<str>),
+    /// The protocol version reported by the peer is not supported.
+    #[error("unsupported protocol version {remote}, expected {local}")]
+    UnsupportedVersion { local: u32, remote: u32 },
     #[error("invalid genesis block payload")]
     InvalidBlock0(#[source] Error),
     #[error("invalid node ID")]
@@ -0,0 +1,282 @@
+//! Authentication of the client node to the servers it connects to.
+
+#[cfg(feature = "legacy")]
+use super::legacy;
+
+use crate::data::p2p::{AuthenticatedNodeId, NodeId, NodeKeyPair};
+use tonic::metadata::{MetadataMap, MetadataValue};
+use tonic::service::Interceptor;
+use tonic::{Request, Status};
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+const NODE_ID_METADATA: &str = "auth-node-id-bin";
+const TIMESTAMP_METADATA: &str = "auth-timestamp";
+const SIGNATURE_METADATA: &str = "auth-signature-bin";
+
+/// Prefixed to the timestamp signed in the identity token, so that
+/// the signature cannot be taken for one made in another context.
+const TOKEN_CONTEXT: &[u8] = b"chain-network subscription";
+
+/// Default tolerance for the difference between the clocks of the client
+/// and the server, see [`ServerAuth::max_clock_skew`].
+pub const DEFAULT_MAX_CLOCK_SKEW: Duration = Duration::from_secs(60);
+
+fn token_message(timestamp: u64) -> Vec<u8> {
+    let mut msg = Vec::with_capacity(TOKEN_CONTEXT.len() + 8);
+    msg.extend_from_slice(TOKEN_CONTEXT);
+    msg.extend_from_slice(&timestamp.to_be_bytes());
+    msg
+}
+
+fn unix_secs(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Identity presented by a client to the server.
+///
+/// The client proves its node ID after the handshake by signing the nonce
+/// sent by the server, so that the server can reject clients claiming the
+/// ID of another node. The identity can also be added to the metadata of
+/// the subscription requests, for servers identifying their subscribers
+/// that way.
+pub trait ClientAuth: Send + Sync + 'static {
+    /// Authenticates the client with the nonce received in the handshake
+    /// response. The client authentication request is not sent if this
+    /// returns `None`.
+    fn authenticate(&self, nonce: &[u8]) -> Option<AuthenticatedNodeId>;
+
+    /// Adds the identification of the client to the metadata of
+    /// a subscription request.
+    fn identify_subscription(&self, _metadata: &mut MetadataMap) {}
+}
+
+/// Anonymous client, which does not authenticate itself to the server.
+///
+/// This is the default, for compatibility with the servers that do not
+/// require client authentication.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NoAuth;
+
+impl ClientAuth for NoAuth {
+    fn authenticate(&self, _nonce: &[u8]) -> Option<AuthenticatedNodeId> {
+        None
+    }
+}
+
+/// Proves the possession of the secret key of the node ID.
+///
+/// Subscription requests carry an identity token: the node ID and
+/// the signature of the current time, which [`ServerAuth`] verifies.
+impl ClientAuth for NodeKeyPair {
+    fn authenticate(&self, nonce: &[u8]) -> Option<AuthenticatedNodeId> {
+        Some(self.sign(nonce))
+    }
+
+    fn identify_subscription(&self, metadata: &mut MetadataMap) {
+        insert_token(self, SystemTime::now(), metadata)
+    }
+}
+
+fn insert_token(keypair: &NodeKeyPair, time: SystemTime, metadata: &mut MetadataMap) {
+    let timestamp = unix_secs(time);
+    let auth = keypair.sign(&token_message(timestamp));
+    metadata.insert_bin(
+        NODE_ID_METADATA,
+        MetadataValue::from_bytes(auth.id().as_bytes()),
+    );
+    metadata.insert(TIMESTAMP_METADATA, timestamp.into());
+    metadata.insert_bin(
+        SIGNATURE_METADATA,
+        MetadataValue::from_bytes(auth.signature()),
+    );
+}
+
+/// Server-side interceptor verifying the identity tokens added by
+/// the clients authenticating with [`NodeKeyPair`].
+///
+/// Requests carrying an invalid or expired token are rejected with
+/// the `Unauthenticated` status, so that a client cannot claim the node ID
+/// of another node. Requests without a token are let through, unless
+/// the interceptor is set to require it.
+#[derive(Clone, Debug)]
+pub struct ServerAuth {
+    max_clock_skew: Duration,
+    required: bool,
+}
+
+impl Default for ServerAuth {
+    fn default() -> Self {
+        ServerAuth {
+            max_clock_skew: DEFAULT_MAX_CLOCK_SKEW,
+            required: false,
+        }
+    }
+}
+
+impl ServerAuth {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets how far the time signed by the client may be from the clock
+    /// of the server. Tokens outside this window are rejected, which
+    /// limits how long a captured token can be replayed.
+    pub fn max_clock_skew(&mut self, skew: Duration) -> &mut Self {
+        self.max_clock_skew = skew;
+        self
+    }
+
+    /// Rejects the requests that do not carry an identity token.
+    /// Note that the interceptor applies to all requests of the service
+    /// it is added to, including the handshake.
+    pub fn required(&mut self, required: bool) -> &mut Self {
+        self.required = required;
+        self
+    }
+
+    /// Verifies the identity token in the request metadata, returning
+    /// the ID of the authenticated node if the token is present.
+    pub fn verify(&self, metadata: &MetadataMap) -> Result<Option<NodeId>, Status> {
+        self.verify_at(metadata, SystemTime::now())
+    }
+
+    fn verify_at(&self, metadata: &MetadataMap, now: SystemTime) -> Result<Option<NodeId>, Status> {
+        let node_id = match metadata.get_bin(NODE_ID_METADATA) {
+            Some(val) => val,
+            None if self.required => {
+                return Err(Status::unauthenticated("missing node identity token"))
+            }
+            None => return Ok(None),
+        };
+        let node_id = node_id
+            .to_bytes()
+            .ok()
+            .and_then(|bytes| NodeId::try_from(&bytes[..]).ok())
+            .ok_or_else(|| Status::unauthenticated("invalid node ID in identity token"))?;
+        let timestamp: u64 = metadata
+            .get(TIMESTAMP_METADATA)
+            .and_then(|val| val.to_str().ok()?.parse().ok())
+            .ok_or_else(|| Status::unauthenticated("invalid timestamp in identity token"))?;
+        let signature = metadata
+            .get_bin(SIGNATURE_METADATA)
+            .and_then(|val| val.to_bytes().ok())
+            .ok_or_else(|| Status::unauthenticated("missing signature in identity token"))?;
+        let auth = node_id
+            .authenticated(&signature)
+            .map_err(|_| Status::unauthenticated("invalid signature in identity token"))?;
+        auth.verify(&token_message(timestamp))
+            .map_err(|_| Status::unauthenticated("identity token signature does not match"))?;
+        let now = unix_secs(now);
+        if now.abs_diff(timestamp) > self.max_clock_skew.as_secs() {
+            return Err(Status::unauthenticated("identity token has expired"));
+        }
+        Ok(Some(auth.into()))
+    }
+}
+
+impl Interceptor for ServerAuth {
+    fn call(&mut self, req: Request<()>) -> Result<Request<()>, Status> {
+        self.verify(req.metadata())?;
+        Ok(req)
+    }
+}
+
+/// Adds "node-id-bin" metadata into subscription requests, for backward
+/// compatibility with jormungandr versions prior to 0.9.
+#[cfg(feature = "legacy")]
+impl ClientAuth for legacy::NodeId {
+    fn authenticate(&self, _nonce: &[u8]) -> Option<AuthenticatedNodeId> {
+        None
+    }
+
+    fn identify_subscription(&self, metadata: &mut MetadataMap) {
+        let val = MetadataValue::from_bytes(&self.encode());
+        metadata.insert_bin("node-id-bin", val);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keypair() -> NodeKeyPair {
+        NodeKeyPair::generate(rand::thread_rng())
+    }
+
+    fn token(keypair: &NodeKeyPair, time: SystemTime) -> MetadataMap {
+        let mut metadata = MetadataMap::new();
+        insert_token(keypair, time, &mut metadata);
+        metadata
+    }
+
+    #[test]
+    fn verifies_token_of_the_client() {
+        let keypair = keypair();
+        let now = SystemTime::now();
+        let metadata = token(&keypair, now);
+        let node_id = ServerAuth::new().verify_at(&metadata, now).unwrap();
+        assert_eq!(node_id, Some(keypair.sign(b"").into()));
+    }
+
+    #[test]
+    fn rejects_spoofed_node_id() {
+        let now = SystemTime::now();
+        let mut metadata = token(&keypair(), now);
+        let other = keypair().sign(b"");
+        metadata.insert_bin(
+            NODE_ID_METADATA,
+            MetadataValue::from_bytes(other.id().as_bytes()),
+        );
+        let status = ServerAuth::new().verify_at(&metadata, now).unwrap_err();
+        assert_eq!(status.code(), tonic::Code::Unauthenticated);
+    }
+
+    #[test]
+    fn rejects_altered_timestamp() {
+        let now = SystemTime::now();
+        let mut metadata = token(&keypair(), now);
+        metadata.insert(TIMESTAMP_METADATA, (unix_secs(now) + 1).into());
+        let status = ServerAuth::new().verify_at(&metadata, now).unwrap_err();
+        assert_eq!(status.code(), tonic::Code::Unauthenticated);
+    }
+
+    #[test]
+    fn rejects_expired_token() {
+        let now = SystemTime::now();
+        let metadata = token(&keypair(), now - Duration::from_secs(120));
+        let mut auth = ServerAuth::new();
+        auth.max_clock_skew(Duration::from_secs(60));
+        let status = auth.verify_at(&metadata, now).unwrap_err();
+        assert_eq!(status.code(), tonic::Code::Unauthenticated);
+        auth.max_clock_skew(Duration::from_secs(180));
+        assert!(auth.verify_at(&metadata, now).unwrap().is_some());
+    }
+
+    #[test]
+    fn missing_token_is_rejected_only_when_required() {
+        let metadata = MetadataMap::new();
+        let mut auth = ServerAuth::new();
+        assert!(auth.verify(&metadata).unwrap().is_none());
+        auth.required(true);
+        let status = auth.verify(&metadata).unwrap_err();
+        assert_eq!(status.code(), tonic::Code::Unauthenticated);
+    }
+
+    #[test]
+    fn interceptor_checks_subscription_requests() {
+        let keypair = keypair();
+        let mut auth = ServerAuth::new();
+        auth.required(true);
+
+        let mut req = Request::new(());
+        keypair.identify_subscription(req.metadata_mut());
+        assert!(Interceptor::call(&mut auth, req).is_ok());
+
+        let mut req = Request::new(());
+        NoAuth.identify_subscription(req.metadata_mut());
+        assert!(Interceptor::call(&mut auth, req).is_err());
+    }
+}
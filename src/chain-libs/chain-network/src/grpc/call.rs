@@ -0,0 +1,39 @@
+use crate::data::CallId;
+
+use tonic::metadata::{AsciiMetadataValue, MetadataMap};
+use tracing::Span;
+
+/// Metadata key of the identifier of the call, see [`CallId`].
+pub const CALL_ID_METADATA: &str = "call-id";
+
+/// Creates the request for a call with a new identifier, along with the
+/// span in which the call is made by the client.
+pub(crate) fn request<M>(service: &'static str, message: M) -> (tonic::Request<M>, Span) {
+    let call_id = CallId::generate();
+    let mut req = tonic::Request::new(message);
+    let val = AsciiMetadataValue::from_str(&call_id.to_string())
+        .expect("call id should be a valid metadata value");
+    req.metadata_mut().insert(CALL_ID_METADATA, val);
+    let span = tracing::debug_span!("grpc_call", service, call_id = %call_id);
+    tracing::debug!(parent: &span, "sending request");
+    (req, span)
+}
+
+/// Gets the identifier of the call made by the client, or a new one if
+/// the client did not send it or sent a malformed one, along with the span
+/// in which the request is served.
+pub(crate) fn serve<M>(service: &'static str, req: &tonic::Request<M>) -> (CallId, Span) {
+    let call_id = call_id(req.metadata()).unwrap_or_else(CallId::generate);
+    let span = match req.remote_addr() {
+        Some(peer) => {
+            tracing::debug_span!("grpc_request", service, call_id = %call_id, peer = %peer)
+        }
+        None => tracing::debug_span!("grpc_request", service, call_id = %call_id),
+    };
+    tracing::debug!(parent: &span, "serving request");
+    (call_id, span)
+}
+
+fn call_id(metadata: &MetadataMap) -> Option<CallId> {
+    metadata.get(CALL_ID_METADATA)?.to_str().ok()?.parse().ok()
+}
@@ -1,14 +1,21 @@
+pub use super::auth::{ClientAuth, NoAuth};
+use super::call;
 use super::convert;
 use super::proto;
+pub use super::streaming::{
+    BufferedInbound, DedupConfig, DedupInbound, DedupStats, InboundBuffer, InboundStats,
+    OverflowPolicy, SeenCache,
+};
 use super::streaming::{InboundStream, OutboundStream};
+use super::Compression;
 
 #[cfg(feature = "legacy")]
 use super::legacy;
 
-use crate::data::block::{Block, BlockEvent, BlockId, BlockIds, Header};
-use crate::data::fragment::{Fragment, FragmentIds};
+use crate::data::block::{Block, BlockEvent, BlockId, BlockIds, Header, TipUpdate};
+use crate::data::fragment::{Fragment, FragmentEvent, FragmentIds};
 use crate::data::p2p::{AuthenticatedNodeId, NodeId};
-use crate::data::{Gossip, HandshakeResponse};
+use crate::data::{Capabilities, Gossip, HandshakeResponse};
 use crate::error::{Error, HandshakeError};
 use crate::PROTOCOL_VERSION;
 use futures::prelude::*;
@@ -16,47 +23,87 @@ use http_body::Body;
 use tonic::body::BoxBody;
 use tonic::client::GrpcService;
 use tonic::codegen::StdError;
+use tracing::Instrument;
 
-#[cfg(feature = "legacy")]
-use tonic::metadata::MetadataValue;
+use std::sync::Arc;
 
+#[cfg(feature = "transport")]
+use super::resolve::{Bootstrap, ResolveError};
+#[cfg(feature = "transport")]
+use std::net::SocketAddr;
 #[cfg(feature = "transport")]
 use tonic::transport;
 
 /// Builder to customize the gRPC client.
 #[derive(Default)]
 pub struct Builder {
-    #[cfg(feature = "legacy")]
-    legacy_node_id: Option<legacy::NodeId>,
+    auth: Option<Arc<dyn ClientAuth>>,
+    block_compression: Option<Compression>,
+    capabilities: Option<Capabilities>,
 }
 
 impl Builder {
     pub fn new() -> Self {
         Builder {
-            #[cfg(feature = "legacy")]
-            legacy_node_id: None,
+            auth: None,
+            block_compression: None,
+            capabilities: None,
         }
     }
 
+    /// Set the capabilities advertised by the client in the handshake.
+    /// By default, all the capabilities supported by this crate are advertised.
+    pub fn capabilities(&mut self, capabilities: Capabilities) -> &mut Self {
+        self.capabilities = Some(capabilities);
+        self
+    }
+
+    /// Make the client request compressed block streams and compress
+    /// the uploaded blocks. Once the handshake is done, the compression is
+    /// only used if the server advertises it in its capabilities.
+    pub fn block_compression(&mut self, compression: Compression) -> &mut Self {
+        self.block_compression = Some(compression);
+        self
+    }
+
+    /// Set the identity the client authenticates with to the server,
+    /// see [`Client::authenticate`]. By default, the client is anonymous.
+    pub fn auth<A: ClientAuth>(&mut self, auth: A) -> &mut Self {
+        self.auth = Some(Arc::new(auth));
+        self
+    }
+
     /// Make the client add "node-id-bin" metadata with the passed value
     /// into subscription requests, for backward compatibility with
     /// jormungandr versions prior to 0.9.
+    ///
+    /// This replaces the identity set with [`auth`](Self::auth).
     #[cfg(feature = "legacy")]
     pub fn legacy_node_id(&mut self, node_id: legacy::NodeId) -> &mut Self {
-        self.legacy_node_id = Some(node_id);
-        self
+        self.auth(node_id)
     }
 
     pub fn build<T>(&self, service: T) -> Client<T>
     where
-        T: GrpcService<BoxBody>,
+        T: GrpcService<BoxBody> + Clone,
         T::ResponseBody: Send + Sync + 'static,
         <T::ResponseBody as Body>::Error: Into<StdError> + Send,
     {
+        // the compression options of a tonic client apply to all requests,
+        // so the block streams get a client of their own
+        let blocks = self.block_compression.map(|compression| {
+            let client = proto::node::node_client::NodeClient::new(service.clone());
+            match compression {
+                Compression::Gzip => client.send_gzip().accept_gzip(),
+            }
+        });
         Client {
             inner: proto::node::node_client::NodeClient::new(service),
-            #[cfg(feature = "legacy")]
-            legacy_node_id: self.legacy_node_id,
+            blocks,
+            block_compression: self.block_compression,
+            capabilities: self.capabilities.unwrap_or(Capabilities::SUPPORTED),
+            negotiated: None,
+            auth: self.auth.clone().unwrap_or_else(|| Arc::new(NoAuth)),
         }
     }
 
@@ -66,28 +113,45 @@ impl Builder {
         D: TryInto<transport::Endpoint>,
         D::Error: Into<StdError>,
     {
-        let inner = proto::node::node_client::NodeClient::connect(dst).await?;
-        Ok(Client {
-            inner,
-            #[cfg(feature = "legacy")]
-            legacy_node_id: self.legacy_node_id,
-        })
+        let channel = transport::Endpoint::new(dst)?.connect().await?;
+        Ok(self.build(channel))
+    }
+
+    /// Connect to the peers resolved from a DNS name, which can be
+    /// `host:port` or an SRV name like `_jormungandr._tcp.example.net`.
+    /// See [`Bootstrap`] to customize the resolution and the retries.
+    #[cfg(feature = "transport")]
+    pub async fn connect_resolved(
+        &self,
+        name: &str,
+    ) -> Result<Vec<(SocketAddr, Client<transport::Channel>)>, ResolveError> {
+        Bootstrap::new(name)?.connect(self).await
     }
 }
 
 #[derive(Clone)]
 pub struct Client<T> {
     inner: proto::node::node_client::NodeClient<T>,
-    #[cfg(feature = "legacy")]
-    legacy_node_id: Option<legacy::NodeId>,
+    blocks: Option<proto::node::node_client::NodeClient<T>>,
+    block_compression: Option<Compression>,
+    capabilities: Capabilities,
+    negotiated: Option<Capabilities>,
+    auth: Arc<dyn ClientAuth>,
 }
 
 /// The inbound subscription stream of block events.
 pub type BlockSubscription = InboundStream<proto::node::BlockEvent, BlockEvent>;
 
+/// The inbound stream of updates of the server's tip.
+pub type TipSubscription = InboundStream<proto::node::TipUpdate, TipUpdate>;
+
 /// The inbound subscription stream of fragments.
 pub type FragmentSubscription = InboundStream<proto::types::Fragment, Fragment>;
 
+/// The inbound subscription stream of fragments and acknowledgments
+/// of the fragments sent by the client.
+pub type FragmentAckSubscription = InboundStream<proto::node::FragmentEvent, FragmentEvent>;
+
 /// The inbound subscription stream of P2P gossip.
 pub type GossipSubscription = InboundStream<proto::node::Gossip, Gossip>;
 
@@ -108,43 +172,73 @@ where
     T::ResponseBody: Send + Sync + 'static,
     <T::ResponseBody as Body>::Error: Into<StdError> + Send,
 {
-    pub fn new(service: T) -> Self {
+    pub fn new(service: T) -> Self
+    where
+        T: Clone,
+    {
         Builder::new().build(service)
     }
 
-    #[allow(unused_mut)]
-    #[allow(clippy::let_and_return)]
-    fn subscription_request<S>(&self, outbound: S) -> tonic::Request<S> {
-        let mut req = tonic::Request::new(outbound);
-        #[cfg(feature = "legacy")]
-        if let Some(node_id) = self.legacy_node_id {
-            let val = MetadataValue::from_bytes(&node_id.encode());
-            req.metadata_mut().insert_bin("node-id-bin", val);
+    /// Capabilities supported by both the client and the server,
+    /// known once the handshake is done.
+    pub fn negotiated_capabilities(&self) -> Option<Capabilities> {
+        self.negotiated
+    }
+
+    /// Compression of the block streams, if enabled in the builder and,
+    /// once the handshake is done, supported by the server.
+    pub fn block_compression(&self) -> Option<Compression> {
+        let negotiated = self.negotiated.unwrap_or(self.capabilities);
+        self.block_compression
+            .filter(|_| negotiated.contains(Capabilities::BLOCK_COMPRESSION))
+    }
+
+    fn blocks_client(&mut self) -> &mut proto::node::node_client::NodeClient<T> {
+        let compressed = self.block_compression().is_some();
+        match &mut self.blocks {
+            Some(client) if compressed => client,
+            _ => &mut self.inner,
         }
-        req
+    }
+
+    fn subscription_request<S>(
+        &self,
+        service: &'static str,
+        outbound: S,
+    ) -> (tonic::Request<S>, tracing::Span) {
+        let (mut req, span) = call::request(service, outbound);
+        self.auth.identify_subscription(req.metadata_mut());
+        (req, span)
     }
 
     /// Requests the identifier of the genesis block from the service node.
     ///
-    /// The implementation can also perform version information checks to
-    /// ascertain that the client use compatible protocol versions.
+    /// The client and the server exchange their protocol versions, failing
+    /// if they differ, and their capabilities. The capabilities in the returned
+    /// response are the ones supported by both sides, which the client uses
+    /// from then on.
     ///
     /// This method should be called first after establishing the client
     /// connection.
     pub async fn handshake(&mut self, nonce: &[u8]) -> Result<HandshakeResponse, HandshakeError> {
         let req = proto::node::HandshakeRequest {
             nonce: nonce.into(),
+            version: PROTOCOL_VERSION,
+            capabilities: self.capabilities.bits(),
         };
+        let (req, span) = call::request("Handshake", req);
         let res = self
             .inner
             .handshake(req)
+            .instrument(span)
             .await
             .map_err(|status| HandshakeError::Rpc(convert::error_from_grpc(status)))?
             .into_inner();
         if res.version != PROTOCOL_VERSION {
-            return Err(HandshakeError::UnsupportedVersion(
-                res.version.to_string().into(),
-            ));
+            return Err(HandshakeError::UnsupportedVersion {
+                local: PROTOCOL_VERSION,
+                remote: res.version,
+            });
         }
         let block0_id =
             BlockId::try_from(&res.block0[..]).map_err(HandshakeError::InvalidBlock0)?;
@@ -153,19 +247,41 @@ where
             .authenticated(&res.signature)
             .map_err(HandshakeError::MalformedSignature)?;
         let nonce = res.nonce.into();
+        let capabilities = self.capabilities & Capabilities::from_bits(res.capabilities);
+        self.negotiated = Some(capabilities);
         Ok(HandshakeResponse {
             block0_id,
             auth,
             nonce,
+            capabilities,
         })
     }
 
+    /// Authenticates the client with the identity set in the builder,
+    /// signing the nonce received in the handshake response.
+    /// Nothing is sent to the server if the client is anonymous.
+    ///
+    /// This method should be called after the handshake, before
+    /// subscribing.
+    pub async fn authenticate(&mut self, nonce: &[u8]) -> Result<(), Error> {
+        match self.auth.authenticate(nonce) {
+            Some(auth) => self.client_auth(auth).await,
+            None => Ok(()),
+        }
+    }
+
     pub async fn client_auth(&mut self, auth: AuthenticatedNodeId) -> Result<(), Error> {
         let req = proto::node::ClientAuthRequest {
             node_id: auth.id().as_bytes().into(),
             signature: auth.signature().into(),
         };
-        let _res = self.inner.client_auth(req).await?.into_inner();
+        let (req, span) = call::request("ClientAuth", req);
+        let _res = self
+            .inner
+            .client_auth(req)
+            .instrument(span)
+            .await?
+            .into_inner();
         Ok(())
     }
 
@@ -177,7 +293,8 @@ where
     pub async fn peers(&mut self, limit: u32) -> Result<Gossip, Error> {
         use crate::grpc::convert::FromProtobuf;
         let req = proto::node::PeersRequest { limit };
-        let res = self.inner.peers(req).await?.into_inner();
+        let (req, span) = call::request("Peers", req);
+        let res = self.inner.peers(req).instrument(span).await?.into_inner();
         let peers = Gossip::from_message(res)?;
         Ok(peers)
     }
@@ -185,11 +302,28 @@ where
     /// Requests the header of the tip block in the node's chain.
     pub async fn tip(&mut self) -> Result<Header, Error> {
         let req = proto::node::TipRequest {};
-        let res = self.inner.tip(req).await?.into_inner();
+        let (req, span) = call::request("Tip", req);
+        let res = self.inner.tip(req).instrument(span).await?.into_inner();
         let header = Header::from_bytes(res.block_header);
         Ok(header)
     }
 
+    /// Subscribes to updates of the tip in the node's chain.
+    ///
+    /// The returned stream starts with the current tip and yields
+    /// the identifier and chain length of each new tip, without the header.
+    pub async fn tip_subscription(&mut self) -> Result<TipSubscription, Error> {
+        let req = proto::node::TipSubscriptionRequest {};
+        let (req, span) = call::request("TipSubscription", req);
+        let inbound = self
+            .inner
+            .tip_subscription(req)
+            .instrument(span)
+            .await?
+            .into_inner();
+        Ok(InboundStream::new(inbound))
+    }
+
     /// Requests the identified blocks in a streamed response.
     pub async fn get_blocks(
         &mut self,
@@ -198,7 +332,13 @@ where
         let ids = proto::types::BlockIds {
             ids: convert::ids_into_repeated_bytes(ids.iter()),
         };
-        let stream = self.inner.get_blocks(ids).await?.into_inner();
+        let (req, span) = call::request("GetBlocks", ids);
+        let stream = self
+            .blocks_client()
+            .get_blocks(req)
+            .instrument(span)
+            .await?
+            .into_inner();
         Ok(InboundStream::new(stream))
     }
 
@@ -210,7 +350,13 @@ where
         let ids = proto::types::BlockIds {
             ids: convert::ids_into_repeated_bytes(ids.iter()),
         };
-        let stream = self.inner.get_headers(ids).await?.into_inner();
+        let (req, span) = call::request("GetHeaders", ids);
+        let stream = self
+            .inner
+            .get_headers(req)
+            .instrument(span)
+            .await?
+            .into_inner();
         Ok(InboundStream::new(stream))
     }
 
@@ -222,7 +368,13 @@ where
         let ids = proto::types::FragmentIds {
             ids: convert::ids_into_repeated_bytes(ids.into_vec()),
         };
-        let stream = self.inner.get_fragments(ids).await?.into_inner();
+        let (req, span) = call::request("GetFragments", ids);
+        let stream = self
+            .inner
+            .get_fragments(req)
+            .instrument(span)
+            .await?
+            .into_inner();
         Ok(InboundStream::new(stream))
     }
 
@@ -236,7 +388,13 @@ where
             from: convert::ids_into_repeated_bytes(from.into_vec()),
             to: to.as_ref().to_vec(),
         };
-        let stream = self.inner.pull_blocks(req).await?.into_inner();
+        let (req, span) = call::request("PullBlocks", req);
+        let stream = self
+            .blocks_client()
+            .pull_blocks(req)
+            .instrument(span)
+            .await?
+            .into_inner();
         Ok(InboundStream::new(stream))
     }
 
@@ -249,7 +407,13 @@ where
         let req = proto::node::PullBlocksToTipRequest {
             from: convert::ids_into_repeated_bytes(from.into_vec()),
         };
-        let stream = self.inner.pull_blocks_to_tip(req).await?.into_inner();
+        let (req, span) = call::request("PullBlocksToTip", req);
+        let stream = self
+            .blocks_client()
+            .pull_blocks_to_tip(req)
+            .instrument(span)
+            .await?
+            .into_inner();
         Ok(InboundStream::new(stream))
     }
 
@@ -267,7 +431,13 @@ where
             from: convert::ids_into_repeated_bytes(from.into_vec()),
             to: to.as_bytes().into(),
         };
-        let stream = self.inner.pull_headers(req).await?.into_inner();
+        let (req, span) = call::request("PullHeaders", req);
+        let stream = self
+            .inner
+            .pull_headers(req)
+            .instrument(span)
+            .await?
+            .into_inner();
         Ok(InboundStream::new(stream))
     }
 
@@ -279,9 +449,13 @@ where
     where
         S: Stream<Item = Header> + Send + Sync + 'static,
     {
-        let outbound = OutboundStream::new(headers);
-        let proto::node::PushHeadersResponse {} =
-            self.inner.push_headers(outbound).await?.into_inner();
+        let (req, span) = call::request("PushHeaders", OutboundStream::new(headers));
+        let proto::node::PushHeadersResponse {} = self
+            .inner
+            .push_headers(req)
+            .instrument(span)
+            .await?
+            .into_inner();
         Ok(())
     }
 
@@ -292,9 +466,13 @@ where
     where
         S: Stream<Item = Block> + Send + Sync + 'static,
     {
-        let outbound = OutboundStream::new(blocks);
-        let proto::node::UploadBlocksResponse {} =
-            self.inner.upload_blocks(outbound).await?.into_inner();
+        let (req, span) = call::request("UploadBlocks", OutboundStream::new(blocks));
+        let proto::node::UploadBlocksResponse {} = self
+            .blocks_client()
+            .upload_blocks(req)
+            .instrument(span)
+            .await?
+            .into_inner();
         Ok(())
     }
 
@@ -307,8 +485,14 @@ where
     where
         S: Stream<Item = Header> + Send + Sync + 'static,
     {
-        let req = self.subscription_request(OutboundStream::new(outbound));
-        let inbound = self.inner.block_subscription(req).await?.into_inner();
+        let (req, span) =
+            self.subscription_request("BlockSubscription", OutboundStream::new(outbound));
+        let inbound = self
+            .inner
+            .block_subscription(req)
+            .instrument(span)
+            .await?
+            .into_inner();
         Ok(InboundStream::new(inbound))
     }
 
@@ -324,8 +508,37 @@ where
     where
         S: Stream<Item = Fragment> + Send + Sync + 'static,
     {
-        let req = self.subscription_request(OutboundStream::new(outbound));
-        let inbound = self.inner.fragment_subscription(req).await?.into_inner();
+        let (req, span) =
+            self.subscription_request("FragmentSubscription", OutboundStream::new(outbound));
+        let inbound = self
+            .inner
+            .fragment_subscription(req)
+            .instrument(span)
+            .await?
+            .into_inner();
+        Ok(InboundStream::new(inbound))
+    }
+
+    /// Same as `fragment_subscription`, but the server also acknowledges
+    /// each fragment sent by the client in the returned stream.
+    ///
+    /// Fails with `Code::Unimplemented` if the server does not support
+    /// acknowledgments, in which case `fragment_subscription` can be used.
+    pub async fn fragment_subscription_with_ack<S>(
+        &mut self,
+        outbound: S,
+    ) -> Result<FragmentAckSubscription, Error>
+    where
+        S: Stream<Item = Fragment> + Send + Sync + 'static,
+    {
+        let (req, span) =
+            self.subscription_request("FragmentSubscriptionWithAck", OutboundStream::new(outbound));
+        let inbound = self
+            .inner
+            .fragment_subscription_with_ack(req)
+            .instrument(span)
+            .await?
+            .into_inner();
         Ok(InboundStream::new(inbound))
     }
 
@@ -337,8 +550,14 @@ where
     where
         S: Stream<Item = Gossip> + Send + Sync + 'static,
     {
-        let req = self.subscription_request(OutboundStream::new(outbound));
-        let inbound = self.inner.gossip_subscription(req).await?.into_inner();
+        let (req, span) =
+            self.subscription_request("GossipSubscription", OutboundStream::new(outbound));
+        let inbound = self
+            .inner
+            .gossip_subscription(req)
+            .instrument(span)
+            .await?
+            .into_inner();
         Ok(InboundStream::new(inbound))
     }
 }
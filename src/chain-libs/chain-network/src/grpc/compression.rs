@@ -0,0 +1,11 @@
+/// Compression of the block streams.
+///
+/// The client asks for compressed responses by sending the
+/// `grpc-accept-encoding` metadata with the `GetBlocks`, `PullBlocks`
+/// and `PullBlocksToTip` requests only, so the server never compresses
+/// other responses. `UploadBlocks` messages sent by the client are
+/// compressed as well, which the server always accepts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Compression {
+    Gzip,
+}
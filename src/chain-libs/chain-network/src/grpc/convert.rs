@@ -1,12 +1,17 @@
 use super::proto;
 use crate::data::{
-    block::{self, Block, BlockEvent, BlockId, ChainPullRequest, Header},
-    fragment::Fragment,
+    block::{self, Block, BlockEvent, BlockId, ChainPullRequest, Header, TipUpdate},
+    fragment::{
+        Fragment, FragmentAck, FragmentEvent, FragmentId, FragmentRejectionReason, FragmentStatus,
+    },
     gossip::{Gossip, Node},
 };
-use crate::error::{self, Error};
+use crate::error::{self, Error, ErrorDetails, ErrorDomain};
+use prost::{bytes::Bytes, Message};
 use tonic::{Code, Status};
 
+use std::time::Duration;
+
 pub(super) fn error_into_grpc(err: Error) -> Status {
     use error::Code::*;
 
@@ -20,11 +25,67 @@ pub(super) fn error_into_grpc(err: Error) -> Status {
         Unimplemented => Code::Unimplemented,
         Internal => Code::Internal,
         Unavailable => Code::Unavailable,
+        DeadlineExceeded => Code::DeadlineExceeded,
+        ResourceExhausted => Code::ResourceExhausted,
         // When a new case has to be added here, remember to
         // add the corresponding case in error_from_grpc below.
     };
 
-    Status::new(code, err.to_string())
+    match err.details() {
+        Some(details) => status_with_details(code, err.to_string(), details),
+        None => Status::new(code, err.to_string()),
+    }
+}
+
+/// Creates a status carrying the structured details of the error,
+/// encoded as an `ErrorDetails` protobuf message.
+pub(super) fn status_with_details(
+    code: Code,
+    message: impl Into<String>,
+    details: &ErrorDetails,
+) -> Status {
+    use proto::types::error_details::Domain;
+
+    let domain = match details.domain() {
+        Some(ErrorDomain::Overload) => Domain::Overload,
+        Some(ErrorDomain::Protocol) => Domain::Protocol,
+        Some(ErrorDomain::Internal) => Domain::Internal,
+        None => Domain::Unspecified,
+    };
+    let retry_after_ms = details.retry_after().map_or(0, |delay| {
+        u64::try_from(delay.as_millis()).unwrap_or(u64::MAX)
+    });
+    let message_details = proto::types::ErrorDetails {
+        domain: domain as i32,
+        retry_after_ms,
+        item_id: details.item_id().map_or_else(Vec::new, <[u8]>::to_vec),
+    };
+    Status::with_details(code, message, Bytes::from(message_details.encode_to_vec()))
+}
+
+/// Decodes the structured details of the error carried by the status,
+/// if any. Details in a format unknown to this protocol, as other gRPC
+/// implementations may send, are ignored.
+pub(super) fn details_from_grpc(status: &Status) -> Option<ErrorDetails> {
+    use proto::types::error_details::Domain;
+
+    if status.details().is_empty() {
+        return None;
+    }
+    let message = proto::types::ErrorDetails::decode(status.details()).ok()?;
+    let mut details = match Domain::from_i32(message.domain) {
+        Some(Domain::Overload) => ErrorDetails::new(ErrorDomain::Overload),
+        Some(Domain::Protocol) => ErrorDetails::new(ErrorDomain::Protocol),
+        Some(Domain::Internal) => ErrorDetails::new(ErrorDomain::Internal),
+        Some(Domain::Unspecified) | None => ErrorDetails::default(),
+    };
+    if message.retry_after_ms != 0 {
+        details = details.with_retry_after(Duration::from_millis(message.retry_after_ms));
+    }
+    if !message.item_id.is_empty() {
+        details = details.with_item_id(message.item_id);
+    }
+    Some(details)
 }
 
 pub(super) fn error_from_grpc(e: Status) -> Error {
@@ -40,10 +101,15 @@ pub(super) fn error_from_grpc(e: Status) -> Error {
         Code::Unimplemented => Unimplemented,
         Code::Internal => Internal,
         Code::Unavailable => Unavailable,
+        Code::DeadlineExceeded => DeadlineExceeded,
+        Code::ResourceExhausted => ResourceExhausted,
         _ => Unknown,
     };
 
-    Error::new(code, e)
+    match details_from_grpc(&e) {
+        Some(details) => Error::new(code, e).with_details(details),
+        None => Error::new(code, e),
+    }
 }
 
 impl From<Error> for Status {
@@ -109,6 +175,26 @@ impl IntoProtobuf for Header {
     }
 }
 
+impl FromProtobuf<proto::node::TipUpdate> for TipUpdate {
+    fn from_message(message: proto::node::TipUpdate) -> Result<Self, Error> {
+        Ok(TipUpdate {
+            id: BlockId::try_from(&message.block_id[..])?,
+            chain_length: message.chain_length,
+        })
+    }
+}
+
+impl IntoProtobuf for TipUpdate {
+    type Message = proto::node::TipUpdate;
+
+    fn into_message(self) -> proto::node::TipUpdate {
+        proto::node::TipUpdate {
+            block_id: self.id.as_bytes().into(),
+            chain_length: self.chain_length,
+        }
+    }
+}
+
 impl FromProtobuf<proto::types::Fragment> for Fragment {
     fn from_message(message: proto::types::Fragment) -> Result<Self, Error> {
         Ok(Fragment::from_bytes(message.content))
@@ -125,6 +211,104 @@ impl IntoProtobuf for Fragment {
     }
 }
 
+impl FromProtobuf<proto::node::FragmentAck> for FragmentAck {
+    fn from_message(message: proto::node::FragmentAck) -> Result<Self, Error> {
+        use proto::node::fragment_ack::{RejectionReason, Status};
+
+        let id = FragmentId::try_from(&message.fragment_id[..])?;
+        let status = match Status::from_i32(message.status) {
+            Some(Status::Accepted) => FragmentStatus::Accepted,
+            Some(Status::Rejected) => {
+                let reason = match RejectionReason::from_i32(message.rejection_reason) {
+                    Some(RejectionReason::AlreadyInLog) => FragmentRejectionReason::AlreadyInLog,
+                    Some(RejectionReason::Invalid) => FragmentRejectionReason::Invalid,
+                    Some(RejectionReason::PreviousInvalid) => {
+                        FragmentRejectionReason::PreviousInvalid
+                    }
+                    Some(RejectionReason::PoolOverflow) => FragmentRejectionReason::PoolOverflow,
+                    Some(RejectionReason::Expired) => FragmentRejectionReason::Expired,
+                    Some(RejectionReason::ValidForTooLong) => {
+                        FragmentRejectionReason::ValidForTooLong
+                    }
+                    Some(RejectionReason::TooLarge) => FragmentRejectionReason::TooLarge,
+                    // Reasons added in later protocol revisions are not an error
+                    Some(RejectionReason::Unspecified) | None => {
+                        FragmentRejectionReason::Unspecified
+                    }
+                };
+                FragmentStatus::Rejected(reason)
+            }
+            None => {
+                return Err(Error::new(
+                    error::Code::InvalidArgument,
+                    format!("invalid fragment acknowledgment status {}", message.status),
+                ))
+            }
+        };
+        Ok(FragmentAck { id, status })
+    }
+}
+
+impl IntoProtobuf for FragmentAck {
+    type Message = proto::node::FragmentAck;
+
+    fn into_message(self) -> proto::node::FragmentAck {
+        use proto::node::fragment_ack::{RejectionReason, Status};
+
+        let (status, reason) = match self.status {
+            FragmentStatus::Accepted => (Status::Accepted, RejectionReason::Unspecified),
+            FragmentStatus::Rejected(reason) => {
+                let reason = match reason {
+                    FragmentRejectionReason::Unspecified => RejectionReason::Unspecified,
+                    FragmentRejectionReason::AlreadyInLog => RejectionReason::AlreadyInLog,
+                    FragmentRejectionReason::Invalid => RejectionReason::Invalid,
+                    FragmentRejectionReason::PreviousInvalid => RejectionReason::PreviousInvalid,
+                    FragmentRejectionReason::PoolOverflow => RejectionReason::PoolOverflow,
+                    FragmentRejectionReason::Expired => RejectionReason::Expired,
+                    FragmentRejectionReason::ValidForTooLong => RejectionReason::ValidForTooLong,
+                    FragmentRejectionReason::TooLarge => RejectionReason::TooLarge,
+                };
+                (Status::Rejected, reason)
+            }
+        };
+        proto::node::FragmentAck {
+            fragment_id: self.id.as_bytes().into(),
+            status: status as i32,
+            rejection_reason: reason as i32,
+        }
+    }
+}
+
+impl FromProtobuf<proto::node::FragmentEvent> for FragmentEvent {
+    fn from_message(msg: proto::node::FragmentEvent) -> Result<Self, Error> {
+        use proto::node::fragment_event::Item;
+
+        match msg.item {
+            Some(Item::Fragment(fragment)) => {
+                Ok(FragmentEvent::Fragment(Fragment::from_message(fragment)?))
+            }
+            Some(Item::Ack(ack)) => Ok(FragmentEvent::Ack(FragmentAck::from_message(ack)?)),
+            None => Err(Error::new(
+                error::Code::InvalidArgument,
+                "one of the FragmentEvent variants must be present",
+            )),
+        }
+    }
+}
+
+impl IntoProtobuf for FragmentEvent {
+    type Message = proto::node::FragmentEvent;
+
+    fn into_message(self) -> proto::node::FragmentEvent {
+        use proto::node::fragment_event::Item;
+        let item = match self {
+            FragmentEvent::Fragment(fragment) => Item::Fragment(fragment.into_message()),
+            FragmentEvent::Ack(ack) => Item::Ack(ack.into_message()),
+        };
+        proto::node::FragmentEvent { item: Some(item) }
+    }
+}
+
 impl FromProtobuf<proto::node::Gossip> for Gossip {
     fn from_message(message: proto::node::Gossip) -> Result<Self, Error> {
         let gossip = Gossip {
@@ -218,3 +402,27 @@ impl IntoProtobuf for BlockEvent {
         proto::node::BlockEvent { item: Some(item) }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn error_details_survive_status_conversion() {
+        let details = ErrorDetails::new(ErrorDomain::Overload)
+            .with_retry_after(Duration::from_millis(1500))
+            .with_item_id([1, 2, 3]);
+        let err = Error::new(error::Code::ResourceExhausted, "busy").with_details(details.clone());
+
+        let decoded = error_from_grpc(error_into_grpc(err));
+        assert_eq!(decoded.code(), error::Code::ResourceExhausted);
+        assert_eq!(decoded.details(), Some(&details));
+        assert!(decoded.is_overload());
+    }
+
+    #[test]
+    fn foreign_status_details_are_ignored() {
+        let status = Status::with_details(Code::Internal, "oops", Bytes::from_static(&[0xff]));
+        assert!(error_from_grpc(status).details().is_none());
+    }
+}
@@ -0,0 +1,205 @@
+//! Fetching blocks from multiple peers concurrently.
+
+use super::client::Client;
+use crate::data::block::{Block, BlockId};
+use crate::error::Error;
+
+use futures::prelude::*;
+use futures::stream::FuturesUnordered;
+use http_body::Body;
+use tonic::body::BoxBody;
+use tonic::client::GrpcService;
+use tonic::codegen::StdError;
+
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+const DEFAULT_CHUNK_SIZE: usize = 64;
+
+/// An error that the future returned by `FetchCoordinator::fetch`
+/// can resolve to.
+#[derive(Debug, thiserror::Error)]
+pub enum FetchError {
+    /// No peer connections were given to the coordinator.
+    #[error("no peer connections to fetch blocks from")]
+    NoPeers,
+    /// A chunk of blocks could not be fetched from any of the peers.
+    #[error("failed to fetch {count} blocks starting from {first:?} from any peer")]
+    ChunkFailed {
+        first: BlockId,
+        count: usize,
+        /// The error of the last attempt.
+        #[source]
+        source: ChunkError,
+    },
+}
+
+/// The reason an attempt to fetch a chunk of blocks from a peer failed.
+#[derive(Debug, thiserror::Error)]
+pub enum ChunkError {
+    /// Error occurred with the protocol request.
+    #[error("{0}")]
+    Rpc(#[source] Error),
+    /// The peer sent a block other than the one expected at this position.
+    #[error("peer sent block {received:?} instead of {expected:?}")]
+    UnexpectedBlock {
+        expected: BlockId,
+        received: BlockId,
+    },
+    /// The peer closed the stream before sending all requested blocks.
+    #[error("peer sent {received} blocks out of {expected} requested")]
+    Incomplete { expected: usize, received: usize },
+}
+
+struct Chunk {
+    index: usize,
+    ids: Vec<BlockId>,
+}
+
+/// Splits a list of block identifiers into chunks fetched in parallel
+/// from several peer connections.
+///
+/// Each connection fetches one chunk at a time with a `GetBlocks` request.
+/// The received blocks are checked against the requested identifiers with
+/// the hash function given to the coordinator, since the protocol layer
+/// does not know the block format. A chunk that fails on a peer, due to
+/// a request error or a mismatched response, is retried on another peer;
+/// the failing peer is not given further chunks. Fetching fails when
+/// a chunk fails with no peers left to retry it on.
+pub struct FetchCoordinator<T> {
+    clients: Vec<Client<T>>,
+    block_id: Arc<dyn Fn(&Block) -> BlockId + Send + Sync>,
+    chunk_size: usize,
+}
+
+impl<T> FetchCoordinator<T>
+where
+    T: GrpcService<BoxBody> + Clone,
+    T::ResponseBody: Send + Sync + 'static,
+    <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+{
+    /// Creates a coordinator fetching over the given peer connections,
+    /// using `block_id` to compute the identifier of a received block.
+    pub fn new<F>(clients: Vec<Client<T>>, block_id: F) -> Self
+    where
+        F: Fn(&Block) -> BlockId + Send + Sync + 'static,
+    {
+        FetchCoordinator {
+            clients,
+            block_id: Arc::new(block_id),
+            chunk_size: DEFAULT_CHUNK_SIZE,
+        }
+    }
+
+    /// Sets the maximum number of blocks requested from a peer at once.
+    ///
+    /// The default is 64 blocks.
+    pub fn chunk_size(&mut self, chunk_size: usize) -> &mut Self {
+        assert!(chunk_size > 0, "chunk size must not be zero");
+        self.chunk_size = chunk_size;
+        self
+    }
+
+    /// Fetches the identified blocks, returning them in the order of `ids`.
+    ///
+    /// Fails if a chunk of the blocks could not be fetched after all peers
+    /// have failed.
+    pub async fn fetch(&mut self, ids: &[BlockId]) -> Result<Vec<Block>, FetchError> {
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        if self.clients.is_empty() {
+            return Err(FetchError::NoPeers);
+        }
+
+        let mut pending: VecDeque<Chunk> = ids
+            .chunks(self.chunk_size)
+            .enumerate()
+            .map(|(index, ids)| Chunk {
+                index,
+                ids: ids.to_vec(),
+            })
+            .collect();
+        let mut results: Vec<Option<Vec<Block>>> = (0..pending.len()).map(|_| None).collect();
+
+        let mut idle: Vec<usize> = (0..self.clients.len()).collect();
+        let mut in_flight = FuturesUnordered::new();
+
+        loop {
+            while !pending.is_empty() {
+                let peer = match idle.pop() {
+                    Some(peer) => peer,
+                    None => break,
+                };
+                let chunk = pending.pop_front().unwrap();
+                let mut client = self.clients[peer].clone();
+                let block_id = Arc::clone(&self.block_id);
+                in_flight.push(async move {
+                    let res = fetch_chunk(&mut client, &chunk.ids, &*block_id).await;
+                    (peer, chunk, res)
+                });
+            }
+
+            let (peer, chunk, res) = match in_flight.next().await {
+                Some(done) => done,
+                None => break,
+            };
+            match res {
+                Ok(blocks) => {
+                    results[chunk.index] = Some(blocks);
+                    idle.push(peer);
+                }
+                Err(source) => {
+                    // the peer is not used any more, the chunk is retried
+                    // on another one if any is left
+                    if idle.is_empty() && in_flight.is_empty() {
+                        return Err(FetchError::ChunkFailed {
+                            first: chunk.ids[0],
+                            count: chunk.ids.len(),
+                            source,
+                        });
+                    }
+                    pending.push_back(chunk);
+                }
+            }
+        }
+
+        Ok(results.into_iter().flatten().flatten().collect())
+    }
+}
+
+async fn fetch_chunk<T>(
+    client: &mut Client<T>,
+    ids: &[BlockId],
+    block_id: &(dyn Fn(&Block) -> BlockId + Send + Sync),
+) -> Result<Vec<Block>, ChunkError>
+where
+    T: GrpcService<BoxBody>,
+    T::ResponseBody: Send + Sync + 'static,
+    <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+{
+    let mut stream = client
+        .get_blocks(ids.into())
+        .await
+        .map_err(ChunkError::Rpc)?;
+    let mut blocks = Vec::with_capacity(ids.len());
+    while let Some(block) = stream.try_next().await.map_err(ChunkError::Rpc)? {
+        let expected = match ids.get(blocks.len()) {
+            Some(id) => *id,
+            // ignore any blocks sent past the requested ones
+            None => break,
+        };
+        let received = block_id(&block);
+        if received != expected {
+            return Err(ChunkError::UnexpectedBlock { expected, received });
+        }
+        blocks.push(block);
+    }
+    if blocks.len() < ids.len() {
+        return Err(ChunkError::Incomplete {
+            expected: ids.len(),
+            received: blocks.len(),
+        });
+    }
+    Ok(blocks)
+}
@@ -0,0 +1,31 @@
+//! Standard gRPC health checking protocol, `grpc.health.v1.Health`,
+//! letting load balancers and generic probes check the node liveness.
+
+use tonic_health::server::HealthReporter;
+
+pub use tonic_health::ServingStatus;
+
+/// Services reported individually, in addition to the overall status
+/// of the server which is reported for the empty service name.
+const SERVICE_NAMES: [&str; 2] = ["iohk.chain.node.Node", "iohk.chain.watch.Watch"];
+
+/// Handle to update the statuses reported by the health checking service
+/// created with [`super::server::Builder::health_service`].
+#[derive(Clone)]
+pub struct HealthStatus {
+    reporter: HealthReporter,
+}
+
+impl HealthStatus {
+    pub(crate) fn new(reporter: HealthReporter) -> Self {
+        HealthStatus { reporter }
+    }
+
+    /// Report the server and the node services with the given status.
+    pub async fn set(&mut self, status: ServingStatus) {
+        self.reporter.set_service_status("", status).await;
+        for name in SERVICE_NAMES {
+            self.reporter.set_service_status(name, status).await;
+        }
+    }
+}
@@ -0,0 +1,78 @@
+use crate::core::server::{Metrics, Request, RequestStats};
+use crate::data::CallId;
+
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Instant;
+
+/// Accounts the messages of a request served to a peer.
+///
+/// The meter is shared by the streams of the request; the request is
+/// reported as finished when the last clone is dropped. A meter created
+/// without metrics hooks does nothing.
+#[derive(Clone, Default)]
+pub(crate) struct RequestMeter {
+    inner: Option<Arc<MeterInner>>,
+}
+
+struct MeterInner {
+    metrics: Arc<dyn Metrics>,
+    request: Request,
+    started: Instant,
+    bytes_received: AtomicU64,
+    bytes_sent: AtomicU64,
+}
+
+impl RequestMeter {
+    pub fn start(
+        metrics: Option<&Arc<dyn Metrics>>,
+        service: &'static str,
+        call_id: CallId,
+        remote_addr: Option<SocketAddr>,
+    ) -> Self {
+        let inner = metrics.zip(remote_addr).map(|(metrics, addr)| {
+            let request = Request {
+                service,
+                call_id,
+                peer: addr.into(),
+            };
+            metrics.request_started(&request);
+            Arc::new(MeterInner {
+                metrics: Arc::clone(metrics),
+                request,
+                started: Instant::now(),
+                bytes_received: AtomicU64::new(0),
+                bytes_sent: AtomicU64::new(0),
+            })
+        });
+        RequestMeter { inner }
+    }
+
+    pub fn received<M: prost::Message>(&self, message: &M) {
+        if let Some(inner) = &self.inner {
+            inner
+                .bytes_received
+                .fetch_add(message.encoded_len() as u64, Ordering::Relaxed);
+        }
+    }
+
+    pub fn sent<M: prost::Message>(&self, message: &M) {
+        if let Some(inner) = &self.inner {
+            inner
+                .bytes_sent
+                .fetch_add(message.encoded_len() as u64, Ordering::Relaxed);
+        }
+    }
+}
+
+impl Drop for MeterInner {
+    fn drop(&mut self) {
+        let stats = RequestStats {
+            duration: self.started.elapsed(),
+            bytes_received: *self.bytes_received.get_mut(),
+            bytes_sent: *self.bytes_sent.get_mut(),
+        };
+        self.metrics.request_finished(&self.request, &stats);
+    }
+}
@@ -1,6 +1,8 @@
 mod proto;
 
+pub mod auth;
 pub mod client;
+pub mod fetch;
 pub mod server;
 
 #[cfg(feature = "legacy")]
@@ -8,8 +10,22 @@ pub mod legacy;
 
 pub mod watch;
 
+#[cfg(feature = "health")]
+pub mod health;
+
+#[cfg(feature = "transport")]
+pub mod resolve;
+
+mod call;
+mod compression;
 mod convert;
+mod metrics;
+mod quota;
 mod streaming;
 
+pub use call::CALL_ID_METADATA;
 pub use client::Client;
+pub use compression::Compression;
+#[cfg(feature = "transport")]
+pub use resolve::{Bootstrap, BootstrapName, Resolve, ResolveError, SystemResolver};
 pub use server::{NodeService, Server};
@@ -11,3 +11,7 @@ pub(crate) mod node {
 pub(crate) mod watch {
     tonic::include_proto!("iohk.chain.watch");
 }
+
+#[cfg(feature = "reflection")]
+pub(crate) const FILE_DESCRIPTOR_SET: &[u8] =
+    tonic::include_file_descriptor_set!("chain_network_descriptor");
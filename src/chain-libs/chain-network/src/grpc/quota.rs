@@ -0,0 +1,116 @@
+use super::convert;
+use crate::error::{ErrorDetails, ErrorDomain};
+use tonic::{Code, Status};
+
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// The delay suggested to a peer whose subscription is refused, leaving
+/// time for its earlier subscriptions to be closed.
+const RETRY_AFTER: Duration = Duration::from_secs(30);
+
+/// Limits the number of simultaneous subscriptions served to each peer.
+///
+/// Peers are told apart by their IP address, so that a peer cannot get
+/// around the quota by opening more connections. The nodes sharing an
+/// address, such as the nodes behind a NAT, share its quota too.
+#[derive(Debug)]
+pub(super) struct SubscriptionQuota {
+    limit: usize,
+    active: Mutex<HashMap<IpAddr, usize>>,
+}
+
+impl SubscriptionQuota {
+    pub fn new(limit: usize) -> Arc<Self> {
+        Arc::new(SubscriptionQuota {
+            limit,
+            active: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// Reserves a subscription slot for the peer, failing with
+    /// `ResourceExhausted` attributed to overload, with a hint to retry
+    /// later, if the peer already uses all of its slots.
+    /// The slot is released when the returned permit is dropped.
+    pub fn acquire(self: &Arc<Self>, addr: IpAddr) -> Result<SubscriptionPermit, Status> {
+        let mut active = self.active.lock().unwrap();
+        let count = active.entry(addr).or_insert(0);
+        if *count >= self.limit {
+            return Err(convert::status_with_details(
+                Code::ResourceExhausted,
+                format!(
+                    "too many simultaneous subscriptions, at most {} are allowed per peer",
+                    self.limit
+                ),
+                &ErrorDetails::new(ErrorDomain::Overload).with_retry_after(RETRY_AFTER),
+            ));
+        }
+        *count += 1;
+        Ok(SubscriptionPermit {
+            quota: Arc::clone(self),
+            addr,
+        })
+    }
+}
+
+/// A subscription slot of a peer, held for as long as the subscription
+/// is served.
+#[derive(Debug)]
+pub(super) struct SubscriptionPermit {
+    quota: Arc<SubscriptionQuota>,
+    addr: IpAddr,
+}
+
+impl Drop for SubscriptionPermit {
+    fn drop(&mut self) {
+        let mut active = self.quota.active.lock().unwrap();
+        if let Some(count) = active.get_mut(&self.addr) {
+            *count -= 1;
+            if *count == 0 {
+                active.remove(&self.addr);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::Ipv4Addr;
+
+    const PEER: IpAddr = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1));
+    const OTHER_PEER: IpAddr = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 2));
+
+    #[test]
+    fn refuses_subscriptions_over_the_limit() {
+        let quota = SubscriptionQuota::new(2);
+        let _first = quota.acquire(PEER).unwrap();
+        let _second = quota.acquire(PEER).unwrap();
+
+        let status = quota.acquire(PEER).unwrap_err();
+        assert_eq!(status.code(), Code::ResourceExhausted);
+        let details = convert::details_from_grpc(&status).unwrap();
+        assert_eq!(details.domain(), Some(ErrorDomain::Overload));
+        assert_eq!(details.retry_after(), Some(RETRY_AFTER));
+    }
+
+    #[test]
+    fn dropped_permit_releases_its_slot() {
+        let quota = SubscriptionQuota::new(1);
+        let permit = quota.acquire(PEER).unwrap();
+        assert!(quota.acquire(PEER).is_err());
+
+        drop(permit);
+        assert!(quota.acquire(PEER).is_ok());
+        assert!(quota.active.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn peers_have_separate_quotas() {
+        let quota = SubscriptionQuota::new(1);
+        let _permit = quota.acquire(PEER).unwrap();
+        assert!(quota.acquire(OTHER_PEER).is_ok());
+    }
+}
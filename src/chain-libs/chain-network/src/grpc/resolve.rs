@@ -0,0 +1,294 @@
+//! Bootstrapping connections from a DNS name.
+//!
+//! A public network can publish its trusted peers under a DNS name,
+//! either as plain address records for `host:port`, or as SRV records
+//! of the form `_service._proto.domain` listing the hosts and ports of
+//! the peers. [`Bootstrap`] resolves such a name and connects to every
+//! resolved address, resolving the name again when no connection succeeds.
+
+use super::client::{Builder, Client};
+use async_trait::async_trait;
+use futures::future;
+use thiserror::Error;
+use tonic::transport;
+
+use std::fmt::{self, Display};
+use std::io;
+use std::net::SocketAddr;
+use std::time::Duration;
+
+const DEFAULT_ATTEMPTS: u32 = 3;
+const DEFAULT_RETRY_DELAY: Duration = Duration::from_secs(1);
+
+/// A DNS name designating the peers to bootstrap from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BootstrapName {
+    /// Address records of a host, with the port to connect to.
+    Host { host: String, port: u16 },
+    /// SRV records of a service, e.g. `_jormungandr._tcp.example.net`.
+    Srv(String),
+}
+
+impl BootstrapName {
+    /// Parses `host:port` as a host name, and a name with
+    /// underscore-prefixed service and protocol labels as an SRV name.
+    pub fn parse(name: &str) -> Result<Self, ResolveError> {
+        let invalid = || ResolveError::InvalidName(name.to_owned());
+        let mut labels = name.splitn(3, '.');
+        match (labels.next(), labels.next(), labels.next()) {
+            (Some(service), Some(proto), Some(domain))
+                if service.len() > 1
+                    && service.starts_with('_')
+                    && proto.len() > 1
+                    && proto.starts_with('_')
+                    && !domain.is_empty() =>
+            {
+                Ok(BootstrapName::Srv(name.trim_end_matches('.').to_owned()))
+            }
+            _ => {
+                let (host, port) = name.rsplit_once(':').ok_or_else(invalid)?;
+                let host = host.trim_start_matches('[').trim_end_matches(']');
+                if host.is_empty() {
+                    return Err(invalid());
+                }
+                let port = port.parse().map_err(|_| invalid())?;
+                Ok(BootstrapName::Host {
+                    host: host.to_owned(),
+                    port,
+                })
+            }
+        }
+    }
+}
+
+impl Display for BootstrapName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BootstrapName::Host { host, port } => write!(f, "{}:{}", host, port),
+            BootstrapName::Srv(name) => f.write_str(name),
+        }
+    }
+}
+
+/// The DNS lookups needed to resolve a [`BootstrapName`].
+#[async_trait]
+pub trait Resolve: Send + Sync {
+    /// Looks up the socket addresses of a host.
+    async fn lookup_host(&self, host: &str, port: u16) -> io::Result<Vec<SocketAddr>>;
+
+    /// Looks up the SRV records of a name, returning the target hosts
+    /// and ports in the order they should be tried.
+    async fn lookup_srv(&self, name: &str) -> io::Result<Vec<(String, u16)>>;
+}
+
+/// Resolver using the name resolution of the operating system.
+///
+/// The system resolver has no interface for SRV records, so
+/// SRV names need a [`Resolve`] implementation backed by a DNS client.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemResolver;
+
+#[async_trait]
+impl Resolve for SystemResolver {
+    async fn lookup_host(&self, host: &str, port: u16) -> io::Result<Vec<SocketAddr>> {
+        let addrs = tokio::net::lookup_host((host, port)).await?;
+        Ok(addrs.collect())
+    }
+
+    async fn lookup_srv(&self, name: &str) -> io::Result<Vec<(String, u16)>> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            format!("the system resolver cannot look up SRV records of {}", name),
+        ))
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum ResolveError {
+    #[error("invalid bootstrap name `{0}`, expected `host:port` or `_service._proto.domain`")]
+    InvalidName(String),
+    #[error("failed to resolve {name}")]
+    Lookup {
+        name: String,
+        #[source]
+        source: io::Error,
+    },
+    #[error("{0} did not resolve to any address")]
+    NoAddresses(String),
+    #[error("invalid endpoint for address {0}")]
+    Endpoint(SocketAddr, #[source] transport::Error),
+    #[error("could not connect to any address of {name} after {attempts} attempts")]
+    Unreachable { name: String, attempts: u32 },
+}
+
+/// Connects to the peers resolved from a DNS name.
+pub struct Bootstrap<R = SystemResolver> {
+    name: BootstrapName,
+    resolver: R,
+    attempts: u32,
+    retry_delay: Duration,
+}
+
+impl Bootstrap<SystemResolver> {
+    pub fn new(name: &str) -> Result<Self, ResolveError> {
+        Ok(Bootstrap {
+            name: BootstrapName::parse(name)?,
+            resolver: SystemResolver,
+            attempts: DEFAULT_ATTEMPTS,
+            retry_delay: DEFAULT_RETRY_DELAY,
+        })
+    }
+}
+
+impl<R: Resolve> Bootstrap<R> {
+    /// Use another resolver, e.g. one able to look up SRV records.
+    pub fn with_resolver<R2: Resolve>(self, resolver: R2) -> Bootstrap<R2> {
+        Bootstrap {
+            name: self.name,
+            resolver,
+            attempts: self.attempts,
+            retry_delay: self.retry_delay,
+        }
+    }
+
+    /// Set how many times the name is resolved before giving up
+    /// when no connection can be established. Defaults to 3.
+    pub fn attempts(mut self, attempts: u32) -> Self {
+        self.attempts = attempts.max(1);
+        self
+    }
+
+    /// Set the delay before the name is resolved again. Defaults to 1 second.
+    pub fn retry_delay(mut self, delay: Duration) -> Self {
+        self.retry_delay = delay;
+        self
+    }
+
+    pub fn name(&self) -> &BootstrapName {
+        &self.name
+    }
+
+    /// Resolves the name into the socket addresses of the peers.
+    pub async fn resolve(&self) -> Result<Vec<SocketAddr>, ResolveError> {
+        let lookup_err = |name: &str, source| ResolveError::Lookup {
+            name: name.to_owned(),
+            source,
+        };
+        let mut addrs = Vec::new();
+        match &self.name {
+            BootstrapName::Host { host, port } => {
+                addrs = self
+                    .resolver
+                    .lookup_host(host, *port)
+                    .await
+                    .map_err(|e| lookup_err(host, e))?;
+            }
+            BootstrapName::Srv(name) => {
+                let targets = self
+                    .resolver
+                    .lookup_srv(name)
+                    .await
+                    .map_err(|e| lookup_err(name, e))?;
+                // an unresolvable target should not prevent
+                // bootstrapping from the others
+                for (host, port) in targets {
+                    match self.resolver.lookup_host(&host, port).await {
+                        Ok(resolved) => addrs.extend(resolved),
+                        Err(e) => {
+                            tracing::debug!(srv_target = %host, reason = %e, "SRV target did not resolve")
+                        }
+                    }
+                }
+            }
+        }
+        addrs.sort();
+        addrs.dedup();
+        if addrs.is_empty() {
+            return Err(ResolveError::NoAddresses(self.name.to_string()));
+        }
+        Ok(addrs)
+    }
+
+    /// Connects to all the addresses the name resolves to, returning
+    /// the clients of the successful connections. If none succeeds,
+    /// the name is resolved again after the retry delay.
+    pub async fn connect(
+        &self,
+        builder: &Builder,
+    ) -> Result<Vec<(SocketAddr, Client<transport::Channel>)>, ResolveError> {
+        for attempt in 1..=self.attempts {
+            if attempt > 1 {
+                tokio::time::sleep(self.retry_delay).await;
+            }
+            let addrs = match self.resolve().await {
+                Ok(addrs) => addrs,
+                Err(e) => {
+                    tracing::info!(name = %self.name, attempt, reason = %e, "bootstrap name resolution failed");
+                    continue;
+                }
+            };
+            let connections = addrs.into_iter().map(|addr| async move {
+                let endpoint = transport::Endpoint::from_shared(format!("http://{}", addr))
+                    .map_err(|e| ResolveError::Endpoint(addr, e))?;
+                match endpoint.connect().await {
+                    Ok(channel) => Ok(Some((addr, builder.build(channel)))),
+                    Err(e) => {
+                        tracing::debug!(%addr, reason = %e, "bootstrap connection failed");
+                        Ok::<_, ResolveError>(None)
+                    }
+                }
+            });
+            let clients = future::try_join_all(connections)
+                .await?
+                .into_iter()
+                .flatten()
+                .collect::<Vec<_>>();
+            if !clients.is_empty() {
+                return Ok(clients);
+            }
+            tracing::info!(name = %self.name, attempt, "no bootstrap address could be connected to");
+        }
+        Err(ResolveError::Unreachable {
+            name: self.name.to_string(),
+            attempts: self.attempts,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_host_names() {
+        assert_eq!(
+            BootstrapName::parse("peers.example.net:3000").unwrap(),
+            BootstrapName::Host {
+                host: "peers.example.net".to_owned(),
+                port: 3000,
+            }
+        );
+        assert_eq!(
+            BootstrapName::parse("[::1]:3000").unwrap(),
+            BootstrapName::Host {
+                host: "::1".to_owned(),
+                port: 3000,
+            }
+        );
+        assert!(BootstrapName::parse("peers.example.net").is_err());
+        assert!(BootstrapName::parse(":3000").is_err());
+        assert!(BootstrapName::parse("peers.example.net:port").is_err());
+    }
+
+    #[test]
+    fn parse_srv_names() {
+        assert_eq!(
+            BootstrapName::parse("_jormungandr._tcp.example.net.").unwrap(),
+            BootstrapName::Srv("_jormungandr._tcp.example.net".to_owned())
+        );
+        assert!(matches!(
+            BootstrapName::parse("_._tcp.example.net"),
+            Err(ResolveError::InvalidName(_))
+        ));
+    }
+}
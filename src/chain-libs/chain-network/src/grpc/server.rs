@@ -1,19 +1,42 @@
+pub use super::auth::ServerAuth;
+
+use super::call;
+use super::convert;
+use super::metrics::RequestMeter;
 use super::proto;
+use super::quota::{SubscriptionPermit, SubscriptionQuota};
 use super::streaming::{InboundStream, OutboundTryStream};
+use super::Compression;
 
+#[cfg(feature = "health")]
+use super::health::HealthStatus;
 #[cfg(feature = "legacy")]
 use super::legacy;
 
-use crate::core::server::{BlockService, FragmentService, GossipService, Node};
+use crate::core::server::{
+    BlockService, FragmentService, GossipService, Metrics, Misbehavior, Node, PeerScore,
+    QuarantineDecision,
+};
 use crate::data::p2p::NodeId;
-use crate::data::{block, fragment, BlockId, Peer};
+use crate::data::{block, fragment, BlockId, Capabilities, Peer};
+use crate::error::{self, ErrorDetails, ErrorDomain};
 use crate::PROTOCOL_VERSION;
+use tonic::codegen::InterceptedService;
 use tonic::{Code, Status};
+use tracing::{Instrument, Span};
 
 #[cfg(feature = "legacy")]
 use tonic::metadata::MetadataValue;
+#[cfg(feature = "health")]
+use tonic_health::proto::health_server::{Health, HealthServer};
+#[cfg(feature = "reflection")]
+use tonic_reflection::server::{ServerReflection, ServerReflectionServer};
 
+use std::fmt;
 use std::net::SocketAddr;
+use std::sync::Arc;
+#[cfg(feature = "transport")]
+use std::time::Duration;
 
 pub type Server<T> = proto::node::node_server::NodeServer<NodeService<T>>;
 
@@ -22,6 +45,18 @@ pub type Server<T> = proto::node::node_server::NodeServer<NodeService<T>>;
 pub struct Builder {
     #[cfg(feature = "legacy")]
     legacy_node_id: Option<legacy::NodeId>,
+    #[cfg(feature = "transport")]
+    http2_keepalive_interval: Option<Duration>,
+    #[cfg(feature = "transport")]
+    http2_keepalive_timeout: Option<Duration>,
+    #[cfg(feature = "transport")]
+    tcp_keepalive: Option<Duration>,
+    #[cfg(feature = "transport")]
+    http2_max_concurrent_streams: Option<u32>,
+    block_compression: Option<Compression>,
+    metrics: Option<Arc<dyn Metrics>>,
+    peer_score: Option<Arc<dyn PeerScore>>,
+    max_subscriptions_per_peer: Option<usize>,
 }
 
 impl Builder {
@@ -29,9 +64,99 @@ impl Builder {
         Builder {
             #[cfg(feature = "legacy")]
             legacy_node_id: None,
+            #[cfg(feature = "transport")]
+            http2_keepalive_interval: None,
+            #[cfg(feature = "transport")]
+            http2_keepalive_timeout: None,
+            #[cfg(feature = "transport")]
+            tcp_keepalive: None,
+            #[cfg(feature = "transport")]
+            http2_max_concurrent_streams: None,
+            block_compression: None,
+            metrics: None,
+            peer_score: None,
+            max_subscriptions_per_peer: None,
         }
     }
 
+    /// Compress the responses to the clients asking for compressed
+    /// block streams. Compressed uploads are accepted regardless.
+    pub fn block_compression(&mut self, compression: Compression) -> &mut Self {
+        self.block_compression = Some(compression);
+        self
+    }
+
+    /// Report the requests served by the node service to the given hooks,
+    /// to collect network statistics per peer.
+    pub fn metrics(&mut self, metrics: Arc<dyn Metrics>) -> &mut Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// Report the misbehavior of the peers detected while serving their
+    /// requests to the given hook, which decides whether they
+    /// are quarantined.
+    pub fn peer_score(&mut self, peer_score: Arc<dyn PeerScore>) -> &mut Self {
+        self.peer_score = Some(peer_score);
+        self
+    }
+
+    /// Make the server send HTTP/2 keepalive pings on every connection
+    /// with the given interval. By default, no pings are sent.
+    #[cfg(feature = "transport")]
+    pub fn http2_keepalive_interval(&mut self, interval: Duration) -> &mut Self {
+        self.http2_keepalive_interval = Some(interval);
+        self
+    }
+
+    /// Close the connections of the peers not acknowledging a keepalive
+    /// ping within the given timeout.
+    /// Only effective if the keepalive interval is set.
+    #[cfg(feature = "transport")]
+    pub fn http2_keepalive_timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.http2_keepalive_timeout = Some(timeout);
+        self
+    }
+
+    /// Set the TCP keepalive duration for accepted connections.
+    #[cfg(feature = "transport")]
+    pub fn tcp_keepalive(&mut self, keepalive: Duration) -> &mut Self {
+        self.tcp_keepalive = Some(keepalive);
+        self
+    }
+
+    /// Limit the number of concurrent HTTP/2 streams, i.e. requests being
+    /// served, on every connection. By default, the limit of the
+    /// HTTP/2 implementation applies.
+    #[cfg(feature = "transport")]
+    pub fn http2_max_concurrent_streams(&mut self, max: u32) -> &mut Self {
+        self.http2_max_concurrent_streams = Some(max);
+        self
+    }
+
+    /// Limit the number of simultaneous subscriptions served to each peer,
+    /// across all of its connections. Excess subscription requests are
+    /// rejected with the `ResourceExhausted` status.
+    /// The peers are told apart by their IP address. Each node opens several
+    /// subscriptions, so the limit has to leave room for all the nodes
+    /// behind a single address.
+    /// By default, the subscriptions are not limited.
+    pub fn max_subscriptions_per_peer(&mut self, max: usize) -> &mut Self {
+        self.max_subscriptions_per_peer = Some(max);
+        self
+    }
+
+    /// Create the transport server builder with the connection-level
+    /// options configured on this builder.
+    #[cfg(feature = "transport")]
+    pub fn transport(&self) -> tonic::transport::Server {
+        tonic::transport::Server::builder()
+            .http2_keepalive_interval(self.http2_keepalive_interval)
+            .http2_keepalive_timeout(self.http2_keepalive_timeout)
+            .tcp_keepalive(self.tcp_keepalive)
+            .max_concurrent_streams(self.http2_max_concurrent_streams)
+    }
+
     /// Make the server add "node-id-bin" metadata with the passed value
     /// into subscription responses, for backward compatibility with
     /// jormungandr versions prior to 0.9.
@@ -41,21 +166,72 @@ impl Builder {
         self
     }
 
+    /// Create the standard gRPC health checking service, to be added to
+    /// the transport next to the node service. The node services are
+    /// unknown to it until their status is set with the returned handle.
+    #[cfg(feature = "health")]
+    pub fn health_service(&self) -> (HealthStatus, HealthServer<impl Health>) {
+        let (reporter, service) = tonic_health::server::health_reporter();
+        (HealthStatus::new(reporter), service)
+    }
+
+    /// Create the gRPC server reflection service, describing the node and
+    /// watch services to generic clients such as `grpcurl`.
+    #[cfg(feature = "reflection")]
+    pub fn reflection_service(&self) -> ServerReflectionServer<impl ServerReflection> {
+        tonic_reflection::server::Builder::configure()
+            .register_encoded_file_descriptor_set(proto::FILE_DESCRIPTOR_SET)
+            .build()
+            .expect("file descriptor set generated at build time should be valid")
+    }
+
     pub fn build<T: Node>(&self, inner: T) -> Server<T> {
         let service = NodeService {
             #[cfg(feature = "legacy")]
             legacy_node_id: self.legacy_node_id,
+            metrics: self.metrics.clone(),
+            peer_score: self.peer_score.clone(),
+            subscription_quota: self.max_subscriptions_per_peer.map(SubscriptionQuota::new),
             ..NodeService::new(inner)
         };
-        Server::new(service)
+        let server = Server::new(service).accept_gzip();
+        match self.block_compression {
+            Some(Compression::Gzip) => server.send_gzip(),
+            None => server,
+        }
+    }
+
+    /// Like [`build`](Self::build), but verifies the identity tokens
+    /// of the clients with the given interceptor.
+    pub fn build_with_auth<T: Node>(
+        &self,
+        inner: T,
+        auth: ServerAuth,
+    ) -> InterceptedService<Server<T>, ServerAuth> {
+        InterceptedService::new(self.build(inner), auth)
     }
 }
 
-#[derive(Debug)]
 pub struct NodeService<T> {
     inner: T,
     #[cfg(feature = "legacy")]
     legacy_node_id: Option<legacy::NodeId>,
+    metrics: Option<Arc<dyn Metrics>>,
+    peer_score: Option<Arc<dyn PeerScore>>,
+    subscription_quota: Option<Arc<SubscriptionQuota>>,
+}
+
+impl<T: fmt::Debug> fmt::Debug for NodeService<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut d = f.debug_struct("NodeService");
+        d.field("inner", &self.inner);
+        #[cfg(feature = "legacy")]
+        d.field("legacy_node_id", &self.legacy_node_id);
+        d.field("metrics", &self.metrics.is_some());
+        d.field("peer_score", &self.peer_score.is_some());
+        d.field("subscription_quota", &self.subscription_quota);
+        d.finish()
+    }
 }
 
 impl<T> NodeService<T>
@@ -67,6 +243,9 @@ where
             inner,
             #[cfg(feature = "legacy")]
             legacy_node_id: None,
+            metrics: None,
+            peer_score: None,
+            subscription_quota: None,
         }
     }
 
@@ -88,10 +267,97 @@ where
             .ok_or_else(|| Status::new(Code::Unimplemented, "not implemented"))
     }
 
+    /// Starts accounting a request with a streamed body, returning the
+    /// span in which the request is served.
+    fn stream_meter<M>(
+        &self,
+        service: &'static str,
+        req: &tonic::Request<M>,
+    ) -> (RequestMeter, Span) {
+        let (call_id, span) = call::serve(service, req);
+        let meter = RequestMeter::start(self.metrics.as_ref(), service, call_id, req.remote_addr());
+        (meter, span)
+    }
+
+    /// Starts accounting a request with a single message body, returning
+    /// the span in which the request is served.
+    fn meter<M: prost::Message>(
+        &self,
+        service: &'static str,
+        req: &tonic::Request<M>,
+    ) -> (RequestMeter, Span) {
+        let (meter, span) = self.stream_meter(service, req);
+        meter.received(req.get_ref());
+        (meter, span)
+    }
+
+    /// Reserves a subscription slot for the peer, if the subscriptions
+    /// are limited.
+    fn subscription_permit(
+        &self,
+        remote_addr: Option<SocketAddr>,
+    ) -> Result<Option<SubscriptionPermit>, Status> {
+        match (&self.subscription_quota, remote_addr) {
+            (Some(quota), Some(addr)) => quota.acquire(addr.ip()).map(Some),
+            _ => Ok(None),
+        }
+    }
+
+    /// Reports the misbehavior of the peer that caused the request to fail,
+    /// turning the error into `PermissionDenied` if the peer gets
+    /// quarantined. The details of the error are preserved; if there are
+    /// none, the quarantine is attributed to a protocol violation.
+    fn misbehaved(
+        &self,
+        remote_addr: Option<SocketAddr>,
+        misbehavior: Misbehavior,
+        status: Status,
+    ) -> Status {
+        match (&self.peer_score, remote_addr) {
+            (Some(peer_score), Some(addr)) => match peer_score.record(&addr.into(), misbehavior) {
+                QuarantineDecision::Keep => status,
+                QuarantineDecision::Quarantine => convert::status_with_details(
+                    Code::PermissionDenied,
+                    format!("peer is quarantined after: {}", status.message()),
+                    &convert::details_from_grpc(&status)
+                        .unwrap_or_else(|| ErrorDetails::new(ErrorDomain::Protocol)),
+                ),
+            },
+            _ => status,
+        }
+    }
+
+    /// Reports a request that does not follow the protocol.
+    fn protocol_error(&self, remote_addr: Option<SocketAddr>, err: error::Error) -> Status {
+        self.misbehaved(remote_addr, Misbehavior::ProtocolError, blamed(err).into())
+    }
+
+    /// Reports the failure of the node to process the blocks or headers
+    /// pushed by the peer, if it is the peer's fault.
+    /// The details given by the service, such as the id of the rejected
+    /// block, are passed on to the peer.
+    fn push_error(&self, remote_addr: Option<SocketAddr>, err: error::Error) -> Status {
+        match err.code() {
+            error::Code::InvalidArgument => {
+                self.misbehaved(remote_addr, Misbehavior::InvalidBlock, blamed(err).into())
+            }
+            _ => err.into(),
+        }
+    }
+
     #[allow(unused_mut)]
     #[allow(clippy::let_and_return)]
-    fn subscription_response<S>(&self, outbound: S) -> tonic::Response<OutboundTryStream<S>> {
-        let mut res = tonic::Response::new(OutboundTryStream::new(outbound));
+    fn subscription_response<S>(
+        &self,
+        outbound: S,
+        meter: RequestMeter,
+        permit: Option<SubscriptionPermit>,
+    ) -> tonic::Response<OutboundTryStream<S>> {
+        let mut res = tonic::Response::new(
+            OutboundTryStream::new(outbound)
+                .metered(meter)
+                .with_permit(permit),
+        );
         #[cfg(feature = "legacy")]
         if let Some(node_id) = self.legacy_node_id {
             let val = MetadataValue::from_bytes(&node_id.encode());
@@ -110,6 +376,20 @@ fn remote_addr_to_peer(maybe_addr: Option<SocketAddr>) -> Result<Peer, Status> {
     }
 }
 
+/// Attributes the error to a protocol violation by the peer, unless its
+/// details already tell what it is about.
+fn blamed(err: error::Error) -> error::Error {
+    match err.details() {
+        Some(_) => err,
+        None => err.with_details(ErrorDetails::new(ErrorDomain::Protocol)),
+    }
+}
+
+fn unary_response<M: prost::Message>(res: M, meter: RequestMeter) -> tonic::Response<M> {
+    meter.sent(&res);
+    tonic::Response::new(res)
+}
+
 #[tonic::async_trait]
 impl<T> proto::node::node_server::Node for NodeService<T>
 where
@@ -119,51 +399,99 @@ where
         &self,
         req: tonic::Request<proto::node::HandshakeRequest>,
     ) -> Result<tonic::Response<proto::node::HandshakeResponse>, tonic::Status> {
+        let (meter, span) = self.meter("Handshake", &req);
         let peer = remote_addr_to_peer(req.remote_addr())?;
         let req = req.into_inner();
+        // clients which predate version negotiation do not send their version
+        if req.version != 0 && req.version != PROTOCOL_VERSION {
+            return Err(convert::status_with_details(
+                Code::FailedPrecondition,
+                format!(
+                    "unsupported protocol version {}, expected {}",
+                    req.version, PROTOCOL_VERSION
+                ),
+                &ErrorDetails::new(ErrorDomain::Protocol),
+            ));
+        }
         let nonce = &req.nonce;
-        let hr = self.inner.handshake(peer, nonce).await?;
+        let hr = self
+            .inner
+            .handshake(peer, nonce)
+            .instrument(span.clone())
+            .await?;
+        // the clients only ask for the optional features in this set,
+        // e.g. compressed block streams
+        let negotiated = hr.capabilities & Capabilities::from_bits(req.capabilities);
+        span.in_scope(|| tracing::debug!(%negotiated, "negotiated protocol capabilities"));
         let res = proto::node::HandshakeResponse {
             version: PROTOCOL_VERSION,
             block0: hr.block0_id.as_bytes().into(),
             node_id: hr.auth.id().as_bytes().into(),
             signature: hr.auth.signature().into(),
             nonce: hr.nonce.into(),
+            capabilities: hr.capabilities.bits(),
         };
-        Ok(tonic::Response::new(res))
+        Ok(unary_response(res, meter))
     }
 
     async fn client_auth(
         &self,
         req: tonic::Request<proto::node::ClientAuthRequest>,
     ) -> Result<tonic::Response<proto::node::ClientAuthResponse>, tonic::Status> {
-        let peer = remote_addr_to_peer(req.remote_addr())?;
+        let (meter, span) = self.meter("ClientAuth", &req);
+        let remote_addr = req.remote_addr();
+        let peer = remote_addr_to_peer(remote_addr)?;
         let req = req.into_inner();
-        let node_id = NodeId::try_from(&req.node_id[..])?;
-        let auth = node_id.authenticated(&req.signature)?;
-        self.inner.client_auth(peer, auth).await?;
+        let auth = NodeId::try_from(&req.node_id[..])
+            .and_then(|node_id| node_id.authenticated(&req.signature))
+            .map_err(|e| self.protocol_error(remote_addr, e))?;
+        self.inner.client_auth(peer, auth).instrument(span).await?;
         let res = proto::node::ClientAuthResponse {};
-        Ok(tonic::Response::new(res))
+        Ok(unary_response(res, meter))
     }
 
     async fn tip(
         &self,
-        _: tonic::Request<proto::node::TipRequest>,
+        req: tonic::Request<proto::node::TipRequest>,
     ) -> Result<tonic::Response<proto::node::TipResponse>, tonic::Status> {
+        let (meter, span) = self.meter("Tip", &req);
         let service = self.block_service()?;
-        let header = service.tip().await?;
+        let header = service.tip().instrument(span).await?;
         let res = proto::node::TipResponse {
             block_header: header.into(),
         };
-        Ok(tonic::Response::new(res))
+        Ok(unary_response(res, meter))
+    }
+
+    type TipSubscriptionStream =
+        OutboundTryStream<<T::BlockService as BlockService>::TipSubscriptionStream>;
+
+    async fn tip_subscription(
+        &self,
+        req: tonic::Request<proto::node::TipSubscriptionRequest>,
+    ) -> Result<tonic::Response<Self::TipSubscriptionStream>, tonic::Status> {
+        let (meter, span) = self.meter("TipSubscription", &req);
+        let service = self.block_service()?;
+        let peer = remote_addr_to_peer(req.remote_addr())?;
+        let permit = self.subscription_permit(req.remote_addr())?;
+        let stream = service.tip_subscription(peer).instrument(span).await?;
+        Ok(tonic::Response::new(
+            OutboundTryStream::new(stream)
+                .metered(meter)
+                .with_permit(permit),
+        ))
     }
 
     async fn peers(
         &self,
         req: tonic::Request<proto::node::PeersRequest>,
     ) -> Result<tonic::Response<proto::node::PeersResponse>, tonic::Status> {
+        let (meter, span) = self.meter("Peers", &req);
         let service = self.gossip_service()?;
-        let peers = service.peers(req.into_inner().limit).await?;
+        let peers = service
+            .peers(req.into_inner().limit)
+            .instrument(span)
+            .await?;
         let res = proto::node::PeersResponse {
             peers: peers
                 .nodes
@@ -172,7 +500,7 @@ where
                 .map(|node| node.into_bytes())
                 .collect(),
         };
-        Ok(tonic::Response::new(res))
+        Ok(unary_response(res, meter))
     }
 
     type GetBlocksStream = OutboundTryStream<<T::BlockService as BlockService>::GetBlocksStream>;
@@ -181,10 +509,15 @@ where
         &self,
         req: tonic::Request<proto::types::BlockIds>,
     ) -> Result<tonic::Response<Self::GetBlocksStream>, tonic::Status> {
+        let (meter, span) = self.meter("GetBlocks", &req);
         let service = self.block_service()?;
-        let ids = block::try_ids_from_iter(req.into_inner().ids)?;
-        let stream = service.get_blocks(ids).await?;
-        Ok(tonic::Response::new(OutboundTryStream::new(stream)))
+        let remote_addr = req.remote_addr();
+        let ids = block::try_ids_from_iter(req.into_inner().ids)
+            .map_err(|e| self.protocol_error(remote_addr, e))?;
+        let stream = service.get_blocks(ids).instrument(span).await?;
+        Ok(tonic::Response::new(
+            OutboundTryStream::new(stream).metered(meter),
+        ))
     }
 
     type GetHeadersStream = OutboundTryStream<<T::BlockService as BlockService>::GetHeadersStream>;
@@ -193,10 +526,15 @@ where
         &self,
         req: tonic::Request<proto::types::BlockIds>,
     ) -> Result<tonic::Response<Self::GetHeadersStream>, tonic::Status> {
+        let (meter, span) = self.meter("GetHeaders", &req);
         let service = self.block_service()?;
-        let ids = block::try_ids_from_iter(req.into_inner().ids)?;
-        let stream = service.get_headers(ids).await?;
-        Ok(tonic::Response::new(OutboundTryStream::new(stream)))
+        let remote_addr = req.remote_addr();
+        let ids = block::try_ids_from_iter(req.into_inner().ids)
+            .map_err(|e| self.protocol_error(remote_addr, e))?;
+        let stream = service.get_headers(ids).instrument(span).await?;
+        Ok(tonic::Response::new(
+            OutboundTryStream::new(stream).metered(meter),
+        ))
     }
 
     type GetFragmentsStream =
@@ -206,10 +544,15 @@ where
         &self,
         req: tonic::Request<proto::types::FragmentIds>,
     ) -> Result<tonic::Response<Self::GetFragmentsStream>, tonic::Status> {
+        let (meter, span) = self.meter("GetFragments", &req);
         let service = self.fragment_service()?;
-        let ids = fragment::try_ids_from_iter(req.into_inner().ids)?;
-        let stream = service.get_fragments(ids).await?;
-        Ok(tonic::Response::new(OutboundTryStream::new(stream)))
+        let remote_addr = req.remote_addr();
+        let ids = fragment::try_ids_from_iter(req.into_inner().ids)
+            .map_err(|e| self.protocol_error(remote_addr, e))?;
+        let stream = service.get_fragments(ids).instrument(span).await?;
+        Ok(tonic::Response::new(
+            OutboundTryStream::new(stream).metered(meter),
+        ))
     }
 
     type PullHeadersStream =
@@ -219,16 +562,19 @@ where
         &self,
         req: tonic::Request<proto::node::PullHeadersRequest>,
     ) -> Result<tonic::Response<Self::PullHeadersStream>, tonic::Status> {
+        let (meter, span) = self.meter("PullHeaders", &req);
         let service = self.block_service()?;
+        let remote_addr = req.remote_addr();
         let (from, to) = {
             let req = req.into_inner();
-            (
-                block::try_ids_from_iter(req.from)?,
-                BlockId::try_from(&req.to[..])?,
-            )
+            block::try_ids_from_iter(req.from)
+                .and_then(|from| Ok((from, BlockId::try_from(&req.to[..])?)))
+                .map_err(|e| self.protocol_error(remote_addr, e))?
         };
-        let stream = service.pull_headers(from, to).await?;
-        Ok(tonic::Response::new(OutboundTryStream::new(stream)))
+        let stream = service.pull_headers(from, to).instrument(span).await?;
+        Ok(tonic::Response::new(
+            OutboundTryStream::new(stream).metered(meter),
+        ))
     }
 
     type PullBlocksStream = OutboundTryStream<<T::BlockService as BlockService>::PullBlocksStream>;
@@ -237,12 +583,17 @@ where
         &self,
         req: tonic::Request<proto::node::PullBlocksRequest>,
     ) -> Result<tonic::Response<Self::PullBlocksStream>, tonic::Status> {
+        let (meter, span) = self.meter("PullBlocks", &req);
         let service = self.block_service()?;
+        let remote_addr = req.remote_addr();
         let req = req.into_inner();
-        let from = block::try_ids_from_iter(req.from)?;
-        let to = BlockId::try_from(&req.to[..])?;
-        let stream = service.pull_blocks(from, to).await?;
-        Ok(tonic::Response::new(OutboundTryStream::new(stream)))
+        let (from, to) = block::try_ids_from_iter(req.from)
+            .and_then(|from| Ok((from, BlockId::try_from(&req.to[..])?)))
+            .map_err(|e| self.protocol_error(remote_addr, e))?;
+        let stream = service.pull_blocks(from, to).instrument(span).await?;
+        Ok(tonic::Response::new(
+            OutboundTryStream::new(stream).metered(meter),
+        ))
     }
 
     type PullBlocksToTipStream =
@@ -252,30 +603,47 @@ where
         &self,
         req: tonic::Request<proto::node::PullBlocksToTipRequest>,
     ) -> Result<tonic::Response<Self::PullBlocksToTipStream>, tonic::Status> {
+        let (meter, span) = self.meter("PullBlocksToTip", &req);
         let service = self.block_service()?;
-        let from = block::try_ids_from_iter(req.into_inner().from)?;
-        let stream = service.pull_blocks_to_tip(from).await?;
-        Ok(tonic::Response::new(OutboundTryStream::new(stream)))
+        let remote_addr = req.remote_addr();
+        let from = block::try_ids_from_iter(req.into_inner().from)
+            .map_err(|e| self.protocol_error(remote_addr, e))?;
+        let stream = service.pull_blocks_to_tip(from).instrument(span).await?;
+        Ok(tonic::Response::new(
+            OutboundTryStream::new(stream).metered(meter),
+        ))
     }
 
     async fn push_headers(
         &self,
         req: tonic::Request<tonic::Streaming<proto::types::Header>>,
     ) -> Result<tonic::Response<proto::node::PushHeadersResponse>, tonic::Status> {
+        let (meter, span) = self.stream_meter("PushHeaders", &req);
         let service = self.block_service()?;
-        let stream = InboundStream::new(req.into_inner());
-        service.push_headers(Box::pin(stream)).await?;
-        Ok(tonic::Response::new(proto::node::PushHeadersResponse {}))
+        let remote_addr = req.remote_addr();
+        let stream = InboundStream::new(req.into_inner()).metered(meter.clone());
+        service
+            .push_headers(Box::pin(stream))
+            .instrument(span)
+            .await
+            .map_err(|e| self.push_error(remote_addr, e))?;
+        Ok(unary_response(proto::node::PushHeadersResponse {}, meter))
     }
 
     async fn upload_blocks(
         &self,
         req: tonic::Request<tonic::Streaming<proto::types::Block>>,
     ) -> Result<tonic::Response<proto::node::UploadBlocksResponse>, tonic::Status> {
+        let (meter, span) = self.stream_meter("UploadBlocks", &req);
         let service = self.block_service()?;
-        let stream = InboundStream::new(req.into_inner());
-        service.upload_blocks(Box::pin(stream)).await?;
-        Ok(tonic::Response::new(proto::node::UploadBlocksResponse {}))
+        let remote_addr = req.remote_addr();
+        let stream = InboundStream::new(req.into_inner()).metered(meter.clone());
+        service
+            .upload_blocks(Box::pin(stream))
+            .instrument(span)
+            .await
+            .map_err(|e| self.push_error(remote_addr, e))?;
+        Ok(unary_response(proto::node::UploadBlocksResponse {}, meter))
     }
 
     type BlockSubscriptionStream =
@@ -285,11 +653,16 @@ where
         &self,
         req: tonic::Request<tonic::Streaming<proto::types::Header>>,
     ) -> Result<tonic::Response<Self::BlockSubscriptionStream>, tonic::Status> {
+        let (meter, span) = self.stream_meter("BlockSubscription", &req);
         let service = self.block_service()?;
         let peer = remote_addr_to_peer(req.remote_addr())?;
-        let inbound = InboundStream::new(req.into_inner());
-        let outbound = service.block_subscription(peer, Box::pin(inbound)).await?;
-        let res = self.subscription_response(outbound);
+        let permit = self.subscription_permit(req.remote_addr())?;
+        let inbound = InboundStream::new(req.into_inner()).metered(meter.clone());
+        let outbound = service
+            .block_subscription(peer, Box::pin(inbound))
+            .instrument(span)
+            .await?;
+        let res = self.subscription_response(outbound, meter, permit);
         Ok(res)
     }
 
@@ -300,13 +673,36 @@ where
         &self,
         req: tonic::Request<tonic::Streaming<proto::types::Fragment>>,
     ) -> Result<tonic::Response<Self::FragmentSubscriptionStream>, tonic::Status> {
+        let (meter, span) = self.stream_meter("FragmentSubscription", &req);
         let service = self.fragment_service()?;
         let peer = remote_addr_to_peer(req.remote_addr())?;
-        let inbound = InboundStream::new(req.into_inner());
+        let permit = self.subscription_permit(req.remote_addr())?;
+        let inbound = InboundStream::new(req.into_inner()).metered(meter.clone());
         let outbound = service
             .fragment_subscription(peer, Box::pin(inbound))
+            .instrument(span)
+            .await?;
+        let res = self.subscription_response(outbound, meter, permit);
+        Ok(res)
+    }
+
+    type FragmentSubscriptionWithAckStream =
+        OutboundTryStream<<T::FragmentService as FragmentService>::AckSubscriptionStream>;
+
+    async fn fragment_subscription_with_ack(
+        &self,
+        req: tonic::Request<tonic::Streaming<proto::types::Fragment>>,
+    ) -> Result<tonic::Response<Self::FragmentSubscriptionWithAckStream>, tonic::Status> {
+        let (meter, span) = self.stream_meter("FragmentSubscriptionWithAck", &req);
+        let service = self.fragment_service()?;
+        let peer = remote_addr_to_peer(req.remote_addr())?;
+        let permit = self.subscription_permit(req.remote_addr())?;
+        let inbound = InboundStream::new(req.into_inner()).metered(meter.clone());
+        let outbound = service
+            .fragment_subscription_with_ack(peer, Box::pin(inbound))
+            .instrument(span)
             .await?;
-        let res = self.subscription_response(outbound);
+        let res = self.subscription_response(outbound, meter, permit);
         Ok(res)
     }
 
@@ -317,11 +713,16 @@ where
         &self,
         req: tonic::Request<tonic::Streaming<proto::node::Gossip>>,
     ) -> Result<tonic::Response<Self::GossipSubscriptionStream>, tonic::Status> {
+        let (meter, span) = self.stream_meter("GossipSubscription", &req);
         let service = self.gossip_service()?;
         let peer = remote_addr_to_peer(req.remote_addr())?;
-        let inbound = InboundStream::new(req.into_inner());
-        let outbound = service.gossip_subscription(peer, Box::pin(inbound)).await?;
-        let res = self.subscription_response(outbound);
+        let permit = self.subscription_permit(req.remote_addr())?;
+        let inbound = InboundStream::new(req.into_inner()).metered(meter.clone());
+        let outbound = service
+            .gossip_subscription(peer, Box::pin(inbound))
+            .instrument(span)
+            .await?;
+        let res = self.subscription_response(outbound, meter, permit);
         Ok(res)
     }
 }
@@ -0,0 +1,304 @@
+use futures::prelude::*;
+use futures::stream::Fuse;
+use pin_project::pin_project;
+
+use std::collections::VecDeque;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+/// What to do with an inbound item when the buffer is full.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverflowPolicy {
+    /// Evict the oldest buffered item to make room for the new one.
+    DropOldest,
+    /// Discard the newly received item.
+    DropNewest,
+    /// Stop reading from the connection until the consumer catches up,
+    /// letting HTTP/2 flow control push back on the peer.
+    Park,
+}
+
+/// Limits the number of items read ahead from an inbound subscription.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InboundBuffer {
+    limit: usize,
+    policy: OverflowPolicy,
+}
+
+impl InboundBuffer {
+    /// Creates a buffer configuration. A limit of zero is treated as one.
+    pub fn new(limit: usize, policy: OverflowPolicy) -> Self {
+        InboundBuffer {
+            limit: limit.max(1),
+            policy,
+        }
+    }
+
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
+    pub fn policy(&self) -> OverflowPolicy {
+        self.policy
+    }
+}
+
+#[derive(Debug, Default)]
+struct Counters {
+    received: AtomicU64,
+    dropped: AtomicU64,
+    coalesced: AtomicU64,
+    parked: AtomicU64,
+    buffered: AtomicUsize,
+}
+
+/// Counters of a buffered subscription, shared with the stream so that
+/// they can be read while the stream is being consumed.
+#[derive(Debug, Clone, Default)]
+pub struct InboundStats {
+    counters: Arc<Counters>,
+}
+
+impl InboundStats {
+    /// Number of items received from the peer.
+    pub fn received(&self) -> u64 {
+        self.counters.received.load(Ordering::Relaxed)
+    }
+
+    /// Number of items discarded because the buffer was full.
+    pub fn dropped(&self) -> u64 {
+        self.counters.dropped.load(Ordering::Relaxed)
+    }
+
+    /// Number of items discarded because an identical one was already
+    /// waiting in the buffer.
+    pub fn coalesced(&self) -> u64 {
+        self.counters.coalesced.load(Ordering::Relaxed)
+    }
+
+    /// Number of times reading from the peer was suspended because
+    /// the buffer was full.
+    pub fn parked(&self) -> u64 {
+        self.counters.parked.load(Ordering::Relaxed)
+    }
+
+    /// Number of items currently waiting in the buffer.
+    pub fn buffered(&self) -> usize {
+        self.counters.buffered.load(Ordering::Relaxed)
+    }
+}
+
+/// Inbound stream adapter reading ahead up to a bounded number of items.
+///
+/// Every time the stream is polled, all items readily available from
+/// the underlying stream are moved into the buffer, applying
+/// the configured [`OverflowPolicy`] once it is full. Errors are
+/// buffered as any other item, so with a dropping policy a lagging consumer
+/// may miss them; the stream still terminates when the peer closes it.
+#[must_use = "streams do nothing unless polled"]
+#[pin_project]
+pub struct BufferedInbound<S: Stream> {
+    #[pin]
+    inner: Fuse<S>,
+    buffer: VecDeque<S::Item>,
+    config: InboundBuffer,
+    coalesce: Option<fn(&S::Item, &S::Item) -> bool>,
+    parked: bool,
+    stats: InboundStats,
+}
+
+impl<S: Stream> BufferedInbound<S> {
+    pub fn new(inner: S, config: InboundBuffer) -> Self {
+        BufferedInbound {
+            inner: inner.fuse(),
+            buffer: VecDeque::with_capacity(config.limit),
+            config,
+            coalesce: None,
+            parked: false,
+            stats: InboundStats::default(),
+        }
+    }
+
+    pub fn config(&self) -> InboundBuffer {
+        self.config
+    }
+
+    /// Discards the received items for which `same` tells that an identical
+    /// item is already waiting in the buffer, e.g. a fragment which is
+    /// received again before the first copy is processed.
+    pub fn coalescing(self, same: fn(&S::Item, &S::Item) -> bool) -> Self {
+        BufferedInbound {
+            coalesce: Some(same),
+            ..self
+        }
+    }
+
+    /// Returns a handle to the counters of this stream.
+    pub fn stats(&self) -> InboundStats {
+        self.stats.clone()
+    }
+}
+
+impl<S: Stream> Stream for BufferedInbound<S> {
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        let counters = &this.stats.counters;
+        loop {
+            let full = this.buffer.len() >= this.config.limit;
+            if full && this.config.policy == OverflowPolicy::Park {
+                if !*this.parked {
+                    *this.parked = true;
+                    counters.parked.fetch_add(1, Ordering::Relaxed);
+                }
+                break;
+            }
+            *this.parked = false;
+            match this.inner.as_mut().poll_next(cx) {
+                Poll::Ready(Some(item)) => {
+                    counters.received.fetch_add(1, Ordering::Relaxed);
+                    if let Some(same) = this.coalesce {
+                        if this.buffer.iter().any(|buffered| same(buffered, &item)) {
+                            counters.coalesced.fetch_add(1, Ordering::Relaxed);
+                            continue;
+                        }
+                    }
+                    if full {
+                        counters.dropped.fetch_add(1, Ordering::Relaxed);
+                        match this.config.policy {
+                            OverflowPolicy::DropOldest => {
+                                this.buffer.pop_front();
+                            }
+                            OverflowPolicy::DropNewest => continue,
+                            OverflowPolicy::Park => unreachable!(),
+                        }
+                    }
+                    this.buffer.push_back(item);
+                }
+                Poll::Ready(None) | Poll::Pending => break,
+            }
+        }
+
+        let next = this.buffer.pop_front();
+        counters
+            .buffered
+            .store(this.buffer.len(), Ordering::Relaxed);
+        match next {
+            Some(item) => Poll::Ready(Some(item)),
+            None if this.inner.is_done() => Poll::Ready(None),
+            None => Poll::Pending,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::executor::block_on;
+    use futures::stream;
+
+    // all the items are readily available, so the first poll fills the buffer
+    fn buffered(
+        policy: OverflowPolicy,
+    ) -> BufferedInbound<stream::Iter<std::ops::RangeInclusive<u32>>> {
+        BufferedInbound::new(stream::iter(1..=6), InboundBuffer::new(3, policy))
+    }
+
+    #[test]
+    fn drop_oldest_delivers_latest_items() {
+        let mut stream = buffered(OverflowPolicy::DropOldest);
+        let stats = stream.stats();
+        assert_eq!(block_on(stream.next()), Some(4));
+        assert_eq!(stats.received(), 6);
+        assert_eq!(stats.dropped(), 3);
+        assert_eq!(stats.buffered(), 2);
+        assert_eq!(block_on(stream.collect::<Vec<_>>()), vec![5, 6]);
+        assert_eq!(stats.parked(), 0);
+        assert_eq!(stats.buffered(), 0);
+    }
+
+    #[test]
+    fn drop_newest_delivers_first_items() {
+        let mut stream = buffered(OverflowPolicy::DropNewest);
+        let stats = stream.stats();
+        assert_eq!(block_on(stream.next()), Some(1));
+        assert_eq!(stats.received(), 6);
+        assert_eq!(stats.dropped(), 3);
+        assert_eq!(stats.buffered(), 2);
+        assert_eq!(block_on(stream.collect::<Vec<_>>()), vec![2, 3]);
+        assert_eq!(stats.parked(), 0);
+        assert_eq!(stats.buffered(), 0);
+    }
+
+    #[test]
+    fn park_delivers_all_items() {
+        let mut stream = buffered(OverflowPolicy::Park);
+        let stats = stream.stats();
+        assert_eq!(block_on(stream.next()), Some(1));
+        assert_eq!(stats.received(), 3);
+        assert_eq!(stats.parked(), 1);
+        assert_eq!(stats.buffered(), 2);
+        assert_eq!(block_on(stream.collect::<Vec<_>>()), vec![2, 3, 4, 5, 6]);
+        assert_eq!(stats.received(), 6);
+        assert_eq!(stats.dropped(), 0);
+        // parked again after reading each of the remaining items
+        assert_eq!(stats.parked(), 4);
+        assert_eq!(stats.buffered(), 0);
+    }
+
+    #[test]
+    fn coalescing_discards_items_waiting_in_buffer() {
+        let mut stream = BufferedInbound::new(
+            stream::iter(vec![1, 2, 1, 3, 2]),
+            InboundBuffer::new(8, OverflowPolicy::Park),
+        )
+        .coalescing(|a, b| a == b);
+        let stats = stream.stats();
+        assert_eq!(block_on(stream.next()), Some(1));
+        assert_eq!(stats.received(), 5);
+        assert_eq!(stats.coalesced(), 2);
+        assert_eq!(block_on(stream.collect::<Vec<_>>()), vec![2, 3]);
+    }
+
+    #[test]
+    fn coalescing_keeps_items_already_delivered() {
+        let mut stream = BufferedInbound::new(
+            stream::iter(vec![1, 2, 1]),
+            InboundBuffer::new(1, OverflowPolicy::Park),
+        )
+        .coalescing(|a, b| a == b);
+        let stats = stream.stats();
+        // the first copy is no longer in the buffer when the second one arrives
+        assert_eq!(block_on(stream.next()), Some(1));
+        assert_eq!(block_on(stream.collect::<Vec<_>>()), vec![2, 1]);
+        assert_eq!(stats.coalesced(), 0);
+    }
+
+    #[test]
+    fn coalesced_items_are_not_counted_as_dropped() {
+        let stream = BufferedInbound::new(
+            stream::iter(vec![1, 1, 2, 3]),
+            InboundBuffer::new(2, OverflowPolicy::DropNewest),
+        )
+        .coalescing(|a, b| a == b);
+        let stats = stream.stats();
+        assert_eq!(block_on(stream.collect::<Vec<_>>()), vec![1, 2]);
+        assert_eq!(stats.coalesced(), 1);
+        assert_eq!(stats.dropped(), 1);
+    }
+
+    #[test]
+    fn zero_limit_buffers_one_item() {
+        let stream = BufferedInbound::new(
+            stream::iter(1..=3),
+            InboundBuffer::new(0, OverflowPolicy::DropNewest),
+        );
+        let stats = stream.stats();
+        assert_eq!(stream.config().limit(), 1);
+        assert_eq!(block_on(stream.collect::<Vec<_>>()), vec![1]);
+        assert_eq!(stats.dropped(), 2);
+    }
+}
@@ -0,0 +1,319 @@
+use futures::prelude::*;
+use futures::ready;
+use pin_project::pin_project;
+
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+
+/// Limits of a cache of recently seen inbound items.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DedupConfig {
+    capacity: usize,
+    ttl: Duration,
+}
+
+impl DedupConfig {
+    /// Creates a cache configuration. A capacity of zero is treated as one.
+    pub fn new(capacity: usize, ttl: Duration) -> Self {
+        DedupConfig {
+            capacity: capacity.max(1),
+            ttl,
+        }
+    }
+
+    /// Maximum number of keys remembered, the oldest ones are forgotten
+    /// first.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// How long a key is remembered after it was first seen.
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+}
+
+#[derive(Debug, Default)]
+struct Counters {
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+/// Counters of a seen-recently cache or of a deduplicated stream, shared
+/// so that they can be read while the stream is being consumed.
+#[derive(Debug, Clone, Default)]
+pub struct DedupStats {
+    counters: Arc<Counters>,
+}
+
+impl DedupStats {
+    /// Number of items discarded because they were seen recently.
+    pub fn hits(&self) -> u64 {
+        self.counters.hits.load(Ordering::Relaxed)
+    }
+
+    /// Number of items seen for the first time within the TTL.
+    pub fn misses(&self) -> u64 {
+        self.counters.misses.load(Ordering::Relaxed)
+    }
+
+    fn record(&self, seen: bool) {
+        if seen {
+            self.counters.hits.fetch_add(1, Ordering::Relaxed);
+        } else {
+            self.counters.misses.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Entries<K> {
+    seen: HashMap<K, Instant>,
+    // keys in the order they were first seen, with the time they were seen;
+    // a key seen again after expiry is queued once more, the stale entry is
+    // recognized by its time when it reaches the front
+    order: VecDeque<(K, Instant)>,
+}
+
+impl<K: Hash + Eq + Clone> Entries<K> {
+    fn pop_front(&mut self) {
+        if let Some((key, time)) = self.order.pop_front() {
+            if self.seen.get(&key) == Some(&time) {
+                self.seen.remove(&key);
+            }
+        }
+    }
+
+    fn purge(&mut self, now: Instant, config: &DedupConfig) {
+        while let Some((_, time)) = self.order.front() {
+            if now.saturating_duration_since(*time) < config.ttl {
+                break;
+            }
+            self.pop_front();
+        }
+        while self.seen.len() >= config.capacity && !self.order.is_empty() {
+            self.pop_front();
+        }
+    }
+}
+
+/// Cache of the keys of the items recently received, e.g. the ids of the
+/// block headers or fragments announced by the peers.
+///
+/// The cache is cheaply cloneable and meant to be shared by the
+/// subscriptions of all peers, so that an item announced by many of them is
+/// only processed once.
+#[derive(Debug, Clone)]
+pub struct SeenCache<K> {
+    entries: Arc<Mutex<Entries<K>>>,
+    config: DedupConfig,
+    stats: DedupStats,
+}
+
+impl<K: Hash + Eq + Clone> SeenCache<K> {
+    pub fn new(config: DedupConfig) -> Self {
+        SeenCache {
+            entries: Arc::new(Mutex::new(Entries {
+                seen: HashMap::with_capacity(config.capacity),
+                order: VecDeque::with_capacity(config.capacity),
+            })),
+            config,
+            stats: DedupStats::default(),
+        }
+    }
+
+    pub fn config(&self) -> DedupConfig {
+        self.config
+    }
+
+    /// Records the key as seen, returning `true` if it has already been
+    /// seen within the TTL.
+    pub fn check(&self, key: K) -> bool {
+        let now = Instant::now();
+        let mut entries = self.entries.lock().unwrap();
+        let seen = match entries.seen.get(&key) {
+            Some(time) => now.saturating_duration_since(*time) < self.config.ttl,
+            None => false,
+        };
+        if !seen {
+            entries.purge(now, &self.config);
+            entries.seen.insert(key.clone(), now);
+            entries.order.push_back((key, now));
+        }
+        self.stats.record(seen);
+        seen
+    }
+
+    /// Number of keys currently remembered, expired ones included until
+    /// they are purged.
+    pub fn len(&self) -> usize {
+        self.entries.lock().unwrap().seen.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a handle to the counters of all the lookups in this cache.
+    pub fn stats(&self) -> DedupStats {
+        self.stats.clone()
+    }
+}
+
+/// Inbound stream adapter discarding the items seen recently.
+///
+/// The key of each item is looked up in a [`SeenCache`], usually shared with
+/// the subscriptions of the other peers. Items without a key, such as
+/// errors, are passed through, as are all items when no cache is given.
+#[must_use = "streams do nothing unless polled"]
+#[pin_project]
+pub struct DedupInbound<S: Stream, K> {
+    #[pin]
+    inner: S,
+    cache: Option<SeenCache<K>>,
+    key: fn(&S::Item) -> Option<K>,
+    stats: DedupStats,
+}
+
+impl<S: Stream, K: Hash + Eq + Clone> DedupInbound<S, K> {
+    pub fn new(inner: S, cache: Option<SeenCache<K>>, key: fn(&S::Item) -> Option<K>) -> Self {
+        DedupInbound {
+            inner,
+            cache,
+            key,
+            stats: DedupStats::default(),
+        }
+    }
+
+    /// Returns a handle to the counters of this stream.
+    pub fn stats(&self) -> DedupStats {
+        self.stats.clone()
+    }
+}
+
+impl<S: Stream, K: Hash + Eq + Clone> Stream for DedupInbound<S, K> {
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        loop {
+            match ready!(this.inner.as_mut().poll_next(cx)) {
+                Some(item) => {
+                    let cache = match this.cache {
+                        Some(cache) => cache,
+                        None => return Poll::Ready(Some(item)),
+                    };
+                    if let Some(key) = (this.key)(&item) {
+                        let seen = cache.check(key);
+                        this.stats.record(seen);
+                        if seen {
+                            continue;
+                        }
+                    }
+                    return Poll::Ready(Some(item));
+                }
+                None => return Poll::Ready(None),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::executor::block_on;
+    use futures::stream;
+
+    const LONG_TTL: Duration = Duration::from_secs(3600);
+
+    // zero is an item without a key, passed through unconditionally
+    fn key(item: &u32) -> Option<u32> {
+        (*item != 0).then_some(*item)
+    }
+
+    fn dedup(items: Vec<u32>, cache: Option<SeenCache<u32>>) -> (Vec<u32>, DedupStats) {
+        let stream = DedupInbound::new(stream::iter(items), cache, key);
+        let stats = stream.stats();
+        (block_on(stream.collect()), stats)
+    }
+
+    #[test]
+    fn hit_within_ttl() {
+        let cache = SeenCache::new(DedupConfig::new(16, LONG_TTL));
+        assert!(!cache.check(1));
+        assert!(cache.check(1));
+        assert!(!cache.check(2));
+        assert_eq!(cache.len(), 2);
+    }
+
+    #[test]
+    fn miss_after_expiry() {
+        let cache = SeenCache::new(DedupConfig::new(16, Duration::ZERO));
+        assert!(!cache.check(1));
+        assert!(!cache.check(1));
+        // the expired entry is purged when the key is seen again
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[test]
+    fn oldest_keys_evicted_at_capacity() {
+        let cache = SeenCache::new(DedupConfig::new(2, LONG_TTL));
+        assert!(!cache.check(1));
+        assert!(!cache.check(2));
+        assert!(!cache.check(3));
+        assert_eq!(cache.len(), 2);
+        assert!(cache.check(3));
+        assert!(cache.check(2));
+        assert!(!cache.check(1));
+    }
+
+    #[test]
+    fn stale_entry_at_front_keeps_requeued_key() {
+        let first = Instant::now();
+        let again = first + Duration::from_secs(1);
+        let mut entries = Entries {
+            seen: HashMap::from([(1, again), (2, first)]),
+            order: VecDeque::from([(1, first), (2, first), (1, again)]),
+        };
+        entries.pop_front();
+        assert_eq!(entries.seen.get(&1), Some(&again));
+        entries.pop_front();
+        assert_eq!(entries.seen.get(&2), None);
+        entries.pop_front();
+        assert!(entries.seen.is_empty());
+        assert!(entries.order.is_empty());
+    }
+
+    #[test]
+    fn pass_through_without_cache() {
+        let (items, stats) = dedup(vec![1, 1, 0, 2, 1], None);
+        assert_eq!(items, vec![1, 1, 0, 2, 1]);
+        assert_eq!(stats.hits(), 0);
+        assert_eq!(stats.misses(), 0);
+    }
+
+    #[test]
+    fn counters_of_stream_and_cache() {
+        let cache = SeenCache::new(DedupConfig::new(16, LONG_TTL));
+        let (items, stats) = dedup(vec![1, 2, 0, 1, 3, 0, 2], Some(cache.clone()));
+        assert_eq!(items, vec![1, 2, 0, 3, 0]);
+        assert_eq!(stats.hits(), 2);
+        assert_eq!(stats.misses(), 3);
+
+        // another stream sharing the cache only counts its own lookups
+        let (items, other) = dedup(vec![3, 4], Some(cache.clone()));
+        assert_eq!(items, vec![4]);
+        assert_eq!(other.hits(), 1);
+        assert_eq!(other.misses(), 1);
+        assert_eq!(stats.hits(), 2);
+
+        let total = cache.stats();
+        assert_eq!(total.hits(), 3);
+        assert_eq!(total.misses(), 4);
+    }
+}
@@ -1,5 +1,7 @@
+use super::{BufferedInbound, DedupInbound, InboundBuffer, SeenCache};
 use crate::error::Error;
 use crate::grpc::convert::{error_from_grpc, FromProtobuf};
+use crate::grpc::metrics::RequestMeter;
 use futures::prelude::*;
 use pin_project::pin_project;
 use tonic::Streaming;
@@ -13,6 +15,7 @@ use std::task::{Context, Poll};
 pub struct InboundStream<P, T> {
     #[pin]
     inner: Streaming<P>,
+    meter: RequestMeter,
     _phantom: PhantomData<T>,
 }
 
@@ -20,22 +23,54 @@ impl<P, T> InboundStream<P, T> {
     pub(crate) fn new(inner: Streaming<P>) -> Self {
         InboundStream {
             inner,
+            meter: RequestMeter::default(),
             _phantom: PhantomData,
         }
     }
+
+    /// Accounts the received messages to the request served by the server.
+    pub(crate) fn metered(self, meter: RequestMeter) -> Self {
+        InboundStream { meter, ..self }
+    }
+
+    /// Reads ahead into a bounded buffer, see [`BufferedInbound`].
+    pub fn buffered(self, config: InboundBuffer) -> BufferedInbound<Self>
+    where
+        P: prost::Message,
+        T: FromProtobuf<P>,
+    {
+        BufferedInbound::new(self, config)
+    }
+
+    /// Discards the items seen recently, see [`DedupInbound`].
+    pub fn deduplicated<K>(
+        self,
+        cache: SeenCache<K>,
+        key: fn(&Result<T, Error>) -> Option<K>,
+    ) -> DedupInbound<Self, K>
+    where
+        P: prost::Message,
+        T: FromProtobuf<P>,
+        K: std::hash::Hash + Eq + Clone,
+    {
+        DedupInbound::new(self, Some(cache), key)
+    }
 }
 
 impl<P, T> Stream for InboundStream<P, T>
 where
+    P: prost::Message,
     T: FromProtobuf<P>,
 {
     type Item = Result<T, Error>;
 
     fn poll_next(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>> {
         let this = self.project();
+        let meter = this.meter;
         this.inner.poll_next(cx).map(|opt| {
             opt.map(|item| match item {
                 Ok(msg) => {
+                    meter.received(&msg);
                     let item = T::from_message(msg)?;
                     Ok(item)
                 }
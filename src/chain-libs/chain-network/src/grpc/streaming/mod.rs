@@ -1,5 +1,9 @@
+mod buffered;
+mod dedup;
 mod inbound;
 mod outbound;
 
+pub use buffered::{BufferedInbound, InboundBuffer, InboundStats, OverflowPolicy};
+pub use dedup::{DedupConfig, DedupInbound, DedupStats, SeenCache};
 pub use inbound::InboundStream;
 pub(super) use outbound::{OutboundStream, OutboundTryStream};
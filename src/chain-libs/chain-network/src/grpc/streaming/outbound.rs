@@ -1,5 +1,7 @@
 use crate::error::Error;
 use crate::grpc::convert::{error_into_grpc, IntoProtobuf};
+use crate::grpc::metrics::RequestMeter;
+use crate::grpc::quota::SubscriptionPermit;
 use futures::prelude::*;
 use pin_project::pin_project;
 use tonic::Status;
@@ -40,11 +42,27 @@ where
 pub struct OutboundTryStream<S> {
     #[pin]
     inner: S,
+    meter: RequestMeter,
+    permit: Option<SubscriptionPermit>,
 }
 
 impl<S> OutboundTryStream<S> {
     pub(crate) fn new(inner: S) -> Self {
-        OutboundTryStream { inner }
+        OutboundTryStream {
+            inner,
+            meter: RequestMeter::default(),
+            permit: None,
+        }
+    }
+
+    /// Accounts the sent messages to the request served by the server.
+    pub(crate) fn metered(self, meter: RequestMeter) -> Self {
+        OutboundTryStream { meter, ..self }
+    }
+
+    /// Holds the subscription slot of the peer until the stream is dropped.
+    pub(crate) fn with_permit(self, permit: Option<SubscriptionPermit>) -> Self {
+        OutboundTryStream { permit, ..self }
     }
 }
 
@@ -52,14 +70,20 @@ impl<S> Stream for OutboundTryStream<S>
 where
     S: TryStream<Error = Error>,
     S::Ok: IntoProtobuf,
+    <S::Ok as IntoProtobuf>::Message: prost::Message,
 {
     type Item = Result<<S::Ok as IntoProtobuf>::Message, Status>;
 
     fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
         let this = self.project();
+        let meter = this.meter;
         this.inner.try_poll_next(cx).map(|maybe_item| {
             maybe_item.map(|item| match item {
-                Ok(data) => Ok(data.into_message()),
+                Ok(data) => {
+                    let message = data.into_message();
+                    meter.sent(&message);
+                    Ok(message)
+                }
                 Err(e) => Err(error_into_grpc(e)),
             })
         })
@@ -22,7 +22,7 @@ pub struct BlockStore {
     tags_tree: Tree,
 
     // needs to be kept so that the database is always closed correctly
-    _db: sled::Db,
+    db: sled::Db,
 }
 
 enum RemoveTipResult {
@@ -146,10 +146,18 @@ impl BlockStore {
             branches_tips_tree,
             tags_tree,
 
-            _db: volatile,
+            db: volatile,
         })
     }
 
+    /// Synchronously write all dirty buffers of the volatile storage to disk.
+    /// Blocks in the permanent storage are written on each flush to it, so
+    /// they do not need to be handled here.
+    pub fn flush(&self) -> Result<(), Error> {
+        self.db.flush()?;
+        Ok(())
+    }
+
     /// Write a block to the store. The parent of the block must exist (unless
     /// it's the root id).
     ///
@@ -1,7 +1,7 @@
 use super::hash::{Hash, HashedKey, Hasher};
 use super::node::{
-    insert_rec, lookup_one, remove_eq_rec, remove_rec, replace_rec, replace_with_rec, size_rec,
-    update_rec, Entry, LookupRet, Node, NodeIter,
+    insert_rec, lookup_one, remove_eq_rec, remove_rec, replace_rec, replace_with_rec, update_rec,
+    Entry, LookupRet, Node, NodeIter,
 };
 pub use super::operation::{InsertError, RemoveError, ReplaceError, UpdateError};
 use std::borrow::Borrow;
@@ -16,6 +16,9 @@ use std::slice;
 #[must_use = "`Hamt`s are not modified in place, instead modified copies are returned`"]
 pub struct Hamt<H: Hasher + Default, K: PartialEq + Eq + Hash, V> {
     root: Node<K, V>,
+    /// number of entries, kept up to date by the operations so that it
+    /// does not need to walk the trie
+    size: usize,
     hasher: PhantomData<H>,
 }
 
@@ -34,6 +37,7 @@ impl<H: Hasher + Default, K: Eq + Hash, V> Hamt<H, K, V> {
     pub fn new() -> Self {
         Hamt {
             root: Node::new(),
+            size: 0,
             hasher: PhantomData,
         }
     }
@@ -42,8 +46,9 @@ impl<H: Hasher + Default, K: Eq + Hash, V> Hamt<H, K, V> {
         self.root.is_empty()
     }
 
+    /// Number of entries, in constant time
     pub fn size(&self) -> usize {
-        size_rec(&self.root)
+        self.size
     }
 }
 
@@ -53,6 +58,7 @@ impl<H: Hasher + Default, K: Clone + Eq + Hash, V: Clone> Hamt<H, K, V> {
         let newroot = insert_rec(&self.root, h, 0, k, v)?;
         Ok(Hamt {
             root: newroot,
+            size: self.size + 1,
             hasher: PhantomData,
         })
     }
@@ -70,6 +76,7 @@ impl<H: Hasher + Default, K: Eq + Hash + Clone, V: PartialEq + Clone> Hamt<H, K,
             None => Ok(Self::new()),
             Some(r) => Ok(Hamt {
                 root: r,
+                size: self.size - 1,
                 hasher: PhantomData,
             }),
         }
@@ -88,6 +95,7 @@ impl<H: Hasher + Default, K: Clone + Eq + Hash, V: Clone> Hamt<H, K, V> {
             None => Ok(Self::new()),
             Some(r) => Ok(Hamt {
                 root: r,
+                size: self.size - 1,
                 hasher: PhantomData,
             }),
         }
@@ -103,6 +111,7 @@ impl<H: Hasher + Default, K: Eq + Hash + Clone, V: Clone> Hamt<H, K, V> {
         Ok((
             Hamt {
                 root: newroot,
+                size: self.size,
                 hasher: PhantomData,
             },
             oldv,
@@ -119,6 +128,7 @@ impl<H: Hasher + Default, K: Eq + Hash + Clone, V: Clone> Hamt<H, K, V> {
         let newroot = replace_with_rec(&self.root, h, 0, k, f)?;
         Ok(Hamt {
             root: newroot,
+            size: self.size,
             hasher: PhantomData,
         })
     }
@@ -136,11 +146,17 @@ impl<H: Hasher + Default, K: Eq + Hash + Clone, V: Clone> Hamt<H, K, V> {
         U: Error + Debug + 'static,
     {
         let h = HashedKey::compute(self.hasher, &k);
-        let newroot = update_rec(&self.root, h, 0, k, f)?;
+        let mut removed = false;
+        let newroot = update_rec(&self.root, h, 0, k, |v| {
+            let newv = f(v)?;
+            removed = newv.is_none();
+            Ok(newv)
+        })?;
         match newroot {
             None => Ok(Self::new()),
             Some(r) => Ok(Hamt {
                 root: r,
+                size: if removed { self.size - 1 } else { self.size },
                 hasher: PhantomData,
             }),
         }
@@ -278,12 +294,12 @@ impl<H: Default + Hasher, K: Eq + Hash + Clone, V: Clone> FromIterator<(K, V)> f
 impl<H: Default + Hasher, K: Eq + Hash, V: PartialEq> PartialEq for Hamt<H, K, V> {
     fn eq(&self, other: &Self) -> bool {
         // optimised the obvious cases first
-        if self.is_empty() && other.is_empty() {
-            return true;
-        }
-        if self.is_empty() != other.is_empty() {
+        if self.size != other.size {
             return false;
         }
+        if self.is_empty() {
+            return true;
+        }
         // then compare key and values
         // TODO : optimise by comparing nodes directly
         for (k, v) in self.iter() {
@@ -373,6 +373,17 @@ mod tests {
         prop_assert!(property_btreemap_eq(&reference, &h));
     }
 
+    #[proptest]
+    fn size_equivalent(
+        #[allow(clippy::type_complexity)]
+        #[strategy(arbitrary_hamt_and_btree())]
+        data: (Hamt<DefaultHasher, Vec<u8>, u32>, BTreeMap<Vec<u8>, u32>),
+    ) {
+        let (h, reference) = data;
+        prop_assert_eq!(h.size(), reference.len());
+        prop_assert_eq!(h.size(), h.iter().count());
+    }
+
     #[proptest]
     #[allow(clippy::type_complexity)]
     fn iter_equivalent(
@@ -24,6 +24,8 @@ where
         Fragment::VoteTally(tx) => for_each_output(tx, on_output),
         Fragment::MintToken(tx) => for_each_output(tx, on_output),
         Fragment::EvmMapping(tx) => for_each_output(tx, on_output),
+        Fragment::Anchor(tx) => for_each_output(tx, on_output),
+        Fragment::MultisigDeclarationUpdate(tx) => for_each_output(tx, on_output),
     }
 }
 
@@ -105,5 +107,15 @@ where
             .inputs_and_witnesses()
             .iter()
             .for_each(on_input),
+        Fragment::Anchor(tx) => tx
+            .as_slice()
+            .inputs_and_witnesses()
+            .iter()
+            .for_each(on_input),
+        Fragment::MultisigDeclarationUpdate(tx) => tx
+            .as_slice()
+            .inputs_and_witnesses()
+            .iter()
+            .for_each(on_input),
     }
 }
@@ -0,0 +1,416 @@
+//! Bulk export of the indexed data as CSV or JSON files.
+//!
+//! The rows are read incrementally from the state of the main branch at the
+//! time of the request and streamed in the response body, so large extracts
+//! are neither paginated nor built in memory.
+//!
+//! * `GET /export/blocks?format=csv&from=<chain length>&to=<chain length>`
+//! * `GET /export/epoch/<epoch>/transactions?format=csv`
+//! * `GET /export/vote-plan/<vote plan id>/votes?format=csv`
+use crate::db::{
+    indexing::{BlockProducer, CertificateKind, ExplorerAddress, ExplorerBlock, ExplorerVote},
+    ExplorerDb,
+};
+use chain_impl_mockchain::{
+    block::{Epoch, HeaderId as HeaderHash},
+    certificate::VotePlanId,
+};
+use futures::{future, stream, Stream, StreamExt};
+use serde::{Deserialize, Serialize};
+use std::{convert::Infallible, str::FromStr};
+use warp::{
+    http::{header, StatusCode},
+    hyper::Body,
+    reply::Response,
+    Filter, Rejection, Reply,
+};
+
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Format {
+    #[default]
+    Csv,
+    /// a JSON array of objects
+    Json,
+}
+
+impl Format {
+    fn extension(&self) -> &'static str {
+        match self {
+            Format::Csv => "csv",
+            Format::Json => "json",
+        }
+    }
+
+    fn content_type(&self) -> &'static str {
+        match self {
+            Format::Csv => "text/csv",
+            Format::Json => "application/json",
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct FormatQuery {
+    #[serde(default)]
+    format: Format,
+}
+
+#[derive(Debug, Deserialize)]
+struct BlocksQuery {
+    #[serde(default)]
+    format: Format,
+    /// first chain length of the export, the genesis block by default
+    from: Option<u32>,
+    /// chain length after the last block of the export, the tip by default
+    to: Option<u32>,
+}
+
+/// A line of an export. The values of the CSV columns never contain commas
+/// or quotes, they do not need escaping.
+trait Row: Serialize + Send + 'static {
+    const CSV_HEADER: &'static str;
+
+    fn to_csv(&self) -> String;
+}
+
+#[derive(Serialize)]
+struct BlockRow {
+    id: String,
+    chain_length: u32,
+    epoch: u32,
+    slot: u32,
+    parent_id: String,
+    producer: Option<String>,
+    transactions: usize,
+    total_input: u64,
+    total_output: u64,
+}
+
+impl From<&ExplorerBlock> for BlockRow {
+    fn from(block: &ExplorerBlock) -> Self {
+        Self {
+            id: block.id.to_string(),
+            chain_length: block.chain_length.into(),
+            epoch: block.date.epoch,
+            slot: block.date.slot_id,
+            parent_id: block.parent_hash.to_string(),
+            producer: match &block.producer {
+                BlockProducer::None => None,
+                BlockProducer::StakePool(pool) => Some(pool.to_string()),
+                BlockProducer::BftLeader(leader) => Some(leader.as_public_key().to_string()),
+            },
+            transactions: block.transactions.len(),
+            total_input: block.total_input.0,
+            total_output: block.total_output.0,
+        }
+    }
+}
+
+impl Row for BlockRow {
+    const CSV_HEADER: &'static str =
+        "id,chain_length,epoch,slot,parent_id,producer,transactions,total_input,total_output";
+
+    fn to_csv(&self) -> String {
+        format!(
+            "{},{},{},{},{},{},{},{},{}",
+            self.id,
+            self.chain_length,
+            self.epoch,
+            self.slot,
+            self.parent_id,
+            self.producer.as_deref().unwrap_or_default(),
+            self.transactions,
+            self.total_input,
+            self.total_output
+        )
+    }
+}
+
+#[derive(Serialize)]
+struct TransactionRow {
+    id: String,
+    block_id: String,
+    chain_length: u32,
+    epoch: u32,
+    slot: u32,
+    offset_in_block: u32,
+    inputs: usize,
+    outputs: usize,
+    total_input: u64,
+    total_output: u64,
+    fee: u64,
+    size: u32,
+    certificate: Option<String>,
+}
+
+impl TransactionRow {
+    /// Rows of the transactions of the block, in block order
+    fn from_block(block: &ExplorerBlock) -> Vec<Self> {
+        let mut transactions: Vec<_> = block.transactions.values().collect();
+        transactions.sort_by_key(|transaction| transaction.offset_in_block);
+        transactions
+            .into_iter()
+            .map(|transaction| Self {
+                id: transaction.id.to_string(),
+                block_id: block.id.to_string(),
+                chain_length: block.chain_length.into(),
+                epoch: block.date.epoch,
+                slot: block.date.slot_id,
+                offset_in_block: transaction.offset_in_block,
+                inputs: transaction.inputs.len(),
+                outputs: transaction.outputs.len(),
+                total_input: transaction.inputs.iter().map(|input| input.value.0).sum(),
+                total_output: transaction
+                    .outputs
+                    .iter()
+                    .map(|output| output.value.0)
+                    .sum(),
+                fee: transaction.fee.0,
+                size: transaction.size,
+                certificate: transaction
+                    .certificate
+                    .as_ref()
+                    .map(|certificate| format!("{:?}", CertificateKind::from(certificate))),
+            })
+            .collect()
+    }
+}
+
+impl Row for TransactionRow {
+    const CSV_HEADER: &'static str = "id,block_id,chain_length,epoch,slot,offset_in_block,inputs,outputs,total_input,total_output,fee,size,certificate";
+
+    fn to_csv(&self) -> String {
+        format!(
+            "{},{},{},{},{},{},{},{},{},{},{},{},{}",
+            self.id,
+            self.block_id,
+            self.chain_length,
+            self.epoch,
+            self.slot,
+            self.offset_in_block,
+            self.inputs,
+            self.outputs,
+            self.total_input,
+            self.total_output,
+            self.fee,
+            self.size,
+            self.certificate.as_deref().unwrap_or_default()
+        )
+    }
+}
+
+#[derive(Serialize)]
+struct VoteRow {
+    proposal_index: usize,
+    proposal_id: String,
+    voter: String,
+    /// not known for the private votes until the tally
+    choice: Option<u8>,
+}
+
+impl Row for VoteRow {
+    const CSV_HEADER: &'static str = "proposal_index,proposal_id,voter,choice";
+
+    fn to_csv(&self) -> String {
+        format!(
+            "{},{},{},{}",
+            self.proposal_index,
+            self.proposal_id,
+            self.voter,
+            self.choice
+                .map(|choice| choice.to_string())
+                .unwrap_or_default()
+        )
+    }
+}
+
+fn address_to_string(address: &ExplorerAddress, address_bech32_prefix: &str) -> String {
+    match address {
+        ExplorerAddress::New(address) => {
+            chain_addr::AddressReadable::from_address(address_bech32_prefix, address).to_string()
+        }
+        ExplorerAddress::Old(address) => address.to_string(),
+    }
+}
+
+pub fn filter(
+    db: ExplorerDb,
+    address_bech32_prefix: String,
+) -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
+    let with_db = warp::any().map(move || db.clone());
+    let with_prefix = warp::any().map(move || address_bech32_prefix.clone());
+
+    let blocks = warp::path!("export" / "blocks")
+        .and(warp::get())
+        .and(with_db.clone())
+        .and(warp::query::<BlocksQuery>())
+        .and_then(export_blocks);
+
+    let transactions = warp::path!("export" / "epoch" / Epoch / "transactions")
+        .and(warp::get())
+        .and(with_db.clone())
+        .and(warp::query::<FormatQuery>())
+        .and_then(export_epoch_transactions);
+
+    let votes = warp::path!("export" / "vote-plan" / String / "votes")
+        .and(warp::get())
+        .and(with_db)
+        .and(with_prefix)
+        .and(warp::query::<FormatQuery>())
+        .and_then(export_votes);
+
+    blocks.or(transactions).or(votes).boxed()
+}
+
+async fn export_blocks(db: ExplorerDb, query: BlocksQuery) -> Result<Response, Infallible> {
+    let (tip_id, tip) = db.get_tip().await;
+    let tip_chain_length = tip
+        .state()
+        .blocks
+        .lookup(&tip_id)
+        .map_or(0, |block| u32::from(block.chain_length));
+    let from = query.from.unwrap_or(0);
+    let to = query
+        .to
+        .map_or(tip_chain_length + 1, |to| to.min(tip_chain_length + 1));
+
+    let rows = stream::iter(from..to).filter_map(move |chain_length| {
+        future::ready(
+            tip.state()
+                .get_block_at(chain_length.into())
+                .map(|block| BlockRow::from(block.as_ref())),
+        )
+    });
+
+    Ok(export(
+        &format!("blocks_{}_{}", from, to),
+        query.format,
+        rows,
+    ))
+}
+
+async fn export_epoch_transactions(
+    epoch: Epoch,
+    db: ExplorerDb,
+    query: FormatQuery,
+) -> Result<Response, Infallible> {
+    let (_, tip) = db.get_tip().await;
+    let chain_lengths = match tip.state().get_epoch(epoch).and_then(|epoch_data| {
+        let chain_length = |id: &HeaderHash| {
+            tip.state()
+                .blocks
+                .lookup(id)
+                .map(|block| u32::from(block.chain_length))
+        };
+        Some(chain_length(&epoch_data.first_block)?..=chain_length(&epoch_data.last_block)?)
+    }) {
+        Some(chain_lengths) => chain_lengths,
+        None => return Ok(not_found(format!("epoch {} not found", epoch))),
+    };
+
+    let rows = stream::iter(chain_lengths)
+        .filter_map(move |chain_length| {
+            future::ready(tip.state().get_block_at(chain_length.into()))
+        })
+        .flat_map(|block| stream::iter(TransactionRow::from_block(&block)));
+
+    Ok(export(
+        &format!("epoch_{}_transactions", epoch),
+        query.format,
+        rows,
+    ))
+}
+
+async fn export_votes(
+    vote_plan_id: String,
+    db: ExplorerDb,
+    address_bech32_prefix: String,
+    query: FormatQuery,
+) -> Result<Response, Infallible> {
+    let id = match VotePlanId::from_str(&vote_plan_id) {
+        Ok(id) => id,
+        Err(_) => {
+            return Ok(warp::reply::with_status(
+                format!("invalid vote plan id {}", vote_plan_id),
+                StatusCode::BAD_REQUEST,
+            )
+            .into_response())
+        }
+    };
+    let (_, tip) = db.get_tip().await;
+    let vote_plan = match tip.state().get_vote_plan(&id) {
+        Some(vote_plan) => vote_plan,
+        None => return Ok(not_found(format!("vote plan {} not found", id))),
+    };
+
+    // the votes of a proposal are read when the previous proposals have been
+    // streamed
+    let rows = stream::iter(0..vote_plan.proposals.len()).flat_map(move |proposal_index| {
+        let proposal = &vote_plan.proposals[proposal_index];
+        let rows: Vec<_> = proposal
+            .votes
+            .iter()
+            .map(|(voter, vote)| VoteRow {
+                proposal_index,
+                proposal_id: proposal.proposal_id.to_string(),
+                voter: address_to_string(voter, &address_bech32_prefix),
+                choice: match vote.as_ref() {
+                    ExplorerVote::Public(choice) => Some(choice.as_byte()),
+                    ExplorerVote::Private { .. } => None,
+                },
+            })
+            .collect();
+        stream::iter(rows)
+    });
+
+    Ok(export(
+        &format!("vote_plan_{}_votes", id),
+        query.format,
+        rows,
+    ))
+}
+
+fn not_found(message: String) -> Response {
+    warp::reply::with_status(message, StatusCode::NOT_FOUND).into_response()
+}
+
+/// Streams the rows as a file in the given format
+fn export<R: Row>(
+    name: &str,
+    format: Format,
+    rows: impl Stream<Item = R> + Send + 'static,
+) -> Response {
+    let body = match format {
+        Format::Csv => stream::once(future::ready(format!("{}\n", R::CSV_HEADER)))
+            .chain(rows.map(|row| format!("{}\n", row.to_csv())))
+            .boxed(),
+        Format::Json => stream::once(future::ready("[".to_owned()))
+            .chain(rows.enumerate().map(|(index, row)| {
+                format!(
+                    "{}\n{}",
+                    if index == 0 { "" } else { "," },
+                    serde_json::to_string(&row).expect("export rows are serializable")
+                )
+            }))
+            .chain(stream::once(future::ready("\n]\n".to_owned())))
+            .boxed(),
+    };
+
+    let mut response = Response::new(Body::wrap_stream(body.map(Ok::<_, Infallible>)));
+    let headers = response.headers_mut();
+    headers.insert(
+        header::CONTENT_TYPE,
+        header::HeaderValue::from_static(format.content_type()),
+    );
+    headers.insert(
+        header::CONTENT_DISPOSITION,
+        header::HeaderValue::from_str(&format!(
+            "attachment; filename=\"{}.{}\"",
+            name,
+            format.extension()
+        ))
+        .expect("export file names are valid header values"),
+    );
+    response
+}
@@ -5,7 +5,8 @@ use super::{
     scalars::{PayloadType, PoolId, PublicKey, TimeOffsetSeconds, VotePlanId},
     Address, BftLeader, BlockDate, ExplorerAddress, Pool, Proposal, TaxType,
 };
-use async_graphql::{Context, FieldResult, Object, Union};
+use crate::db::indexing::CertificateKind;
+use async_graphql::{Context, Enum, FieldResult, Object, Union};
 use chain_impl_mockchain::certificate;
 
 // interface for grouping certificates as a graphl union
@@ -23,6 +24,50 @@ pub enum Certificate {
     UpdateVote(UpdateVote),
     MintToken(MintToken),
     EvmMapping(EvmMapping),
+    Anchor(Anchor),
+    MultisigDeclarationUpdate(MultisigDeclarationUpdate),
+}
+
+/// Type of certificate, used to filter transactions
+#[derive(Clone, Copy, PartialEq, Eq, Enum)]
+pub enum CertificateType {
+    StakeDelegation,
+    OwnerStakeDelegation,
+    PoolRegistration,
+    PoolRetirement,
+    PoolUpdate,
+    VotePlan,
+    VoteCast,
+    VoteTally,
+    UpdateProposal,
+    UpdateVote,
+    MintToken,
+    EvmMapping,
+    Anchor,
+    MultisigDeclarationUpdate,
+}
+
+impl From<CertificateType> for CertificateKind {
+    fn from(certificate_type: CertificateType) -> Self {
+        match certificate_type {
+            CertificateType::StakeDelegation => CertificateKind::StakeDelegation,
+            CertificateType::OwnerStakeDelegation => CertificateKind::OwnerStakeDelegation,
+            CertificateType::PoolRegistration => CertificateKind::PoolRegistration,
+            CertificateType::PoolRetirement => CertificateKind::PoolRetirement,
+            CertificateType::PoolUpdate => CertificateKind::PoolUpdate,
+            CertificateType::VotePlan => CertificateKind::VotePlan,
+            CertificateType::VoteCast => CertificateKind::VoteCast,
+            CertificateType::VoteTally => CertificateKind::VoteTally,
+            CertificateType::UpdateProposal => CertificateKind::UpdateProposal,
+            CertificateType::UpdateVote => CertificateKind::UpdateVote,
+            CertificateType::MintToken => CertificateKind::MintToken,
+            CertificateType::EvmMapping => CertificateKind::EvmMapping,
+            CertificateType::Anchor => CertificateKind::Anchor,
+            CertificateType::MultisigDeclarationUpdate => {
+                CertificateKind::MultisigDeclarationUpdate
+            }
+        }
+    }
 }
 
 pub struct StakeDelegation(certificate::StakeDelegation);
@@ -50,6 +95,12 @@ pub struct MintToken(certificate::MintToken);
 
 pub struct EvmMapping(certificate::EvmMapping);
 
+/// Commitment to some external data anchored on-chain
+pub struct Anchor(certificate::Anchor);
+
+/// New owners and threshold of an existing multisig account
+pub struct MultisigDeclarationUpdate(certificate::MultisigDeclarationUpdate);
+
 #[Object]
 impl StakeDelegation {
     // FIXME: Maybe a new Account type would be better?
@@ -273,6 +324,42 @@ impl EvmMapping {
     }
 }
 
+#[Object]
+impl Anchor {
+    /// hex encoded commitment to the external data
+    pub async fn commitment(&self) -> String {
+        hex::encode(self.0.commitment())
+    }
+
+    /// hex encoded tag identifying the anchored data
+    pub async fn tag(&self) -> Option<String> {
+        self.0.tag().map(hex::encode)
+    }
+}
+
+#[Object]
+impl MultisigDeclarationUpdate {
+    /// hex encoded identifier of the multisig account, which does not change
+    pub async fn account(&self) -> String {
+        hex::encode(self.0.id())
+    }
+
+    /// Number of owners needed to sign for the account
+    pub async fn threshold(&self) -> i32 {
+        self.0.declaration().threshold() as i32
+    }
+
+    /// hex encoded hashes of the owners' public keys
+    pub async fn owners(&self) -> Vec<String> {
+        self.0
+            .declaration()
+            .owners()
+            .iter()
+            .map(|owner| hex::encode(owner.to_hash()))
+            .collect()
+    }
+}
+
 /*------------------------------*/
 /*------- Conversions ---------*/
 /*----------------------------*/
@@ -302,6 +389,10 @@ impl From<chain_impl_mockchain::certificate::Certificate> for Certificate {
             certificate::Certificate::UpdateVote(c) => Certificate::UpdateVote(UpdateVote(c)),
             certificate::Certificate::MintToken(c) => Certificate::MintToken(MintToken(c)),
             certificate::Certificate::EvmMapping(c) => Certificate::EvmMapping(EvmMapping(c)),
+            certificate::Certificate::Anchor(c) => Certificate::Anchor(Anchor(c)),
+            certificate::Certificate::MultisigDeclarationUpdate(c) => {
+                Certificate::MultisigDeclarationUpdate(MultisigDeclarationUpdate(c))
+            }
         }
     }
 }
@@ -365,3 +456,15 @@ impl From<certificate::EvmMapping> for EvmMapping {
         EvmMapping(evm_mapping)
     }
 }
+
+impl From<certificate::Anchor> for Anchor {
+    fn from(anchor: certificate::Anchor) -> Self {
+        Anchor(anchor)
+    }
+}
+
+impl From<certificate::MultisigDeclarationUpdate> for MultisigDeclarationUpdate {
+    fn from(update: certificate::MultisigDeclarationUpdate) -> Self {
+        MultisigDeclarationUpdate(update)
+    }
+}
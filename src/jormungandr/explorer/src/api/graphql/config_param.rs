@@ -310,10 +310,17 @@ pub struct HalvingRewardParams {
     epoch_rate: NonZeroU32,
 }
 
+#[derive(SimpleObject)]
+pub struct FixedRewardParams {
+    constant: u64,
+    epoch_start: u32,
+}
+
 #[derive(Union)]
 pub enum RewardParamsUnion {
     Linear(LinearRewardParams),
     Halving(HalvingRewardParams),
+    Fixed(FixedRewardParams),
 }
 
 #[derive(SimpleObject)]
@@ -350,6 +357,15 @@ impl From<&RewardParamsLib> for RewardParams {
                     epoch_rate: *epoch_rate,
                 }),
             },
+            RewardParamsLib::Fixed {
+                constant,
+                epoch_start,
+            } => Self {
+                reward_params: RewardParamsUnion::Fixed(FixedRewardParams {
+                    constant: *constant,
+                    epoch_start: *epoch_start,
+                }),
+            },
         }
     }
 }
@@ -436,6 +452,45 @@ impl From<&u8> for TransactionMaxExpiryEpochs {
     }
 }
 
+#[derive(SimpleObject)]
+pub struct MaxFragmentSize {
+    max_fragment_size: u32,
+}
+
+impl From<&u32> for MaxFragmentSize {
+    fn from(v: &u32) -> Self {
+        Self {
+            max_fragment_size: *v,
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct VotingPowerSnapshot {
+    voting_power_snapshot: bool,
+}
+
+impl From<&bool> for VotingPowerSnapshot {
+    fn from(v: &bool) -> Self {
+        Self {
+            voting_power_snapshot: *v,
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct ScriptAddresses {
+    script_addresses: bool,
+}
+
+impl From<&bool> for ScriptAddresses {
+    fn from(v: &bool) -> Self {
+        Self {
+            script_addresses: *v,
+        }
+    }
+}
+
 #[cfg(feature = "evm")]
 #[derive(SimpleObject)]
 pub struct EvmConfig {
@@ -498,6 +553,9 @@ pub enum ConfigParam {
     EvmConfiguration(EvmConfig),
     #[cfg(feature = "evm")]
     EvmEnvironment(EvmEnvSettings),
+    MaxFragmentSize(MaxFragmentSize),
+    VotingPowerSnapshot(VotingPowerSnapshot),
+    ScriptAddresses(ScriptAddresses),
 }
 
 #[derive(SimpleObject)]
@@ -548,6 +606,9 @@ impl From<&ConfigParamLib> for ConfigParam {
             ConfigParamLib::EvmConfiguration(v) => Self::EvmConfiguration(v.into()),
             #[cfg(feature = "evm")]
             ConfigParamLib::EvmEnvironment(v) => Self::EvmEnvironment(v.into()),
+            ConfigParamLib::MaxFragmentSize(v) => Self::MaxFragmentSize(v.into()),
+            ConfigParamLib::VotingPowerSnapshot(v) => Self::VotingPowerSnapshot(v.into()),
+            ConfigParamLib::ScriptAddresses(v) => Self::ScriptAddresses(v.into()),
         }
     }
 }
@@ -1,4 +1,8 @@
-use async_graphql::{FieldResult, OutputType, SimpleObject};
+use super::scalars::IndexCursor;
+use async_graphql::{
+    connection::{Connection, EmptyFields},
+    FieldResult, OutputType, SimpleObject,
+};
 use std::convert::TryFrom;
 
 #[derive(SimpleObject)]
@@ -19,12 +23,30 @@ pub struct PageMeta {
     pub total_count: u64,
 }
 
+impl PageMeta {
+    /// Create an empty connection carrying the Relay `PageInfo` flags and the
+    /// `totalCount` of the whole collection, edges are to be added by the caller
+    pub fn into_connection<N, C>(
+        self,
+    ) -> Connection<IndexCursor, N, ConnectionFields<C>, EmptyFields>
+    where
+        N: OutputType,
+        C: OutputType + Send + Sync + From<u64>,
+    {
+        Connection::with_additional_fields(
+            self.has_previous_page,
+            self.has_next_page,
+            ConnectionFields {
+                total_count: C::from(self.total_count),
+            },
+        )
+    }
+}
+
 fn compute_range_boundaries(
     total_elements: InclusivePaginationInterval<u64>,
     pagination_arguments: ValidatedPaginationArguments<u64>,
-) -> InclusivePaginationInterval<u64>
-where
-{
+) -> PaginationInterval<u64> {
     use std::cmp::{max, min};
 
     let InclusivePaginationInterval {
@@ -41,11 +63,20 @@ where
     };
 
     let mut to: u64 = match pagination_arguments.before {
-        Some(cursor) => min(cursor - 1, upper_bound),
+        Some(cursor) => match cursor.checked_sub(1) {
+            Some(cursor) => min(cursor, upper_bound),
+            // nothing can come before the first element
+            None => return PaginationInterval::Empty,
+        },
         // If `before` is not set, start from the beginning
         None => upper_bound,
     };
 
+    // the cursors don't overlap with the elements, this is an empty page
+    if from > to {
+        return PaginationInterval::Empty;
+    }
+
     // Move `to` enough values to make the result have `first` blocks
     if let Some(first) = pagination_arguments.first {
         to = min(
@@ -66,10 +97,10 @@ where
         );
     }
 
-    InclusivePaginationInterval {
+    PaginationInterval::Inclusive(InclusivePaginationInterval {
         lower_bound: from,
         upper_bound: to,
-    }
+    })
 }
 
 pub fn compute_interval<I>(
@@ -91,22 +122,29 @@ where
                 lower_bound,
             } = total_elements;
 
+            let after = pagination_arguments.after;
+            let before = pagination_arguments.before;
             let page = compute_range_boundaries(total_elements, pagination_arguments);
 
-            let has_next_page = page.upper_bound < upper_bound;
-            let has_previous_page = page.lower_bound > lower_bound;
+            // for an empty page the flags are derived from the cursors, so the
+            // client can still move towards the elements that do exist
+            let (has_next_page, has_previous_page) = match &page {
+                PaginationInterval::Empty => (
+                    before.map_or(false, |cursor| cursor <= upper_bound),
+                    after.map_or(false, |cursor| cursor >= lower_bound),
+                ),
+                PaginationInterval::Inclusive(page) => (
+                    page.upper_bound < upper_bound,
+                    page.lower_bound > lower_bound,
+                ),
+            };
 
             let total_count = upper_bound
                 .checked_add(1)
                 .unwrap()
                 .checked_sub(lower_bound)
                 .expect("upper_bound should be >= than lower_bound");
-            (
-                PaginationInterval::Inclusive(page),
-                has_next_page,
-                has_previous_page,
-                total_count,
-            )
+            (page, has_next_page, has_previous_page, total_count)
         }
     };
 
@@ -181,3 +219,155 @@ impl<I> PaginationInterval<I> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_graphql::{
+        connection::{query, Edge},
+        EmptyMutation, EmptySubscription, Object, Schema,
+    };
+    use serde_json::json;
+
+    struct Query {
+        items: Vec<i32>,
+    }
+
+    #[Object]
+    impl Query {
+        async fn items(
+            &self,
+            first: Option<i32>,
+            last: Option<i32>,
+            before: Option<String>,
+            after: Option<String>,
+        ) -> FieldResult<Connection<IndexCursor, i32, ConnectionFields<u64>, EmptyFields>> {
+            query(
+                after,
+                before,
+                first,
+                last,
+                |after, before, first, last| async move {
+                    let boundaries = match self.items.len().checked_sub(1) {
+                        Some(upper_bound) => {
+                            PaginationInterval::Inclusive(InclusivePaginationInterval {
+                                lower_bound: 0u32,
+                                upper_bound: upper_bound.try_into().unwrap(),
+                            })
+                        }
+                        None => PaginationInterval::Empty,
+                    };
+                    let pagination_arguments = ValidatedPaginationArguments {
+                        first,
+                        last,
+                        before: before.map(u32::try_from).transpose()?,
+                        after: after.map(u32::try_from).transpose()?,
+                    };
+
+                    let (range, page_meta) = compute_interval(boundaries, pagination_arguments)?;
+                    let mut connection = page_meta.into_connection();
+                    if let PaginationInterval::Inclusive(range) = range {
+                        connection.edges.extend(
+                            (range.lower_bound..=range.upper_bound)
+                                .map(|i| Edge::new(IndexCursor::from(i), self.items[i as usize])),
+                        );
+                    }
+                    Ok::<_, async_graphql::Error>(connection)
+                },
+            )
+            .await
+        }
+    }
+
+    fn items_page(len: i32, arguments: &str) -> serde_json::Value {
+        let schema = Schema::new(
+            Query {
+                items: (0..len).collect(),
+            },
+            EmptyMutation,
+            EmptySubscription,
+        );
+        let query = format!(
+            "{{ items{} {{ totalCount pageInfo {{ hasNextPage hasPreviousPage }} edges {{ node }} }} }}",
+            arguments
+        );
+        let response = futures::executor::block_on(schema.execute(query.as_str()));
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        response.data.into_json().unwrap()["items"].clone()
+    }
+
+    fn nodes(page: &serde_json::Value) -> Vec<i64> {
+        page["edges"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|edge| edge["node"].as_i64().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn first_page() {
+        let page = items_page(5, "(first: 2)");
+        assert_eq!(page["totalCount"], 5);
+        assert_eq!(
+            page["pageInfo"],
+            json!({ "hasNextPage": true, "hasPreviousPage": false })
+        );
+        assert_eq!(nodes(&page), vec![0, 1]);
+    }
+
+    #[test]
+    fn last_page() {
+        let page = items_page(5, "(last: 2)");
+        assert_eq!(page["totalCount"], 5);
+        assert_eq!(
+            page["pageInfo"],
+            json!({ "hasNextPage": false, "hasPreviousPage": true })
+        );
+        assert_eq!(nodes(&page), vec![3, 4]);
+
+        // the last page reached by moving forward has no next page
+        let page = items_page(5, "(first: 2, after: \"2\")");
+        assert_eq!(
+            page["pageInfo"],
+            json!({ "hasNextPage": false, "hasPreviousPage": true })
+        );
+        assert_eq!(nodes(&page), vec![3, 4]);
+    }
+
+    #[test]
+    fn whole_collection_page() {
+        let page = items_page(3, "(first: 10)");
+        assert_eq!(page["totalCount"], 3);
+        assert_eq!(
+            page["pageInfo"],
+            json!({ "hasNextPage": false, "hasPreviousPage": false })
+        );
+        assert_eq!(nodes(&page), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn empty_page_of_non_empty_connection() {
+        // total count is reported even when the cursors select nothing
+        let page = items_page(3, "(first: 2, after: \"2\")");
+        assert_eq!(page["totalCount"], 3);
+        assert_eq!(
+            page["pageInfo"],
+            json!({ "hasNextPage": false, "hasPreviousPage": true })
+        );
+        assert!(nodes(&page).is_empty());
+    }
+
+    #[test]
+    fn empty_connection() {
+        for arguments in ["(first: 2)", "(last: 2)"] {
+            let page = items_page(0, arguments);
+            assert_eq!(page["totalCount"], 0);
+            assert_eq!(
+                page["pageInfo"],
+                json!({ "hasNextPage": false, "hasPreviousPage": false })
+            );
+            assert!(nodes(&page).is_empty());
+        }
+    }
+}
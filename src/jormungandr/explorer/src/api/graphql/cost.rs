@@ -0,0 +1,350 @@
+//! Estimation of the cost of a query before it is executed.
+//!
+//! The complexity computed by async-graphql counts each selected field
+//! once, no matter how many times it is resolved, so a query nesting
+//! paginated connections stays cheap while resolving a number of fields
+//! growing with the product of the page sizes. The estimation done here
+//! multiplies the cost of the elements of a connection by the number of
+//! elements requested.
+
+use async_graphql::{
+    extensions::{Extension, ExtensionContext, ExtensionFactory, NextParseQuery},
+    parser::types::{ExecutableDocument, Field, Selection, SelectionSet},
+    Name, ServerError, ServerResult, Value as ConstValue, Variables,
+};
+use std::{collections::HashSet, sync::Arc};
+
+/// Page size assumed for connections queried without `first` or `last`,
+/// for which the actual size is only known once the query is executed.
+const UNBOUNDED_PAGE_SIZE: u64 = 100;
+
+/// Rejects queries that are nested too deep or estimated to be too
+/// expensive, with an error carrying a `code` extension of
+/// `QUERY_TOO_DEEP` or `QUERY_TOO_EXPENSIVE` along with the `limit` and
+/// the estimated value.
+pub struct QueryLimits {
+    max_depth: usize,
+    max_cost: u64,
+}
+
+impl QueryLimits {
+    pub fn new(max_depth: usize, max_cost: usize) -> Self {
+        Self {
+            max_depth,
+            max_cost: max_cost as u64,
+        }
+    }
+}
+
+impl ExtensionFactory for QueryLimits {
+    fn create(&self) -> Arc<dyn Extension> {
+        Arc::new(QueryLimitsExtension {
+            max_depth: self.max_depth,
+            max_cost: self.max_cost,
+        })
+    }
+}
+
+struct QueryLimitsExtension {
+    max_depth: usize,
+    max_cost: u64,
+}
+
+#[async_trait::async_trait]
+impl Extension for QueryLimitsExtension {
+    async fn parse_query(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        query: &str,
+        variables: &Variables,
+        next: NextParseQuery<'_>,
+    ) -> ServerResult<ExecutableDocument> {
+        let document = next.run(ctx, query, variables).await?;
+
+        let mut estimator = Estimator {
+            document: &document,
+            variables,
+            max_depth: self.max_depth,
+            fragments: HashSet::new(),
+        };
+        for (_, operation) in document.operations.iter() {
+            let cost = estimator
+                .selection_set(&operation.node.selection_set.node, 0, 1)
+                .ok_or_else(|| {
+                    limit_error(
+                        "QUERY_TOO_DEEP",
+                        format!("query is nested deeper than {} levels", self.max_depth),
+                        self.max_depth as u64,
+                        None,
+                    )
+                })?;
+            if cost > self.max_cost {
+                return Err(limit_error(
+                    "QUERY_TOO_EXPENSIVE",
+                    format!(
+                        "query has an estimated cost of {}, above the limit of {}",
+                        cost, self.max_cost
+                    ),
+                    self.max_cost,
+                    Some(cost),
+                ));
+            }
+        }
+
+        Ok(document)
+    }
+}
+
+fn limit_error(code: &str, message: String, limit: u64, estimated: Option<u64>) -> ServerError {
+    let mut error = ServerError::new(message, None);
+    let extensions = error.extensions.get_or_insert_with(Default::default);
+    extensions.set("code", code);
+    extensions.set("limit", limit);
+    if let Some(estimated) = estimated {
+        extensions.set("estimated", estimated);
+    }
+    error
+}
+
+/// Estimates the cost of a query: each field costs one, plus the cost of
+/// its selection, the elements of a connection being counted once per
+/// element of the requested page. Each of the functions returns `None` as
+/// soon as the maximum depth is exceeded, and takes the `page_size` of the
+/// field the selection is made on
+struct Estimator<'a> {
+    document: &'a ExecutableDocument,
+    variables: &'a Variables,
+    max_depth: usize,
+    // fragments being expanded, to stop on cycles which are rejected by the
+    // validation only after the query is parsed
+    fragments: HashSet<&'a Name>,
+}
+
+impl<'a> Estimator<'a> {
+    fn selection_set(
+        &mut self,
+        selection_set: &'a SelectionSet,
+        depth: usize,
+        page_size: u64,
+    ) -> Option<u64> {
+        let mut cost = 0u64;
+        for selection in &selection_set.items {
+            cost = cost.saturating_add(self.selection(&selection.node, depth, page_size)?);
+        }
+        Some(cost)
+    }
+
+    fn selection(&mut self, selection: &'a Selection, depth: usize, page_size: u64) -> Option<u64> {
+        match selection {
+            Selection::Field(field) => {
+                let cost = self.field(&field.node, depth + 1)?;
+                // the elements of a connection are resolved once per element
+                // of the page, the other fields of the connection only once
+                if matches!(field.node.name.node.as_str(), "edges" | "nodes") {
+                    Some(cost.saturating_mul(page_size))
+                } else {
+                    Some(cost)
+                }
+            }
+            Selection::InlineFragment(fragment) => {
+                self.selection_set(&fragment.node.selection_set.node, depth, page_size)
+            }
+            Selection::FragmentSpread(spread) => {
+                let name = &spread.node.fragment_name.node;
+                let fragment = match self.document.fragments.get(name) {
+                    Some(fragment) => fragment,
+                    // unknown fragments are reported by the validation
+                    None => return Some(0),
+                };
+                if !self.fragments.insert(name) {
+                    return Some(0);
+                }
+                let cost = self.selection_set(&fragment.node.selection_set.node, depth, page_size);
+                self.fragments.remove(name);
+                cost
+            }
+        }
+    }
+
+    fn field(&mut self, field: &'a Field, depth: usize) -> Option<u64> {
+        if depth > self.max_depth {
+            return None;
+        }
+
+        let page_size = field
+            .arguments
+            .iter()
+            .filter(|(name, _)| matches!(name.node.as_str(), "first" | "last"))
+            .filter_map(|(_, value)| {
+                let value = value
+                    .node
+                    .clone()
+                    .into_const_with(|name| self.variables.get(&name).cloned().ok_or(()))
+                    .ok()?;
+                match value {
+                    ConstValue::Number(number) => number.as_u64(),
+                    _ => None,
+                }
+            })
+            .min()
+            .unwrap_or(UNBOUNDED_PAGE_SIZE);
+
+        let selection_cost = self.selection_set(&field.selection_set.node, depth, page_size)?;
+        Some(selection_cost.saturating_add(1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_graphql::{parser::parse_query, EmptyMutation, EmptySubscription, Object, Schema};
+    use serde_json::json;
+
+    fn estimate(query: &str, variables: serde_json::Value, max_depth: usize) -> Option<u64> {
+        let document = parse_query(query).unwrap();
+        let variables = Variables::from_json(variables);
+        let mut estimator = Estimator {
+            document: &document,
+            variables: &variables,
+            max_depth,
+            fragments: HashSet::new(),
+        };
+        let (_, operation) = document.operations.iter().next().unwrap();
+        estimator.selection_set(&operation.node.selection_set.node, 0, 1)
+    }
+
+    #[test]
+    fn fields_cost_one() {
+        assert_eq!(estimate("{ tip { id blockDate } }", json!({}), 10), Some(3));
+    }
+
+    #[test]
+    fn default_page_size() {
+        // blocks + 100 * (edges + node + id) + totalCount
+        let query = "{ blocks { edges { node { id } } totalCount } }";
+        assert_eq!(estimate(query, json!({}), 10), Some(1 + 100 * 3 + 1));
+    }
+
+    #[test]
+    fn nested_connections_multiply_page_sizes() {
+        let query = "{
+            blocks(first: 10) {
+                edges { node { transactions(first: 5) { nodes { id } } } }
+            }
+        }";
+        // transactions: 1 + 5 * (nodes + id)
+        let transactions = 1 + 5 * 2;
+        // blocks: 1 + 10 * (edges + node + transactions)
+        assert_eq!(
+            estimate(query, json!({}), 10),
+            Some(1 + 10 * (2 + transactions))
+        );
+    }
+
+    #[test]
+    fn page_size_from_variables() {
+        let query = "query($first: Int, $last: Int) {
+            blocks(first: $first, last: $last) { nodes { id } }
+        }";
+        assert_eq!(estimate(query, json!({ "first": 4 }), 10), Some(1 + 4 * 2));
+        assert_eq!(estimate(query, json!({ "last": 7 }), 10), Some(1 + 7 * 2));
+        // the smallest of the two is the page size
+        assert_eq!(
+            estimate(query, json!({ "first": 4, "last": 3 }), 10),
+            Some(1 + 3 * 2)
+        );
+        // unset variables leave the page unbounded
+        assert_eq!(estimate(query, json!({}), 10), Some(1 + 100 * 2));
+    }
+
+    #[test]
+    fn fragment_spreads_are_expanded() {
+        let query = "
+            { blocks(first: 2) { ...page } }
+            fragment page on BlockConnection { edges { ...block } totalCount }
+            fragment block on BlockEdge { node { id } }
+        ";
+        // blocks: 1 + 2 * (edges + node + id) + totalCount
+        assert_eq!(estimate(query, json!({}), 10), Some(1 + 2 * 3 + 1));
+
+        let inline = "{ blocks(first: 2) { ... on BlockConnection { nodes { id } } } }";
+        assert_eq!(estimate(inline, json!({}), 10), Some(1 + 2 * 2));
+    }
+
+    #[test]
+    fn fragment_cycles_terminate() {
+        let query = "
+            { tip { ...a } }
+            fragment a on Block { id ...b }
+            fragment b on Block { blockDate ...a }
+        ";
+        // tip + id + blockDate, the cycle is cut at the second spread of `a`
+        assert_eq!(estimate(query, json!({}), 10), Some(3));
+    }
+
+    #[test]
+    fn depth_is_limited() {
+        let query = "{ tip { block { id } } }";
+        assert_eq!(estimate(query, json!({}), 3), Some(3));
+        assert_eq!(estimate(query, json!({}), 2), None);
+
+        // fragments do not count as a level
+        let fragment = "{ tip { ...f } } fragment f on Tip { block { id } }";
+        assert_eq!(estimate(fragment, json!({}), 3), Some(3));
+        assert_eq!(estimate(fragment, json!({}), 2), None);
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn value(&self) -> i32 {
+            0
+        }
+
+        async fn page(&self, #[graphql(name = "first")] _first: Option<i32>) -> Query {
+            Query
+        }
+
+        async fn nodes(&self) -> Vec<Query> {
+            vec![Query]
+        }
+    }
+
+    fn execute_with_limits(query: &str, limits: QueryLimits) -> serde_json::Value {
+        let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+            .extension(limits)
+            .finish();
+        let response = futures::executor::block_on(schema.execute(query));
+        serde_json::to_value(&response.errors).unwrap()
+    }
+
+    #[test]
+    fn too_deep_error() {
+        let errors = execute_with_limits("{ page { page { value } } }", QueryLimits::new(2, 1_000));
+        assert_eq!(errors[0]["extensions"]["code"], "QUERY_TOO_DEEP");
+        assert_eq!(errors[0]["extensions"]["limit"], 2);
+        assert!(errors[0]["extensions"].get("estimated").is_none());
+    }
+
+    #[test]
+    fn too_expensive_error() {
+        let errors = execute_with_limits(
+            "{ page(first: 10) { nodes { value } } }",
+            QueryLimits::new(10, 20),
+        );
+        assert_eq!(errors[0]["extensions"]["code"], "QUERY_TOO_EXPENSIVE");
+        assert_eq!(errors[0]["extensions"]["limit"], 20);
+        // page + 10 * (nodes + value)
+        assert_eq!(errors[0]["extensions"]["estimated"], 21);
+    }
+
+    #[test]
+    fn queries_within_limits_are_executed() {
+        let errors = execute_with_limits(
+            "{ page(first: 2) { nodes { value } } }",
+            QueryLimits::new(10, 20),
+        );
+        assert_eq!(errors, json!([]));
+    }
+}
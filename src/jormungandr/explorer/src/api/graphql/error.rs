@@ -14,4 +14,6 @@ pub enum ApiError {
     InvalidCursor(String),
     #[error("invalid address {0}")]
     InvalidAddress(String),
+    #[error("unauthorized: {0}")]
+    Unauthorized(String),
 }
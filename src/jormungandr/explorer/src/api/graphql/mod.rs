@@ -1,8 +1,10 @@
 mod certificates;
 mod config_param;
 mod connections;
+mod cost;
 mod error;
 mod scalars;
+pub use self::cost::QueryLimits;
 use self::{
     config_param::{EpochStabilityDepth, LinearFee},
     connections::{
@@ -23,11 +25,13 @@ use crate::db::{
     },
     persistent_sequence::PersistentSequence,
     tally::compute_public_tally,
-    ExplorerDb, Settings as ChainSettings,
+    watchlist::WatchlistError,
+    ExplorerDb, ReorgEvent, Settings as ChainSettings,
 };
+use crate::metadata::{ProposalFilter, ProposalMetadata, VotePlanMetadata};
 use async_graphql::{
     connection::{query, Connection, Edge, EmptyFields},
-    Context, EmptyMutation, FieldError, FieldResult, Object, SimpleObject, Subscription, Union,
+    ComplexObject, Context, FieldError, FieldResult, Object, SimpleObject, Subscription, Union,
 };
 use cardano_legacy_address::Addr as OldAddress;
 use certificates::*;
@@ -37,9 +41,11 @@ use chain_impl_mockchain::{
     fragment::FragmentId,
     key::BftLeaderId,
     stake::StakeControl,
+    value::Value as InternalValue,
     vote::{EncryptedVote, PayloadType as OtherPayloadType, ProofOfCorrectVote},
 };
 use std::{
+    collections::HashSet,
     convert::{TryFrom, TryInto},
     str::FromStr,
     sync::Arc,
@@ -110,13 +116,7 @@ impl Branch {
 
                 let (range, page_meta) = compute_interval(boundaries, pagination_arguments)?;
 
-                let mut connection = Connection::with_additional_fields(
-                    page_meta.has_previous_page,
-                    page_meta.has_next_page,
-                    ConnectionFields {
-                        total_count: page_meta.total_count,
-                    },
-                );
+                let mut connection = page_meta.into_connection();
 
                 let edges = match range {
                     PaginationInterval::Empty => Default::default(),
@@ -163,59 +163,17 @@ impl Branch {
             .transactions_by_address(&address)
             .unwrap_or_else(PersistentSequence::<FragmentId>::new);
 
-        let len = transactions.len();
-
-        query(
-            after,
-            before,
-            first,
-            last,
-            |after, before, first, last| async move {
-                let boundaries = if len > 0 {
-                    PaginationInterval::Inclusive(InclusivePaginationInterval {
-                        lower_bound: 0u64,
-                        upper_bound: len,
-                    })
-                } else {
-                    PaginationInterval::Empty
-                };
-
-                let pagination_arguments = ValidatedPaginationArguments {
-                    first,
-                    last,
-                    before: before.map(TryInto::try_into).transpose()?,
-                    after: after.map(TryInto::try_into).transpose()?,
-                };
-
-                let (range, page_meta) = compute_interval(boundaries, pagination_arguments)?;
-
-                let mut connection = Connection::with_additional_fields(
-                    page_meta.has_previous_page,
-                    page_meta.has_next_page,
-                    ConnectionFields {
-                        total_count: page_meta.total_count,
-                    },
-                );
-
-                let edges = match range {
-                    PaginationInterval::Empty => vec![],
-                    PaginationInterval::Inclusive(range) => (range.lower_bound..=range.upper_bound)
-                        .filter_map(|i| transactions.get(i).map(|h| (HeaderHash::clone(h), i)))
-                        .collect(),
-                };
-
-                connection.edges.extend(edges.iter().map(|(h, i)| {
-                    Edge::new(IndexCursor::from(*i), Transaction::from_valid_id(*h))
-                }));
-
-                Ok::<_, async_graphql::Error>(connection)
-            },
-        )
-        .await
+        transactions_connection(transactions, first, last, before, after).await
     }
 
+    /// get the vote plans, keeping only those with at least one proposal
+    /// matching the given off-chain metadata if any filter is set
+    #[allow(clippy::too_many_arguments)]
     pub async fn all_vote_plans(
         &self,
+        context: &Context<'_>,
+        challenge_id: Option<String>,
+        proposer: Option<String>,
         first: Option<i32>,
         last: Option<i32>,
         before: Option<String>,
@@ -223,7 +181,20 @@ impl Branch {
     ) -> FieldResult<
         Connection<IndexCursor, VotePlanStatus, ConnectionFields<VotePlanStatusCount>, EmptyFields>,
     > {
+        let metadata = &extract_context(context).metadata;
+        let filter = ProposalFilter {
+            challenge_id,
+            proposer,
+        };
+
         let mut vote_plans = self.state.state().get_vote_plans();
+        if !filter.is_empty() {
+            vote_plans.retain(|(_id, vote_plan)| {
+                vote_plan.proposals.iter().any(|proposal| {
+                    filter.matches(metadata.proposal(&proposal.proposal_id.to_string()))
+                })
+            });
+        }
 
         vote_plans.sort_unstable_by_key(|(id, _data)| id.clone());
 
@@ -255,13 +226,7 @@ impl Branch {
                 };
 
                 let (range, page_meta) = compute_interval(boundaries, pagination_arguments)?;
-                let mut connection = Connection::with_additional_fields(
-                    page_meta.has_previous_page,
-                    page_meta.has_next_page,
-                    ConnectionFields {
-                        total_count: page_meta.total_count,
-                    },
-                );
+                let mut connection = page_meta.into_connection();
 
                 let edges = match range {
                     PaginationInterval::Empty => vec![],
@@ -274,7 +239,10 @@ impl Branch {
                                 let (_pool_id, vote_plan_data) =
                                     &vote_plans[usize::try_from(i).unwrap()];
                                 (
-                                    VotePlanStatus::vote_plan_from_data(Arc::clone(vote_plan_data)),
+                                    VotePlanStatus::vote_plan_from_data(
+                                        Arc::clone(vote_plan_data),
+                                        metadata,
+                                    ),
                                     i,
                                 )
                             })
@@ -338,13 +306,7 @@ impl Branch {
                 };
 
                 let (range, page_meta) = compute_interval(boundaries, pagination_arguments)?;
-                let mut connection = Connection::with_additional_fields(
-                    page_meta.has_previous_page,
-                    page_meta.has_next_page,
-                    ConnectionFields {
-                        total_count: page_meta.total_count,
-                    },
-                );
+                let mut connection = page_meta.into_connection();
 
                 let edges = match range {
                     PaginationInterval::Empty => vec![],
@@ -435,18 +397,10 @@ impl Branch {
                     };
 
                     let (range, page_meta) = compute_interval(boundaries, pagination_arguments)?;
-                    let mut connection = Connection::with_additional_fields(
-                        page_meta.has_previous_page,
-                        page_meta.has_next_page,
-                        ConnectionFields {
-                            total_count: page_meta.total_count,
-                        },
-                    );
+                    let mut connection = page_meta.into_connection();
 
                     let edges = match range {
-                        PaginationInterval::Empty => {
-                            unreachable!("No blocks found (not even genesis)")
-                        }
+                        PaginationInterval::Empty => vec![],
                         PaginationInterval::Inclusive(range) => self
                             .state
                             .state()
@@ -601,13 +555,7 @@ impl Block {
                 };
 
                 let (range, page_meta) = compute_interval(boundaries, pagination_arguments)?;
-                let mut connection = Connection::with_additional_fields(
-                    page_meta.has_previous_page,
-                    page_meta.has_next_page,
-                    ConnectionFields {
-                        total_count: page_meta.total_count,
-                    },
-                );
+                let mut connection = page_meta.into_connection();
 
                 let edges = match range {
                     PaginationInterval::Empty => vec![],
@@ -736,6 +684,35 @@ impl From<InternalBlockDate> for BlockDate {
     }
 }
 
+/// A switch of the main branch to a tip that does not descend from the
+/// previous one. Blocks of the abandoned branch are no longer part of the
+/// main branch, and may no longer be known by the explorer.
+#[derive(SimpleObject)]
+pub struct Reorg {
+    /// tip of the abandoned branch
+    abandoned_tip: String,
+    /// tip of the main branch after the switch
+    new_tip: String,
+    /// last block shared by both branches
+    common_ancestor: String,
+    /// number of blocks of the abandoned branch that were rolled back
+    depth: u32,
+    /// time the switch was processed by the explorer, in RFC 3339 format
+    timestamp: String,
+}
+
+impl From<ReorgEvent> for Reorg {
+    fn from(event: ReorgEvent) -> Reorg {
+        Reorg {
+            abandoned_tip: event.abandoned_tip.to_string(),
+            new_tip: event.new_tip.to_string(),
+            common_ancestor: event.common_ancestor.to_string(),
+            depth: event.depth,
+            timestamp: jormungandr_lib::time::SystemTime::from(event.timestamp).to_string(),
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct Transaction {
     id: FragmentId,
@@ -892,6 +869,18 @@ impl Transaction {
             .collect())
     }
 
+    /// Fee paid by the transaction
+    pub async fn fee(&self, context: &Context<'_>) -> FieldResult<Value> {
+        let transaction = self.get_contents(context).await?;
+        Ok(Value(transaction.fee()))
+    }
+
+    /// Size of the serialized transaction fragment, in bytes
+    pub async fn size(&self, context: &Context<'_>) -> FieldResult<u32> {
+        let transaction = self.get_contents(context).await?;
+        Ok(transaction.size())
+    }
+
     pub async fn certificate(
         &self,
         context: &Context<'_>,
@@ -955,6 +944,50 @@ impl Address {
     }
 }
 
+/// A named set of addresses monitored through the explorer
+pub struct Watchlist {
+    name: String,
+    addresses: Arc<HashSet<ExplorerAddress>>,
+}
+
+impl Watchlist {
+    async fn from_name(name: String, context: &Context<'_>) -> FieldResult<Watchlist> {
+        let addresses = extract_context(context)
+            .db
+            .watchlists()
+            .get(&name)
+            .await
+            .ok_or_else(|| ApiError::NotFound(format!("watchlist not found: {}", name)))?;
+        Ok(Watchlist { name, addresses })
+    }
+}
+
+#[Object]
+impl Watchlist {
+    pub async fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub async fn addresses(&self) -> Vec<Address> {
+        self.addresses.iter().map(Address::from).collect()
+    }
+
+    /// Sum of the balances of the addresses in the main branch, resulting
+    /// from the transactions to and from them. Rewards credited to accounts
+    /// are not included.
+    pub async fn balance(&self, context: &Context<'_>) -> Value {
+        let (_, state_ref) = extract_context(context).db.get_tip().await;
+        let state = state_ref.state();
+        Value(
+            self.addresses
+                .iter()
+                .fold(InternalValue::zero(), |total, address| {
+                    total.saturating_add(state.balance(address))
+                }),
+        )
+    }
+}
+
 pub struct TaxType(chain_impl_mockchain::rewards::TaxType);
 
 #[Object]
@@ -1062,7 +1095,8 @@ impl Pool {
         last: Option<i32>,
         before: Option<String>,
         after: Option<String>,
-    ) -> FieldResult<Connection<IndexCursor, Block, ConnectionFields<BlockCount>>> {
+    ) -> FieldResult<Connection<IndexCursor, Block, ConnectionFields<BlockCount>, EmptyFields>>
+    {
         let blocks = match &self.blocks {
             Some(b) => b.clone(),
             None => extract_context(context)
@@ -1110,13 +1144,7 @@ impl Pool {
                         .collect(),
                 };
 
-                let mut connection = Connection::with_additional_fields(
-                    page_meta.has_previous_page,
-                    page_meta.has_next_page,
-                    ConnectionFields {
-                        total_count: page_meta.total_count,
-                    },
-                );
+                let mut connection = page_meta.into_connection();
 
                 connection.edges.extend(
                     edges
@@ -1157,6 +1185,39 @@ impl Pool {
                 }),
         }
     }
+
+    /// Number of blocks produced by the pool in the main branch during the epoch
+    pub async fn blocks_produced(&self, context: &Context<'_>, epoch: EpochNumber) -> u32 {
+        extract_context(context)
+            .db
+            .get_stake_pool_epoch(&self.id, epoch.0)
+            .await
+            .map(|data| data.blocks_produced)
+            .unwrap_or(0)
+    }
+
+    /// Total rewards earned by the pool for the blocks produced during the
+    /// epoch, before the split between its owners and delegators. The
+    /// rewards are distributed when the next epoch starts.
+    pub async fn rewards_earned(&self, context: &Context<'_>, epoch: EpochNumber) -> Value {
+        extract_context(context)
+            .db
+            .get_stake_pool_epoch(&self.id, epoch.0)
+            .await
+            .map(|data| Value(data.rewards))
+            .unwrap_or_else(|| Value(chain_impl_mockchain::value::Value::zero()))
+    }
+
+    /// Estimated annual percentage rate for the delegators of the pool, as a
+    /// fraction, based on the rewards of the last completed epoch and the
+    /// stake the pool had in the leadership of that epoch. Null if nothing
+    /// was delegated.
+    pub async fn estimated_apr(&self, context: &Context<'_>) -> Option<f64> {
+        extract_context(context)
+            .db
+            .get_stake_pool_estimated_apr(&self.id)
+            .await
+    }
 }
 
 pub struct Settings {}
@@ -1239,6 +1300,20 @@ impl Epoch {
             .await
             .map_or(0u32.into(), |data| data.total_blocks.into())
     }
+
+    /// Sum of the fees paid by the transactions included in the epoch
+    pub async fn total_fees(&self, context: &Context<'_>) -> Option<Value> {
+        self.get_epoch_data(&extract_context(context).db)
+            .await
+            .map(|data| Value(data.total_fees))
+    }
+
+    /// Sum of the outputs of the transactions included in the epoch
+    pub async fn total_output(&self, context: &Context<'_>) -> Option<Value> {
+        self.get_epoch_data(&extract_context(context).db)
+            .await
+            .map(|data| Value(data.total_output))
+    }
 }
 
 #[derive(SimpleObject)]
@@ -1309,15 +1384,37 @@ pub enum TallyStatus {
 }
 
 #[derive(Clone, SimpleObject)]
+#[graphql(complex)]
 pub struct VotePlanStatus {
     id: VotePlanId,
     vote_start: BlockDate,
     vote_end: BlockDate,
     committee_end: BlockDate,
     payload_type: PayloadType,
+    #[graphql(skip)]
     proposals: Vec<VoteProposalStatus>,
 }
 
+#[ComplexObject]
+impl VotePlanStatus {
+    /// the proposals of the vote plan, keeping only those matching the given
+    /// off-chain metadata if any filter is set
+    pub async fn proposals(
+        &self,
+        challenge_id: Option<String>,
+        proposer: Option<String>,
+    ) -> Vec<&VoteProposalStatus> {
+        let filter = ProposalFilter {
+            challenge_id,
+            proposer,
+        };
+        self.proposals
+            .iter()
+            .filter(|proposal| filter.matches(proposal.metadata.as_ref()))
+            .collect()
+    }
+}
+
 impl VotePlanStatus {
     pub async fn vote_plan_from_id(
         vote_plan_id: VotePlanId,
@@ -1330,13 +1427,19 @@ impl VotePlanStatus {
             .get_vote_plan_by_id(&vote_plan_id)
             .await
         {
-            return Ok(Self::vote_plan_from_data(vote_plan));
+            return Ok(Self::vote_plan_from_data(
+                vote_plan,
+                &extract_context(context).metadata,
+            ));
         }
 
         Err(ApiError::NotFound(format!("Vote plan with id {} not found", vote_plan_id)).into())
     }
 
-    pub fn vote_plan_from_data(vote_plan: Arc<ExplorerVotePlan>) -> Self {
+    pub fn vote_plan_from_data(
+        vote_plan: Arc<ExplorerVotePlan>,
+        metadata: &VotePlanMetadata,
+    ) -> Self {
         let ExplorerVotePlan {
             id,
             vote_start,
@@ -1355,6 +1458,9 @@ impl VotePlanStatus {
             proposals: proposals
                 .into_iter()
                 .map(|proposal| VoteProposalStatus {
+                    metadata: metadata
+                        .proposal(&proposal.proposal_id.to_string())
+                        .cloned(),
                     proposal_id: ExternalProposalId::from(proposal.proposal_id.clone()),
                     options: VoteOptionRange::from(proposal.options.clone()),
                     tally: proposal.tally.clone().map_or(
@@ -1458,6 +1564,7 @@ pub struct VoteProposalStatus {
     options: VoteOptionRange,
     tally: Option<TallyStatus>,
     votes: Vec<VoteStatus>,
+    metadata: Option<ProposalMetadata>,
 }
 
 #[Object]
@@ -1466,6 +1573,20 @@ impl VoteProposalStatus {
         &self.proposal_id
     }
 
+    /// id of the challenge of the proposal, from the off-chain metadata
+    pub async fn challenge_id(&self) -> Option<&String> {
+        self.metadata
+            .as_ref()
+            .and_then(|metadata| metadata.challenge_id.as_ref())
+    }
+
+    /// alias of the proposer, from the off-chain metadata
+    pub async fn proposer(&self) -> Option<&String> {
+        self.metadata
+            .as_ref()
+            .and_then(|metadata| metadata.proposer.as_ref())
+    }
+
     pub async fn options(&self) -> &VoteOptionRange {
         &self.options
     }
@@ -1510,13 +1631,7 @@ impl VoteProposalStatus {
                 };
 
                 let (range, page_meta) = compute_interval(boundaries, pagination_arguments)?;
-                let mut connection = Connection::with_additional_fields(
-                    page_meta.has_previous_page,
-                    page_meta.has_next_page,
-                    ConnectionFields {
-                        total_count: page_meta.total_count,
-                    },
-                );
+                let mut connection = page_meta.into_connection();
 
                 let edges = match range {
                     PaginationInterval::Empty => vec![],
@@ -1543,6 +1658,63 @@ impl VoteProposalStatus {
     }
 }
 
+/// Paginates over a sequence of transactions, the cursor being the index in the sequence
+async fn transactions_connection(
+    transactions: PersistentSequence<FragmentId>,
+    first: Option<i32>,
+    last: Option<i32>,
+    before: Option<String>,
+    after: Option<String>,
+) -> FieldResult<
+    Connection<IndexCursor, Transaction, ConnectionFields<TransactionCount>, EmptyFields>,
+> {
+    let len = transactions.len();
+
+    query(
+        after,
+        before,
+        first,
+        last,
+        |after, before, first, last| async move {
+            let boundaries = if len > 0 {
+                PaginationInterval::Inclusive(InclusivePaginationInterval {
+                    lower_bound: 0u64,
+                    upper_bound: len.checked_sub(1).unwrap(),
+                })
+            } else {
+                PaginationInterval::Empty
+            };
+
+            let pagination_arguments = ValidatedPaginationArguments {
+                first,
+                last,
+                before: before.map(TryInto::try_into).transpose()?,
+                after: after.map(TryInto::try_into).transpose()?,
+            };
+
+            let (range, page_meta) = compute_interval(boundaries, pagination_arguments)?;
+
+            let mut connection = page_meta.into_connection();
+
+            let edges = match range {
+                PaginationInterval::Empty => vec![],
+                PaginationInterval::Inclusive(range) => (range.lower_bound..=range.upper_bound)
+                    .filter_map(|i| transactions.get(i).map(|h| (HeaderHash::clone(h), i)))
+                    .collect(),
+            };
+
+            connection.edges.extend(
+                edges
+                    .iter()
+                    .map(|(h, i)| Edge::new(IndexCursor::from(*i), Transaction::from_valid_id(*h))),
+            );
+
+            Ok::<_, async_graphql::Error>(connection)
+        },
+    )
+    .await
+}
+
 pub struct Query;
 
 #[Object]
@@ -1598,6 +1770,18 @@ impl Query {
         Branch::try_from_id(id, extract_context(context)).await
     }
 
+    /// get the latest switches of the main branch that rolled back blocks,
+    /// the most recent first
+    pub async fn recent_reorgs(&self, context: &Context<'_>) -> Vec<Reorg> {
+        extract_context(context)
+            .db
+            .recent_reorgs()
+            .await
+            .into_iter()
+            .map(Reorg::from)
+            .collect()
+    }
+
     pub async fn epoch(&self, _context: &Context<'_>, id: EpochNumber) -> Epoch {
         Epoch::from_epoch_number(id.0)
     }
@@ -1606,6 +1790,10 @@ impl Query {
         Address::from_bech32(&bech32)
     }
 
+    pub async fn watchlist(&self, context: &Context<'_>, name: String) -> FieldResult<Watchlist> {
+        Watchlist::from_name(name, context).await
+    }
+
     pub async fn stake_pool(&self, context: &Context<'_>, id: PoolId) -> FieldResult<Pool> {
         Pool::from_string_id(&id.0.to_string(), &extract_context(context).db).await
     }
@@ -1621,6 +1809,84 @@ impl Query {
     ) -> FieldResult<VotePlanStatus> {
         VotePlanStatus::vote_plan_from_id(VotePlanId(id), context).await
     }
+
+    /// get the transactions carrying a certificate of the given type in the
+    /// main branch, in the order they were included in the chain
+    pub async fn certificates(
+        &self,
+        context: &Context<'_>,
+        #[graphql(name = "type")] certificate_type: CertificateType,
+        first: Option<i32>,
+        last: Option<i32>,
+        before: Option<String>,
+        after: Option<String>,
+    ) -> FieldResult<
+        Connection<IndexCursor, Transaction, ConnectionFields<TransactionCount>, EmptyFields>,
+    > {
+        let (_, state_ref) = extract_context(context).db.get_tip().await;
+        let transactions = state_ref
+            .state()
+            .transactions_by_certificate(certificate_type.into())
+            .unwrap_or_else(PersistentSequence::<FragmentId>::new);
+
+        transactions_connection(transactions, first, last, before, after).await
+    }
+}
+
+pub struct Mutation;
+
+#[Object]
+impl Mutation {
+    /// add the addresses to the watchlist, creating it if it does not exist.
+    /// Watchlists are not persisted across restarts of the explorer
+    pub async fn watch_addresses(
+        &self,
+        context: &Context<'_>,
+        token: String,
+        watchlist: String,
+        addresses: Vec<String>,
+    ) -> FieldResult<Watchlist> {
+        check_watchlist_token(context, &token)?;
+        let addresses = addresses
+            .iter()
+            .map(|bech32| Address::from_bech32(bech32).map(|address| address.id))
+            .collect::<FieldResult<Vec<_>>>()?;
+        let addresses = extract_context(context)
+            .db
+            .watchlists()
+            .watch(watchlist.clone(), addresses)
+            .await
+            .map_err(|err: WatchlistError| ApiError::ArgumentError(err.to_string()))?;
+        Ok(Watchlist {
+            name: watchlist,
+            addresses,
+        })
+    }
+
+    /// remove the watchlist, returns false if it did not exist
+    pub async fn remove_watchlist(
+        &self,
+        context: &Context<'_>,
+        token: String,
+        watchlist: String,
+    ) -> FieldResult<bool> {
+        check_watchlist_token(context, &token)?;
+        Ok(extract_context(context)
+            .db
+            .watchlists()
+            .remove(&watchlist)
+            .await)
+    }
+}
+
+/// The watchlists are kept in memory, so only the clients given the token
+/// configured for the explorer can change them
+fn check_watchlist_token(context: &Context<'_>, token: &str) -> FieldResult<()> {
+    match &extract_context(context).settings.watchlist_token {
+        Some(expected) if expected == token => Ok(()),
+        Some(_) => Err(ApiError::Unauthorized("invalid watchlist token".to_string()).into()),
+        None => Err(ApiError::Unauthorized("watchlists are not enabled".to_string()).into()),
+    }
 }
 
 pub struct Subscription;
@@ -1639,13 +1905,61 @@ impl Subscription {
                     .map(|(hash, state)| Branch::from_id_and_state(hash, state))
             })
     }
+
+    /// transactions to or from the addresses of the watchlist, as they are
+    /// included in the main branch. Changes to the watchlist apply to the
+    /// blocks added after them
+    async fn watchlist_transactions(
+        &self,
+        context: &Context<'_>,
+        watchlist: String,
+    ) -> FieldResult<impl futures::Stream<Item = Transaction>> {
+        use futures::StreamExt;
+        // fail early if the watchlist does not exist
+        Watchlist::from_name(watchlist.clone(), context).await?;
+        let db = extract_context(context).db.clone();
+        let watchlists = db.watchlists().clone();
+        Ok(db
+            .main_branch_blocks()
+            .then(move |block| {
+                let watchlists = watchlists.clone();
+                let watchlist = watchlist.clone();
+                async move {
+                    let addresses = watchlists.get(&watchlist).await.unwrap_or_default();
+                    let mut transactions: Vec<_> = block
+                        .transactions
+                        .values()
+                        .filter(|tx| {
+                            tx.inputs()
+                                .iter()
+                                .map(|input| &input.address)
+                                .chain(tx.outputs().iter().map(|output| &output.address))
+                                .any(|address| addresses.contains(address))
+                        })
+                        .map(|tx| Transaction {
+                            id: tx.id(),
+                            block_hashes: vec![block.id()],
+                            contents: Some(tx.clone()),
+                        })
+                        .collect();
+                    transactions.sort_unstable_by_key(|tx| {
+                        tx.contents
+                            .as_ref()
+                            .map(|contents| contents.offset_in_block)
+                    });
+                    futures::stream::iter(transactions)
+                }
+            })
+            .flatten())
+    }
 }
 
-pub type Schema = async_graphql::Schema<Query, EmptyMutation, Subscription>;
+pub type Schema = async_graphql::Schema<Query, Mutation, Subscription>;
 
 pub struct EContext {
     pub db: ExplorerDb,
     pub settings: ChainSettings,
+    pub metadata: Arc<VotePlanMetadata>,
 }
 
 fn extract_context<'a>(context: &Context<'a>) -> &'a EContext {
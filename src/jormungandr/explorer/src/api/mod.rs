@@ -1,11 +1,12 @@
+pub mod export;
 pub mod graphql;
 
 use self::graphql::EContext;
-use crate::db::ExplorerDb;
+use crate::{db::ExplorerDb, metadata::VotePlanMetadata};
 use async_graphql::http::{playground_source, GraphQLPlaygroundConfig};
 use futures::Future;
 use jormungandr_lib::interfaces::{Cors, Tls};
-use std::{net::SocketAddr, time::Duration};
+use std::{net::SocketAddr, sync::Arc, time::Duration};
 use warp::{http::Response as HttpResponse, Filter, Rejection, Reply};
 
 pub async fn setup_cors<API>(
@@ -66,15 +67,26 @@ async fn serve<API>(
 pub fn filter(
     db: ExplorerDb,
     settings: crate::db::Settings,
+    metadata: VotePlanMetadata,
 ) -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
+    let export = export::filter(db.clone(), settings.address_bech32_prefix.clone());
+
     let schema = async_graphql::Schema::build(
         crate::api::graphql::Query {},
-        async_graphql::EmptyMutation,
+        crate::api::graphql::Mutation {},
         crate::api::graphql::Subscription {},
     )
     .limit_depth(settings.query_depth_limit)
     .limit_complexity(settings.query_complexity_limit)
-    .data(EContext { db, settings })
+    .extension(graphql::QueryLimits::new(
+        settings.query_depth_limit,
+        settings.query_cost_limit,
+    ))
+    .data(EContext {
+        db,
+        settings,
+        metadata: Arc::new(metadata),
+    })
     .finish();
 
     let graphql_post = async_graphql_warp::graphql(schema.clone())
@@ -98,6 +110,7 @@ pub fn filter(
     subscription
         .or(graphql)
         .or(playground)
+        .or(export)
         .with(warp::filters::trace::trace(|info| {
             use http_zipkin::get_trace_context;
             use tracing::field::Empty;
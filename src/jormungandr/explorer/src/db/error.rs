@@ -20,6 +20,8 @@ pub enum ExplorerError {
     BootstrapError(String),
     #[error("cannot apply block")]
     CannotApplyBlock,
+    #[error("transaction '{0}' spends more than the indexed balance of one of its inputs")]
+    BalanceUnderflow(FragmentId),
     #[error("tally decryption failure")]
     TallyDecryptionFailure,
     #[error("tx calculation failure")]
@@ -1,9 +1,9 @@
 use super::{error, persistent_sequence::PersistentSequence};
 use cardano_legacy_address::Addr as OldAddress;
 use chain_addr::{Address, Discrimination};
-use chain_core::property::{Block as _, Fragment as _};
+use chain_core::property::{Block as _, Fragment as _, Serialize as _};
 use chain_impl_mockchain::{
-    account::Identifier,
+    account::{DelegationType, Identifier},
     block::{Block, Proof},
     certificate::{
         Certificate, ExternalProposalId, PoolId, PoolRegistration, PoolRetirement, VotePlanId,
@@ -29,10 +29,14 @@ pub type Blocks = Hamt<HeaderHash, ExplorerBlock>;
 pub type ChainLengths = Hamt<ChainLength, HeaderHash>;
 
 pub type Addresses = Hamt<ExplorerAddress, PersistentSequence<FragmentId>>;
+pub type Balances = Hamt<ExplorerAddress, Value>;
+pub type Certificates = Hamt<CertificateKind, PersistentSequence<FragmentId>>;
 pub type Epochs = Hamt<Epoch, EpochData>;
 
 pub type StakePoolBlocks = Hamt<PoolId, PersistentSequence<HeaderHash>>;
 pub type StakePool = Hamt<PoolId, StakePoolData>;
+pub type StakePoolEpochs = Hamt<(PoolId, Epoch), StakePoolEpochData>;
+pub type Delegations = Hamt<Identifier, DelegationType>;
 
 pub type VotePlans = Hamt<VotePlanId, ExplorerVotePlan>;
 
@@ -43,6 +47,16 @@ pub struct StakePoolData {
     // TODO: Track updates here too?
 }
 
+/// Blocks produced by a stake pool during an epoch and the rewards it got for them
+#[derive(Clone, Debug)]
+pub struct StakePoolEpochData {
+    pub blocks_produced: u32,
+    /// Total reward of the pool, before the split between the owners and
+    /// the delegators. Rewards are distributed when the next epoch starts,
+    /// this is zero until then.
+    pub rewards: Value,
+}
+
 /// Block with unified inputs the metadata needed in the queries
 #[derive(Clone, Debug)]
 pub struct ExplorerBlock {
@@ -72,6 +86,10 @@ pub struct ExplorerTransaction {
     pub certificate: Option<Certificate>,
     pub offset_in_block: u32,
     pub config_params: Option<ConfigParams>,
+    /// Fee paid by the transaction, i.e. the difference between inputs and outputs
+    pub fee: Value,
+    /// Size of the serialized fragment, in bytes
+    pub size: u32,
 }
 
 impl Default for ExplorerTransaction {
@@ -83,6 +101,8 @@ impl Default for ExplorerTransaction {
             certificate: Default::default(),
             offset_in_block: Default::default(),
             config_params: Default::default(),
+            fee: Value::zero(),
+            size: Default::default(),
         }
     }
 }
@@ -105,6 +125,50 @@ pub struct EpochData {
     pub first_block: HeaderHash,
     pub last_block: HeaderHash,
     pub total_blocks: u32,
+    /// Sum of the fees paid by the transactions of the epoch
+    pub total_fees: Value,
+    /// Sum of the outputs of the transactions of the epoch
+    pub total_output: Value,
+}
+
+/// The type of a certificate, used to index the transactions carrying it
+#[derive(Eq, PartialEq, Clone, Copy, Hash, Debug)]
+pub enum CertificateKind {
+    StakeDelegation,
+    OwnerStakeDelegation,
+    PoolRegistration,
+    PoolRetirement,
+    PoolUpdate,
+    VotePlan,
+    VoteCast,
+    VoteTally,
+    UpdateProposal,
+    UpdateVote,
+    MintToken,
+    EvmMapping,
+    Anchor,
+    MultisigDeclarationUpdate,
+}
+
+impl From<&Certificate> for CertificateKind {
+    fn from(certificate: &Certificate) -> Self {
+        match certificate {
+            Certificate::StakeDelegation(_) => CertificateKind::StakeDelegation,
+            Certificate::OwnerStakeDelegation(_) => CertificateKind::OwnerStakeDelegation,
+            Certificate::PoolRegistration(_) => CertificateKind::PoolRegistration,
+            Certificate::PoolRetirement(_) => CertificateKind::PoolRetirement,
+            Certificate::PoolUpdate(_) => CertificateKind::PoolUpdate,
+            Certificate::VotePlan(_) => CertificateKind::VotePlan,
+            Certificate::VoteCast(_) => CertificateKind::VoteCast,
+            Certificate::VoteTally(_) => CertificateKind::VoteTally,
+            Certificate::UpdateProposal(_) => CertificateKind::UpdateProposal,
+            Certificate::UpdateVote(_) => CertificateKind::UpdateVote,
+            Certificate::MintToken(_) => CertificateKind::MintToken,
+            Certificate::EvmMapping(_) => CertificateKind::EvmMapping,
+            Certificate::Anchor(_) => CertificateKind::Anchor,
+            Certificate::MultisigDeclarationUpdate(_) => CertificateKind::MultisigDeclarationUpdate,
+        }
+    }
 }
 
 #[derive(Eq, PartialEq, Clone, Hash, Debug)]
@@ -189,6 +253,8 @@ impl ExplorerBlock {
                     certificate: None,
                     offset_in_block: offset,
                     config_params: Some(config.clone()),
+                    fee: Value::zero(),
+                    size: 0,
                 }),
                 Fragment::UpdateProposal(tx) => {
                     let tx = tx.as_slice();
@@ -379,6 +445,42 @@ impl ExplorerBlock {
                         }
                     }
                 }
+                Fragment::Anchor(tx) => {
+                    let tx = tx.as_slice();
+                    match ExplorerTransaction::from(
+                        &context,
+                        &fragment_id,
+                        &tx,
+                        Some(Certificate::Anchor(tx.payload().into_payload())),
+                        offset,
+                        &current_block_txs,
+                    ) {
+                        Ok(tx) => Some(tx),
+                        Err(e) => {
+                            error!(error = %e, "unable to map anchor fragment");
+                            return Err(Error::ExplorerTransmuteFail);
+                        }
+                    }
+                }
+                Fragment::MultisigDeclarationUpdate(tx) => {
+                    let tx = tx.as_slice();
+                    match ExplorerTransaction::from(
+                        &context,
+                        &fragment_id,
+                        &tx,
+                        Some(Certificate::MultisigDeclarationUpdate(
+                            tx.payload().into_payload(),
+                        )),
+                        offset,
+                        &current_block_txs,
+                    ) {
+                        Ok(tx) => Some(tx),
+                        Err(e) => {
+                            error!(error = %e, "unable to map multisig declaration update fragment");
+                            return Err(Error::ExplorerTransmuteFail);
+                        }
+                    }
+                }
                 Fragment::OldUtxoDeclaration(decl) => {
                     let outputs = decl
                         .addrs
@@ -395,12 +497,15 @@ impl ExplorerBlock {
                         certificate: None,
                         offset_in_block: offset,
                         config_params: None,
+                        fee: Value::zero(),
+                        size: 0,
                     })
                 }
                 _ => None,
             };
 
-            if let Some(etx) = metx {
+            if let Some(mut etx) = metx {
+                etx.size = fragment.serialized_size().try_into().unwrap();
                 current_block_txs.insert(fragment_id, etx);
             }
         }
@@ -514,6 +619,7 @@ impl ExplorerTransaction {
                     new_inputs.push(ExplorerInput { address, value });
                 }
                 (InputEnum::AccountInput(_, _), Witness::OldUtxo(_, _, _)) => {}
+                (InputEnum::AccountInput(_, _), Witness::Script(_)) => {}
                 (InputEnum::AccountInput(id, value), Witness::Multisig(_, _)) => {
                     let kind = chain_addr::Kind::Multisig(
                         match id.to_multi_account().as_ref().try_into() {
@@ -529,7 +635,7 @@ impl ExplorerTransaction {
                     let address = ExplorerAddress::New(Address(context.discrimination, kind));
                     new_inputs.push(ExplorerInput { address, value });
                 }
-                (InputEnum::UtxoInput(utxo_pointer), Witness::Utxo(_)) => {
+                (InputEnum::UtxoInput(utxo_pointer), Witness::Utxo(_) | Witness::Script(_)) => {
                     let tx = utxo_pointer.transaction_id;
                     let index = utxo_pointer.output_index;
 
@@ -569,6 +675,24 @@ impl ExplorerTransaction {
             }
         }
 
+        // the initial funds of block0 are transactions without inputs and pay no fee;
+        // otherwise the ledger only accepts balanced transactions, so whatever is
+        // not spent in outputs goes to fees
+        let fee = if tx.nb_inputs() == 0 {
+            Value::zero()
+        } else {
+            match tx
+                .total_input()
+                .and_then(|input| tx.total_output().and_then(|output| input - output))
+            {
+                Ok(fee) => fee,
+                Err(e) => {
+                    error!(error = %e, "cannot compute transaction fee");
+                    return Err(Error::ExplorerTransmuteFail);
+                }
+            }
+        };
+
         Ok(ExplorerTransaction {
             id: *id,
             inputs: new_inputs,
@@ -576,6 +700,9 @@ impl ExplorerTransaction {
             certificate,
             offset_in_block,
             config_params: None,
+            fee,
+            // set by the caller, only the transaction slice is available here
+            size: 0,
         })
     }
 
@@ -590,6 +717,14 @@ impl ExplorerTransaction {
     pub fn outputs(&self) -> &Vec<ExplorerOutput> {
         &self.outputs
     }
+
+    pub fn fee(&self) -> Value {
+        self.fee
+    }
+
+    pub fn size(&self) -> u32 {
+        self.size
+    }
 }
 
 impl ExplorerAddress {
@@ -2,21 +2,30 @@ pub mod error;
 pub mod indexing;
 pub mod multiverse;
 pub mod persistent_sequence;
+pub mod rewards;
 pub(crate) mod tally;
+pub mod watchlist;
 
 use self::{
     error::{BlockNotFound, ExplorerError as Error},
     indexing::{
-        Addresses, Blocks, ChainLengths, EpochData, Epochs, ExplorerAddress, ExplorerBlock,
-        ExplorerVote, ExplorerVotePlan, ExplorerVoteProposal, StakePool, StakePoolBlocks,
-        StakePoolData, Transactions, VotePlans,
+        Addresses, Balances, Blocks, CertificateKind, Certificates, ChainLengths, Delegations,
+        EpochData, Epochs, ExplorerAddress, ExplorerBlock, ExplorerVote, ExplorerVotePlan,
+        ExplorerVoteProposal, StakePool, StakePoolBlocks, StakePoolData, StakePoolEpochData,
+        StakePoolEpochs, Transactions, VotePlans,
     },
     persistent_sequence::PersistentSequence,
+    rewards::{
+        compute_epoch_rewards, distribute_pool_reward, pools_stake, total_stake, AccountRewards,
+        LeadershipStake, PoolStake, RewardPots, RewardSettings,
+    },
+    watchlist::Watchlists,
 };
 use crate::db::tally::{compute_private_tally, compute_public_tally};
 use chain_addr::Discrimination;
 use chain_core::property::Block as _;
 use chain_impl_mockchain::{
+    account::Identifier,
     block::{Block, ChainLength, Epoch, HeaderId as HeaderHash},
     certificate::{Certificate, PoolId, VotePlanId},
     chaintypes::ConsensusVersion,
@@ -24,21 +33,27 @@ use chain_impl_mockchain::{
     fee::LinearFee,
     fragment::{ConfigParams, Fragment, FragmentId},
     stake::{Stake, StakeControl},
+    value::Value,
     vote::PayloadType,
 };
 use futures::prelude::*;
 use multiverse::Multiverse;
 pub use multiverse::Ref;
 use std::{
+    collections::VecDeque,
     convert::Infallible,
     sync::{
         atomic::{AtomicU32, Ordering},
         Arc,
     },
+    time::SystemTime,
 };
 use tokio::sync::{broadcast, RwLock};
 use tracing::error;
 
+/// Number of reorg events kept in memory by the explorer
+const RECENT_REORGS_CAPACITY: usize = 64;
+
 #[derive(Clone)]
 pub struct Explorer {
     pub db: ExplorerDb,
@@ -60,6 +75,24 @@ pub struct ExplorerDb {
     pub blockchain_config: BlockchainConfig,
     stable_store: StableIndex,
     tip_broadcast: tokio::sync::broadcast::Sender<(HeaderHash, multiverse::Ref)>,
+    /// The most recent switches of the main branch, the latest last
+    recent_reorgs: Arc<RwLock<VecDeque<ReorgEvent>>>,
+    watchlists: Watchlists,
+}
+
+/// A switch of the main branch to a tip that does not descend from the
+/// previous one, rolling back the blocks of the abandoned branch.
+#[derive(Clone, Debug)]
+pub struct ReorgEvent {
+    /// Tip of the abandoned branch
+    pub abandoned_tip: HeaderHash,
+    /// Tip of the new main branch
+    pub new_tip: HeaderHash,
+    /// Last block shared by both branches
+    pub common_ancestor: HeaderHash,
+    /// Number of blocks of the abandoned branch that were rolled back
+    pub depth: u32,
+    pub timestamp: SystemTime,
 }
 
 #[derive(Clone)]
@@ -75,6 +108,9 @@ pub struct BlockchainConfig {
     pub consensus_version: ConsensusVersion,
     pub fees: LinearFee,
     pub epoch_stability_depth: u32,
+    pub slots_per_epoch: u32,
+    pub slot_duration: u8,
+    pub rewards: RewardSettings,
 }
 
 /// Inmutable data structure used to represent the explorer's state at a given Block
@@ -85,10 +121,16 @@ pub struct State {
     pub transactions: Transactions,
     pub blocks: Blocks,
     addresses: Addresses,
+    balances: Balances,
+    certificates: Certificates,
     epochs: Epochs,
     chain_lengths: ChainLengths,
     stake_pool_data: StakePool,
     stake_pool_blocks: StakePoolBlocks,
+    stake_pool_epochs: StakePoolEpochs,
+    reward_pots: RewardPots,
+    leadership_stake: LeadershipStake,
+    delegations: Delegations,
     vote_plans: VotePlans,
     stake_control: StakeControl,
 }
@@ -103,6 +145,12 @@ pub struct Settings {
 
     pub query_depth_limit: usize,
     pub query_complexity_limit: usize,
+    /// Maximum estimated cost of a query, accounting for the size of the
+    /// requested pages
+    pub query_cost_limit: usize,
+    /// Token the watchlist mutations have to be given, the mutations are
+    /// disabled if not set
+    pub watchlist_token: Option<String>,
 }
 
 impl ExplorerDb {
@@ -132,19 +180,38 @@ impl ExplorerDb {
         let chain_lengths = apply_block_to_chain_lengths(ChainLengths::new(), &block)?;
         let transactions = apply_block_to_transactions(Transactions::new(), &block)?;
         let addresses = apply_block_to_addresses(Addresses::new(), &block);
+        let balances = apply_block_to_balances(
+            Balances::new(),
+            blockchain_config.discrimination,
+            &[],
+            &block,
+        )?;
+        let certificates = apply_block_to_certificates(Certificates::new(), &block);
         let (stake_pool_data, stake_pool_blocks) =
             apply_block_to_stake_pools(StakePool::new(), StakePoolBlocks::new(), &block)?;
         let stake_control = apply_block_to_stake_control(StakeControl::new(), &block);
+        let delegations = apply_block_to_delegations(Delegations::new(), &block);
         let vote_plans = apply_block_to_vote_plans(VotePlans::new(), &block, &stake_control)?;
+        let leadership_stake =
+            LeadershipStake::genesis(pools_stake(&delegations, &stake_control, &stake_pool_data));
 
         let initial_state = State {
             transactions,
             blocks,
             addresses,
+            balances,
+            certificates,
             epochs,
             chain_lengths,
             stake_pool_data,
             stake_pool_blocks,
+            stake_pool_epochs: StakePoolEpochs::new(),
+            reward_pots: RewardPots {
+                rewards: blockchain_config.rewards.initial_pot,
+                fees: Value::zero(),
+            },
+            leadership_stake,
+            delegations,
             vote_plans,
             stake_control,
         };
@@ -164,6 +231,8 @@ impl ExplorerDb {
                 confirmed_block_chain_length: Arc::new(AtomicU32::default()),
             },
             tip_broadcast: tx,
+            recent_reorgs: Arc::new(RwLock::new(VecDeque::new())),
+            watchlists: Watchlists::default(),
         };
 
         Ok(bootstraped_db)
@@ -184,17 +253,24 @@ impl ExplorerDb {
             .get_ref(&previous_block)
             .await
             .ok_or_else(|| Error::AncestorNotFound(block.id()))?;
+        let previous = previous_state.state();
         let State {
             transactions,
             blocks,
             addresses,
+            balances,
+            certificates,
             epochs,
             chain_lengths,
             stake_pool_data,
             stake_pool_blocks,
+            stake_pool_epochs: _,
+            reward_pots: _,
+            leadership_stake: _,
+            delegations,
             vote_plans,
             stake_control,
-        } = previous_state.state().clone();
+        } = previous.clone();
 
         let explorer_block = ExplorerBlock::resolve_from(
             &block,
@@ -204,10 +280,15 @@ impl ExplorerDb {
                 prev_blocks: &blocks,
             },
         )?;
+        // rewards are computed from the stake before the block is applied
+        let (stake_pool_epochs, reward_pots, leadership_stake, account_rewards) =
+            apply_block_to_rewards(&self.blockchain_config.rewards, previous, &explorer_block)?;
+
         let (stake_pool_data, stake_pool_blocks) =
             apply_block_to_stake_pools(stake_pool_data, stake_pool_blocks, &explorer_block)?;
 
         let stake_control = apply_block_to_stake_control(stake_control, &explorer_block);
+        let delegations = apply_block_to_delegations(delegations, &explorer_block);
 
         let vote_plans = apply_block_to_vote_plans(vote_plans, &explorer_block, &stake_control)?;
 
@@ -220,10 +301,21 @@ impl ExplorerDb {
                     transactions: apply_block_to_transactions(transactions, &explorer_block)?,
                     blocks: apply_block_to_blocks(blocks, &explorer_block)?,
                     addresses: apply_block_to_addresses(addresses, &explorer_block),
+                    balances: apply_block_to_balances(
+                        balances,
+                        discrimination,
+                        &account_rewards,
+                        &explorer_block,
+                    )?,
+                    certificates: apply_block_to_certificates(certificates, &explorer_block),
                     epochs: apply_block_to_epochs(epochs, &explorer_block),
                     chain_lengths: apply_block_to_chain_lengths(chain_lengths, &explorer_block)?,
                     stake_pool_data,
                     stake_pool_blocks,
+                    stake_pool_epochs,
+                    reward_pots,
+                    leadership_stake,
+                    delegations,
                     vote_plans,
                     stake_control,
                 },
@@ -256,6 +348,23 @@ impl ExplorerDb {
         let state = state_ref.state();
         let block = Arc::clone(state.blocks.lookup(&hash).unwrap());
 
+        // this needs to be done before the garbage collection below, which may
+        // drop the abandoned branch
+        let previous_tip = self.longest_chain_tip.get_block_id().await;
+        if let Some(reorg) = self.find_reorg(previous_tip, &state_ref).await {
+            tracing::info!(
+                "main branch switched from {} to {}, rolling back {} blocks",
+                reorg.abandoned_tip,
+                reorg.new_tip,
+                reorg.depth
+            );
+            let mut recent_reorgs = self.recent_reorgs.write().await;
+            if recent_reorgs.len() == RECENT_REORGS_CAPACITY {
+                recent_reorgs.pop_front();
+            }
+            recent_reorgs.push_back(reorg);
+        }
+
         if let Some(confirmed_block_chain_length) = block
             .chain_length()
             .nth_ancestor(self.blockchain_config.epoch_stability_depth)
@@ -286,6 +395,49 @@ impl ExplorerDb {
         Ok(())
     }
 
+    /// detect whether moving the tip from `previous_tip` to the given branch
+    /// rolls back blocks, walking the previous branch back to the first block
+    /// that is also in the new one
+    async fn find_reorg(
+        &self,
+        previous_tip: HeaderHash,
+        new_branch: &multiverse::Ref,
+    ) -> Option<ReorgEvent> {
+        let new_blocks = &new_branch.state().blocks;
+        if new_blocks.lookup(&previous_tip).is_some() {
+            return None;
+        }
+
+        let previous_branch = self.multiverse.get_ref(&previous_tip).await?;
+        let previous_blocks = &previous_branch.state().blocks;
+
+        let mut depth = 0;
+        let mut current = previous_tip;
+        while new_blocks.lookup(&current).is_none() {
+            current = previous_blocks.lookup(&current)?.parent_hash;
+            depth += 1;
+        }
+
+        Some(ReorgEvent {
+            abandoned_tip: previous_tip,
+            new_tip: *new_branch.id(),
+            common_ancestor: current,
+            depth,
+            timestamp: SystemTime::now(),
+        })
+    }
+
+    /// get the most recent reorg events, the latest first
+    pub async fn recent_reorgs(&self) -> Vec<ReorgEvent> {
+        self.recent_reorgs
+            .read()
+            .await
+            .iter()
+            .rev()
+            .cloned()
+            .collect()
+    }
+
     pub async fn get_block_with_branches(
         &self,
         block_id: &HeaderHash,
@@ -404,6 +556,53 @@ impl ExplorerDb {
         None
     }
 
+    pub async fn get_stake_pool_epoch(
+        &self,
+        pool: &PoolId,
+        epoch: Epoch,
+    ) -> Option<Arc<StakePoolEpochData>> {
+        let (_, state_ref) = self.get_tip().await;
+        state_ref
+            .state()
+            .stake_pool_epochs
+            .lookup(&(pool.clone(), epoch))
+            .map(Arc::clone)
+    }
+
+    /// Estimates the yearly return of delegating to the pool, from the rewards
+    /// the delegators got for the last completed epoch and the stake the pool
+    /// had in the leadership of that epoch.
+    pub async fn get_stake_pool_estimated_apr(&self, pool: &PoolId) -> Option<f64> {
+        let (tip, state_ref) = self.get_tip().await;
+        let state = state_ref.state();
+
+        let epoch = state.blocks.lookup(&tip)?.date().epoch.checked_sub(1)?;
+        let registration = &state.stake_pool_data.lookup(pool)?.registration;
+        let rewards = state
+            .stake_pool_epochs
+            .lookup(&(pool.clone(), epoch))
+            .map(|data| data.rewards)
+            .unwrap_or_else(Value::zero);
+        let to_delegators = chain_impl_mockchain::rewards::tax_cut(rewards, &registration.rewards)
+            .ok()?
+            .after_tax;
+
+        let stake = state
+            .leadership_stake
+            .rewarded
+            .as_ref()?
+            .lookup(pool)?
+            .total;
+        if stake.0 == 0 {
+            return None;
+        }
+
+        let epoch_duration = self.blockchain_config.slots_per_epoch as f64
+            * self.blockchain_config.slot_duration as f64;
+        let epochs_per_year = 365.0 * 24.0 * 3600.0 / epoch_duration;
+        Some(to_delegators.0 as f64 / stake.0 as f64 * epochs_per_year)
+    }
+
     pub async fn get_vote_plan_by_id(
         &self,
         vote_plan_id: &VotePlanId,
@@ -440,6 +639,53 @@ impl ExplorerDb {
     > {
         tokio_stream::wrappers::BroadcastStream::new(self.tip_broadcast.subscribe())
     }
+
+    /// Stream of the blocks added to the main branch after the subscription,
+    /// in chain order. When the main branch switches, the blocks of the new
+    /// branch after the last block shared with the previous one are yielded.
+    pub fn main_branch_blocks(&self) -> impl Stream<Item = Arc<ExplorerBlock>> {
+        self.tip_subscription()
+            // the blocks of the tips missed by a lagging subscriber are
+            // recovered by walking back to the previous tip
+            .filter_map(|tip| async move { tip.ok() })
+            .scan(None, |previous: &mut Option<multiverse::Ref>, (_, tip)| {
+                let blocks = match previous {
+                    Some(previous) => blocks_since(previous.state(), tip.state(), *tip.id()),
+                    None => tip
+                        .state()
+                        .blocks
+                        .lookup(tip.id())
+                        .cloned()
+                        .into_iter()
+                        .collect(),
+                };
+                *previous = Some(tip);
+                future::ready(Some(stream::iter(blocks)))
+            })
+            .flatten()
+    }
+
+    pub fn watchlists(&self) -> &Watchlists {
+        &self.watchlists
+    }
+}
+
+/// Blocks of the branch ending at `tip` which are not in the `previous` state,
+/// in chain order
+fn blocks_since(previous: &State, state: &State, tip: HeaderHash) -> Vec<Arc<ExplorerBlock>> {
+    let mut blocks = Vec::new();
+    let mut current = tip;
+    while previous.blocks.lookup(&current).is_none() {
+        match state.blocks.lookup(&current) {
+            Some(block) => {
+                current = block.parent_hash;
+                blocks.push(Arc::clone(block));
+            }
+            None => break,
+        }
+    }
+    blocks.reverse();
+    blocks
 }
 
 fn apply_block_to_transactions(
@@ -495,9 +741,91 @@ fn apply_block_to_addresses(mut addresses: Addresses, block: &ExplorerBlock) ->
     addresses
 }
 
+/// Balances are the sum of the outputs to an address and of the rewards
+/// credited to its account, minus the inputs from it. The rewards of an epoch
+/// are credited before the first block of the next one, as in the ledger.
+///
+/// An input spending more than the balance of its address means the indexing
+/// diverged from the ledger, so the block is rejected.
+fn apply_block_to_balances(
+    mut balances: Balances,
+    discrimination: Discrimination,
+    account_rewards: &[(Identifier, Value)],
+    block: &ExplorerBlock,
+) -> Result<Balances, Error> {
+    let credit = |balances: Balances, address: ExplorerAddress, value: Value| {
+        balances.insert_or_update_simple(address, Arc::new(value), |balance| {
+            Some(Arc::new(balance.saturating_add(value)))
+        })
+    };
+
+    for (account, reward) in account_rewards {
+        let address = ExplorerAddress::New(chain_addr::Address(
+            discrimination,
+            chain_addr::Kind::Account(account.clone().into()),
+        ));
+        balances = credit(balances, address, *reward);
+    }
+
+    // transactions are stored in a HashMap, so the outputs of the block are
+    // credited first, for the inputs spending them in the same block
+    for tx in block.transactions.values() {
+        for output in tx.outputs() {
+            balances = credit(balances, output.address.clone(), output.value);
+        }
+    }
+    for tx in block.transactions.values() {
+        for input in tx.inputs() {
+            let balance = balances
+                .lookup(&input.address)
+                .map_or_else(Value::zero, |balance| **balance);
+            let balance = (balance - input.value).map_err(|_| Error::BalanceUnderflow(tx.id()))?;
+            balances =
+                balances.insert_or_update_simple(input.address.clone(), Arc::new(balance), |_| {
+                    Some(Arc::new(balance))
+                });
+        }
+    }
+    Ok(balances)
+}
+
+fn apply_block_to_certificates(
+    mut certificates: Certificates,
+    block: &ExplorerBlock,
+) -> Certificates {
+    // transactions are stored in a HashMap, sort them so the index follows
+    // the order of the block
+    let mut transactions: Vec<_> = block
+        .transactions
+        .values()
+        .filter_map(|tx| {
+            tx.certificate
+                .as_ref()
+                .map(|cert| (tx.offset_in_block, tx.id(), cert))
+        })
+        .collect();
+    transactions.sort_unstable_by_key(|(offset, _, _)| *offset);
+
+    for (_, id, certificate) in transactions {
+        certificates = certificates.insert_or_update_simple(
+            CertificateKind::from(certificate),
+            Arc::new(PersistentSequence::new().append(id)),
+            |set| Some(Arc::new(set.append(id))),
+        )
+    }
+    certificates
+}
+
+/// Epoch aggregates are kept up to date incrementally, so that they can be read without
+/// walking the blocks of the epoch. As with the rest of the state, each branch has its own
+/// copy, so a reorg does not need to revert them.
 fn apply_block_to_epochs(epochs: Epochs, block: &ExplorerBlock) -> Epochs {
     let epoch_id = block.date().epoch;
     let block_id = block.id();
+    let block_fees = block
+        .transactions
+        .values()
+        .fold(Value::zero(), |total, tx| total.saturating_add(tx.fee));
 
     epochs.insert_or_update_simple(
         epoch_id,
@@ -505,12 +833,16 @@ fn apply_block_to_epochs(epochs: Epochs, block: &ExplorerBlock) -> Epochs {
             first_block: block_id,
             last_block: block_id,
             total_blocks: 0,
+            total_fees: block_fees,
+            total_output: block.total_output,
         }),
         |data| {
             Some(Arc::new(EpochData {
                 first_block: data.first_block,
                 last_block: block_id,
                 total_blocks: data.total_blocks + 1,
+                total_fees: data.total_fees.saturating_add(block_fees),
+                total_output: data.total_output.saturating_add(block.total_output),
             }))
         },
     )
@@ -602,6 +934,124 @@ fn apply_block_to_stake_pools(
     Ok((data, blocks))
 }
 
+/// Distributes the rewards of the previous epoch when the block starts a new
+/// one, then accounts the block production and fees for the current epoch.
+///
+/// Also returns the rewards credited to each account by the distribution.
+fn apply_block_to_rewards(
+    settings: &RewardSettings,
+    previous: &State,
+    block: &ExplorerBlock,
+) -> Result<(StakePoolEpochs, RewardPots, LeadershipStake, AccountRewards), Error> {
+    let mut pool_epochs = previous.stake_pool_epochs.clone();
+    let mut pots = previous.reward_pots;
+    let mut leadership_stake = previous.leadership_stake.clone();
+    let mut account_rewards = Vec::new();
+
+    let previous_epoch = previous
+        .blocks
+        .lookup(&block.parent_hash)
+        .map(|parent| parent.date().epoch)
+        .filter(|epoch| *epoch < block.date().epoch);
+
+    if let Some(epoch) = previous_epoch {
+        let pool_blocks: Vec<_> = previous
+            .stake_pool_data
+            .iter()
+            .filter_map(|(id, _)| {
+                pool_epochs
+                    .lookup(&(id.clone(), epoch))
+                    .map(|data| (id.clone(), data.blocks_produced))
+            })
+            .collect();
+        // the rewards follow the stake distribution the leaders of the epoch
+        // were elected with, not the stake delegated when the epoch ends
+        let declared_stake = total_stake(&leadership_stake.current);
+        let rewarded_stake = leadership_stake.current.clone();
+        leadership_stake = leadership_stake.next_epoch(pools_stake(
+            &previous.delegations,
+            &previous.stake_control,
+            &previous.stake_pool_data,
+        ));
+
+        for (pool_id, reward) in
+            compute_epoch_rewards(settings, &mut pots, epoch, declared_stake, &pool_blocks)?
+        {
+            if let Some(data) = previous.stake_pool_data.lookup(&pool_id) {
+                let stake = rewarded_stake
+                    .lookup(&pool_id)
+                    .map_or_else(PoolStake::default, |stake| (**stake).clone());
+                account_rewards.extend(distribute_pool_reward(&data.registration, &stake, reward)?);
+            }
+            pool_epochs = pool_epochs.insert_or_update_simple(
+                (pool_id, epoch),
+                Arc::new(StakePoolEpochData {
+                    blocks_produced: 0,
+                    rewards: reward,
+                }),
+                |data| {
+                    Some(Arc::new(StakePoolEpochData {
+                        blocks_produced: data.blocks_produced,
+                        rewards: reward,
+                    }))
+                },
+            );
+        }
+    }
+
+    let block_fees = block
+        .transactions
+        .values()
+        .fold(Value::zero(), |total, tx| total.saturating_add(tx.fee));
+    pots.fees = pots.fees.saturating_add(block_fees);
+
+    if let indexing::BlockProducer::StakePool(id) = block.producer() {
+        pool_epochs = pool_epochs.insert_or_update_simple(
+            (id.clone(), block.date().epoch),
+            Arc::new(StakePoolEpochData {
+                blocks_produced: 1,
+                rewards: Value::zero(),
+            }),
+            |data| {
+                Some(Arc::new(StakePoolEpochData {
+                    blocks_produced: data.blocks_produced + 1,
+                    rewards: data.rewards,
+                }))
+            },
+        );
+    }
+
+    Ok((pool_epochs, pots, leadership_stake, account_rewards))
+}
+
+fn apply_block_to_delegations(mut delegations: Delegations, block: &ExplorerBlock) -> Delegations {
+    for tx in block.transactions.values() {
+        let (account, delegation) = match &tx.certificate {
+            Some(Certificate::StakeDelegation(cert)) => (
+                cert.account_id.to_single_account(),
+                cert.get_delegation_type(),
+            ),
+            // the owner delegation applies to the account spending the first input
+            Some(Certificate::OwnerStakeDelegation(cert)) => (
+                tx.inputs
+                    .first()
+                    .and_then(|input| input.address.to_identifier()),
+                cert.get_delegation_type(),
+            ),
+            _ => continue,
+        };
+
+        if let Some(account) = account {
+            delegations =
+                delegations.insert_or_update_simple(account, Arc::new(delegation.clone()), |_| {
+                    Some(Arc::new(delegation.clone()))
+                });
+        }
+    }
+
+    delegations
+}
+
 #[tracing::instrument]
 fn apply_block_to_vote_plans(
     mut vote_plans: VotePlans,
@@ -801,6 +1251,8 @@ impl BlockchainConfig {
         let mut consensus_version: Option<ConsensusVersion> = None;
         let mut fees: Option<LinearFee> = None;
         let mut epoch_stability_depth: Option<u32> = None;
+        let mut slots_per_epoch: Option<u32> = None;
+        let mut slot_duration: Option<u8> = None;
 
         for p in params.iter() {
             match p {
@@ -816,6 +1268,12 @@ impl BlockchainConfig {
                 ConfigParam::EpochStabilityDepth(d) => {
                     epoch_stability_depth.replace(*d);
                 }
+                ConfigParam::SlotsPerEpoch(n) => {
+                    slots_per_epoch.replace(*n);
+                }
+                ConfigParam::SlotDuration(d) => {
+                    slot_duration.replace(*d);
+                }
                 _ => (),
             }
         }
@@ -827,6 +1285,9 @@ impl BlockchainConfig {
             fees: fees.expect("fees not found in initial params"),
             epoch_stability_depth: epoch_stability_depth
                 .expect("epoch stability depth not found in initial params"),
+            slots_per_epoch: slots_per_epoch.expect("slots per epoch not found in initial params"),
+            slot_duration: slot_duration.expect("slot duration not found in initial params"),
+            rewards: RewardSettings::from_config_params(params),
         }
     }
 }
@@ -865,6 +1326,38 @@ impl State {
             .map(|txs| PersistentSequence::clone(txs))
     }
 
+    /// Balance of the address resulting from the indexed transactions
+    pub fn balance(&self, address: &ExplorerAddress) -> Value {
+        self.balances
+            .lookup(address)
+            .map_or_else(Value::zero, |balance| **balance)
+    }
+
+    pub fn transactions_by_certificate(
+        &self,
+        kind: CertificateKind,
+    ) -> Option<PersistentSequence<FragmentId>> {
+        self.certificates
+            .lookup(&kind)
+            .map(|txs| PersistentSequence::clone(txs))
+    }
+
+    /// Block of the branch at the given chain length
+    pub fn get_block_at(&self, chain_length: ChainLength) -> Option<Arc<ExplorerBlock>> {
+        self.chain_lengths
+            .lookup(&chain_length)
+            .and_then(|hash| self.blocks.lookup(hash))
+            .cloned()
+    }
+
+    pub fn get_epoch(&self, epoch: Epoch) -> Option<Arc<EpochData>> {
+        self.epochs.lookup(&epoch).cloned()
+    }
+
+    pub fn get_vote_plan(&self, vote_plan_id: &VotePlanId) -> Option<Arc<ExplorerVotePlan>> {
+        self.vote_plans.lookup(vote_plan_id).cloned()
+    }
+
     // Get the hashes of all blocks in the range [from, to)
     // the ChainLength is returned to for easy of use in the case where
     // `to` is greater than the max
@@ -885,3 +1378,198 @@ impl State {
             .collect()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chain_impl_mockchain::{
+        block::{self, BlockDate, BlockVersion, ContentsBuilder},
+        header::Header,
+        testing::{ledger::ConfigBuilder, TestGen},
+    };
+    use futures::executor::block_on;
+    use indexing::{BlockProducer, ExplorerInput, ExplorerOutput, ExplorerTransaction};
+
+    fn block0() -> Block {
+        let mut params = ConfigBuilder::new()
+            .with_fee(LinearFee::new(0, 0, 0))
+            .build();
+        params.push(ConfigParam::EpochStabilityDepth(10));
+        let mut contents = ContentsBuilder::new();
+        contents.push(Fragment::Initial(params));
+        block::builder(BlockVersion::Genesis, contents.into(), |hdr| {
+            Ok::<_, ()>(
+                hdr.set_genesis()
+                    .set_date(BlockDate::first())
+                    .into_unsigned_header()
+                    .unwrap()
+                    .generalize(),
+            )
+        })
+        .unwrap()
+    }
+
+    /// empty blocks following `parent`, one per given slot of the first epoch
+    fn branch(parent: &Header, slots: &[u32]) -> Vec<Block> {
+        let mut parent = parent.clone();
+        slots
+            .iter()
+            .map(|slot_id| {
+                let block = block::builder(
+                    BlockVersion::Genesis,
+                    ContentsBuilder::new().into(),
+                    |hdr| {
+                        Ok::<_, ()>(
+                            hdr.set_parent(&parent.hash(), parent.chain_length().increase())
+                                .set_date(BlockDate {
+                                    epoch: 0,
+                                    slot_id: *slot_id,
+                                })
+                                .into_unsigned_header()
+                                .unwrap()
+                                .generalize(),
+                        )
+                    },
+                )
+                .unwrap();
+                parent = block.header().clone();
+                block
+            })
+            .collect()
+    }
+
+    fn account_address(account: &Identifier) -> ExplorerAddress {
+        ExplorerAddress::New(chain_addr::Address(
+            Discrimination::Test,
+            chain_addr::Kind::Account(account.clone().into()),
+        ))
+    }
+
+    fn transaction(
+        inputs: &[(&ExplorerAddress, u64)],
+        outputs: &[(&ExplorerAddress, u64)],
+    ) -> ExplorerTransaction {
+        ExplorerTransaction {
+            id: TestGen::hash(),
+            inputs: inputs
+                .iter()
+                .map(|(address, value)| ExplorerInput {
+                    address: (*address).clone(),
+                    value: Value(*value),
+                })
+                .collect(),
+            outputs: outputs
+                .iter()
+                .map(|(address, value)| ExplorerOutput {
+                    address: (*address).clone(),
+                    value: Value(*value),
+                })
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    fn explorer_block(transactions: Vec<ExplorerTransaction>) -> ExplorerBlock {
+        ExplorerBlock {
+            transactions: transactions.into_iter().map(|tx| (tx.id, tx)).collect(),
+            id: TestGen::hash(),
+            date: BlockDate::first(),
+            chain_length: ChainLength::from(1),
+            parent_hash: TestGen::hash(),
+            producer: BlockProducer::None,
+            total_input: Value::zero(),
+            total_output: Value::zero(),
+        }
+    }
+
+    fn balance(balances: &Balances, address: &ExplorerAddress) -> Option<Value> {
+        balances.lookup(address).map(|balance| **balance)
+    }
+
+    #[test]
+    fn balances_account_for_outputs_rewards_and_inputs() {
+        let account = TestGen::identifier();
+        let (rewarded, other) = (
+            account_address(&account),
+            account_address(&TestGen::identifier()),
+        );
+
+        let funding = explorer_block(vec![transaction(&[], &[(&rewarded, 100)])]);
+        let balances =
+            apply_block_to_balances(Balances::new(), Discrimination::Test, &[], &funding).unwrap();
+        assert_eq!(balance(&balances, &rewarded), Some(Value(100)));
+
+        // the rewards are spendable in the block they are credited with, and
+        // the outputs in the block they are created in
+        let spending = explorer_block(vec![
+            transaction(&[(&rewarded, 110)], &[(&other, 105)]),
+            transaction(&[(&other, 105)], &[(&rewarded, 100)]),
+        ]);
+        let balances = apply_block_to_balances(
+            balances,
+            Discrimination::Test,
+            &[(account, Value(10))],
+            &spending,
+        )
+        .unwrap();
+        assert_eq!(balance(&balances, &rewarded), Some(Value(100)));
+        assert_eq!(balance(&balances, &other), Some(Value::zero()));
+    }
+
+    #[test]
+    fn spending_more_than_the_balance_is_rejected() {
+        let address = account_address(&TestGen::identifier());
+        let funding = explorer_block(vec![transaction(&[], &[(&address, 10)])]);
+        let balances =
+            apply_block_to_balances(Balances::new(), Discrimination::Test, &[], &funding).unwrap();
+
+        let overspending = transaction(&[(&address, 11)], &[]);
+        let id = overspending.id;
+        let result = apply_block_to_balances(
+            balances,
+            Discrimination::Test,
+            &[],
+            &explorer_block(vec![overspending]),
+        );
+        assert!(matches!(result, Err(Error::BalanceUnderflow(tx)) if tx == id));
+    }
+
+    #[test]
+    fn switching_to_competing_branch_records_reorg() {
+        let block0 = block0();
+        let db = ExplorerDb::bootstrap(block0.clone()).unwrap();
+
+        let main = branch(block0.header(), &[1, 2, 3]);
+        // forks after the first block of the main branch
+        let fork = branch(main[0].header(), &[4, 5, 6]);
+
+        block_on(async {
+            for block in main.iter().chain(fork.iter()) {
+                db.apply_block(block.clone()).await.unwrap();
+            }
+
+            // moving the tip forward along a branch is not a reorg
+            for block in &main {
+                db.set_tip(block.id()).await.unwrap();
+            }
+            assert!(db.recent_reorgs().await.is_empty());
+
+            db.set_tip(fork[2].id()).await.unwrap();
+            let reorgs = db.recent_reorgs().await;
+            assert_eq!(reorgs.len(), 1);
+            assert_eq!(reorgs[0].abandoned_tip, main[2].id());
+            assert_eq!(reorgs[0].new_tip, fork[2].id());
+            assert_eq!(reorgs[0].common_ancestor, main[0].id());
+            assert_eq!(reorgs[0].depth, 2);
+
+            // switching back rolls back the whole fork
+            db.set_tip(main[2].id()).await.unwrap();
+            let reorgs = db.recent_reorgs().await;
+            assert_eq!(reorgs.len(), 2);
+            assert_eq!(reorgs[0].abandoned_tip, fork[2].id());
+            assert_eq!(reorgs[0].new_tip, main[2].id());
+            assert_eq!(reorgs[0].common_ancestor, main[0].id());
+            assert_eq!(reorgs[0].depth, 3);
+        });
+    }
+}
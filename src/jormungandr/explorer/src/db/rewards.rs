@@ -0,0 +1,614 @@
+use super::{
+    error::ExplorerError as Error,
+    indexing::{Delegations, Hamt, StakePool},
+};
+use chain_impl_mockchain::{
+    account::{DelegationType, Identifier},
+    block::Epoch,
+    certificate::{PoolId, PoolRegistration},
+    config::{ConfigParam, RewardParams},
+    fragment::ConfigParams,
+    rewards::{self, CompoundingType, Limit, Parameters, Ratio, TaxType},
+    stake::{PercentStake, Stake, StakeControl},
+    transaction::AccountIdentifier,
+    value::Value,
+};
+use std::{num::NonZeroU32, sync::Arc};
+
+/// The stake delegated to each registered stake pool
+pub type PoolsStake = Hamt<PoolId, PoolStake>;
+
+/// The rewards credited to accounts by a distribution
+pub type AccountRewards = Vec<(Identifier, Value)>;
+
+/// The stake delegated to a stake pool, and by which accounts
+#[derive(Clone)]
+pub struct PoolStake {
+    pub total: Stake,
+    pub accounts: Hamt<Identifier, Stake>,
+}
+
+impl Default for PoolStake {
+    fn default() -> Self {
+        PoolStake {
+            total: Stake::zero(),
+            accounts: Hamt::new(),
+        }
+    }
+}
+
+impl PoolStake {
+    #[must_use = "operation does not change the stake in place"]
+    pub fn add(&self, account: &Identifier, stake: Stake) -> Self {
+        PoolStake {
+            total: self.total + stake,
+            accounts: self.accounts.insert_or_update_simple(
+                account.clone(),
+                Arc::new(stake),
+                |account_stake| Some(Arc::new(**account_stake + stake)),
+            ),
+        }
+    }
+}
+
+/// The reward settings of the blockchain, as set in the block0.
+///
+/// Changes made later through update proposals are not tracked, so the
+/// rewards indexed by the explorer may diverge from the ledger's if those
+/// settings are updated.
+#[derive(Clone, Debug)]
+pub struct RewardSettings {
+    pub initial_pot: Value,
+    pub params: Parameters,
+    pub treasury_tax: TaxType,
+    pub fees_in_treasury: bool,
+}
+
+/// The value waiting in the ledger to be distributed as rewards
+#[derive(Clone, Copy, Debug)]
+pub struct RewardPots {
+    pub rewards: Value,
+    pub fees: Value,
+}
+
+impl RewardSettings {
+    pub fn from_config_params(params: &ConfigParams) -> RewardSettings {
+        let mut initial_pot = Value::zero();
+        let mut reward_params: Option<RewardParams> = None;
+        let mut treasury_tax = TaxType::zero();
+        let mut fees_in_treasury = false;
+        let mut limit = Limit::None;
+        let mut capping: Option<(NonZeroU32, NonZeroU32)> = None;
+
+        for p in params.iter() {
+            match p {
+                ConfigParam::RewardPot(value) => initial_pot = *value,
+                ConfigParam::RewardParams(params) => {
+                    reward_params.replace(params.clone());
+                }
+                ConfigParam::TreasuryParams(tax) => treasury_tax = *tax,
+                ConfigParam::FeesInTreasury(value) => fees_in_treasury = *value,
+                ConfigParam::RewardLimitNone => limit = Limit::None,
+                ConfigParam::RewardLimitByAbsoluteStake(ratio) => {
+                    limit = Limit::ByStakeAbsolute(*ratio)
+                }
+                ConfigParam::PoolRewardParticipationCapping(c) => {
+                    capping.replace(*c);
+                }
+                _ => (),
+            }
+        }
+
+        // same as the ledger settings: the limits only apply along with reward parameters
+        let params = match reward_params {
+            None => Parameters::zero(),
+            Some(RewardParams::Linear {
+                constant,
+                ratio,
+                epoch_start,
+                epoch_rate,
+            }) => Parameters {
+                initial_value: constant,
+                compounding_ratio: ratio,
+                compounding_type: CompoundingType::Linear,
+                epoch_start,
+                epoch_rate,
+                reward_drawing_limit_max: limit,
+                pool_participation_capping: capping,
+            },
+            Some(RewardParams::Halving {
+                constant,
+                ratio,
+                epoch_start,
+                epoch_rate,
+            }) => Parameters {
+                initial_value: constant,
+                compounding_ratio: ratio,
+                compounding_type: CompoundingType::Halvening,
+                epoch_start,
+                epoch_rate,
+                reward_drawing_limit_max: limit,
+                pool_participation_capping: capping,
+            },
+            Some(RewardParams::Fixed {
+                constant,
+                epoch_start,
+            }) => Parameters {
+                initial_value: constant,
+                compounding_ratio: Ratio::zero(),
+                compounding_type: CompoundingType::Fixed,
+                epoch_start,
+                epoch_rate: NonZeroU32::new(u32::MAX).unwrap(),
+                reward_drawing_limit_max: limit,
+                pool_participation_capping: capping,
+            },
+        };
+
+        RewardSettings {
+            initial_pot,
+            params,
+            treasury_tax,
+            fees_in_treasury,
+        }
+    }
+}
+
+/// Distributes the rewards for the blocks produced during `epoch` between
+/// the stake pools that produced them, the same way the ledger does when
+/// the next epoch starts. The pots are updated with the drawn value.
+///
+/// Returns the total reward of each pool, before the pool's own tax.
+pub fn compute_epoch_rewards(
+    settings: &RewardSettings,
+    pots: &mut RewardPots,
+    epoch: Epoch,
+    declared_stake: Stake,
+    pool_blocks: &[(PoolId, u32)],
+) -> Result<Vec<(PoolId, Value)>, Error> {
+    let total_blocks: u32 = pool_blocks.iter().map(|(_, blocks)| blocks).sum();
+    if total_blocks == 0 {
+        // the ledger does not touch the pots when no pool produced blocks
+        return Ok(Vec::new());
+    }
+
+    let expected_reward = rewards::rewards_contribution_calculation(
+        epoch + 1,
+        &settings.params,
+        &rewards::SystemInformation { declared_stake },
+    );
+    let drawn = std::cmp::min(pots.rewards, expected_reward);
+    pots.rewards = (pots.rewards - drawn).map_err(|_| Error::CannotApplyBlock)?;
+
+    let fees = std::mem::replace(&mut pots.fees, Value::zero());
+    let total_reward = if settings.fees_in_treasury {
+        drawn
+    } else {
+        drawn.saturating_add(fees)
+    };
+    let total_reward = rewards::tax_cut(total_reward, &settings.treasury_tax)
+        .map_err(|_| Error::CannotApplyBlock)?
+        .after_tax;
+
+    let pool_cap = settings
+        .params
+        .pool_participation_capping
+        .filter(|(threshold, _)| pool_blocks.len() >= threshold.get() as usize)
+        .map(|(_, expected_nb_pools)| Value(total_reward.0 / expected_nb_pools.get() as u64));
+
+    let reward_unit = total_reward.split_in(total_blocks);
+    pool_blocks
+        .iter()
+        .map(|(pool_id, blocks)| {
+            let reward = reward_unit
+                .parts
+                .scale(*blocks)
+                .map_err(|_| Error::CannotApplyBlock)?;
+            let reward = match pool_cap {
+                Some(cap) => std::cmp::min(cap, reward),
+                None => reward,
+            };
+            Ok((pool_id.clone(), reward))
+        })
+        .collect()
+}
+
+/// Splits the reward of a stake pool between the accounts the same way the
+/// ledger does: the pool's tax goes to its reward account, or is split
+/// between its owners, and the rest to its delegators along their stake.
+/// What the ledger puts back in the treasury is left out.
+pub fn distribute_pool_reward(
+    registration: &PoolRegistration,
+    stake: &PoolStake,
+    reward: Value,
+) -> Result<AccountRewards, Error> {
+    let distribution =
+        rewards::tax_cut(reward, &registration.rewards).map_err(|_| Error::CannotApplyBlock)?;
+
+    let mut shares = Vec::new();
+    match &registration.reward_account {
+        Some(AccountIdentifier::Single(account)) => {
+            shares.push((account.clone(), distribution.taxed));
+        }
+        // not supported by the ledger either
+        Some(AccountIdentifier::Multi(_)) => return Err(Error::CannotApplyBlock),
+        None => {
+            if let Some((first, _)) = registration.owners.split_first() {
+                let split = distribution
+                    .taxed
+                    .split_in(registration.owners.len() as u32);
+                for owner in &registration.owners {
+                    shares.push((owner.clone().into(), split.parts));
+                }
+                // the first owner gets the remainder of the split
+                if split.remaining > Value::zero() {
+                    shares.push((first.clone().into(), split.remaining));
+                }
+            }
+        }
+    }
+
+    if distribution.after_tax > Value::zero() && stake.total > Stake::zero() {
+        for (account, account_stake) in stake.accounts.iter() {
+            let share =
+                PercentStake::new(**account_stake, stake.total).scale_value(distribution.after_tax);
+            shares.push((account.clone(), share));
+        }
+    }
+
+    Ok(shares)
+}
+
+/// The stake distributions the ledger elects the leaders of an epoch with,
+/// and distributes the rewards of the epoch along.
+///
+/// The leadership of an epoch uses the stake at the start of the previous
+/// epoch, except for epoch 0, which uses the block0 stake, and epoch 1,
+/// which uses the stake at the end of epoch 0.
+#[derive(Clone)]
+pub struct LeadershipStake {
+    /// The distribution of the epoch whose rewards were last distributed
+    pub rewarded: Option<PoolsStake>,
+    /// The distribution of the current epoch
+    pub current: PoolsStake,
+    /// The distribution at the start of the current epoch, used by the next one
+    epoch_start: Option<PoolsStake>,
+}
+
+impl LeadershipStake {
+    pub fn genesis(block0_stake: PoolsStake) -> Self {
+        LeadershipStake {
+            rewarded: None,
+            current: block0_stake,
+            epoch_start: None,
+        }
+    }
+
+    /// Moves to the next epoch, given the stake at the end of the current one.
+    pub fn next_epoch(self, stake: PoolsStake) -> Self {
+        LeadershipStake {
+            rewarded: Some(self.current),
+            current: self.epoch_start.unwrap_or_else(|| stake.clone()),
+            epoch_start: Some(stake),
+        }
+    }
+}
+
+/// Sums the stake of a distribution, as declared to the reward computation.
+pub fn total_stake(distribution: &PoolsStake) -> Stake {
+    Stake::sum(distribution.iter().map(|(_, stake)| stake.total))
+}
+
+/// Computes the stake delegated to each stake pool the same way the ledger's
+/// stake distribution does: the stake delegated to unknown or retired pools
+/// is left out, and the remainder of a ratio split goes to the first
+/// registered pool of the ratio.
+pub fn pools_stake(
+    delegations: &Delegations,
+    stake: &StakeControl,
+    pools: &StakePool,
+) -> PoolsStake {
+    let registered = |id: &PoolId| {
+        pools
+            .lookup(id)
+            .map_or(false, |data| data.retirement.is_none())
+    };
+    let add = |distribution: PoolsStake, id: &PoolId, account: &Identifier, stake: Stake| {
+        distribution.insert_or_update_simple(
+            id.clone(),
+            Arc::new(PoolStake::default().add(account, stake)),
+            |pool_stake| Some(Arc::new(pool_stake.add(account, stake))),
+        )
+    };
+
+    let mut distribution = PoolsStake::new();
+    for (account, delegation) in delegations.iter() {
+        let account_stake = match stake.by(account) {
+            Some(account_stake) => account_stake,
+            None => continue,
+        };
+        match delegation.as_ref() {
+            DelegationType::NonDelegated => (),
+            DelegationType::Full(id) => {
+                if registered(id) {
+                    distribution = add(distribution, id, account, account_stake);
+                }
+            }
+            DelegationType::Ratio(ratio) => {
+                if !ratio.is_valid() {
+                    continue;
+                }
+                let split = account_stake.split_in(ratio.parts() as u32);
+                let mut remaining = split.remaining;
+                for (id, parts) in ratio.pools().iter().filter(|(id, _)| registered(id)) {
+                    let pool_stake = split.parts.scale(*parts as u32)
+                        + std::mem::replace(&mut remaining, Stake::zero());
+                    distribution = add(distribution, id, account, pool_stake);
+                }
+            }
+        }
+    }
+    distribution
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::indexing::StakePoolData;
+    use chain_impl_mockchain::{
+        account::{self, DelegationRatio},
+        certificate::PoolRetirement,
+        config::RewardParams,
+        stake::{self, PoolsState},
+        testing::{
+            builders::StakePoolBuilder,
+            ledger::ConfigBuilder,
+            scenario::{prepare_scenario, wallet},
+            TestGen,
+        },
+        utxo,
+    };
+    use chain_time::DurationSeconds;
+    use std::{collections::BTreeMap, num::NonZeroU64};
+
+    fn reward_config() -> ConfigBuilder {
+        ConfigBuilder::new()
+            .with_rewards(Value(1_000_000))
+            .with_treasury_params(TaxType {
+                fixed: Value(10),
+                ratio: Ratio {
+                    numerator: 1,
+                    denominator: NonZeroU64::new(10).unwrap(),
+                },
+                max_limit: None,
+            })
+            .with_rewards_params(RewardParams::Linear {
+                constant: 10_000,
+                ratio: Ratio {
+                    numerator: 1,
+                    denominator: NonZeroU64::new(100).unwrap(),
+                },
+                epoch_start: 0,
+                epoch_rate: NonZeroU32::new(1).unwrap(),
+            })
+    }
+
+    /// Produces blocks with two pools for an epoch, then checks the rewards
+    /// computed for them, and their split between the accounts, against the
+    /// ledger's report of the distribution.
+    fn check_rewards_against_ledger(config: ConfigBuilder) {
+        let (mut ledger, controller) = prepare_scenario()
+            .with_config(config)
+            .with_initials(vec![
+                wallet("Alice").with(1_000).owns_and_delegates_to("pool_a"),
+                wallet("Bob").with(3_000).owns_and_delegates_to("pool_b"),
+            ])
+            .build()
+            .unwrap();
+        let pool_a = controller.stake_pool("pool_a").unwrap();
+        let pool_b = controller.stake_pool("pool_b").unwrap();
+        for pool in [&pool_a, &pool_a, &pool_b] {
+            ledger.apply_empty_praos_block(pool).unwrap();
+        }
+
+        let settings = RewardSettings::from_config_params(&ledger.cfg);
+        let mut pots = RewardPots {
+            rewards: settings.initial_pot,
+            fees: Value::zero(),
+        };
+        let pool_blocks: Vec<_> = ledger
+            .leaders_log()
+            .iter()
+            .map(|(pool_id, blocks)| (pool_id.clone(), *blocks))
+            .collect();
+        let distribution = ledger.ledger.get_stake_distribution();
+        let declared_stake = distribution.get_total_stake();
+        let rewards = compute_epoch_rewards(
+            &settings,
+            &mut pots,
+            ledger.date().epoch,
+            declared_stake,
+            &pool_blocks,
+        )
+        .unwrap();
+
+        ledger.distribute_rewards().unwrap();
+        let report = &ledger.ledger.rewards_report().unwrap().info;
+
+        assert_eq!(rewards.len(), report.stake_pools.len());
+        let mut accounts = BTreeMap::new();
+        for (pool_id, reward) in rewards {
+            let (taxed, after_tax) = report.stake_pools[&pool_id];
+            assert_eq!(reward, taxed.saturating_add(after_tax));
+
+            let info = &distribution.to_pools[&pool_id];
+            let stake = info
+                .stake
+                .iter()
+                .fold(PoolStake::default(), |stake, (account, account_stake)| {
+                    stake.add(account, *account_stake)
+                });
+            let shares =
+                distribute_pool_reward(info.registration.as_ref().unwrap(), &stake, reward)
+                    .unwrap();
+            for (account, share) in shares {
+                let total = accounts.entry(account).or_insert_with(Value::zero);
+                *total = total.saturating_add(share);
+            }
+        }
+        assert_eq!(accounts, report.accounts);
+        assert_eq!(pots.rewards, (settings.initial_pot - report.drawn).unwrap());
+    }
+
+    #[test]
+    fn rewards_match_the_ledger() {
+        check_rewards_against_ledger(reward_config());
+    }
+
+    #[test]
+    fn capped_rewards_match_the_ledger() {
+        check_rewards_against_ledger(reward_config().with_pool_reward_participation_caping(2, 4));
+    }
+
+    #[test]
+    fn pool_reward_is_split_between_owners_and_delegators() {
+        let owners = vec![TestGen::public_key(), TestGen::public_key()];
+        let pool = StakePoolBuilder::new()
+            .with_owners(owners.clone())
+            .with_tax_type(TaxType {
+                fixed: Value(11),
+                ratio: Ratio::zero(),
+                max_limit: None,
+            })
+            .build();
+        let (small, large) = (TestGen::identifier(), TestGen::identifier());
+        let stake = PoolStake::default()
+            .add(&small, Stake(1))
+            .add(&large, Stake(2));
+
+        let mut shares = BTreeMap::new();
+        for (account, share) in distribute_pool_reward(&pool.info(), &stake, Value(111)).unwrap() {
+            let total = shares.entry(account).or_insert_with(Value::zero);
+            *total = total.saturating_add(share);
+        }
+
+        // the first owner gets the remainder of the tax split
+        assert_eq!(shares[&Identifier::from(owners[0].clone())], Value(6));
+        assert_eq!(shares[&Identifier::from(owners[1].clone())], Value(5));
+        assert_eq!(shares[&small], Value(33));
+        assert_eq!(shares[&large], Value(66));
+    }
+
+    #[test]
+    fn pools_stake_matches_the_ledger_distribution() {
+        let registered = StakePoolBuilder::new().build();
+        let other = StakePoolBuilder::new().build();
+        let retired = StakePoolBuilder::new().build();
+        let unknown = StakePoolBuilder::new().build();
+
+        let ratio = DelegationRatio::new(
+            3,
+            vec![(unknown.id(), 1), (registered.id(), 1), (other.id(), 1)],
+        )
+        .unwrap();
+        let account_delegations = vec![
+            (Value(100), DelegationType::Full(registered.id())),
+            (Value(1_000), DelegationType::Full(retired.id())),
+            (Value(500), DelegationType::Full(unknown.id())),
+            (Value(101), DelegationType::Ratio(ratio)),
+            (Value(200), DelegationType::NonDelegated),
+        ];
+
+        // the ledger forgets retired pools, the explorer marks them
+        let pools_state = PoolsState::new()
+            .register_stake_pool(registered.info())
+            .unwrap()
+            .register_stake_pool(other.info())
+            .unwrap();
+        let mut pools = StakePool::new();
+        for (pool, retirement) in [
+            (&registered, None),
+            (&other, None),
+            (
+                &retired,
+                Some(PoolRetirement {
+                    pool_id: retired.id(),
+                    retirement_time: DurationSeconds(0).into(),
+                }),
+            ),
+        ] {
+            let data = StakePoolData {
+                registration: pool.info(),
+                retirement,
+            };
+            pools = pools.insert(pool.id(), Arc::new(data)).unwrap();
+        }
+
+        let mut accounts = account::Ledger::new();
+        let mut delegations = Delegations::new();
+        let mut stake_control = StakeControl::new();
+        for (value, delegation) in account_delegations {
+            let id = TestGen::identifier();
+            accounts = accounts
+                .add_account(id.clone(), value, ())
+                .unwrap()
+                .set_delegation(&id, &delegation)
+                .unwrap();
+            delegations = delegations
+                .insert(id.clone(), Arc::new(delegation))
+                .unwrap();
+            stake_control = stake_control.add_to(id, Stake::from_value(value));
+        }
+
+        let expected = stake::get_distribution(&accounts, &pools_state, &utxo::Ledger::new());
+        let distribution = pools_stake(&delegations, &stake_control, &pools);
+
+        assert_eq!(total_stake(&distribution), expected.get_total_stake());
+        for (pool_id, info) in expected.to_pools.iter() {
+            let pool_stake = distribution.lookup(pool_id).unwrap();
+            assert_eq!(pool_stake.total, info.stake.total);
+            assert_eq!(pool_stake.accounts.size(), info.stake.accounts.len());
+            for (account, stake) in info.stake.iter() {
+                assert_eq!(
+                    pool_stake.accounts.lookup(account).map(|s| **s),
+                    Some(*stake)
+                );
+            }
+        }
+        // the remainder of the ratio split goes to the first registered pool
+        assert_eq!(
+            distribution.lookup(&registered.id()).unwrap().total,
+            Stake(135)
+        );
+        assert_eq!(distribution.lookup(&other.id()).unwrap().total, Stake(33));
+        assert!(distribution.lookup(&retired.id()).is_none());
+    }
+
+    #[test]
+    fn leadership_uses_the_stake_of_the_previous_epoch_start() {
+        let pool_id = StakePoolBuilder::new().build().id();
+        let distribution = |stake| {
+            let pool_stake = PoolStake::default().add(&TestGen::identifier(), Stake(stake));
+            PoolsStake::new()
+                .insert(pool_id.clone(), Arc::new(pool_stake))
+                .unwrap()
+        };
+
+        let epoch0 = LeadershipStake::genesis(distribution(1));
+        assert!(epoch0.rewarded.is_none());
+        assert_eq!(total_stake(&epoch0.current), Stake(1));
+
+        // epoch 1 is led with the stake at the end of epoch 0
+        let epoch1 = epoch0.next_epoch(distribution(2));
+        assert_eq!(total_stake(epoch1.rewarded.as_ref().unwrap()), Stake(1));
+        assert_eq!(total_stake(&epoch1.current), Stake(2));
+
+        // from then on, with the stake at the start of the previous epoch
+        let epoch2 = epoch1.next_epoch(distribution(3));
+        assert_eq!(total_stake(epoch2.rewarded.as_ref().unwrap()), Stake(2));
+        assert_eq!(total_stake(&epoch2.current), Stake(2));
+
+        let epoch3 = epoch2.next_epoch(distribution(4));
+        assert_eq!(total_stake(epoch3.rewarded.as_ref().unwrap()), Stake(2));
+        assert_eq!(total_stake(&epoch3.current), Stake(3));
+    }
+}
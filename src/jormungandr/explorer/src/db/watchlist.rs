@@ -0,0 +1,138 @@
+use super::indexing::ExplorerAddress;
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+use thiserror::Error;
+use tokio::sync::RwLock;
+
+/// Maximum number of watchlists kept by the explorer
+pub const MAX_WATCHLISTS: usize = 256;
+/// Maximum number of addresses in a watchlist
+pub const MAX_WATCHLIST_ADDRESSES: usize = 1024;
+
+#[derive(Debug, Error, Clone)]
+pub enum WatchlistError {
+    #[error("cannot create more than {} watchlists", MAX_WATCHLISTS)]
+    TooManyWatchlists,
+    #[error(
+        "cannot watch more than {} addresses in a watchlist",
+        MAX_WATCHLIST_ADDRESSES
+    )]
+    TooManyAddresses,
+}
+
+/// Sets of addresses registered through the API to be monitored, by name.
+///
+/// Watchlists are kept in memory only, so they have to be registered again
+/// when the explorer is restarted.
+#[derive(Clone, Default)]
+pub struct Watchlists {
+    inner: Arc<RwLock<HashMap<String, Arc<HashSet<ExplorerAddress>>>>>,
+}
+
+impl Watchlists {
+    /// Adds the addresses to the named watchlist, creating it if needed, and
+    /// returns the updated set of addresses of the watchlist.
+    pub async fn watch(
+        &self,
+        name: String,
+        addresses: impl IntoIterator<Item = ExplorerAddress>,
+    ) -> Result<Arc<HashSet<ExplorerAddress>>, WatchlistError> {
+        let mut watchlists = self.inner.write().await;
+        if !watchlists.contains_key(&name) && watchlists.len() >= MAX_WATCHLISTS {
+            return Err(WatchlistError::TooManyWatchlists);
+        }
+
+        let mut watched = watchlists
+            .get(&name)
+            .map(|watched| HashSet::clone(watched))
+            .unwrap_or_default();
+        watched.extend(addresses);
+        if watched.len() > MAX_WATCHLIST_ADDRESSES {
+            return Err(WatchlistError::TooManyAddresses);
+        }
+
+        let watched = Arc::new(watched);
+        watchlists.insert(name, Arc::clone(&watched));
+        Ok(watched)
+    }
+
+    /// Removes the named watchlist, returns whether it existed
+    pub async fn remove(&self, name: &str) -> bool {
+        self.inner.write().await.remove(name).is_some()
+    }
+
+    pub async fn get(&self, name: &str) -> Option<Arc<HashSet<ExplorerAddress>>> {
+        self.inner.read().await.get(name).cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chain_addr::{Address, Discrimination, Kind};
+    use chain_impl_mockchain::testing::TestGen;
+    use futures::executor::block_on;
+
+    fn addresses(count: usize) -> Vec<ExplorerAddress> {
+        (0..count)
+            .map(|_| {
+                ExplorerAddress::New(Address(
+                    Discrimination::Test,
+                    Kind::Account(TestGen::public_key()),
+                ))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn watch_extends_the_watchlist() {
+        let watchlists = Watchlists::default();
+        let (first, second) = (addresses(2), addresses(1));
+
+        block_on(async {
+            let watched = watchlists
+                .watch("list".to_string(), first.clone())
+                .await
+                .unwrap();
+            assert_eq!(watched.len(), 2);
+
+            // watching an address twice does not duplicate it
+            let watched = watchlists
+                .watch("list".to_string(), first.iter().chain(&second).cloned())
+                .await
+                .unwrap();
+            assert_eq!(watched.len(), 3);
+            assert_eq!(watchlists.get("list").await.unwrap(), watched);
+
+            assert!(watchlists.remove("list").await);
+            assert!(!watchlists.remove("list").await);
+            assert!(watchlists.get("list").await.is_none());
+        });
+    }
+
+    #[test]
+    fn watchlists_are_bounded() {
+        let watchlists = Watchlists::default();
+
+        block_on(async {
+            let result = watchlists
+                .watch("large".to_string(), addresses(MAX_WATCHLIST_ADDRESSES + 1))
+                .await;
+            assert!(matches!(result, Err(WatchlistError::TooManyAddresses)));
+            assert!(watchlists.get("large").await.is_none());
+
+            for i in 0..MAX_WATCHLISTS {
+                watchlists.watch(i.to_string(), addresses(1)).await.unwrap();
+            }
+            let result = watchlists.watch("extra".to_string(), addresses(1)).await;
+            assert!(matches!(result, Err(WatchlistError::TooManyWatchlists)));
+            // existing watchlists can still be extended
+            watchlists
+                .watch("0".to_string(), addresses(1))
+                .await
+                .unwrap();
+        });
+    }
+}
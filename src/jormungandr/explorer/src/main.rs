@@ -2,6 +2,7 @@ mod api;
 pub mod db;
 mod indexer;
 mod logging;
+mod metadata;
 mod settings;
 
 use crate::indexer::Indexer;
@@ -14,6 +15,7 @@ use chain_network::grpc::watch::client::{
 use db::ExplorerDb;
 use futures::stream::StreamExt;
 use futures_util::{future, pin_mut, FutureExt, TryFutureExt};
+use metadata::VotePlanMetadata;
 use settings::Settings;
 use thiserror::Error;
 use tokio::{
@@ -31,6 +33,8 @@ pub enum Error {
     SettingsError(#[from] settings::Error),
     #[error(transparent)]
     LoggingError(#[from] logging::Error),
+    #[error(transparent)]
+    MetadataError(#[from] metadata::Error),
     #[error("failed to bootstrap from node, reason {0}")]
     BootstrapError(#[from] BootstrapError),
     #[error(transparent)]
@@ -67,6 +71,11 @@ async fn main() -> Result<(), Error> {
         (guard, settings)
     };
 
+    let metadata = match settings.vote_plan_metadata.as_ref() {
+        Some(source) => source.load().await?,
+        None => Default::default(),
+    };
+
     let mut settings = Some(settings);
 
     let (state_tx, state_rx) = broadcast::channel(3);
@@ -130,7 +139,7 @@ async fn main() -> Result<(), Error> {
 
         let rest = tokio::spawn(
             async {
-                rest_service(state_rx, settings).await;
+                rest_service(state_rx, settings, metadata).await;
                 Ok(())
             }
             .instrument(span!(Level::INFO, "rest service")),
@@ -247,7 +256,11 @@ async fn bootstrap(mut sync_stream: SyncMultiverseStream) -> Result<ExplorerDb,
     db.ok_or(BootstrapError::EmptyStream).map_err(Into::into)
 }
 
-async fn rest_service(mut state: broadcast::Receiver<GlobalState>, settings: Settings) {
+async fn rest_service(
+    mut state: broadcast::Receiver<GlobalState>,
+    settings: Settings,
+    metadata: VotePlanMetadata,
+) {
     tracing::info!("starting rest task, waiting for database to be ready");
 
     let (rest_shutdown, rest_shutdown_signal) = oneshot::channel();
@@ -281,7 +294,10 @@ async fn rest_service(mut state: broadcast::Receiver<GlobalState>, settings: Set
             address_bech32_prefix: settings.address_bech32_prefix,
             query_depth_limit: settings.query_depth_limit,
             query_complexity_limit: settings.query_complexity_limit,
+            query_cost_limit: settings.query_cost_limit,
+            watchlist_token: settings.watchlist_token,
         },
+        metadata,
     );
 
     let binding_address = settings.binding_address;
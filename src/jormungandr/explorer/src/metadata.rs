@@ -0,0 +1,303 @@
+//! Off-chain metadata of the vote proposals, loaded once at startup.
+//!
+//! The chain only knows the external id of a proposal, the metadata joins
+//! it with the information of the fund it belongs to, e.g.
+//!
+//! ```json
+//! {
+//!   "proposals": {
+//!     "<external proposal id, hex>": { "challenge_id": "12", "proposer": "alice" }
+//!   }
+//! }
+//! ```
+use serde::{Deserialize, Deserializer};
+use std::{collections::HashMap, convert::Infallible, fs::File, path::PathBuf, str::FromStr};
+use thiserror::Error;
+use url::Url;
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("cannot read vote plan metadata from {path}")]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+    #[error("invalid vote plan metadata")]
+    Format(#[from] serde_json::Error),
+    #[error("cannot fetch vote plan metadata from {url}")]
+    Http {
+        url: Url,
+        #[source]
+        source: reqwest::Error,
+    },
+}
+
+/// Where to load the metadata from: an `http(s)` URL, or a file path
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MetadataSource {
+    File(PathBuf),
+    Url(Url),
+}
+
+impl FromStr for MetadataSource {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match Url::parse(s) {
+            Ok(url) if url.scheme() == "http" || url.scheme() == "https" => {
+                Ok(MetadataSource::Url(url))
+            }
+            _ => Ok(MetadataSource::File(s.into())),
+        }
+    }
+}
+
+impl MetadataSource {
+    pub async fn load(&self) -> Result<VotePlanMetadata, Error> {
+        match self {
+            MetadataSource::File(path) => {
+                let file = File::open(path).map_err(|source| Error::Io {
+                    path: path.clone(),
+                    source,
+                })?;
+                serde_json::from_reader(file).map_err(Into::into)
+            }
+            MetadataSource::Url(url) => {
+                let http_error = |source| Error::Http {
+                    url: url.clone(),
+                    source,
+                };
+                let bytes = reqwest::get(url.clone())
+                    .await
+                    .and_then(|response| response.error_for_status())
+                    .map_err(http_error)?
+                    .bytes()
+                    .await
+                    .map_err(http_error)?;
+                serde_json::from_slice(&bytes).map_err(Into::into)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct VotePlanMetadata {
+    #[serde(default, deserialize_with = "deserialize_proposals")]
+    proposals: HashMap<String, ProposalMetadata>,
+}
+
+impl VotePlanMetadata {
+    /// Metadata of the proposal with the given external id, as an hex string
+    pub fn proposal(&self, proposal_id: &str) -> Option<&ProposalMetadata> {
+        self.proposals.get(&proposal_id.to_ascii_lowercase())
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ProposalMetadata {
+    #[serde(default, deserialize_with = "deserialize_challenge_id")]
+    pub challenge_id: Option<String>,
+    #[serde(default)]
+    pub proposer: Option<String>,
+}
+
+/// Filter on the metadata of the proposals, an unset criterion matching
+/// any proposal
+#[derive(Debug, Clone, Default)]
+pub struct ProposalFilter {
+    pub challenge_id: Option<String>,
+    pub proposer: Option<String>,
+}
+
+impl ProposalFilter {
+    pub fn is_empty(&self) -> bool {
+        self.challenge_id.is_none() && self.proposer.is_none()
+    }
+
+    pub fn matches(&self, metadata: Option<&ProposalMetadata>) -> bool {
+        fn criterion(expected: &Option<String>, actual: Option<&String>) -> bool {
+            expected
+                .as_ref()
+                .map_or(true, |expected| Some(expected) == actual)
+        }
+
+        criterion(
+            &self.challenge_id,
+            metadata.and_then(|metadata| metadata.challenge_id.as_ref()),
+        ) && criterion(
+            &self.proposer,
+            metadata.and_then(|metadata| metadata.proposer.as_ref()),
+        )
+    }
+}
+
+fn deserialize_proposals<'de, D>(
+    deserializer: D,
+) -> Result<HashMap<String, ProposalMetadata>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let proposals = HashMap::<String, ProposalMetadata>::deserialize(deserializer)?;
+    Ok(proposals
+        .into_iter()
+        .map(|(id, metadata)| (id.to_ascii_lowercase(), metadata))
+        .collect())
+}
+
+/// Challenge ids are numbers in the fund data, they are accepted as numbers
+/// or strings and exposed as strings
+fn deserialize_challenge_id<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum ChallengeId {
+        Number(u64),
+        String(String),
+    }
+
+    Ok(match Option::<ChallengeId>::deserialize(deserializer)? {
+        Some(ChallengeId::Number(id)) => Some(id.to_string()),
+        Some(ChallengeId::String(id)) => Some(id),
+        None => None,
+    })
+}
+
+pub(crate) fn deserialize_source<'de, D>(
+    deserializer: D,
+) -> Result<Option<MetadataSource>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let source = String::deserialize(deserializer)?;
+    Ok(Some(
+        source
+            .parse()
+            .unwrap_or_else(|never: Infallible| match never {}),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::executor::block_on;
+
+    fn parse(json: &str) -> VotePlanMetadata {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn challenge_ids_are_read_as_numbers_or_strings() {
+        let metadata = parse(
+            r#"{
+                "proposals": {
+                    "aa": { "challenge_id": 12, "proposer": "alice" },
+                    "bb": { "challenge_id": "13" },
+                    "cc": {}
+                }
+            }"#,
+        );
+
+        let expected = |challenge_id: Option<&str>, proposer: Option<&str>| ProposalMetadata {
+            challenge_id: challenge_id.map(Into::into),
+            proposer: proposer.map(Into::into),
+        };
+        assert_eq!(
+            metadata.proposal("aa"),
+            Some(&expected(Some("12"), Some("alice")))
+        );
+        assert_eq!(metadata.proposal("bb"), Some(&expected(Some("13"), None)));
+        assert_eq!(metadata.proposal("cc"), Some(&expected(None, None)));
+        assert_eq!(metadata.proposal("dd"), None);
+    }
+
+    #[test]
+    fn proposals_are_indexed_by_lowercase_id() {
+        let metadata = parse(r#"{ "proposals": { "AbCd": { "proposer": "alice" } } }"#);
+        assert!(metadata.proposal("abcd").is_some());
+        assert!(metadata.proposal("ABCD").is_some());
+        assert!(metadata.proposal("AbCd").is_some());
+    }
+
+    #[test]
+    fn proposals_default_to_empty() {
+        let metadata = parse("{}");
+        assert!(metadata.proposals.is_empty());
+    }
+
+    #[test]
+    fn unknown_fields_are_rejected() {
+        assert!(serde_json::from_str::<VotePlanMetadata>(r#"{ "funds": {} }"#).is_err());
+        assert!(serde_json::from_str::<VotePlanMetadata>(
+            r#"{ "proposals": { "aa": { "title": "x" } } }"#
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn filter_matches_set_criteria_only() {
+        let metadata = ProposalMetadata {
+            challenge_id: Some("12".into()),
+            proposer: Some("alice".into()),
+        };
+
+        let filter = ProposalFilter::default();
+        assert!(filter.is_empty());
+        assert!(filter.matches(Some(&metadata)));
+        assert!(filter.matches(None));
+
+        let filter = ProposalFilter {
+            challenge_id: Some("12".into()),
+            proposer: None,
+        };
+        assert!(!filter.is_empty());
+        assert!(filter.matches(Some(&metadata)));
+        assert!(!filter.matches(None));
+
+        let filter = ProposalFilter {
+            challenge_id: Some("12".into()),
+            proposer: Some("bob".into()),
+        };
+        assert!(!filter.matches(Some(&metadata)));
+    }
+
+    #[test]
+    fn source_is_a_url_or_a_file() {
+        assert_eq!(
+            "https://example.com/metadata.json".parse::<MetadataSource>(),
+            Ok(MetadataSource::Url(
+                Url::parse("https://example.com/metadata.json").unwrap()
+            ))
+        );
+        assert_eq!(
+            "/tmp/metadata.json".parse::<MetadataSource>(),
+            Ok(MetadataSource::File("/tmp/metadata.json".into()))
+        );
+        assert_eq!(
+            "file:///tmp/metadata.json".parse::<MetadataSource>(),
+            Ok(MetadataSource::File("file:///tmp/metadata.json".into()))
+        );
+    }
+
+    #[test]
+    fn load_from_file() {
+        let path =
+            std::env::temp_dir().join(format!("explorer-metadata-{}.json", std::process::id()));
+        std::fs::write(&path, r#"{ "proposals": { "AA": { "challenge_id": 1 } } }"#).unwrap();
+        let loaded = block_on(MetadataSource::File(path.clone()).load());
+        std::fs::remove_file(&path).unwrap();
+
+        let metadata = loaded.unwrap();
+        assert_eq!(
+            metadata.proposal("aa").unwrap().challenge_id.as_deref(),
+            Some("1")
+        );
+
+        let missing = block_on(MetadataSource::File(path).load());
+        assert!(matches!(missing, Err(Error::Io { .. })));
+    }
+}
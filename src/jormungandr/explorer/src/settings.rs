@@ -1,4 +1,7 @@
-use crate::logging::{LogFormat, LogOutput, LogSettings};
+use crate::{
+    logging::{LogFormat, LogOutput, LogSettings},
+    metadata::MetadataSource,
+};
 use jormungandr_lib::interfaces::{Cors, Tls};
 use lazy_static::lazy_static;
 use serde::{de, de::Error as _, Deserialize, Deserializer, Serialize, Serializer};
@@ -11,6 +14,7 @@ use url::Url;
 
 const DEFAULT_QUERY_DEPTH_LIMIT: usize = 15;
 const DEFAULT_QUERY_COMPLEXITY_LIMIT: usize = 100;
+const DEFAULT_QUERY_COST_LIMIT: usize = 10_000;
 
 lazy_static! {
     pub static ref LOG_FILTER_LEVEL_POSSIBLE_VALUES: Vec<&'static str> = {
@@ -45,9 +49,12 @@ pub struct Settings {
     pub address_bech32_prefix: String,
     pub query_depth_limit: usize,
     pub query_complexity_limit: usize,
+    pub query_cost_limit: usize,
     pub tls: Option<Tls>,
     pub cors: Option<Cors>,
     pub log_settings: Option<LogSettings>,
+    pub vote_plan_metadata: Option<MetadataSource>,
+    pub watchlist_token: Option<String>,
 }
 
 impl Settings {
@@ -89,8 +96,23 @@ impl Settings {
             .or(file.query_complexity_limit)
             .unwrap_or(DEFAULT_QUERY_COMPLEXITY_LIMIT);
 
+        let query_cost_limit = cmd
+            .query_cost_limit
+            .or(file.query_cost_limit)
+            .unwrap_or(DEFAULT_QUERY_COST_LIMIT);
+
         let log_settings = Some(Self::log_settings(&cmd, &file));
 
+        let vote_plan_metadata = cmd
+            .vote_plan_metadata
+            .clone()
+            .or_else(|| file.vote_plan_metadata.clone());
+
+        let watchlist_token = cmd
+            .watchlist_token
+            .clone()
+            .or_else(|| file.watchlist_token.clone());
+
         let tls = file.tls;
         let cors = file.cors;
 
@@ -100,9 +122,12 @@ impl Settings {
             address_bech32_prefix,
             query_depth_limit,
             query_complexity_limit,
+            query_cost_limit,
             tls,
             cors,
             log_settings,
+            vote_plan_metadata,
+            watchlist_token,
         })
     }
 
@@ -158,6 +183,19 @@ struct CommandLine {
     pub query_depth_limit: Option<usize>,
     #[structopt(long)]
     pub query_complexity_limit: Option<usize>,
+    /// Reject queries with an estimated cost above this limit, the cost of
+    /// the elements of a connection being multiplied by the page size.
+    /// If not configured anywhere, defaults to 10000.
+    #[structopt(long)]
+    pub query_cost_limit: Option<usize>,
+    /// Off-chain metadata of the vote proposals (challenge id, proposer),
+    /// as a JSON file path or an http(s) URL, loaded at startup.
+    #[structopt(long)]
+    pub vote_plan_metadata: Option<MetadataSource>,
+    /// Enable the mutations managing the address watchlists, which then
+    /// have to be given this token. Disabled if not configured anywhere.
+    #[structopt(long)]
+    pub watchlist_token: Option<String>,
 
     pub config: Option<PathBuf>,
     /// Set log messages minimum severity. If not configured anywhere, defaults to "info".
@@ -195,7 +233,11 @@ pub struct Config {
     pub address_bech32_prefix: Option<String>,
     pub query_depth_limit: Option<usize>,
     pub query_complexity_limit: Option<usize>,
+    pub query_cost_limit: Option<usize>,
     pub logs: Option<ConfigLogSettings>,
+    #[serde(default, deserialize_with = "crate::metadata::deserialize_source")]
+    pub vote_plan_metadata: Option<MetadataSource>,
+    pub watchlist_token: Option<String>,
 }
 
 fn deserialize_uri_string<'de, D>(deserializer: D) -> Result<Option<Uri>, D::Error>
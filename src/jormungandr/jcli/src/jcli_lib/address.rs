@@ -1,6 +1,11 @@
-use crate::jcli_lib::utils::key_parser::parse_pub_key;
+use crate::jcli_lib::utils::{io, key_parser::parse_pub_key};
 use chain_addr::{AddressReadable, Discrimination, Kind};
-use chain_crypto::{bech32::Bech32 as _, AsymmetricPublicKey, Ed25519, PublicKey};
+use chain_crypto::{
+    bech32::{self, Bech32 as _},
+    AsymmetricPublicKey, Ed25519, PublicKey,
+};
+use serde::Serialize;
+use std::{io::BufRead as _, path::PathBuf};
 use structopt::StructOpt;
 use thiserror::Error;
 
@@ -16,6 +21,10 @@ pub enum Address {
 
     /// Create an account address from a single public key.
     Account(AccountArgs),
+
+    /// Create account addresses from many public keys, one address
+    /// per line of input.
+    AccountBatch(AccountBatchArgs),
 }
 
 #[derive(StructOpt)]
@@ -63,10 +72,42 @@ pub struct AccountArgs {
     discrimination_data: DiscriminationData,
 }
 
+#[derive(StructOpt)]
+pub struct AccountBatchArgs {
+    /// File with one public key in bech32 encoding per line, empty lines
+    /// are skipped. If not provided, keys are read from standard input.
+    #[structopt(long = "input", short = "i")]
+    input: Option<PathBuf>,
+
+    /// Print a JSON array holding each address along with its public key
+    /// instead of one address per line.
+    #[structopt(long = "json")]
+    json: bool,
+
+    #[structopt(flatten)]
+    discrimination_data: DiscriminationData,
+}
+
 #[derive(Debug, Error)]
 pub enum Error {
     #[error("multisig addresses are not supported")]
     MultisigAddressNotSupported,
+    #[error("could not read public keys")]
+    InputInvalid(#[from] std::io::Error),
+    #[error("invalid public key on line {line}")]
+    PublicKeyInvalid {
+        line: usize,
+        #[source]
+        source: bech32::Error,
+    },
+    #[error("could not serialize addresses")]
+    Serialize(#[from] serde_json::Error),
+}
+
+#[derive(Serialize)]
+struct BatchAddress {
+    public_key: String,
+    address: String,
 }
 
 impl Address {
@@ -94,6 +135,7 @@ impl Address {
                 account_args.key,
                 account_args.discrimination_data.testing,
             ),
+            Address::AccountBatch(batch_args) => mk_account_batch(batch_args)?,
         }
         Ok(())
     }
@@ -135,6 +177,37 @@ fn mk_account(prefix: &str, s: PublicKey<Ed25519>, testing: bool) {
     mk_address_1(prefix, s, testing, Kind::Account)
 }
 
+fn mk_account_batch(args: AccountBatchArgs) -> Result<(), Error> {
+    let discrimination = mk_discrimination(args.discrimination_data.testing);
+    let mut addresses = Vec::new();
+    for (index, line) in io::open_file_read(&args.input)?.lines().enumerate() {
+        let line = line?;
+        let public_key = line.trim();
+        if public_key.is_empty() {
+            continue;
+        }
+        let key =
+            parse_pub_key::<Ed25519>(public_key).map_err(|source| Error::PublicKeyInvalid {
+                line: index + 1,
+                source,
+            })?;
+        let address = chain_addr::Address(discrimination, Kind::Account(key));
+        let address = AddressReadable::from_address(&args.discrimination_data.prefix, &address);
+        if args.json {
+            addresses.push(BatchAddress {
+                public_key: public_key.to_string(),
+                address: address.to_string(),
+            });
+        } else {
+            println!("{}", address);
+        }
+    }
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&addresses)?);
+    }
+    Ok(())
+}
+
 fn mk_discrimination(testing: bool) -> Discrimination {
     if testing {
         Discrimination::Test
@@ -1,9 +1,11 @@
+mod new_anchor;
 #[cfg(feature = "evm")]
 mod new_evm_mapping;
 mod new_owner_stake_delegation;
 mod new_stake_delegation;
 mod new_stake_pool_registration;
 mod new_stake_pool_retirement;
+mod new_stake_pool_update;
 mod new_update_proposal;
 mod new_update_vote;
 mod new_vote_cast;
@@ -18,10 +20,13 @@ pub(crate) use self::sign::{
     stake_delegation_account_binding_sign, update_proposal_sign, update_vote_sign,
 };
 use crate::jcli_lib::utils::{
-    io, key_parser,
+    io, key_parser, output_format,
     vote::{SharesError, VotePlanError},
 };
-use chain_impl_mockchain::{block::BlockDate, certificate::DecryptedPrivateTallyError};
+use chain_impl_mockchain::{
+    block::BlockDate,
+    certificate::{DecryptedPrivateTallyError, PoolRegistration, VotePlan},
+};
 use chain_vote::UnitVectorInitializationError;
 use jormungandr_lib::interfaces::{self, CertificateFromBech32Error, CertificateFromStrError};
 use std::{
@@ -66,6 +71,12 @@ pub enum Error {
     OwnerStakeDelegationDoesntNeedSignature,
     #[error("mint token does not need a signature")]
     MintTokenDoesntNeedSignature,
+    #[error("anchor certificate does not need a signature")]
+    AnchorDoesntNeedSignature,
+    #[error("signing multisig declaration updates is not supported")]
+    MultisigDeclarationUpdateSigningUnsupported,
+    #[error("invalid anchor certificate")]
+    InvalidAnchor(#[from] chain_impl_mockchain::certificate::AnchorError),
     #[error("vote plan certificate does not need a signature")]
     VotePlanDoesntNeedSignature,
     #[error("vote cast certificate does not need a signature")]
@@ -76,6 +87,14 @@ pub enum Error {
     ExpectedSignedOrNotCertificate,
     #[error("Invalid bech32 data")]
     InvalidBech32(#[from] chain_crypto::bech32::Error),
+    #[error("invalid hex encoding")]
+    InvalidHex(#[from] hex::FromHexError),
+    #[error("Invalid input, expected a certificate or a fragment carrying one")]
+    ExpectedCertificateOrFragment,
+    #[error("the fragment does not carry a certificate")]
+    FragmentWithoutCertificate,
+    #[error(transparent)]
+    OutputFormat(#[from] output_format::Error),
     #[error("attempted to build delegation with zero weight")]
     PoolDelegationWithZeroWeight,
     #[error("pool delegation rates sum up to {actual}, maximum is 255")]
@@ -133,7 +152,7 @@ pub enum Certificate {
     /// Sign a certificate. You can call this command multiple
     /// times to add multiple signatures if this is required.
     Sign(sign::Sign),
-    /// Output information encoded into the certificate
+    /// Describe the certificate, or output a specific information encoded into it
     Show(show::ShowArgs),
     /// Print certificate
     Print(PrintArgs),
@@ -168,6 +187,11 @@ pub enum NewArgs {
     /// by doing so all remaining stake delegated to this stake pool will
     /// become pending and will need to be re-delegated.
     StakePoolRetirement(new_stake_pool_retirement::StakePoolRetirement),
+    /// update the declaration data of a registered stake pool
+    ///
+    /// The certificate refers to the registration currently in effect and carries
+    /// the replacing one. It needs to be signed by the owners of the current registration.
+    StakePoolUpdate(new_stake_pool_update::StakePoolUpdate),
     /// create a new vote plan certificate
     VotePlan(new_vote_plan::VotePlanRegistration),
     /// create a new vote tally certificate
@@ -181,6 +205,8 @@ pub enum NewArgs {
     #[cfg(feature = "evm")]
     /// create an EVM address mapping certificate
     EvmMapping(new_evm_mapping::EvmMapCmd),
+    /// create an anchor certificate committing the hash of some external data
+    Anchor(new_anchor::AnchorCmd),
 }
 
 #[derive(StructOpt)]
@@ -208,6 +234,7 @@ impl NewArgs {
             NewArgs::StakeDelegation(args) => args.exec()?,
             NewArgs::OwnerStakeDelegation(args) => args.exec()?,
             NewArgs::StakePoolRetirement(args) => args.exec()?,
+            NewArgs::StakePoolUpdate(args) => args.exec()?,
             NewArgs::VotePlan(args) => args.exec()?,
             NewArgs::VoteTally(args) => args.exec()?,
             NewArgs::VoteCast(args) => args.exec()?,
@@ -215,6 +242,7 @@ impl NewArgs {
             NewArgs::UpdateProposal(args) => args.exec()?,
             #[cfg(feature = "evm")]
             NewArgs::EvmMapping(args) => args.exec()?,
+            NewArgs::Anchor(args) => args.exec()?,
         }
         Ok(())
     }
@@ -242,14 +270,17 @@ impl Certificate {
 }
 
 fn read_cert_or_signed_cert(input: Option<&Path>) -> Result<interfaces::Certificate, Error> {
-    let cert_str = read_input(input)?.trim_end().to_owned();
-    let (hrp, _, _variant) =
-        bech32::decode(&cert_str).map_err(chain_crypto::bech32::Error::from)?;
+    parse_cert_or_signed_cert(read_input(input)?.trim_end())
+}
+
+/// parse a bech32 encoded certificate, signed or not
+fn parse_cert_or_signed_cert(cert_str: &str) -> Result<interfaces::Certificate, Error> {
+    let (hrp, _, _variant) = bech32::decode(cert_str).map_err(chain_crypto::bech32::Error::from)?;
 
     match hrp.as_ref() {
         interfaces::SIGNED_CERTIFICATE_HRP => {
             use chain_impl_mockchain::certificate::{Certificate, SignedCertificate};
-            let signed_cert = interfaces::SignedCertificate::from_bech32(&cert_str)?;
+            let signed_cert = interfaces::SignedCertificate::from_bech32(cert_str)?;
 
             let cert = match signed_cert.0 {
                 SignedCertificate::StakeDelegation(sd, _) => Certificate::StakeDelegation(sd),
@@ -264,17 +295,36 @@ fn read_cert_or_signed_cert(input: Option<&Path>) -> Result<interfaces::Certific
                 SignedCertificate::UpdateProposal(vt, _) => Certificate::UpdateProposal(vt),
                 SignedCertificate::UpdateVote(vt, _) => Certificate::UpdateVote(vt),
                 SignedCertificate::EvmMapping(vt, _) => Certificate::EvmMapping(vt),
+                SignedCertificate::MultisigDeclarationUpdate(mdu, _) => {
+                    Certificate::MultisigDeclarationUpdate(mdu)
+                }
             };
 
             Ok(interfaces::Certificate(cert))
         }
         interfaces::CERTIFICATE_HRP => {
-            interfaces::Certificate::from_bech32(&cert_str).map_err(Error::from)
+            interfaces::Certificate::from_bech32(cert_str).map_err(Error::from)
         }
         _ => Err(Error::ExpectedSignedOrNotCertificate),
     }
 }
 
+/// read a stake pool registration certificate, signed or not
+pub(crate) fn read_pool_registration(input: Option<&Path>) -> Result<PoolRegistration, Error> {
+    match read_cert_or_signed_cert(input)?.0 {
+        chain_impl_mockchain::certificate::Certificate::PoolRegistration(reg) => Ok(reg),
+        _ => Err(Error::NotStakePoolRegistration),
+    }
+}
+
+/// read a vote plan certificate, signed or not
+pub(crate) fn read_vote_plan(input: Option<&Path>) -> Result<VotePlan, Error> {
+    match read_cert_or_signed_cert(input)?.0 {
+        chain_impl_mockchain::certificate::Certificate::VotePlan(vote_plan) => Ok(vote_plan),
+        _ => Err(Error::NotVotePlanCertificate),
+    }
+}
+
 fn read_cert(input: Option<&Path>) -> Result<interfaces::Certificate, Error> {
     use std::str::FromStr as _;
 
@@ -0,0 +1,33 @@
+use crate::jcli_lib::certificate::{write_cert, Error};
+use chain_impl_mockchain::certificate::{Anchor, AnchorCommitment, Certificate};
+use jormungandr_lib::interfaces::Certificate as CertificateType;
+use std::path::PathBuf;
+use structopt::StructOpt;
+
+#[derive(StructOpt, Debug)]
+#[structopt(rename_all = "kebab-case")]
+pub struct AnchorCmd {
+    /// hex encoded 32 bytes commitment (e.g. hash of the external state)
+    #[structopt(name = "COMMITMENT", parse(try_from_str = parse_commitment))]
+    commitment: AnchorCommitment,
+    /// optional tag identifying the anchored data (up to 64 bytes)
+    #[structopt(long = "tag")]
+    tag: Option<String>,
+    /// write the output to the given file or print it to the standard output if not defined
+    #[structopt(short = "o", long = "output")]
+    output: Option<PathBuf>,
+}
+
+fn parse_commitment(hex: &str) -> Result<AnchorCommitment, hex::FromHexError> {
+    let mut commitment = AnchorCommitment::default();
+    hex::decode_to_slice(hex, &mut commitment)?;
+    Ok(commitment)
+}
+
+impl AnchorCmd {
+    pub fn exec(self) -> Result<(), Error> {
+        let anchor = Anchor::new(self.commitment, self.tag.map(String::into_bytes))?;
+        let cert = Certificate::Anchor(anchor);
+        write_cert(self.output.as_deref(), CertificateType(cert))
+    }
+}
@@ -0,0 +1,55 @@
+use crate::jcli_lib::certificate::{read_pool_registration, write_cert, Error};
+use chain_crypto::Blake2b256;
+use chain_impl_mockchain::certificate::{Certificate, PoolUpdate};
+use std::path::PathBuf;
+use structopt::StructOpt;
+
+/// update the declaration data of a registered stake pool
+///
+/// the new registration replaces the current one once the certificate is
+/// applied. The rewards parameters (`--tax-*`) cannot be changed this way.
+#[derive(StructOpt)]
+pub struct StakePoolUpdate {
+    /// set the 32bytes (in hexadecimal) of the Stake Pool identifier. This is
+    /// the identifier of the original registration, which differs from the
+    /// one of the current registration once the pool has been updated
+    #[structopt(long = "pool-id", name = "POOL_ID")]
+    pool_id: Blake2b256,
+
+    /// the stake pool registration certificate currently in effect (signed or not)
+    #[structopt(
+        long = "current-registration",
+        parse(from_os_str),
+        name = "CURRENT_REGISTRATION"
+    )]
+    current_registration: PathBuf,
+
+    /// the stake pool registration certificate holding the updated data,
+    /// as built with `jcli certificate new stake-pool-registration`
+    #[structopt(
+        long = "new-registration",
+        parse(from_os_str),
+        name = "NEW_REGISTRATION"
+    )]
+    new_registration: PathBuf,
+
+    /// print the output certificate in the given file, if no file given
+    /// the output will be printed in the standard output
+    pub output: Option<PathBuf>,
+}
+
+impl StakePoolUpdate {
+    pub fn exec(self) -> Result<(), Error> {
+        let current = read_pool_registration(Some(&self.current_registration))?;
+        let new_pool_reg = read_pool_registration(Some(&self.new_registration))?;
+
+        let pool_update = PoolUpdate {
+            pool_id: self.pool_id.into(),
+            last_pool_reg_hash: current.to_id(),
+            new_pool_reg,
+        };
+
+        let cert = Certificate::PoolUpdate(pool_update);
+        write_cert(self.output, cert.into())
+    }
+}
@@ -1,12 +1,30 @@
+mod description;
 mod stake_pool_id;
 mod vote_plan_id;
 
-use crate::jcli_lib::certificate::Error;
+use crate::jcli_lib::{
+    certificate::{read_input, Error},
+    utils::OutputFormat,
+};
 use structopt::StructOpt;
 
 #[derive(StructOpt)]
 #[structopt(rename_all = "kebab-case")]
-pub enum ShowArgs {
+pub struct ShowArgs {
+    #[structopt(subcommand)]
+    cmd: Option<ShowCmd>,
+    /// certificate to describe: bech32 encoded, signed or not, or hex encoded
+    /// certificate bytes or fragment carrying a certificate. If not provided,
+    /// it will be read from the standard input
+    #[structopt(name = "CERTIFICATE")]
+    input: Option<String>,
+    #[structopt(flatten)]
+    output_format: OutputFormat,
+}
+
+#[derive(StructOpt)]
+#[structopt(rename_all = "kebab-case")]
+pub enum ShowCmd {
     /// get the stake pool id from the given stake pool registration certificate
     StakePoolId(stake_pool_id::GetStakePoolId),
     /// get the vote plan id from the given vote plan certificate
@@ -15,9 +33,21 @@ pub enum ShowArgs {
 
 impl ShowArgs {
     pub fn exec(self) -> Result<(), Error> {
-        match self {
-            ShowArgs::StakePoolId(args) => args.exec(),
-            ShowArgs::VotePlanId(args) => args.exec(),
+        match self.cmd {
+            Some(ShowCmd::StakePoolId(args)) => args.exec(),
+            Some(ShowCmd::VotePlanId(args)) => args.exec(),
+            None => {
+                let input = match self.input {
+                    Some(input) => input,
+                    None => read_input(None)?,
+                };
+                let cert = description::read_certificate(input.trim())?;
+                let formatted = self
+                    .output_format
+                    .format_json(description::describe(&cert))?;
+                println!("{}", formatted);
+                Ok(())
+            }
         }
     }
 }
@@ -0,0 +1,231 @@
+use crate::jcli_lib::certificate::{parse_cert_or_signed_cert, Error};
+use chain_core::{packer::Codec, property::DeserializeFromSlice as _};
+use chain_crypto::bech32::Bech32 as _;
+use chain_impl_mockchain::{
+    certificate::{Certificate, PoolRegistration, VoteAction},
+    fragment::Fragment,
+    transaction::{self, Payload as _},
+    vote::Payload,
+};
+use jormungandr_lib::interfaces::{
+    self, AccountIdentifier, BlockDate, ConfigParams, DelegationType, TaxType, TokenIdentifier,
+    VotePrivacy,
+};
+use serde_json::{json, Value};
+
+/// Decodes a certificate from its bech32 form, signed or not, or from the hex
+/// encoding of either the certificate bytes or a fragment carrying one.
+pub fn read_certificate(input: &str) -> Result<Certificate, Error> {
+    if bech32::decode(input).is_ok() {
+        return parse_cert_or_signed_cert(input).map(|cert| cert.0);
+    }
+
+    let bytes = hex::decode(input)?;
+    if let Ok(fragment) = Fragment::deserialize_from_slice(&mut Codec::new(bytes.as_slice())) {
+        return fragment_certificate(fragment).ok_or(Error::FragmentWithoutCertificate);
+    }
+    interfaces::Certificate::deserialize_from_slice(&mut Codec::new(bytes.as_slice()))
+        .map(|cert| cert.0)
+        .map_err(|_| Error::ExpectedCertificateOrFragment)
+}
+
+fn fragment_certificate(fragment: Fragment) -> Option<Certificate> {
+    match fragment {
+        Fragment::StakeDelegation(tx) => Some(Certificate::StakeDelegation(
+            tx.as_slice().payload().into_payload(),
+        )),
+        Fragment::OwnerStakeDelegation(tx) => Some(Certificate::OwnerStakeDelegation(
+            tx.as_slice().payload().into_payload(),
+        )),
+        Fragment::PoolRegistration(tx) => Some(Certificate::PoolRegistration(
+            tx.as_slice().payload().into_payload(),
+        )),
+        Fragment::PoolRetirement(tx) => Some(Certificate::PoolRetirement(
+            tx.as_slice().payload().into_payload(),
+        )),
+        Fragment::PoolUpdate(tx) => Some(Certificate::PoolUpdate(
+            tx.as_slice().payload().into_payload(),
+        )),
+        Fragment::VotePlan(tx) => Some(Certificate::VotePlan(
+            tx.as_slice().payload().into_payload(),
+        )),
+        Fragment::VoteCast(tx) => Some(Certificate::VoteCast(
+            tx.as_slice().payload().into_payload(),
+        )),
+        Fragment::VoteTally(tx) => Some(Certificate::VoteTally(
+            tx.as_slice().payload().into_payload(),
+        )),
+        Fragment::UpdateProposal(tx) => Some(Certificate::UpdateProposal(
+            tx.as_slice().payload().into_payload(),
+        )),
+        Fragment::UpdateVote(tx) => Some(Certificate::UpdateVote(
+            tx.as_slice().payload().into_payload(),
+        )),
+        Fragment::MintToken(tx) => Some(Certificate::MintToken(
+            tx.as_slice().payload().into_payload(),
+        )),
+        Fragment::EvmMapping(tx) => Some(Certificate::EvmMapping(
+            tx.as_slice().payload().into_payload(),
+        )),
+        Fragment::Anchor(tx) => Some(Certificate::Anchor(tx.as_slice().payload().into_payload())),
+        Fragment::MultisigDeclarationUpdate(tx) => Some(Certificate::MultisigDeclarationUpdate(
+            tx.as_slice().payload().into_payload(),
+        )),
+        Fragment::Initial(_)
+        | Fragment::OldUtxoDeclaration(_)
+        | Fragment::Transaction(_)
+        | Fragment::Evm(_) => None,
+    }
+}
+
+/// Structured description of the certificate, keys are bech32 encoded and
+/// accounts are given as identifiers
+pub fn describe(cert: &Certificate) -> Value {
+    match cert {
+        Certificate::StakeDelegation(delegation) => json!({
+            "type": "stake_delegation",
+            "account": AccountIdentifier::from(delegation.account_id.clone()),
+            "delegation": DelegationType::from(delegation.delegation.clone()),
+        }),
+        Certificate::OwnerStakeDelegation(delegation) => json!({
+            "type": "owner_stake_delegation",
+            "delegation": DelegationType::from(delegation.delegation.clone()),
+        }),
+        Certificate::PoolRegistration(registration) => {
+            let mut description = describe_pool_registration(registration);
+            description["type"] = json!("pool_registration");
+            description
+        }
+        Certificate::PoolRetirement(retirement) => json!({
+            "type": "pool_retirement",
+            "pool_id": retirement.pool_id.to_string(),
+            "retirement_time": u64::from(retirement.retirement_time),
+        }),
+        Certificate::PoolUpdate(update) => json!({
+            "type": "pool_update",
+            "pool_id": update.pool_id.to_string(),
+            "last_pool_registration_hash": update.last_pool_reg_hash.to_string(),
+            "new_pool_registration": describe_pool_registration(&update.new_pool_reg),
+        }),
+        Certificate::VotePlan(vote_plan) => json!({
+            "type": "vote_plan",
+            "id": vote_plan.to_id().to_string(),
+            "payload_type": VotePrivacy::from(vote_plan.payload_type()),
+            "vote_start": BlockDate::from(vote_plan.vote_start()),
+            "vote_end": BlockDate::from(vote_plan.vote_end()),
+            "committee_end": BlockDate::from(vote_plan.committee_end()),
+            "voting_token": TokenIdentifier::from(vote_plan.voting_token().clone()),
+            "committee_member_public_keys": vote_plan
+                .committee_public_keys()
+                .iter()
+                .map(|key| key.to_bech32_str())
+                .collect::<Vec<_>>(),
+            "proposals": vote_plan
+                .proposals()
+                .iter()
+                .map(|proposal| json!({
+                    "external_id": proposal.external_id().to_string(),
+                    "options": proposal.options().choice_range().end,
+                    "action": match proposal.action() {
+                        VoteAction::OffChain => "off_chain",
+                        VoteAction::Treasury { .. } => "treasury",
+                        VoteAction::Parameters { .. } => "parameters",
+                    },
+                }))
+                .collect::<Vec<_>>(),
+        }),
+        Certificate::VoteCast(vote_cast) => json!({
+            "type": "vote_cast",
+            "vote_plan_id": vote_cast.vote_plan().to_string(),
+            "proposal_index": vote_cast.proposal_index(),
+            "payload_type": VotePrivacy::from(vote_cast.payload().payload_type()),
+            "choice": match vote_cast.payload() {
+                Payload::Public { choice } => Some(choice.as_byte()),
+                Payload::Private { .. } => None,
+            },
+        }),
+        Certificate::VoteTally(vote_tally) => json!({
+            "type": "vote_tally",
+            "vote_plan_id": vote_tally.id().to_string(),
+            "payload_type": VotePrivacy::from(vote_tally.tally_type()),
+            "decrypted_results": vote_tally.tally_decrypted().map(|tally| {
+                tally
+                    .iter()
+                    .map(|proposal| proposal.tally_result.to_vec())
+                    .collect::<Vec<_>>()
+            }),
+        }),
+        Certificate::UpdateProposal(proposal) => json!({
+            "type": "update_proposal",
+            "proposer_id": proposal.proposer_id().as_public_key().to_bech32_str(),
+            "changes": ConfigParams::from(proposal.changes().clone()),
+        }),
+        Certificate::UpdateVote(vote) => json!({
+            "type": "update_vote",
+            "proposal_id": vote.proposal_id().to_string(),
+            "voter_id": vote.voter_id().as_public_key().to_bech32_str(),
+        }),
+        Certificate::MintToken(mint_token) => json!({
+            "type": "mint_token",
+            "name": hex::encode(mint_token.name.as_ref()),
+            "to": AccountIdentifier::from(transaction::AccountIdentifier::Single(
+                mint_token.to.clone(),
+            )),
+            "value": mint_token.value.0,
+        }),
+        #[cfg(feature = "evm")]
+        Certificate::EvmMapping(mapping) => json!({
+            "type": "evm_mapping",
+            "account": AccountIdentifier::from(transaction::AccountIdentifier::Single(
+                mapping.account_id().clone(),
+            )),
+            "evm_address": format!("{:?}", mapping.evm_address()),
+        }),
+        #[cfg(not(feature = "evm"))]
+        Certificate::EvmMapping(_) => json!({ "type": "evm_mapping" }),
+        Certificate::Anchor(anchor) => json!({
+            "type": "anchor",
+            "commitment": hex::encode(anchor.commitment()),
+            "tag": anchor.tag().map(hex::encode),
+        }),
+        Certificate::MultisigDeclarationUpdate(update) => json!({
+            "type": "multisig_declaration_update",
+            "account": AccountIdentifier::from(transaction::AccountIdentifier::Multi(
+                update.id().clone(),
+            )),
+            "threshold": update.declaration().threshold(),
+            "owners": update
+                .declaration()
+                .owners()
+                .iter()
+                .map(|owner| hex::encode(owner.to_hash()))
+                .collect::<Vec<_>>(),
+        }),
+    }
+}
+
+fn describe_pool_registration(registration: &PoolRegistration) -> Value {
+    json!({
+        "pool_id": registration.to_id().to_string(),
+        "serial": registration.serial.to_string(),
+        "start_validity": u64::from(registration.start_validity),
+        "management_threshold": registration.management_threshold(),
+        "owners": registration
+            .owners
+            .iter()
+            .map(|owner| owner.to_bech32_str())
+            .collect::<Vec<_>>(),
+        "operators": registration
+            .operators
+            .iter()
+            .map(|operator| operator.to_bech32_str())
+            .collect::<Vec<_>>(),
+        "rewards": TaxType::from(registration.rewards),
+        "reward_account": registration
+            .reward_account
+            .clone()
+            .map(AccountIdentifier::from),
+        "kes_public_key": registration.keys.kes_public_key.to_bech32_str(),
+        "vrf_public_key": registration.keys.vrf_public_key.to_bech32_str(),
+    })
+}
@@ -25,6 +25,9 @@ impl GetStakePoolId {
             Certificate::PoolRetirement(stake_pool_info) => {
                 write_output(self.output.as_deref(), stake_pool_info.pool_id)
             }
+            Certificate::PoolUpdate(stake_pool_update) => {
+                write_output(self.output.as_deref(), stake_pool_update.pool_id)
+            }
             _ => Err(Error::NotStakePoolRegistration),
         }
     }
@@ -1,5 +1,5 @@
 use crate::jcli_lib::{
-    certificate::{read_cert, read_input, write_signed_cert, Error},
+    certificate::{read_cert, read_input, read_pool_registration, write_signed_cert, Error},
     utils::key_parser::{self, parse_ed25519_secret_key},
 };
 use chain_crypto::{Ed25519, PublicKey};
@@ -33,6 +33,11 @@ pub struct Sign {
     /// provided it will be written into the standard output
     #[structopt(short = "o", long = "output")]
     pub output: Option<PathBuf>,
+    /// stake pool registration certificate currently in effect, used to match
+    /// the signing keys with the pool owners when signing a stake pool retirement
+    /// or update. Without it, keys are expected in the owners order.
+    #[structopt(long = "pool-registration")]
+    pub pool_registration: Option<PathBuf>,
 }
 
 impl Sign {
@@ -50,6 +55,12 @@ impl Sign {
             .collect();
         let keys_str = keys_str?;
 
+        let pool_reg = self
+            .pool_registration
+            .as_deref()
+            .map(|path| read_pool_registration(Some(path)))
+            .transpose()?;
+
         let signedcert = match cert.into() {
             Certificate::StakeDelegation(s) => {
                 let txbuilder = Transaction::block0_payload_builder(&s);
@@ -70,13 +81,13 @@ impl Sign {
             }
             Certificate::PoolRetirement(s) => {
                 let txbuilder = Transaction::block0_payload_builder(&s);
-                pool_owner_sign(s, None, &keys_str, txbuilder, |c, a| {
+                pool_owner_sign(s, pool_reg.as_ref(), &keys_str, txbuilder, |c, a| {
                     SignedCertificate::PoolRetirement(c, PoolSignature::Owners(a))
                 })?
             }
             Certificate::PoolUpdate(s) => {
                 let txbuilder = Transaction::block0_payload_builder(&s);
-                pool_owner_sign(s, None, &keys_str, txbuilder, |c, a| {
+                pool_owner_sign(s, pool_reg.as_ref(), &keys_str, txbuilder, |c, a| {
                     SignedCertificate::PoolUpdate(c, PoolSignature::Owners(a))
                 })?
             }
@@ -125,6 +136,10 @@ impl Sign {
                     })??
             }
             Certificate::MintToken(_) => return Err(Error::MintTokenDoesntNeedSignature),
+            Certificate::Anchor(_) => return Err(Error::AnchorDoesntNeedSignature),
+            Certificate::MultisigDeclarationUpdate(_) => {
+                return Err(Error::MultisigDeclarationUpdateSigningUnsupported)
+            }
             Certificate::EvmMapping(uv) => {
                 let txbuilder = Transaction::block0_payload_builder(&uv);
                 keys_str
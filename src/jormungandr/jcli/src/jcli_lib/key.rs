@@ -154,7 +154,12 @@ pub struct Sign {
     #[structopt(long = "output", short = "o")]
     output: Option<PathBuf>,
 
-    /// path to file with data to sign, if no value is passed, standard input will be used
+    /// path to file with the message to sign, or `-` for standard input
+    #[structopt(long = "message", conflicts_with = "data")]
+    message: Option<PathBuf>,
+
+    /// path to file with data to sign, if no value is passed, standard input will be used.
+    /// DEPRECATED: use '--message' instead
     data: Option<PathBuf>,
 }
 
@@ -170,7 +175,12 @@ pub struct Verify {
     #[structopt(long = "signature")]
     signature: PathBuf,
 
-    /// path to file with signed data, if no value is passed, standard input will be used
+    /// path to file with the signed message, or `-` for standard input
+    #[structopt(long = "message", conflicts_with = "data")]
+    message: Option<PathBuf>,
+
+    /// path to file with signed data, if no value is passed, standard input will be used.
+    /// DEPRECATED: use '--message' instead
     data: Option<PathBuf>,
 }
 
@@ -332,8 +342,7 @@ impl Sign {
         <A as AsymmetricKey>::PubAlg: VerificationAlgorithm,
     {
         let secret = SecretKey::<A>::from_binary(secret_bytes)?;
-        let mut data = Vec::new();
-        io::open_file_read(&self.data)?.read_to_end(&mut data)?;
+        let data = read_message(self.message.or(self.data))?;
         let signature = secret.sign(&data);
         io::open_file_write(&self.output)?.write_all(signature.to_bech32_str().as_ref())?;
         Ok(())
@@ -360,8 +369,7 @@ impl Verify {
         let public = <PublicKey<A::PubAlg>>::from_binary(public_bytes)?;
         let raw_signature = io::read_line(&Some(self.signature))?;
         let signature = <Signature<&[u8], A::PubAlg>>::try_from_bech32_str(&raw_signature)?;
-        let mut data = Vec::new();
-        io::open_file_read(&self.data)?.read_to_end(&mut data)?;
+        let data = read_message(self.message.or(self.data))?;
         match signature.verify_slice(&public, &data) {
             Verification::Success => Ok(()),
             Verification::Failed => Err(Error::SignatureVerification),
@@ -404,6 +412,15 @@ impl Derive {
     }
 }
 
+/// Reads the whole message to sign or verify, from standard input if no path
+/// or `-` is given
+fn read_message(path: Option<PathBuf>) -> Result<Vec<u8>, Error> {
+    let path = path.filter(|path| path != Path::new("-"));
+    let mut data = Vec::new();
+    io::open_file_read(&path)?.read_to_end(&mut data)?;
+    Ok(data)
+}
+
 fn read_hex<P: AsRef<Path>>(path: &Option<P>) -> Result<Vec<u8>, Error> {
     hex::decode(io::read_line(path)?).map_err(Into::into)
 }
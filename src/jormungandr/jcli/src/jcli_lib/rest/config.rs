@@ -21,8 +21,27 @@ pub struct RestArgs {
     /// certificate CA is not present within the webpki certificate bundle.
     #[structopt(long, name = "PATH", env = "JORMUNGANDR_TLS_CERT_PATH")]
     tls_cert_path: Option<PathBuf>,
+    /// An optional PEM-encoded TLS client certificate, presented to the node
+    /// API when it requires client authentication (mutual TLS).
+    /// Must be given along with `--tls-client-key`.
+    #[structopt(
+        long,
+        name = "CLIENT_CERT_PATH",
+        env = "JORMUNGANDR_TLS_CLIENT_CERT",
+        requires = "CLIENT_KEY_PATH"
+    )]
+    tls_client_cert: Option<PathBuf>,
+    /// The PEM-encoded private key of the TLS client certificate.
+    #[structopt(
+        long,
+        name = "CLIENT_KEY_PATH",
+        env = "JORMUNGANDR_TLS_CLIENT_KEY",
+        requires = "CLIENT_CERT_PATH"
+    )]
+    tls_client_key: Option<PathBuf>,
 }
 
+#[derive(Clone)]
 pub struct RestClient {
     client: Client,
     debug: bool,
@@ -45,6 +64,8 @@ pub enum Error {
     CertIo(#[source] std::io::Error),
     #[error("expected a valid PEM-encoded certificate")]
     Pem(#[source] reqwest::Error),
+    #[error("expected a valid PEM-encoded client certificate and private key")]
+    ClientIdentity(#[source] reqwest::Error),
     #[error("failed to build an HTTP client")]
     Client(#[source] reqwest::Error),
     #[error("invalid request")]
@@ -68,16 +89,37 @@ pub enum Error {
 }
 
 impl RestArgs {
+    /// Arguments reaching the node API at the given address, with the
+    /// default TLS settings.
+    pub fn from_host(host: Url) -> Self {
+        RestArgs {
+            host,
+            debug: false,
+            tls_cert_path: None,
+            tls_client_cert: None,
+            tls_client_key: None,
+        }
+    }
+
     pub fn client(self) -> Result<RestClient, Error> {
-        use reqwest::{blocking::ClientBuilder, Certificate};
+        use reqwest::{blocking::ClientBuilder, Certificate, Identity};
         use std::{fs::File, io::Read};
 
         let Self {
             tls_cert_path,
+            tls_client_cert,
+            tls_client_key,
             host,
             debug,
         } = self;
 
+        let read_pem = |path: PathBuf, buf: &mut Vec<u8>| {
+            File::open(path)
+                .map_err(Error::CertIo)?
+                .read_to_end(buf)
+                .map_err(Error::CertIo)
+        };
+
         if host.cannot_be_a_base() {
             return Err(Error::HostAddrNotBase { addr: host });
         }
@@ -87,16 +129,27 @@ impl RestArgs {
         // load certificate
         let client_builder = if let Some(path) = tls_cert_path {
             let mut buf = Vec::new();
-            File::open(path)
-                .map_err(Error::CertIo)?
-                .read_to_end(&mut buf)
-                .map_err(Error::CertIo)?;
+            read_pem(path, &mut buf)?;
             let cert = Certificate::from_pem(&buf).map_err(Error::Pem)?;
             client_builder.use_rustls_tls().add_root_certificate(cert)
         } else {
             client_builder
         };
 
+        // load client certificate, both are required by the arguments definition
+        let client_builder =
+            if let (Some(cert_path), Some(key_path)) = (tls_client_cert, tls_client_key) {
+                // rustls reads the identity from a single PEM bundle with the key and the certificate
+                let mut buf = Vec::new();
+                read_pem(key_path, &mut buf)?;
+                buf.push(b'\n');
+                read_pem(cert_path, &mut buf)?;
+                let identity = Identity::from_pem(&buf).map_err(Error::ClientIdentity)?;
+                client_builder.use_rustls_tls().identity(identity)
+            } else {
+                client_builder
+            };
+
         let client = client_builder.build().map_err(Error::Client)?;
 
         let rest_client = RestClient {
@@ -37,6 +37,8 @@ pub enum Error {
     RequestError(#[from] config::Error),
     #[error("error loading data from response")]
     SerdeError(#[from] serde_json::Error),
+    #[error("the fragment id and the output index of the UTxO are required")]
+    UtxoPointerMissing,
 }
 
 impl From<ReadYamlError> for Error {
@@ -7,7 +7,10 @@ use chain_core::{
     property::{DeserializeFromSlice as _, Serialize},
 };
 use chain_impl_mockchain::fragment::Fragment;
-use std::path::PathBuf;
+use jormungandr_lib::interfaces::{
+    FragmentLogsQuery, FragmentOriginKind, FragmentStatusKind, MessageBatch,
+};
+use std::{io::BufRead, path::PathBuf};
 use structopt::StructOpt;
 
 #[derive(StructOpt)]
@@ -23,6 +26,21 @@ pub enum Message {
         file: Option<PathBuf>,
     },
 
+    /// Post a batch of messages. Prints the processing summary of the batch
+    PostBatch {
+        #[structopt(flatten)]
+        args: RestArgs,
+        /// File containing hex-encoded messages, one per line.
+        /// If not provided, messages will be read from stdin.
+        #[structopt(short, long)]
+        file: Option<PathBuf>,
+        /// Reject all the messages if any of them is rejected
+        #[structopt(long)]
+        all_or_nothing: bool,
+        #[structopt(flatten)]
+        output_format: OutputFormat,
+    },
+
     /// get the node's logs on the message pool. This will provide information
     /// on pending transaction, rejected transaction and or when a transaction
     /// has been added in a block
@@ -30,26 +48,70 @@ pub enum Message {
         #[structopt(flatten)]
         args: RestArgs,
         #[structopt(flatten)]
+        filter: LogsFilter,
+        #[structopt(flatten)]
         output_format: OutputFormat,
     },
 }
 
+#[derive(StructOpt)]
+#[structopt(rename_all = "kebab-case")]
+pub struct LogsFilter {
+    /// number of matching logs to skip
+    #[structopt(long)]
+    offset: Option<usize>,
+    /// maximum number of logs to get
+    #[structopt(long)]
+    limit: Option<usize>,
+    /// only get the logs of fragments with this status:
+    /// pending, rejected or in_a_block
+    #[structopt(long)]
+    status: Option<FragmentStatusKind>,
+    /// only get the logs of fragments received from:
+    /// network, rest or jrpc
+    #[structopt(long)]
+    origin: Option<FragmentOriginKind>,
+}
+
+impl From<LogsFilter> for FragmentLogsQuery {
+    fn from(filter: LogsFilter) -> Self {
+        FragmentLogsQuery {
+            offset: filter.offset,
+            limit: filter.limit,
+            status: filter.status,
+            origin: filter.origin,
+        }
+    }
+}
+
 impl Message {
     pub fn exec(self) -> Result<(), Error> {
         match self {
             Message::Post { args, file } => post_message(args, file),
+            Message::PostBatch {
+                args,
+                file,
+                all_or_nothing,
+                output_format,
+            } => post_message_batch(args, file, all_or_nothing, output_format),
             Message::Logs {
                 args,
+                filter,
                 output_format,
-            } => get_logs(args, output_format),
+            } => get_logs(args, filter.into(), output_format),
         }
     }
 }
 
-fn get_logs(args: RestArgs, output_format: OutputFormat) -> Result<(), Error> {
+fn get_logs(
+    args: RestArgs,
+    query: FragmentLogsQuery,
+    output_format: OutputFormat,
+) -> Result<(), Error> {
     let response = args
         .client()?
         .get(&["v0", "fragment", "logs"])
+        .query(&query)
         .execute()?
         .json()?;
     let formatted = output_format.format_json(response)?;
@@ -66,6 +128,39 @@ fn post_message(args: RestArgs, file: Option<PathBuf>) -> Result<(), Error> {
     Ok(())
 }
 
+fn post_message_batch(
+    args: RestArgs,
+    file: Option<PathBuf>,
+    all_or_nothing: bool,
+    output_format: OutputFormat,
+) -> Result<(), Error> {
+    let mut fragments = Vec::new();
+    for line in io::open_file_read(&file)?.lines() {
+        let line = line?;
+        let msg_hex = line.trim();
+        if msg_hex.is_empty() {
+            continue;
+        }
+        let msg_bin = hex::decode(msg_hex)?;
+        fragments.push(Fragment::deserialize_from_slice(&mut Codec::new(
+            msg_bin.as_slice(),
+        ))?);
+    }
+    let batch = MessageBatch {
+        all_or_nothing,
+        fragments,
+    };
+    let response = args
+        .client()?
+        .post(&["v0", "message", "batch"])
+        .json(&batch)
+        .execute()?
+        .json()?;
+    let formatted = output_format.format_json(response)?;
+    println!("{}", formatted);
+    Ok(())
+}
+
 pub fn post_fragment(args: RestArgs, fragment: Fragment) -> Result<String, Error> {
     let fragment_id = args
         .client()?
@@ -1,6 +1,7 @@
+mod quarantined;
 mod stats;
 
-use self::stats::Stats;
+use self::{quarantined::Quarantined, stats::Stats};
 use crate::jcli_lib::rest::Error;
 use structopt::StructOpt;
 
@@ -9,12 +10,15 @@ use structopt::StructOpt;
 pub enum Network {
     /// Network information
     Stats(Stats),
+    /// Quarantined peers management
+    Quarantined(Quarantined),
 }
 
 impl Network {
     pub fn exec(self) -> Result<(), Error> {
         match self {
             Network::Stats(stats) => stats.exec(),
+            Network::Quarantined(quarantined) => quarantined.exec(),
         }
     }
 }
@@ -0,0 +1,85 @@
+use crate::jcli_lib::{
+    rest::{Error, RestArgs},
+    utils::OutputFormat,
+};
+use jormungandr_lib::time::Duration;
+use structopt::StructOpt;
+
+#[derive(StructOpt)]
+#[structopt(rename_all = "kebab-case")]
+pub enum Quarantined {
+    /// List the quarantined peers
+    List {
+        #[structopt(flatten)]
+        args: RestArgs,
+        #[structopt(flatten)]
+        output_format: OutputFormat,
+    },
+    /// Lift the quarantine of a peer
+    Lift {
+        #[structopt(flatten)]
+        args: RestArgs,
+        #[structopt(flatten)]
+        output_format: OutputFormat,
+        /// id or address of the peer
+        peer: String,
+    },
+    /// Keep a peer in quarantine for longer
+    Extend {
+        #[structopt(flatten)]
+        args: RestArgs,
+        #[structopt(flatten)]
+        output_format: OutputFormat,
+        /// id or address of the peer
+        peer: String,
+        /// additional quarantine time, e.g. "30m" or "2h"
+        #[structopt(long)]
+        duration: Duration,
+    },
+}
+
+impl Quarantined {
+    pub fn exec(self) -> Result<(), Error> {
+        let response = match self {
+            Quarantined::List {
+                args,
+                output_format,
+            } => {
+                let response = args
+                    .client()?
+                    .get(&["v0", "network", "p2p", "quarantined"])
+                    .execute()?
+                    .json()?;
+                output_format.format_json(response)?
+            }
+            Quarantined::Lift {
+                args,
+                output_format,
+                peer,
+            } => {
+                let response = args
+                    .client()?
+                    .delete(&["v0", "network", "p2p", "quarantined", &peer])
+                    .execute()?
+                    .json()?;
+                output_format.format_json(response)?
+            }
+            Quarantined::Extend {
+                args,
+                output_format,
+                peer,
+                duration,
+            } => {
+                let response = args
+                    .client()?
+                    .post(&["v0", "network", "p2p", "quarantined", &peer])
+                    .query(&[("duration", duration.to_string())])
+                    .execute()?
+                    .json()?;
+                output_format.format_json(response)?
+            }
+        };
+        println!("{}", response);
+        Ok(())
+    }
+}
@@ -1,7 +1,9 @@
 use crate::jcli_lib::{
-    rest::{Error, RestArgs},
+    rest::{config::RestClient, Error, RestArgs},
     utils::OutputFormat,
 };
+use serde_json::{Map, Value};
+use std::{thread, time::Duration};
 use structopt::StructOpt;
 
 #[derive(StructOpt)]
@@ -13,6 +15,15 @@ pub enum Stats {
         args: RestArgs,
         #[structopt(flatten)]
         output_format: OutputFormat,
+        /// keep querying the node stats until interrupted
+        #[structopt(long)]
+        watch: bool,
+        /// number of seconds between two queries in watch mode
+        #[structopt(long, default_value = "5", requires = "watch")]
+        interval: u64,
+        /// in watch mode, only print the fields which changed since the previous query
+        #[structopt(long, requires = "watch")]
+        diff: bool,
     },
 }
 
@@ -21,14 +32,56 @@ impl Stats {
         let Stats::Get {
             args,
             output_format,
+            watch,
+            interval,
+            diff,
         } = self;
-        let response = args
-            .client()?
-            .get(&["v0", "node", "stats"])
-            .execute()?
-            .json()?;
-        let formatted = output_format.format_json(response)?;
-        println!("{}", formatted);
-        Ok(())
+        let client = args.client()?;
+        let mut previous = request_stats(client.clone())?;
+        println!("{}", output_format.format_json(previous.clone())?);
+        if !watch {
+            return Ok(());
+        }
+
+        loop {
+            thread::sleep(Duration::from_secs(interval));
+            let current = request_stats(client.clone())?;
+            if current == previous {
+                continue;
+            }
+            let output = if diff {
+                changed_fields(&previous, &current)
+            } else {
+                current.clone()
+            };
+            println!("{}", output_format.format_json(output)?);
+            previous = current;
+        }
+    }
+}
+
+fn request_stats(client: RestClient) -> Result<Value, Error> {
+    Ok(client.get(&["v0", "node", "stats"]).execute()?.json()?)
+}
+
+/// Top level fields of `current` which differ from `previous`,
+/// fields missing from `current` are reported as `null`
+fn changed_fields(previous: &Value, current: &Value) -> Value {
+    match (previous, current) {
+        (Value::Object(previous), Value::Object(current)) => {
+            let mut changed: Map<String, Value> = current
+                .iter()
+                .filter(|(key, value)| previous.get(*key) != Some(*value))
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect();
+            changed.extend(
+                previous
+                    .keys()
+                    .filter(|key| !current.contains_key(*key))
+                    .map(|key| (key.clone(), Value::Null)),
+            );
+            Value::Object(changed)
+        }
+        _ => current.clone(),
     }
 }
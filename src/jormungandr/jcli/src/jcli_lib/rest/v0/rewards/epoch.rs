@@ -1,4 +1,8 @@
-use crate::jcli_lib::rest::{Error, RestArgs};
+use crate::jcli_lib::{
+    rest::{Error, RestArgs},
+    utils::OutputFormat,
+};
+use jormungandr_lib::interfaces::EpochRewardsInfo;
 use structopt::StructOpt;
 
 #[derive(StructOpt)]
@@ -8,6 +12,8 @@ pub enum Epoch {
     Get {
         #[structopt(flatten)]
         args: RestArgs,
+        #[structopt(flatten)]
+        output_format: OutputFormat,
         /// Epoch number
         epoch: u32,
     },
@@ -15,13 +21,18 @@ pub enum Epoch {
 
 impl Epoch {
     pub fn exec(self) -> Result<(), Error> {
-        let Epoch::Get { args, epoch } = self;
-        let response = args
+        let Epoch::Get {
+            args,
+            output_format,
+            epoch,
+        } = self;
+        let response: EpochRewardsInfo = args
             .client()?
             .get(&["v0", "rewards", "epoch", &epoch.to_string()])
             .execute()?
-            .text()?;
-        println!("{}", response);
+            .json()?;
+        let formatted = output_format.format_json(serde_json::to_value(response)?)?;
+        println!("{}", formatted);
         Ok(())
     }
 }
@@ -1,4 +1,8 @@
-use crate::jcli_lib::rest::{Error, RestArgs};
+use crate::jcli_lib::{
+    rest::{Error, RestArgs},
+    utils::OutputFormat,
+};
+use jormungandr_lib::interfaces::EpochRewardsInfo;
 use structopt::StructOpt;
 
 #[derive(StructOpt)]
@@ -8,20 +12,28 @@ pub enum History {
     Get {
         #[structopt(flatten)]
         args: RestArgs,
+        #[structopt(flatten)]
+        output_format: OutputFormat,
         /// Number of epochs
+        #[structopt(long)]
         length: usize,
     },
 }
 
 impl History {
     pub fn exec(self) -> Result<(), Error> {
-        let History::Get { args, length } = self;
-        let response = args
+        let History::Get {
+            args,
+            output_format,
+            length,
+        } = self;
+        let response: Vec<EpochRewardsInfo> = args
             .client()?
             .get(&["v0", "rewards", "history", &length.to_string()])
             .execute()?
-            .text()?;
-        println!("{}", response);
+            .json()?;
+        let formatted = output_format.format_json(serde_json::to_value(response)?)?;
+        println!("{}", formatted);
         Ok(())
     }
 }
@@ -2,6 +2,11 @@ use crate::jcli_lib::{
     rest::{Error, RestArgs},
     utils::OutputFormat,
 };
+use jormungandr_lib::{
+    crypto::hash::Hash,
+    interfaces::{Stake as StakeValue, StakeDistributionDto},
+};
+use serde::Serialize;
 use structopt::StructOpt;
 
 #[derive(StructOpt)]
@@ -13,26 +18,89 @@ pub enum Stake {
         args: RestArgs,
         #[structopt(flatten)]
         output_format: OutputFormat,
-        /// Epoch to get the stake distribution from
+        /// Epoch to get the stake distribution from, defaults to the current epoch
+        #[structopt(long = "epoch", name = "EPOCH_NUMBER", conflicts_with = "EPOCH")]
         epoch: Option<u32>,
+        /// Epoch to get the stake distribution from.
+        /// DEPRECATED: use '--epoch' instead
+        #[structopt(name = "EPOCH")]
+        epoch_positional: Option<u32>,
+        /// print the total delegated stake and the stake of every pool
+        /// instead of the raw stake distribution
+        #[structopt(long)]
+        summary: bool,
     },
 }
 
+/// Stake distribution with the total stake delegated to pools
+#[derive(Debug, Serialize)]
+pub struct StakeDistributionSummary {
+    pub epoch: u32,
+    pub total_delegated: StakeValue,
+    pub dangling: StakeValue,
+    pub unassigned: StakeValue,
+    pub pools: Vec<PoolStake>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PoolStake {
+    pub id: Hash,
+    pub stake: StakeValue,
+}
+
+impl From<StakeDistributionDto> for StakeDistributionSummary {
+    fn from(dto: StakeDistributionDto) -> Self {
+        let total_delegated = dto
+            .stake
+            .pools
+            .iter()
+            .map(|(_, stake)| u64::from(*stake))
+            .sum::<u64>()
+            .into();
+        Self {
+            epoch: dto.epoch,
+            total_delegated,
+            dangling: dto.stake.dangling,
+            unassigned: dto.stake.unassigned,
+            pools: dto
+                .stake
+                .pools
+                .into_iter()
+                .map(|(id, stake)| PoolStake { id, stake })
+                .collect(),
+        }
+    }
+}
+
 impl Stake {
     pub fn exec(self) -> Result<(), Error> {
         let Stake::Get {
             args,
             output_format,
             epoch,
+            epoch_positional,
+            summary,
         } = self;
-        let epoch = epoch.map(|epoch| epoch.to_string());
-        let mut url = vec!["v0", "stake"];
-        if let Some(epoch) = &epoch {
-            url.push(epoch);
-        }
-        let response = args.client()?.get(&url).execute()?.json()?;
+        let response = get_stake_distribution(args, epoch.or(epoch_positional))?;
+        let response = if summary {
+            serde_json::to_value(StakeDistributionSummary::from(response))?
+        } else {
+            serde_json::to_value(response)?
+        };
         let formatted = output_format.format_json(response)?;
         println!("{}", formatted);
         Ok(())
     }
 }
+
+pub(super) fn get_stake_distribution(
+    args: RestArgs,
+    epoch: Option<u32>,
+) -> Result<StakeDistributionDto, Error> {
+    let epoch = epoch.map(|epoch| epoch.to_string());
+    let mut url = vec!["v0", "stake"];
+    if let Some(epoch) = &epoch {
+        url.push(epoch);
+    }
+    Ok(args.client()?.get(&url).execute()?.json()?)
+}
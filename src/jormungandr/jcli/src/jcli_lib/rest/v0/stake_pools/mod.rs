@@ -1,7 +1,10 @@
+use super::stake::{get_stake_distribution, PoolStake};
 use crate::jcli_lib::{
     rest::{Error, RestArgs},
     utils::OutputFormat,
 };
+use jormungandr_lib::{crypto::hash::Hash, interfaces::Stake as StakeValue};
+use serde::Serialize;
 use structopt::StructOpt;
 
 #[derive(StructOpt)]
@@ -13,20 +16,61 @@ pub enum StakePools {
         args: RestArgs,
         #[structopt(flatten)]
         output_format: OutputFormat,
+        /// print the stake delegated to every pool and their total,
+        /// as of the current epoch
+        #[structopt(long)]
+        stake: bool,
     },
 }
 
+/// Registered stake pools with the stake delegated to them
+#[derive(Debug, Serialize)]
+pub struct StakePoolsSummary {
+    pub total_delegated: StakeValue,
+    pub pools: Vec<PoolStake>,
+}
+
 impl StakePools {
     pub fn exec(self) -> Result<(), Error> {
         let StakePools::Get {
             args,
             output_format,
+            stake,
         } = self;
-        let response = args
+        let pool_ids: Vec<Hash> = args
+            .clone()
             .client()?
             .get(&["v0", "stake_pools"])
             .execute()?
             .json()?;
+        let response = if stake {
+            let distribution = get_stake_distribution(args, None)?;
+            // pools without any delegation are not part of the distribution
+            let pools: Vec<PoolStake> = pool_ids
+                .into_iter()
+                .map(|id| {
+                    let stake = distribution
+                        .stake
+                        .pools
+                        .iter()
+                        .find(|(pool_id, _)| *pool_id == id)
+                        .map(|(_, stake)| *stake)
+                        .unwrap_or_else(|| 0u64.into());
+                    PoolStake { id, stake }
+                })
+                .collect();
+            let total_delegated = pools
+                .iter()
+                .map(|pool| u64::from(pool.stake))
+                .sum::<u64>()
+                .into();
+            serde_json::to_value(StakePoolsSummary {
+                total_delegated,
+                pools,
+            })?
+        } else {
+            serde_json::to_value(pool_ids)?
+        };
         let formatted = output_format.format_json(response)?;
         println!("{}", formatted);
         Ok(())
@@ -2,16 +2,17 @@ use crate::jcli_lib::{
     rest::{Error, RestArgs},
     utils::OutputFormat,
 };
+use jormungandr_lib::interfaces::{Address, UTxOsQuery};
 use structopt::StructOpt;
 
 #[derive(StructOpt)]
 #[structopt(rename_all = "kebab-case")]
 pub struct Utxo {
-    /// hex-encoded ID of the transaction fragment
-    fragment_id: String,
+    /// hex-encoded ID of the transaction fragment, required by `get`
+    fragment_id: Option<String>,
 
-    /// index of the transaction output
-    output_index: u8,
+    /// index of the transaction output, required by `get`
+    output_index: Option<u8>,
 
     #[structopt(subcommand)]
     subcommand: Subcommand,
@@ -28,26 +29,78 @@ enum Subcommand {
         #[structopt(flatten)]
         args: RestArgs,
     },
+    /// List the UTxOs of the ledger, a page at a time
+    List {
+        #[structopt(flatten)]
+        filter: ListFilter,
+
+        #[structopt(flatten)]
+        output_format: OutputFormat,
+
+        #[structopt(flatten)]
+        args: RestArgs,
+    },
+}
+
+#[derive(StructOpt)]
+#[structopt(rename_all = "kebab-case")]
+struct ListFilter {
+    /// only list the UTxOs of this bech32 encoded address
+    #[structopt(long)]
+    address: Option<Address>,
+    /// number of matching UTxOs to skip
+    #[structopt(long)]
+    offset: Option<usize>,
+    /// maximum number of UTxOs to list
+    #[structopt(long)]
+    limit: Option<usize>,
+}
+
+impl From<ListFilter> for UTxOsQuery {
+    fn from(filter: ListFilter) -> Self {
+        UTxOsQuery {
+            offset: filter.offset,
+            limit: filter.limit,
+            address: filter.address,
+        }
+    }
 }
 
 impl Utxo {
     pub fn exec(self) -> Result<(), Error> {
-        let Subcommand::Get {
-            args,
-            output_format,
-        } = self.subcommand;
-        let response = args
-            .client()?
-            .get(&[
-                "v0",
-                "utxo",
-                &self.fragment_id,
-                &self.output_index.to_string(),
-            ])
-            .execute()?
-            .json()?;
-        let formatted = output_format.format_json(response)?;
-        println!("{}", formatted);
-        Ok(())
+        match self.subcommand {
+            Subcommand::Get {
+                args,
+                output_format,
+            } => {
+                let (fragment_id, output_index) = match (self.fragment_id, self.output_index) {
+                    (Some(fragment_id), Some(output_index)) => (fragment_id, output_index),
+                    _ => return Err(Error::UtxoPointerMissing),
+                };
+                let response = args
+                    .client()?
+                    .get(&["v0", "utxo", &fragment_id, &output_index.to_string()])
+                    .execute()?
+                    .json()?;
+                let formatted = output_format.format_json(response)?;
+                println!("{}", formatted);
+                Ok(())
+            }
+            Subcommand::List {
+                filter,
+                output_format,
+                args,
+            } => {
+                let response = args
+                    .client()?
+                    .get(&["v0", "utxo"])
+                    .query(&UTxOsQuery::from(filter))
+                    .execute()?
+                    .json()?;
+                let formatted = output_format.format_json(response)?;
+                println!("{}", formatted);
+                Ok(())
+            }
+        }
     }
 }
@@ -2,6 +2,7 @@ use crate::jcli_lib::{
     rest::{Error, RestArgs},
     utils::OutputFormat,
 };
+use serde_json::Value;
 use structopt::StructOpt;
 
 #[derive(StructOpt)]
@@ -11,6 +12,10 @@ pub enum Plans {
     Get {
         #[structopt(flatten)]
         args: RestArgs,
+        /// Include the tally of each proposal, needed to decrypt a private
+        /// tally or to verify the results with `votes tally verify`
+        #[structopt(long)]
+        with_tally: bool,
         #[structopt(flatten)]
         output_format: OutputFormat,
     },
@@ -20,15 +25,34 @@ impl Plans {
     pub fn exec(self) -> Result<(), Error> {
         let Plans::Get {
             args,
+            with_tally,
             output_format,
         } = self;
-        let response = args
+        let mut response = args
             .client()?
             .get(&["v0", "vote", "active", "plans"])
             .execute()?
             .json()?;
+        if !with_tally {
+            remove_tallies(&mut response);
+        }
         let formatted = output_format.format_json(response)?;
         println!("{}", formatted);
         Ok(())
     }
 }
+
+fn remove_tallies(vote_plans: &mut Value) {
+    let proposals = vote_plans
+        .as_array_mut()
+        .into_iter()
+        .flatten()
+        .filter_map(|vote_plan| vote_plan.get_mut("proposals"))
+        .filter_map(Value::as_array_mut)
+        .flatten();
+    for proposal in proposals {
+        if let Some(proposal) = proposal.as_object_mut() {
+            proposal.remove("tally");
+        }
+    }
+}
@@ -16,6 +16,11 @@ pub struct AddOutput {
     /// the value
     #[structopt(name = "VALUE")]
     pub value: interfaces::Value,
+
+    /// the output cannot be spent before this block date (epoch.slot),
+    /// only available for UTxO addresses
+    #[structopt(long = "locked-until")]
+    pub locked_until: Option<interfaces::BlockDate>,
 }
 
 impl AddOutput {
@@ -25,6 +30,7 @@ impl AddOutput {
         transaction.add_output(Output {
             address: self.address.into(),
             value: self.value.into(),
+            locked_until: self.locked_until.map(Into::into),
         })?;
 
         self.common.store(&transaction)
@@ -1,5 +1,5 @@
 use crate::jcli_lib::{
-    certificate::read_input,
+    certificate::{read_input, read_pool_registration},
     transaction::{common, Error},
 };
 use std::path::PathBuf;
@@ -13,6 +13,11 @@ pub struct Auth {
     /// path to the file with the signing key
     #[structopt(short = "k", long = "key")]
     pub signing_keys: Vec<PathBuf>,
+    /// stake pool registration certificate currently in effect, used to match
+    /// the signing keys with the pool owners when authenticating a stake pool
+    /// retirement or update. Without it, keys are expected in the owners order.
+    #[structopt(long = "pool-registration")]
+    pub pool_registration: Option<PathBuf>,
 }
 
 impl Auth {
@@ -32,7 +37,14 @@ impl Auth {
             .collect();
         let keys_str = keys_str?;
 
-        transaction.set_auth(&keys_str)?;
+        let pool_registration = self
+            .pool_registration
+            .as_deref()
+            .map(|path| read_pool_registration(Some(path)))
+            .transpose()
+            .map_err(|e| Error::CertificateError { error: e })?;
+
+        transaction.set_auth(&keys_str, pool_registration.as_ref())?;
 
         self.common.store(&transaction)
     }
@@ -0,0 +1,79 @@
+use crate::jcli_lib::transaction::{
+    common,
+    mk_witness::{WitnessRequest, WitnessType},
+    Error,
+};
+use chain_impl_mockchain::header::HeaderId;
+use std::path::PathBuf;
+use structopt::StructOpt;
+
+#[derive(StructOpt)]
+#[structopt(rename_all = "kebab-case")]
+pub struct DataForWitness {
+    #[structopt(flatten)]
+    pub common: common::CommonTransaction,
+
+    /// also write in this JSON file a request for the witness, with the data
+    /// to sign and everything else `make-witness --from-request` needs
+    /// besides the signing key, e.g. to make the witness on an offline
+    /// machine
+    #[structopt(long)]
+    pub request: Option<PathBuf>,
+
+    /// the type of witness to request: account, UTxO or Legacy UtxO
+    #[structopt(long = "type", parse(try_from_str), requires = "request")]
+    pub witness_type: Option<WitnessType>,
+
+    /// the hash of the block0, the first block of the blockchain
+    #[structopt(long, parse(try_from_str), requires = "request")]
+    pub genesis_block_hash: Option<HeaderId>,
+
+    /// the counter value of the account, required by account witnesses
+    #[structopt(long, requires = "request")]
+    pub account_spending_counter: Option<u32>,
+
+    /// lane to use for the spending counter.
+    /// If unsure, leave blank and lane 0 will be used
+    #[structopt(long, requires = "request")]
+    pub account_spending_counter_lane: Option<usize>,
+}
+
+impl From<common::CommonTransaction> for DataForWitness {
+    fn from(common: common::CommonTransaction) -> Self {
+        DataForWitness {
+            common,
+            request: None,
+            witness_type: None,
+            genesis_block_hash: None,
+            account_spending_counter: None,
+            account_spending_counter_lane: None,
+        }
+    }
+}
+
+impl DataForWitness {
+    pub fn exec(self) -> Result<(), Error> {
+        let id = self.common.load()?.transaction_sign_data_hash()?;
+        if let Some(path) = &self.request {
+            let request = WitnessRequest {
+                transaction_id: id.clone(),
+                witness_type: self
+                    .witness_type
+                    .ok_or(Error::WitnessRequestArgumentMissing("--type"))?,
+                genesis_block_hash: self
+                    .genesis_block_hash
+                    .ok_or(Error::WitnessRequestArgumentMissing("--genesis-block-hash"))?,
+                account_spending_counter: self.account_spending_counter,
+                account_spending_counter_lane: self.account_spending_counter_lane,
+            };
+            // fail now rather than on the offline machine
+            if request.witness_type == WitnessType::Account && request.spending_counter()?.is_none()
+            {
+                return Err(Error::MakeWitnessAccountCounterMissing);
+            }
+            request.store(path)?;
+        }
+        println!("{}", id);
+        Ok(())
+    }
+}
@@ -1,9 +1,17 @@
 use crate::{
-    jcli_lib::transaction::{common, Error},
+    jcli_lib::{
+        rest::RestArgs,
+        transaction::{common, simplified::common_fee_from_settings, Error},
+    },
+    rest,
     transaction::staging::Staging,
 };
-use chain_impl_mockchain::transaction::OutputPolicy;
+use chain_impl_mockchain::{
+    fee::FeeAlgorithm,
+    transaction::{Balance, OutputPolicy},
+};
 use jormungandr_lib::interfaces;
+use reqwest::Url;
 use structopt::StructOpt;
 
 #[derive(StructOpt)]
@@ -15,15 +23,38 @@ pub struct Finalize {
     #[structopt(flatten)]
     pub fee: common::CommonFees,
 
-    /// Set the change in the given address
+    /// fetch the fee parameters from the settings of the node at the given
+    /// API address, instead of taking them from the fee options
+    #[structopt(long, name = "NODE_API_ADDRESS")]
+    pub fees_from_node: Option<Url>,
+
+    /// add an output sending what is left of the inputs, after the outputs
+    /// and the exact fee, to the given address
+    #[structopt(long = "change", name = "CHANGE_ADDRESS", conflicts_with = "CHANGE")]
     pub change: Option<interfaces::Address>,
+
+    /// Set the change in the given address.
+    /// DEPRECATED: use '--change' instead
+    #[structopt(name = "CHANGE")]
+    pub change_positional: Option<interfaces::Address>,
 }
 
 impl Finalize {
     pub fn exec(self) -> Result<(), Error> {
         let mut transaction = self.common.load()?;
 
-        finalize(self.fee, self.change, &mut transaction)?;
+        let fee = match self.fees_from_node {
+            Some(host) => {
+                let settings = rest::v0::settings::request_settings(RestArgs::from_host(host))?;
+                common_fee_from_settings(&settings)
+            }
+            None => self.fee,
+        };
+        finalize(
+            fee,
+            self.change.or(self.change_positional),
+            &mut transaction,
+        )?;
 
         self.common.store(&transaction)?;
         Ok(())
@@ -38,8 +69,37 @@ pub fn finalize(
     let fee_algo = fee.linear_fee();
     let output_policy = match change {
         None => OutputPolicy::Forget,
-        Some(change) => OutputPolicy::One(change.into()),
+        Some(change) => {
+            check_change(transaction, &fee_algo)?;
+            OutputPolicy::One(change.into())
+        }
     };
     let _balance = transaction.balance_inputs_outputs(&fee_algo, output_policy)?;
     Ok(())
 }
+
+/// Checks that the inputs pay for the outputs and the fee, and leave enough
+/// to pay for a change output if they are not exactly balanced already.
+fn check_change(transaction: &Staging, fee_algo: &impl FeeAlgorithm) -> Result<(), Error> {
+    if let Balance::Zero = transaction.balance(fee_algo)? {
+        return Ok(());
+    }
+
+    let inputs = transaction.total_input()?;
+    let outputs = transaction.total_output()?;
+    let fee = transaction.fees_with_extra_outputs(fee_algo, 1);
+    let spent = (outputs + fee)?;
+    if inputs > spent {
+        return Ok(());
+    }
+
+    match (inputs - (outputs + transaction.fees(fee_algo))?).ok() {
+        Some(left) => Err(Error::TxChangeNotPayable { left, fee }),
+        None => Err(Error::TxNotEnoughInputForChange {
+            inputs,
+            outputs,
+            fee,
+            missing: (spent - inputs)?,
+        }),
+    }
+}
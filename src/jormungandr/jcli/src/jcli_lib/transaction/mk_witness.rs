@@ -11,27 +11,36 @@ use chain_impl_mockchain::{
     key::EitherEd25519SecretKey,
     transaction::{TransactionSignDataHash, Witness},
 };
-use std::{io::Write, path::PathBuf};
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt,
+    io::Write,
+    path::{Path, PathBuf},
+};
 use structopt::StructOpt;
 
 #[derive(StructOpt)]
 #[structopt(rename_all = "kebab-case")]
 pub struct MkWitness {
-    /// the Transaction ID of the witness to sign
-    #[structopt(name = "TRANSACTION_ID")]
-    pub sign_data_hash: TransactionSignDataHash,
+    /// the Transaction ID of the witness to sign, omitted with `--from-request`
+    #[structopt(name = "TRANSACTION_ID", required_unless = "from-request")]
+    pub sign_data_hash: Option<TransactionSignDataHash>,
 
     /// the file path to the file to write the witness in.
     /// If omitted it will be printed to the standard output.
     pub output: Option<PathBuf>,
 
     /// the type of witness to build: account, UTxO or Legacy UtxO
-    #[structopt(long = "type", parse(try_from_str))]
-    pub witness_type: WitnessType,
+    #[structopt(long = "type", parse(try_from_str), required_unless = "from-request")]
+    pub witness_type: Option<WitnessType>,
 
     /// the hash of the block0, the first block of the blockchain
-    #[structopt(long = "genesis-block-hash", parse(try_from_str))]
-    pub genesis_block_hash: HeaderId,
+    #[structopt(
+        long = "genesis-block-hash",
+        parse(try_from_str),
+        required_unless = "from-request"
+    )]
+    pub genesis_block_hash: Option<HeaderId>,
 
     /// value is mandatory if `--type=account`. It is the counter value for
     /// every time the account is being utilized.
@@ -44,17 +53,46 @@ pub struct MkWitness {
     #[structopt(long)]
     pub account_spending_counter_lane: Option<usize>,
 
+    /// read the Transaction ID, the type of witness, the genesis block hash
+    /// and the spending counter from the JSON file written by
+    /// `data-for-witness --request`, e.g. to sign on an offline machine.
+    /// The Transaction ID and the options above are not accepted then, so
+    /// the signing key is read from the standard input and the witness is
+    /// printed to the standard output.
+    #[structopt(
+        long,
+        conflicts_with_all = &[
+            "TRANSACTION_ID",
+            "witness-type",
+            "genesis-block-hash",
+            "account-spending-counter",
+            "account-spending-counter-lane",
+        ]
+    )]
+    pub from_request: Option<PathBuf>,
+
     /// the file path to the file to read the signing key from.
     /// If omitted it will be read from the standard input.
     pub secret: Option<PathBuf>,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum WitnessType {
     UTxO,
     OldUTxO,
     Account,
 }
 
+impl fmt::Display for WitnessType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WitnessType::UTxO => f.write_str("utxo"),
+            WitnessType::OldUTxO => f.write_str("legacy-utxo"),
+            WitnessType::Account => f.write_str("account"),
+        }
+    }
+}
+
 impl std::str::FromStr for WitnessType {
     type Err = &'static str;
 
@@ -68,11 +106,56 @@ impl std::str::FromStr for WitnessType {
     }
 }
 
-impl MkWitness {
-    pub fn exec(self) -> Result<(), Error> {
-        let secret_key = read_ed25519_secret_key_from_file(&self.secret)?;
-        let sc = self
-            .account_spending_counter
+/// Everything needed to make a witness besides the signing key, passed from
+/// `data-for-witness --request` to `make-witness --from-request` so that the
+/// witness can be made on another machine.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WitnessRequest {
+    #[serde(with = "as_string")]
+    pub transaction_id: TransactionSignDataHash,
+    #[serde(rename = "type", with = "as_string")]
+    pub witness_type: WitnessType,
+    #[serde(with = "as_string")]
+    pub genesis_block_hash: HeaderId,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub account_spending_counter: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub account_spending_counter_lane: Option<usize>,
+}
+
+impl WitnessRequest {
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let path = path.as_ref();
+        let reader =
+            io::open_file_read(&Some(path)).map_err(|source| Error::WitnessRequestReadFailed {
+                source,
+                path: path.to_path_buf(),
+            })?;
+        serde_json::from_reader(reader).map_err(|source| Error::WitnessRequestMalformed {
+            source,
+            path: path.to_path_buf(),
+        })
+    }
+
+    pub fn store<P: AsRef<Path>>(&self, path: P) -> Result<(), Error> {
+        let path = path.as_ref();
+        let mut writer = io::open_file_write(&Some(path)).map_err(|source| {
+            Error::WitnessRequestWriteFailed {
+                source,
+                path: path.to_path_buf(),
+            }
+        })?;
+        serde_json::to_writer_pretty(&mut writer, self)
+            .map_err(std::io::Error::from)
+            .and_then(|()| writeln!(writer))
+            .map_err(|source| Error::WitnessRequestWriteFailed {
+                source,
+                path: path.to_path_buf(),
+            })
+    }
+
+    pub fn spending_counter(&self) -> Result<Option<SpendingCounter>, Error> {
+        self.account_spending_counter
             .map(|counter| {
                 let lane = self.account_spending_counter_lane.unwrap_or_default();
                 if lane > SpendingCounterIncreasing::LANES {
@@ -83,17 +166,69 @@ impl MkWitness {
                 }
                 Ok(SpendingCounter::new(lane, counter).unwrap())
             })
-            .transpose()?;
+            .transpose()
+    }
+}
+
+// the hashes and the witness type are written the way they are passed on
+// the command line
+mod as_string {
+    use serde::{de::Error as _, Deserialize, Deserializer, Serializer};
+    use std::{fmt::Display, str::FromStr};
+
+    pub fn serialize<T: Display, S: Serializer>(
+        value: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(value)
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: FromStr,
+        T::Err: Display,
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(D::Error::custom)
+    }
+}
+
+impl MkWitness {
+    pub fn exec(self) -> Result<(), Error> {
+        let request = self.request()?;
+        let secret_key = read_ed25519_secret_key_from_file(&self.secret)?;
         let witness = make_witness(
-            &self.witness_type,
-            &self.genesis_block_hash,
-            &self.sign_data_hash,
-            sc,
+            &request.witness_type,
+            &request.genesis_block_hash,
+            &request.transaction_id,
+            request.spending_counter()?,
             &secret_key,
         )?;
         self.write_witness(&witness)
     }
 
+    fn request(&self) -> Result<WitnessRequest, Error> {
+        if let Some(path) = &self.from_request {
+            return WitnessRequest::load(path);
+        }
+        Ok(WitnessRequest {
+            transaction_id: self
+                .sign_data_hash
+                .clone()
+                .ok_or(Error::WitnessRequestArgumentMissing("TRANSACTION_ID"))?,
+            witness_type: self
+                .witness_type
+                .ok_or(Error::WitnessRequestArgumentMissing("--type"))?,
+            genesis_block_hash: self
+                .genesis_block_hash
+                .ok_or(Error::WitnessRequestArgumentMissing("--genesis-block-hash"))?,
+            account_spending_counter: self.account_spending_counter,
+            account_spending_counter_lane: self.account_spending_counter_lane,
+        })
+    }
+
     fn write_witness(&self, witness: &Witness) -> Result<(), Error> {
         let mut writer =
             io::open_file_write(&self.output).map_err(|source| Error::WitnessFileWriteFailed {
@@ -6,6 +6,7 @@ pub mod add_output;
 mod add_witness;
 mod auth;
 mod common;
+mod data_for_witness;
 pub mod finalize;
 mod info;
 mod mk_witness;
@@ -68,7 +69,7 @@ pub enum Transaction {
     Id(common::CommonTransaction),
     /// get the data to sign from the given transaction
     /// (if the transaction is edited, the returned value will change)
-    DataForWitness(common::CommonTransaction),
+    DataForWitness(data_for_witness::DataForWitness),
     /// display the info regarding a given transaction
     Info(info::Info),
     /// create witnesses
@@ -145,6 +146,26 @@ pub enum Error {
     },
     #[error("could not serialize witness data")]
     WitnessFileSerializationFailed(#[source] WriteError),
+    #[error("could not read witness request file '{path}'")]
+    WitnessRequestReadFailed {
+        #[source]
+        source: std::io::Error,
+        path: PathBuf,
+    },
+    #[error("could not parse witness request file '{path}'")]
+    WitnessRequestMalformed {
+        #[source]
+        source: serde_json::Error,
+        path: PathBuf,
+    },
+    #[error("could not write witness request file '{path}'")]
+    WitnessRequestWriteFailed {
+        #[source]
+        source: std::io::Error,
+        path: PathBuf,
+    },
+    #[error("making the witness requires passing {0}")]
+    WitnessRequestArgumentMissing(StaticStr),
     #[error("could not write info file '{path}'")]
     InfoFileWriteFailed {
         #[source]
@@ -234,6 +255,19 @@ pub enum Error {
 
     #[error("cannot finalize the payload without a validity end date set")]
     CannotFinalizeWithoutValidUntil,
+
+    #[error("not enough input value: the inputs total {inputs}, but the outputs total {outputs} and the fee with the change output is {fee}, {missing} is missing")]
+    TxNotEnoughInputForChange {
+        inputs: chain::value::Value,
+        outputs: chain::value::Value,
+        fee: chain::value::Value,
+        missing: chain::value::Value,
+    },
+    #[error("the {left} left after the outputs and the fee does not cover the fee of a change output, which raises the fee to {fee}")]
+    TxChangeNotPayable {
+        left: chain::value::Value,
+        fee: chain::value::Value,
+    },
 }
 
 /*
@@ -258,7 +292,7 @@ impl Transaction {
             Transaction::Seal(seal) => seal.exec(),
             Transaction::FragmentId(common) => display_fragment_id(common),
             Transaction::Id(common) => display_id(common),
-            Transaction::DataForWitness(common) => display_data_for_witness(common),
+            Transaction::DataForWitness(data_for_witness) => data_for_witness.exec(),
             Transaction::Info(info) => info.exec(),
             Transaction::MakeWitness(mk_witness) => mk_witness.exec(),
             Transaction::Auth(auth) => auth.exec(),
@@ -271,13 +305,7 @@ impl Transaction {
 
 fn display_id(common: common::CommonTransaction) -> Result<(), Error> {
     eprintln!("DEPRECATED: use 'data-for-witness' instead");
-    display_data_for_witness(common)
-}
-
-fn display_data_for_witness(common: common::CommonTransaction) -> Result<(), Error> {
-    let id = common.load()?.transaction_sign_data_hash()?;
-    println!("{}", id);
-    Ok(())
+    data_for_witness::DataForWitness::from(common).exec()
 }
 
 fn display_fragment_id(common: common::CommonTransaction) -> Result<(), Error> {
@@ -121,7 +121,7 @@ fn create_receiver_secret_key_and_address(
     Ok((sk, address))
 }
 
-fn common_fee_from_settings(settings: &SettingsDto) -> CommonFees {
+pub(super) fn common_fee_from_settings(settings: &SettingsDto) -> CommonFees {
     let fees = settings.fees.clone();
     CommonFees {
         constant: fees.constant,
@@ -185,10 +185,7 @@ pub fn make_transaction(
     transaction.add_account(sender_account.clone(), transfer_value)?;
 
     // add output
-    transaction.add_output(Output {
-        address: receiver_address.into(),
-        value: value.into(),
-    })?;
+    transaction.add_output(Output::from_address(receiver_address.into(), value.into()))?;
 
     transaction.set_expiry_date(valid_until)?;
 
@@ -10,7 +10,9 @@ use crate::jcli_lib::{
 use chain_addr::{Address, Kind};
 use chain_impl_mockchain::{
     self as chain,
-    certificate::{Certificate, CertificatePayload, PoolSignature, SignedCertificate},
+    certificate::{
+        Certificate, CertificatePayload, PoolRegistration, PoolSignature, SignedCertificate,
+    },
     fee::FeeAlgorithm,
     fragment::Fragment,
     transaction::{
@@ -167,7 +169,11 @@ impl Staging {
         Ok(())
     }
 
-    pub fn set_auth(&mut self, keys: &[String]) -> Result<(), Error> {
+    pub fn set_auth(
+        &mut self,
+        keys: &[String],
+        pool_registration: Option<&PoolRegistration>,
+    ) -> Result<(), Error> {
         if self.kind != StagingKind::Sealed {
             return Err(Error::TxKindToSealInvalid { kind: self.kind });
         }
@@ -203,18 +209,16 @@ impl Staging {
                     self.extra_authed = Some(sc.into())
                 }
                 Certificate::PoolRetirement(s) => {
-                    let pool_reg = None; // TODO eventually ask for optional extra registration cert to do a better job
                     let builder = self.builder_after_witness(TxBuilder::new().set_payload(&s))?;
-                    let sc = pool_owner_sign(s, pool_reg, keys, builder, |p, pos| {
+                    let sc = pool_owner_sign(s, pool_registration, keys, builder, |p, pos| {
                         SignedCertificate::PoolRetirement(p, PoolSignature::Owners(pos))
                     })
                     .map_err(|e| Error::CertificateError { error: e })?;
                     self.extra_authed = Some(sc.into())
                 }
                 Certificate::PoolUpdate(s) => {
-                    let pool_reg = None; // TODO eventually ask for optional extra registration cert to do a better job
                     let builder = self.builder_after_witness(TxBuilder::new().set_payload(&s))?;
-                    let sc = pool_owner_sign(s, pool_reg, keys, builder, |p, pos| {
+                    let sc = pool_owner_sign(s, pool_registration, keys, builder, |p, pos| {
                         SignedCertificate::PoolUpdate(p, PoolSignature::Owners(pos))
                     })
                     .map_err(|e| Error::CertificateError { error: e })?;
@@ -288,6 +292,12 @@ impl Staging {
                     self.extra_authed = Some(sc.into())
                 }
                 Certificate::MintToken(_) => unreachable!(),
+                Certificate::Anchor(_) => unreachable!(),
+                Certificate::MultisigDeclarationUpdate(_) => {
+                    return Err(Error::CertificateError {
+                        error: certificate::Error::MultisigDeclarationUpdateSigningUnsupported,
+                    })
+                }
             },
         };
         self.kind = StagingKind::Authed;
@@ -411,6 +421,12 @@ impl Staging {
                 Certificate::EvmMapping(vt) => {
                     self.finalize_payload(&vt, fee_algorithm, output_policy)
                 }
+                Certificate::Anchor(anchor) => {
+                    self.finalize_payload(&anchor, fee_algorithm, output_policy)
+                }
+                Certificate::MultisigDeclarationUpdate(mdu) => {
+                    self.finalize_payload(&mdu, fee_algorithm, output_policy)
+                }
 
                 Certificate::OwnerStakeDelegation(c) => {
                     let balance = self.finalize_payload(&c, fee_algorithm, output_policy)?;
@@ -521,6 +537,9 @@ impl Staging {
                         Certificate::MintToken(mint_token) => {
                             self.make_fragment(&mint_token, &(), Fragment::MintToken)
                         }
+                        Certificate::Anchor(anchor) => {
+                            self.make_fragment(&anchor, &(), Fragment::Anchor)
+                        }
                         _ => unreachable!(),
                     },
                 }
@@ -560,6 +579,9 @@ impl Staging {
                     SignedCertificate::EvmMapping(vt, a) => {
                         self.make_fragment(&vt, &a, Fragment::EvmMapping)
                     }
+                    SignedCertificate::MultisigDeclarationUpdate(mdu, a) => {
+                        self.make_fragment(&mdu, &a, Fragment::MultisigDeclarationUpdate)
+                    }
                 }
             }
         }
@@ -627,6 +649,12 @@ impl Staging {
                 Certificate::EvmMapping(vt) => {
                     self.transaction_sign_data_hash_on(TxBuilder::new().set_payload(&vt))
                 }
+                Certificate::Anchor(anchor) => {
+                    self.transaction_sign_data_hash_on(TxBuilder::new().set_payload(&anchor))
+                }
+                Certificate::MultisigDeclarationUpdate(mdu) => {
+                    self.transaction_sign_data_hash_on(TxBuilder::new().set_payload(&mdu))
+                }
             },
         };
 
@@ -676,6 +704,15 @@ impl Staging {
     }
 
     pub fn fees(&self, fee_algorithm: &impl FeeAlgorithm) -> Value {
+        self.fees_with_extra_outputs(fee_algorithm, 0)
+    }
+
+    /// Fees of the transaction once the given number of outputs is added
+    pub fn fees_with_extra_outputs(
+        &self,
+        fee_algorithm: &impl FeeAlgorithm,
+        extra_outputs: u8,
+    ) -> Value {
         let cert_extra = self.extra_authed.clone().map(|cert| cert.strip_auth());
         let cert_payload = cert_extra
             .as_ref()
@@ -683,7 +720,7 @@ impl Staging {
             .map(|cert| CertificatePayload::from(&cert.0));
         let cert_slice = cert_payload.as_ref().map(CertificatePayload::as_slice);
         let inputs_count = self.inputs().len() as u8;
-        let outputs_count = self.outputs().len() as u8;
+        let outputs_count = self.outputs().len() as u8 + extra_outputs;
         fee_algorithm.calculate(cert_slice, inputs_count, outputs_count)
     }
 
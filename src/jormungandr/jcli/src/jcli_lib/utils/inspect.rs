@@ -0,0 +1,159 @@
+use bech32::{FromBase32, Variant};
+use chain_addr::{Address, Discrimination, Kind};
+use chain_crypto::{
+    bech32::{Bech32, Error as Bech32Error},
+    AsymmetricKey, AsymmetricPublicKey, Ed25519, Ed25519Bip32, Ed25519Extended, PublicKey,
+    RistrettoGroup2HashDh, SecretKey, Signature, SumEd25519_12, VerificationAlgorithm,
+};
+use chain_vote::{
+    committee::{MemberCommunicationPublicKey, MemberSecretKey},
+    ElectionPublicKey, MemberCommunicationKey, MemberPublicKey,
+};
+use structopt::StructOpt;
+
+/// Length of the blake2b256 digests, used e.g. for the stake pool ids
+const DIGEST_LEN: usize = 32;
+
+#[derive(StructOpt)]
+pub struct InspectArgs {
+    /// the bech32 string to inspect
+    #[structopt(name = "BECH32")]
+    bech32: String,
+}
+
+impl InspectArgs {
+    pub fn exec(self) -> Result<(), super::Error> {
+        let (hrp, data, variant) = bech32::decode(&self.bech32)?;
+        let payload = Vec::<u8>::from_base32(&data)?;
+
+        println!("hrp: {}", hrp);
+        match variant {
+            Variant::Bech32 => println!("variant: bech32"),
+            Variant::Bech32m => println!("variant: bech32m"),
+        }
+        println!("payload length: {} bytes", payload.len());
+
+        match describe_key(&hrp) {
+            Some((description, parse)) => match parse(&self.bech32) {
+                Ok(()) => println!("type: {}", description),
+                Err(err) => println!("type: {} (invalid: {})", description, err),
+            },
+            None if is_digest(&hrp) => {
+                if payload.len() == DIGEST_LEN {
+                    println!("type: blake2b256 digest, e.g. a stake pool id");
+                } else {
+                    println!(
+                        "type: blake2b256 digest (invalid: expected {} bytes)",
+                        DIGEST_LEN
+                    );
+                }
+                println!("hex: {}", hex::encode(&payload));
+            }
+            None => match Address::from_bytes(&payload) {
+                Ok(address) => describe_address(&address),
+                Err(_) => println!("type: unknown"),
+            },
+        }
+        Ok(())
+    }
+}
+
+type Parse = fn(&str) -> Result<(), Bech32Error>;
+
+fn parses<T: Bech32>(bech32: &str) -> Result<(), Bech32Error> {
+    T::try_from_bech32_str(bech32).map(|_| ())
+}
+
+fn describe_key(hrp: &str) -> Option<(&'static str, Parse)> {
+    let described: (&'static str, Parse) = match hrp {
+        Ed25519::PUBLIC_BECH32_HRP => ("ed25519 public key", parses::<PublicKey<Ed25519>>),
+        Ed25519::SECRET_BECH32_HRP => ("ed25519 secret key", parses::<SecretKey<Ed25519>>),
+        Ed25519::SIGNATURE_BECH32_HRP => ("ed25519 signature", parses::<Signature<(), Ed25519>>),
+        Ed25519Extended::SECRET_BECH32_HRP => (
+            "ed25519 extended secret key",
+            parses::<SecretKey<Ed25519Extended>>,
+        ),
+        Ed25519Bip32::PUBLIC_BECH32_HRP => (
+            "ed25519 bip32 extended public key",
+            parses::<PublicKey<Ed25519Bip32>>,
+        ),
+        Ed25519Bip32::SECRET_BECH32_HRP => (
+            "ed25519 bip32 extended secret key",
+            parses::<SecretKey<Ed25519Bip32>>,
+        ),
+        Ed25519Bip32::SIGNATURE_BECH32_HRP => (
+            "ed25519 bip32 signature",
+            parses::<Signature<(), Ed25519Bip32>>,
+        ),
+        SumEd25519_12::PUBLIC_BECH32_HRP => (
+            "KES public key, e.g. of a stake pool",
+            parses::<PublicKey<SumEd25519_12>>,
+        ),
+        SumEd25519_12::SECRET_BECH32_HRP => (
+            "KES secret key, e.g. of a stake pool",
+            parses::<SecretKey<SumEd25519_12>>,
+        ),
+        SumEd25519_12::SIGNATURE_BECH32_HRP => {
+            ("KES signature", parses::<Signature<(), SumEd25519_12>>)
+        }
+        RistrettoGroup2HashDh::PUBLIC_BECH32_HRP => (
+            "VRF public key, e.g. of a stake pool",
+            parses::<PublicKey<RistrettoGroup2HashDh>>,
+        ),
+        RistrettoGroup2HashDh::SECRET_BECH32_HRP => (
+            "VRF secret key, e.g. of a stake pool",
+            parses::<SecretKey<RistrettoGroup2HashDh>>,
+        ),
+        ElectionPublicKey::BECH32_HRP => (
+            "election public key, used to encrypt private votes",
+            parses::<ElectionPublicKey>,
+        ),
+        MemberPublicKey::BECH32_HRP => (
+            "vote committee member public key",
+            parses::<MemberPublicKey>,
+        ),
+        MemberSecretKey::BECH32_HRP => (
+            "vote committee member secret key",
+            parses::<MemberSecretKey>,
+        ),
+        MemberCommunicationPublicKey::BECH32_HRP => (
+            "vote committee member communication public key",
+            parses::<MemberCommunicationPublicKey>,
+        ),
+        MemberCommunicationKey::BECH32_HRP => (
+            "vote committee member communication secret key",
+            parses::<MemberCommunicationKey>,
+        ),
+        _ => return None,
+    };
+    Some(described)
+}
+
+fn is_digest(hrp: &str) -> bool {
+    matches!(hrp, "blake2b" | "blake2b256")
+}
+
+fn describe_address(address: &Address) {
+    let Address(discrimination, kind) = address;
+    match kind {
+        Kind::Single(_) => println!("type: single address"),
+        Kind::Group(_, _) => println!("type: group address"),
+        Kind::Account(_) => println!("type: account address"),
+        Kind::Multisig(_) => println!("type: multisig address"),
+        Kind::Script(_) => println!("type: script address"),
+    }
+    match discrimination {
+        Discrimination::Production => println!("discrimination: production"),
+        Discrimination::Test => println!("discrimination: testing"),
+    }
+    match kind {
+        Kind::Single(key) => println!("public key: {}", key.to_bech32_str()),
+        Kind::Group(key, group) => {
+            println!("public key: {}", key.to_bech32_str());
+            println!("group key: {}", group.to_bech32_str());
+        }
+        Kind::Account(key) => println!("account: {}", key.to_bech32_str()),
+        Kind::Multisig(id) => println!("multisig identifier: {}", hex::encode(id)),
+        Kind::Script(id) => println!("script identifier: {}", hex::encode(id)),
+    }
+}
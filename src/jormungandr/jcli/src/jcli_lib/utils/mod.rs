@@ -1,4 +1,5 @@
 pub mod account_id;
+mod inspect;
 pub mod io;
 pub mod key_parser;
 pub mod output_file;
@@ -14,6 +15,9 @@ use thiserror::Error;
 pub enum Utils {
     /// convert a bech32 with hrp n into a bech32 with prefix m
     Bech32Convert(Bech32ConvertArgs),
+    /// report the HRP and payload length of a bech32 string, along with
+    /// what it encodes when recognized
+    Inspect(inspect::InspectArgs),
 }
 
 #[derive(StructOpt)]
@@ -39,6 +43,7 @@ impl Utils {
             Utils::Bech32Convert(convert_args) => {
                 convert_prefix(convert_args.from_bech32, convert_args.new_hrp)
             }
+            Utils::Inspect(inspect_args) => inspect_args.exec(),
         }
     }
 }
@@ -127,6 +127,17 @@ impl TryFrom<Vec<MemberVotePlanShares>> for VotePlanDecryptShares {
     }
 }
 
+impl VotePlanDecryptShares {
+    /// Checks there are shares for all proposals, from at least `threshold` members
+    pub fn check(&self, proposals: usize, threshold: Option<usize>) -> Result<(), SharesError> {
+        let members = self.0.first().map_or(0, Vec::len);
+        if self.0.len() != proposals || members < threshold.unwrap_or(1) {
+            return Err(SharesError::InsufficientShares);
+        }
+        Ok(())
+    }
+}
+
 impl TryFrom<VotePlanDecryptShares> for Vec<Vec<chain_vote::TallyDecryptShare>> {
     type Error = SharesError;
     fn try_from(vote_plan: VotePlanDecryptShares) -> Result<Self, Self::Error> {
@@ -149,10 +160,6 @@ pub fn read_vote_plan_shares_from_file<P: AsRef<Path>>(
 ) -> Result<VotePlanDecryptShares, SharesError> {
     let vote_plan_shares: VotePlanDecryptShares =
         serde_json::from_reader(io::open_file_read(&share_path)?)?;
-    if vote_plan_shares.0.len() != proposals || vote_plan_shares.0[0].len() < threshold.unwrap_or(1)
-    {
-        return Err(SharesError::InsufficientShares);
-    }
-
+    vote_plan_shares.check(proposals, threshold)?;
     Ok(vote_plan_shares)
 }
@@ -87,6 +87,10 @@ pub enum Error {
     },
     #[error(transparent)]
     MergeError(#[from] tally::merge_results::Error),
+    #[error(transparent)]
+    VerifyError(#[from] tally::verify::Error),
+    #[error(transparent)]
+    CertificateError(#[from] crate::jcli_lib::certificate::Error),
 }
 
 #[derive(StructOpt)]
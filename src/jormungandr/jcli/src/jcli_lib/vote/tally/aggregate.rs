@@ -0,0 +1,61 @@
+use super::{decrypt_tally::decrypt_vote_plan, Error};
+use crate::jcli_lib::utils::{
+    io,
+    vote::{self, MemberVotePlanShares, VotePlanDecryptShares},
+    OutputFormat,
+};
+use jormungandr_lib::crypto::hash::Hash;
+use std::{
+    convert::{TryFrom, TryInto},
+    path::PathBuf,
+};
+use structopt::StructOpt;
+
+/// Aggregate the decryption shares of the committee members and decrypt
+/// all proposals in a vote plan.
+///
+/// This is the same as `merge-shares` followed by `decrypt-results`.
+#[derive(StructOpt)]
+#[structopt(rename_all = "kebab-case")]
+pub struct TallyAggregate {
+    /// The path to json-encoded vote plan to decrypt. If this parameter is not
+    /// specified, the vote plan will be read from the standard input.
+    #[structopt(long)]
+    vote_plan: Option<PathBuf>,
+    /// The id of the vote plan to decrypt.
+    /// Can be left unspecified if there is only one vote plan in the input
+    #[structopt(long)]
+    vote_plan_id: Option<Hash>,
+    /// The minimum number of shares needed for decryption
+    #[structopt(long, default_value = "3")]
+    threshold: usize,
+    /// The paths to the decryption shares of each committee member, as
+    /// created by `decryption-shares`
+    #[structopt(required = true)]
+    shares: Vec<PathBuf>,
+    #[structopt(flatten)]
+    output_format: OutputFormat,
+}
+
+impl TallyAggregate {
+    pub fn exec(&self) -> Result<(), Error> {
+        let mut vote_plan =
+            vote::get_vote_plan_by_id(self.vote_plan.as_ref(), self.vote_plan_id.as_ref())?;
+        let member_shares = self
+            .shares
+            .iter()
+            .map(|path| Ok(serde_json::from_reader(io::open_file_read(&Some(path))?)?))
+            .collect::<Result<Vec<MemberVotePlanShares>, Error>>()?;
+        let vote_plan_shares = VotePlanDecryptShares::try_from(member_shares)?;
+        vote_plan_shares.check(vote_plan.proposals.len(), Some(self.threshold))?;
+
+        decrypt_vote_plan(&mut vote_plan, vote_plan_shares.try_into()?)?;
+
+        let output = self
+            .output_format
+            .format_json(serde_json::to_value(vote_plan)?)?;
+        println!("{}", output);
+
+        Ok(())
+    }
+}
@@ -6,7 +6,7 @@ use crate::jcli_lib::utils::{
 use chain_vote::tally::{batch_decrypt, EncryptedTally};
 use jormungandr_lib::{
     crypto::hash::Hash,
-    interfaces::{PrivateTallyState, Tally},
+    interfaces::{PrivateTallyState, Tally, VotePlanStatus},
 };
 use rayon::prelude::*;
 use serde::Serialize;
@@ -53,45 +53,7 @@ impl TallyVotePlanWithAllShares {
                 Some(self.threshold),
             )?
             .try_into()?;
-        let committee_member_keys = vote_plan.committee_member_keys.clone();
-
-        let validated_tallies = (&vote_plan.proposals)
-            .into_par_iter()
-            .zip(shares.into_par_iter())
-            .map(|(proposal, shares)| {
-                let encrypted_tally = match &proposal.tally {
-                    Tally::Private {
-                        state:
-                            PrivateTallyState::Encrypted {
-                                encrypted_tally, ..
-                            },
-                    } => encrypted_tally,
-                    _ => unreachable!("expected encrypted private tally"),
-                };
-
-                let encrypted_tally = EncryptedTally::from_bytes(encrypted_tally.as_ref())
-                    .ok_or(Error::EncryptedTallyRead)?;
-
-                encrypted_tally
-                    .validate_partial_decryptions(&committee_member_keys, &shares)
-                    .map_err(SharesError::ValidationFailed)
-                    .map_err(Error::SharesError)
-            })
-            .collect::<Result<Vec<_>, _>>()?;
-
-        let decrypted_tallies = batch_decrypt(validated_tallies)?;
-
-        for (proposal, decrypted_tally) in vote_plan
-            .proposals
-            .iter_mut()
-            .zip(decrypted_tallies.into_iter())
-        {
-            proposal.tally = Tally::Private {
-                state: PrivateTallyState::Decrypted {
-                    result: decrypted_tally.into(),
-                },
-            }
-        }
+        decrypt_vote_plan(&mut vote_plan, shares)?;
 
         let output = self
             .output_format
@@ -101,3 +63,51 @@ impl TallyVotePlanWithAllShares {
         Ok(())
     }
 }
+
+/// Validates the decryption shares of each proposal against the committee
+/// member keys and replaces the encrypted tallies with the decrypted results
+pub(super) fn decrypt_vote_plan(
+    vote_plan: &mut VotePlanStatus,
+    shares: Vec<Vec<chain_vote::TallyDecryptShare>>,
+) -> Result<(), Error> {
+    let committee_member_keys = vote_plan.committee_member_keys.clone();
+
+    let validated_tallies = (&vote_plan.proposals)
+        .into_par_iter()
+        .zip(shares.into_par_iter())
+        .map(|(proposal, shares)| {
+            let encrypted_tally = match &proposal.tally {
+                Tally::Private {
+                    state:
+                        PrivateTallyState::Encrypted {
+                            encrypted_tally, ..
+                        },
+                } => encrypted_tally,
+                _ => unreachable!("expected encrypted private tally"),
+            };
+
+            let encrypted_tally = EncryptedTally::from_bytes(encrypted_tally.as_ref())
+                .ok_or(Error::EncryptedTallyRead)?;
+
+            encrypted_tally
+                .validate_partial_decryptions(&committee_member_keys, &shares)
+                .map_err(SharesError::ValidationFailed)
+                .map_err(Error::SharesError)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let decrypted_tallies = batch_decrypt(validated_tallies)?;
+
+    for (proposal, decrypted_tally) in vote_plan
+        .proposals
+        .iter_mut()
+        .zip(decrypted_tallies.into_iter())
+    {
+        proposal.tally = Tally::Private {
+            state: PrivateTallyState::Decrypted {
+                result: decrypted_tally.into(),
+            },
+        }
+    }
+    Ok(())
+}
@@ -1,6 +1,8 @@
+mod aggregate;
 mod decrypt_tally;
 mod decryption_shares;
 pub(crate) mod merge_results;
+pub(crate) mod verify;
 
 use super::Error;
 pub use merge_results::MergedVotePlan;
@@ -27,6 +29,16 @@ pub enum Tally {
     /// The tally data will be printed in json encoding on standard output. There order of the
     /// result is unspecified.
     MergeResults(merge_results::MergeVotePlan),
+    /// Aggregate the decryption shares of the committee members, verify them
+    /// and decrypt all proposals in a vote plan.
+    ///
+    /// The decrypted vote plan will be printed in json encoding on standard output.
+    Aggregate(aggregate::TallyAggregate),
+    /// Verify the tally results of a vote plan against its certificate and,
+    /// for private vote plans, against the decryption shares of the committee.
+    ///
+    /// The verified results will be printed in json encoding on standard output.
+    Verify(verify::TallyVerify),
 }
 
 impl Tally {
@@ -36,6 +48,8 @@ impl Tally {
             Tally::DecryptResults(cmd) => cmd.exec(),
             Tally::MergeShares(cmd) => cmd.exec(),
             Tally::MergeResults(cmd) => cmd.exec(),
+            Tally::Aggregate(cmd) => cmd.exec(),
+            Tally::Verify(cmd) => cmd.exec(),
         }
     }
 }
@@ -0,0 +1,353 @@
+use crate::jcli_lib::{
+    certificate,
+    utils::{vote, OutputFormat},
+};
+use chain_impl_mockchain::{certificate::VotePlan, vote::PayloadType};
+use chain_vote::{tally::EncryptedTally, MemberPublicKey, TallyDecryptShare};
+use jormungandr_lib::{
+    crypto::hash::Hash,
+    interfaces::{
+        BlockDate, PrivateTallyState, Tally, TallyResult, TokenIdentifier, VotePlanStatus,
+    },
+};
+use serde::Serialize;
+use std::{convert::TryInto, path::PathBuf};
+use structopt::StructOpt;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("the {0} of the vote plan does not match the certificate")]
+    CertificateMismatch(&'static str),
+    #[error("proposal {index} does not match the certificate")]
+    ProposalMismatch { index: u8 },
+    #[error("tally of proposal {index} does not have the privacy of the vote plan")]
+    PrivacyMismatch { index: u8 },
+    #[error("tally of proposal {index} is not decrypted yet")]
+    NotDecrypted { index: u8 },
+    #[error("tally of proposal {index} is already decrypted, an encrypted tally is needed")]
+    NotEncrypted { index: u8 },
+    #[error("results of proposal {index} do not match its options")]
+    InvalidResults { index: u8 },
+    #[error("results of proposal {index} do not match the decryption of its encrypted tally")]
+    DecryptionMismatch { index: u8 },
+    #[error("the encrypted tally is needed to verify the results of a private vote plan")]
+    EncryptedTallyRequired,
+    #[error("the decryption shares are needed to verify the results of a private vote plan")]
+    SharesRequired,
+}
+
+/// Verify the tally results announced for a vote plan, independently of
+/// the node which published them.
+///
+/// The vote plan is checked against its certificate. The results of a
+/// private vote plan are verified with the decryption shares of the
+/// committee, against the encrypted tally and the committee member keys
+/// of the certificate.
+#[derive(StructOpt)]
+#[structopt(rename_all = "kebab-case")]
+pub struct TallyVerify {
+    /// The path to the vote plan certificate, as created by
+    /// `certificate new vote-plan`
+    #[structopt(long)]
+    vote_plan_certificate: PathBuf,
+    /// The path to json-encoded vote plans with the announced results, as
+    /// downloaded with `rest v0 vote active plans get --with-tally`. If this
+    /// parameter is not specified, the vote plans will be read from the
+    /// standard input.
+    #[structopt(long)]
+    tally: Option<PathBuf>,
+    /// For private vote plans: the path to json-encoded vote plans downloaded
+    /// with `rest v0 vote active plans get --with-tally` before the tally
+    /// was decrypted
+    #[structopt(long)]
+    encrypted_tally: Option<PathBuf>,
+    /// For private vote plans: the path to the decryption shares, as created
+    /// by `merge-shares`
+    #[structopt(long)]
+    shares: Option<PathBuf>,
+    #[structopt(flatten)]
+    output_format: OutputFormat,
+}
+
+#[derive(Serialize)]
+struct VerifiedTally {
+    vote_plan_id: Hash,
+    proposals: Vec<VerifiedProposal>,
+}
+
+#[derive(Serialize)]
+struct VerifiedProposal {
+    index: u8,
+    proposal_id: Hash,
+    results: Vec<u64>,
+}
+
+impl TallyVerify {
+    pub fn exec(&self) -> Result<(), super::Error> {
+        let certificate = certificate::read_vote_plan(Some(self.vote_plan_certificate.as_path()))?;
+        let id: Hash = certificate.to_id().into();
+        let vote_plan = vote::get_vote_plan_by_id(self.tally.as_ref(), Some(&id))?;
+        check_certificate(&vote_plan, &certificate)?;
+
+        let results = match certificate.payload_type() {
+            PayloadType::Public => public_results(&vote_plan)?,
+            PayloadType::Private => {
+                let encrypted_tally = self
+                    .encrypted_tally
+                    .as_ref()
+                    .ok_or(Error::EncryptedTallyRequired)?;
+                let encrypted_vote_plan =
+                    vote::get_vote_plan_by_id(Some(encrypted_tally), Some(&id))?;
+                check_certificate(&encrypted_vote_plan, &certificate)?;
+                let shares = vote::read_vote_plan_shares_from_file(
+                    Some(self.shares.as_ref().ok_or(Error::SharesRequired)?),
+                    vote_plan.proposals.len(),
+                    None,
+                )?
+                .try_into()?;
+                private_results(
+                    &vote_plan,
+                    &encrypted_vote_plan,
+                    certificate.committee_public_keys(),
+                    shares,
+                )?
+            }
+        };
+
+        let verified = VerifiedTally {
+            vote_plan_id: id,
+            proposals: vote_plan
+                .proposals
+                .iter()
+                .zip(results)
+                .map(|(proposal, results)| VerifiedProposal {
+                    index: proposal.index,
+                    proposal_id: proposal.proposal_id,
+                    results,
+                })
+                .collect(),
+        };
+        let output = self
+            .output_format
+            .format_json(serde_json::to_value(verified)?)?;
+        println!("{}", output);
+
+        Ok(())
+    }
+}
+
+fn check_certificate(vote_plan: &VotePlanStatus, certificate: &VotePlan) -> Result<(), Error> {
+    if vote_plan.payload != certificate.payload_type() {
+        return Err(Error::CertificateMismatch("payload type"));
+    }
+    if vote_plan.vote_start != BlockDate::from(certificate.vote_start())
+        || vote_plan.vote_end != BlockDate::from(certificate.vote_end())
+        || vote_plan.committee_end != BlockDate::from(certificate.committee_end())
+    {
+        return Err(Error::CertificateMismatch("voting period"));
+    }
+    if vote_plan.committee_member_keys != certificate.committee_public_keys() {
+        return Err(Error::CertificateMismatch("committee"));
+    }
+    if vote_plan.voting_token != TokenIdentifier::from(certificate.voting_token().clone()) {
+        return Err(Error::CertificateMismatch("voting token"));
+    }
+    if vote_plan.proposals.len() != certificate.proposals().len() {
+        return Err(Error::CertificateMismatch("number of proposals"));
+    }
+    for (index, (status, proposal)) in vote_plan
+        .proposals
+        .iter()
+        .zip(certificate.proposals().iter())
+        .enumerate()
+    {
+        let proposal_id: Hash = proposal.external_id().clone().into();
+        if status.index as usize != index
+            || status.proposal_id != proposal_id
+            || &status.options != proposal.options().choice_range()
+        {
+            return Err(Error::ProposalMismatch {
+                index: status.index,
+            });
+        }
+    }
+    Ok(())
+}
+
+fn check_results(
+    index: u8,
+    options: &std::ops::Range<u8>,
+    result: &TallyResult,
+) -> Result<(), Error> {
+    if &result.options != options || result.results.len() != options.len() {
+        return Err(Error::InvalidResults { index });
+    }
+    Ok(())
+}
+
+/// Public results are computed by the ledger from votes which are not part
+/// of the vote plan status, they can only be checked for consistency.
+fn public_results(vote_plan: &VotePlanStatus) -> Result<Vec<Vec<u64>>, Error> {
+    vote_plan
+        .proposals
+        .iter()
+        .map(|proposal| match &proposal.tally {
+            Tally::Public { result } => {
+                check_results(proposal.index, &proposal.options, result)?;
+                Ok(result.results.clone())
+            }
+            Tally::Private { .. } => Err(Error::PrivacyMismatch {
+                index: proposal.index,
+            }),
+        })
+        .collect()
+}
+
+fn private_results(
+    vote_plan: &VotePlanStatus,
+    encrypted_vote_plan: &VotePlanStatus,
+    committee_member_keys: &[MemberPublicKey],
+    shares: Vec<Vec<TallyDecryptShare>>,
+) -> Result<Vec<Vec<u64>>, super::Error> {
+    vote_plan
+        .proposals
+        .iter()
+        .zip(encrypted_vote_plan.proposals.iter())
+        .zip(shares)
+        .map(|((proposal, encrypted_proposal), shares)| {
+            let index = proposal.index;
+            let result = match &proposal.tally {
+                Tally::Private {
+                    state: PrivateTallyState::Decrypted { result },
+                } => result,
+                Tally::Private {
+                    state: PrivateTallyState::Encrypted { .. },
+                } => return Err(Error::NotDecrypted { index }.into()),
+                Tally::Public { .. } => return Err(Error::PrivacyMismatch { index }.into()),
+            };
+            check_results(index, &proposal.options, result)?;
+
+            let encrypted_tally = match &encrypted_proposal.tally {
+                Tally::Private {
+                    state: PrivateTallyState::Encrypted { encrypted_tally },
+                } => EncryptedTally::from_bytes(encrypted_tally.as_ref())
+                    .ok_or(super::Error::EncryptedTallyRead)?,
+                Tally::Private {
+                    state: PrivateTallyState::Decrypted { .. },
+                } => return Err(Error::NotEncrypted { index }.into()),
+                Tally::Public { .. } => return Err(Error::PrivacyMismatch { index }.into()),
+            };
+
+            let tally = chain_vote::Tally {
+                votes: result.results.clone(),
+            };
+            if !tally.verify(&encrypted_tally, committee_member_keys, &shares) {
+                return Err(Error::DecryptionMismatch { index }.into());
+            }
+            Ok(result.results.clone())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chain_core::property::FromStr;
+    use chain_impl_mockchain::{
+        block,
+        certificate::{Proposal, Proposals, VoteAction},
+        testing::VoteTestGen,
+        tokens::identifier,
+        vote::{self, Choice, Options},
+    };
+    use jormungandr_lib::interfaces::VoteProposalStatus;
+
+    fn public_vote_plan() -> VotePlan {
+        let mut proposals = Proposals::new();
+        for _ in 0..2 {
+            let _ = proposals.push(Proposal::new(
+                VoteTestGen::external_proposal_id(),
+                Options::new_length(3).unwrap(),
+                VoteAction::OffChain,
+            ));
+        }
+        VotePlan::new(
+            block::BlockDate {
+                epoch: 1,
+                slot_id: 0,
+            },
+            block::BlockDate {
+                epoch: 2,
+                slot_id: 0,
+            },
+            block::BlockDate {
+                epoch: 3,
+                slot_id: 0,
+            },
+            proposals,
+            PayloadType::Public,
+            Vec::new(),
+            identifier::TokenIdentifier::from_str(
+                "00000000000000000000000000000000000000000000000000000000.00000000",
+            )
+            .unwrap(),
+        )
+    }
+
+    fn status(certificate: &VotePlan, results: &[[u64; 3]]) -> VotePlanStatus {
+        VotePlanStatus {
+            id: certificate.to_id().into(),
+            payload: certificate.payload_type(),
+            vote_start: certificate.vote_start().into(),
+            vote_end: certificate.vote_end().into(),
+            committee_end: certificate.committee_end().into(),
+            committee_member_keys: certificate.committee_public_keys().to_vec(),
+            proposals: certificate
+                .proposals()
+                .iter()
+                .zip(results)
+                .enumerate()
+                .map(|(index, (proposal, results))| {
+                    let mut result = vote::TallyResult::new(proposal.options().clone());
+                    for (choice, weight) in results.iter().enumerate() {
+                        result.add_vote(Choice::new(choice as u8), *weight).unwrap();
+                    }
+                    VoteProposalStatus {
+                        index: index as u8,
+                        proposal_id: proposal.external_id().clone().into(),
+                        options: proposal.options().choice_range().clone(),
+                        tally: Tally::Public {
+                            result: result.into(),
+                        },
+                        votes_cast: 1,
+                    }
+                })
+                .collect(),
+            voting_token: certificate.voting_token().clone().into(),
+        }
+    }
+
+    #[test]
+    fn public_results_match_certificate() {
+        let certificate = public_vote_plan();
+        let vote_plan = status(&certificate, &[[1, 0, 2], [0, 5, 0]]);
+
+        check_certificate(&vote_plan, &certificate).unwrap();
+        assert_eq!(
+            public_results(&vote_plan).unwrap(),
+            vec![vec![1, 0, 2], vec![0, 5, 0]]
+        );
+    }
+
+    #[test]
+    fn proposals_swapped_are_rejected() {
+        let certificate = public_vote_plan();
+        let mut vote_plan = status(&certificate, &[[1, 0, 2], [0, 5, 0]]);
+        vote_plan.proposals.swap(0, 1);
+
+        assert!(matches!(
+            check_certificate(&vote_plan, &certificate),
+            Err(Error::ProposalMismatch { index: 1 })
+        ));
+    }
+}
@@ -1,6 +1,10 @@
 use super::mint_token::TokenIdentifier;
 use crate::{crypto::hash::Hash, interfaces::Value};
-use chain_impl_mockchain::{account::SpendingCounterIncreasing, accounting::account, block::Epoch};
+use chain_impl_mockchain::{
+    account::{SpendingCounter, SpendingCounterIncreasing},
+    accounting::account,
+    block::Epoch,
+};
 use serde::{Deserialize, Serialize};
 use std::{collections::BTreeMap, convert::TryInto};
 
@@ -77,6 +81,27 @@ impl LastRewards {
     }
 }
 
+/// next valid spending counter of one of the account lanes, already split
+/// in its lane and per lane counter value
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct SpendingLane {
+    lane: usize,
+    counter: u32,
+}
+
+impl SpendingLane {
+    #[inline]
+    pub fn lane(&self) -> usize {
+        self.lane
+    }
+
+    /// the counter value within the lane, without the lane bits
+    #[inline]
+    pub fn counter(&self) -> u32 {
+        self.counter
+    }
+}
+
 /// represent the current state of an account in the ledger
 ///
 /// This type is different from the [`UTxOInfo`] which represents another
@@ -89,6 +114,8 @@ pub struct AccountState {
     delegation: DelegationType,
     value: Value,
     counters: [u32; SpendingCounterIncreasing::LANES],
+    #[serde(default)]
+    lanes: Vec<SpendingLane>,
     tokens: BTreeMap<TokenIdentifier, Value>,
     last_rewards: LastRewards,
 }
@@ -118,6 +145,13 @@ impl AccountState {
         self.counters
     }
 
+    /// Same as [`counters`](Self::counters) but decoded, so the next counter to
+    /// use for each lane can be read without knowing the lane encoding.
+    #[inline]
+    pub fn lanes(&self) -> &[SpendingLane] {
+        &self.lanes
+    }
+
     /// the last rewards transfered to account
     #[inline]
     pub fn last_rewards(&self) -> &LastRewards {
@@ -151,6 +185,15 @@ impl From<LastRewards> for account::LastRewards {
     }
 }
 
+impl From<SpendingCounter> for SpendingLane {
+    fn from(counter: SpendingCounter) -> Self {
+        Self {
+            lane: counter.lane(),
+            counter: counter.unlaned_counter(),
+        }
+    }
+}
+
 impl<E> From<account::AccountState<E>> for AccountState {
     fn from(account: account::AccountState<E>) -> Self {
         let counters = account.spending.get_valid_counters();
@@ -167,6 +210,7 @@ impl<E> From<account::AccountState<E>> for AccountState {
                 counters[6].into(),
                 counters[7].into(),
             ],
+            lanes: counters.iter().copied().map(SpendingLane::from).collect(),
             tokens: account
                 .tokens
                 .iter()
@@ -198,6 +242,7 @@ impl<'a, E> From<&'a account::AccountState<E>> for AccountState {
                 counters[6].into(),
                 counters[7].into(),
             ],
+            lanes: counters.iter().copied().map(SpendingLane::from).collect(),
             tokens: account
                 .tokens
                 .iter()
@@ -106,6 +106,22 @@ pub struct BlockchainConfiguration {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tx_max_expiry_epochs: Option<u8>,
 
+    /// set the maximal size of a single fragment, in bytes. If omitted
+    /// fragments are only limited by the block content maximal size
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_fragment_size: Option<u32>,
+
+    /// weight the votes with the stake the accounts had when the vote plan
+    /// started, instead of their stake when the vote and the tally are
+    /// applied. If omitted the live stake is used
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub voting_power_snapshot: Option<bool>,
+
+    /// accept outputs to script addresses and the script witnesses spending
+    /// them. If omitted script addresses are refused
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub script_addresses: Option<bool>,
+
     /// Fees go to settings, the default being `rewards`.
     ///
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -204,6 +220,9 @@ impl BlockchainConfiguration {
             block_content_max_size: BlockContentMaxSize::default(),
             epoch_stability_depth: EpochStabilityDepth::default(),
             tx_max_expiry_epochs: None,
+            max_fragment_size: None,
+            voting_power_snapshot: None,
+            script_addresses: None,
             fees_go_to: None,
             treasury: None,
             treasury_parameters: None,
@@ -245,6 +264,9 @@ impl BlockchainConfiguration {
         let mut reward_constraints = RewardConstraints::default();
         let mut committees = Vec::new();
         let mut tx_max_expiry_epochs = None;
+        let mut max_fragment_size = None;
+        let mut voting_power_snapshot = None;
+        let mut script_addresses = None;
         #[cfg(feature = "evm")]
         let mut evm_configs = None;
         #[cfg(feature = "evm")]
@@ -335,6 +357,15 @@ impl BlockchainConfiguration {
                 ConfigParam::TransactionMaxExpiryEpochs(value) => tx_max_expiry_epochs
                     .replace(value)
                     .map(|_| "tx_max_expiry_epochs"),
+                ConfigParam::MaxFragmentSize(value) => max_fragment_size
+                    .replace(value)
+                    .map(|_| "max_fragment_size"),
+                ConfigParam::VotingPowerSnapshot(value) => voting_power_snapshot
+                    .replace(value)
+                    .map(|_| "voting_power_snapshot"),
+                ConfigParam::ScriptAddresses(value) => {
+                    script_addresses.replace(value).map(|_| "script_addresses")
+                }
                 #[cfg(feature = "evm")]
                 ConfigParam::EvmConfiguration(params) => {
                     evm_configs.replace(params.into()).map(|_| "evm_params")
@@ -386,6 +417,9 @@ impl BlockchainConfiguration {
             reward_constraints,
             committees,
             tx_max_expiry_epochs,
+            max_fragment_size,
+            voting_power_snapshot,
+            script_addresses,
             #[cfg(feature = "evm")]
             evm_configs,
             #[cfg(feature = "evm")]
@@ -415,6 +449,9 @@ impl BlockchainConfiguration {
             reward_constraints,
             committees,
             tx_max_expiry_epochs,
+            max_fragment_size,
+            voting_power_snapshot,
+            script_addresses,
             #[cfg(feature = "evm")]
             evm_configs,
             #[cfg(feature = "evm")]
@@ -494,6 +531,18 @@ impl BlockchainConfiguration {
             ));
         }
 
+        if let Some(max_fragment_size) = max_fragment_size {
+            params.push(ConfigParam::MaxFragmentSize(max_fragment_size));
+        }
+
+        if let Some(voting_power_snapshot) = voting_power_snapshot {
+            params.push(ConfigParam::VotingPowerSnapshot(voting_power_snapshot));
+        }
+
+        if let Some(script_addresses) = script_addresses {
+            params.push(ConfigParam::ScriptAddresses(script_addresses));
+        }
+
         #[cfg(feature = "evm")]
         if let Some(evm_configs) = evm_configs {
             params.push(ConfigParam::EvmConfiguration(evm_configs.into()));
@@ -594,6 +643,9 @@ mod test {
                     .take(counter_committee)
                     .collect(),
                 tx_max_expiry_epochs: Arbitrary::arbitrary(g),
+                max_fragment_size: Arbitrary::arbitrary(g),
+                voting_power_snapshot: Arbitrary::arbitrary(g),
+                script_addresses: Arbitrary::arbitrary(g),
                 #[cfg(feature = "evm")]
                 evm_configs: Arbitrary::arbitrary(g),
                 #[cfg(feature = "evm")]
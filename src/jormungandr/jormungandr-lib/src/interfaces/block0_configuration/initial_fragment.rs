@@ -34,10 +34,7 @@ pub struct InitialUTxO {
 
 impl InitialUTxO {
     pub fn to_output(&self) -> Output<chain_addr::Address> {
-        Output {
-            address: self.address.clone().into(),
-            value: self.value.into(),
-        }
+        Output::from_address(self.address.clone().into(), self.value.into())
     }
 }
 
@@ -248,6 +245,9 @@ fn pack_certificate_in_empty_tx_fragment(cert: &SignedCertificate) -> Vec<Fragme
         certificate::SignedCertificate::EvmMapping(c, a) => {
             Fragment::EvmMapping(empty_auth_tx(c, a))
         }
+        certificate::SignedCertificate::MultisigDeclarationUpdate(c, a) => {
+            Fragment::MultisigDeclarationUpdate(empty_auth_tx(c, a))
+        }
     }]
 }
 
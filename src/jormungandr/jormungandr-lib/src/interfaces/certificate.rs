@@ -58,6 +58,9 @@ impl SignedCertificate {
             certificate::SignedCertificate::EvmMapping(c, _) => {
                 Certificate(certificate::Certificate::EvmMapping(c))
             }
+            certificate::SignedCertificate::MultisigDeclarationUpdate(c, _) => {
+                Certificate(certificate::Certificate::MultisigDeclarationUpdate(c))
+            }
         }
     }
 }
@@ -116,6 +119,14 @@ impl property::Serialize for Certificate {
                 codec.put_bytes(&[12])?;
                 codec.put_bytes(c.serialize().as_slice())?;
             }
+            certificate::Certificate::Anchor(c) => {
+                codec.put_bytes(&[13])?;
+                codec.put_bytes(c.serialize().as_slice())?;
+            }
+            certificate::Certificate::MultisigDeclarationUpdate(c) => {
+                codec.put_bytes(&[14])?;
+                codec.put_bytes(c.serialize().as_slice())?;
+            }
         };
         Ok(())
     }
@@ -178,6 +189,16 @@ impl property::DeserializeFromSlice for Certificate {
                 let cert = certificate::EvmMapping::deserialize_from_slice(codec)?;
                 Ok(Certificate(certificate::Certificate::EvmMapping(cert)))
             }
+            13 => {
+                let cert = certificate::Anchor::deserialize_from_slice(codec)?;
+                Ok(Certificate(certificate::Certificate::Anchor(cert)))
+            }
+            14 => {
+                let cert = certificate::MultisigDeclarationUpdate::deserialize_from_slice(codec)?;
+                Ok(Certificate(
+                    certificate::Certificate::MultisigDeclarationUpdate(cert),
+                ))
+            }
             t => Err(property::ReadError::UnknownTag(t as u32)),
         }
     }
@@ -238,6 +259,11 @@ impl property::Serialize for SignedCertificate {
                 codec.put_bytes(c.serialize().as_slice())?;
                 codec.put_bytes(a.serialize_in(ByteBuilder::new()).finalize().as_slice())?;
             }
+            certificate::SignedCertificate::MultisigDeclarationUpdate(c, a) => {
+                codec.put_bytes(&[11])?;
+                codec.put_bytes(c.serialize().as_slice())?;
+                codec.put_bytes(a.serialize_in(ByteBuilder::new()).finalize().as_slice())?;
+            }
         };
         Ok(())
     }
@@ -317,6 +343,13 @@ impl property::DeserializeFromSlice for SignedCertificate {
                     certificate::SignedCertificate::EvmMapping(cert, auth),
                 ))
             }
+            11 => {
+                let cert = certificate::MultisigDeclarationUpdate::deserialize_from_slice(codec)?;
+                let auth = property::DeserializeFromSlice::deserialize_from_slice(codec)?;
+                Ok(SignedCertificate(
+                    certificate::SignedCertificate::MultisigDeclarationUpdate(cert, auth),
+                ))
+            }
             t => Err(property::ReadError::UnknownTag(t as u32)),
         }
     }
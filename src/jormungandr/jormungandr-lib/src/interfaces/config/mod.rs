@@ -6,7 +6,8 @@ mod secret;
 pub use log::{Log, LogEntry, LogOutput};
 pub use mempool::{LogMaxEntries, Mempool, PersistentLog, PoolMaxEntries};
 pub use node::{
-    Bootstrap, Connection, Cors, CorsOrigin, JRpc, LayersConfig, NodeConfig, NodeId, P2p, Policy,
-    PreferredListConfig, Rest, Tls, TopicsOfInterest, TrustedPeer,
+    BlockCompression, Bootstrap, Connection, Cors, CorsOrigin, FragmentDedup, JRpc, LayersConfig,
+    NodeConfig, NodeId, OverflowPolicy, P2p, Policy, PreferredListConfig, Rest,
+    SubscriptionOverflow, Tls, TopicsOfInterest, TrustedPeer,
 };
 pub use secret::{Bft, GenesisPraos, NodeSecret};
@@ -242,6 +242,94 @@ pub struct Connection {
     /// If no gossip has been received in the last interval, try to connect to nodes that were previously known to this node
     #[serde(skip_serializing_if = "Option::is_none")]
     pub network_stuck_check: Option<Duration>,
+
+    /// Interval of HTTP/2 keepalive pings sent to the connected peers
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http2_keepalive_interval: Option<Duration>,
+
+    /// Connections to peers not acknowledging a keepalive ping within this timeout are closed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keepalive_timeout: Option<Duration>,
+
+    /// Limit of requests served at the same time on each connection accepted by the node
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http2_max_concurrent_streams: Option<u32>,
+
+    /// Limit of subscriptions served at the same time to a peer
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_subscriptions_per_peer: Option<usize>,
+
+    /// Compression of the block streams exchanged with the peers
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_compression: Option<BlockCompression>,
+
+    /// Serve the gRPC reflection service next to the node services
+    #[serde(default)]
+    pub grpc_reflection: bool,
+
+    /// Handling of the messages received from the peers faster than the node processes them
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subscription_overflow: Option<SubscriptionOverflow>,
+
+    /// Suppression of the fragments received again from other peers
+    /// shortly after they were first seen
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fragment_dedup: Option<FragmentDedup>,
+}
+
+/// Compression algorithm of the block streams
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BlockCompression {
+    Gzip,
+}
+
+/// Overflow policies of the subscriptions of the peers, per message type.
+///
+/// Block announcements are never dropped: reading them is suspended until
+/// the node catches up.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SubscriptionOverflow {
+    /// Policy of the gossip subscriptions, `drop_oldest` by default
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gossip: Option<OverflowPolicy>,
+    /// Policy of the fragment subscriptions, `park` by default. Fragments
+    /// received again while a copy is still buffered are always discarded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fragments: Option<OverflowPolicy>,
+}
+
+/// Limits of the cache of the fragments recently received from the peers.
+/// Fragments found in the cache are not processed again.
+///
+/// Block headers are deliberately excluded: the announcement of a block
+/// which failed to be pulled from one peer is needed from the others to
+/// pull it again.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FragmentDedup {
+    /// How long a fragment is remembered after it was first received,
+    /// 60 seconds by default
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ttl: Option<Duration>,
+    /// Maximum number of fragments remembered,
+    /// 4096 by default
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub capacity: Option<usize>,
+}
+
+/// What to do with a message received while the buffer of the
+/// subscription is full
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OverflowPolicy {
+    /// Discard the oldest buffered message
+    DropOldest,
+    /// Discard the received message
+    DropNewest,
+    /// Stop reading from the peer until the node catches up
+    Park,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
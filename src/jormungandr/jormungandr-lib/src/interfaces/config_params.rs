@@ -63,6 +63,9 @@ pub enum ConfigParam {
     EvmConfiguration(super::evm_params::EvmConfig),
     #[cfg(feature = "evm")]
     EvmEnvironment(super::evm_params::EvmEnvSettings),
+    MaxFragmentSize(u32),
+    VotingPowerSnapshot(bool),
+    ScriptAddresses(bool),
 }
 
 #[derive(Debug, Error)]
@@ -126,6 +129,9 @@ impl From<ConfigParam> for ConfigParamLib {
             ConfigParam::EvmConfiguration(val) => Self::EvmConfiguration(val.into()),
             #[cfg(feature = "evm")]
             ConfigParam::EvmEnvironment(val) => Self::EvmEnvironment(val.into()),
+            ConfigParam::MaxFragmentSize(val) => Self::MaxFragmentSize(val),
+            ConfigParam::VotingPowerSnapshot(val) => Self::VotingPowerSnapshot(val),
+            ConfigParam::ScriptAddresses(val) => Self::ScriptAddresses(val),
         }
     }
 }
@@ -172,6 +178,9 @@ impl TryFrom<ConfigParamLib> for ConfigParam {
             ConfigParamLib::EvmConfiguration(val) => Self::EvmConfiguration(val.into()),
             #[cfg(feature = "evm")]
             ConfigParamLib::EvmEnvironment(val) => Self::EvmEnvironment(val.into()),
+            ConfigParamLib::MaxFragmentSize(val) => Self::MaxFragmentSize(val),
+            ConfigParamLib::VotingPowerSnapshot(val) => Self::VotingPowerSnapshot(val),
+            ConfigParamLib::ScriptAddresses(val) => Self::ScriptAddresses(val),
         })
     }
 }
@@ -187,7 +196,7 @@ mod test {
 
     impl Arbitrary for ConfigParam {
         fn arbitrary<G: Gen>(g: &mut G) -> Self {
-            match u8::arbitrary(g) % 30 {
+            match u8::arbitrary(g) % 33 {
                 0 => Self::Block0Date(Arbitrary::arbitrary(g)),
                 1 => Self::Discrimination(Arbitrary::arbitrary(g)),
                 2 => Self::ConsensusVersion(Arbitrary::arbitrary(g)),
@@ -218,6 +227,9 @@ mod test {
                 27 => Self::RemoveCommitteeId(Arbitrary::arbitrary(g)),
                 28 => Self::PerCertificateFees(Arbitrary::arbitrary(g)),
                 29 => Self::TransactionMaxExpiryEpochs(Arbitrary::arbitrary(g)),
+                30 => Self::MaxFragmentSize(Arbitrary::arbitrary(g)),
+                31 => Self::VotingPowerSnapshot(Arbitrary::arbitrary(g)),
+                32 => Self::ScriptAddresses(Arbitrary::arbitrary(g)),
                 _ => unreachable!(),
             }
         }
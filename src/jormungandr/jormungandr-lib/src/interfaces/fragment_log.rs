@@ -1,9 +1,10 @@
-use std::net::IpAddr;
+use std::{net::IpAddr, str::FromStr};
 
 use crate::{crypto::hash::Hash, interfaces::BlockDate, time::SystemTime};
 use chain_impl_mockchain::key;
 
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
 
 /// identify the source of a fragment
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
@@ -129,3 +130,177 @@ impl FragmentLog {
         &self.status
     }
 }
+
+impl FragmentOrigin {
+    pub fn kind(&self) -> FragmentOriginKind {
+        match self {
+            FragmentOrigin::Network { .. } => FragmentOriginKind::Network,
+            FragmentOrigin::Rest => FragmentOriginKind::Rest,
+            FragmentOrigin::JRpc => FragmentOriginKind::JRpc,
+        }
+    }
+}
+
+impl FragmentStatus {
+    pub fn kind(&self) -> FragmentStatusKind {
+        match self {
+            FragmentStatus::Pending => FragmentStatusKind::Pending,
+            FragmentStatus::Rejected { .. } => FragmentStatusKind::Rejected,
+            FragmentStatus::InABlock { .. } => FragmentStatusKind::InABlock,
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+#[error("unknown {kind} '{value}', expected one of: {expected}")]
+pub struct ParseKindError {
+    kind: &'static str,
+    value: String,
+    expected: &'static str,
+}
+
+/// [`FragmentOrigin`] without the details, used to filter the fragment logs
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FragmentOriginKind {
+    Network,
+    Rest,
+    JRpc,
+}
+
+impl FromStr for FragmentOriginKind {
+    type Err = ParseKindError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "network" => Ok(FragmentOriginKind::Network),
+            "rest" => Ok(FragmentOriginKind::Rest),
+            "jrpc" => Ok(FragmentOriginKind::JRpc),
+            _ => Err(ParseKindError {
+                kind: "fragment origin",
+                value: s.to_owned(),
+                expected: "network, rest, jrpc",
+            }),
+        }
+    }
+}
+
+/// [`FragmentStatus`] without the details, used to filter the fragment logs
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FragmentStatusKind {
+    Pending,
+    Rejected,
+    InABlock,
+}
+
+impl FromStr for FragmentStatusKind {
+    type Err = ParseKindError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pending" => Ok(FragmentStatusKind::Pending),
+            "rejected" => Ok(FragmentStatusKind::Rejected),
+            "in_a_block" => Ok(FragmentStatusKind::InABlock),
+            _ => Err(ParseKindError {
+                kind: "fragment status",
+                value: s.to_owned(),
+                expected: "pending, rejected, in_a_block",
+            }),
+        }
+    }
+}
+
+/// Filtering and pagination of the fragment logs, as query parameters
+/// of the fragment logs REST endpoint.
+///
+/// The filters are applied first, `offset` and `limit` select a page
+/// of the remaining logs.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FragmentLogsQuery {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub offset: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<FragmentStatusKind>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub origin: Option<FragmentOriginKind>,
+}
+
+impl FragmentLogsQuery {
+    pub fn matches(&self, log: &FragmentLog) -> bool {
+        self.status
+            .map_or(true, |status| log.status().kind() == status)
+            && self
+                .origin
+                .map_or(true, |origin| log.received_from().kind() == origin)
+    }
+
+    pub fn apply<'a, I>(&self, logs: I) -> impl Iterator<Item = &'a FragmentLog>
+    where
+        I: IntoIterator<Item = &'a FragmentLog>,
+    {
+        let query = self.clone();
+        logs.into_iter()
+            .filter(move |log| query.matches(log))
+            .skip(self.offset.unwrap_or(0))
+            .take(self.limit.unwrap_or(usize::MAX))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn log(origin: FragmentOrigin, status: FragmentStatus) -> FragmentLog {
+        let mut log = FragmentLog::new(key::Hash::hash_bytes(&[]), origin);
+        log.modify(status);
+        log
+    }
+
+    #[test]
+    fn fragment_logs_query() {
+        let logs = vec![
+            log(FragmentOrigin::Rest, FragmentStatus::Pending),
+            log(
+                FragmentOrigin::JRpc,
+                FragmentStatus::Rejected {
+                    reason: "invalid".to_owned(),
+                },
+            ),
+            log(FragmentOrigin::Rest, FragmentStatus::Pending),
+            log(FragmentOrigin::Rest, FragmentStatus::Pending),
+        ];
+
+        let all = FragmentLogsQuery::default();
+        assert_eq!(all.apply(&logs).count(), 4);
+
+        let pending_from_rest = FragmentLogsQuery {
+            status: Some(FragmentStatusKind::Pending),
+            origin: Some(FragmentOriginKind::Rest),
+            ..Default::default()
+        };
+        assert_eq!(pending_from_rest.apply(&logs).count(), 3);
+
+        let page = FragmentLogsQuery {
+            offset: Some(1),
+            limit: Some(1),
+            ..pending_from_rest
+        };
+        assert_eq!(page.apply(&logs).collect::<Vec<_>>(), vec![&logs[2]]);
+    }
+
+    #[test]
+    fn kinds_from_str() {
+        assert_eq!(
+            "in_a_block".parse::<FragmentStatusKind>().unwrap(),
+            FragmentStatusKind::InABlock
+        );
+        assert_eq!(
+            "jrpc".parse::<FragmentOriginKind>().unwrap(),
+            FragmentOriginKind::JRpc
+        );
+        assert!("block".parse::<FragmentStatusKind>().is_err());
+    }
+}
@@ -15,3 +15,15 @@ pub struct FragmentsBatch {
     #[serde_as(as = "Vec<FragmentDef>")]
     pub fragments: Vec<Fragment>,
 }
+
+/// Submission of a batch of hex-encoded fragments through `v0/message/batch`.
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct MessageBatch {
+    /// When enabled, either all the fragments are accepted to the mempool or none of them is.
+    /// Otherwise, every fragment is processed independently.
+    #[serde(default)]
+    pub all_or_nothing: bool,
+    #[serde_as(as = "Vec<FragmentDef>")]
+    pub fragments: Vec<Fragment>,
+}
@@ -14,6 +14,10 @@ pub enum FragmentRejectionReason {
     PoolOverflow,
     FragmentExpired,
     FragmentValidForTooLong,
+    FragmentTooLarge,
+    /// The fragment was valid, but it was submitted in an all-or-nothing batch
+    /// together with another fragment which was rejected.
+    BatchFragmentInvalid,
 }
 
 /// Information about a fragment rejected by the mempool. This is different from being rejected by
@@ -42,8 +46,10 @@ impl FragmentRejectionReason {
         matches!(
             self,
             FragmentRejectionReason::FragmentInvalid
+                | FragmentRejectionReason::FragmentTooLarge
                 | FragmentRejectionReason::PreviousFragmentInvalid
                 | FragmentRejectionReason::PoolOverflow
+                | FragmentRejectionReason::BatchFragmentInvalid
         )
     }
 }
@@ -72,11 +78,12 @@ mod tests {
 
     impl Arbitrary for FragmentRejectionReason {
         fn arbitrary<G: Gen>(g: &mut G) -> Self {
-            match g.next_u32() % 4 {
+            match g.next_u32() % 5 {
                 0 => FragmentRejectionReason::FragmentAlreadyInLog,
                 1 => FragmentRejectionReason::FragmentInvalid,
                 2 => FragmentRejectionReason::PreviousFragmentInvalid,
                 3 => FragmentRejectionReason::PoolOverflow,
+                4 => FragmentRejectionReason::BatchFragmentInvalid,
                 _ => unreachable!(),
             }
         }
@@ -41,7 +41,7 @@ mod vote;
 
 pub use self::{
     account_identifier::AccountIdentifier,
-    account_state::AccountState,
+    account_state::{AccountState, DelegationType, SpendingLane},
     address::Address,
     block0_configuration::*,
     block0_date::Block0DateDef,
@@ -57,13 +57,16 @@ pub use self::{
     },
     evm_transaction::EvmTransaction,
     fragment::FragmentDef,
-    fragment_log::{FragmentLog, FragmentOrigin, FragmentStatus},
+    fragment_log::{
+        FragmentLog, FragmentLogsQuery, FragmentOrigin, FragmentOriginKind, FragmentStatus,
+        FragmentStatusKind, ParseKindError,
+    },
     fragment_log_persistent::{
         load_persistent_fragments_logs_from_folder_path,
         read_persistent_fragment_logs_from_file_path,
         DeserializeError as FragmentLogDeserializeError, FileFragments, PersistentFragmentLog,
     },
-    fragments_batch::FragmentsBatch,
+    fragments_batch::{FragmentsBatch, MessageBatch},
     fragments_processing_summary::{
         FragmentRejectionReason, FragmentsProcessingSummary, RejectedFragmentInfo,
     },
@@ -86,7 +89,7 @@ pub use self::{
     transaction_output::TransactionOutput,
     transaction_witness::TransactionWitness,
     updates::{UpdateProposalDef, UpdateProposalStateDef},
-    utxo_info::{UTxOInfo, UTxOOutputInfo},
+    utxo_info::{UTxOInfo, UTxOOutputInfo, UTxOsQuery},
     value::{Value, ValueDef},
     vote::{
         serde_base64_bytes, serde_choices, serde_committee_member_public_keys,
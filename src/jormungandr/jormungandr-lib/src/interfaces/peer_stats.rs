@@ -10,6 +10,35 @@ pub struct PeerStats {
     pub last_block_received: Option<SystemTime>,
     pub last_fragment_received: Option<SystemTime>,
     pub last_gossip_received: Option<SystemTime>,
+    /// number of requests of the peer served by the node
+    #[serde(default)]
+    pub requests_served: u64,
+    /// size of the messages received in the requests served to the peer
+    #[serde(default)]
+    pub bytes_received: u64,
+    /// size of the messages sent in the requests served to the peer
+    #[serde(default)]
+    pub bytes_sent: u64,
+    /// number of gossip messages of the peer dropped because the node
+    /// was falling behind in processing them
+    #[serde(default)]
+    pub gossip_dropped: u64,
+    /// number of fragments of the peer dropped because the node was
+    /// falling behind in processing them
+    #[serde(default)]
+    pub fragments_dropped: u64,
+    /// number of fragments of the peer discarded because the same
+    /// fragment was already waiting to be processed
+    #[serde(default)]
+    pub fragments_coalesced: u64,
+    /// number of fragments of the peer discarded because the same
+    /// fragment was recently received, from this peer or another one
+    #[serde(default)]
+    pub fragment_dedup_hits: u64,
+    /// number of fragments of the peer looked up in the deduplication
+    /// cache and processed
+    #[serde(default)]
+    pub fragment_dedup_misses: u64,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
@@ -20,6 +49,9 @@ pub struct PeerRecord {
     pub last_update: SystemTime,
     pub quarantined: Option<SystemTime>,
     pub subscriptions: Vec<Subscription>,
+    /// misbehavior score of the peer, as recorded by the node serving it
+    #[serde(default)]
+    pub score: u32,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
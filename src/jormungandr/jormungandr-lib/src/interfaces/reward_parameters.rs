@@ -18,6 +18,10 @@ pub enum RewardParams {
         epoch_start: Epoch,
         epoch_rate: NonZeroU32,
     },
+    Fixed {
+        constant: u64,
+        epoch_start: Epoch,
+    },
 }
 
 /* ************** Conversion *********************************** */
@@ -47,6 +51,13 @@ impl From<RewardParams> for RewardParamsStd {
                 epoch_start,
                 epoch_rate,
             },
+            RewardParams::Fixed {
+                constant,
+                epoch_start,
+            } => RewardParamsStd::Fixed {
+                constant,
+                epoch_start,
+            },
         }
     }
 }
@@ -76,6 +87,13 @@ impl From<RewardParamsStd> for RewardParams {
                 epoch_start,
                 epoch_rate,
             },
+            RewardParamsStd::Fixed {
+                constant,
+                epoch_start,
+            } => RewardParams::Fixed {
+                constant,
+                epoch_start,
+            },
         }
     }
 }
@@ -91,22 +109,25 @@ mod test {
         where
             G: Gen,
         {
-            if bool::arbitrary(g) {
-                Self::Linear {
+            match u8::arbitrary(g) % 3 {
+                0 => Self::Linear {
                     constant: u64::arbitrary(g),
                     ratio: Ratio::arbitrary(g),
                     epoch_start: Epoch::arbitrary(g),
                     epoch_rate: NonZeroU32::new(Arbitrary::arbitrary(g))
                         .unwrap_or_else(|| NonZeroU32::new(1).unwrap()),
-                }
-            } else {
-                Self::Halving {
+                },
+                1 => Self::Halving {
                     constant: u64::arbitrary(g),
                     ratio: Ratio::arbitrary(g),
                     epoch_start: Epoch::arbitrary(g),
                     epoch_rate: NonZeroU32::new(Arbitrary::arbitrary(g))
                         .unwrap_or_else(|| NonZeroU32::new(1).unwrap()),
-                }
+                },
+                _ => Self::Fixed {
+                    constant: u64::arbitrary(g),
+                    epoch_start: Epoch::arbitrary(g),
+                },
             }
         }
     }
@@ -159,6 +180,25 @@ mod test {
         );
     }
 
+    #[test]
+    fn fixed_serde_yaml() {
+        const CONSTANT: u64 = 8170;
+        const EPOCH_START: Epoch = 2;
+
+        let parameters = RewardParams::Fixed {
+            constant: CONSTANT,
+            epoch_start: EPOCH_START,
+        };
+
+        assert_eq!(
+            serde_yaml::to_string(&parameters).unwrap(),
+            format!(
+                "---\nfixed:\n  constant: {}\n  epoch_start: {}\n",
+                CONSTANT, EPOCH_START,
+            )
+        );
+    }
+
     quickcheck! {
         fn value_serde_human_readable_encode_decode(value: RewardParams) -> TestResult {
             let s = serde_yaml::to_string(&value).unwrap();
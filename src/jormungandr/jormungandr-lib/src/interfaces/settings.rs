@@ -32,6 +32,12 @@ pub struct SettingsDto {
     #[serde(with = "DiscriminationDef")]
     pub discrimination: Discrimination,
     pub tx_max_expiry_epochs: u8,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_fragment_size: Option<u32>,
+    #[serde(default)]
+    pub voting_power_snapshot: bool,
+    #[serde(default)]
+    pub script_addresses: bool,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -106,5 +112,8 @@ impl PartialEq<SettingsDto> for SettingsDto {
             && self.slots_per_epoch == other.slots_per_epoch
             && self.treasury_tax == other.treasury_tax
             && self.reward_params == other.reward_params
+            && self.max_fragment_size == other.max_fragment_size
+            && self.voting_power_snapshot == other.voting_power_snapshot
+            && self.script_addresses == other.script_addresses
     }
 }
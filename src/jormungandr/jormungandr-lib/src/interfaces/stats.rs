@@ -44,4 +44,5 @@ pub enum NodeState {
     Bootstrapping,
     StartingWorkers,
     Running,
+    ShuttingDown,
 }
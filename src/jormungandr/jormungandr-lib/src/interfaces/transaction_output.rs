@@ -1,4 +1,4 @@
-use crate::interfaces::{Address, Value};
+use crate::interfaces::{Address, BlockDate, Value};
 use chain_impl_mockchain::transaction::Output;
 use serde::{Deserialize, Serialize};
 
@@ -6,13 +6,29 @@ use serde::{Deserialize, Serialize};
 pub struct TransactionOutput {
     address: Address,
     value: Value,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    locked_until: Option<BlockDate>,
 }
 
 impl TransactionOutput {
     /// create a new Transaction output from the given values
     #[inline]
     pub fn new(address: Address, value: Value) -> Self {
-        TransactionOutput { address, value }
+        TransactionOutput {
+            address,
+            value,
+            locked_until: None,
+        }
+    }
+
+    /// create a new Transaction output that cannot be spent before `locked_until`
+    #[inline]
+    pub fn new_time_locked(address: Address, value: Value, locked_until: BlockDate) -> Self {
+        TransactionOutput {
+            address,
+            value,
+            locked_until: Some(locked_until),
+        }
     }
 
     /// get the address component of the `TransactionOutput`
@@ -26,6 +42,12 @@ impl TransactionOutput {
     pub fn value(&self) -> &Value {
         &self.value
     }
+
+    /// get the date until which the `TransactionOutput` cannot be spent, if any
+    #[inline]
+    pub fn locked_until(&self) -> Option<&BlockDate> {
+        self.locked_until.as_ref()
+    }
 }
 
 /* ---------------- Conversion --------------------------------------------- */
@@ -35,6 +57,7 @@ impl From<Output<chain_addr::Address>> for TransactionOutput {
         TransactionOutput {
             address: v.address.into(),
             value: v.value.into(),
+            locked_until: v.locked_until.map(Into::into),
         }
     }
 }
@@ -44,6 +67,7 @@ impl From<TransactionOutput> for Output<chain_addr::Address> {
         Output {
             address: v.address.into(),
             value: v.value.into(),
+            locked_until: v.locked_until.map(Into::into),
         }
     }
 }
@@ -82,6 +82,44 @@ impl<'a> From<Entry<'a, chain_addr::Address>> for UTxOInfo {
     }
 }
 
+/// Filter and pagination parameters of the UTxO listing REST endpoint.
+///
+/// The filter is applied first, `offset` and `limit` select a page of the
+/// remaining UTxOs. The UTxOs are listed in the order of the ledger of the
+/// tip, pages fetched at different tips may overlap or miss some UTxOs.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UTxOsQuery {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub offset: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address: Option<Address>,
+}
+
+impl UTxOsQuery {
+    /// the address prefix is ignored when filtering
+    pub fn matches(&self, utxo: &Entry<'_, chain_addr::Address>) -> bool {
+        self.address
+            .as_ref()
+            .map_or(true, |address| address.1 == utxo.output.address)
+    }
+
+    pub fn apply<'a, I>(&self, utxos: I) -> impl Iterator<Item = UTxOInfo> + 'a
+    where
+        I: IntoIterator<Item = Entry<'a, chain_addr::Address>>,
+        I::IntoIter: 'a,
+    {
+        let query = self.clone();
+        utxos
+            .into_iter()
+            .filter(move |utxo| query.matches(utxo))
+            .skip(self.offset.unwrap_or(0))
+            .take(self.limit.unwrap_or(usize::MAX))
+            .map(UTxOInfo::from)
+    }
+}
+
 /// The UTxO data about output without its location in blockchain
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
 pub struct UTxOOutputInfo {
@@ -4,6 +4,7 @@ use crate::{
 };
 use chain_crypto::bech32::Bech32;
 use chain_impl_mockchain::{
+    account,
     certificate::{self, ExternalProposalId, Proposal, Proposals, VoteAction},
     ledger::governance::{ParametersGovernanceAction, TreasuryGovernanceAction},
     value::Value,
@@ -202,9 +203,34 @@ enum TreasuryGovernanceActionDef {
         #[serde(with = "ValueDef")]
         value: Value,
     },
+    TransferToAccount {
+        #[serde(with = "ValueDef")]
+        value: Value,
+        #[serde(with = "serde_account_identifier")]
+        destination: account::Identifier,
+    },
     NoOp,
 }
 
+mod serde_account_identifier {
+    use crate::crypto::account::Identifier;
+    use chain_impl_mockchain::account;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        identifier: &account::Identifier,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        Identifier::from(identifier.clone()).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<account::Identifier, D::Error> {
+        Identifier::deserialize(deserializer).map(|identifier| identifier.to_inner())
+    }
+}
+
 impl From<certificate::VotePlan> for VotePlan {
     fn from(vp: certificate::VotePlan) -> Self {
         VotePlan {
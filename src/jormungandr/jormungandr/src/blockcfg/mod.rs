@@ -1,5 +1,5 @@
 pub use chain_impl_mockchain::{
-    block::{builder as block_builder, Block},
+    block::{builder as block_builder, Block, BlockReadError, BlockReader},
     chaineval::HeaderContentEvalContext,
     chaintypes::ConsensusVersion,
     config::{self, Block0Date, ConfigParam},
@@ -22,6 +22,16 @@ use thiserror::Error;
 
 pub type HeaderHash = HeaderId;
 
+/// The largest block the node reads from its storage or from the network.
+/// Block0 carries the initial funds and can be much larger than the blocks
+/// allowed by the ledger settings afterwards, so the limit is generous.
+pub const MAX_BLOCK_SIZE: usize = 64 * 1024 * 1024;
+
+/// Reader of the blocks of at most `MAX_BLOCK_SIZE` bytes
+pub fn block_reader() -> BlockReader {
+    BlockReader::new(MAX_BLOCK_SIZE)
+}
+
 #[derive(Debug, Error)]
 pub enum Block0Error {
     #[error("Block0 Initial settings: {0}")]
@@ -299,7 +299,9 @@ impl Blockchain {
 
     pub async fn gc(&self, tip: Arc<Ref>) -> Result<()> {
         let depth = tip.ledger().settings().epoch_stability_depth;
-        self.ledgers.gc(depth).await;
+        self.ledgers
+            .gc(depth, tip.hash(), &[self.block0], &self.storage)
+            .await;
         self.storage.gc(depth, tip.hash().as_ref())?;
         Ok(())
     }
@@ -1,5 +1,5 @@
 use crate::blockcfg::{ChainLength, HeaderHash, Multiverse as MultiverseData};
-use chain_impl_mockchain::multiverse;
+use chain_impl_mockchain::multiverse::{self, AncestryOracle};
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
@@ -46,9 +46,16 @@ impl<T: Clone> Multiverse<T> {
 impl<T> Multiverse<T> {
     /// run the garbage collection of the multiverse
     ///
-    pub async fn gc(&self, depth: u32) {
+    /// old states are kept only if they are ancestors of `tip` or listed in `roots`
+    pub async fn gc<O: AncestryOracle>(
+        &self,
+        depth: u32,
+        tip: HeaderHash,
+        roots: &[HeaderHash],
+        oracle: &O,
+    ) {
         let mut guard = self.inner.write().await;
-        guard.gc(depth)
+        guard.gc_with_ancestry(depth, &tip, roots, oracle)
     }
 }
 
@@ -7,7 +7,7 @@ use super::{
 use crate::{
     blockcfg::{Block, Header, HeaderHash},
     blockchain::Checkpoints,
-    intercom::{self, BlockMsg, NetworkMsg, PropagateMsg, TransactionMsg, WatchMsg},
+    intercom::{self, BlockMsg, NetworkMsg, PropagateMsg, ReplyHandle, TransactionMsg, WatchMsg},
     metrics::{Metrics, MetricsBackend},
     topology::NodeId,
     utils::{
@@ -19,13 +19,17 @@ use crate::{
     },
 };
 use chain_core::property::{Block as _, Header as _};
+use chain_network::error::{ErrorDetails, ErrorDomain};
 use futures::prelude::*;
 use std::{sync::Arc, time::Duration};
+use tokio::sync::mpsc;
 use tracing::{span, Level};
 use tracing_futures::Instrument;
 
 type PullHeadersScheduler = FireForgetScheduler<HeaderHash, NodeId, Checkpoints>;
 type GetNextBlockScheduler = FireForgetScheduler<HeaderHash, NodeId, ()>;
+/// Held by every block processing task, the channel is closed once all of them are done
+type InFlightGuard = mpsc::Sender<()>;
 
 const TIP_UPDATE_QUEUE_SIZE: usize = 10;
 
@@ -77,6 +81,8 @@ struct Process {
     pull_headers_scheduler: PullHeadersScheduler,
     get_next_block_scheduler: GetNextBlockScheduler,
     service_info: TokioServiceInfo,
+    in_flight: Option<InFlightGuard>,
+    in_flight_done: Option<mpsc::Receiver<()>>,
 }
 
 fn spawn_pull_headers_scheduler(
@@ -150,6 +156,7 @@ pub async fn start(
         spawn_pull_headers_scheduler(network_msgbox.clone(), &service_info);
     let get_next_block_scheduler =
         spawn_get_next_block_scheduler(network_msgbox.clone(), &service_info);
+    let (in_flight, in_flight_done) = mpsc::channel(1);
 
     Process {
         blockchain,
@@ -163,6 +170,8 @@ pub async fn start(
         pull_headers_scheduler,
         get_next_block_scheduler,
         service_info,
+        in_flight: Some(in_flight),
+        in_flight_done: Some(in_flight_done),
     }
     .start(input, tip_update_queue)
     .await
@@ -200,6 +209,11 @@ impl Process {
         let watch_msg_box = self.watch_msgbox.clone();
         let stats_counter = self.stats_counter.clone();
         tracing::trace!("handling new blockchain task item");
+        if self.in_flight.is_none() && !matches!(input, BlockMsg::Drain(_)) {
+            tracing::debug!("block task is drained, dropping new item");
+            return;
+        }
+        let in_flight = self.in_flight.clone();
         match input {
             BlockMsg::LeadershipBlock(leadership_block) => {
                 let span = span!(
@@ -216,14 +230,17 @@ impl Process {
                 self.service_info.timeout_spawn_fallible(
                     "process leadership block",
                     Duration::from_secs(DEFAULT_TIMEOUT_PROCESS_LEADERSHIP),
-                    process_leadership_block(
-                        blockchain,
-                        self.tip_update_mbox.clone(),
-                        network_msg_box,
-                        watch_msg_box,
-                        *leadership_block,
-                    )
-                    .instrument(span.clone()),
+                    track_in_flight(
+                        in_flight,
+                        process_leadership_block(
+                            blockchain,
+                            self.tip_update_mbox.clone(),
+                            network_msg_box,
+                            watch_msg_box,
+                            *leadership_block,
+                        )
+                        .instrument(span.clone()),
+                    ),
                 );
             }
             BlockMsg::AnnouncedBlock(header, node_id) => {
@@ -242,15 +259,18 @@ impl Process {
                 self.service_info.timeout_spawn_fallible(
                     "process block announcement",
                     Duration::from_secs(DEFAULT_TIMEOUT_PROCESS_ANNOUNCEMENT),
-                    process_block_announcement(
-                        blockchain,
-                        blockchain_tip,
-                        *header,
-                        node_id,
-                        self.pull_headers_scheduler.clone(),
-                        self.get_next_block_scheduler.clone(),
-                    )
-                    .instrument(span.clone()),
+                    track_in_flight(
+                        in_flight,
+                        process_block_announcement(
+                            blockchain,
+                            blockchain_tip,
+                            *header,
+                            node_id,
+                            self.pull_headers_scheduler.clone(),
+                            self.get_next_block_scheduler.clone(),
+                        )
+                        .instrument(span.clone()),
+                    ),
                 )
             }
             BlockMsg::NetworkBlocks(handle) => {
@@ -265,16 +285,19 @@ impl Process {
                 self.service_info.timeout_spawn_fallible(
                     "process network blocks",
                     Duration::from_secs(DEFAULT_TIMEOUT_PROCESS_BLOCKS),
-                    process_network_blocks(
-                        self.blockchain.clone(),
-                        self.tip_update_mbox.clone(),
-                        network_msg_box,
-                        watch_msg_box,
-                        self.get_next_block_scheduler.clone(),
-                        handle,
-                        stats_counter,
-                    )
-                    .instrument(span.clone()),
+                    track_in_flight(
+                        in_flight,
+                        process_network_blocks(
+                            self.blockchain.clone(),
+                            self.tip_update_mbox.clone(),
+                            network_msg_box,
+                            watch_msg_box,
+                            self.get_next_block_scheduler.clone(),
+                            handle,
+                            stats_counter,
+                        )
+                        .instrument(span.clone()),
+                    ),
                 );
             }
             BlockMsg::ChainHeaders(handle) => {
@@ -285,19 +308,37 @@ impl Process {
                 self.service_info.timeout_spawn(
                     "process network headers",
                     Duration::from_secs(DEFAULT_TIMEOUT_PROCESS_HEADERS),
-                    process_chain_headers(
-                        blockchain,
-                        handle,
-                        self.pull_headers_scheduler.clone(),
-                        network_msg_box,
-                    )
-                    .instrument(span.clone()),
+                    track_in_flight(
+                        in_flight,
+                        process_chain_headers(
+                            blockchain,
+                            handle,
+                            self.pull_headers_scheduler.clone(),
+                            network_msg_box,
+                        )
+                        .instrument(span.clone()),
+                    ),
                 );
             }
+            BlockMsg::Drain(reply_handle) => self.drain(reply_handle),
         }
         tracing::trace!("item handling finished");
     }
 
+    fn drain(&mut self, reply_handle: ReplyHandle<()>) {
+        tracing::info!("draining block task, new blocks will be ignored");
+        // dropping our own guard lets the channel close when the last task in flight is done
+        self.in_flight = None;
+        let in_flight_done = self.in_flight_done.take();
+        self.service_info.spawn("drain", async move {
+            if let Some(mut in_flight_done) = in_flight_done {
+                while in_flight_done.recv().await.is_some() {}
+            }
+            tracing::debug!("all blocks in flight are processed");
+            reply_handle.reply_ok(());
+        });
+    }
+
     fn start_garbage_collector(&self, info: &TokioServiceInfo) {
         let blockchain = self.blockchain.clone();
         let tip = self.blockchain_tip.clone();
@@ -314,6 +355,11 @@ impl Process {
     }
 }
 
+async fn track_in_flight<F: Future>(guard: Option<InFlightGuard>, future: F) -> F::Output {
+    let _guard = guard;
+    future.await
+}
+
 async fn process_and_propagate_new_ref(
     new_block_ref: Arc<Ref>,
     mut tip_update_mbox: MessageBox<Arc<Ref>>,
@@ -447,6 +493,7 @@ async fn process_network_blocks(
         let (maybe_block, stream_tail) = stream.into_future().await;
         match maybe_block {
             Some(block) => {
+                let block_id = block.id();
                 let res = process_network_block(
                     &blockchain,
                     block.clone(),
@@ -469,7 +516,7 @@ async fn process_network_blocks(
                             reason = ?e,
                             "validation of an incoming block failed"
                         );
-                        reply.reply_error(network_block_error_into_reply(e));
+                        reply.reply_error(network_block_error_into_reply(e, block_id));
                         break candidate;
                     }
                 }
@@ -611,7 +658,7 @@ async fn process_chain_headers(
     }
 }
 
-fn network_block_error_into_reply(err: chain::Error) -> intercom::Error {
+fn network_block_error_into_reply(err: chain::Error, block_id: HeaderHash) -> intercom::Error {
     use super::chain::Error::*;
 
     match err {
@@ -619,7 +666,8 @@ fn network_block_error_into_reply(err: chain::Error) -> intercom::Error {
         Ledger(e) => intercom::Error::failed_precondition(e),
         Block0(e) => intercom::Error::failed(e),
         MissingParentBlock(_) => intercom::Error::failed_precondition(err.to_string()),
-        BlockHeaderVerificationFailed(_) => intercom::Error::invalid_argument(err.to_string()),
+        BlockHeaderVerificationFailed(_) => intercom::Error::invalid_argument(err.to_string())
+            .with_details(ErrorDetails::new(ErrorDomain::Protocol).with_item_id(block_id)),
         _ => intercom::Error::failed(err.to_string()),
     }
 }
@@ -1,11 +1,12 @@
 use crate::{
-    blockcfg::{Block, HeaderHash},
+    blockcfg::{self, Block, BlockReadError, HeaderHash},
     intercom::{self, ReplySendError, ReplyStreamHandle},
 };
 use chain_core::{
     packer::Codec,
     property::{Deserialize, ReadError, Serialize, WriteError},
 };
+use chain_impl_mockchain::multiverse::AncestryOracle;
 use chain_storage::{BlockInfo, BlockStore, Error as StorageError};
 use futures::prelude::*;
 use std::{convert::identity, path::Path};
@@ -14,6 +15,12 @@ use tracing::Span;
 
 const MINIMUM_BLOCKS_TO_FLUSH: usize = 256;
 
+fn read_block(raw_block: &[u8]) -> Result<Block, Error> {
+    blockcfg::block_reader()
+        .read(&mut Codec::new(raw_block))
+        .map_err(Error::ReadBlock)
+}
+
 #[derive(Debug, Error)]
 pub enum Error {
     #[error("block not found")]
@@ -22,6 +29,8 @@ pub enum Error {
     BackendError(#[source] StorageError),
     #[error("deserialization error")]
     Deserialize(#[source] ReadError),
+    #[error("cannot read block")]
+    ReadBlock(#[source] BlockReadError),
     #[error("serialization error")]
     Serialize(#[source] WriteError),
     #[error("Block already present in DB")]
@@ -95,9 +104,7 @@ impl Storage {
 
     pub fn get(&self, header_hash: HeaderHash) -> Result<Option<Block>, Error> {
         match self.storage.get_block(header_hash.as_bytes()) {
-            Ok(block) => Block::deserialize(&mut Codec::new(block.as_ref()))
-                .map(Some)
-                .map_err(Error::Deserialize),
+            Ok(block) => read_block(block.as_ref()).map(Some),
             Err(StorageError::BlockNotFound) => Ok(None),
             Err(e) => Err(Error::BackendError(e)),
         }
@@ -127,9 +134,8 @@ impl Storage {
             .and_then(|blocks| {
                 blocks
                     .into_iter()
-                    .map(|block| Block::deserialize(&mut Codec::new(block.as_ref())))
+                    .map(|block| read_block(block.as_ref()))
                     .collect::<Result<Vec<_>, _>>()
-                    .map_err(Error::Deserialize)
             })
     }
 
@@ -147,9 +153,7 @@ impl Storage {
                     .storage
                     .get_block(block.id().as_ref())
                     .expect("already found this block, it must exists inside the storage");
-                Block::deserialize(&mut Codec::new(block.as_ref()))
-                    .map(Some)
-                    .map_err(Error::Deserialize)
+                read_block(block.as_ref()).map(Some)
             }
             Err(StorageError::BlockNotFound) => Ok(None),
             Err(e) => Err(Error::BackendError(e)),
@@ -177,6 +181,11 @@ impl Storage {
             .map_err(Into::into)
     }
 
+    /// Make sure everything written so far is persisted on disk
+    pub fn flush(&self) -> Result<(), Error> {
+        self.storage.flush().map_err(Into::into)
+    }
+
     pub fn get_parent(&self, header_hash: HeaderHash) -> Result<Option<HeaderHash>, Error> {
         let block_info = match self.storage.get_block_info(header_hash.as_ref()) {
             Ok(block_info) => block_info,
@@ -221,9 +230,7 @@ impl Storage {
 
         let stream = futures::stream::iter(self.storage.iter(to.as_bytes(), distance)?)
             .map_err(Into::into)
-            .and_then(|raw_block| async move {
-                Block::deserialize(&mut Codec::new(raw_block.as_ref())).map_err(Error::Deserialize)
-            })
+            .and_then(|raw_block| async move { read_block(raw_block.as_ref()) })
             .map_err(Into::into);
 
         Ok(stream)
@@ -271,10 +278,9 @@ impl Storage {
 
         let mut stream = futures::stream::iter(iter)
             .map(|raw_block_result| {
-                raw_block_result.map_err(Into::into).and_then(|raw_block| {
-                    Block::deserialize(&mut Codec::new(raw_block.as_ref()))
-                        .map_err(Error::Deserialize)
-                })
+                raw_block_result
+                    .map_err(Into::into)
+                    .and_then(|raw_block| read_block(raw_block.as_ref()))
             })
             .map_ok(transform)
             .map_err(Into::into)
@@ -389,3 +395,9 @@ impl Storage {
         Ok(())
     }
 }
+
+impl AncestryOracle for Storage {
+    fn is_ancestor(&self, ancestor: &HeaderHash, descendant: &HeaderHash) -> bool {
+        Storage::is_ancestor(self, *ancestor, *descendant)
+    }
+}
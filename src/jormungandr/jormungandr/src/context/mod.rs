@@ -1,7 +1,7 @@
 use crate::{
     blockchain::{Blockchain, Tip},
     diagnostic::Diagnostic,
-    intercom::{NetworkMsg, TopologyMsg, TransactionMsg},
+    intercom::{BlockMsg, NetworkMsg, TopologyMsg, TransactionMsg},
     leadership::Logs as LeadershipLogs,
     metrics::backends::SimpleCounter,
     network::GlobalStateR as NetworkStateR,
@@ -57,6 +57,8 @@ pub enum Error {
     BlockchainTip,
     #[error("Diagnostic data not set in REST/RPC context")]
     Diagnostic,
+    #[error("Node is shutting down")]
+    ShuttingDown,
 }
 
 impl warp::reject::Reject for Error {}
@@ -109,6 +111,14 @@ impl Context {
         &self.node_state
     }
 
+    /// Fails once a graceful shutdown has started, so that no new fragments are accepted
+    pub fn check_not_shutting_down(&self) -> Result<(), Error> {
+        match self.node_state {
+            NodeState::ShuttingDown => Err(Error::ShuttingDown),
+            _ => Ok(()),
+        }
+    }
+
     pub fn set_span(&mut self, span: Span) {
         self.span = Some(span);
     }
@@ -166,6 +176,8 @@ pub struct FullContext {
     pub network_task: MessageBox<NetworkMsg>,
     pub topology_task: MessageBox<TopologyMsg>,
     pub transaction_task: MessageBox<TransactionMsg>,
+    pub block_task: MessageBox<BlockMsg>,
+    pub network_shutdown: CancellationToken,
     pub leadership_logs: LeadershipLogs,
     pub enclave: Enclave,
     #[cfg(feature = "evm")]
@@ -123,12 +123,28 @@ impl Pool {
             }
         }
 
+        if fragment.serialized_size() > ledger_settings.fragment_max_size() as usize {
+            tracing::debug!("fragment is too large, not including to the pool");
+            return Err(FragmentRejectionReason::FragmentTooLarge);
+        }
+
         if !is_fragment_valid(fragment) {
             tracing::debug!("fragment is invalid, not including to the pool");
             return Err(FragmentRejectionReason::FragmentInvalid);
         }
 
-        if let Some(persistent_log) = self.persistent_log.as_mut() {
+        tracing::debug!("including fragment to the pool");
+        Ok(())
+    }
+
+    /// Writes the accepted fragments to the persistent log, if any, and
+    /// flushes it.
+    async fn write_persistent_log(&mut self, fragments: &[(Fragment, FragmentId)]) {
+        let persistent_log = match self.persistent_log.as_mut() {
+            Some(persistent_log) => persistent_log,
+            None => return,
+        };
+        for (fragment, _) in fragments {
             let entry = PersistentFragmentLog {
                 time: SecondsSinceUnixEpoch::now(),
                 fragment: fragment.clone(),
@@ -144,9 +160,10 @@ impl Pool {
                 tracing::error!(err = %err, "failed to write persistent fragment log entry");
             }
         }
-
-        tracing::debug!("including fragment to the pool");
-        Ok(())
+        // flush every request to minimize possibility of losing fragments at the expense of non optimal performance
+        if let Err(error) = persistent_log.flush().await {
+            tracing::error!(%error, "failed to flush persistent logs");
+        }
     }
 
     /// Returns number of registered fragments. Setting `fail_fast` to `true` will force this
@@ -156,6 +173,7 @@ impl Pool {
         origin: FragmentOrigin,
         fragments: Vec<Fragment>,
         fail_fast: bool,
+        all_or_nothing: bool,
     ) -> Result<FragmentsProcessingSummary, Error> {
         tracing::debug!(origin = ?origin, "received {} fragments", fragments.len());
 
@@ -192,13 +210,6 @@ impl Pool {
             }
         }
 
-        // flush every request to minimize possibility of losing fragments at the expense of non optimal performance
-        if let Some(persistent_log) = self.persistent_log.as_mut() {
-            if let Err(error) = persistent_log.flush().await {
-                tracing::error!(%error, "failed to flush persistent logs");
-            }
-        }
-
         if fail_fast {
             for (_, id) in fragments {
                 tracing::error!(
@@ -211,6 +222,34 @@ impl Pool {
             }
         }
 
+        if all_or_nothing && !filtered_fragments.is_empty() {
+            let free_entries = self.pool.max_entries().saturating_sub(self.pool.len());
+            let reason = if rejected.iter().any(|info| info.reason.is_error()) {
+                Some(FragmentRejectionReason::BatchFragmentInvalid)
+            } else if filtered_fragments.len() > free_entries {
+                Some(FragmentRejectionReason::PoolOverflow)
+            } else {
+                None
+            };
+            if let Some(reason) = reason {
+                tracing::debug!(
+                    ?reason,
+                    "all_or_nothing is enabled; rejecting all the valid fragments of the batch"
+                );
+                rejected.extend(
+                    filtered_fragments
+                        .drain(..)
+                        .map(|(_, id)| RejectedFragmentInfo {
+                            id,
+                            reason: reason.clone(),
+                        }),
+                );
+            }
+        }
+
+        // only the fragments of a batch that was accepted as a whole are logged
+        self.write_persistent_log(&filtered_fragments).await;
+
         let span = tracing::trace_span!("pool_insert_fragment");
         let _enter = span.enter();
 
@@ -359,6 +398,8 @@ fn is_fragment_valid(fragment: &Fragment) -> bool {
         Fragment::VoteCast(ref tx) => is_transaction_valid(tx),
         Fragment::VoteTally(ref tx) => is_transaction_valid(tx),
         Fragment::MintToken(ref tx) => is_transaction_valid(tx),
+        Fragment::Anchor(ref tx) => is_transaction_valid(tx),
+        Fragment::MultisigDeclarationUpdate(ref tx) => is_transaction_valid(tx),
         // evm stuff
         // TODO, maybe we need to develop some evm specific stateless validation in this place
         Fragment::Evm(_) => true,
@@ -388,6 +429,8 @@ fn get_transaction_expiry_date(fragment: &Fragment) -> Option<BlockDate> {
         Fragment::VoteTally(tx) => Some(tx.as_slice().valid_until()),
         Fragment::MintToken(tx) => Some(tx.as_slice().valid_until()),
         Fragment::EvmMapping(tx) => Some(tx.as_slice().valid_until()),
+        Fragment::Anchor(tx) => Some(tx.as_slice().valid_until()),
+        Fragment::MultisigDeclarationUpdate(tx) => Some(tx.as_slice().valid_until()),
     }
 }
 
@@ -121,7 +121,7 @@ impl Process {
                         match maybe_msg {
                             None => break,
                             Some(msg) => match msg {
-                                TransactionMsg::SendTransactions { origin, fragments, fail_fast, reply_handle } => {
+                                TransactionMsg::SendTransactions { origin, fragments, fail_fast, all_or_nothing, reply_handle } => {
                                     // Note that we cannot use apply_block here, since we don't have a valid context to which to apply
                                     // those blocks. one valid tx in a given context, could be invalid in another. for example
                                     // fee calculations, existence utxo / account solvency.
@@ -136,7 +136,7 @@ impl Process {
                                     async {
                                         let stats_counter = stats_counter.clone();
                                         let summary = pool
-                                            .insert_and_propagate_all(origin, fragments, fail_fast)
+                                            .insert_and_propagate_all(origin, fragments, fail_fast, all_or_nothing)
                                             .await?;
 
                                         stats_counter.add_tx_recv_cnt(summary.accepted.len());
@@ -159,8 +159,8 @@ impl Process {
                                         pool.remove_expired_txs().await;
                                     }.instrument(span).await
                                 }
-                                TransactionMsg::GetLogs(reply_handle) => {
-                                    let logs = pool.logs().logs().cloned().collect();
+                                TransactionMsg::GetLogs(query, reply_handle) => {
+                                    let logs = query.apply(pool.logs().logs()).cloned().collect();
                                     reply_handle.reply_ok(logs);
                                 }
                                 TransactionMsg::GetStatuses(fragment_ids, reply_handle) => {
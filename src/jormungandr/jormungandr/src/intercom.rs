@@ -3,7 +3,7 @@ use crate::{
     blockchain::{Checkpoints, LeadershipBlock, StorageError},
     fragment::selection::FragmentSelectionAlgorithmParams,
     network::p2p::comm::PeerInfo,
-    topology::{Gossips, NodeId, Peer, PeerInfo as TopologyPeerInfo, View},
+    topology::{Gossips, NodeId, Peer, PeerInfo as TopologyPeerInfo, PeerRef, View},
     utils::async_msg::{self, MessageBox, MessageQueue},
 };
 use chain_impl_mockchain::fragment::Contents as FragmentContents;
@@ -14,7 +14,8 @@ use futures::{
     ready,
 };
 use jormungandr_lib::interfaces::{
-    BlockDate, FragmentLog, FragmentOrigin, FragmentStatus, FragmentsProcessingSummary,
+    BlockDate, FragmentLog, FragmentLogsQuery, FragmentOrigin, FragmentStatus,
+    FragmentsProcessingSummary,
 };
 use poldercast::layer::Selection;
 use std::{
@@ -24,6 +25,7 @@ use std::{
     marker::PhantomData,
     pin::Pin,
     task::{Context, Poll},
+    time::Duration,
 };
 
 /// The error values passed via intercom messages.
@@ -31,6 +33,7 @@ use std::{
 pub struct Error {
     code: net_error::Code,
     cause: Box<dyn error::Error + Send + Sync>,
+    details: Option<net_error::ErrorDetails>,
 }
 
 impl Error {
@@ -41,6 +44,7 @@ impl Error {
         Error {
             code: net_error::Code::Internal,
             cause: cause.into(),
+            details: None,
         }
     }
 
@@ -51,6 +55,7 @@ impl Error {
         Error {
             code: net_error::Code::Aborted,
             cause: cause.into(),
+            details: None,
         }
     }
 
@@ -61,6 +66,7 @@ impl Error {
         Error {
             code: net_error::Code::Canceled,
             cause: cause.into(),
+            details: None,
         }
     }
 
@@ -71,6 +77,7 @@ impl Error {
         Error {
             code: net_error::Code::FailedPrecondition,
             cause: cause.into(),
+            details: None,
         }
     }
 
@@ -81,6 +88,7 @@ impl Error {
         Error {
             code: net_error::Code::InvalidArgument,
             cause: cause.into(),
+            details: None,
         }
     }
 
@@ -91,6 +99,7 @@ impl Error {
         Error {
             code: net_error::Code::NotFound,
             cause: cause.into(),
+            details: None,
         }
     }
 
@@ -98,6 +107,16 @@ impl Error {
         Error {
             code: net_error::Code::Unimplemented,
             cause: message.into().into(),
+            details: None,
+        }
+    }
+
+    /// Attaches structured details to be passed on to the peer, such as
+    /// the id of the offending block.
+    pub fn with_details(self, details: net_error::ErrorDetails) -> Self {
+        Error {
+            details: Some(details),
+            ..self
         }
     }
 
@@ -111,6 +130,7 @@ impl From<oneshot::Canceled> for Error {
         Error {
             code: net_error::Code::Unavailable,
             cause: src.into(),
+            details: None,
         }
     }
 }
@@ -124,18 +144,24 @@ impl From<StorageError> for Error {
             StorageError::BlockAlreadyPresent => net_error::Code::Internal,
             StorageError::MissingParent => net_error::Code::InvalidArgument,
             StorageError::Deserialize(_) => net_error::Code::Internal,
+            StorageError::ReadBlock(_) => net_error::Code::Internal,
             StorageError::Serialize(_) => net_error::Code::Internal,
         };
         Error {
             code,
             cause: err.into(),
+            details: None,
         }
     }
 }
 
 impl From<Error> for net_error::Error {
     fn from(err: Error) -> Self {
-        net_error::Error::new(err.code(), err.cause)
+        let net_err = net_error::Error::new(err.code, err.cause);
+        match err.details {
+            Some(details) => net_err.with_details(details),
+            None => net_err,
+        }
     }
 }
 
@@ -506,11 +532,13 @@ pub enum TransactionMsg {
         origin: FragmentOrigin,
         fragments: Vec<Fragment>,
         fail_fast: bool,
+        /// reject the whole batch if any of the fragments is rejected
+        all_or_nothing: bool,
         reply_handle: ReplyHandle<FragmentsProcessingSummary>,
     },
     RemoveTransactions(Vec<FragmentId>, FragmentStatus),
     BranchSwitch(BlockDate),
-    GetLogs(ReplyHandle<Vec<FragmentLog>>),
+    GetLogs(FragmentLogsQuery, ReplyHandle<Vec<FragmentLog>>),
     GetStatuses(
         Vec<FragmentId>,
         ReplyHandle<HashMap<FragmentId, FragmentStatus>>,
@@ -585,6 +613,9 @@ pub enum BlockMsg {
     /// from the network in response to a PullHeaders request or a Missing
     /// solicitation event.
     ChainHeaders(RequestStreamHandle<Header, ()>),
+    /// Stop accepting new blocks and reply once the blocks being
+    /// processed are done, used for graceful shutdown.
+    Drain(ReplyHandle<()>),
 }
 
 /// Propagation requests for the network task.
@@ -618,6 +649,8 @@ pub enum TopologyMsg {
     ListAvailable(ReplyHandle<Vec<TopologyPeerInfo>>),
     ListNonPublic(ReplyHandle<Vec<TopologyPeerInfo>>),
     ListQuarantined(ReplyHandle<Vec<TopologyPeerInfo>>),
+    LiftQuarantine(PeerRef, ReplyHandle<Option<TopologyPeerInfo>>),
+    ExtendQuarantine(PeerRef, Duration, ReplyHandle<Option<TopologyPeerInfo>>),
 }
 
 /// Messages to the notifier task
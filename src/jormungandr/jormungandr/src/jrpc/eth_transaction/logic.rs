@@ -39,12 +39,14 @@ fn get_transaction_from_block_by_index(
 }
 
 pub async fn send_transaction(tx: Transaction, context: &Context) -> Result<H256, Error> {
+    context.check_not_shutting_down()?;
     let fragment = Fragment::Evm(tx.into());
     let (reply_handle, reply_future) = intercom::unary_reply();
     let msg = TransactionMsg::SendTransactions {
         origin: FragmentOrigin::JRpc,
         fragments: vec![fragment],
         fail_fast: true,
+        all_or_nothing: false,
         reply_handle,
     };
 
@@ -63,6 +65,7 @@ pub async fn send_transaction(tx: Transaction, context: &Context) -> Result<H256
 }
 
 pub async fn send_raw_transaction(raw_tx: Bytes, context: &Context) -> Result<H256, Error> {
+    context.check_not_shutting_down()?;
     let tx = EthereumSignedTransaction::from_bytes(raw_tx.as_ref())
         .map_err(|e| Error::TransactionDecodedError(e.to_string()))?;
     let fragment = Fragment::Evm(tx.try_into().map_err(Error::TransactionDecodedError)?);
@@ -71,6 +74,7 @@ pub async fn send_raw_transaction(raw_tx: Bytes, context: &Context) -> Result<H2
         origin: FragmentOrigin::JRpc,
         fragments: vec![fragment],
         fail_fast: true,
+        all_or_nothing: false,
         reply_handle,
     };
 
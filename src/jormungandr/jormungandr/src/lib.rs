@@ -179,6 +179,7 @@ fn start_services(bootstrapped_node: BootstrappedNode) -> Result<(), start_up::E
         stats_counter.clone(),
         span!(Level::TRACE, "task", kind = "network"),
     ));
+    let network_shutdown = CancellationToken::new();
 
     {
         let fragment_msgbox = fragment_msgbox.clone();
@@ -191,12 +192,14 @@ fn start_services(bootstrapped_node: BootstrappedNode) -> Result<(), start_up::E
             topology_box: topology_msgbox.clone(),
         };
 
+        let shutdown = network_shutdown.clone();
         services.spawn_future("network", move |_| {
             let params = network::TaskParams {
                 global_state,
                 input: network_queue,
                 channels,
                 watch: watch_client,
+                shutdown,
             };
             network::start(params)
         });
@@ -267,7 +270,7 @@ fn start_services(bootstrapped_node: BootstrappedNode) -> Result<(), start_up::E
 
     {
         let logs = leadership_logs.clone();
-        let block_message = block_msgbox;
+        let block_message = block_msgbox.clone();
         let tip = blockchain_tip.clone();
         let enclave = leadership::Enclave::new(enclave.clone());
         let pool = fragment_msgbox.clone();
@@ -320,6 +323,8 @@ fn start_services(bootstrapped_node: BootstrappedNode) -> Result<(), start_up::E
             network_task: network_msgbox,
             transaction_task: fragment_msgbox,
             topology_task: topology_msgbox,
+            block_task: block_msgbox,
+            network_shutdown,
             leadership_logs,
             #[cfg(feature = "evm")]
             evm_keys,
@@ -37,6 +37,14 @@ pub struct Prometheus {
     block_chain_length: UIntGauge,
     block_time: UIntGauge,
     block_hash: Vec<UIntGauge>,
+    ledger_utxos: UIntGauge,
+    ledger_old_utxos: UIntGauge,
+    ledger_accounts: UIntGauge,
+    ledger_multisig_accounts: UIntGauge,
+    ledger_multisig_declarations: UIntGauge,
+    ledger_stake_pools: UIntGauge,
+    ledger_vote_plans: UIntGauge,
+    ledger_approximate_size: UIntGauge,
 
     block_hash_value: ArcSwapOption<BlockContentHash>,
 }
@@ -152,6 +160,20 @@ impl Default for Prometheus {
             pcs
         };
 
+        let ledger_gauge = |name: &str| {
+            let gauge = UIntGauge::new(name, name).unwrap();
+            registry.register(Box::new(gauge.clone())).unwrap();
+            gauge
+        };
+        let ledger_utxos = ledger_gauge("ledgerUtxos");
+        let ledger_old_utxos = ledger_gauge("ledgerOldUtxos");
+        let ledger_accounts = ledger_gauge("ledgerAccounts");
+        let ledger_multisig_accounts = ledger_gauge("ledgerMultisigAccounts");
+        let ledger_multisig_declarations = ledger_gauge("ledgerMultisigDeclarations");
+        let ledger_stake_pools = ledger_gauge("ledgerStakePools");
+        let ledger_vote_plans = ledger_gauge("ledgerVotePlans");
+        let ledger_approximate_size = ledger_gauge("ledgerApproximateSizeBytes");
+
         Self {
             registry,
             tx_recv_cnt,
@@ -174,6 +196,14 @@ impl Default for Prometheus {
             block_chain_length,
             block_time,
             block_hash,
+            ledger_utxos,
+            ledger_old_utxos,
+            ledger_accounts,
+            ledger_multisig_accounts,
+            ledger_multisig_declarations,
+            ledger_stake_pools,
+            ledger_vote_plans,
+            ledger_approximate_size,
             block_hash_value: Default::default(),
         }
     }
@@ -268,6 +298,8 @@ impl MetricsBackend for Prometheus {
                     Fragment::UpdateProposal(tx) => totals(tx),
                     Fragment::UpdateVote(tx) => totals(tx),
                     Fragment::EvmMapping(tx) => totals(tx),
+                    Fragment::Anchor(tx) => totals(tx),
+                    Fragment::MultisigDeclarationUpdate(tx) => totals(tx),
                     Fragment::Initial(_) | Fragment::OldUtxoDeclaration(_) | Fragment::Evm(_) => {
                         return Ok(())
                     }
@@ -300,6 +332,19 @@ impl MetricsBackend for Prometheus {
                 .as_secs(),
         );
 
+        let ledger_stats = block_ref.ledger().stats();
+        self.ledger_utxos.set(ledger_stats.utxos as u64);
+        self.ledger_old_utxos.set(ledger_stats.old_utxos as u64);
+        self.ledger_accounts.set(ledger_stats.accounts as u64);
+        self.ledger_multisig_accounts
+            .set(ledger_stats.multisig_accounts as u64);
+        self.ledger_multisig_declarations
+            .set(ledger_stats.multisig_declarations as u64);
+        self.ledger_stake_pools.set(ledger_stats.stake_pools as u64);
+        self.ledger_vote_plans.set(ledger_stats.vote_plans as u64);
+        self.ledger_approximate_size
+            .set(ledger_stats.approximate_size as u64);
+
         let block_hash = block.header().hash();
         self.block_hash_value.store(Some(Arc::new(block_hash)));
     }
@@ -206,6 +206,8 @@ impl MetricsBackend for SimpleCounter {
                     Fragment::UpdateProposal(tx) => totals(tx),
                     Fragment::UpdateVote(tx) => totals(tx),
                     Fragment::EvmMapping(tx) => totals(tx),
+                    Fragment::Anchor(tx) => totals(tx),
+                    Fragment::MultisigDeclarationUpdate(tx) => totals(tx),
                     Fragment::Initial(_) | Fragment::OldUtxoDeclaration(_) | Fragment::Evm(_) => {
                         return Ok(())
                     }
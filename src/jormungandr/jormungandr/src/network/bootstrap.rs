@@ -2,7 +2,7 @@ use super::grpc;
 use crate::{
     blockchain::{self, Blockchain, BootstrapError, Error as BlockchainError, Tip},
     network::convert::Decode,
-    settings::start::network::Peer,
+    settings::start::{config::BlockCompression, network::Peer},
     topology,
 };
 use chain_core::property::ReadError;
@@ -45,7 +45,7 @@ const MAX_BOOTSTRAP_PEERS: u32 = 32;
 pub async fn peers_from_trusted_peer(peer: &Peer) -> Result<Vec<topology::Peer>, Error> {
     tracing::info!("getting peers from bootstrap peer {}", peer.connection);
 
-    let mut client = grpc::connect(peer).await.map_err(Error::Connect)?;
+    let mut client = grpc::connect(peer, None).await.map_err(Error::Connect)?;
     let gossip = client
         .peers(MAX_BOOTSTRAP_PEERS)
         .await
@@ -64,6 +64,7 @@ pub async fn peers_from_trusted_peer(peer: &Peer) -> Result<Vec<topology::Peer>,
 
 pub async fn bootstrap_from_peer(
     peer: &Peer,
+    block_compression: Option<BlockCompression>,
     blockchain: Blockchain,
     tip: Tip,
     cancellation_token: CancellationToken,
@@ -84,9 +85,12 @@ pub async fn bootstrap_from_peer(
 
     tracing::debug!("connecting to bootstrap peer {}", peer.connection);
 
-    let mut client = with_cancellation_token(grpc::connect(peer).boxed(), &cancellation_token)
-        .await?
-        .map_err(Error::Connect)?;
+    let mut client = with_cancellation_token(
+        grpc::connect(peer, block_compression).boxed(),
+        &cancellation_token,
+    )
+    .await?
+    .map_err(Error::Connect)?;
 
     loop {
         let remote_tip = with_cancellation_token(client.tip().boxed(), &cancellation_token)
@@ -1,7 +1,13 @@
 use super::{Client, ClientBuilder, InboundSubscriptions};
 use crate::{
     blockcfg::HeaderHash,
-    network::{grpc, p2p::comm::PeerComms, security_params::NONCE_LEN, Channels, ConnectionState},
+    network::{
+        grpc,
+        p2p::comm::PeerComms,
+        security_params::NONCE_LEN,
+        subscription::{buffer_fragments, buffer_gossip, dedup_fragments},
+        Channels, ConnectionState,
+    },
     topology::NodeId,
 };
 use chain_core::{
@@ -41,7 +47,7 @@ pub fn connect(
     let cf = async move {
         let mut grpc_client = {
             tracing::debug!("connecting");
-            grpc::connect(&peer).await
+            grpc::connect_as_node(&peer, state.global.config.block_compression, keypair).await
         }
         .map_err(ConnectError::Transport)?;
 
@@ -75,12 +81,16 @@ pub fn connect(
             )));
         }
 
-        tracing::debug!(node_id = %peer_id, "authenticated server peer node");
+        tracing::debug!(
+            node_id = %peer_id,
+            capabilities = %hr.capabilities,
+            block_compression = ?grpc_client.block_compression(),
+            "authenticated server peer node"
+        );
 
         // Send client authentication
-        let auth = keypair.sign(&hr.nonce);
         grpc_client
-            .client_auth(auth)
+            .authenticate(&hr.nonce)
             .await
             .map_err(ConnectError::ClientAuth)?;
 
@@ -101,8 +111,12 @@ pub fn connect(
         let inbound = InboundSubscriptions {
             peer_id,
             block_events: block_sub,
-            fragments: fragment_sub,
-            gossip: gossip_sub,
+            fragments: dedup_fragments(
+                buffer_fragments(fragment_sub, peer_id, &state.global, true),
+                peer_id,
+                &state.global,
+            ),
+            gossip: buffer_gossip(gossip_sub, peer_id, &state.global),
         };
         let builder = ClientBuilder {
             channels,
@@ -6,13 +6,17 @@ use super::{
     convert::{Decode, Encode},
     grpc::{
         self,
-        client::{BlockSubscription, FragmentSubscription, GossipSubscription},
+        client::{
+            BlockSubscription, BufferedInbound, DedupInbound, FragmentSubscription,
+            GossipSubscription,
+        },
     },
     p2p::comm::{OutboundSubscription, PeerComms},
     subscription::{BlockAnnouncementProcessor, Direction, FragmentProcessor, GossipProcessor},
     Channels, GlobalStateR,
 };
 use crate::{
+    blockcfg::FragmentId,
     intercom::{self, BlockMsg, ClientMsg},
     topology::NodeId,
     utils::async_msg::MessageBox,
@@ -105,8 +109,8 @@ impl Client {
 struct InboundSubscriptions {
     pub peer_id: NodeId,
     pub block_events: BlockSubscription,
-    pub fragments: FragmentSubscription,
-    pub gossip: GossipSubscription,
+    pub fragments: DedupInbound<BufferedInbound<FragmentSubscription>, FragmentId>,
+    pub gossip: BufferedInbound<GossipSubscription>,
 }
 
 #[derive(Copy, Clone)]
@@ -455,7 +459,12 @@ impl Client {
                 Ok(Continue).into()
             }
             Poll::Ready(None) => {
-                tracing::debug!("fragment subscription ended by the peer");
+                let stats = self.inbound.fragments.stats();
+                tracing::debug!(
+                    dropped = stats.dropped(),
+                    coalesced = stats.coalesced(),
+                    "fragment subscription ended by the peer"
+                );
                 Ok(Disconnect).into()
             }
             Poll::Ready(Some(Err(e))) => {
@@ -487,7 +496,10 @@ impl Client {
                 Ok(Continue).into()
             }
             Poll::Ready(None) => {
-                tracing::debug!("gossip subscription ended by the peer");
+                tracing::debug!(
+                    dropped = self.inbound.gossip.stats().dropped(),
+                    "gossip subscription ended by the peer"
+                );
                 Ok(Disconnect).into()
             }
             Poll::Ready(Some(Err(e))) => {
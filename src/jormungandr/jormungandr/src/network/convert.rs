@@ -1,5 +1,5 @@
 use crate::{
-    blockcfg::{Block, Fragment, Header, HeaderId},
+    blockcfg::{self, Block, Fragment, Header, HeaderId},
     intercom,
     topology::{Gossip, Gossips, NodeId},
 };
@@ -12,6 +12,7 @@ use chain_network::{
     error::{Code, Error},
 };
 use futures::stream::{self, TryStreamExt};
+use jormungandr_lib::interfaces::{FragmentRejectionReason, FragmentsProcessingSummary};
 use std::convert::TryFrom;
 
 fn read<T, U>(src: &T) -> Result<U, Error>
@@ -71,7 +72,9 @@ impl Decode for net_data::Block {
     type Object = Block;
 
     fn decode(self) -> Result<Self::Object, Error> {
-        read(&self)
+        blockcfg::block_reader()
+            .read(&mut Codec::new(self.as_bytes()))
+            .map_err(|e| Error::new(Code::InvalidArgument, e))
     }
 }
 
@@ -95,8 +98,10 @@ impl Decode for net_data::Fragment {
 impl Decode for net_data::gossip::Node {
     type Object = Gossip;
     fn decode(self) -> Result<Self::Object, Error> {
-        Gossip::deserialize_from_slice(&mut Codec::new(self.as_bytes()))
-            .map_err(|e| Error::new(Code::InvalidArgument, e))
+        // sections added by peers of newer versions are ignored
+        let envelope = net_data::gossip::GossipEnvelope::decode(&self)?;
+        tracing::trace!(version = ?envelope.version(), "decoding gossip");
+        Gossip::from_payload(envelope.payload()).map_err(|e| Error::new(Code::InvalidArgument, e))
     }
 }
 
@@ -151,12 +156,43 @@ impl Encode for Fragment {
     }
 }
 
+impl Encode for FragmentsProcessingSummary {
+    type NetworkData = Vec<net_data::FragmentAck>;
+
+    fn encode(&self) -> Self::NetworkData {
+        use net_data::fragment::{FragmentRejectionReason as Reason, FragmentStatus};
+
+        let accepted = self.accepted.iter().map(|id| net_data::FragmentAck {
+            id: net_data::FragmentId::try_from(id.as_bytes()).unwrap(),
+            status: FragmentStatus::Accepted,
+        });
+        let rejected = self.rejected.iter().map(|info| {
+            let reason = match info.reason {
+                FragmentRejectionReason::FragmentAlreadyInLog => Reason::AlreadyInLog,
+                FragmentRejectionReason::FragmentInvalid => Reason::Invalid,
+                FragmentRejectionReason::PreviousFragmentInvalid => Reason::PreviousInvalid,
+                FragmentRejectionReason::PoolOverflow => Reason::PoolOverflow,
+                FragmentRejectionReason::FragmentExpired => Reason::Expired,
+                FragmentRejectionReason::FragmentValidForTooLong => Reason::ValidForTooLong,
+                FragmentRejectionReason::FragmentTooLarge => Reason::TooLarge,
+                FragmentRejectionReason::BatchFragmentInvalid => Reason::PreviousInvalid,
+            };
+            net_data::FragmentAck {
+                id: net_data::FragmentId::try_from(info.id.as_bytes()).unwrap(),
+                status: FragmentStatus::Rejected(reason),
+            }
+        });
+        accepted.chain(rejected).collect()
+    }
+}
+
 impl Encode for Gossip {
     type NetworkData = net_data::gossip::Node;
 
     fn encode(&self) -> Self::NetworkData {
-        let bytes = self.serialize_as_vec().unwrap();
-        net_data::gossip::Node::from_bytes(bytes)
+        // the envelope starts with the serialized gossip, so it can still be
+        // read by peers that do not know about envelopes
+        net_data::gossip::GossipEnvelope::new(self.payload()).encode()
     }
 }
 
@@ -1,12 +1,21 @@
 use crate::{
     blockcfg::{Block, HeaderHash},
     network::{concurrency_limits, convert::Decode, keepalive_durations},
-    settings::start::network::{Peer, Protocol},
+    settings::start::{
+        config::BlockCompression,
+        network::{Peer, Protocol},
+    },
 };
 pub use chain_network::grpc::client::{
-    BlockSubscription, FragmentSubscription, GossipSubscription,
+    BlockSubscription, BufferedInbound, DedupConfig, DedupInbound, DedupStats,
+    FragmentSubscription, GossipSubscription, InboundBuffer, InboundStats, OverflowPolicy,
+    SeenCache,
+};
+use chain_network::{
+    data::{self as net_data, NodeKeyPair},
+    error as net_error,
+    grpc::client::Builder,
 };
-use chain_network::{data as net_data, error as net_error, grpc::client::Builder};
 use futures::prelude::*;
 use std::{convert::TryFrom, net::SocketAddr};
 use thiserror::Error;
@@ -33,8 +42,32 @@ pub type ConnectError = transport::Error;
 
 pub type Client = chain_network::grpc::Client<tonic::transport::Channel>;
 
-pub async fn connect(peer: &Peer) -> Result<Client, ConnectError> {
-    connect_internal(peer, Builder::new()).await
+pub async fn connect(
+    peer: &Peer,
+    block_compression: Option<BlockCompression>,
+) -> Result<Client, ConnectError> {
+    let mut builder = Builder::new();
+    builder.capabilities(super::capabilities(block_compression));
+    if let Some(compression) = block_compression {
+        builder.block_compression(super::net_compression(compression));
+    }
+    connect_internal(peer, builder).await
+}
+
+/// Connects to a peer of the p2p network, the client authenticating with
+/// the key pair of the node after the handshake.
+pub async fn connect_as_node(
+    peer: &Peer,
+    block_compression: Option<BlockCompression>,
+    keypair: NodeKeyPair,
+) -> Result<Client, ConnectError> {
+    let mut builder = Builder::new();
+    builder.auth(keypair);
+    builder.capabilities(super::capabilities(block_compression));
+    if let Some(compression) = block_compression {
+        builder.block_compression(super::net_compression(compression));
+    }
+    connect_internal(peer, builder).await
 }
 
 async fn connect_internal(peer: &Peer, builder: Builder) -> Result<Client, ConnectError> {
@@ -54,9 +87,13 @@ fn destination_endpoint(addr: SocketAddr) -> transport::Endpoint {
 
 // Fetches a block from a network peer.
 // This function is used during node bootstrap to fetch the genesis block.
-pub async fn fetch_block(peer: &Peer, hash: HeaderHash) -> Result<Block, FetchBlockError> {
+pub async fn fetch_block(
+    peer: &Peer,
+    hash: HeaderHash,
+    block_compression: Option<BlockCompression>,
+) -> Result<Block, FetchBlockError> {
     tracing::info!("fetching block {}", hash);
-    let mut client = connect(peer)
+    let mut client = connect(peer, block_compression)
         .await
         .map_err(|err| FetchBlockError::Connect { source: err })?;
     let block_id = net_data::BlockId::try_from(hash.as_bytes()).unwrap();
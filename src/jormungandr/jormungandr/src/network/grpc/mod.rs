@@ -5,3 +5,24 @@ pub use self::{
     client::{connect, fetch_block, Client, ConnectError, FetchBlockError},
     server::run_listen_socket,
 };
+
+use crate::settings::start::config::BlockCompression;
+use chain_network::{data::Capabilities, grpc::Compression};
+
+fn net_compression(compression: BlockCompression) -> Compression {
+    match compression {
+        BlockCompression::Gzip => Compression::Gzip,
+    }
+}
+
+/// Capabilities advertised to the peers in the handshake, block compression
+/// is only advertised when it is enabled in the configuration
+pub(super) fn capabilities(block_compression: Option<BlockCompression>) -> Capabilities {
+    let capabilities =
+        Capabilities::PEERS | Capabilities::TIP_SUBSCRIPTION | Capabilities::FRAGMENT_ACK;
+    if block_compression.is_some() {
+        capabilities | Capabilities::BLOCK_COMPRESSION
+    } else {
+        capabilities
+    }
+}
@@ -3,8 +3,8 @@ use super::super::{
     ListenError,
 };
 use crate::settings::start::network::Listen;
-use chain_network::grpc::{self, watch::server::Server as WatchServer};
-use tonic::transport::Server;
+use chain_network::grpc::{self, health::ServingStatus};
+use std::future::Future;
 use tracing::{span, Level};
 use tracing_futures::Instrument;
 
@@ -12,22 +12,53 @@ pub async fn run_listen_socket(
     listen: &Listen,
     state: GlobalStateR,
     channels: Channels,
-    watch_service: WatchServer<crate::watch_client::WatchClient>,
+    watch: crate::watch_client::WatchClient,
+    shutdown: impl Future<Output = ()>,
 ) -> Result<(), ListenError> {
     let sockaddr = listen.address();
     let span = span!(parent: &state.span, Level::TRACE, "listen_socket", local_addr = %sockaddr.to_string());
     async {
         tracing::info!("listening and accepting gRPC connections");
-        let service = grpc::server::Builder::new().build(NodeService::new(channels, state));
+        let mut builder = grpc::server::Builder::new();
+        builder
+            .metrics(state.peers.traffic())
+            .peer_score(state.peers.score())
+            .http2_keepalive_interval(state.config.http2_keepalive_interval)
+            .http2_keepalive_timeout(state.config.keepalive_timeout)
+            .tcp_keepalive(keepalive_durations::TCP);
+        if let Some(max) = state.config.http2_max_concurrent_streams {
+            builder.http2_max_concurrent_streams(max);
+        }
+        if let Some(max) = state.config.max_subscriptions_per_peer {
+            builder.max_subscriptions_per_peer(max);
+        }
+        if let Some(compression) = state.config.block_compression {
+            builder.block_compression(super::net_compression(compression));
+        }
+        let transport = builder.transport();
+        let reflection = state
+            .config
+            .grpc_reflection
+            .then(|| builder.reflection_service());
+        let (mut health, health_service) = builder.health_service();
+        health.set(ServingStatus::Serving).await;
+        let service = builder.build(NodeService::new(channels, state, watch.tip_receiver()));
 
-        Server::builder()
+        let shutdown = async move {
+            shutdown.await;
+            health.set(ServingStatus::NotServing).await;
+        };
+        transport
             .concurrency_limit_per_connection(concurrency_limits::SERVER_REQUESTS)
-            .tcp_keepalive(Some(keepalive_durations::TCP))
             .add_service(service)
-            .add_service(watch_service)
-            .serve(sockaddr)
+            .add_service(watch.into_server())
+            .add_service(health_service)
+            .add_optional_service(reflection)
+            .serve_with_shutdown(sockaddr, shutdown)
             .await
-            .map_err(|cause| ListenError { cause, sockaddr })
+            .map_err(|cause| ListenError { cause, sockaddr })?;
+        tracing::info!("stopped accepting gRPC connections");
+        Ok(())
     }
     .instrument(span)
     .await
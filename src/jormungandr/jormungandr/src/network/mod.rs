@@ -39,6 +39,11 @@ mod buffer_sizes {
         // The maximum number of fragments to buffer from an incoming subscription
         // while waiting for the fragment task to become ready to process them.
         pub const FRAGMENTS: usize = 128;
+
+        // The maximum number of gossip messages to read ahead from a peer
+        // subscription. By default, the oldest ones are dropped if the topology
+        // task falls behind, as they are superseded by the newer ones.
+        pub const GOSSIP: usize = 64;
     }
     pub mod outbound {
         // Size of buffer for outbound header streams.
@@ -48,6 +53,10 @@ mod buffer_sizes {
         // (GetBlocks response or an UploadBlocks request)
         // before the client request task producing them gets preempted.
         pub const BLOCKS: usize = 8;
+
+        // The maximum number of fragment acknowledgments to buffer for
+        // a subscriber before the processing of its fragments gets preempted.
+        pub const FRAGMENT_ACKS: usize = 128;
     }
 }
 
@@ -73,10 +82,25 @@ mod security_params {
     pub const NONCE_LEN: usize = 32;
 }
 
+mod overload_backoff {
+    use std::time::Duration;
+
+    // How long to wait before connecting again to an overloaded peer
+    // that does not suggest a delay
+    pub const DEFAULT: Duration = Duration::from_secs(30);
+
+    // Upper bound on the delay suggested by an overloaded peer
+    pub const MAX: Duration = Duration::from_secs(300);
+}
+
 pub use self::bootstrap::Error as BootstrapError;
-use self::{client::ConnectError, p2p::comm::Peers};
+use self::{
+    client::ConnectError,
+    grpc::client::{DedupConfig, SeenCache},
+    p2p::comm::Peers,
+};
 use crate::{
-    blockcfg::{Block, HeaderHash},
+    blockcfg::{Block, FragmentId, HeaderHash},
     blockchain::{Blockchain as NewBlockchain, Tip},
     intercom::{BlockMsg, ClientMsg, NetworkMsg, PropagateMsg, TopologyMsg, TransactionMsg},
     metrics::Metrics,
@@ -84,7 +108,7 @@ use crate::{
     topology::{self, NodeId},
     utils::async_msg::{MessageBox, MessageQueue},
 };
-use chain_network::data::NodeKeyPair;
+use chain_network::{data::NodeKeyPair, error::ErrorDetails};
 use rand::seq::SliceRandom;
 use std::{
     collections::HashSet,
@@ -159,6 +183,8 @@ pub struct GlobalState {
     peers: Peers,
     keypair: NodeKeyPair,
     span: Span,
+    // fragments recently received from any peer, if deduplicated
+    seen_fragments: Option<SeenCache<FragmentId>>,
 
     connected_count: AtomicUsize,
 }
@@ -179,12 +205,17 @@ impl GlobalState {
         let keypair =
             NodeKeyPair::from(<chain_crypto::SecretKey<_>>::from(config.node_key.clone()));
 
+        let dedup = config
+            .fragment_dedup
+            .map(|dedup| DedupConfig::new(dedup.capacity, dedup.ttl));
+
         GlobalState {
             block0_hash,
             config,
             peers,
             keypair,
             span,
+            seen_fragments: dedup.map(SeenCache::new),
             connected_count: AtomicUsize::new(0),
         }
     }
@@ -265,6 +296,9 @@ pub struct TaskParams {
     pub input: MessageQueue<NetworkMsg>,
     pub channels: Channels,
     pub watch: crate::watch_client::WatchClient,
+    /// Cancelled on graceful shutdown: the listener stops accepting connections
+    /// and all peer subscriptions are closed
+    pub shutdown: CancellationToken,
 }
 
 pub async fn start(params: TaskParams) {
@@ -275,10 +309,12 @@ pub async fn start(params: TaskParams) {
     let channels = params.channels;
     let global_state = params.global_state;
     let watch = params.watch;
+    let shutdown = params.shutdown;
 
     // open the port for listening/accepting other peers to connect too
     let listen_state = global_state.clone();
     let listen_channels = channels.clone();
+    let listen_shutdown = shutdown.clone();
     let listener = async move {
         if let Some(listen) = listen_state.config.listen() {
             match listen.protocol {
@@ -287,7 +323,8 @@ pub async fn start(params: TaskParams) {
                         &listen,
                         listen_state,
                         listen_channels,
-                        watch.into_server(),
+                        watch,
+                        listen_shutdown.cancelled(),
                     )
                     .await
                     .unwrap_or_else(|e| {
@@ -302,8 +339,15 @@ pub async fn start(params: TaskParams) {
         }
     };
 
+    let peers_state = global_state.clone();
+    let close_peers = async move {
+        shutdown.cancelled().await;
+        tracing::info!("closing all peer connections");
+        peers_state.peers.clear().await;
+    };
+
     let handle_cmds = handle_network_input(input, global_state.clone(), channels.clone());
-    future::join(listener, handle_cmds).await;
+    future::join3(listener, close_peers, handle_cmds).await;
 }
 
 async fn handle_network_input(
@@ -472,6 +516,10 @@ fn connect_and_propagate(
             return;
         }
     }
+    if state.peers.backoff().is_backing_off(&id) {
+        tracing::debug!(peer = %addr, "peer is overloaded, not connecting to it yet");
+        return;
+    }
     drop(_enter);
     let peer = Peer::new(addr);
     let conn_span = span!(parent: &state.span, Level::DEBUG, "client", %addr, %id);
@@ -482,6 +530,18 @@ fn connect_and_propagate(
         let (handle, connecting) = client::connect(conn_state, channels.clone(), id);
         state.peers.add_connecting(id, addr, handle, options).await;
         match connecting.await {
+            Err(ConnectError::Subscription(e)) if e.is_overload() => {
+                // The peer is not at fault, keep it in the topology
+                // but do not connect to it again for a while
+                let delay = e
+                    .details()
+                    .and_then(ErrorDetails::retry_after)
+                    .unwrap_or(overload_backoff::DEFAULT)
+                    .min(overload_backoff::MAX);
+                tracing::info!(reason = %e, ?delay, "peer is overloaded, backing off");
+                state.peers.backoff().back_off(id, delay);
+                state.peers.remove_peer(&id).await;
+            }
             Err(e) => {
                 let benign = match e {
                     ConnectError::Transport(e) => {
@@ -656,6 +716,7 @@ pub async fn bootstrap(
             span!(parent: span, Level::DEBUG, "bootstrap", peer_addr = %peer.address().to_string());
         let res = bootstrap::bootstrap_from_peer(
             &Peer::new(peer.address()),
+            config.block_compression,
             blockchain.clone(),
             branch.clone(),
             cancellation_token.clone(),
@@ -736,7 +797,7 @@ pub async fn fetch_block(
         for address in trusted_peers_shuffled(config) {
             let peer_span = span!(Level::TRACE, "peer_address", address = %address.to_string());
             let peer = Peer::new(address);
-            match grpc::fetch_block(&peer, hash)
+            match grpc::fetch_block(&peer, hash, config.block_compression)
                 .instrument(peer_span.clone())
                 .await
             {
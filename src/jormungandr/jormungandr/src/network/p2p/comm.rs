@@ -1,10 +1,16 @@
+mod backoff;
+mod client_ids;
+mod overflow;
 mod peer_map;
+mod score;
+mod traffic;
 use super::Address;
 use crate::{
     metrics::Metrics,
     network::{client::ConnectHandle, security_params::NONCE_LEN},
     topology::NodeId,
 };
+pub use backoff::OverloadBackoff;
 use chain_network::{
     data::{
         block::{BlockEvent, ChainPullRequest},
@@ -12,24 +18,29 @@ use chain_network::{
     },
     error::Error,
 };
+pub use client_ids::ClientIds;
 use futures::{
     channel::mpsc,
     lock::{Mutex, MutexLockFuture},
     prelude::*,
     stream,
 };
+pub use overflow::{OverflowCounter, PeerOverflow};
 use peer_map::{CommStatus, PeerMap};
+pub use score::ScoreCounter;
 use std::{
     fmt,
     fmt::Debug,
     mem,
     net::SocketAddr,
     pin::Pin,
+    sync::Arc,
     task::{Context, Poll},
     time::SystemTime,
 };
 use tracing::{debug_span, Span};
 use tracing_futures::Instrument;
+pub use traffic::{PeerTraffic, TrafficCounter};
 
 // Buffer size determines the number of stream items pending processing that
 // can be buffered before back pressure is applied to the inbound half of
@@ -463,6 +474,10 @@ pub struct PeerInfo {
     pub id: NodeId,
     pub addr: Option<SocketAddr>,
     pub stats: PeerStats,
+    /// Requests of the peer served by this node since it completed the handshake
+    pub traffic: PeerTraffic,
+    /// Messages of the peer discarded by its subscriptions
+    pub overflow: PeerOverflow,
 }
 
 /// The collection of currently connected peer nodes.
@@ -471,15 +486,46 @@ pub struct PeerInfo {
 /// all network connection tasks.
 pub struct Peers {
     mutex: Mutex<PeerMap>,
+    client_ids: Arc<ClientIds>,
+    traffic: Arc<TrafficCounter>,
+    score: Arc<ScoreCounter>,
+    overflow: OverflowCounter,
+    backoff: OverloadBackoff,
 }
 
 impl Peers {
     pub fn new(capacity: usize, stats_counter: Metrics) -> Self {
+        let client_ids = Arc::new(ClientIds::new(capacity));
         Peers {
             mutex: Mutex::new(PeerMap::new(capacity, stats_counter)),
+            traffic: Arc::new(TrafficCounter::new(capacity, Arc::clone(&client_ids))),
+            score: Arc::new(ScoreCounter::new(capacity, Arc::clone(&client_ids))),
+            client_ids,
+            overflow: OverflowCounter::new(capacity),
+            backoff: OverloadBackoff::new(capacity),
         }
     }
 
+    /// Traffic counter to be fed by the gRPC server
+    pub fn traffic(&self) -> Arc<TrafficCounter> {
+        Arc::clone(&self.traffic)
+    }
+
+    /// Misbehavior scores of the peers, to be fed by the gRPC server
+    pub fn score(&self) -> Arc<ScoreCounter> {
+        Arc::clone(&self.score)
+    }
+
+    /// Counters of the inbound subscriptions, to be registered as they are established
+    pub fn overflow(&self) -> &OverflowCounter {
+        &self.overflow
+    }
+
+    /// Peers that are not to be connected to while they are overloaded
+    pub fn backoff(&self) -> &OverloadBackoff {
+        &self.backoff
+    }
+
     fn inner(&self) -> MutexLockFuture<PeerMap> {
         self.mutex.lock()
     }
@@ -540,6 +586,7 @@ impl Peers {
     {
         let mut map = self.inner().await;
         map.complete_handshake(peer_addr, id, verify)?;
+        self.client_ids.insert(peer_addr, id);
         tracing::debug!(addr = %peer_addr, %id, "authenticated client peer node");
         Ok(())
     }
@@ -766,7 +813,75 @@ impl Peers {
     }
 
     pub async fn infos(&self) -> Vec<PeerInfo> {
-        let map = self.inner().await;
-        map.infos()
+        let mut infos = self.inner().await.infos();
+        for info in infos.iter_mut() {
+            if let Some(traffic) = self.traffic.get(&info.id) {
+                info.traffic = traffic;
+            }
+            if let Some(overflow) = self.overflow.get(&info.id) {
+                info.overflow = overflow;
+            }
+        }
+        infos
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chain_crypto::{Ed25519, SecretKey};
+    use chain_network::{
+        core::server::{Metrics as _, Request, RequestStats},
+        data::CallId,
+    };
+    use rand::SeedableRng;
+    use std::time::Duration;
+
+    fn node_id(seed: u8) -> NodeId {
+        let key = SecretKey::<Ed25519>::generate(rand_chacha::ChaChaRng::from_seed([seed; 32]));
+        NodeId::try_from(key.to_public().as_ref()).unwrap()
+    }
+
+    fn request_finished(traffic: &TrafficCounter, addr: Address, bytes: u64) {
+        let request = Request {
+            service: "PullBlocks",
+            call_id: CallId::generate(),
+            peer: addr.into(),
+        };
+        let stats = RequestStats {
+            duration: Duration::from_millis(1),
+            bytes_received: bytes,
+            bytes_sent: 2 * bytes,
+        };
+        traffic.request_finished(&request, &stats);
+    }
+
+    #[tokio::test]
+    async fn traffic_is_reported_by_node() {
+        let peers = Peers::new(16, Metrics::builder().build());
+        let id = node_id(1);
+        let addr: Address = "127.0.0.1:50001".parse().unwrap();
+        peers.generate_auth_nonce(addr).await;
+        peers
+            .server_complete_handshake(addr, id, |_| Ok(()))
+            .await
+            .unwrap();
+
+        let traffic = peers.traffic();
+        // made before the handshake on another connection
+        request_finished(&traffic, "127.0.0.1:50002".parse().unwrap(), 1000);
+        request_finished(&traffic, addr, 10);
+        request_finished(&traffic, addr, 20);
+
+        let infos = peers.infos().await;
+        let info = infos.iter().find(|info| info.id == id).unwrap();
+        assert_eq!(
+            info.traffic,
+            PeerTraffic {
+                requests: 2,
+                bytes_received: 30,
+                bytes_sent: 60,
+            }
+        );
     }
 }
@@ -0,0 +1,66 @@
+use crate::topology::NodeId;
+use lru::LruCache;
+use std::{
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+/// Keeps track of the peers that refused a connection because they were
+/// overloaded, so that the node does not connect to them again before
+/// the delay they asked for has passed.
+/// Only the most recently refused peers are kept.
+pub struct OverloadBackoff {
+    peers: Mutex<LruCache<NodeId, Instant>>,
+}
+
+impl OverloadBackoff {
+    pub fn new(capacity: usize) -> Self {
+        OverloadBackoff {
+            peers: Mutex::new(LruCache::new(capacity)),
+        }
+    }
+
+    /// Holds off connecting to the peer for the given delay
+    pub fn back_off(&self, peer: NodeId, delay: Duration) {
+        let mut peers = self.peers.lock().unwrap();
+        peers.put(peer, Instant::now() + delay);
+    }
+
+    /// Returns true if the node should not connect to the peer yet
+    pub fn is_backing_off(&self, peer: &NodeId) -> bool {
+        let mut peers = self.peers.lock().unwrap();
+        match peers.peek(peer) {
+            Some(until) if *until > Instant::now() => true,
+            Some(_) => {
+                peers.pop(peer);
+                false
+            }
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chain_crypto::{Ed25519, SecretKey};
+    use rand::SeedableRng;
+
+    fn node_id(seed: u8) -> NodeId {
+        let key = SecretKey::<Ed25519>::generate(rand_chacha::ChaChaRng::from_seed([seed; 32]));
+        NodeId::try_from(key.to_public().as_ref()).unwrap()
+    }
+
+    #[test]
+    fn backs_off_until_the_delay_has_passed() {
+        let backoff = OverloadBackoff::new(16);
+        let (overloaded, expired, other) = (node_id(1), node_id(2), node_id(3));
+        backoff.back_off(overloaded, Duration::from_secs(60));
+        backoff.back_off(expired, Duration::ZERO);
+
+        assert!(backoff.is_backing_off(&overloaded));
+        assert!(!backoff.is_backing_off(&expired));
+        assert!(!backoff.is_backing_off(&other));
+        assert!(backoff.peers.lock().unwrap().peek(&expired).is_none());
+    }
+}
@@ -0,0 +1,30 @@
+use crate::{network::p2p::Address, topology::NodeId};
+use lru::LruCache;
+use std::sync::Mutex;
+
+/// Node identifiers of the clients that completed the handshake, by the
+/// address of their connection.
+///
+/// The gRPC server only knows the remote address of a request, so the
+/// counters it feeds look the client up here to account for the node rather
+/// than for its ephemeral address. Only the most recently authenticated
+/// clients are kept.
+pub struct ClientIds {
+    ids: Mutex<LruCache<Address, NodeId>>,
+}
+
+impl ClientIds {
+    pub fn new(capacity: usize) -> Self {
+        ClientIds {
+            ids: Mutex::new(LruCache::new(capacity)),
+        }
+    }
+
+    pub fn insert(&self, addr: Address, id: NodeId) {
+        self.ids.lock().unwrap().put(addr, id);
+    }
+
+    pub fn get(&self, addr: &Address) -> Option<NodeId> {
+        self.ids.lock().unwrap().peek(addr).copied()
+    }
+}
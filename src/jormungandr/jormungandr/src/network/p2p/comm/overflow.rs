@@ -0,0 +1,81 @@
+use crate::{
+    network::grpc::client::{DedupStats, InboundStats},
+    topology::NodeId,
+};
+use lru::LruCache;
+use std::sync::Mutex;
+
+/// Messages received from a peer on its current subscriptions and discarded
+/// because the node was falling behind in processing them, or because they
+/// were recently received from another peer.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PeerOverflow {
+    pub gossip_dropped: u64,
+    pub fragments_dropped: u64,
+    pub fragments_coalesced: u64,
+    pub fragment_dedup_hits: u64,
+    pub fragment_dedup_misses: u64,
+}
+
+#[derive(Default)]
+struct Subscriptions {
+    gossip: Option<InboundStats>,
+    fragments: Option<InboundStats>,
+    fragment_dedup: Option<DedupStats>,
+}
+
+/// Keeps track of the counters of the buffered inbound subscriptions of
+/// each peer. Only the most recently subscribed peers are kept.
+pub struct OverflowCounter {
+    peers: Mutex<LruCache<NodeId, Subscriptions>>,
+}
+
+impl OverflowCounter {
+    pub fn new(capacity: usize) -> Self {
+        OverflowCounter {
+            peers: Mutex::new(LruCache::new(capacity)),
+        }
+    }
+
+    /// Registers the counters of a new gossip subscription of the peer,
+    /// replacing those of the previous one
+    pub fn gossip_subscribed(&self, peer: NodeId, stats: InboundStats) {
+        let mut peers = self.peers.lock().unwrap();
+        let mut subscriptions = peers.pop(&peer).unwrap_or_default();
+        subscriptions.gossip = Some(stats);
+        peers.put(peer, subscriptions);
+    }
+
+    /// Registers the counters of a new fragment subscription of the peer,
+    /// replacing those of the previous one
+    pub fn fragments_subscribed(&self, peer: NodeId, stats: InboundStats) {
+        let mut peers = self.peers.lock().unwrap();
+        let mut subscriptions = peers.pop(&peer).unwrap_or_default();
+        subscriptions.fragments = Some(stats);
+        peers.put(peer, subscriptions);
+    }
+
+    /// Registers the counters of the deduplication of a new fragment
+    /// subscription of the peer, replacing those of the previous one
+    pub fn fragments_deduplicated(&self, peer: NodeId, stats: DedupStats) {
+        let mut peers = self.peers.lock().unwrap();
+        let mut subscriptions = peers.pop(&peer).unwrap_or_default();
+        subscriptions.fragment_dedup = Some(stats);
+        peers.put(peer, subscriptions);
+    }
+
+    pub fn get(&self, peer: &NodeId) -> Option<PeerOverflow> {
+        let peers = self.peers.lock().unwrap();
+        let subscriptions = peers.peek(peer)?;
+        let gossip = subscriptions.gossip.as_ref();
+        let fragments = subscriptions.fragments.as_ref();
+        let fragment_dedup = subscriptions.fragment_dedup.as_ref();
+        Some(PeerOverflow {
+            gossip_dropped: gossip.map_or(0, InboundStats::dropped),
+            fragments_dropped: fragments.map_or(0, InboundStats::dropped),
+            fragments_coalesced: fragments.map_or(0, InboundStats::coalesced),
+            fragment_dedup_hits: fragment_dedup.map_or(0, DedupStats::hits),
+            fragment_dedup_misses: fragment_dedup.map_or(0, DedupStats::misses),
+        })
+    }
+}
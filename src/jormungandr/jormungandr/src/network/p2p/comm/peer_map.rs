@@ -2,7 +2,7 @@ use crate::{
     metrics::{Metrics, MetricsBackend},
     network::{
         client::ConnectHandle,
-        p2p::comm::{Address, PeerComms, PeerInfo, PeerStats},
+        p2p::comm::{Address, PeerComms, PeerInfo, PeerOverflow, PeerStats, PeerTraffic},
         security_params::NONCE_LEN,
     },
     topology::NodeId,
@@ -271,6 +271,8 @@ impl PeerMap {
                 id,
                 addr: Some(data.comms.remote_addr),
                 stats: data.stats.clone(),
+                traffic: PeerTraffic::default(),
+                overflow: PeerOverflow::default(),
             })
             .collect()
     }
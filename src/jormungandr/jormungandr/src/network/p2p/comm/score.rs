@@ -0,0 +1,345 @@
+use super::ClientIds;
+use crate::topology::NodeId;
+use chain_network::{
+    core::server::{self, Misbehavior, QuarantineDecision},
+    data::p2p::Peer,
+};
+use lru::LruCache;
+use std::{
+    net::IpAddr,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+/// Score at which a peer gets quarantined.
+const QUARANTINE_SCORE: u32 = 100;
+/// How long a quarantined peer is refused, after which its score is reset.
+const QUARANTINE_DURATION: Duration = Duration::from_secs(30 * 60);
+
+fn penalty(misbehavior: Misbehavior) -> u32 {
+    match misbehavior {
+        Misbehavior::InvalidBlock => 50,
+        Misbehavior::ProtocolError => 20,
+    }
+}
+
+/// Peers are scored as nodes once they have completed the handshake.
+/// Requests that do not need the handshake, such as pushing blocks, may come
+/// from peers that never identify themselves: those are scored by IP address.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum PeerKey {
+    Node(NodeId),
+    Ip(IpAddr),
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+struct Record {
+    score: u32,
+    quarantined_until: Option<Instant>,
+}
+
+impl Record {
+    fn is_quarantined(&self, now: Instant) -> bool {
+        self.quarantined_until.map_or(false, |until| now < until)
+    }
+
+    /// The misbehavior of the peer is forgotten once its quarantine is over.
+    fn is_expired(&self, now: Instant) -> bool {
+        self.quarantined_until.map_or(false, |until| now >= until)
+    }
+}
+
+/// Misbehavior score of the peers, as reported by the gRPC server.
+///
+/// Peers that completed the handshake are told apart by their node id, the
+/// others by their IP address. Only the most recently misbehaving peers are
+/// kept.
+pub struct ScoreCounter {
+    peers: Mutex<LruCache<PeerKey, Record>>,
+    client_ids: Arc<ClientIds>,
+}
+
+impl ScoreCounter {
+    pub fn new(capacity: usize, client_ids: Arc<ClientIds>) -> Self {
+        ScoreCounter {
+            peers: Mutex::new(LruCache::new(capacity)),
+            client_ids,
+        }
+    }
+
+    fn score_of(&self, key: &PeerKey) -> u32 {
+        let now = Instant::now();
+        self.peers
+            .lock()
+            .unwrap()
+            .peek(key)
+            .filter(|record| !record.is_expired(now))
+            .map_or(0, |record| record.score)
+    }
+
+    fn is_key_quarantined(&self, key: &PeerKey) -> bool {
+        let now = Instant::now();
+        self.peers
+            .lock()
+            .unwrap()
+            .peek(key)
+            .map_or(false, |record| record.is_quarantined(now))
+    }
+
+    fn extend_key_quarantine(&self, key: &PeerKey, duration: Duration) -> bool {
+        let now = Instant::now();
+        let mut peers = self.peers.lock().unwrap();
+        match peers.peek_mut(key) {
+            Some(record) if record.is_quarantined(now) => {
+                record.quarantined_until = record.quarantined_until.map(|until| until + duration);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Current misbehavior score of the node
+    pub fn get(&self, id: &NodeId) -> u32 {
+        self.score_of(&PeerKey::Node(*id))
+    }
+
+    pub fn is_quarantined(&self, id: &NodeId) -> bool {
+        self.is_key_quarantined(&PeerKey::Node(*id))
+    }
+
+    /// Whether the unidentified peers at the given IP address are quarantined
+    pub fn is_ip_quarantined(&self, addr: IpAddr) -> bool {
+        self.is_key_quarantined(&PeerKey::Ip(addr))
+    }
+
+    /// Forgets the misbehavior of the node, lifting its quarantine if any
+    pub fn forgive(&self, id: &NodeId) {
+        self.peers.lock().unwrap().pop(&PeerKey::Node(*id));
+    }
+
+    /// Forgets the misbehavior of the unidentified peers at the given IP
+    /// address, lifting their quarantine if any
+    pub fn forgive_ip(&self, addr: IpAddr) {
+        self.peers.lock().unwrap().pop(&PeerKey::Ip(addr));
+    }
+
+    /// Postpones the end of the quarantine of the node by `duration`,
+    /// returns false if the node is not quarantined
+    pub fn extend_quarantine(&self, id: &NodeId, duration: Duration) -> bool {
+        self.extend_key_quarantine(&PeerKey::Node(*id), duration)
+    }
+
+    /// Postpones the end of the quarantine of the unidentified peers at the
+    /// given IP address by `duration`, returns false if they are not
+    /// quarantined
+    pub fn extend_ip_quarantine(&self, addr: IpAddr, duration: Duration) -> bool {
+        self.extend_key_quarantine(&PeerKey::Ip(addr), duration)
+    }
+}
+
+impl server::PeerScore for ScoreCounter {
+    fn record(&self, peer: &Peer, misbehavior: Misbehavior) -> QuarantineDecision {
+        let now = Instant::now();
+        let key = match self.client_ids.get(&peer.addr()) {
+            Some(id) => PeerKey::Node(id),
+            None => PeerKey::Ip(peer.addr().ip()),
+        };
+        let mut peers = self.peers.lock().unwrap();
+        let mut record = peers
+            .pop(&key)
+            .filter(|record| !record.is_expired(now))
+            .unwrap_or_default();
+        record.score = record.score.saturating_add(penalty(misbehavior));
+        if record.score >= QUARANTINE_SCORE && !record.is_quarantined(now) {
+            record.quarantined_until = Some(now + QUARANTINE_DURATION);
+            tracing::info!(
+                peer = %peer,
+                ?misbehavior,
+                score = record.score,
+                "quarantining misbehaving peer"
+            );
+        } else {
+            tracing::debug!(peer = %peer, ?misbehavior, score = record.score, "peer misbehaved");
+        }
+        let decision = if record.is_quarantined(now) {
+            QuarantineDecision::Quarantine
+        } else {
+            QuarantineDecision::Keep
+        };
+        peers.put(key, record);
+        decision
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chain_crypto::{Ed25519, SecretKey};
+    use chain_network::core::server::PeerScore;
+    use rand::SeedableRng;
+    use std::net::SocketAddr;
+
+    fn node_id(seed: u8) -> NodeId {
+        let key = SecretKey::<Ed25519>::generate(rand_chacha::ChaChaRng::from_seed([seed; 32]));
+        NodeId::try_from(key.to_public().as_ref()).unwrap()
+    }
+
+    fn counter() -> (ScoreCounter, Arc<ClientIds>) {
+        let client_ids = Arc::new(ClientIds::new(16));
+        (ScoreCounter::new(16, Arc::clone(&client_ids)), client_ids)
+    }
+
+    fn peer(addr: &str) -> Peer {
+        addr.parse::<SocketAddr>().unwrap().into()
+    }
+
+    #[test]
+    fn penalties_accumulate_until_quarantine() {
+        let (score, client_ids) = counter();
+        let id = node_id(1);
+        client_ids.insert("127.0.0.1:4000".parse().unwrap(), id);
+        let peer = peer("127.0.0.1:4000");
+
+        assert_eq!(
+            score.record(&peer, Misbehavior::ProtocolError),
+            QuarantineDecision::Keep
+        );
+        assert_eq!(
+            score.record(&peer, Misbehavior::ProtocolError),
+            QuarantineDecision::Keep
+        );
+        assert_eq!(score.get(&id), 40);
+        assert!(!score.is_quarantined(&id));
+
+        assert_eq!(
+            score.record(&peer, Misbehavior::InvalidBlock),
+            QuarantineDecision::Keep
+        );
+        assert_eq!(score.get(&id), 90);
+        assert_eq!(
+            score.record(&peer, Misbehavior::ProtocolError),
+            QuarantineDecision::Quarantine
+        );
+        assert_eq!(score.get(&id), 110);
+        assert!(score.is_quarantined(&id));
+    }
+
+    #[test]
+    fn quarantined_at_the_threshold() {
+        let (score, client_ids) = counter();
+        let id = node_id(1);
+        client_ids.insert("127.0.0.1:4000".parse().unwrap(), id);
+        let peer = peer("127.0.0.1:4000");
+
+        assert_eq!(
+            score.record(&peer, Misbehavior::InvalidBlock),
+            QuarantineDecision::Keep
+        );
+        assert_eq!(
+            score.record(&peer, Misbehavior::InvalidBlock),
+            QuarantineDecision::Quarantine
+        );
+        assert_eq!(score.get(&id), QUARANTINE_SCORE);
+        assert!(score.is_quarantined(&id));
+    }
+
+    #[test]
+    fn node_is_scored_across_connections() {
+        let (score, client_ids) = counter();
+        let id = node_id(1);
+        client_ids.insert("127.0.0.1:4000".parse().unwrap(), id);
+        client_ids.insert("127.0.0.1:4001".parse().unwrap(), id);
+
+        score.record(&peer("127.0.0.1:4000"), Misbehavior::InvalidBlock);
+        score.record(&peer("127.0.0.1:4001"), Misbehavior::InvalidBlock);
+        assert!(score.is_quarantined(&id));
+        assert!(!score.is_ip_quarantined("127.0.0.1".parse().unwrap()));
+    }
+
+    #[test]
+    fn nodes_behind_one_ip_are_scored_apart() {
+        let (score, client_ids) = counter();
+        let (id1, id2) = (node_id(1), node_id(2));
+        client_ids.insert("127.0.0.1:4000".parse().unwrap(), id1);
+        client_ids.insert("127.0.0.1:4001".parse().unwrap(), id2);
+
+        score.record(&peer("127.0.0.1:4000"), Misbehavior::InvalidBlock);
+        score.record(&peer("127.0.0.1:4000"), Misbehavior::InvalidBlock);
+        assert!(score.is_quarantined(&id1));
+        assert!(!score.is_quarantined(&id2));
+        assert_eq!(score.get(&id2), 0);
+    }
+
+    #[test]
+    fn unidentified_peers_are_scored_by_ip() {
+        let (score, _) = counter();
+        let ip = "127.0.0.1".parse().unwrap();
+
+        score.record(&peer("127.0.0.1:4000"), Misbehavior::InvalidBlock);
+        assert_eq!(
+            score.record(&peer("127.0.0.1:4001"), Misbehavior::InvalidBlock),
+            QuarantineDecision::Quarantine
+        );
+        assert!(score.is_ip_quarantined(ip));
+
+        score.forgive_ip(ip);
+        assert!(!score.is_ip_quarantined(ip));
+    }
+
+    #[test]
+    fn extended_quarantine_lasts_longer() {
+        let (score, client_ids) = counter();
+        let id = node_id(1);
+        client_ids.insert("127.0.0.1:4000".parse().unwrap(), id);
+        let ip = "127.0.0.2".parse().unwrap();
+        assert!(!score.extend_quarantine(&id, QUARANTINE_DURATION));
+
+        for _ in 0..2 {
+            score.record(&peer("127.0.0.1:4000"), Misbehavior::InvalidBlock);
+            score.record(&peer("127.0.0.2:4000"), Misbehavior::InvalidBlock);
+        }
+        let quarantined_until = |key| {
+            score
+                .peers
+                .lock()
+                .unwrap()
+                .peek(&key)
+                .and_then(|record| record.quarantined_until)
+                .unwrap()
+        };
+        let (node_until, ip_until) = (
+            quarantined_until(PeerKey::Node(id)),
+            quarantined_until(PeerKey::Ip(ip)),
+        );
+
+        assert!(score.extend_quarantine(&id, QUARANTINE_DURATION));
+        assert!(score.extend_ip_quarantine(ip, QUARANTINE_DURATION));
+        assert_eq!(
+            quarantined_until(PeerKey::Node(id)),
+            node_until + QUARANTINE_DURATION
+        );
+        assert_eq!(
+            quarantined_until(PeerKey::Ip(ip)),
+            ip_until + QUARANTINE_DURATION
+        );
+    }
+
+    #[test]
+    fn forgiven_node_starts_over() {
+        let (score, client_ids) = counter();
+        let id = node_id(1);
+        client_ids.insert("127.0.0.1:4000".parse().unwrap(), id);
+        let peer = peer("127.0.0.1:4000");
+        score.record(&peer, Misbehavior::InvalidBlock);
+        score.record(&peer, Misbehavior::InvalidBlock);
+        assert!(score.is_quarantined(&id));
+
+        score.forgive(&id);
+        assert!(!score.is_quarantined(&id));
+        assert_eq!(score.get(&id), 0);
+        assert_eq!(
+            score.record(&peer, Misbehavior::InvalidBlock),
+            QuarantineDecision::Keep
+        );
+    }
+}
@@ -0,0 +1,60 @@
+use super::ClientIds;
+use crate::topology::NodeId;
+use chain_network::core::server::{self, Request, RequestStats};
+use lru::LruCache;
+use std::sync::{Arc, Mutex};
+
+/// Traffic of the requests served to a peer by the gRPC server.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PeerTraffic {
+    pub requests: u64,
+    pub bytes_received: u64,
+    pub bytes_sent: u64,
+}
+
+/// Collects the traffic served to each node, as reported by the gRPC server
+/// hooks. Clients connect from ephemeral ports, so a request is accounted
+/// for the node which completed the handshake on its connection; requests
+/// made before the handshake are not accounted for. Only the most recently
+/// active nodes are kept.
+pub struct TrafficCounter {
+    peers: Mutex<LruCache<NodeId, PeerTraffic>>,
+    client_ids: Arc<ClientIds>,
+}
+
+impl TrafficCounter {
+    pub fn new(capacity: usize, client_ids: Arc<ClientIds>) -> Self {
+        TrafficCounter {
+            peers: Mutex::new(LruCache::new(capacity)),
+            client_ids,
+        }
+    }
+
+    pub fn get(&self, id: &NodeId) -> Option<PeerTraffic> {
+        self.peers.lock().unwrap().peek(id).copied()
+    }
+}
+
+impl server::Metrics for TrafficCounter {
+    fn request_finished(&self, request: &Request, stats: &RequestStats) {
+        tracing::trace!(
+            service = request.service,
+            call_id = %request.call_id,
+            peer = %request.peer,
+            duration = ?stats.duration,
+            bytes_received = stats.bytes_received,
+            bytes_sent = stats.bytes_sent,
+            "request finished"
+        );
+        let id = match self.client_ids.get(&request.peer.addr()) {
+            Some(id) => id,
+            None => return,
+        };
+        let mut peers = self.peers.lock().unwrap();
+        let mut traffic = peers.pop(&id).unwrap_or_default();
+        traffic.requests += 1;
+        traffic.bytes_received += stats.bytes_received;
+        traffic.bytes_sent += stats.bytes_sent;
+        peers.put(id, traffic);
+    }
+}
@@ -1,6 +1,7 @@
 use super::{
     buffer_sizes,
     convert::{self, Decode, Encode, ResponseStream},
+    grpc,
     p2p::{
         comm::{BlockEventSubscription, FragmentSubscription, GossipSubscription},
         Address,
@@ -18,12 +19,15 @@ use chain_network::{
     core::server::{BlockService, FragmentService, GossipService, Node, PushStream},
     data::{
         p2p::{AuthenticatedNodeId, Peer},
-        Block, BlockId, BlockIds, Fragment, FragmentIds, Gossip, HandshakeResponse, Header,
+        Block, BlockId, BlockIds, Fragment, FragmentAck, FragmentEvent, FragmentIds, Gossip,
+        HandshakeResponse, Header, TipUpdate,
     },
     error::{Code as ErrorCode, Error},
 };
-use futures::{prelude::*, try_join};
+use futures::{channel::mpsc, prelude::*, try_join};
 use std::convert::TryFrom;
+use tokio::sync::watch;
+use tokio_stream::wrappers::WatchStream;
 use tracing::{instrument, Span};
 use tracing_futures::Instrument;
 
@@ -31,13 +35,19 @@ use tracing_futures::Instrument;
 pub struct NodeService {
     channels: Channels,
     global_state: GlobalStateR,
+    tip_receiver: watch::Receiver<Header>,
 }
 
 impl NodeService {
-    pub fn new(channels: Channels, global_state: GlobalStateR) -> Self {
+    pub fn new(
+        channels: Channels,
+        global_state: GlobalStateR,
+        tip_receiver: watch::Receiver<Header>,
+    ) -> Self {
         NodeService {
             channels,
             global_state,
+            tip_receiver,
         }
     }
 
@@ -61,12 +71,19 @@ impl Node for NodeService {
         let keypair = &self.global_state.keypair;
         let auth = keypair.sign(nonce);
         let addr = peer.addr();
+        if self.global_state.peers.score().is_ip_quarantined(addr.ip()) {
+            return Err(Error::new(
+                ErrorCode::FailedPrecondition,
+                "peer is quarantined for misbehavior",
+            ));
+        }
         let nonce = self.global_state.peers.generate_auth_nonce(addr).await;
 
         Ok(HandshakeResponse {
             block0_id,
             auth,
             nonce: nonce.into(),
+            capabilities: grpc::capabilities(self.global_state.config.block_compression),
         })
     }
 
@@ -74,6 +91,12 @@ impl Node for NodeService {
     async fn client_auth(&self, peer: Peer, auth: AuthenticatedNodeId) -> Result<(), Error> {
         let addr = peer.addr();
         let id = auth.id().clone().decode()?;
+        if self.global_state.peers.score().is_quarantined(&id) {
+            return Err(Error::new(
+                ErrorCode::FailedPrecondition,
+                "peer is quarantined for misbehavior",
+            ));
+        }
         self.global_state
             .peers
             .server_complete_handshake(addr, id, |nonce| auth.verify(&nonce[..]))
@@ -107,6 +130,25 @@ async fn send_message<T>(mut mbox: MessageBox<T>, msg: T) -> Result<(), Error> {
 type SubscriptionStream<S> =
     stream::Map<S, fn(<S as Stream>::Item) -> Result<<S as Stream>::Item, Error>>;
 
+type FragmentAckSubscriptionStream = stream::Map<
+    stream::Select<
+        stream::Map<FragmentSubscription, fn(Fragment) -> FragmentEvent>,
+        stream::Map<mpsc::Receiver<FragmentAck>, fn(FragmentAck) -> FragmentEvent>,
+    >,
+    fn(FragmentEvent) -> Result<FragmentEvent, Error>,
+>;
+
+type TipSubscriptionStream =
+    stream::Map<WatchStream<Header>, fn(Header) -> Result<TipUpdate, Error>>;
+
+fn tip_update(header: Header) -> Result<TipUpdate, Error> {
+    let header = header.decode()?;
+    Ok(TipUpdate {
+        id: header.id().encode(),
+        chain_length: header.chain_length().into(),
+    })
+}
+
 fn serve_subscription<S: Stream>(sub: Option<S>) -> Result<SubscriptionStream<S>, Error> {
     Ok(sub
         .ok_or_else(|| Error::new(ErrorCode::FailedPrecondition, "handshake not performed"))?
@@ -142,6 +184,7 @@ impl BlockService for NodeService {
     type PullHeadersStream = ResponseStream<app_data::Header>;
     type GetHeadersStream = ResponseStream<app_data::Header>;
     type SubscriptionStream = SubscriptionStream<BlockEventSubscription>;
+    type TipSubscriptionStream = TipSubscriptionStream;
 
     #[instrument(level = "debug", skip(self))]
     async fn tip(&self) -> Result<Header, Error> {
@@ -152,6 +195,14 @@ impl BlockService for NodeService {
         Ok(header.encode())
     }
 
+    #[instrument(level = "debug", skip(self))]
+    async fn tip_subscription(
+        &self,
+        _subscriber: Peer,
+    ) -> Result<Self::TipSubscriptionStream, Error> {
+        Ok(WatchStream::new(self.tip_receiver.clone()).map(tip_update as fn(_) -> _))
+    }
+
     #[instrument(level = "debug", skip(self))]
     async fn pull_blocks(
         &self,
@@ -262,6 +313,7 @@ impl BlockService for NodeService {
 impl FragmentService for NodeService {
     type GetFragmentsStream = ResponseStream<app_data::Fragment>;
     type SubscriptionStream = SubscriptionStream<FragmentSubscription>;
+    type AckSubscriptionStream = FragmentAckSubscriptionStream;
 
     async fn get_fragments(&self, _ids: FragmentIds) -> Result<Self::GetFragmentsStream, Error> {
         Err(Error::unimplemented())
@@ -281,6 +333,7 @@ impl FragmentService for NodeService {
                 self.channels.transaction_box.clone(),
                 peer_id,
                 self.global_state.clone(),
+                None,
             )
             .in_current_span(),
         );
@@ -292,6 +345,38 @@ impl FragmentService for NodeService {
             .await;
         Ok(serve_subscription(outbound)?)
     }
+
+    #[instrument(level = "debug", skip_all, fields(direction = "in", addr = %subscriber, id))]
+    async fn fragment_subscription_with_ack(
+        &self,
+        subscriber: Peer,
+        stream: PushStream<Fragment>,
+    ) -> Result<Self::AckSubscriptionStream, Error> {
+        let peer_id = self.peer_id(subscriber.addr()).await?;
+        Span::current().record("id", peer_id.to_string().as_str());
+        let outbound = self
+            .global_state
+            .peers
+            .subscribe_to_fragments(&peer_id)
+            .await
+            .ok_or_else(|| Error::new(ErrorCode::FailedPrecondition, "handshake not performed"))?;
+
+        let (acks_tx, acks_rx) = mpsc::channel(buffer_sizes::outbound::FRAGMENT_ACKS);
+        self.global_state.spawn(
+            subscription::process_fragments(
+                stream,
+                self.channels.transaction_box.clone(),
+                peer_id,
+                self.global_state.clone(),
+                Some(acks_tx),
+            )
+            .in_current_span(),
+        );
+
+        let fragments = outbound.map(FragmentEvent::Fragment as fn(_) -> _);
+        let acks = acks_rx.map(FragmentEvent::Ack as fn(_) -> _);
+        Ok(stream::select(fragments, acks).map(Ok))
+    }
 }
 
 #[async_trait]
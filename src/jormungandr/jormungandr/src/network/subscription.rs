@@ -1,9 +1,14 @@
-use super::{buffer_sizes, convert::Decode, GlobalStateR};
+use super::{
+    buffer_sizes,
+    convert::{Decode, Encode},
+    grpc::client::{BufferedInbound, DedupInbound, InboundBuffer, OverflowPolicy},
+    GlobalState, GlobalStateR,
+};
 use crate::{
-    blockcfg::Fragment,
+    blockcfg::{Fragment, FragmentId},
     intercom::{self, BlockMsg, TopologyMsg, TransactionMsg},
     network::retrieve_local_ip,
-    settings::start::network::Configuration,
+    settings::start::{config, network::Configuration},
     topology::{Gossip, NodeId},
     utils::async_msg::{self, MessageBox},
 };
@@ -11,7 +16,7 @@ use chain_network::{
     data as net_data,
     error::{Code, Error},
 };
-use futures::executor;
+use futures::{channel::mpsc, executor};
 use futures::{future::BoxFuture, prelude::*, ready};
 use jormungandr_lib::interfaces::FragmentOrigin;
 use std::{
@@ -40,6 +45,116 @@ fn handle_mbox_error(err: async_msg::SendError) -> Error {
     Error::new(Code::Internal, err)
 }
 
+fn net_overflow_policy(policy: config::OverflowPolicy) -> OverflowPolicy {
+    match policy {
+        config::OverflowPolicy::DropOldest => OverflowPolicy::DropOldest,
+        config::OverflowPolicy::DropNewest => OverflowPolicy::DropNewest,
+        config::OverflowPolicy::Park => OverflowPolicy::Park,
+    }
+}
+
+/// Reads ahead the gossip subscription of the peer, applying the configured
+/// overflow policy. The counters of the buffer are reported in the peer stats.
+pub fn buffer_gossip<S>(
+    stream: S,
+    node_id: NodeId,
+    global_state: &GlobalState,
+) -> BufferedInbound<S>
+where
+    S: Stream<Item = Result<net_data::Gossip, Error>>,
+{
+    let config = InboundBuffer::new(
+        buffer_sizes::inbound::GOSSIP,
+        net_overflow_policy(global_state.config.gossip_overflow),
+    );
+    let buffered = BufferedInbound::new(stream, config);
+    global_state
+        .peers
+        .overflow()
+        .gossip_subscribed(node_id, buffered.stats());
+    buffered
+}
+
+/// Reads ahead the fragment subscription of the peer, applying the configured
+/// overflow policy. If `coalesce` is set, the fragments already waiting in
+/// the buffer are discarded; this must not be done when the peer expects
+/// an acknowledgment for each of its fragments.
+/// The counters of the buffer are reported in the peer stats.
+pub fn buffer_fragments<S>(
+    stream: S,
+    node_id: NodeId,
+    global_state: &GlobalState,
+    coalesce: bool,
+) -> BufferedInbound<S>
+where
+    S: Stream<Item = Result<net_data::Fragment, Error>>,
+{
+    let config = InboundBuffer::new(
+        buffer_sizes::inbound::FRAGMENTS,
+        net_overflow_policy(global_state.config.fragment_overflow),
+    );
+    let buffered = BufferedInbound::new(stream, config);
+    let buffered = if coalesce {
+        buffered.coalescing(same_fragment)
+    } else {
+        buffered
+    };
+    global_state
+        .peers
+        .overflow()
+        .fragments_subscribed(node_id, buffered.stats());
+    buffered
+}
+
+// the fragment id is the hash of its bytes
+fn same_fragment(
+    a: &Result<net_data::Fragment, Error>,
+    b: &Result<net_data::Fragment, Error>,
+) -> bool {
+    match (a, b) {
+        (Ok(a), Ok(b)) => a.as_bytes() == b.as_bytes(),
+        _ => false,
+    }
+}
+
+/// Discards the fragments of the peer already received from a peer within
+/// the configured TTL, if fragments are deduplicated. The counters of
+/// the deduplication are reported in the peer stats.
+///
+/// This must be applied to the output of [`buffer_fragments`]: the ids are
+/// recorded as seen here, so a fragment recorded before the buffer dropped
+/// it would be discarded when it arrives again from another peer, and
+/// never processed.
+///
+/// Header ids are deliberately not deduplicated: if pulling the block
+/// announced by the first peer failed, the same announcement from the other
+/// peers is needed to retry.
+pub fn dedup_fragments<S>(
+    stream: S,
+    node_id: NodeId,
+    global_state: &GlobalState,
+) -> DedupInbound<S, FragmentId>
+where
+    S: Stream<Item = Result<net_data::Fragment, Error>>,
+{
+    let stream = DedupInbound::new(stream, global_state.seen_fragments.clone(), fragment_id);
+    if global_state.seen_fragments.is_some() {
+        global_state
+            .peers
+            .overflow()
+            .fragments_deduplicated(node_id, stream.stats());
+    }
+    stream
+}
+
+fn fragment_id(item: &Result<net_data::Fragment, Error>) -> Option<FragmentId> {
+    item.as_ref()
+        .ok()
+        .map(|fragment| FragmentId::hash_bytes(fragment.as_bytes()))
+}
+
+/// Processes the block announcements of the peer. They are never dropped:
+/// the stream is not read while the block task is busy.
 pub async fn process_block_announcements<S>(
     stream: S,
     mbox: MessageBox<BlockMsg>,
@@ -66,35 +181,39 @@ pub async fn process_gossip<S>(
 ) where
     S: TryStream<Ok = net_data::Gossip, Error = Error>,
 {
+    let stream = buffer_gossip(stream.into_stream(), node_id, &global_state);
     let processor = GossipProcessor::new(mbox, node_id, global_state, Direction::Server);
-    stream
-        .into_stream()
-        .forward(processor)
-        .await
-        .unwrap_or_else(|e| {
-            tracing::debug!(
-                error = ?e,
-                "processing of inbound gossip failed"
-            );
-        });
+    stream.forward(processor).await.unwrap_or_else(|e| {
+        tracing::debug!(
+            error = ?e,
+            "processing of inbound gossip failed"
+        );
+    });
 }
 
+/// Sending half of the acknowledgments of fragments received from a peer
+pub type FragmentAckSender = mpsc::Sender<net_data::FragmentAck>;
+
 pub async fn process_fragments<S>(
     stream: S,
     mbox: MessageBox<TransactionMsg>,
     node_id: NodeId,
     global_state: GlobalStateR,
+    acks: Option<FragmentAckSender>,
 ) where
     S: TryStream<Ok = net_data::Fragment, Error = Error>,
 {
-    let sink = FragmentProcessor::new(mbox, node_id, global_state);
-    stream
-        .into_stream()
-        .forward(sink)
-        .await
-        .unwrap_or_else(|e| {
-            tracing::debug!(error = ?e, "processing of inbound subscription stream failed");
-        });
+    let stream = buffer_fragments(stream.into_stream(), node_id, &global_state, acks.is_none());
+    let stream = if acks.is_some() {
+        // the subscriber expects an acknowledgment for each of its fragments
+        DedupInbound::new(stream, None, fragment_id)
+    } else {
+        dedup_fragments(stream, node_id, &global_state)
+    };
+    let sink = FragmentProcessor::new(mbox, node_id, global_state).with_acks(acks);
+    stream.forward(sink).await.unwrap_or_else(|e| {
+        tracing::debug!(error = ?e, "processing of inbound subscription stream failed");
+    });
 }
 
 #[must_use = "sinks do nothing unless polled"]
@@ -153,6 +272,7 @@ pub struct FragmentProcessor {
     global_state: GlobalStateR,
     buffered_fragments: Vec<Fragment>,
     pending_processing: PendingProcessing,
+    acks: Option<FragmentAckSender>,
 }
 
 impl FragmentProcessor {
@@ -167,9 +287,16 @@ impl FragmentProcessor {
             global_state,
             buffered_fragments: Vec::with_capacity(buffer_sizes::inbound::FRAGMENTS),
             pending_processing: PendingProcessing::default(),
+            acks: None,
         }
     }
 
+    /// Reports the outcome of processing the received fragments back to the peer
+    pub(super) fn with_acks(mut self, acks: Option<FragmentAckSender>) -> Self {
+        self.acks = acks;
+        self
+    }
+
     /// Retrieves socket addr of inbound peer
     fn get_ingress_addr(&self) -> Option<std::net::SocketAddr> {
         let state = self.global_state.clone();
@@ -387,12 +514,33 @@ impl FragmentProcessor {
             }
         };
 
-        let (reply_handle, _reply_future) = intercom::unary_reply();
+        let (reply_handle, reply_future) = intercom::unary_reply();
+        if let Some(mut acks) = self.acks.clone() {
+            self.global_state.spawn(
+                async move {
+                    let summary = match reply_future.await {
+                        Ok(summary) => summary,
+                        Err(e) => {
+                            tracing::debug!(reason = %e, "fragments processing summary not received");
+                            return;
+                        }
+                    };
+                    for ack in summary.encode() {
+                        if acks.send(ack).await.is_err() {
+                            // the subscriber has gone away
+                            break;
+                        }
+                    }
+                }
+                .in_current_span(),
+            );
+        }
         self.mbox
             .start_send(TransactionMsg::SendTransactions {
                 origin: addr,
                 fragments,
                 fail_fast: false,
+                all_or_nothing: false,
                 reply_handle,
             })
             .map_err(|e| {
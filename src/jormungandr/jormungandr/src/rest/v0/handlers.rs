@@ -1,4 +1,6 @@
 use crate::rest::{v0::logic, ContextLock};
+use jormungandr_lib::interfaces::{FragmentLogsQuery, MessageBatch, UTxOsQuery};
+use std::time::Duration;
 use warp::{reject::Reject, Rejection, Reply};
 
 impl Reject for logic::Error {}
@@ -23,9 +25,12 @@ pub async fn get_update_proposals(context: ContextLock) -> Result<impl Reply, Re
         .map(|r| warp::reply::json(&r))
 }
 
-pub async fn get_message_logs(context: ContextLock) -> Result<impl Reply, Rejection> {
+pub async fn get_message_logs(
+    query: FragmentLogsQuery,
+    context: ContextLock,
+) -> Result<impl Reply, Rejection> {
     let context = context.read().await;
-    logic::get_message_logs(&context)
+    logic::get_message_logs(&context, query)
         .await
         .map_err(warp::reject::custom)
         .map(|r| warp::reply::json(&r))
@@ -42,6 +47,17 @@ pub async fn post_message(
         .map_err(warp::reject::custom)
 }
 
+pub async fn post_message_batch(
+    batch: MessageBatch,
+    context: ContextLock,
+) -> Result<impl Reply, Rejection> {
+    let context = context.read().await;
+    logic::post_message_batch(&context, batch)
+        .await
+        .map(|r| warp::reply::json(&r))
+        .map_err(warp::reject::custom)
+}
+
 pub async fn get_tip(context: ContextLock) -> Result<impl Reply, Rejection> {
     let context = context.read().await;
     logic::get_tip(&context).await.map_err(warp::reject::custom)
@@ -112,12 +128,36 @@ pub async fn get_settings(context: ContextLock) -> Result<impl Reply, Rejection>
         .map_err(warp::reject::custom)
 }
 
-pub async fn shutdown(context: ContextLock) -> Result<impl Reply, Rejection> {
-    let mut context = context.write().await;
-    logic::shutdown(&mut context)
-        .await
-        .map(|_| warp::reply())
-        .map_err(warp::reject::custom)
+#[derive(Deserialize, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum ShutdownMode {
+    Immediate,
+    Graceful,
+}
+
+#[derive(Deserialize)]
+pub struct ShutdownQuery {
+    mode: Option<ShutdownMode>,
+    /// seconds to wait for the blocks in flight in graceful mode
+    timeout: Option<u64>,
+}
+
+pub async fn shutdown(query: ShutdownQuery, context: ContextLock) -> Result<impl Reply, Rejection> {
+    match query.mode.unwrap_or(ShutdownMode::Immediate) {
+        ShutdownMode::Immediate => {
+            let mut context = context.write().await;
+            logic::shutdown(&mut context).await
+        }
+        ShutdownMode::Graceful => {
+            let timeout = query
+                .timeout
+                .map(Duration::from_secs)
+                .unwrap_or(logic::DEFAULT_GRACEFUL_SHUTDOWN_TIMEOUT);
+            logic::shutdown_graceful(&context, timeout).await
+        }
+    }
+    .map(|_| warp::reply())
+    .map_err(warp::reject::custom)
 }
 
 pub async fn get_leaders_logs(context: ContextLock) -> Result<impl Reply, Rejection> {
@@ -175,6 +215,14 @@ pub async fn get_rewards_remaining(context: ContextLock) -> Result<impl Reply, R
         .map_err(warp::reject::custom)
 }
 
+pub async fn get_utxos(query: UTxOsQuery, context: ContextLock) -> Result<impl Reply, Rejection> {
+    let context = context.read().await;
+    logic::get_utxos(&context, query)
+        .await
+        .map(|r| warp::reply::json(&r))
+        .map_err(warp::reject::custom)
+}
+
 pub async fn get_utxo(
     fragment_id_hex: String,
     output_index: u8,
@@ -216,6 +264,36 @@ pub async fn get_network_p2p_quarantined(context: ContextLock) -> Result<impl Re
         .map_err(warp::reject::custom)
 }
 
+pub async fn delete_network_p2p_quarantined(
+    peer: String,
+    context: ContextLock,
+) -> Result<impl Reply, Rejection> {
+    let context = context.read().await;
+    logic::delete_network_p2p_quarantined(&context, &peer)
+        .await
+        .map_err(warp::reject::custom)?
+        .map(|r| warp::reply::json(&r))
+        .ok_or_else(warp::reject::not_found)
+}
+
+#[derive(Deserialize)]
+pub struct ExtendQuarantineQuery {
+    duration: jormungandr_lib::time::Duration,
+}
+
+pub async fn extend_network_p2p_quarantined(
+    peer: String,
+    query: ExtendQuarantineQuery,
+    context: ContextLock,
+) -> Result<impl Reply, Rejection> {
+    let context = context.read().await;
+    logic::extend_network_p2p_quarantined(&context, &peer, query.duration.into())
+        .await
+        .map_err(warp::reject::custom)?
+        .map(|r| warp::reply::json(&r))
+        .ok_or_else(warp::reject::not_found)
+}
+
 pub async fn get_network_p2p_non_public(context: ContextLock) -> Result<impl Reply, Rejection> {
     let context = context.read().await;
     logic::get_network_p2p_non_public(&context)
@@ -7,10 +7,11 @@
 //   they are 400 or 500.
 use crate::{
     blockchain::StorageError,
+    context::FullContext,
     diagnostic::Diagnostic,
-    intercom::{self, NetworkMsg, TopologyMsg, TransactionMsg},
-    rest::Context,
-    topology::PeerInfo,
+    intercom::{self, BlockMsg, NetworkMsg, TopologyMsg, TransactionMsg},
+    rest::{Context, ContextLock},
+    topology::{PeerInfo, PeerRef},
     utils::async_msg::MessageBox,
 };
 use chain_core::{
@@ -34,14 +35,15 @@ use futures::{
 };
 use jormungandr_lib::{
     interfaces::{
-        AccountState, EpochRewardsInfo, FragmentLog, FragmentOrigin, FragmentsProcessingSummary,
-        LeadershipLog, NodeStatsDto, PeerStats, Rewards as StakePoolRewards, SettingsDto,
-        StakeDistribution, StakeDistributionDto, StakePoolStats, TaxTypeSerde, TransactionOutput,
-        UpdateProposalStateDef, Value, VotePlanStatus,
+        AccountState, EpochRewardsInfo, FragmentLog, FragmentLogsQuery, FragmentOrigin,
+        FragmentsProcessingSummary, LeadershipLog, MessageBatch, NodeState, NodeStatsDto,
+        PeerStats, Rewards as StakePoolRewards, SettingsDto, StakeDistribution,
+        StakeDistributionDto, StakePoolStats, TaxTypeSerde, TransactionOutput, UTxOInfo,
+        UTxOsQuery, UpdateProposalStateDef, Value, VotePlanStatus,
     },
     time::SystemTime,
 };
-use std::{collections::HashMap, net::SocketAddr, sync::Arc};
+use std::{collections::HashMap, net::SocketAddr, sync::Arc, time::Duration};
 use tracing::{span, Level};
 use tracing_futures::Instrument;
 
@@ -97,6 +99,15 @@ fn parse_block_hash(hex: &str) -> Result<Hash, Error> {
         .map(Into::into)
 }
 
+fn parse_peer_ref(peer: &str) -> Result<PeerRef, Error> {
+    match peer.parse::<SocketAddr>() {
+        Ok(address) => Ok(PeerRef::Address(address)),
+        Err(_) => jormungandr_lib::interfaces::NodeId::from_hex(peer)
+            .map(|id| PeerRef::Id(id.into()))
+            .map_err(Into::into),
+    }
+}
+
 fn parse_fragment_id(id_hex: &str) -> Result<FragmentId, Error> {
     match FragmentId::from_str(id_hex) {
         Ok(id) => Ok(id),
@@ -137,12 +148,15 @@ pub async fn get_account_state(
         .map(Into::into))
 }
 
-pub async fn get_message_logs(context: &Context) -> Result<Vec<FragmentLog>, Error> {
+pub async fn get_message_logs(
+    context: &Context,
+    query: FragmentLogsQuery,
+) -> Result<Vec<FragmentLog>, Error> {
     let span = span!(parent: context.span()?, Level::TRACE, "message_logs");
     async move {
         let (reply_handle, reply_future) = intercom::unary_reply();
         let mut mbox = context.try_full()?.transaction_task.clone();
-        mbox.send(TransactionMsg::GetLogs(reply_handle))
+        mbox.send(TransactionMsg::GetLogs(query, reply_handle))
             .await
             .map_err(|e| {
                 tracing::debug!(reason = %e, "error getting message logs");
@@ -158,6 +172,7 @@ pub async fn post_message(
     context: &Context,
     message: &[u8],
 ) -> Result<FragmentsProcessingSummary, Error> {
+    context.check_not_shutting_down()?;
     let fragment =
         Fragment::deserialize_from_slice(&mut Codec::new(message)).map_err(Error::Deserialize)?;
     let (reply_handle, reply_future) = intercom::unary_reply();
@@ -165,6 +180,29 @@ pub async fn post_message(
         origin: FragmentOrigin::Rest,
         fragments: vec![fragment],
         fail_fast: true,
+        all_or_nothing: false,
+        reply_handle,
+    };
+    context.try_full()?.transaction_task.clone().try_send(msg)?;
+    let reply = reply_future.await?;
+    if reply.is_error() {
+        Err(Error::Fragment(reply))
+    } else {
+        Ok(reply)
+    }
+}
+
+pub async fn post_message_batch(
+    context: &Context,
+    batch: MessageBatch,
+) -> Result<FragmentsProcessingSummary, Error> {
+    context.check_not_shutting_down()?;
+    let (reply_handle, reply_future) = intercom::unary_reply();
+    let msg = TransactionMsg::SendTransactions {
+        origin: FragmentOrigin::Rest,
+        fragments: batch.fragments,
+        fail_fast: batch.all_or_nothing,
+        all_or_nothing: batch.all_or_nothing,
         reply_handle,
     };
     context.try_full()?.transaction_task.clone().try_send(msg)?;
@@ -334,15 +372,62 @@ pub async fn get_settings(context: &Context) -> Result<SettingsDto, Error> {
         reward_params: current_params.reward_params(),
         discrimination: static_params.discrimination,
         tx_max_expiry_epochs: ledger.settings().transaction_max_expiry_epochs,
+        max_fragment_size: current_params.max_fragment_size,
+        voting_power_snapshot: current_params.voting_power_snapshot,
+        script_addresses: current_params.script_addresses,
     })
 }
 
+pub const DEFAULT_GRACEFUL_SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(30);
+
 pub async fn shutdown(context: &mut Context) -> Result<(), Error> {
     context.stop_bootstrap();
     context.rest_server_stopper()?.stop();
     Ok(())
 }
 
+/// Stops accepting fragments and P2P connections, closes the peer subscriptions,
+/// then waits up to `timeout` for the blocks in flight before flushing the storage
+/// and stopping the node.
+pub async fn shutdown_graceful(context: &ContextLock, timeout: Duration) -> Result<(), Error> {
+    let draining = {
+        let mut context = context.write().await;
+        context.stop_bootstrap();
+        // nothing to drain if the node has not started its workers yet
+        let draining = match context.try_full() {
+            Ok(full_context) => Some((
+                full_context.network_shutdown.clone(),
+                full_context.block_task.clone(),
+                context.blockchain()?.storage().clone(),
+            )),
+            Err(_) => None,
+        };
+        if draining.is_some() {
+            context.set_node_state(NodeState::ShuttingDown);
+        }
+        draining
+    };
+
+    if let Some((network_shutdown, mut block_task, storage)) = draining {
+        tracing::info!("gracefully shutting down the node");
+        network_shutdown.cancel();
+
+        let (reply_handle, reply_future) = intercom::unary_reply();
+        block_task.send(BlockMsg::Drain(reply_handle)).await?;
+        match tokio::time::timeout(timeout, reply_future).await {
+            Ok(res) => res?,
+            Err(_) => tracing::warn!(
+                "blocks still in flight after {} seconds, shutting down anyway",
+                timeout.as_secs()
+            ),
+        }
+        storage.flush()?;
+    }
+
+    context.read().await.rest_server_stopper()?.stop();
+    Ok(())
+}
+
 pub async fn get_leaders_logs(context: &Context) -> Result<Vec<LeadershipLog>, Error> {
     Ok(context.try_full()?.leadership_logs.logs().await)
 }
@@ -381,6 +466,14 @@ pub async fn get_network_stats(context: &Context) -> Result<Vec<PeerStats>, Erro
                 last_block_received: info.stats.last_block_received().map(SystemTime::from),
                 last_fragment_received: info.stats.last_fragment_received().map(SystemTime::from),
                 last_gossip_received: info.stats.last_gossip_received().map(SystemTime::from),
+                requests_served: info.traffic.requests,
+                bytes_received: info.traffic.bytes_received,
+                bytes_sent: info.traffic.bytes_sent,
+                gossip_dropped: info.overflow.gossip_dropped,
+                fragments_dropped: info.overflow.fragments_dropped,
+                fragments_coalesced: info.overflow.fragments_coalesced,
+                fragment_dedup_hits: info.overflow.fragment_dedup_hits,
+                fragment_dedup_misses: info.overflow.fragment_dedup_misses,
             })
             .collect())
     }
@@ -473,6 +566,11 @@ pub async fn get_utxo(
         .map(Into::into))
 }
 
+pub async fn get_utxos(context: &Context, query: UTxOsQuery) -> Result<Vec<UTxOInfo>, Error> {
+    let ledger = context.blockchain_tip()?.get_ref().await.ledger();
+    Ok(query.apply(ledger.utxos()).collect())
+}
+
 pub async fn get_stake_pool(
     context: &Context,
     pool_id_hex: &str,
@@ -513,38 +611,115 @@ pub async fn get_diagnostic(context: &Context) -> Result<Diagnostic, Error> {
 
 pub async fn get_network_p2p_quarantined(context: &Context) -> Result<Vec<PeerInfo>, Error> {
     let (reply_handle, reply_future) = intercom::unary_reply();
-    let mut mbox = context.try_full()?.topology_task.clone();
+    let full_context = context.try_full()?;
+    let mut mbox = full_context.topology_task.clone();
     mbox.send(TopologyMsg::ListQuarantined(reply_handle))
         .await
         .map_err(|e| {
             tracing::debug!(reason = %e, "error getting non public peers");
             Error::MsgSendError(e)
         })?;
-    reply_future.await.map_err(Into::into)
+    let peers = reply_future.await?;
+    Ok(with_scores(full_context, peers))
+}
+
+pub async fn delete_network_p2p_quarantined(
+    context: &Context,
+    peer: &str,
+) -> Result<Option<PeerInfo>, Error> {
+    let peer_ref = parse_peer_ref(peer)?;
+    let (reply_handle, reply_future) = intercom::unary_reply();
+    let full_context = context.try_full()?;
+    let mut mbox = full_context.topology_task.clone();
+    mbox.send(TopologyMsg::LiftQuarantine(peer_ref, reply_handle))
+        .await
+        .map_err(|e| {
+            tracing::debug!(reason = %e, "error lifting peer quarantine");
+            Error::MsgSendError(e)
+        })?;
+    let peer = reply_future.await?;
+    // The gRPC server may have quarantined the peer without the topology
+    // knowing of it, so its score is forgiven in any case
+    let scores = full_context.network_state.peers.score();
+    match peer_ref {
+        PeerRef::Id(id) => scores.forgive(&id),
+        PeerRef::Address(addr) => scores.forgive_ip(addr.ip()),
+    }
+    if let Some(peer) = &peer {
+        scores.forgive(&peer.id);
+    }
+    Ok(peer)
+}
+
+pub async fn extend_network_p2p_quarantined(
+    context: &Context,
+    peer: &str,
+    duration: Duration,
+) -> Result<Option<PeerInfo>, Error> {
+    let peer_ref = parse_peer_ref(peer)?;
+    let (reply_handle, reply_future) = intercom::unary_reply();
+    let full_context = context.try_full()?;
+    let mut mbox = full_context.topology_task.clone();
+    mbox.send(TopologyMsg::ExtendQuarantine(
+        peer_ref,
+        duration,
+        reply_handle,
+    ))
+    .await
+    .map_err(|e| {
+        tracing::debug!(reason = %e, "error extending peer quarantine");
+        Error::MsgSendError(e)
+    })?;
+    let peer = reply_future.await?;
+    let scores = full_context.network_state.peers.score();
+    match peer_ref {
+        PeerRef::Id(id) => scores.extend_quarantine(&id, duration),
+        PeerRef::Address(addr) => scores.extend_ip_quarantine(addr.ip(), duration),
+    };
+    Ok(peer.map(|mut peer| {
+        if let PeerRef::Address(_) = peer_ref {
+            scores.extend_quarantine(&peer.id, duration);
+        }
+        peer.score = scores.get(&peer.id);
+        peer
+    }))
 }
 
 pub async fn get_network_p2p_non_public(context: &Context) -> Result<Vec<PeerInfo>, Error> {
     let (reply_handle, reply_future) = intercom::unary_reply();
-    let mut mbox = context.try_full()?.topology_task.clone();
+    let full_context = context.try_full()?;
+    let mut mbox = full_context.topology_task.clone();
     mbox.send(TopologyMsg::ListNonPublic(reply_handle))
         .await
         .map_err(|e| {
             tracing::debug!(reason = %e, "error getting non public peers");
             Error::MsgSendError(e)
         })?;
-    reply_future.await.map_err(Into::into)
+    let peers = reply_future.await?;
+    Ok(with_scores(full_context, peers))
 }
 
 pub async fn get_network_p2p_available(context: &Context) -> Result<Vec<PeerInfo>, Error> {
     let (reply_handle, reply_future) = intercom::unary_reply();
-    let mut mbox = context.try_full()?.topology_task.clone();
+    let full_context = context.try_full()?;
+    let mut mbox = full_context.topology_task.clone();
     mbox.send(TopologyMsg::ListAvailable(reply_handle))
         .await
         .map_err(|e| {
             tracing::debug!(reason = %e, "error getting available peers");
             Error::MsgSendError(e)
         })?;
-    reply_future.await.map_err(Into::into)
+    let peers = reply_future.await?;
+    Ok(with_scores(full_context, peers))
+}
+
+/// Fills in the misbehavior scores of the peers recorded by the gRPC server
+fn with_scores(context: &FullContext, mut peers: Vec<PeerInfo>) -> Vec<PeerInfo> {
+    let scores = context.network_state.peers.score();
+    for peer in peers.iter_mut() {
+        peer.score = scores.get(&peer.id);
+    }
+    peers
 }
 
 async fn get_topology_view(
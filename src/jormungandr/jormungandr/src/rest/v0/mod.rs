@@ -31,8 +31,9 @@ pub fn filter(
 
     let shutdown = warp::path!("shutdown")
         .and(warp::get().or(warp::post()))
+        .and(warp::query())
         .and(with_context.clone())
-        .and_then(|_, context| handlers::shutdown(context))
+        .and_then(|_, query, context| handlers::shutdown(query, context))
         .boxed();
 
     let account = warp::path!("account" / String)
@@ -65,6 +66,7 @@ pub fn filter(
 
         let logs = warp::path!("logs")
             .and(warp::get())
+            .and(warp::query())
             .and(with_context.clone())
             .and_then(handlers::get_message_logs)
             .boxed();
@@ -87,11 +89,30 @@ pub fn filter(
     let p2p = {
         let root = warp::path!("p2p" / ..);
 
-        let quarantined = warp::path!("quarantined")
-            .and(warp::get())
-            .and(with_context.clone())
-            .and_then(handlers::get_network_p2p_quarantined)
-            .boxed();
+        let quarantined = {
+            let root = warp::path!("quarantined" / ..);
+
+            let list = warp::path::end()
+                .and(warp::get())
+                .and(with_context.clone())
+                .and_then(handlers::get_network_p2p_quarantined)
+                .boxed();
+
+            let lift = warp::path!(String)
+                .and(warp::delete())
+                .and(with_context.clone())
+                .and_then(handlers::delete_network_p2p_quarantined)
+                .boxed();
+
+            let extend = warp::path!(String)
+                .and(warp::post())
+                .and(warp::query())
+                .and(with_context.clone())
+                .and_then(handlers::extend_network_p2p_quarantined)
+                .boxed();
+
+            root.and(list.or(lift).or(extend)).boxed()
+        };
 
         let non_public = warp::path!("non_public")
             .and(warp::get())
@@ -175,12 +196,25 @@ pub fn filter(
         .and_then(handlers::get_stake_pool)
         .boxed();
 
-    let message = warp::path!("message")
-        .and(warp::post())
-        .and(warp::body::bytes())
-        .and(with_context.clone())
-        .and_then(handlers::post_message)
-        .boxed();
+    let message = {
+        let root = warp::path!("message" / ..);
+
+        let post = warp::path::end()
+            .and(warp::post())
+            .and(warp::body::bytes())
+            .and(with_context.clone())
+            .and_then(handlers::post_message)
+            .boxed();
+
+        let batch = warp::path!("batch")
+            .and(warp::post())
+            .and(warp::body::json())
+            .and(with_context.clone())
+            .and_then(handlers::post_message_batch)
+            .boxed();
+
+        root.and(post.or(batch)).boxed()
+    };
 
     let node_stats = warp::path!("node" / "stats")
         .and(warp::get())
@@ -218,11 +252,24 @@ pub fn filter(
         root.and(history.or(epoch).or(remaining)).boxed()
     };
 
-    let utxo = warp::path!("utxo" / String / u8)
-        .and(warp::get())
-        .and(with_context.clone())
-        .and_then(handlers::get_utxo)
-        .boxed();
+    let utxo = {
+        let root = warp::path!("utxo" / ..);
+
+        let list = warp::path::end()
+            .and(warp::get())
+            .and(warp::query())
+            .and(with_context.clone())
+            .and_then(handlers::get_utxos)
+            .boxed();
+
+        let get = warp::path!(String / u8)
+            .and(warp::get())
+            .and(with_context.clone())
+            .and_then(handlers::get_utxo)
+            .boxed();
+
+        root.and(list.or(get)).boxed()
+    };
 
     let diagnostic = warp::path!("diagnostic")
         .and(warp::get())
@@ -288,6 +335,9 @@ async fn handle_rejection(err: Rejection) -> Result<impl Reply, Rejection> {
                 serde_json::to_string(&summary).unwrap(),
                 StatusCode::BAD_REQUEST,
             ),
+            logic::Error::ContextError(crate::context::Error::ShuttingDown) => {
+                (err.to_string(), StatusCode::SERVICE_UNAVAILABLE)
+            }
             err => (
                 display_internal_server_error(err),
                 StatusCode::INTERNAL_SERVER_ERROR,
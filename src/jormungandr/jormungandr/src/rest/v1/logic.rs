@@ -18,7 +18,7 @@ use futures::{
 };
 use hex::ToHex;
 use jormungandr_lib::interfaces::{
-    AccountVotes, FragmentLog, FragmentOrigin, FragmentStatus, FragmentsBatch,
+    AccountVotes, FragmentLog, FragmentLogsQuery, FragmentOrigin, FragmentStatus, FragmentsBatch,
     FragmentsProcessingSummary, VotePlanId,
 };
 use std::{collections::HashMap, convert::TryInto, str::FromStr};
@@ -95,12 +95,14 @@ pub async fn post_fragments(
     context: &Context,
     batch: FragmentsBatch,
 ) -> Result<FragmentsProcessingSummary, Error> {
+    context.check_not_shutting_down()?;
     let mut msgbox = context.try_full()?.transaction_task.clone();
     let (reply_handle, reply_future) = intercom::unary_reply();
     let msg = TransactionMsg::SendTransactions {
         origin: FragmentOrigin::Rest,
         fragments: batch.fragments,
         fail_fast: batch.fail_fast,
+        all_or_nothing: false,
         reply_handle,
     };
     msgbox.try_send(msg)?;
@@ -118,12 +120,15 @@ pub async fn get_fragment_logs(context: &Context) -> Result<Vec<FragmentLog>, Er
     async move {
         let (reply_handle, reply_future) = intercom::unary_reply();
         let mut mbox = context.try_full()?.transaction_task.clone();
-        mbox.send(TransactionMsg::GetLogs(reply_handle))
-            .await
-            .map_err(|e| {
-                tracing::debug!(reason = %e, "error getting fragment logs");
-                Error::MsgSend(e)
-            })?;
+        mbox.send(TransactionMsg::GetLogs(
+            FragmentLogsQuery::default(),
+            reply_handle,
+        ))
+        .await
+        .map_err(|e| {
+            tracing::debug!(reason = %e, "error getting fragment logs");
+            Error::MsgSend(e)
+        })?;
         reply_future.await.map_err(Into::into)
     }
     .instrument(span)
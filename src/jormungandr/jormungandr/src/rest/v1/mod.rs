@@ -68,6 +68,9 @@ async fn handle_rejection(err: Rejection) -> Result<impl Reply, Rejection> {
                 serde_json::to_string(&summary).unwrap(),
                 StatusCode::BAD_REQUEST,
             ),
+            logic::Error::Context(crate::context::Error::ShuttingDown) => {
+                (err.to_string(), StatusCode::SERVICE_UNAVAILABLE)
+            }
             err => (
                 display_internal_server_error(err),
                 StatusCode::INTERNAL_SERVER_ERROR,
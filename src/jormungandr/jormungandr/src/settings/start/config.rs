@@ -6,7 +6,10 @@ use crate::{
     },
     topology::QuarantineConfig,
 };
-pub use jormungandr_lib::interfaces::{Cors, JRpc, LayersConfig, Rest, Tls, TrustedPeer};
+pub use jormungandr_lib::interfaces::{
+    BlockCompression, Cors, FragmentDedup, JRpc, LayersConfig, OverflowPolicy, Rest,
+    SubscriptionOverflow, Tls, TrustedPeer,
+};
 use jormungandr_lib::{interfaces::Mempool, time::Duration};
 use multiaddr::Multiaddr;
 use serde::{de::Error as _, Deserialize, Deserializer, Serialize, Serializer};
@@ -149,6 +152,71 @@ pub struct Connection {
     /// The default value is 5 min.
     #[serde(default)]
     pub network_stuck_check: Option<Duration>,
+
+    /// interval of the HTTP/2 keepalive pings sent by the node to the
+    /// peers connected to it.
+    ///
+    /// The default value is 60 seconds.
+    #[serde(default)]
+    pub http2_keepalive_interval: Option<Duration>,
+
+    /// peers not acknowledging a keepalive ping within this timeout
+    /// are considered stale and their connection is closed.
+    ///
+    /// The default value is 20 seconds.
+    #[serde(default)]
+    pub keepalive_timeout: Option<Duration>,
+
+    /// maximum number of requests served at the same time on each
+    /// connection accepted by the node.
+    ///
+    /// The limit of the HTTP/2 implementation applies by default.
+    #[serde(default)]
+    pub http2_max_concurrent_streams: Option<u32>,
+
+    /// maximum number of block, fragment, gossip and tip subscriptions
+    /// served at the same time to a peer, across all of its connections.
+    /// Excess subscription requests are refused. The peers are told apart
+    /// by their IP address, so the nodes behind a NAT share the limit.
+    ///
+    /// The subscriptions are not limited by default.
+    #[serde(default)]
+    pub max_subscriptions_per_peer: Option<usize>,
+
+    /// compression of the block streams exchanged with the peers, used
+    /// for the blocks requested and uploaded by this node and offered
+    /// to the peers asking for it.
+    ///
+    /// Block streams are not compressed by default.
+    #[serde(default)]
+    pub block_compression: Option<BlockCompression>,
+
+    /// serve the gRPC server reflection service, describing the node
+    /// services to generic tools such as `grpcurl`.
+    ///
+    /// Reflection is disabled by default.
+    #[serde(default)]
+    pub grpc_reflection: bool,
+
+    /// what to do with the gossip and fragments received from a peer
+    /// when the node falls behind in processing them: drop the oldest
+    /// messages, drop the new ones or park the subscription until the
+    /// node catches up.
+    ///
+    /// Gossip drops the oldest messages and fragments are parked by
+    /// default. Block announcements are always parked.
+    #[serde(default)]
+    pub subscription_overflow: SubscriptionOverflow,
+
+    /// remember the fragments received from the peers for a while, so that
+    /// the same fragment arriving from many peers is only processed once.
+    /// Header ids are deliberately not remembered: if pulling a block
+    /// announced by one peer fails, the same announcement from another peer
+    /// is needed to pull it again.
+    ///
+    /// Fragments are not deduplicated by default.
+    #[serde(default)]
+    pub fragment_dedup: Option<FragmentDedup>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
@@ -340,6 +340,37 @@ fn generate_network(
             .network_stuck_check
             .map(Into::into)
             .unwrap_or(crate::topology::DEFAULT_NETWORK_STUCK_INTERVAL),
+        http2_keepalive_interval: p2p
+            .connection
+            .http2_keepalive_interval
+            .map(Into::into)
+            .unwrap_or(network::DEFAULT_HTTP2_KEEPALIVE_INTERVAL),
+        keepalive_timeout: p2p
+            .connection
+            .keepalive_timeout
+            .map(Into::into)
+            .unwrap_or(network::DEFAULT_KEEPALIVE_TIMEOUT),
+        http2_max_concurrent_streams: p2p.connection.http2_max_concurrent_streams,
+        max_subscriptions_per_peer: p2p.connection.max_subscriptions_per_peer,
+        block_compression: p2p.connection.block_compression,
+        grpc_reflection: p2p.connection.grpc_reflection,
+        gossip_overflow: p2p
+            .connection
+            .subscription_overflow
+            .gossip
+            .unwrap_or(network::DEFAULT_GOSSIP_OVERFLOW),
+        fragment_overflow: p2p
+            .connection
+            .subscription_overflow
+            .fragments
+            .unwrap_or(network::DEFAULT_FRAGMENT_OVERFLOW),
+        fragment_dedup: p2p.connection.fragment_dedup.map(|dedup| network::Dedup {
+            capacity: dedup.capacity.unwrap_or(network::DEFAULT_DEDUP_CAPACITY),
+            ttl: dedup
+                .ttl
+                .map(Into::into)
+                .unwrap_or(network::DEFAULT_DEDUP_TTL),
+        }),
         max_bootstrap_attempts: p2p.bootstrap.max_bootstrap_attempts,
         http_fetch_block0_service,
         bootstrap_from_trusted_peers,
@@ -1,4 +1,4 @@
-use super::config;
+use super::config::{self, BlockCompression, OverflowPolicy};
 use crate::{
     network::p2p::Address,
     topology::{layers::LayersConfig, NodeId, QuarantineConfig},
@@ -46,6 +46,31 @@ pub const DEFAULT_MAX_CONNECTIONS: usize = 256;
 /// used unless the corresponding configuration option is specified.
 pub const DEFAULT_MAX_CLIENT_CONNECTIONS: usize = 192;
 
+/// The interval of HTTP/2 keepalive pings sent to inbound connections
+/// used unless the corresponding configuration option is specified.
+pub const DEFAULT_HTTP2_KEEPALIVE_INTERVAL: Duration = Duration::from_secs(60);
+
+/// The timeout after which an inbound connection not acknowledging
+/// keepalive pings is closed, unless configured otherwise.
+pub const DEFAULT_KEEPALIVE_TIMEOUT: Duration = Duration::from_secs(20);
+
+/// The overflow policy of the gossip subscriptions
+/// used unless the corresponding configuration option is specified.
+pub const DEFAULT_GOSSIP_OVERFLOW: OverflowPolicy = OverflowPolicy::DropOldest;
+
+/// The overflow policy of the fragment subscriptions
+/// used unless the corresponding configuration option is specified.
+pub const DEFAULT_FRAGMENT_OVERFLOW: OverflowPolicy = OverflowPolicy::Park;
+
+/// How long the announcements are remembered when deduplication is enabled,
+/// unless the corresponding configuration option is specified.
+pub const DEFAULT_DEDUP_TTL: Duration = Duration::from_secs(60);
+
+/// The number of fragments remembered when
+/// deduplication is enabled, unless the corresponding configuration option
+/// is specified.
+pub const DEFAULT_DEDUP_CAPACITY: usize = 4096;
+
 /// The default timeout for connections
 const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
 
@@ -91,6 +116,35 @@ pub struct Configuration {
 
     pub network_stuck_check: Duration,
 
+    /// Interval of HTTP/2 keepalive pings sent to inbound connections.
+    pub http2_keepalive_interval: Duration,
+
+    /// Inbound connections not acknowledging a keepalive ping within
+    /// this timeout are closed.
+    pub keepalive_timeout: Duration,
+
+    /// Limit of concurrent requests on each inbound connection, if any.
+    pub http2_max_concurrent_streams: Option<u32>,
+
+    /// Limit of simultaneous subscriptions served to each peer, if any.
+    pub max_subscriptions_per_peer: Option<usize>,
+
+    /// Compression of the block streams, if any.
+    pub block_compression: Option<BlockCompression>,
+
+    /// Whether to serve the gRPC reflection service.
+    pub grpc_reflection: bool,
+
+    /// Overflow policy of the gossip subscriptions of the peers.
+    pub gossip_overflow: OverflowPolicy,
+
+    /// Overflow policy of the fragment subscriptions of the peers.
+    pub fragment_overflow: OverflowPolicy,
+
+    /// Limits of the cache of the fragments recently received from
+    /// the peers, if they are deduplicated.
+    pub fragment_dedup: Option<Dedup>,
+
     pub max_bootstrap_attempts: Option<usize>,
 
     /// Whether to limit bootstrap to trusted peers (which increase their load / reduce their connectivities)
@@ -102,6 +156,13 @@ pub struct Configuration {
     pub http_fetch_block0_service: Vec<String>,
 }
 
+/// Limits of the cache of recently received fragments.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Dedup {
+    pub capacity: usize,
+    pub ttl: Duration,
+}
+
 /// Trusted peer with DNS address resolved.
 #[derive(Clone, Hash)]
 pub struct TrustedPeer {
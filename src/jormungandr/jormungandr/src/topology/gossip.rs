@@ -21,6 +21,24 @@ impl Gossip {
         NodeId(self.0.id())
     }
 
+    /// The node description to carry in a gossip envelope
+    #[inline]
+    pub fn payload(&self) -> &[u8] {
+        self.0.as_ref()
+    }
+
+    /// Reads the gossip from the node description carried in a gossip envelope
+    pub fn from_payload(bytes: &[u8]) -> Result<Self, GossipError> {
+        if bytes.len() > limits::MAX_GOSSIP_SIZE {
+            return Err(
+                property::ReadError::SizeTooBig(limits::MAX_GOSSIP_SIZE, bytes.len()).into(),
+            );
+        }
+        Ok(Gossip(
+            poldercast::GossipSlice::try_from_slice(bytes)?.to_owned(),
+        ))
+    }
+
     pub fn has_valid_address(&self) -> bool {
         let addr = self.address();
 
@@ -172,7 +190,9 @@ impl property::Deserialize for Gossip {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use chain_core::property::{DeserializeFromSlice, Serialize as _};
     use chain_impl_mockchain::testing::serialization::serialization_bijection;
+    use chain_network::data::gossip::{GossipEnvelope, GOSSIP_VERSION};
     use quickcheck::{quickcheck, Arbitrary, TestResult};
     use rand::SeedableRng;
     use std::net::{Ipv4Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
@@ -205,6 +225,35 @@ mod tests {
         }
     }
 
+    #[test]
+    fn gossip_envelope_unknown_sections_ignored() {
+        let ip = Ipv4Addr::new(1, 2, 3, 4);
+        let gossip = build_gossip(SocketAddr::V4(SocketAddrV4::new(ip, 1234)));
+        let node = GossipEnvelope::new(gossip.payload())
+            .with_section(42, vec![1, 2, 3])
+            .encode();
+
+        // peers without envelopes read the gossip and ignore the trailer
+        let legacy = Gossip::deserialize_from_slice(&mut Codec::new(node.as_bytes())).unwrap();
+        assert_eq!(legacy, gossip);
+
+        let envelope = GossipEnvelope::decode(&node).unwrap();
+        assert_eq!(envelope.version(), Some(GOSSIP_VERSION));
+        assert_eq!(Gossip::from_payload(envelope.payload()).unwrap(), gossip);
+    }
+
+    #[test]
+    fn gossip_without_envelope() {
+        let ip = Ipv4Addr::new(1, 2, 3, 4);
+        let gossip = build_gossip(SocketAddr::V4(SocketAddrV4::new(ip, 1234)));
+        let node =
+            chain_network::data::gossip::Node::from_bytes(gossip.serialize_as_vec().unwrap());
+
+        let envelope = GossipEnvelope::decode(&node).unwrap();
+        assert_eq!(envelope.version(), None);
+        assert_eq!(Gossip::from_payload(envelope.payload()).unwrap(), gossip);
+    }
+
     #[test]
     fn gossip_global_ipv4_private() {
         let ip = Ipv4Addr::new(10, 0, 0, 1);
@@ -22,7 +22,7 @@ pub use self::{
     process::{start, TaskData, DEFAULT_NETWORK_STUCK_INTERVAL},
     topology::{P2pTopology, View},
 };
-pub use quarantine::{QuarantineConfig, ReportRecords};
+pub use quarantine::{PeerRef, QuarantineConfig, ReportRecords};
 
 /**
 # topics definition for p2p interest subscriptions
@@ -114,6 +114,8 @@ pub struct PeerInfo {
     pub last_update: SystemTime,
     pub quarantined: Option<SystemTime>,
     pub subscriptions: Vec<Subscription>,
+    /// Misbehavior score of the peer, as recorded by the gRPC server
+    pub score: u32,
 }
 
 impl PartialEq for PeerInfo {
@@ -137,6 +139,7 @@ impl From<Peer> for PeerInfo {
             address: other.address(),
             last_update: other.time().to_system_time().into(),
             quarantined: None,
+            score: 0,
             subscriptions: other
                 .subscriptions()
                 .iter()
@@ -88,6 +88,12 @@ impl Process {
                         TopologyMsg::ListQuarantined(handle) => {
                             handle.reply_ok(self.topology.list_quarantined())
                         }
+                        TopologyMsg::LiftQuarantine(peer, handle) => {
+                            handle.reply_ok(self.topology.lift_quarantine(&peer))
+                        }
+                        TopologyMsg::ExtendQuarantine(peer, duration, handle) => {
+                            handle.reply_ok(self.topology.extend_quarantine(&peer, duration))
+                        }
                     }
                     tracing::trace!("item handling finished");
                 },
@@ -28,7 +28,23 @@ const DEFAULT_MAX_NUM_QUARANTINE_RECORDS: usize = 24_000;
 #[derive(Debug, Clone)]
 struct ReportRecord {
     peer_info: PeerInfo,
-    report_time: Instant,
+    lift_time: Instant,
+}
+
+/// A reported peer, given by its id or by its address
+#[derive(Debug, Clone, Copy)]
+pub enum PeerRef {
+    Id(NodeId),
+    Address(Address),
+}
+
+impl PeerRef {
+    fn matches(&self, peer_info: &PeerInfo) -> bool {
+        match self {
+            PeerRef::Id(id) => peer_info.id == *id,
+            PeerRef::Address(address) => peer_info.address == *address,
+        }
+    }
 }
 
 pub enum ReportNodeStatus {
@@ -112,7 +128,7 @@ impl ReportRecords {
                 peer_info.id,
                 ReportRecord {
                     peer_info,
-                    report_time: Instant::now(),
+                    lift_time: Instant::now() + self.report_duration,
                 },
             );
 
@@ -132,20 +148,47 @@ impl ReportRecords {
     }
 
     pub fn lift_reports(&mut self) -> Vec<PeerInfo> {
-        let mut res = Vec::new();
-        // This is basically a FIFO queue, a lru cache is being used just to
-        // avoid keeping another data structure to know if an address was already quarantined
-        while let Some((_id, record)) = self.report_records.peek_lru() {
-            if record.report_time.elapsed() < self.report_duration {
-                break;
-            }
+        let now = Instant::now();
+        // Reports can be extended, so records are not lifted in the order
+        // they were reported in.
+        let lifted: Vec<NodeId> = self
+            .report_records
+            .iter()
+            .filter(|(_, record)| record.lift_time <= now)
+            .map(|(id, _)| *id)
+            .collect();
 
-            let (id, record) = self.report_records.pop_lru().unwrap();
-            self.report_grace.put(id, ());
-            res.push(record.peer_info);
-        }
+        lifted.into_iter().filter_map(|id| self.lift(&id)).collect()
+    }
+
+    /// Lifts the report against the peer before it is due, returns `None`
+    /// if the peer is not reported.
+    pub fn lift_report(&mut self, peer: &PeerRef) -> Option<PeerInfo> {
+        let id = self.find(peer)?;
+        self.lift(&id)
+    }
+
+    /// Postpones the lifting of the report against the peer by `duration`,
+    /// returns `None` if the peer is not reported.
+    pub fn extend_report(&mut self, peer: &PeerRef, duration: StdDuration) -> Option<PeerInfo> {
+        let id = self.find(peer)?;
+        let record = self.report_records.peek_mut(&id)?;
+        record.lift_time += duration;
+        tracing::debug!(node = %record.peer_info.address, id=%record.peer_info.id, ?duration, "report extended");
+        Some(record.peer_info.clone())
+    }
 
-        res
+    fn find(&self, peer: &PeerRef) -> Option<NodeId> {
+        self.report_records
+            .iter()
+            .find(|(_, record)| peer.matches(&record.peer_info))
+            .map(|(id, _)| *id)
+    }
+
+    fn lift(&mut self, id: &NodeId) -> Option<PeerInfo> {
+        let record = self.report_records.pop(id)?;
+        self.report_grace.put(*id, ());
+        Some(record.peer_info)
     }
 }
 
@@ -182,3 +225,84 @@ impl Default for QuarantineConfig {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chain_crypto::{Ed25519, SecretKey};
+    use rand::SeedableRng;
+
+    const EXTENSION: StdDuration = StdDuration::from_secs(60);
+
+    fn peer_info(seed: u8, address: &str) -> PeerInfo {
+        let key = SecretKey::<Ed25519>::generate(rand_chacha::ChaChaRng::from_seed([seed; 32]));
+        PeerInfo {
+            id: NodeId::try_from(key.to_public().as_ref()).unwrap(),
+            address: address.parse().unwrap(),
+            last_update: SystemTime::now(),
+            quarantined: Some(SystemTime::now()),
+            subscriptions: Vec::new(),
+            score: 0,
+        }
+    }
+
+    fn report(records: &mut ReportRecords, peer_info: &PeerInfo, lift_time: Instant) {
+        records.report_records.put(
+            peer_info.id,
+            ReportRecord {
+                peer_info: peer_info.clone(),
+                lift_time,
+            },
+        );
+    }
+
+    #[test]
+    fn lift_report_by_id_or_address() {
+        let first = peer_info(1, "127.0.0.1:4000");
+        let second = peer_info(2, "127.0.0.2:4000");
+        let mut records = ReportRecords::default();
+        let lift_time = Instant::now() + records.report_duration;
+        report(&mut records, &first, lift_time);
+        report(&mut records, &second, lift_time);
+
+        assert_eq!(
+            records.lift_report(&PeerRef::Id(first.id)),
+            Some(first.clone())
+        );
+        assert_eq!(
+            records.lift_report(&PeerRef::Address(second.address)),
+            Some(second.clone())
+        );
+        assert!(records.reported_nodes().is_empty());
+        assert!(records.report_grace.contains(&first.id));
+        assert!(records.report_grace.contains(&second.id));
+        assert_eq!(records.lift_report(&PeerRef::Id(first.id)), None);
+    }
+
+    #[test]
+    fn extend_report_postpones_lifting() {
+        let extended = peer_info(1, "127.0.0.1:4000");
+        let due = peer_info(2, "127.0.0.2:4000");
+        let unreported = peer_info(3, "127.0.0.3:4000");
+        let mut records = ReportRecords::default();
+        let now = Instant::now();
+        report(&mut records, &extended, now);
+        report(&mut records, &due, now);
+
+        assert_eq!(
+            records.extend_report(&PeerRef::Address(extended.address), EXTENSION),
+            Some(extended.clone())
+        );
+        assert_eq!(
+            records.extend_report(&PeerRef::Id(unreported.id), EXTENSION),
+            None
+        );
+        assert_eq!(
+            records.report_records.peek(&extended.id).unwrap().lift_time,
+            now + EXTENSION
+        );
+
+        assert_eq!(records.lift_reports(), vec![due]);
+        assert_eq!(records.reported_nodes(), vec![extended]);
+    }
+}
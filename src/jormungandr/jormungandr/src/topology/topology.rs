@@ -3,7 +3,7 @@
 use super::{
     layers::{self, LayersConfig},
     quarantine::ReportNodeStatus,
-    topic, Gossips, NodeId, Peer, PeerInfo, ReportRecords,
+    topic, Gossips, NodeId, Peer, PeerInfo, PeerRef, ReportRecords,
 };
 use crate::{
     metrics::{Metrics, MetricsBackend},
@@ -20,6 +20,7 @@ use rand_chacha::ChaChaRng;
 use std::{
     convert::TryInto,
     net::{IpAddr, Ipv4Addr, SocketAddr},
+    time::Duration,
 };
 use tracing::instrument;
 
@@ -256,6 +257,30 @@ impl P2pTopology {
             .collect()
     }
 
+    /// Lifts the quarantine of the peer on request of the node operator,
+    /// the peer is then available to be selected in the next views.
+    pub fn lift_quarantine(&mut self, peer: &PeerRef) -> Option<PeerInfo> {
+        let peer_info = self.quarantine.lift_report(peer)?;
+        if self
+            .topology
+            .peers()
+            .dirty()
+            .contains(peer_info.id.as_ref())
+        {
+            tracing::info!(node = %peer_info.address, id=%peer_info.id, "lifting node from quarantine on request");
+            self.topology.promote_peer(peer_info.id.as_ref());
+            self.stats_counter.sub_peer_quarantined_cnt(1);
+            self.stats_counter
+                .set_peer_available_cnt(self.peer_available_cnt());
+        }
+        Some(peer_info)
+    }
+
+    /// Keeps the peer in quarantine for `duration` more
+    pub fn extend_quarantine(&mut self, peer: &PeerRef, duration: Duration) -> Option<PeerInfo> {
+        self.quarantine.extend_report(peer, duration)
+    }
+
     fn peer_available_cnt(&self) -> usize {
         // We cannot use ExactSizeIterator as a limitation of iterator::chain, but since
         // size_hint still relies on the underlying exact size iterator, it is equivalent.
@@ -174,6 +174,11 @@ impl WatchClient {
         (client, message_processor)
     }
 
+    /// Receiver of the node's tip headers, as broadcast to watch subscribers
+    pub fn tip_receiver(&self) -> watch::Receiver<Header> {
+        self.tip_receiver.clone()
+    }
+
     pub fn into_server(self) -> server::Server<Self> {
         server::Server::new(WatchService::new(self))
     }
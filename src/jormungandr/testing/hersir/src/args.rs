@@ -1,3 +1,4 @@
+use jormungandr_automation::jormungandr::NodeWrapper;
 use std::path::PathBuf;
 use structopt::StructOpt;
 
@@ -13,4 +14,16 @@ pub struct Args {
     /// Enable verbose mode
     #[structopt(long, short)]
     pub verbose: bool,
+
+    /// Run all nodes under the given tool: valgrind, heaptrack, perf or
+    /// any custom command prefix (e.g. "strace -f"). Tool outputs are
+    /// stored in the node directories under the session root.
+    #[structopt(long)]
+    pub node_wrapper: Option<NodeWrapper>,
+
+    /// Record every fragment sent in interactive mode into the given
+    /// session file, so that it can be replayed later with the `replay`
+    /// command against a network started from the same block0.
+    #[structopt(long)]
+    pub record_fragments: Option<PathBuf>,
 }
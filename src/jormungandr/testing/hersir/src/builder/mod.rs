@@ -29,7 +29,7 @@ use std::{
     path::Path,
     rc::{Rc, Weak},
 };
-pub use topology::{Node, Topology};
+pub use topology::{Node, RelayZone, Topology};
 pub use vote::VotePlanKey;
 
 #[derive(Default)]
@@ -3,6 +3,8 @@ use std::collections::{HashMap, HashSet};
 #[derive(Debug, Clone, Default)]
 pub struct Topology {
     pub nodes: HashMap<NodeAlias, Node>,
+    /// relays of all the zones added so far, in order
+    relays: Vec<NodeAlias>,
 }
 
 impl Topology {
@@ -10,6 +12,71 @@ impl Topology {
         self.nodes.insert(node.alias.clone(), node);
         self
     }
+
+    /// Adds the nodes of a relay zone. Each relay trusts the relays added
+    /// before it, from this zone and the previous ones, so that all relays are
+    /// interconnected without circular trust. Edge nodes only trust the relays
+    /// of their zone.
+    ///
+    /// Trusted peers already set on a relay node are kept.
+    pub fn with_relay_zone(mut self, zone: RelayZone) -> Self {
+        for relay in zone.relays.iter() {
+            let node = self
+                .nodes
+                .remove(relay)
+                .unwrap_or_else(|| Node::new(relay.clone()));
+            let node = self
+                .relays
+                .iter()
+                .fold(node, |node, peer| node.with_trusted_peer(peer));
+            self = self.with_node(node);
+            self.relays.push(relay.clone());
+        }
+
+        for edge in zone.edges.iter() {
+            let node = zone
+                .relays
+                .iter()
+                .fold(Node::new(edge.clone()), |node, relay| {
+                    node.with_trusted_peer(relay)
+                });
+            self = self.with_node(node);
+        }
+        self
+    }
+
+    /// Relays of all the zones, in the order they were added
+    pub fn relays(&self) -> &[NodeAlias] {
+        &self.relays
+    }
+}
+
+/// A group of edge nodes which only connect to the network through the
+/// relays of the group.
+#[derive(Debug, Clone, Default)]
+pub struct RelayZone {
+    relays: Vec<NodeAlias>,
+    edges: Vec<NodeAlias>,
+}
+
+impl RelayZone {
+    pub fn with_relay<S: Into<NodeAlias>>(mut self, relay: S) -> Self {
+        self.relays.push(relay.into());
+        self
+    }
+
+    pub fn with_edge<S: Into<NodeAlias>>(mut self, edge: S) -> Self {
+        self.edges.push(edge.into());
+        self
+    }
+
+    pub fn relays(&self) -> &[NodeAlias] {
+        &self.relays
+    }
+
+    pub fn edges(&self) -> &[NodeAlias] {
+        &self.edges
+    }
 }
 
 #[derive(Debug, Clone)]
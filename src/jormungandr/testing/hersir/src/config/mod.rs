@@ -15,25 +15,66 @@ use crate::{
     error::Error,
 };
 use jormungandr_automation::jormungandr::{
-    explorer::configuration::ExplorerParams, LogLevel, PersistenceMode, TestingDirectory,
+    explorer::configuration::ExplorerParams, LogLevel, NodeWrapper, PersistenceMode,
+    TestingDirectory,
 };
 use serde::Deserialize;
-use std::{collections::HashSet, path::PathBuf, str::FromStr};
+use std::{
+    collections::HashSet,
+    fs::File,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
 pub use vote_plan::{PrivateParameters, VotePlanTemplate};
 
+/// Network description: nodes with their trusted peers and leadership, block0
+/// settings and wallets funding. Topologies can be described in YAML files
+/// and loaded with [`Config::from_file`], without writing any code.
 #[derive(Debug, Deserialize, Clone)]
 pub struct Config {
+    #[serde(default)]
     pub blockchain: BlockchainConfigurationOrHash,
     pub nodes: Vec<NodeConfig>,
     pub explorer: Option<ExplorerTemplate>,
     #[serde(default)]
     pub session: SessionSettings,
+    #[serde(default)]
     pub wallets: Vec<WalletTemplate>,
+    #[serde(default)]
     pub committees: Vec<CommitteeTemplate>,
+    #[serde(default)]
     pub vote_plans: Vec<VotePlanTemplate>,
 }
 
 impl Config {
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        Ok(serde_yaml::from_reader(File::open(path)?)?)
+    }
+
+    /// Order in which nodes should be spawned, so that each node starts after
+    /// all of its trusted peers
+    pub fn spawn_order(&self) -> Result<Vec<NodeAlias>, Error> {
+        let mut topology = self.build_topology();
+        let mut order = Vec::with_capacity(topology.nodes.len());
+
+        while !topology.nodes.is_empty() {
+            let alias = topology
+                .nodes
+                .values()
+                .filter(|n| n.trusted_peers.is_empty())
+                .map(|n| n.alias.clone())
+                .min()
+                .ok_or(Error::CircularTrust)?;
+
+            topology.nodes.remove(&alias);
+            topology.nodes.values_mut().for_each(|n| {
+                n.trusted_peers.remove(&alias);
+            });
+            order.push(alias);
+        }
+        Ok(order)
+    }
+
     pub fn build_topology(&self) -> Topology {
         let mut topology = Topology::default();
 
@@ -68,7 +109,7 @@ impl Config {
     }
 
     pub fn node_spawn_params(&self, alias: &str) -> Result<SpawnParams, Error> {
-        Ok(self
+        let spawn_params = self
             .nodes
             .iter()
             .find(|c| c.spawn_params.get_alias() == alias)
@@ -76,7 +117,14 @@ impl Config {
             .ok_or_else(|| Error::Internal(format!("Node '{}' has no spawn parameters", alias)))?
             .clone()
             .jormungandr(self.session.jormungandr.to_path_buf())
-            .log_level(self.session.log.clone()))
+            .log_level(self.session.log.clone());
+
+        Ok(match &self.session.node_wrapper {
+            Some(node_wrapper) if spawn_params.get_node_wrapper().is_none() => {
+                spawn_params.node_wrapper(node_wrapper.clone())
+            }
+            _ => spawn_params,
+        })
     }
 }
 
@@ -100,6 +148,12 @@ pub struct SessionSettings {
     pub log: LogLevel,
     #[serde(default = "default_title")]
     pub title: String,
+    /// Tool all nodes are run under, unless overridden in node spawn params
+    #[serde(default)]
+    pub node_wrapper: Option<NodeWrapper>,
+    /// Session file every fragment sent from interactive mode is recorded into
+    #[serde(default)]
+    pub record_fragments: Option<PathBuf>,
 }
 
 fn default_jormungandr() -> PathBuf {
@@ -151,6 +205,8 @@ impl Default for SessionSettings {
             log: default_log_level(),
             generate_documentation: false,
             title: default_title(),
+            node_wrapper: None,
+            record_fragments: None,
         }
     }
 }
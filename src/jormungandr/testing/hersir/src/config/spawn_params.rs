@@ -1,6 +1,6 @@
 use super::NodeAlias;
 use jormungandr_automation::jormungandr::{
-    FaketimeConfig, LeadershipMode, LogLevel, PersistenceMode, Version,
+    FaketimeConfig, LeadershipMode, LogLevel, NodeWrapper, PersistenceMode, Version,
 };
 use jormungandr_lib::{
     interfaces::{
@@ -35,6 +35,7 @@ pub struct SpawnParams {
     mempool: Option<Mempool>,
     network_stuck_check: Option<Duration>,
     node_key_file: Option<PathBuf>,
+    node_wrapper: Option<NodeWrapper>,
     persistence_mode: PersistenceMode,
     persistent_fragment_log: Option<PathBuf>,
     policy: Option<Policy>,
@@ -68,6 +69,7 @@ impl SpawnParams {
             mempool: None,
             network_stuck_check: None,
             node_key_file: None,
+            node_wrapper: None,
             persistence_mode: PersistenceMode::Persistent,
             persistent_fragment_log: None,
             policy: None,
@@ -223,6 +225,17 @@ impl SpawnParams {
         self
     }
 
+    /// Run the node under a tool like valgrind, heaptrack or perf. Outputs of the
+    /// tool are written in the node directory, along with its configuration.
+    pub fn node_wrapper(mut self, node_wrapper: NodeWrapper) -> Self {
+        self.node_wrapper = Some(node_wrapper);
+        self
+    }
+
+    pub fn get_node_wrapper(&self) -> Option<&NodeWrapper> {
+        self.node_wrapper.as_ref()
+    }
+
     pub fn faketime(mut self, faketime: FaketimeConfig) -> Self {
         self.faketime = Some(faketime);
         self
@@ -0,0 +1,310 @@
+use super::{Controller, Error as ControllerError};
+use crate::config::SpawnParams;
+use jormungandr_automation::jormungandr::{
+    JormungandrProcess, JormungandrRest, NodeAlias, ShutdownError,
+};
+use jormungandr_lib::interfaces::TrustedPeer;
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    thread::JoinHandle,
+    time::{Duration, Instant},
+};
+use thiserror::Error;
+
+const SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Error)]
+pub enum ChaosError {
+    #[error(transparent)]
+    Controller(#[from] ControllerError),
+    #[error(transparent)]
+    Shutdown(#[from] ShutdownError),
+    #[error("node '{0}' is not running")]
+    NodeNotRunning(NodeAlias),
+    #[error("node '{0}' is not managed by the chaos plan")]
+    UnknownNode(NodeAlias),
+    #[error("chaos plan thread panicked")]
+    Panicked,
+}
+
+/// Disruption applied to the network by a [`ChaosPlan`]
+#[derive(Debug, Clone)]
+pub enum ChaosAction {
+    /// Kill the node process without giving it a chance to shut down
+    Kill(NodeAlias),
+    /// Request a graceful shutdown and wait for the node to exit
+    Shutdown(NodeAlias),
+    /// Spawn a node with given parameters
+    Start(SpawnParams),
+    /// Shut the node down and start it again with its last parameters
+    Restart(NodeAlias),
+    /// Restart the nodes of both groups with trusted peers limited to their own group,
+    /// so that the groups never learn about each other. Nodes outside of both groups
+    /// are not restarted and may still bridge the partition.
+    Partition {
+        left: Vec<NodeAlias>,
+        right: Vec<NodeAlias>,
+    },
+    /// Restart the partitioned nodes with the parameters they had before the partition
+    Heal,
+}
+
+#[derive(Debug, Clone)]
+pub struct ChaosEvent {
+    /// Time since the start of the plan
+    pub at: Duration,
+    pub action: ChaosAction,
+}
+
+/// Declarative timeline of disruptions executed in the background against nodes spawned
+/// by the [`Controller`]. Events scheduled at the same time are executed in the order
+/// they were added.
+#[derive(Debug, Clone, Default)]
+pub struct ChaosPlan {
+    events: Vec<ChaosEvent>,
+}
+
+impl ChaosPlan {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn at(mut self, at: Duration, action: ChaosAction) -> Self {
+        self.events.push(ChaosEvent { at, action });
+        self
+    }
+
+    pub fn kill(self, at: Duration, alias: &str) -> Self {
+        self.at(at, ChaosAction::Kill(alias.to_string()))
+    }
+
+    pub fn shutdown(self, at: Duration, alias: &str) -> Self {
+        self.at(at, ChaosAction::Shutdown(alias.to_string()))
+    }
+
+    pub fn start(self, at: Duration, spawn_params: SpawnParams) -> Self {
+        self.at(at, ChaosAction::Start(spawn_params))
+    }
+
+    pub fn restart(self, at: Duration, alias: &str) -> Self {
+        self.at(at, ChaosAction::Restart(alias.to_string()))
+    }
+
+    pub fn partition(self, at: Duration, left: &[&str], right: &[&str]) -> Self {
+        self.at(
+            at,
+            ChaosAction::Partition {
+                left: left.iter().map(ToString::to_string).collect(),
+                right: right.iter().map(ToString::to_string).collect(),
+            },
+        )
+    }
+
+    pub fn heal(self, at: Duration) -> Self {
+        self.at(at, ChaosAction::Heal)
+    }
+
+    /// Events ordered by the time of execution
+    pub fn events(&self) -> Vec<ChaosEvent> {
+        let mut events = self.events.clone();
+        events.sort_by_key(|event| event.at);
+        events
+    }
+
+    /// Starts executing the plan in a background thread. The plan takes ownership of
+    /// the given nodes, which should be spawned with the given parameters, so that it
+    /// can stop and restart them.
+    pub fn run(
+        self,
+        controller: Controller,
+        nodes: Vec<(SpawnParams, JormungandrProcess)>,
+    ) -> ChaosHandle {
+        let events = self.events();
+        let nodes: HashMap<_, _> = nodes
+            .into_iter()
+            .map(|(params, process)| {
+                (
+                    params.get_alias().clone(),
+                    ChaosNode {
+                        params,
+                        process: Some(process),
+                    },
+                )
+            })
+            .collect();
+        let nodes = Arc::new(Mutex::new(nodes));
+
+        let runner = ChaosRunner {
+            controller,
+            nodes: Arc::clone(&nodes),
+            partitioned: Vec::new(),
+        };
+        let thread = std::thread::spawn(move || runner.run(events));
+
+        ChaosHandle { nodes, thread }
+    }
+}
+
+struct ChaosNode {
+    params: SpawnParams,
+    process: Option<JormungandrProcess>,
+}
+
+type ChaosNodes = Arc<Mutex<HashMap<NodeAlias, ChaosNode>>>;
+
+struct ChaosRunner {
+    controller: Controller,
+    nodes: ChaosNodes,
+    partitioned: Vec<SpawnParams>,
+}
+
+impl ChaosRunner {
+    fn run(mut self, events: Vec<ChaosEvent>) -> Result<(), ChaosError> {
+        let started = Instant::now();
+        for event in events {
+            if let Some(wait) = event.at.checked_sub(started.elapsed()) {
+                std::thread::sleep(wait);
+            }
+            println!("[chaos +{}s] {:?}", event.at.as_secs(), event.action);
+            self.apply(event.action)?;
+        }
+        Ok(())
+    }
+
+    fn apply(&mut self, action: ChaosAction) -> Result<(), ChaosError> {
+        match action {
+            ChaosAction::Kill(alias) => self.take_process(&alias)?.stop(),
+            ChaosAction::Shutdown(alias) => self.shutdown(&alias)?,
+            ChaosAction::Start(spawn_params) => self.start(spawn_params)?,
+            ChaosAction::Restart(alias) => {
+                let spawn_params = self.params(&alias)?;
+                self.shutdown(&alias)?;
+                self.start(spawn_params)?;
+            }
+            ChaosAction::Partition { left, right } => self.partition(&[left, right])?,
+            ChaosAction::Heal => {
+                for spawn_params in std::mem::take(&mut self.partitioned) {
+                    if let Ok(process) = self.take_process(spawn_params.get_alias()) {
+                        process.stop();
+                    }
+                    self.start(spawn_params)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn partition(&mut self, groups: &[Vec<NodeAlias>]) -> Result<(), ChaosError> {
+        let mut members = Vec::new();
+        for group in groups {
+            let peers = group
+                .iter()
+                .map(|alias| self.trusted_peer(alias).map(|peer| (alias.clone(), peer)))
+                .collect::<Result<Vec<_>, _>>()?;
+            members.push(peers);
+        }
+
+        for (alias, _) in members.iter().flatten() {
+            self.take_process(alias)?.stop();
+        }
+
+        for peers in members.iter() {
+            for (alias, own_address) in peers {
+                let spawn_params = self.params(alias)?;
+                self.partitioned.push(spawn_params.clone());
+                let trusted_peers = peers
+                    .iter()
+                    .filter(|(_, peer)| peer.address != own_address.address)
+                    .map(|(_, peer)| peer.clone())
+                    .collect();
+                self.start(spawn_params.trusted_peers(trusted_peers))?;
+            }
+        }
+        Ok(())
+    }
+
+    fn start(&mut self, spawn_params: SpawnParams) -> Result<(), ChaosError> {
+        let process = self.controller.spawn(spawn_params.clone())?;
+        self.nodes.lock().unwrap().insert(
+            spawn_params.get_alias().clone(),
+            ChaosNode {
+                params: spawn_params,
+                process: Some(process),
+            },
+        );
+        Ok(())
+    }
+
+    fn shutdown(&mut self, alias: &str) -> Result<(), ChaosError> {
+        let mut process = self.take_process(alias)?;
+        process.shutdown();
+        process.wait_for_shutdown(SHUTDOWN_TIMEOUT)?;
+        Ok(())
+    }
+
+    fn params(&self, alias: &str) -> Result<SpawnParams, ChaosError> {
+        self.nodes
+            .lock()
+            .unwrap()
+            .get(alias)
+            .map(|node| node.params.clone())
+            .ok_or_else(|| ChaosError::UnknownNode(alias.to_string()))
+    }
+
+    fn trusted_peer(&self, alias: &str) -> Result<TrustedPeer, ChaosError> {
+        self.nodes
+            .lock()
+            .unwrap()
+            .get(alias)
+            .and_then(|node| node.process.as_ref())
+            .map(|process| process.to_trusted_peer())
+            .ok_or_else(|| ChaosError::NodeNotRunning(alias.to_string()))
+    }
+
+    fn take_process(&mut self, alias: &str) -> Result<JormungandrProcess, ChaosError> {
+        self.nodes
+            .lock()
+            .unwrap()
+            .get_mut(alias)
+            .and_then(|node| node.process.take())
+            .ok_or_else(|| ChaosError::NodeNotRunning(alias.to_string()))
+    }
+}
+
+/// Handle to a running [`ChaosPlan`]
+pub struct ChaosHandle {
+    nodes: ChaosNodes,
+    thread: JoinHandle<Result<(), ChaosError>>,
+}
+
+impl ChaosHandle {
+    /// REST client of the node, if the node is currently running
+    pub fn rest(&self, alias: &str) -> Option<JormungandrRest> {
+        self.nodes
+            .lock()
+            .unwrap()
+            .get(alias)
+            .and_then(|node| node.process.as_ref())
+            .map(|process| process.rest())
+    }
+
+    pub fn is_running(&self, alias: &str) -> bool {
+        self.rest(alias).is_some()
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.thread.is_finished()
+    }
+
+    /// Waits until all the events of the plan are executed and returns the nodes
+    /// which are running at the end of the plan
+    pub fn join(self) -> Result<HashMap<NodeAlias, JormungandrProcess>, ChaosError> {
+        self.thread.join().map_err(|_| ChaosError::Panicked)??;
+        let mut nodes = self.nodes.lock().unwrap();
+        Ok(nodes
+            .drain()
+            .filter_map(|(alias, node)| node.process.map(|process| (alias, process)))
+            .collect())
+    }
+}
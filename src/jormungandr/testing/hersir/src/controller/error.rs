@@ -53,6 +53,9 @@ pub enum Error {
     #[error(transparent)]
     FragmentSender(#[from] FragmentSenderError),
 
+    #[error(transparent)]
+    FragmentRecorder(#[from] thor::FragmentRecorderError),
+
     #[error(transparent)]
     Serialization(#[from] serde_yaml::Error),
     #[error(transparent)]
@@ -0,0 +1,262 @@
+use super::{Controller, Error as ControllerError};
+use jormungandr_automation::jormungandr::{JormungandrProcess, RemoteJormungandr};
+use jormungandr_lib::interfaces::{Address, Value};
+use std::{
+    io::{BufRead, BufReader, Write},
+    net::{SocketAddr, TcpListener, TcpStream},
+    str::FromStr,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    thread::JoinHandle,
+    time::Duration,
+};
+use thiserror::Error;
+use thor::{DummySyncNode, FragmentSender, Wallet};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+/// How long a client may take to send its request, so that a stalled
+/// client does not hold up the faucet
+const READ_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Error)]
+pub enum FaucetError {
+    #[error(transparent)]
+    Controller(#[from] ControllerError),
+    #[error("cannot bind the faucet endpoint")]
+    Bind(#[source] std::io::Error),
+    #[error("faucet thread panicked")]
+    Panicked,
+}
+
+/// Funded wallet served behind a local HTTP endpoint, so that tools used
+/// during manual testing can get funds without a change in the block0.
+///
+/// Each `POST /fund/<address>` request sends a fixed amount to the bech32
+/// address and answers with the id of the transaction, once the node has
+/// accepted it. Requests are served one at a time, in order to keep track
+/// of the spending counter of the wallet.
+pub struct Faucet {
+    address: SocketAddr,
+    stop: Arc<AtomicBool>,
+    thread: JoinHandle<Wallet>,
+}
+
+impl Faucet {
+    /// Starts serving funds from `wallet` on `address`, sending the
+    /// transactions to the given node. Use port 0 to pick any free port.
+    pub fn start(
+        controller: &Controller,
+        wallet: Wallet,
+        node: &JormungandrProcess,
+        amount: Value,
+        address: SocketAddr,
+    ) -> Result<Self, FaucetError> {
+        let listener = TcpListener::bind(address).map_err(FaucetError::Bind)?;
+        // non blocking, so that the thread can notice when it is stopped
+        listener.set_nonblocking(true).map_err(FaucetError::Bind)?;
+        let address = listener.local_addr().map_err(FaucetError::Bind)?;
+
+        let stop = Arc::new(AtomicBool::new(false));
+        let server = FaucetServer {
+            fragment_sender: FragmentSender::from(&controller.settings().block0),
+            node: node.to_remote(),
+            wallet,
+            amount,
+        };
+        let thread = {
+            let stop = Arc::clone(&stop);
+            std::thread::spawn(move || server.run(listener, stop))
+        };
+
+        println!("faucet listening on http://{}/fund/<address>", address);
+
+        Ok(Faucet {
+            address,
+            stop,
+            thread,
+        })
+    }
+
+    /// Address the faucet endpoint is bound to
+    pub fn address(&self) -> SocketAddr {
+        self.address
+    }
+
+    /// Stops serving funds and returns the wallet, with its state updated
+    /// with the transactions sent by the faucet
+    pub fn stop(self) -> Result<Wallet, FaucetError> {
+        self.stop.store(true, Ordering::Relaxed);
+        self.thread.join().map_err(|_| FaucetError::Panicked)
+    }
+}
+
+impl Controller {
+    /// Starts a [`Faucet`] serving funds from the controlled wallet with the given alias
+    pub fn faucet(
+        &self,
+        wallet: &str,
+        node: &JormungandrProcess,
+        amount: Value,
+        address: SocketAddr,
+    ) -> Result<Faucet, FaucetError> {
+        let wallet = self
+            .controlled_wallet(wallet)
+            .ok_or_else(|| ControllerError::WalletNotFound(wallet.to_string()))?;
+        Faucet::start(self, wallet, node, amount, address)
+    }
+}
+
+struct FaucetServer {
+    fragment_sender: FragmentSender<'static, DummySyncNode>,
+    node: RemoteJormungandr,
+    wallet: Wallet,
+    amount: Value,
+}
+
+/// Accepts the connections one at a time until stopped, answering the
+/// request line of each with `handle`
+fn serve_until_stopped<H>(
+    listener: TcpListener,
+    stop: &AtomicBool,
+    read_timeout: Duration,
+    mut handle: H,
+) where
+    H: FnMut(&str) -> (&'static str, String),
+{
+    while !stop.load(Ordering::Relaxed) {
+        match listener.accept() {
+            Ok((stream, _)) => {
+                if let Err(err) = serve(stream, read_timeout, &mut handle) {
+                    println!("faucet: cannot answer request: {}", err);
+                }
+            }
+            Err(err) if err.kind() == std::io::ErrorKind::WouldBlock => {
+                std::thread::sleep(POLL_INTERVAL)
+            }
+            Err(err) => println!("faucet: cannot accept connection: {}", err),
+        }
+    }
+}
+
+fn serve<H>(stream: TcpStream, read_timeout: Duration, handle: &mut H) -> std::io::Result<()>
+where
+    H: FnMut(&str) -> (&'static str, String),
+{
+    stream.set_nonblocking(false)?;
+    stream.set_read_timeout(Some(read_timeout))?;
+    let mut reader = BufReader::new(stream);
+
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    // the headers and the body are not needed
+    let mut line = String::new();
+    while reader.read_line(&mut line)? > 2 {
+        line.clear();
+    }
+
+    let (status, body) = handle(&request_line);
+    let mut stream = reader.into_inner();
+    write!(
+        stream,
+        "HTTP/1.1 {}\r\nContent-Type: text/plain\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        body.len(),
+        body
+    )?;
+    stream.flush()
+}
+
+impl FaucetServer {
+    fn run(mut self, listener: TcpListener, stop: Arc<AtomicBool>) -> Wallet {
+        serve_until_stopped(listener, &stop, READ_TIMEOUT, |request_line| {
+            self.handle(request_line)
+        });
+        self.wallet
+    }
+
+    fn handle(&mut self, request_line: &str) -> (&'static str, String) {
+        let mut parts = request_line.split_whitespace();
+        let (method, path) = match (parts.next(), parts.next()) {
+            (Some(method), Some(path)) => (method, path),
+            _ => return ("400 Bad Request", "malformed request\n".to_string()),
+        };
+        let address = match path.strip_prefix("/fund/") {
+            Some(address) if method == "POST" => address,
+            _ => {
+                return (
+                    "404 Not Found",
+                    "use POST /fund/<bech32 address>\n".to_string(),
+                )
+            }
+        };
+        let address = match Address::from_str(address) {
+            Ok(address) => address,
+            Err(err) => return ("400 Bad Request", format!("invalid address: {}\n", err)),
+        };
+
+        match self.fragment_sender.send_transaction_to_address(
+            &mut self.wallet,
+            address.clone(),
+            &self.node,
+            self.amount,
+        ) {
+            Ok(check) => {
+                println!("faucet: sent {} to {}", self.amount, address);
+                ("200 OK", format!("{}\n", check.fragment_id()))
+            }
+            Err(err) => (
+                "500 Internal Server Error",
+                format!("cannot send funds: {}\n", err),
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Read;
+
+    const TEST_READ_TIMEOUT: Duration = Duration::from_millis(200);
+
+    fn request(address: SocketAddr, request: &str) -> String {
+        let mut stream = TcpStream::connect(address).unwrap();
+        stream.write_all(request.as_bytes()).unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        response
+    }
+
+    #[test]
+    fn serves_requests_until_stopped() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        listener.set_nonblocking(true).unwrap();
+        let address = listener.local_addr().unwrap();
+        let stop = Arc::new(AtomicBool::new(false));
+        let thread = {
+            let stop = Arc::clone(&stop);
+            std::thread::spawn(move || {
+                let mut requests = Vec::new();
+                serve_until_stopped(listener, &stop, TEST_READ_TIMEOUT, |request_line| {
+                    requests.push(request_line.trim_end().to_string());
+                    ("200 OK", "funded\n".to_string())
+                });
+                requests
+            })
+        };
+
+        // a client that never sends its request times out
+        let _stalled = TcpStream::connect(address).unwrap();
+        let response = request(address, "POST /fund/addr HTTP/1.1\r\nHost: faucet\r\n\r\n");
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(response.contains("Content-Length: 7\r\n"));
+        assert!(response.ends_with("\r\n\r\nfunded\n"));
+
+        stop.store(true, Ordering::Relaxed);
+        let requests = thread.join().unwrap();
+        assert_eq!(requests, vec!["POST /fund/addr HTTP/1.1".to_string()]);
+        assert!(TcpStream::connect(address).is_err());
+    }
+}
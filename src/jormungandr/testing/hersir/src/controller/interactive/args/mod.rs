@@ -1,5 +1,6 @@
 pub mod describe;
 pub mod explorer;
+pub mod replay;
 pub mod send;
 pub mod show;
 pub mod spawn;
@@ -0,0 +1,52 @@
+use super::UserInteractionController;
+use crate::{controller::Error, style};
+use std::path::PathBuf;
+use structopt::StructOpt;
+
+#[derive(StructOpt, Debug)]
+pub struct Replay {
+    /// Session file recorded with `--record-fragments`
+    #[structopt(name = "SESSION_FILE")]
+    pub session_file: PathBuf,
+    /// Send all fragments to the given node instead of the recorded ones
+    #[structopt(short = "v", long = "via")]
+    pub via: Option<String>,
+    /// Keep the delays between the recorded sends
+    #[structopt(long = "keep-timing")]
+    pub keep_timing: bool,
+}
+
+impl Replay {
+    pub fn exec(&self, controller: &mut UserInteractionController) -> Result<(), Error> {
+        let results = controller.replay_fragments(
+            &self.session_file,
+            self.via.as_deref(),
+            self.keep_timing,
+        )?;
+        let total = results.len();
+        let mut rejected = 0;
+        for (id, result) in results {
+            match result {
+                Ok(_) => println!(
+                    "{}",
+                    style::info.apply_to(format!("fragment '{}' successfully sent", id))
+                ),
+                Err(err) => {
+                    rejected += 1;
+                    println!(
+                        "{}",
+                        style::error.apply_to(format!("fragment '{}' rejected: {}", id, err))
+                    )
+                }
+            }
+        }
+        println!(
+            "{}",
+            style::info.apply_to(format!(
+                "replayed {} fragments, {} rejected",
+                total, rejected
+            ))
+        );
+        Ok(())
+    }
+}
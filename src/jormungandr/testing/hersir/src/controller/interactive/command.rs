@@ -1,4 +1,4 @@
-use super::args::{describe, explorer, send, show, spawn};
+use super::args::{describe, explorer, replay, send, show, spawn};
 use structopt::{clap::AppSettings, StructOpt};
 
 #[derive(StructOpt, Debug)]
@@ -16,4 +16,6 @@ pub enum InteractiveCommand {
     Describe(describe::Describe),
     /// send fragments
     Send(send::Send),
+    /// re-send fragments recorded in a session file
+    Replay(replay::Replay),
 }
@@ -2,17 +2,24 @@ use crate::{
     config::SpawnParams,
     controller::{Controller, Error},
 };
-use chain_impl_mockchain::vote::Choice;
-use jormungandr_automation::jormungandr::JormungandrProcess;
+use chain_impl_mockchain::{fragment::FragmentId, vote::Choice};
+use jormungandr_automation::jormungandr::{
+    FragmentNode, FragmentNodeError, JormungandrProcess, MemPoolCheck,
+};
 use jormungandr_lib::interfaces::Value;
 use jortestkit::prelude::InteractiveCommandError;
-use thor::{FragmentSender, Wallet};
+use std::path::{Path, PathBuf};
+use thor::{
+    DummySyncNode, FragmentRecorder, FragmentSender, FragmentSenderSetup,
+    FragmentSenderSetupBuilder, Wallet,
+};
 
 pub struct UserInteractionController {
     controller: Controller,
     wallets: Vec<Wallet>,
     nodes: Vec<JormungandrProcess>,
     legacy_nodes: Vec<JormungandrProcess>,
+    record_fragments: Option<PathBuf>,
 }
 
 impl UserInteractionController {
@@ -28,9 +35,16 @@ impl UserInteractionController {
             wallets,
             nodes: Vec::new(),
             legacy_nodes: Vec::new(),
+            record_fragments: None,
         }
     }
 
+    /// Record all fragments sent by the controller into the given session file
+    pub fn record_fragments_into(mut self, session_file: Option<PathBuf>) -> Self {
+        self.record_fragments = session_file;
+        self
+    }
+
     pub fn wallets(&self) -> &[Wallet] {
         &self.wallets
     }
@@ -91,7 +105,7 @@ impl UserInteractionController {
         let node = self.nodes.iter().find(|x| x.alias() == node_alias);
         let legacy_node = self.legacy_nodes.iter().find(|x| x.alias() == node_alias);
 
-        let fragment_sender = FragmentSender::from(&self.controller.settings().block0);
+        let fragment_sender = self.fragment_sender();
 
         let check = match (node, legacy_node) {
             (Some(node), None) => {
@@ -137,7 +151,7 @@ impl UserInteractionController {
         let node = self.nodes.iter().find(|x| x.alias() == node_alias);
         let legacy_node = self.legacy_nodes.iter().find(|x| x.alias() == node_alias);
 
-        let fragment_sender = FragmentSender::from(&self.controller.settings().block0);
+        let fragment_sender = self.fragment_sender();
         let check = match (node, legacy_node) {
             (Some(node), None) => fragment_sender.send_vote_cast(
                 wallet,
@@ -200,7 +214,7 @@ impl UserInteractionController {
         let node = self.nodes.iter().find(|x| x.alias() == node_alias);
         let legacy_node = self.legacy_nodes.iter().find(|x| x.alias() == node_alias);
 
-        let fragment_sender = FragmentSender::from(&self.controller.settings().block0);
+        let fragment_sender = self.fragment_sender();
 
         let check = match (node, legacy_node) {
             (Some(node), None) => fragment_sender.send_transaction(from, &to, node, value)?,
@@ -215,9 +229,48 @@ impl UserInteractionController {
         Ok(check)
     }
 
+    /// Re-sends the fragments of a recorded session, in the recorded order.
+    /// Each fragment goes to the node with the alias it was originally sent to,
+    /// unless `via` is given. With `keep_timing`, the delays between the
+    /// recorded sends are preserved.
+    pub fn replay_fragments(
+        &self,
+        session_file: &Path,
+        via: Option<&str>,
+        keep_timing: bool,
+    ) -> Result<Vec<(FragmentId, Result<MemPoolCheck, FragmentNodeError>)>, Error> {
+        let entries = FragmentRecorder::read(session_file)?;
+
+        let targets = entries
+            .iter()
+            .map(|entry| {
+                let alias = via.unwrap_or(&entry.node);
+                self.nodes
+                    .iter()
+                    .chain(self.legacy_nodes.iter())
+                    .find(|node| node.alias() == alias)
+                    .map(|node| node as &dyn FragmentNode)
+                    .ok_or_else(|| Error::NodeNotFound(alias.to_string()))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(FragmentRecorder::replay(
+            entries.iter().zip(targets),
+            keep_timing,
+        ))
+    }
+
     pub fn spawn_node(&mut self, input_params: SpawnParams) -> Result<JormungandrProcess, Error> {
         self.controller.spawn(input_params).map_err(Into::into)
     }
+
+    fn fragment_sender(&self) -> FragmentSender<'static, DummySyncNode> {
+        let mut builder = FragmentSenderSetupBuilder::from(FragmentSenderSetup::default());
+        if let Some(session_file) = &self.record_fragments {
+            builder.record_fragments_into(session_file.clone());
+        }
+        FragmentSender::from_with_setup(&self.controller.settings().block0, builder.into())
+    }
 }
 
 pub fn do_for_all_alias<F: Fn(&JormungandrProcess), G: Fn(&JormungandrProcess)>(
@@ -31,6 +31,7 @@ impl InteractiveCommandExec for JormungandrInteractiveCommandExec {
                             describe.exec(&mut self.controller)
                         }
                         InteractiveCommand::Send(send) => send.exec(&mut self.controller),
+                        InteractiveCommand::Replay(replay) => replay.exec(&mut self.controller),
                         InteractiveCommand::Explorer(explorer) => {
                             explorer.exec(&mut self.controller)
                         }
@@ -1,4 +1,6 @@
+mod chaos;
 mod error;
+mod faucet;
 pub mod interactive;
 mod monitor;
 
@@ -13,7 +15,9 @@ use chain_impl_mockchain::{
     ledger::governance::{ParametersGovernanceAction, TreasuryGovernanceAction},
     testing::scenario::template::{ProposalDefBuilder, VotePlanDef, VotePlanDefBuilder},
 };
+pub use chaos::{ChaosAction, ChaosError, ChaosEvent, ChaosHandle, ChaosPlan};
 pub use error::Error;
+pub use faucet::{Faucet, FaucetError};
 pub use interactive::{
     do_for_all_alias, InteractiveCommandError, JormungandrInteractiveCommandExec,
     UserInteractionController,
@@ -188,6 +192,9 @@ impl Controller {
                     TreasuryGovernanceAction::TransferToRewards { value } => {
                         proposal_builder.action_rewards_add(value.0);
                     }
+                    TreasuryGovernanceAction::TransferToAccount { value, destination } => {
+                        proposal_builder.action_transfer_to_account(value.0, destination.clone());
+                    }
                     TreasuryGovernanceAction::NoOp => {
                         unimplemented!();
                     }
@@ -312,6 +319,7 @@ impl Controller {
             .config(params)
             .alias(spawn_params.get_alias().to_string())
             .jormungandr_app_option(spawn_params.get_jormungandr())
+            .wrapper_option(spawn_params.get_node_wrapper().cloned())
             .verbose(spawn_params.get_verbose())
             .alias(spawn_params.get_alias().clone()))
     }
@@ -243,10 +243,7 @@ impl SyncNode for LegacyNode {
     }
 
     fn get_lines_with_error_and_invalid(&self) -> Vec<String> {
-        self.logger()
-            .get_log_lines_with_level(LogLevel::ERROR)
-            .map(|x| x.to_string())
-            .collect()
+        self.logger().query().level(LogLevel::ERROR).lines()
     }
 
     fn is_running(&self) -> bool {
@@ -14,7 +14,7 @@ pub use jormungandr_automation::jormungandr::{
 use jormungandr_automation::{
     jormungandr::{
         explorer::configuration::ExplorerParams, ExplorerProcess, JormungandrProcess, LogLevel,
-        NodeAlias, ShutdownError, StartupError, StartupVerificationMode, Status,
+        NodeAlias, RetryPolicy, ShutdownError, StartupError, StartupVerificationMode, Status,
     },
     testing::SyncNode,
 };
@@ -33,6 +33,10 @@ use std::{
     time::Duration,
 };
 
+/// Time given to the node to finish processing blocks in flight when shutting down.
+/// Kept well under the REST client timeout, as the request returns only after draining.
+const GRACEFUL_SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(10);
+
 #[derive(custom_debug::Debug, thiserror::Error)]
 pub enum Error {
     #[error(transparent)]
@@ -188,6 +192,10 @@ impl Node {
         self.process.rest()
     }
 
+    pub fn rest_with_retry(&self, retry: RetryPolicy) -> JormungandrRest {
+        self.process.rest_with_retry(retry)
+    }
+
     pub fn grpc(&self) -> JormungandrClient {
         self.process.grpc()
     }
@@ -232,7 +240,7 @@ impl Node {
 
     pub fn shutdown(&mut self) -> Result<Option<ExitStatus>, Error> {
         self.progress_bar.log_info("shutting down...");
-        let message = self.rest().shutdown()?;
+        let message = self.rest().shutdown_graceful(GRACEFUL_SHUTDOWN_TIMEOUT)?;
         if message.is_empty() {
             let exit_status = self.wait_for_shutdown();
             self.finish_monitoring();
@@ -452,9 +460,6 @@ impl SyncNode for Node {
     }
 
     fn get_lines_with_error_and_invalid(&self) -> Vec<String> {
-        self.logger()
-            .get_log_lines_with_level(LogLevel::ERROR)
-            .map(|x| x.to_string())
-            .collect()
+        self.logger().query().level(LogLevel::ERROR).lines()
     }
 }
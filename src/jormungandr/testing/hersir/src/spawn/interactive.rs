@@ -7,6 +7,7 @@ use crate::{
 use jortestkit::prelude::UserInteraction;
 
 pub fn spawn_network(mut config: Config) -> Result<(), Error> {
+    let record_fragments = config.session.record_fragments.clone();
     let controller = NetworkBuilder::default()
         .topology(config.build_topology())
         .blockchain_config_or_hash(config.build_blockchain())
@@ -16,7 +17,8 @@ pub fn spawn_network(mut config: Config) -> Result<(), Error> {
     let user_integration = jormungandr_user_interaction();
 
     let mut interactive_commands = JormungandrInteractiveCommandExec {
-        controller: UserInteractionController::new(controller),
+        controller: UserInteractionController::new(controller)
+            .record_fragments_into(record_fragments),
     };
 
     user_integration
@@ -35,6 +37,7 @@ fn jormungandr_user_interaction() -> UserInteraction {
             "You can control each aspect of test:".to_string(),
             "- spawn nodes,".to_string(),
             "- send fragments,".to_string(),
+            "- replay recorded fragments,".to_string(),
             "- filter logs,".to_string(),
             "- show node stats and data.".to_string(),
         ],
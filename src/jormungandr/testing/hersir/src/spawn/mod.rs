@@ -8,10 +8,17 @@ use crate::{
     error::Error,
 };
 pub use monitor::run_health_check;
-use std::fs::File;
 
 pub fn spawn_network(args: Args) -> Result<(), Error> {
-    let config: Config = serde_yaml::from_reader(File::open(&args.config)?)?;
+    let mut config = Config::from_file(&args.config)?;
+
+    if let Some(node_wrapper) = &args.node_wrapper {
+        config.session.node_wrapper = Some(node_wrapper.clone());
+    }
+
+    if let Some(record_fragments) = &args.record_fragments {
+        config.session.record_fragments = Some(record_fragments.clone());
+    }
 
     match &config.session.mode {
         SessionMode::Standard => standard::spawn_network(config, args),
@@ -9,32 +9,19 @@ use slave_pool::ThreadPool;
 use std::sync::mpsc::channel;
 
 pub fn spawn_network(mut config: Config, args: Args) -> Result<(), Error> {
-    let mut topology = config.build_topology();
     let (tx, rx) = channel();
 
     let mut monitor_controller = MonitorControllerBuilder::new(&config.session.title)
-        .topology(topology.clone())
+        .topology(config.build_topology())
         .blockchain(config.build_blockchain())
         .build(config.session.clone())?;
 
     let mut processes = Vec::new();
 
-    while !topology.nodes.is_empty() {
-        let alias = topology
-            .nodes
-            .values()
-            .find(|n| n.trusted_peers.is_empty())
-            .map(|n| n.alias.clone())
-            .ok_or(Error::CircularTrust)?;
-
+    for alias in config.spawn_order()? {
         let spawn_params = config.node_spawn_params(&alias)?;
 
         processes.push(monitor_controller.spawn_node_custom(spawn_params.verbose(args.verbose))?);
-
-        topology.nodes.remove(&alias);
-        topology.nodes.values_mut().for_each(|n| {
-            n.trusted_peers.remove(&alias);
-        });
     }
 
     println!("Waiting for Ctrl-C to exit..");
@@ -3,8 +3,6 @@ use jormungandr_automation::jormungandr::{JormungandrProcess, NodeAlias};
 use std::{collections::HashMap, time::Duration};
 
 pub fn spawn_network(config: Config, args: Args) -> Result<(), Error> {
-    let mut topology = config.build_topology();
-
     println!("Building network...");
     let mut controller = NetworkBuilder::default()
         .apply_config(config.clone())
@@ -12,14 +10,7 @@ pub fn spawn_network(config: Config, args: Args) -> Result<(), Error> {
 
     let mut processes: HashMap<NodeAlias, JormungandrProcess> = HashMap::new();
 
-    while !topology.nodes.is_empty() {
-        let alias = topology
-            .nodes
-            .values()
-            .find(|n| n.trusted_peers.is_empty())
-            .map(|n| n.alias.clone())
-            .ok_or(Error::CircularTrust)?;
-
+    for alias in config.spawn_order()? {
         let spawn_params = config.node_spawn_params(&alias)?;
 
         processes.insert(
@@ -27,11 +18,6 @@ pub fn spawn_network(config: Config, args: Args) -> Result<(), Error> {
             controller.spawn(spawn_params.verbose(args.verbose))?,
         );
 
-        topology.nodes.remove(&alias);
-        topology.nodes.values_mut().for_each(|n| {
-            n.trusted_peers.remove(&alias);
-        });
-
         println!("Node '{}' started", alias);
     }
 
@@ -2,7 +2,7 @@ use crate::jcli::command::AddressCommand;
 use assert_cmd::assert::OutputAssertExt;
 use chain_addr::Discrimination;
 use jortestkit::prelude::ProcessOutput;
-use std::collections::BTreeMap;
+use std::{collections::BTreeMap, path::Path};
 
 pub struct Address {
     address_command: AddressCommand,
@@ -49,6 +49,45 @@ impl Address {
             .as_single_line()
     }
 
+    pub fn account_batch<P: AsRef<Path>>(
+        self,
+        input: P,
+        discrimination: Discrimination,
+    ) -> Vec<String> {
+        let mut address_command = self.address_command.account_batch().input(input);
+
+        if discrimination == Discrimination::Test {
+            address_command = address_command.test_discrimination();
+        }
+
+        address_command
+            .build()
+            .assert()
+            .success()
+            .get_output()
+            .as_multi_line()
+    }
+
+    pub fn account_batch_json<P: AsRef<Path>>(
+        self,
+        input: P,
+        discrimination: Discrimination,
+    ) -> Vec<BTreeMap<String, String>> {
+        let mut address_command = self.address_command.account_batch().input(input).json();
+
+        if discrimination == Discrimination::Test {
+            address_command = address_command.test_discrimination();
+        }
+
+        let content = address_command
+            .build()
+            .assert()
+            .success()
+            .get_output()
+            .as_lossy_string();
+        serde_json::from_str(&content).expect("Failed to parse batch addresses")
+    }
+
     pub fn account_expect_fail<S: Into<String>>(
         self,
         public_key: S,
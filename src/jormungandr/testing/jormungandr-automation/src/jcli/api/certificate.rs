@@ -181,6 +181,19 @@ impl Certificate {
         file::read_file(temp_file.path())
     }
 
+    pub fn show<S: Into<String>>(self, certificate: S) -> serde_json::Value {
+        println!("Running show certificate...");
+        let content = self
+            .command
+            .show(certificate)
+            .build()
+            .assert()
+            .success()
+            .get_output()
+            .as_lossy_string();
+        serde_json::from_str(&content).expect("JCLI returned malformed certificate description")
+    }
+
     pub fn sign<P: AsRef<Path>, Q: AsRef<Path>, R: AsRef<Path>>(
         self,
         signing_key: P,
@@ -207,4 +220,20 @@ impl Certificate {
             .get_output()
             .as_single_line()
     }
+
+    pub fn new_stake_pool_update<S: Into<String>, P: AsRef<Path>, Q: AsRef<Path>>(
+        self,
+        pool_id: S,
+        current_registration: P,
+        new_registration: Q,
+    ) -> String {
+        println!("Running create stake pool update certification...");
+        self.command
+            .update(pool_id, current_registration, new_registration)
+            .build()
+            .assert()
+            .success()
+            .get_output()
+            .as_single_line()
+    }
 }
@@ -162,4 +162,59 @@ impl Key {
             .failure()
             .stderr(predicates::str::contains(expected_msg_path));
     }
+
+    pub fn sign<S: Into<String>, P: AsRef<Path>>(self, secret_key: S, message: P) -> String {
+        let secret_key_file = NamedTempFile::new("key_sign.secret_key").unwrap();
+        secret_key_file.write_str(&secret_key.into()).unwrap();
+        let signature_file = NamedTempFile::new("key_sign.signature").unwrap();
+
+        self.key_command
+            .sign()
+            .secret_key(secret_key_file.path())
+            .message(message)
+            .output(signature_file.path())
+            .build()
+            .assert()
+            .success();
+        std::fs::read_to_string(signature_file.path()).unwrap()
+    }
+
+    pub fn verify<S: Into<String>, P: AsRef<Path>>(self, public_key: S, signature: S, message: P) {
+        let public_key_file = NamedTempFile::new("key_verify.public_key").unwrap();
+        public_key_file.write_str(&public_key.into()).unwrap();
+        let signature_file = NamedTempFile::new("key_verify.signature").unwrap();
+        signature_file.write_str(&signature.into()).unwrap();
+
+        self.key_command
+            .verify()
+            .public_key(public_key_file.path())
+            .signature(signature_file.path())
+            .message(message)
+            .build()
+            .assert()
+            .success();
+    }
+
+    pub fn verify_expect_fail<S: Into<String>, P: AsRef<Path>>(
+        self,
+        public_key: S,
+        signature: S,
+        message: P,
+        expected_msg_path: &str,
+    ) {
+        let public_key_file = NamedTempFile::new("key_verify.public_key").unwrap();
+        public_key_file.write_str(&public_key.into()).unwrap();
+        let signature_file = NamedTempFile::new("key_verify.signature").unwrap();
+        signature_file.write_str(&signature.into()).unwrap();
+
+        self.key_command
+            .verify()
+            .public_key(public_key_file.path())
+            .signature(signature_file.path())
+            .message(message)
+            .build()
+            .assert()
+            .failure()
+            .stderr(predicates::str::contains(expected_msg_path));
+    }
 }
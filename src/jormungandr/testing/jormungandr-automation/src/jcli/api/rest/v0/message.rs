@@ -1,7 +1,7 @@
 use crate::jcli::command::rest::v0::MessageCommand;
 use assert_cmd::assert::OutputAssertExt;
 use assert_fs::{fixture::FileWriteStr, NamedTempFile};
-use jormungandr_lib::interfaces::{FragmentLog, FragmentsProcessingSummary};
+use jormungandr_lib::interfaces::{FragmentLog, FragmentLogsQuery, FragmentsProcessingSummary};
 use jortestkit::prelude::ProcessOutput;
 
 pub struct Message {
@@ -41,4 +41,21 @@ impl Message {
 
         serde_yaml::from_str(&content).expect("Failed to parse fragment log")
     }
+
+    pub fn logs_with_query<S: Into<String>>(
+        self,
+        host: S,
+        query: &FragmentLogsQuery,
+    ) -> Vec<FragmentLog> {
+        let content = self
+            .message_command
+            .logs_with_query(host.into(), query)
+            .build()
+            .assert()
+            .success()
+            .get_output()
+            .as_lossy_string();
+
+        serde_yaml::from_str(&content).expect("Failed to parse fragment log")
+    }
 }
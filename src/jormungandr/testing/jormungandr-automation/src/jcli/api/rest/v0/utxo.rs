@@ -2,7 +2,7 @@ use crate::jcli::command::rest::v0::UtxOCommand;
 use assert_cmd::assert::OutputAssertExt;
 use jormungandr_lib::{
     crypto::hash::Hash,
-    interfaces::{UTxOInfo, UTxOOutputInfo},
+    interfaces::{UTxOInfo, UTxOOutputInfo, UTxOsQuery},
 };
 use jortestkit::prelude::ProcessOutput;
 use std::str::FromStr;
@@ -50,6 +50,20 @@ impl UtxO {
             .into_utxo_info(Hash::from_str(&fragment_id.into()).unwrap(), output_index)
     }
 
+    pub fn list_with_query<S: Into<String>>(self, host: S, query: &UTxOsQuery) -> Vec<UTxOInfo> {
+        let content = self
+            .utxo_command
+            .list_with_query(query)
+            .host(host.into())
+            .build()
+            .assert()
+            .success()
+            .get_output()
+            .as_lossy_string();
+
+        serde_yaml::from_str(&content).expect("JCLI returned malformed UTxO list")
+    }
+
     pub fn expect_item_not_found<S: Into<String>>(self, utxo: &UTxOInfo, host: S) {
         self.expect_not_found(
             &utxo.transaction_id().to_string(),
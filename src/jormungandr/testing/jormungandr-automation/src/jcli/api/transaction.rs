@@ -11,7 +11,7 @@ use jormungandr_lib::{
     interfaces::{BlockDate, LegacyUTxO, UTxOInfo, Value},
 };
 use jortestkit::process::output_extensions::ProcessOutput as _;
-use std::path::Path;
+use std::{fs::File, path::Path, process::Stdio};
 
 pub struct Transaction {
     command: TransactionCommand,
@@ -151,6 +151,34 @@ impl Transaction {
             .success();
     }
 
+    pub fn finalize_with_change<P: AsRef<Path>>(
+        self,
+        change: &str,
+        linear_fee: &LinearFee,
+        staging_file: P,
+    ) {
+        self.command
+            .finalize_with_change(change, linear_fee, staging_file)
+            .build()
+            .assert()
+            .success();
+    }
+
+    pub fn finalize_with_change_expect_fail<P: AsRef<Path>>(
+        self,
+        change: &str,
+        linear_fee: &LinearFee,
+        staging_file: P,
+        expected_part: &str,
+    ) {
+        self.command
+            .finalize_with_change(change, linear_fee, staging_file)
+            .build()
+            .assert()
+            .failure()
+            .stderr(predicates::str::contains(expected_part));
+    }
+
     pub fn finalize_expect_fail<P: AsRef<Path>>(self, staging_file: P, expected_part: &str) {
         self.command
             .finalize(staging_file)
@@ -183,6 +211,34 @@ impl Transaction {
             .success();
     }
 
+    pub fn witness_request<P: AsRef<Path>, Q: AsRef<Path>>(
+        self,
+        witness: &Witness,
+        request_file: P,
+        staging_file: Q,
+    ) {
+        self.command
+            .witness_request(
+                &witness.block_hash.to_hex(),
+                witness.addr_type,
+                witness.account_spending_counter,
+                request_file,
+                staging_file,
+            )
+            .build()
+            .assert()
+            .success();
+    }
+
+    /// Makes the witness the way it is done on an offline machine, from the
+    /// request alone, with the signing key passed on the standard input.
+    pub fn make_witness_from_request<P: AsRef<Path>>(self, witness: &Witness, request_file: P) {
+        let mut command = self.command.make_witness_from_request(request_file).build();
+        command.stdin(Stdio::from(File::open(&witness.private_key_path).unwrap()));
+        let output = command.assert().success().get_output().stdout.clone();
+        std::fs::write(&witness.file, output).unwrap();
+    }
+
     pub fn make_witness_expect_fail(self, witness: &Witness, expected_msg: &str) {
         self.command
             .make_witness(
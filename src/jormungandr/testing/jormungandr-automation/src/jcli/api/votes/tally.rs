@@ -60,6 +60,22 @@ impl Tally {
             .stderr(predicates::str::contains(expected_msg));
     }
 
+    pub fn aggregate<P: AsRef<Path>, R: AsRef<Path>, S: Into<String>>(
+        self,
+        vote_plan: P,
+        vote_plan_id: S,
+        member_shares: Vec<R>,
+        threshold: u32,
+    ) -> String {
+        self.tally_command
+            .aggregate(vote_plan, vote_plan_id, member_shares, threshold)
+            .build()
+            .assert()
+            .success()
+            .get_output()
+            .as_lossy_string()
+    }
+
     pub fn merge_shares<P: AsRef<Path>>(self, shares_to_merge: Vec<P>) -> String {
         self.tally_command
             .merge_shares(shares_to_merge)
@@ -0,0 +1,34 @@
+use std::{path::Path, process::Command};
+pub struct AccountBatchCommand {
+    command: Command,
+}
+
+impl AccountBatchCommand {
+    pub fn new(command: Command) -> Self {
+        Self { command }
+    }
+
+    pub fn input<P: AsRef<Path>>(mut self, input: P) -> Self {
+        self.command.arg("--input").arg(input.as_ref());
+        self
+    }
+
+    pub fn test_discrimination(mut self) -> Self {
+        self.command.arg("--testing");
+        self
+    }
+
+    pub fn prefix<S: Into<String>>(mut self, prefix: S) -> Self {
+        self.command.arg("--prefix").arg(prefix.into());
+        self
+    }
+
+    pub fn json(mut self) -> Self {
+        self.command.arg("--json");
+        self
+    }
+
+    pub fn build(self) -> Command {
+        self.command
+    }
+}
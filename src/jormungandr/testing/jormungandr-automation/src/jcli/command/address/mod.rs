@@ -1,8 +1,10 @@
 mod account;
+mod account_batch;
 mod info;
 mod single;
 
 pub use account::AccountCommand;
+pub use account_batch::AccountBatchCommand;
 pub use info::InfoCommand;
 pub use single::SingleCommand;
 use std::process::Command;
@@ -20,6 +22,11 @@ impl AddressCommand {
         AccountCommand::new(self.command)
     }
 
+    pub fn account_batch(mut self) -> AccountBatchCommand {
+        self.command.arg("account-batch");
+        AccountBatchCommand::new(self.command)
+    }
+
     pub fn info(mut self) -> InfoCommand {
         self.command.arg("info");
         InfoCommand::new(self.command)
@@ -38,6 +38,24 @@ impl CertificateCommand {
         self
     }
 
+    pub fn update<S: Into<String>, P: AsRef<Path>, Q: AsRef<Path>>(
+        mut self,
+        pool_id: S,
+        current_registration: P,
+        new_registration: Q,
+    ) -> Self {
+        self.command
+            .arg("new")
+            .arg("stake-pool-update")
+            .arg("--pool-id")
+            .arg(pool_id.into())
+            .arg("--current-registration")
+            .arg(current_registration.as_ref())
+            .arg("--new-registration")
+            .arg(new_registration.as_ref());
+        self
+    }
+
     pub fn vote<P: AsRef<Path>>(mut self, proposal_file: P) -> Self {
         self.command
             .arg("new")
@@ -197,6 +215,15 @@ impl CertificateCommand {
         self
     }
 
+    pub fn show<S: Into<String>>(mut self, certificate: S) -> Self {
+        self.command
+            .arg("show")
+            .arg(certificate.into())
+            .arg("--output-format")
+            .arg("json");
+        self
+    }
+
     pub fn vote_plan_id<P: AsRef<Path>, Q: AsRef<Path>>(
         mut self,
         input_file: P,
@@ -1,13 +1,17 @@
 mod from_bytes;
 mod generate;
+mod sign;
 mod to_bytes;
 mod to_public;
+mod verify;
 
 pub use from_bytes::KeyFromBytesCommand;
 pub use generate::KeyGenerateCommand;
+pub use sign::KeySignCommand;
 use std::process::Command;
 pub use to_bytes::KeyToBytesCommand;
 pub use to_public::KeyToPublicCommand;
+pub use verify::KeyVerifyCommand;
 
 pub struct KeyCommand {
     command: Command,
@@ -38,4 +42,14 @@ impl KeyCommand {
         self.command.arg("to-public");
         KeyToPublicCommand::new(self.command)
     }
+
+    pub fn sign(mut self) -> KeySignCommand {
+        self.command.arg("sign");
+        KeySignCommand::new(self.command)
+    }
+
+    pub fn verify(mut self) -> KeyVerifyCommand {
+        self.command.arg("verify");
+        KeyVerifyCommand::new(self.command)
+    }
 }
@@ -0,0 +1,29 @@
+use std::{path::Path, process::Command};
+pub struct KeySignCommand {
+    command: Command,
+}
+
+impl KeySignCommand {
+    pub fn new(command: Command) -> Self {
+        Self { command }
+    }
+
+    pub fn secret_key<P: AsRef<Path>>(mut self, secret_key: P) -> Self {
+        self.command.arg("--secret-key").arg(secret_key.as_ref());
+        self
+    }
+
+    pub fn message<P: AsRef<Path>>(mut self, message: P) -> Self {
+        self.command.arg("--message").arg(message.as_ref());
+        self
+    }
+
+    pub fn output<P: AsRef<Path>>(mut self, output: P) -> Self {
+        self.command.arg("--output").arg(output.as_ref());
+        self
+    }
+
+    pub fn build(self) -> Command {
+        self.command
+    }
+}
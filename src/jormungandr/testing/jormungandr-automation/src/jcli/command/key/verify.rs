@@ -0,0 +1,29 @@
+use std::{path::Path, process::Command};
+pub struct KeyVerifyCommand {
+    command: Command,
+}
+
+impl KeyVerifyCommand {
+    pub fn new(command: Command) -> Self {
+        Self { command }
+    }
+
+    pub fn public_key<P: AsRef<Path>>(mut self, public_key: P) -> Self {
+        self.command.arg("--public-key").arg(public_key.as_ref());
+        self
+    }
+
+    pub fn signature<P: AsRef<Path>>(mut self, signature: P) -> Self {
+        self.command.arg("--signature").arg(signature.as_ref());
+        self
+    }
+
+    pub fn message<P: AsRef<Path>>(mut self, message: P) -> Self {
+        self.command.arg("--message").arg(message.as_ref());
+        self
+    }
+
+    pub fn build(self) -> Command {
+        self.command
+    }
+}
@@ -1,3 +1,4 @@
+use jormungandr_lib::interfaces::FragmentLogsQuery;
 use std::{path::Path, process::Command};
 
 pub struct MessageCommand {
@@ -24,6 +25,27 @@ impl MessageCommand {
         self
     }
 
+    pub fn logs_with_query<S: Into<String>>(mut self, host: S, query: &FragmentLogsQuery) -> Self {
+        self = self.logs(host);
+        if let Some(offset) = query.offset {
+            self.command.arg("--offset").arg(offset.to_string());
+        }
+        if let Some(limit) = query.limit {
+            self.command.arg("--limit").arg(limit.to_string());
+        }
+        if let Some(status) = query.status {
+            self.command
+                .arg("--status")
+                .arg(serde_json::to_value(status).unwrap().as_str().unwrap());
+        }
+        if let Some(origin) = query.origin {
+            self.command
+                .arg("--origin")
+                .arg(serde_json::to_value(origin).unwrap().as_str().unwrap());
+        }
+        self
+    }
+
     pub fn build(self) -> Command {
         self.command
     }
@@ -1,3 +1,4 @@
+use jormungandr_lib::interfaces::UTxOsQuery;
 use std::process::Command;
 
 pub struct UtxOCommand {
@@ -14,6 +15,20 @@ impl UtxOCommand {
         self
     }
 
+    pub fn list_with_query(mut self, query: &UTxOsQuery) -> Self {
+        self.command.arg("list");
+        if let Some(address) = &query.address {
+            self.command.arg("--address").arg(address.to_string());
+        }
+        if let Some(offset) = query.offset {
+            self.command.arg("--offset").arg(offset.to_string());
+        }
+        if let Some(limit) = query.limit {
+            self.command.arg("--limit").arg(limit.to_string());
+        }
+        self
+    }
+
     pub fn host<S: Into<String>>(mut self, host: S) -> Self {
         self.command.arg("--host").arg(host.into());
         self
@@ -24,6 +24,7 @@ impl VoteCommand {
             .arg("active")
             .arg("plans")
             .arg("get")
+            .arg("--with-tally")
             .arg("--host")
             .arg(host.into());
         self
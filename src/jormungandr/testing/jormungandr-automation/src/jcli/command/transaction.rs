@@ -103,6 +103,27 @@ impl TransactionCommand {
         self
     }
 
+    pub fn finalize_with_change<P: AsRef<Path>>(
+        mut self,
+        change: &str,
+        linear_fees: &LinearFee,
+        staging_file: P,
+    ) -> Self {
+        self.command
+            .arg("finalize")
+            .arg("--change")
+            .arg(change)
+            .arg("--fee-certificate")
+            .arg(linear_fees.certificate.to_string())
+            .arg("--fee-coefficient")
+            .arg(linear_fees.coefficient.to_string())
+            .arg("--fee-constant")
+            .arg(linear_fees.constant.to_string())
+            .arg("--staging")
+            .arg(staging_file.as_ref());
+        self
+    }
+
     pub fn make_witness<P: AsRef<Path>, Q: AsRef<Path>>(
         mut self,
         block0_hash: &str,
@@ -129,6 +150,42 @@ impl TransactionCommand {
         self
     }
 
+    pub fn witness_request<P: AsRef<Path>, Q: AsRef<Path>>(
+        mut self,
+        block0_hash: &str,
+        addr_type: WitnessType,
+        account_spending_counter: Option<SpendingCounter>,
+        request_file: P,
+        staging_file: Q,
+    ) -> Self {
+        self.command
+            .arg("data-for-witness")
+            .arg("--staging")
+            .arg(staging_file.as_ref())
+            .arg("--request")
+            .arg(request_file.as_ref())
+            .arg("--genesis-block-hash")
+            .arg(block0_hash)
+            .arg("--type")
+            .arg(addr_type.to_string());
+        if let Some(spending_counter) = account_spending_counter {
+            self.command
+                .arg("--account-spending-counter")
+                .arg(spending_counter.unlaned_counter().to_string())
+                .arg("--account-spending-counter-lane")
+                .arg(spending_counter.lane().to_string());
+        }
+        self
+    }
+
+    pub fn make_witness_from_request<P: AsRef<Path>>(mut self, request_file: P) -> Self {
+        self.command
+            .arg("make-witness")
+            .arg("--from-request")
+            .arg(request_file.as_ref());
+        self
+    }
+
     pub fn add_witness<P: AsRef<Path>, Q: AsRef<Path>>(
         mut self,
         witness_file: P,
@@ -180,7 +237,7 @@ impl TransactionCommand {
     pub fn info<P: AsRef<Path>>(mut self, format: &str, staging_file: P) -> Self {
         self.command
             .arg("info")
-            .arg("--format")
+            .arg("--output-format")
             .arg(format)
             .arg("--staging")
             .arg(staging_file.as_ref());
@@ -47,6 +47,30 @@ impl TallyCommand {
         self
     }
 
+    pub fn aggregate<P: AsRef<Path>, R: AsRef<Path>, S: Into<String>>(
+        mut self,
+        vote_plan: P,
+        vote_plan_id: S,
+        member_shares: Vec<R>,
+        threshold: u32,
+    ) -> Self {
+        self.command
+            .arg("aggregate")
+            .arg("--vote-plan")
+            .arg(vote_plan.as_ref())
+            .arg("--vote-plan-id")
+            .arg(vote_plan_id.into())
+            .arg("--threshold")
+            .arg(threshold.to_string())
+            .arg("--output-format")
+            .arg("json");
+
+        for share in member_shares {
+            self.command.arg(share.as_ref());
+        }
+        self
+    }
+
     pub fn merge_shares<P: AsRef<Path>>(mut self, shares: Vec<P>) -> Self {
         self.command.arg("merge-shares");
 
@@ -170,6 +170,29 @@ impl TransactionBuilder {
         self
     }
 
+    pub fn finalize_with_change(&mut self, change: &str, linear_fee: &LinearFee) -> &mut Self {
+        self.jcli.transaction().finalize_with_change(
+            change,
+            linear_fee,
+            self.staging_file().path(),
+        );
+        self
+    }
+
+    pub fn finalize_with_change_expect_fail(
+        &self,
+        change: &str,
+        linear_fee: &LinearFee,
+        expected_part: &str,
+    ) {
+        self.jcli.transaction().finalize_with_change_expect_fail(
+            change,
+            linear_fee,
+            self.staging_file().path(),
+            expected_part,
+        );
+    }
+
     pub fn finalize_expect_fail(&self, expected_part: &str) {
         self.jcli
             .transaction()
@@ -208,6 +231,21 @@ impl TransactionBuilder {
         self
     }
 
+    /// Makes the witness through a request file, as when the transaction is
+    /// signed on an offline machine.
+    pub fn make_witness_from_request(&mut self, witness: &Witness) -> &mut Self {
+        let request_file = self.staging_dir.child("witness_request.json");
+        self.jcli.transaction().witness_request(
+            witness,
+            request_file.path(),
+            self.staging_file().path(),
+        );
+        self.jcli
+            .transaction()
+            .make_witness_from_request(witness, request_file.path());
+        self
+    }
+
     pub fn make_witness_expect_fail(&mut self, witness: &Witness, expected_msg: &str) -> &mut Self {
         self.jcli
             .transaction()
@@ -67,6 +67,9 @@ impl Default for Block0ConfigurationBuilder {
                 }),
                 committees: Vec::new(),
                 tx_max_expiry_epochs: Some(100),
+                max_fragment_size: None,
+                voting_power_snapshot: None,
+                script_addresses: None,
                 #[cfg(feature = "evm")]
                 evm_configs: None,
                 #[cfg(feature = "evm")]
@@ -283,6 +286,21 @@ impl Block0ConfigurationBuilder {
         self
     }
 
+    pub fn with_max_fragment_size(mut self, max_fragment_size: u32) -> Self {
+        self.blockchain_configuration.max_fragment_size = Some(max_fragment_size);
+        self
+    }
+
+    pub fn with_voting_power_snapshot(mut self, voting_power_snapshot: bool) -> Self {
+        self.blockchain_configuration.voting_power_snapshot = Some(voting_power_snapshot);
+        self
+    }
+
+    pub fn with_script_addresses(mut self, script_addresses: bool) -> Self {
+        self.blockchain_configuration.script_addresses = Some(script_addresses);
+        self
+    }
+
     pub fn build(mut self) -> Block0Configuration {
         if self.minimal_setup {
             if self.initial.is_empty() {
@@ -1,3 +1,5 @@
 mod block0_config_builder;
+mod synthetic;
 
 pub use block0_config_builder::Block0ConfigurationBuilder;
+pub use synthetic::SyntheticBlock0Builder;
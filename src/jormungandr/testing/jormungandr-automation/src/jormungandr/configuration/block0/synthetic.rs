@@ -0,0 +1,181 @@
+use crate::testing::block0::Block0Error;
+use chain_addr::{Address, Kind};
+use chain_core::property::Serialize;
+use chain_crypto::{Ed25519, PublicKey};
+use chain_impl_mockchain::{
+    fragment::Fragment,
+    header::{BlockDate, BlockVersion, HeaderBuilderNew, HeaderId},
+    key::Hash,
+};
+use cryptoxide::{blake2b::Blake2b, digest::Digest};
+use jormungandr_lib::interfaces::{BlockchainConfiguration, Initial, InitialUTxO, Value};
+use rand::{RngCore, SeedableRng};
+use rand_chacha::ChaChaRng;
+use std::{
+    fs::File,
+    io::{BufWriter, Write},
+    iter,
+    path::Path,
+};
+
+/// Maximum number of outputs of the transactions declaring the initial funds
+const OUTPUTS_PER_FRAGMENT: usize = 255;
+
+/// Builds a block0 with a large synthetic initial state, like hundreds of
+/// thousands of utxos and accounts, to benchmark the startup time and memory
+/// usage of the node and the explorer on mainnet-scale initial states.
+///
+/// Unlike with [`Block0ConfigurationBuilder`](super::Block0ConfigurationBuilder),
+/// the initial state is never held in memory: the fragments are generated on
+/// the fly from a seed, a first time to compute the content hash of the
+/// header, then a second time to write the encoded block.
+///
+/// The addresses are made of random public keys, so the initial funds cannot
+/// be spent.
+pub struct SyntheticBlock0Builder {
+    blockchain_configuration: BlockchainConfiguration,
+    utxos: usize,
+    accounts: usize,
+    value: Value,
+    seed: [u8; 32],
+}
+
+impl SyntheticBlock0Builder {
+    pub fn new(blockchain_configuration: BlockchainConfiguration) -> Self {
+        Self {
+            blockchain_configuration,
+            utxos: 0,
+            accounts: 0,
+            value: 1.into(),
+            seed: [0; 32],
+        }
+    }
+
+    /// Number of utxos to declare, each at a different single address
+    pub fn with_utxos(mut self, utxos: usize) -> Self {
+        self.utxos = utxos;
+        self
+    }
+
+    /// Number of accounts to declare
+    pub fn with_accounts(mut self, accounts: usize) -> Self {
+        self.accounts = accounts;
+        self
+    }
+
+    /// Value of each utxo and account
+    pub fn with_value(mut self, value: Value) -> Self {
+        self.value = value;
+        self
+    }
+
+    /// Seed of the generated addresses, the same seed gives the same block0
+    pub fn with_seed(mut self, seed: [u8; 32]) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Writes the encoded block0 and returns its hash
+    pub fn write<W: Write>(&self, writer: W) -> Result<HeaderId, Block0Error> {
+        let mut hasher = Blake2b::new(32);
+        let mut content_size = 0usize;
+        for fragment in self.fragments() {
+            let bytes = fragment.serialize_as_vec()?;
+            hasher.input(&bytes);
+            content_size += bytes.len();
+        }
+        let mut content_hash = [0; 32];
+        hasher.result(&mut content_hash);
+        let content_size = u32::try_from(content_size)
+            .expect("block0 content is larger than the maximum block size");
+
+        let header = HeaderBuilderNew::new_raw(
+            BlockVersion::Genesis,
+            &Hash::from(content_hash),
+            content_size,
+        )
+        .set_genesis()
+        .set_date(BlockDate::first())
+        .into_unsigned_header()
+        .expect("internal error cannot build unsigned block")
+        .generalize();
+
+        let mut writer = BufWriter::new(writer);
+        writer.write_all(&header.serialize_as_vec()?)?;
+        for fragment in self.fragments() {
+            writer.write_all(&fragment.serialize_as_vec()?)?;
+        }
+        writer.flush()?;
+        Ok(header.id())
+    }
+
+    /// Writes the encoded block0 to a file and returns its hash
+    pub fn write_to_file<P: AsRef<Path>>(&self, path: P) -> Result<HeaderId, Block0Error> {
+        self.write(File::create(path)?)
+    }
+
+    fn fragments(&self) -> impl Iterator<Item = Fragment> + '_ {
+        let discrimination = self.blockchain_configuration.discrimination;
+        let value = self.value;
+        let mut rng = ChaChaRng::from_seed(self.seed);
+        let mut funds = iter::repeat(false)
+            .take(self.utxos)
+            .chain(iter::repeat(true).take(self.accounts))
+            .map(move |account| {
+                let mut key = [0; 32];
+                rng.fill_bytes(&mut key);
+                let key = PublicKey::<Ed25519>::from_binary(&key).unwrap();
+                let kind = if account {
+                    Kind::Account(key)
+                } else {
+                    Kind::Single(key)
+                };
+                InitialUTxO {
+                    address: Address(discrimination, kind).into(),
+                    value,
+                }
+            });
+
+        let initial = Fragment::Initial(self.blockchain_configuration.clone().into());
+        let funds = iter::from_fn(move || {
+            let chunk: Vec<_> = funds.by_ref().take(OUTPUTS_PER_FRAGMENT).collect();
+            if chunk.is_empty() {
+                None
+            } else {
+                Some(Initial::Fund(chunk))
+            }
+        })
+        .flat_map(|fund| Vec::<Fragment>::try_from(&fund).expect("funds are always valid"));
+        iter::once(initial).chain(funds)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::jormungandr::Block0ConfigurationBuilder;
+    use chain_core::{packer::Codec, property::Deserialize};
+    use chain_impl_mockchain::{block::Block, ledger::Ledger};
+
+    #[test]
+    fn synthetic_block0_is_a_valid_genesis() {
+        let blockchain_configuration = Block0ConfigurationBuilder::minimal_setup()
+            .build()
+            .blockchain_configuration;
+        let builder = SyntheticBlock0Builder::new(blockchain_configuration)
+            .with_utxos(600)
+            .with_accounts(300)
+            .with_value(10.into());
+
+        let mut bytes = Vec::new();
+        let id = builder.write(&mut bytes).unwrap();
+
+        let block = Block::deserialize(&mut Codec::new(bytes.as_slice())).unwrap();
+        assert_eq!(block.header().id(), id);
+        // the initial fragment, then 4 transactions of 255 outputs at most
+        assert_eq!(block.fragments().count(), 5);
+        let ledger = Ledger::new(id, block.fragments()).unwrap();
+        assert_eq!(ledger.utxos().count(), 600);
+        assert_eq!(ledger.accounts().iter().count(), 300);
+    }
+}
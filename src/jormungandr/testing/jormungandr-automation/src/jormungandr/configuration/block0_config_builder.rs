@@ -68,6 +68,9 @@ impl Block0ConfigurationBuilder {
                 }),
                 committees: Vec::new(),
                 tx_max_expiry_epochs: Some(100),
+                max_fragment_size: None,
+                voting_power_snapshot: None,
+                script_addresses: None,
                 #[cfg(feature = "evm")]
                 evm_configs: None,
                 #[cfg(feature = "evm")]
@@ -195,6 +198,21 @@ impl Block0ConfigurationBuilder {
         self
     }
 
+    pub fn with_max_fragment_size(&mut self, max_fragment_size: u32) -> &mut Self {
+        self.blockchain_configuration.max_fragment_size = Some(max_fragment_size);
+        self
+    }
+
+    pub fn with_voting_power_snapshot(&mut self, voting_power_snapshot: bool) -> &mut Self {
+        self.blockchain_configuration.voting_power_snapshot = Some(voting_power_snapshot);
+        self
+    }
+
+    pub fn with_script_addresses(&mut self, script_addresses: bool) -> &mut Self {
+        self.blockchain_configuration.script_addresses = Some(script_addresses);
+        self
+    }
+
     fn default_initial() -> Vec<Initial> {
         let sk1: SecretKey<Ed25519Extended> =
             SecretKey::generate(&mut ChaChaRng::from_seed([1; 32]));
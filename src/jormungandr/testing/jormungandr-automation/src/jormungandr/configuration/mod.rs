@@ -2,6 +2,7 @@ use lazy_static::lazy_static;
 use netstat2::{get_sockets_info, AddressFamilyFlags, ProtocolFlags};
 use std::{
     collections::HashSet,
+    net::{Ipv4Addr, TcpListener, UdpSocket},
     sync::atomic::{AtomicU16, Ordering},
 };
 
@@ -10,7 +11,7 @@ mod jormungandr_config;
 mod node;
 mod secret;
 
-pub use block0::Block0ConfigurationBuilder;
+pub use block0::{Block0ConfigurationBuilder, SyntheticBlock0Builder};
 pub use jormungandr_config::EitherHashOrBlock0;
 pub use node::{NodeConfigBuilder, NodeConfigManager};
 pub use secret::{NodeSecretExtension, SecretModelFactory};
@@ -28,11 +29,21 @@ lazy_static! {
     };
 }
 
+/// Returns a port which is not used by any other process.
+///
+/// Sockets opened at the time of the first call are skipped right away, then
+/// the candidate port is bound to make sure no service started listening on it
+/// in the meantime, which is common during long test runs.
 pub fn get_available_port() -> u16 {
     loop {
         let candidate_port = NEXT_AVAILABLE_PORT_NUMBER.fetch_add(1, Ordering::SeqCst);
-        if !(*OCCUPIED_PORTS).contains(&candidate_port) {
+        if !(*OCCUPIED_PORTS).contains(&candidate_port) && can_bind(candidate_port) {
             return candidate_port;
         }
     }
 }
+
+fn can_bind(port: u16) -> bool {
+    TcpListener::bind((Ipv4Addr::UNSPECIFIED, port)).is_ok()
+        && UdpSocket::bind((Ipv4Addr::UNSPECIFIED, port)).is_ok()
+}
@@ -74,6 +74,14 @@ impl Default for NodeConfigBuilder {
                     allow_private_addresses: true,
                     gossip_interval: None,
                     network_stuck_check: None,
+                    http2_keepalive_interval: None,
+                    keepalive_timeout: None,
+                    http2_max_concurrent_streams: None,
+                    max_subscriptions_per_peer: None,
+                    block_compression: None,
+                    grpc_reflection: false,
+                    subscription_overflow: None,
+                    fragment_dedup: None,
                     whitelist: None,
                 },
 
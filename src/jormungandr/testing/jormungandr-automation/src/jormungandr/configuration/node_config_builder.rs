@@ -72,6 +72,14 @@ impl NodeConfigBuilder {
                 gossip_interval: None,
                 max_bootstrap_attempts: None,
                 network_stuck_check: None,
+                http2_keepalive_interval: None,
+                keepalive_timeout: None,
+                http2_max_concurrent_streams: None,
+                max_subscriptions_per_peer: None,
+                block_compression: None,
+                grpc_reflection: false,
+                subscription_overflow: None,
+                fragment_dedup: None,
             },
             mempool: Some(Mempool::default()),
         }
@@ -0,0 +1,170 @@
+use super::{Explorer, ExplorerError};
+use crate::jormungandr::{JormungandrRest, MemPoolCheck, RestError};
+use chain_impl_mockchain::fragment::FragmentId;
+use jormungandr_lib::{
+    crypto::hash::Hash,
+    interfaces::{FragmentStatus, VotePlanId},
+};
+use jortestkit::process::Wait;
+use std::time::Duration;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum CrossCheckError {
+    #[error("explorer request failed")]
+    Explorer(#[from] ExplorerError),
+    #[error("node request failed")]
+    Rest(#[from] RestError),
+    #[error("fragment {id} is not in a block on the node, status: {status:?}")]
+    FragmentNotInBlock {
+        id: FragmentId,
+        status: Option<FragmentStatus>,
+    },
+    #[error("transaction {id} is not indexed by the explorer after {timeout:?}")]
+    TransactionNotIndexed { id: FragmentId, timeout: Duration },
+    #[error("transaction {id} is in block {node} on the node, but in blocks {explorer:?} in the explorer")]
+    BlockMismatch {
+        id: FragmentId,
+        node: Hash,
+        explorer: Vec<String>,
+    },
+    #[error("vote plan {id} is not active on the node")]
+    VotePlanNotOnNode { id: VotePlanId },
+    #[error("votes of vote plan {id} do not match after {timeout:?}, node: {node:?}, explorer: {explorer:?}")]
+    VotesMismatch {
+        id: VotePlanId,
+        node: Vec<usize>,
+        explorer: Vec<usize>,
+        timeout: Duration,
+    },
+}
+
+/// Cross-checks the state of a node with the one indexed by an explorer
+/// attached to the network, to verify the explorer and not only the node.
+///
+/// The explorer lags behind the node, so each check is retried until it
+/// passes or the wait times out.
+pub struct ExplorerCrossCheck<'a> {
+    explorer: &'a Explorer,
+    rest: &'a JormungandrRest,
+    wait: Wait,
+}
+
+impl<'a> ExplorerCrossCheck<'a> {
+    pub fn new(explorer: &'a Explorer, rest: &'a JormungandrRest) -> Self {
+        Self {
+            explorer,
+            rest,
+            wait: Wait::new(Duration::from_secs(1), 30),
+        }
+    }
+
+    pub fn with_wait(mut self, wait: Wait) -> Self {
+        self.wait = wait;
+        self
+    }
+
+    fn timeout(&self) -> Duration {
+        self.wait.sleep_duration() * self.wait.attempts() as u32
+    }
+
+    /// Checks that the transaction, already in a block on the node, is
+    /// indexed by the explorer in the same block.
+    pub fn transaction_indexed(&self, id: FragmentId) -> Result<(), CrossCheckError> {
+        let mut statuses = self.rest.fragments_statuses(vec![id.to_string()])?;
+        let block = match statuses.remove(&id.to_string()) {
+            Some(FragmentStatus::InABlock { block, .. }) => block,
+            status => return Err(CrossCheckError::FragmentNotInBlock { id, status }),
+        };
+
+        let mut wait = self.wait.clone();
+        loop {
+            let response = self.explorer.transaction(id.into())?;
+            if let Some(data) = response.data {
+                let blocks: Vec<String> = data
+                    .transaction
+                    .blocks
+                    .into_iter()
+                    .map(|block| block.id)
+                    .collect();
+                if blocks.contains(&block.to_string()) {
+                    return Ok(());
+                }
+                return Err(CrossCheckError::BlockMismatch {
+                    id,
+                    node: block,
+                    explorer: blocks,
+                });
+            }
+            if wait.timeout_reached() {
+                return Err(CrossCheckError::TransactionNotIndexed {
+                    id,
+                    timeout: self.timeout(),
+                });
+            }
+            wait.advance();
+        }
+    }
+
+    /// Checks [`transaction_indexed`](Self::transaction_indexed) for each
+    /// of the sent fragments.
+    pub fn transactions_indexed<'b, I>(&self, checks: I) -> Result<(), CrossCheckError>
+    where
+        I: IntoIterator<Item = &'b MemPoolCheck>,
+    {
+        checks
+            .into_iter()
+            .try_for_each(|check| self.transaction_indexed(*check.fragment_id()))
+    }
+
+    /// Checks that the explorer counts, for each proposal of the vote plan,
+    /// as many votes as the node.
+    pub fn votes_counted(&self, id: VotePlanId) -> Result<(), CrossCheckError> {
+        let mut wait = self.wait.clone();
+        loop {
+            let vote_plan = self
+                .rest
+                .vote_plan_statuses()?
+                .into_iter()
+                .find(|vote_plan| vote_plan.id == id)
+                .ok_or(CrossCheckError::VotePlanNotOnNode { id })?;
+            let node: Vec<usize> = vote_plan
+                .proposals
+                .iter()
+                .map(|proposal| proposal.votes_cast)
+                .collect();
+
+            let explorer: Vec<usize> = match self.explorer.vote_plan(id.to_string())?.data {
+                Some(data) => vote_plan
+                    .proposals
+                    .iter()
+                    .map(|proposal| {
+                        data.vote_plan
+                            .proposals
+                            .iter()
+                            .find(|explorer_proposal| {
+                                explorer_proposal.proposal_id == proposal.proposal_id.to_string()
+                            })
+                            .map_or(0, |explorer_proposal| {
+                                explorer_proposal.votes.total_count as usize
+                            })
+                    })
+                    .collect(),
+                None => vec![0; node.len()],
+            };
+
+            if node == explorer {
+                return Ok(());
+            }
+            if wait.timeout_reached() {
+                return Err(CrossCheckError::VotesMismatch {
+                    id,
+                    node,
+                    explorer,
+                    timeout: self.timeout(),
+                });
+            }
+            wait.advance();
+        }
+    }
+}
@@ -27,6 +27,14 @@ pub struct Address;
 )]
 pub struct TransactionsByAddress;
 
+#[derive(GraphQLQuery)]
+#[graphql(
+    query_path = "resources/explorer/graphql/certificates_by_type.graphql",
+    schema_path = "resources/explorer/graphql/schema.graphql",
+    response_derives = "Debug"
+)]
+pub struct CertificatesByType;
+
 #[derive(GraphQLQuery)]
 #[graphql(
     query_path = "resources/explorer/graphql/allblocks.graphql",
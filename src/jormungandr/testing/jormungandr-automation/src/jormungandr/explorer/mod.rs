@@ -2,11 +2,11 @@ use self::{
     client::GraphQlClient,
     data::{
         address, all_blocks, all_stake_pools, all_vote_plans, block, block_by_id,
-        blocks_by_chain_length, epoch, last_block, settings, stake_pool, transaction_by_id,
-        transaction_by_id_certificates, transactions_by_address, vote_plan_by_id, Address,
-        AllBlocks, AllStakePools, AllVotePlans, Block, BlockById, BlocksByChainLength, Epoch,
-        LastBlock, Settings, StakePool, TransactionById, TransactionByIdCertificates,
-        TransactionsByAddress, VotePlanById,
+        blocks_by_chain_length, certificates_by_type, epoch, last_block, settings, stake_pool,
+        transaction_by_id, transaction_by_id_certificates, transactions_by_address,
+        vote_plan_by_id, Address, AllBlocks, AllStakePools, AllVotePlans, Block, BlockById,
+        BlocksByChainLength, CertificatesByType, Epoch, LastBlock, Settings, StakePool,
+        TransactionById, TransactionByIdCertificates, TransactionsByAddress, VotePlanById,
     },
 };
 use crate::testing::configuration::get_explorer_app;
@@ -20,6 +20,7 @@ use std::{
 };
 mod client;
 pub mod configuration;
+pub mod cross_check;
 pub mod data;
 pub mod verifiers;
 mod wrappers;
@@ -433,6 +434,22 @@ impl Explorer {
         Ok(response_body)
     }
 
+    pub fn certificates(
+        &self,
+        certificate_type: certificates_by_type::CertificateType,
+        limit: i64,
+    ) -> Result<Response<certificates_by_type::ResponseData>, ExplorerError> {
+        let query = CertificatesByType::build_query(certificates_by_type::Variables {
+            type_: certificate_type,
+            first: limit,
+        });
+        self.print_request(&query);
+        let response = self.client.run(query).map_err(ExplorerError::ClientError)?;
+        let response_body: Response<certificates_by_type::ResponseData> = response.json()?;
+        self.print_log(&response_body);
+        Ok(response_body)
+    }
+
     pub fn current_time(&self) -> BlockDate {
         self.last_block().unwrap().block_date()
     }
@@ -13,7 +13,12 @@ use crate::jormungandr::explorer::data::settings::SettingsSettingsFees;
 use bech32::FromBase32;
 use chain_core::{packer::Codec, property::Deserialize};
 use chain_crypto::{Ed25519, PublicKey};
-use chain_impl_mockchain::{block::Block, fee::LinearFee};
+use chain_impl_mockchain::{
+    block::Block,
+    certificate::{Anchor, MultisigDeclarationUpdate},
+    fee::LinearFee,
+    transaction::Transaction,
+};
 use jormungandr_lib::interfaces::Address;
 use thiserror::Error;
 
@@ -96,6 +101,34 @@ impl ExplorerVerifier {
         assert_eq!(address.to_string(), explorer_address.id);
     }
 
+    fn assert_anchor(
+        fragment_cert: &Transaction<Anchor>,
+        explorer_commitment: &str,
+        explorer_tag: &Option<String>,
+    ) {
+        let anchor = fragment_cert.as_slice().payload().into_payload();
+        assert_eq!(explorer_commitment, hex::encode(anchor.commitment()));
+        assert_eq!(explorer_tag, &anchor.tag().map(hex::encode));
+    }
+
+    fn assert_multisig_declaration_update(
+        fragment_cert: &Transaction<MultisigDeclarationUpdate>,
+        explorer_account: &str,
+        explorer_threshold: i64,
+        explorer_owners: &[String],
+    ) {
+        let update = fragment_cert.as_slice().payload().into_payload();
+        assert_eq!(explorer_account, hex::encode(update.id()));
+        assert_eq!(explorer_threshold, update.declaration().threshold() as i64);
+        let owners: Vec<String> = update
+            .declaration()
+            .owners()
+            .iter()
+            .map(|owner| hex::encode(owner.to_hash()))
+            .collect();
+        assert_eq!(explorer_owners, owners.as_slice());
+    }
+
     fn decode_bech32_pk(bech32_public_key: &str) -> PublicKey<Ed25519> {
         let (_, data, _variant) = bech32::decode(bech32_public_key).unwrap();
         let dat = Vec::from_base32(&data).unwrap();
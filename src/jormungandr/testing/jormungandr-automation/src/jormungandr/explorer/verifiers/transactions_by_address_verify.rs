@@ -231,6 +231,36 @@ impl ExplorerVerifier {
                     received: "MintToken can be only in block0".to_string(),
                 })
             }
+            TransactionsByAddressTipTransactionsByAddressEdgesNodeCertificate::Anchor(explorer_cert) => {
+                if let Fragment::Anchor(fragment_cert) = fragment {
+                    Self::assert_transaction_address_params(
+                        fragment_cert,
+                        explorer_transaction,
+                    )
+                    .unwrap();
+                    Self::assert_anchor(fragment_cert, &explorer_cert.commitment, &explorer_cert.tag);
+                    Ok(())
+                } else {
+                    Err(VerifierError::InvalidCertificate {
+                        received: "Anchor".to_string(),
+                    })
+                }
+            }
+            TransactionsByAddressTipTransactionsByAddressEdgesNodeCertificate::MultisigDeclarationUpdate(explorer_cert) => {
+                if let Fragment::MultisigDeclarationUpdate(fragment_cert) = fragment {
+                    Self::assert_transaction_address_params(
+                        fragment_cert,
+                        explorer_transaction,
+                    )
+                    .unwrap();
+                    Self::assert_multisig_declaration_update(fragment_cert, &explorer_cert.account, explorer_cert.threshold, &explorer_cert.owners);
+                    Ok(())
+                } else {
+                    Err(VerifierError::InvalidCertificate {
+                        received: "MultisigDeclarationUpdate".to_string(),
+                    })
+                }
+            }
             TransactionsByAddressTipTransactionsByAddressEdgesNodeCertificate::EvmMapping(_) => {
                 //Not implemented because of the bug EAS-238
                 Err(VerifierError::Unimplemented)
@@ -776,6 +806,10 @@ impl ExplorerVerifier {
                                     u64::from(ratio.denominator) == exp_halving_param.ratio.denominator.parse::<u64>().unwrap() &&
                                     u32::from(*epoch_rate) == exp_halving_param.epoch_rate as u32 &&
                                     *epoch_start == exp_halving_param.epoch_start as u32}) },
+                        ConfigParamOnRewardParamsRewardParams::FixedRewardParams(exp_fixed_param) =>
+                            {matches!(certificate_param, RewardParams::Fixed { constant,epoch_start }
+                                if {*constant == exp_fixed_param.constant as u64 &&
+                                    *epoch_start == exp_fixed_param.epoch_start as u32}) },
                     }})).count();
                     assert_eq!(matching_params, 1);
                 }
@@ -853,6 +887,42 @@ impl ExplorerVerifier {
                     if explorer_param.transaction_max_expiry_epochs as u8 == *certificate_param)).count();
                     assert_eq!(matching_params, 1);
                 }
+                MaxFragmentSize(certificate_param) => {
+                    let matching_params = explorer_cert
+                        .changes
+                        .config_params
+                        .iter()
+                        .filter(|&config_param| {
+                            matches!(config_param, configParam::MaxFragmentSize(explorer_param)
+                    if explorer_param.max_fragment_size as u32 == *certificate_param)
+                        })
+                        .count();
+                    assert_eq!(matching_params, 1);
+                }
+                VotingPowerSnapshot(certificate_param) => {
+                    let matching_params = explorer_cert
+                        .changes
+                        .config_params
+                        .iter()
+                        .filter(|&config_param| {
+                            matches!(config_param, configParam::VotingPowerSnapshot(explorer_param)
+                    if explorer_param.voting_power_snapshot == *certificate_param)
+                        })
+                        .count();
+                    assert_eq!(matching_params, 1);
+                }
+                ScriptAddresses(certificate_param) => {
+                    let matching_params = explorer_cert
+                        .changes
+                        .config_params
+                        .iter()
+                        .filter(|&config_param| {
+                            matches!(config_param, configParam::ScriptAddresses(explorer_param)
+                    if explorer_param.script_addresses == *certificate_param)
+                        })
+                        .count();
+                    assert_eq!(matching_params, 1);
+                }
                 #[cfg(feature = "evm")]
                 EvmConfiguration(_) => unimplemented!(),
                 #[cfg(feature = "evm")]
@@ -2,6 +2,7 @@ use super::{
     node::{
         node_client::NodeClient, HandshakeRequest, HandshakeResponse, PullBlocksRequest,
         PullBlocksToTipRequest, PullHeadersRequest, TipRequest,
+        TipSubscriptionRequest as NodeTipSubscriptionRequest,
     },
     types::{Block, BlockIds, Fragment, FragmentIds, Header},
     watch::{
@@ -115,6 +116,7 @@ impl JormungandrClient {
         let mut client = self.client();
         let request = tonic::Request::new(HandshakeRequest {
             nonce: nonce.to_vec(),
+            ..Default::default()
         });
 
         self.rt
@@ -130,6 +132,27 @@ impl JormungandrClient {
         read_into(&response.block_header)
     }
 
+    /// Collects the first `count` tip updates streamed by the node, starting with the current tip
+    pub fn tip_updates(&self, count: usize) -> Vec<(Hash, ChainLength)> {
+        use futures::StreamExt;
+
+        let mut client = self.client();
+        self.rt.block_on(async {
+            client
+                .tip_subscription(NodeTipSubscriptionRequest {})
+                .await
+                .unwrap()
+                .into_inner()
+                .take(count)
+                .map(|update| {
+                    let update = update.unwrap();
+                    (read_into(&update.block_id), update.chain_length.into())
+                })
+                .collect()
+                .await
+        })
+    }
+
     pub fn headers(&self, block_ids: &[Hash]) -> Result<Vec<LibHeader>, MockClientError> {
         let mut client = self.client();
 
@@ -1,4 +1,7 @@
-use super::{JormungandrServerImpl, MockController, MockLogger, MockServerData, ProtocolVersion};
+use super::{
+    FaultInjection, JormungandrServerImpl, MockController, MockLogger, MockServerData,
+    ProtocolVersion,
+};
 use crate::jormungandr::{configuration::get_available_port, grpc::server::NodeServer};
 use chain_core::property::Serialize;
 use chain_impl_mockchain::{block::Block, key::Hash};
@@ -20,6 +23,8 @@ pub struct MockBuilder {
     genesis_block: Option<Block>,
     protocol_version: ProtocolVersion,
     invalid_block0_hash: bool,
+    faults: FaultInjection,
+    capabilities: u64,
 }
 
 impl Default for MockBuilder {
@@ -29,6 +34,9 @@ impl Default for MockBuilder {
             genesis_block: None,
             protocol_version: ProtocolVersion::GenesisPraos,
             invalid_block0_hash: false,
+            faults: FaultInjection::default(),
+            // no optional protocol feature is advertised by default
+            capabilities: 0,
         }
     }
 }
@@ -54,6 +62,17 @@ impl MockBuilder {
         self
     }
 
+    pub fn with_faults(&mut self, faults: FaultInjection) -> &mut Self {
+        self.faults = faults;
+        self
+    }
+
+    /// Bitset of the optional protocol features advertised in the handshake
+    pub fn with_capabilities(&mut self, capabilities: u64) -> &mut Self {
+        self.capabilities = capabilities;
+        self
+    }
+
     pub fn build_data(&self) -> Arc<RwLock<MockServerData>> {
         let storage = BlockStore::memory(Hash::zero_hash().as_bytes().to_owned()).unwrap();
         let block0 = if let Some(block) = self.genesis_block.clone().take() {
@@ -64,7 +83,7 @@ impl MockBuilder {
             super::data::block0()
         };
 
-        let data = MockServerData::new(
+        let mut data = MockServerData::new(
             block0.header().hash(),
             self.protocol_version.clone(),
             format!(
@@ -76,6 +95,8 @@ impl MockBuilder {
             storage,
             self.invalid_block0_hash,
         );
+        *data.faults_mut() = self.faults.clone();
+        *data.capabilities_mut() = self.capabilities;
 
         data.put_block(&block0).unwrap();
         data.set_tip(block0.header().hash().serialize_as_vec().unwrap().as_ref())
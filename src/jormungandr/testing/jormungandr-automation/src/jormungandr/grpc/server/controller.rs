@@ -1,4 +1,6 @@
-use super::{MockExitCode, MockLogger, MockServerData, MockVerifier, ProtocolVersion};
+use super::{
+    FaultInjection, MockExitCode, MockLogger, MockServerData, MockVerifier, ProtocolVersion,
+};
 use chain_core::property::Serialize;
 use chain_impl_mockchain::{
     block::{Block, Header},
@@ -69,6 +71,21 @@ impl MockController {
             .unwrap();
     }
 
+    /// Stores the block as the new tip and announces it to subscribed peers,
+    /// unless the announcement is dropped by the injected faults
+    pub fn announce_block(&mut self, block: &Block) {
+        self.set_tip_block(block);
+        let data = self.data.read().unwrap();
+        data.announce(block.header()).unwrap();
+    }
+
+    /// Changes the faults injected from now on. Already established
+    /// subscriptions keep the faults in effect when they started.
+    pub fn set_faults(&mut self, faults: FaultInjection) {
+        let mut data = self.data.write().unwrap();
+        *data.faults_mut() = faults;
+    }
+
     pub fn genesis_hash(&self) -> Hash {
         let data = self.data.read().unwrap();
         *data.genesis_hash()
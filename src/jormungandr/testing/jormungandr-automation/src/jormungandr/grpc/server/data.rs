@@ -1,4 +1,4 @@
-use super::ProtocolVersion;
+use super::{FaultInjection, ProtocolVersion};
 use chain_core::{
     packer::Codec,
     property::{DeserializeFromSlice, ReadError, Serialize, WriteError},
@@ -12,6 +12,7 @@ use chain_impl_mockchain::{
 use chain_storage::{BlockInfo, BlockStore};
 use rand::Rng;
 use std::net::SocketAddr;
+use tokio::sync::broadcast;
 
 const AUTH_NONCE_LEN: usize = 32;
 
@@ -23,6 +24,9 @@ pub struct MockServerData {
     auth_nonce: [u8; AUTH_NONCE_LEN],
     storage: BlockStore,
     invalid_block0_hash: bool,
+    faults: FaultInjection,
+    capabilities: u64,
+    announcements: broadcast::Sender<Vec<u8>>,
 }
 
 #[derive(thiserror::Error, Debug)]
@@ -47,6 +51,7 @@ impl MockServerData {
         let keypair = KeyPair::generate(rand::thread_rng());
         let topology_key = keynesis::key::ed25519::SecretKey::new(rand::thread_rng());
         let profile = poldercast::Profile::new(addr, &topology_key);
+        let (announcements, _) = broadcast::channel(16);
         Self {
             genesis_hash,
             protocol,
@@ -55,6 +60,9 @@ impl MockServerData {
             auth_nonce: [0; AUTH_NONCE_LEN],
             storage,
             invalid_block0_hash: invalid_get_blocks_hash,
+            faults: FaultInjection::default(),
+            capabilities: 0,
+            announcements,
         }
     }
 
@@ -153,6 +161,34 @@ impl MockServerData {
     pub fn invalid_block0_hash(&self) -> bool {
         self.invalid_block0_hash
     }
+
+    pub fn faults(&self) -> &FaultInjection {
+        &self.faults
+    }
+
+    pub fn faults_mut(&mut self) -> &mut FaultInjection {
+        &mut self.faults
+    }
+
+    /// Bitset of the optional protocol features advertised in the handshake
+    pub fn capabilities(&self) -> u64 {
+        self.capabilities
+    }
+
+    pub fn capabilities_mut(&mut self) -> &mut u64 {
+        &mut self.capabilities
+    }
+
+    /// Sends the header to all peers subscribed to block announcements
+    pub fn announce(&self, header: &Header) -> Result<(), Error> {
+        // no subscribers is not an error, the announcement is just lost
+        let _ = self.announcements.send(header.serialize_as_vec()?);
+        Ok(())
+    }
+
+    pub fn subscribe_announcements(&self) -> broadcast::Receiver<Vec<u8>> {
+        self.announcements.subscribe()
+    }
 }
 
 pub fn block0() -> Block {
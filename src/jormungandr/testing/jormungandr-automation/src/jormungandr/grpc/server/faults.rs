@@ -0,0 +1,192 @@
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaChaRng;
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use tonic::Status;
+
+/// Ways for the mock peer to cut short or scramble the blocks it streams
+/// in response to a `GetBlocks` request
+#[derive(Debug, Clone, Copy)]
+enum BlocksStreamFault {
+    FailAfter(usize),
+    EndAfter(usize),
+    Reverse,
+}
+
+/// Misbehaviors of the mock peer, used to test the node resilience to
+/// network disruptions. Random decisions are driven by a seeded rng, so a
+/// given seed always produces the same sequence of faults.
+#[derive(Debug, Clone)]
+pub struct FaultInjection {
+    announcements_drop_ratio: f64,
+    headers_delay: Option<Duration>,
+    corrupted_blocks: bool,
+    blocks_stream: Option<BlocksStreamFault>,
+    rng: Arc<Mutex<ChaChaRng>>,
+}
+
+impl Default for FaultInjection {
+    fn default() -> Self {
+        Self {
+            announcements_drop_ratio: 0.0,
+            headers_delay: None,
+            corrupted_blocks: false,
+            blocks_stream: None,
+            rng: Arc::new(Mutex::new(ChaChaRng::seed_from_u64(0))),
+        }
+    }
+}
+
+impl FaultInjection {
+    /// Drops the given percentage (0 to 100) of block announcements
+    pub fn drop_announcements(mut self, percentage: u8) -> Self {
+        self.announcements_drop_ratio = f64::from(percentage.min(100)) / 100.0;
+        self
+    }
+
+    /// Waits the given duration before sending each header
+    pub fn delay_headers(mut self, delay: Duration) -> Self {
+        self.headers_delay = Some(delay);
+        self
+    }
+
+    /// Flips the last byte of each served block, so that the block content
+    /// no longer matches its header
+    pub fn corrupt_blocks(mut self) -> Self {
+        self.corrupted_blocks = true;
+        self
+    }
+
+    /// Fails the streams of requested blocks with an error once the given
+    /// number of blocks has been sent
+    pub fn fail_blocks_after(mut self, count: usize) -> Self {
+        self.blocks_stream = Some(BlocksStreamFault::FailAfter(count));
+        self
+    }
+
+    /// Ends the streams of requested blocks once the given number of
+    /// blocks has been sent
+    pub fn end_blocks_after(mut self, count: usize) -> Self {
+        self.blocks_stream = Some(BlocksStreamFault::EndAfter(count));
+        self
+    }
+
+    /// Sends the requested blocks in the reverse order
+    pub fn reverse_blocks(mut self) -> Self {
+        self.blocks_stream = Some(BlocksStreamFault::Reverse);
+        self
+    }
+
+    pub fn with_seed(self, seed: u64) -> Self {
+        *self.rng.lock().unwrap() = ChaChaRng::seed_from_u64(seed);
+        self
+    }
+
+    pub fn should_drop_announcement(&self) -> bool {
+        self.announcements_drop_ratio > 0.0
+            && self
+                .rng
+                .lock()
+                .unwrap()
+                .gen_bool(self.announcements_drop_ratio)
+    }
+
+    pub fn headers_delay(&self) -> Option<Duration> {
+        self.headers_delay
+    }
+
+    pub fn apply_to_block(&self, mut content: Vec<u8>) -> Vec<u8> {
+        if self.corrupted_blocks {
+            if let Some(last) = content.last_mut() {
+                *last ^= 0xff;
+            }
+        }
+        content
+    }
+
+    pub fn apply_to_blocks_stream<T>(
+        &self,
+        mut blocks: Vec<Result<T, Status>>,
+    ) -> Vec<Result<T, Status>> {
+        match self.blocks_stream {
+            None => (),
+            Some(BlocksStreamFault::FailAfter(count)) => {
+                blocks.truncate(count);
+                blocks.push(Err(Status::unavailable("injected blocks stream failure")));
+            }
+            Some(BlocksStreamFault::EndAfter(count)) => blocks.truncate(count),
+            Some(BlocksStreamFault::Reverse) => blocks.reverse(),
+        }
+        blocks
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn announcements_drop_is_deterministic() {
+        let drops = |seed| {
+            let faults = FaultInjection::default()
+                .drop_announcements(50)
+                .with_seed(seed);
+            (0..100)
+                .map(|_| faults.should_drop_announcement())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(drops(42), drops(42));
+        assert!(drops(42).iter().any(|dropped| *dropped));
+        assert!(drops(42).iter().any(|dropped| !*dropped));
+
+        let none = FaultInjection::default();
+        assert!((0..100).all(|_| !none.should_drop_announcement()));
+        let all = FaultInjection::default().drop_announcements(100);
+        assert!((0..100).all(|_| all.should_drop_announcement()));
+    }
+
+    #[test]
+    fn corrupted_block_differs() {
+        let block = vec![1, 2, 3];
+        assert_eq!(
+            FaultInjection::default().apply_to_block(block.clone()),
+            block
+        );
+        assert_ne!(
+            FaultInjection::default()
+                .corrupt_blocks()
+                .apply_to_block(block.clone()),
+            block
+        );
+    }
+
+    #[test]
+    fn blocks_stream_faults() {
+        let blocks = || (1..=3).map(Ok).collect::<Vec<Result<u8, Status>>>();
+        let served = |faults: FaultInjection| {
+            faults
+                .apply_to_blocks_stream(blocks())
+                .into_iter()
+                .map(|block| block.ok())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            served(FaultInjection::default()),
+            [Some(1), Some(2), Some(3)]
+        );
+        assert_eq!(
+            served(FaultInjection::default().fail_blocks_after(1)),
+            [Some(1), None]
+        );
+        assert_eq!(
+            served(FaultInjection::default().end_blocks_after(2)),
+            [Some(1), Some(2)]
+        );
+        assert_eq!(
+            served(FaultInjection::default().reverse_blocks()),
+            [Some(3), Some(2), Some(1)]
+        );
+    }
+}
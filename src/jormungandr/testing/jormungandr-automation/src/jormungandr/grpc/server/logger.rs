@@ -16,6 +16,7 @@ pub enum MethodType {
     PullBlocks,
     PullBlocksToTip,
     Tip,
+    TipSubscription,
     GetBlocks,
     GetHeaders,
     GetFragments,
@@ -25,6 +26,7 @@ pub enum MethodType {
     UploadBlocks,
     BlockSubscription,
     FragmentSubscription,
+    FragmentSubscriptionWithAck,
     GossipSubscription,
 }
 
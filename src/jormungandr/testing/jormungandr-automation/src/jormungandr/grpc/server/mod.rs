@@ -1,12 +1,14 @@
 use crate::jormungandr::{
     grpc::{
         node::{
+            block_event,
             node_server::{Node, NodeServer},
-            BlockEvent, ClientAuthRequest, ClientAuthResponse, Gossip, HandshakeRequest,
-            HandshakeResponse, PeersRequest, PeersResponse, PullBlocksRequest,
+            BlockEvent, ClientAuthRequest, ClientAuthResponse, FragmentEvent, Gossip,
+            HandshakeRequest, HandshakeResponse, PeersRequest, PeersResponse, PullBlocksRequest,
             PullBlocksToTipRequest, PullHeadersRequest, PushHeadersResponse, TipRequest,
-            TipResponse, UploadBlocksResponse,
+            TipResponse, TipSubscriptionRequest, TipUpdate, UploadBlocksResponse,
         },
+        read_into,
         types::{Block, BlockIds, Fragment, FragmentIds, Header},
     },
     Block0ConfigurationBuilder,
@@ -15,12 +17,16 @@ use chain_core::{
     packer::Codec,
     property::{DeserializeFromSlice, Header as BlockHeader, Serialize},
 };
-use chain_impl_mockchain::{block::BlockVersion, chaintypes::ConsensusVersion, key::Hash};
+use chain_impl_mockchain::{
+    block::{Block as ChainBlock, BlockVersion},
+    chaintypes::ConsensusVersion,
+    key::Hash,
+};
 use std::{
     fmt,
     sync::{Arc, RwLock},
 };
-use tokio::sync::mpsc;
+use tokio::sync::{broadcast::error::RecvError, mpsc};
 use tokio_stream::wrappers::ReceiverStream;
 use tonic::{Request, Response, Status};
 use tracing::info;
@@ -28,12 +34,14 @@ use tracing::info;
 mod builder;
 mod controller;
 mod data;
+mod faults;
 mod logger;
 mod verifier;
 
 pub use builder::{start_thread, MockBuilder};
 pub use controller::MockController;
 pub use data::MockServerData;
+pub use faults::FaultInjection;
 pub use logger::{MethodType, MockLogger};
 pub use verifier::MockVerifier;
 
@@ -76,6 +84,30 @@ impl JormungandrServerImpl {
         );
         JormungandrServerImpl { data }
     }
+
+    fn faults(&self) -> FaultInjection {
+        self.data.read().unwrap().faults().clone()
+    }
+
+    /// Streams headers, waiting before each one if a delay is injected
+    fn headers_stream(
+        &self,
+        headers: Vec<Result<Header, Status>>,
+    ) -> ReceiverStream<Result<Header, Status>> {
+        let delay = self.faults().headers_delay();
+        let (tx, rx) = mpsc::channel(headers.len().max(1));
+        tokio::spawn(async move {
+            for header in headers {
+                if let Some(delay) = delay {
+                    tokio::time::sleep(delay).await;
+                }
+                if tx.send(header).await.is_err() {
+                    break;
+                }
+            }
+        });
+        ReceiverStream::new(rx)
+    }
 }
 
 #[tonic::async_trait]
@@ -88,7 +120,9 @@ impl Node for JormungandrServerImpl {
     type GetFragmentsStream = ReceiverStream<Result<Fragment, Status>>;
     type BlockSubscriptionStream = ReceiverStream<Result<BlockEvent, Status>>;
     type FragmentSubscriptionStream = ReceiverStream<Result<Fragment, Status>>;
+    type FragmentSubscriptionWithAckStream = ReceiverStream<Result<FragmentEvent, Status>>;
     type GossipSubscriptionStream = ReceiverStream<Result<Gossip, Status>>;
+    type TipSubscriptionStream = ReceiverStream<Result<TipUpdate, Status>>;
 
     async fn handshake(
         &self,
@@ -109,6 +143,7 @@ impl Node for JormungandrServerImpl {
             node_id: data.node_id().to_vec(),
             signature,
             nonce,
+            capabilities: data.capabilities(),
         };
         Ok(Response::new(reply))
     }
@@ -148,6 +183,30 @@ impl Node for JormungandrServerImpl {
         Ok(Response::new(tip_response))
     }
 
+    async fn tip_subscription(
+        &self,
+        _request: tonic::Request<TipSubscriptionRequest>,
+    ) -> Result<tonic::Response<Self::TipSubscriptionStream>, tonic::Status> {
+        info!(
+            method = %MethodType::TipSubscription,
+            "Tip subscription request received"
+        );
+        let tip = self
+            .data
+            .read()
+            .unwrap()
+            .tip()
+            .map_err(|e| tonic::Status::internal(format!("invalid tip {}", e)))?;
+        let (tx, rx) = mpsc::channel(1);
+        // only the current tip is sent, the mock does not produce new blocks
+        tx.try_send(Ok(TipUpdate {
+            block_id: tip.id().as_ref().to_vec(),
+            chain_length: tip.chain_length().into(),
+        }))
+        .unwrap();
+        Ok(Response::new(ReceiverStream::new(rx)))
+    }
+
     async fn peers(
         &self,
         _request: tonic::Request<PeersRequest>,
@@ -202,14 +261,16 @@ impl Node for JormungandrServerImpl {
             blocks.push(block);
         }
 
-        let (tx, rx) = mpsc::channel(blocks.len());
+        let faults = self.faults();
+        let blocks = faults.apply_to_blocks_stream(blocks);
+        let (tx, rx) = mpsc::channel(blocks.len().max(1));
 
         for block in blocks {
             tx.send(block.map(|b| {
                 let mut codec = Codec::new(vec![]);
                 b.serialize(&mut codec).unwrap();
                 Block {
-                    content: codec.into_inner(),
+                    content: faults.apply_to_block(codec.into_inner()),
                 }
             }))
             .await
@@ -220,14 +281,29 @@ impl Node for JormungandrServerImpl {
     }
     async fn get_headers(
         &self,
-        _request: tonic::Request<BlockIds>,
+        request: tonic::Request<BlockIds>,
     ) -> Result<tonic::Response<Self::GetHeadersStream>, tonic::Status> {
         info!(
             method = %MethodType::GetHeaders,
             "Get headers request received",
         );
-        let (_tx, rx) = mpsc::channel(1);
-        Ok(Response::new(ReceiverStream::new(rx)))
+        let headers = {
+            let data = self.data.read().unwrap();
+            request
+                .into_inner()
+                .ids
+                .iter()
+                .map(|block_id| {
+                    let block_hash = read_into::<Hash>(block_id);
+                    data.get_block(block_hash)
+                        .map(|block| Header {
+                            content: block.header().serialize_as_vec().unwrap(),
+                        })
+                        .map_err(|_| Status::not_found(format!("{} not available", block_hash)))
+                })
+                .collect()
+        };
+        Ok(Response::new(self.headers_stream(headers)))
     }
     async fn get_fragments(
         &self,
@@ -242,14 +318,41 @@ impl Node for JormungandrServerImpl {
     }
     async fn pull_headers(
         &self,
-        _request: tonic::Request<PullHeadersRequest>,
+        request: tonic::Request<PullHeadersRequest>,
     ) -> Result<tonic::Response<Self::PullHeadersStream>, tonic::Status> {
         info!(
             method = %MethodType::PullHeaders,
             "Pull Headers request received",
         );
-        let (_tx, rx) = mpsc::channel(1);
-        Ok(Response::new(ReceiverStream::new(rx)))
+        let request = request.into_inner();
+        let headers = {
+            let data = self.data.read().unwrap();
+            let from = request
+                .from
+                .first()
+                .ok_or_else(|| Status::invalid_argument("missing starting point"))?;
+            let distance = data
+                .storage()
+                // This ignores all checkpoints except the first one, we don't need it for now
+                .is_ancestor(from, &request.to)
+                .map_err(|e| Status::not_found(e.to_string()))?
+                .ok_or_else(|| Status::invalid_argument("from is not an ancestor of to"))?;
+            data.storage()
+                .iter(&request.to, distance)
+                .map_err(|e| Status::not_found(e.to_string()))?
+                .map(|block| {
+                    block
+                        .map(|b| Header {
+                            content: read_into::<ChainBlock>(b.as_ref())
+                                .header()
+                                .serialize_as_vec()
+                                .unwrap(),
+                        })
+                        .map_err(|e| Status::aborted(e.to_string()))
+                })
+                .collect()
+        };
+        Ok(Response::new(self.headers_stream(headers)))
     }
     async fn pull_blocks(
         &self,
@@ -276,11 +379,12 @@ impl Node for JormungandrServerImpl {
         };
 
         let (tx, rx) = mpsc::channel(distance as usize);
+        let faults = self.faults();
         for block in block_iter {
             tx.send(
                 block
                     .map(|b| Block {
-                        content: b.as_ref().into(),
+                        content: faults.apply_to_block(b.as_ref().into()),
                     })
                     .map_err(|e| tonic::Status::aborted(e.to_string())),
             )
@@ -329,7 +433,31 @@ impl Node for JormungandrServerImpl {
             method = %MethodType::BlockSubscription,
             "Block subscription event received",
         );
-        let (_tx, rx) = mpsc::channel(1);
+        let mut announcements = self.data.read().unwrap().subscribe_announcements();
+        let faults = self.faults();
+        let (tx, rx) = mpsc::channel(16);
+        tokio::spawn(async move {
+            loop {
+                let header = match announcements.recv().await {
+                    Ok(header) => header,
+                    Err(RecvError::Lagged(_)) => continue,
+                    Err(RecvError::Closed) => break,
+                };
+                if faults.should_drop_announcement() {
+                    info!(
+                        method = %MethodType::BlockSubscription,
+                        "Block announcement dropped",
+                    );
+                    continue;
+                }
+                let event = BlockEvent {
+                    item: Some(block_event::Item::Announce(Header { content: header })),
+                };
+                if tx.send(Ok(event)).await.is_err() {
+                    break;
+                }
+            }
+        });
         Ok(Response::new(ReceiverStream::new(rx)))
     }
 
@@ -344,6 +472,18 @@ impl Node for JormungandrServerImpl {
         let (_tx, rx) = mpsc::channel(1);
         Ok(Response::new(ReceiverStream::new(rx)))
     }
+
+    async fn fragment_subscription_with_ack(
+        &self,
+        _request: tonic::Request<tonic::Streaming<Fragment>>,
+    ) -> Result<tonic::Response<Self::FragmentSubscriptionWithAckStream>, tonic::Status> {
+        info!(
+            method = %MethodType::FragmentSubscriptionWithAck,
+            "Fragment subscription with acknowledgments event received",
+        );
+        let (_tx, rx) = mpsc::channel(1);
+        Ok(Response::new(ReceiverStream::new(rx)))
+    }
     async fn gossip_subscription(
         &self,
         _request: tonic::Request<tonic::Streaming<Gossip>>,
@@ -13,7 +13,7 @@ use jormungandr_lib::{
     interfaces::{Address, FragmentLog, FragmentStatus, FragmentsProcessingSummary, VotePlanId},
 };
 use reqwest::blocking::Response;
-use std::collections::HashMap;
+use std::{collections::HashMap, time::Duration};
 
 /// Legacy tolerant rest api
 /// This layer returns raw strings without deserialization
@@ -60,12 +60,6 @@ impl BackwardCompatibleRest {
         self.raw.rest_settings_mut().enable_debug = true;
     }
 
-    pub(crate) fn updates(&self) -> Result<String, reqwest::Error> {
-        let response_text = self.raw().updates()?.text()?;
-        self.print_response_text(&response_text);
-        Ok(response_text)
-    }
-
     pub fn epoch_reward_history(&self, epoch: u32) -> Result<String, reqwest::Error> {
         let response_text = self.raw().epoch_reward_history(epoch)?.text()?;
         self.print_response_text(&response_text);
@@ -195,6 +189,10 @@ impl BackwardCompatibleRest {
         self.raw().shutdown()?.text()
     }
 
+    pub fn shutdown_graceful(&self, timeout: Duration) -> Result<String, reqwest::Error> {
+        self.raw().shutdown_graceful(timeout)?.text()
+    }
+
     pub fn settings(&self) -> Result<String, reqwest::Error> {
         self.raw().settings()?.text()
     }
@@ -2,6 +2,7 @@ use crate::testing::{collector::OutputCollector, Timestamp};
 use chain_core::property::FromStr;
 use chain_impl_mockchain::{block, key::Hash};
 use jormungandr_lib::{interfaces::BlockDate, time::SystemTime};
+use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::{
     cell::{Ref, RefCell},
@@ -168,17 +169,11 @@ impl LogEntry {
     const MESSAGE: &'static str = "message";
 
     pub fn reason_contains(&self, reason_part: &str) -> bool {
-        match &self.fields.get("reason") {
-            Some(reason) => reason.contains(reason_part),
-            None => false,
-        }
+        self.field_contains("reason", reason_part)
     }
 
     pub fn error_contains(&self, error_part: &str) -> bool {
-        match &self.fields.get("error") {
-            Some(error) => error.contains(error_part),
-            None => false,
-        }
+        self.field_contains("error", error_part)
     }
 
     pub fn block_date(&self) -> Option<BlockDate> {
@@ -195,6 +190,167 @@ impl LogEntry {
     pub fn message(&self) -> String {
         self.fields.get(Self::MESSAGE).cloned().unwrap_or_default()
     }
+
+    pub fn field_contains(&self, field: &str, part: &str) -> bool {
+        match &self.fields.get(field) {
+            Some(value) => value.contains(part),
+            None => false,
+        }
+    }
+}
+
+type LogFilter<'a> = Box<dyn Fn(&LogEntry) -> bool + 'a>;
+
+/// Structured query over the entries collected by a [`JormungandrLogger`],
+/// narrowed down by chaining filters, e.g.
+///
+/// ```ignore
+/// logger
+///     .query()
+///     .min_level(LogLevel::WARN)
+///     .since(restart_time)
+///     .assert_none("after restart");
+/// ```
+///
+/// The entries are read when a result is requested, so a query can be
+/// evaluated again as the node keeps logging.
+pub struct LogQuery<'a> {
+    logger: &'a JormungandrLogger,
+    filters: Vec<(String, LogFilter<'a>)>,
+}
+
+impl<'a> LogQuery<'a> {
+    fn new(logger: &'a JormungandrLogger) -> Self {
+        Self {
+            logger,
+            filters: Vec::new(),
+        }
+    }
+
+    fn filter<F>(mut self, description: String, filter: F) -> Self
+    where
+        F: Fn(&LogEntry) -> bool + 'a,
+    {
+        self.filters.push((description, Box::new(filter)));
+        self
+    }
+
+    pub fn level(self, level: Level) -> Self {
+        self.filter(format!("with level {}", level), move |entry| {
+            entry.level == level
+        })
+    }
+
+    pub fn min_level(self, level: Level) -> Self {
+        self.filter(format!("with level {} or above", level), move |entry| {
+            entry.level >= level
+        })
+    }
+
+    pub fn since(self, time: SystemTime) -> Self {
+        self.filter(format!("logged since {}", time), move |entry| {
+            entry.is_later_than(&time)
+        })
+    }
+
+    pub fn until(self, time: SystemTime) -> Self {
+        self.filter(format!("logged until {}", time), move |entry| {
+            !entry.is_later_than(&time)
+        })
+    }
+
+    pub fn within(self, from: SystemTime, to: SystemTime) -> Self {
+        self.since(from).until(to)
+    }
+
+    /// Keeps the entries whose whole line, as printed by the node, matches
+    /// the regular expression.
+    pub fn matching(self, pattern: &str) -> Self {
+        let regex = Regex::new(pattern)
+            .unwrap_or_else(|e| panic!("invalid log pattern '{}': {}", pattern, e));
+        self.filter(format!("matching '{}'", pattern), move |entry| {
+            regex.is_match(&entry.to_string())
+        })
+    }
+
+    pub fn message(self, message: &'a str) -> Self {
+        self.filter(format!("with message '{}'", message), move |entry| {
+            entry.message() == message
+        })
+    }
+
+    pub fn field_contains(self, field: &'a str, part: &'a str) -> Self {
+        self.filter(
+            format!("with {} containing '{}'", field, part),
+            move |entry| entry.field_contains(field, part),
+        )
+    }
+
+    pub fn entries(&self) -> Vec<LogEntry> {
+        self.logger
+            .entries()
+            .iter()
+            .filter(|entry| self.filters.iter().all(|(_, filter)| filter(entry)))
+            .cloned()
+            .collect()
+    }
+
+    pub fn lines(&self) -> Vec<String> {
+        self.entries().iter().map(LogEntry::to_string).collect()
+    }
+
+    pub fn count(&self) -> usize {
+        self.entries().len()
+    }
+
+    pub fn exists(&self) -> bool {
+        self.count() > 0
+    }
+
+    pub fn assert_none(&self, message: &str) {
+        let lines = self.lines();
+        assert!(
+            lines.is_empty(),
+            "{} expected no log entry {}, found {}:\n{}",
+            message,
+            self,
+            lines.len(),
+            lines.join("\n")
+        );
+    }
+
+    pub fn assert_some(&self, message: &str) {
+        assert!(
+            self.exists(),
+            "{} expected a log entry {}, log content:\n{}",
+            message,
+            self,
+            self.logger.get_log_content()
+        );
+    }
+
+    pub fn assert_count(&self, expected: usize, message: &str) {
+        let lines = self.lines();
+        assert_eq!(
+            lines.len(),
+            expected,
+            "{} expected {} log entries {}, found:\n{}",
+            message,
+            expected,
+            self,
+            lines.join("\n")
+        );
+    }
+}
+
+impl fmt::Display for LogQuery<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.filters.is_empty() {
+            return f.write_str("at all");
+        }
+        let descriptions: Vec<_> = self.filters.iter().map(|(d, _)| d.as_str()).collect();
+        f.write_str(&descriptions.join(" and "))
+    }
 }
 
 impl From<LogEntry> for Timestamp {
@@ -232,6 +388,11 @@ impl JormungandrLogger {
             .extend(self.panics_collector.borrow_mut().take_available_input());
     }
 
+    /// Starts a query over the log entries, see [`LogQuery`].
+    pub fn query(&self) -> LogQuery<'_> {
+        LogQuery::new(self)
+    }
+
     pub fn get_error_indicators() -> Vec<&'static str> {
         vec!["panicked"]
     }
@@ -326,11 +487,7 @@ impl JormungandrLogger {
     }
 
     pub fn assert_no_errors(&self, message: &str) {
-        let error_lines = self
-            .get_log_lines_with_level(Level::ERROR)
-            .collect::<Vec<_>>();
-
-        let panics = self.panic_entries();
+        let panics = self.get_panic_lines();
 
         assert_eq!(
             panics.len(),
@@ -340,13 +497,7 @@ impl JormungandrLogger {
             panics,
         );
 
-        assert_eq!(
-            error_lines.len(),
-            0,
-            "{} there are some errors in log: {:?}",
-            message,
-            error_lines,
-        );
+        self.query().min_level(Level::ERROR).assert_none(message);
     }
 
     fn try_parse_line_as_entry(line: &str) -> Result<LogEntry, serde_json::Error> {
@@ -392,3 +543,131 @@ impl JormungandrLogger {
         serde_json::from_value(value)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LINES: &[&str] = &[
+        r#"{"timestamp":"2022-01-01T00:00:00Z","level":"INFO","fields":{"message":"node started"},"target":"jormungandr::main"}"#,
+        r#"{"timestamp":"2022-01-01T00:00:10Z","level":"WARN","fields":{"message":"peer quarantined","peer":"1.2.3.4:3000"},"target":"jormungandr::network"}"#,
+        r#"{"timestamp":"2022-01-01T00:00:20Z","level":"ERROR","fields":{"message":"block rejected","reason":"invalid parent"},"target":"jormungandr::blockchain"}"#,
+        r#"{"timestamp":"2022-01-01T00:00:30Z","level":"DEBUG","fields":{"message":"tip updated","block_date":"1.2"},"target":"jormungandr::blockchain"}"#,
+    ];
+
+    fn logger() -> JormungandrLogger {
+        let logger = JormungandrLogger::new(std::io::empty(), std::io::empty());
+        logger.collected_logs.borrow_mut().extend(
+            LINES
+                .iter()
+                .map(|line| JormungandrLogger::try_parse_line_as_entry(line).unwrap()),
+        );
+        logger
+    }
+
+    fn at(time: &str) -> SystemTime {
+        time.parse().unwrap()
+    }
+
+    fn messages(query: LogQuery<'_>) -> Vec<String> {
+        query.entries().iter().map(LogEntry::message).collect()
+    }
+
+    #[test]
+    fn since_includes_and_until_excludes_the_boundary() {
+        let logger = logger();
+
+        assert_eq!(
+            messages(logger.query().since(at("2022-01-01T00:00:20Z"))),
+            vec!["block rejected", "tip updated"]
+        );
+        assert_eq!(
+            messages(logger.query().until(at("2022-01-01T00:00:20Z"))),
+            vec!["node started", "peer quarantined"]
+        );
+        assert_eq!(
+            messages(
+                logger
+                    .query()
+                    .within(at("2022-01-01T00:00:05Z"), at("2022-01-01T00:00:30Z"))
+            ),
+            vec!["peer quarantined", "block rejected"]
+        );
+        assert!(!logger.query().since(at("2022-01-01T00:00:31Z")).exists());
+    }
+
+    #[test]
+    fn min_level_follows_the_severity_order() {
+        let logger = logger();
+
+        assert_eq!(logger.query().min_level(Level::TRACE).count(), 4);
+        assert_eq!(logger.query().min_level(Level::INFO).count(), 3);
+        assert_eq!(
+            messages(logger.query().min_level(Level::WARN)),
+            vec!["peer quarantined", "block rejected"]
+        );
+        assert_eq!(
+            messages(logger.query().level(Level::DEBUG)),
+            vec!["tip updated"]
+        );
+    }
+
+    #[test]
+    fn matching_applies_to_the_whole_line() {
+        let logger = logger();
+
+        assert_eq!(
+            messages(logger.query().matching(r"peer=1\.2\.3\.4")),
+            vec!["peer quarantined"]
+        );
+        assert_eq!(
+            messages(logger.query().matching("jormungandr::blockchain")),
+            vec!["block rejected", "tip updated"]
+        );
+        assert_eq!(
+            messages(
+                logger
+                    .query()
+                    .matching("jormungandr::blockchain")
+                    .min_level(Level::INFO)
+            ),
+            vec!["block rejected"]
+        );
+        assert!(!logger.query().matching("^no such line$").exists());
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid log pattern")]
+    fn matching_rejects_invalid_patterns() {
+        logger().query().matching("(");
+    }
+
+    #[test]
+    fn assert_count_accepts_the_expected_count() {
+        let logger = logger();
+        logger
+            .query()
+            .min_level(Level::WARN)
+            .assert_count(2, "after start");
+        logger
+            .query()
+            .field_contains("reason", "parent")
+            .assert_count(1, "rejected block");
+        logger
+            .query()
+            .message("node stopped")
+            .assert_count(0, "stop");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "after start expected 1 log entries with level WARN or above and logged since"
+    )]
+    fn assert_count_reports_the_query() {
+        logger()
+            .query()
+            .min_level(Level::WARN)
+            .since(at("2022-01-01T00:00:00Z"))
+            .assert_count(1, "after start");
+    }
+}
@@ -16,7 +16,7 @@ pub mod grpc;
 pub use self::{
     configuration::{
         get_available_port, Block0ConfigurationBuilder, EitherHashOrBlock0, NodeConfigBuilder,
-        NodeConfigManager, SecretModelFactory,
+        NodeConfigManager, SecretModelFactory, SyntheticBlock0Builder,
     },
     explorer::{
         compare_schema as compare_explorer_schema, Explorer, ExplorerError, ExplorerProcess,
@@ -27,14 +27,17 @@ pub use self::{
         LegacyConfigError, LegacyNodeConfig, LegacyNodeConfigBuilder, LegacyNodeConfigConverter,
         LegacyNodeConfigManager, Version,
     },
-    logger::{JormungandrLogger, Level as LogLevel},
+    logger::{JormungandrLogger, Level as LogLevel, LogQuery},
     process::*,
     remote::{RemoteJormungandr, RemoteJormungandrBuilder},
-    rest::{uri_from_socket_addr, JormungandrRest, RawRest, RestError, RestSettings},
+    rest::{
+        uri_from_socket_addr, JormungandrRest, RawRest, RequestTiming, RestError, RestSettings,
+        RetryPolicy,
+    },
     starter::{
         ConfigurableNodeConfig, ConfiguredStarter, FaketimeConfig, JormungandrBootstrapper,
-        JormungandrParams, LeadershipMode, NodeBlock0, PersistenceMode, Starter, StartupError,
-        StartupVerificationMode, TestingDirectory,
+        JormungandrParams, LeadershipMode, NodeBlock0, NodeWrapper, NodeWrapperError,
+        PersistenceMode, Starter, StartupError, StartupVerificationMode, TestingDirectory,
     },
     verifier::{assert_accepted_rejected, assert_bad_request, JormungandrStateVerifier},
 };
@@ -9,8 +9,8 @@ use crate::{
         explorer::configuration::ExplorerConfigurationBuilder, grpc::JormungandrClient,
         rest::uri_from_socket_addr, starter::CommunicationParams, ExplorerError, FragmentNode,
         FragmentNodeError, JormungandrLogger, JormungandrRest, JormungandrStateVerifier, LogLevel,
-        MemPoolCheck, NodeAlias, RemoteJormungandr, RemoteJormungandrBuilder,
-        StartupVerificationMode, TestingDirectory,
+        MemPoolCheck, NodeAlias, RemoteJormungandr, RemoteJormungandrBuilder, RestSettings,
+        RetryPolicy, StartupVerificationMode, TestingDirectory,
     },
     testing::SyncNode,
 };
@@ -210,6 +210,13 @@ impl JormungandrProcess {
         JormungandrRest::new(self.rest_uri())
     }
 
+    pub fn rest_with_retry(&self, retry: RetryPolicy) -> JormungandrRest {
+        JormungandrRest::new_with_custom_settings(
+            self.rest_uri(),
+            RestSettings::default().with_retry(retry),
+        )
+    }
+
     pub fn rest_debug(&self) -> JormungandrRest {
         let mut rest = JormungandrRest::new(self.rest_uri());
         rest.enable_logger();
@@ -246,10 +253,7 @@ impl JormungandrProcess {
     }
 
     pub fn check_no_errors_in_log(&self) -> Result<(), JormungandrError> {
-        let error_lines = self
-            .logger
-            .get_log_lines_with_level(LogLevel::ERROR)
-            .collect::<Vec<_>>();
+        let error_lines = self.logger.query().level(LogLevel::ERROR).entries();
 
         if !error_lines.is_empty() {
             return Err(JormungandrError::ErrorInLogs {
@@ -396,8 +400,10 @@ impl SyncNode for JormungandrProcess {
 
     fn get_lines_with_error_and_invalid(&self) -> Vec<String> {
         self.logger
-            .get_log_lines_with_level(LogLevel::ERROR)
-            .map(|x| x.to_string())
+            .query()
+            .level(LogLevel::ERROR)
+            .lines()
+            .into_iter()
             .chain(self.logger.get_panic_lines().into_iter())
             .collect()
     }
@@ -92,8 +92,10 @@ impl SyncNode for RemoteJormungandr {
     fn get_lines_with_error_and_invalid(&self) -> Vec<String> {
         match &self.logger {
             Some(logger) => logger
-                .get_log_lines_with_level(LogLevel::ERROR)
-                .map(|x| x.to_string())
+                .query()
+                .level(LogLevel::ERROR)
+                .lines()
+                .into_iter()
                 .chain(logger.get_panic_lines().into_iter())
                 .collect(),
             None => vec!["log not available".to_string()],
@@ -20,17 +20,29 @@ use jormungandr_lib::{
         VotePlanStatus,
     },
 };
-pub use raw::RawRest;
-pub use settings::RestSettings;
-use std::{collections::HashMap, fs::File, io::Read, net::SocketAddr, path::Path};
+pub use raw::{RawRest, RequestTiming};
+use reqwest::blocking::Response;
+use serde::de::DeserializeOwned;
+pub use settings::{RestSettings, RetryPolicy};
+use std::{collections::HashMap, fs::File, io::Read, net::SocketAddr, path::Path, time::Duration};
 use thiserror::Error;
 
 #[derive(Debug, Error)]
 pub enum RestError {
     #[error("could not deserialize response")]
     CannotDeserialize(#[from] serde_json::Error),
+    #[error("cannot connect to node")]
+    Connection(#[source] reqwest::Error),
+    #[error("request timed out")]
+    Timeout(#[source] reqwest::Error),
+    #[error("node responded with error code {status}")]
+    HttpStatus {
+        status: reqwest::StatusCode,
+        #[source]
+        source: reqwest::Error,
+    },
     #[error("could not send reqeuest")]
-    RequestError(#[from] reqwest::Error),
+    RequestError(#[source] reqwest::Error),
     #[error("hash parse error")]
     HashParseError(#[from] chain_crypto::hash::Error),
     #[error("error while polling endpoint")]
@@ -45,6 +57,23 @@ pub enum RestError {
     ReadBytes(#[from] chain_core::property::ReadError),
 }
 
+impl From<reqwest::Error> for RestError {
+    fn from(error: reqwest::Error) -> Self {
+        if error.is_connect() {
+            Self::Connection(error)
+        } else if error.is_timeout() {
+            Self::Timeout(error)
+        } else if let Some(status) = error.status() {
+            Self::HttpStatus {
+                status,
+                source: error,
+            }
+        } else {
+            Self::RequestError(error)
+        }
+    }
+}
+
 pub fn uri_from_socket_addr(addr: SocketAddr) -> String {
     format!("http://{}/api", addr)
 }
@@ -88,6 +117,10 @@ impl JormungandrRest {
         self.inner.raw()
     }
 
+    pub fn timings(&self) -> Vec<RequestTiming> {
+        self.raw().timings()
+    }
+
     pub fn new_with_cert<P: AsRef<Path>>(uri: String, cert_file: P) -> Self {
         //replace http with https
         //replace localhost ip to localhost
@@ -111,37 +144,49 @@ impl JormungandrRest {
         reqwest::Certificate::from_pem(&buf).unwrap()
     }
 
+    /// Reads the body of a successful response, a response with an error
+    /// code is reported as [`RestError::HttpStatus`]
+    fn text(&self, response: Result<Response, reqwest::Error>) -> Result<String, RestError> {
+        let text = response?.error_for_status()?.text()?;
+        if self.raw().rest_settings().enable_debug {
+            println!("Response: {}", text);
+        }
+        Ok(text)
+    }
+
+    fn json<T: DeserializeOwned>(
+        &self,
+        response: Result<Response, reqwest::Error>,
+    ) -> Result<T, RestError> {
+        serde_json::from_str(&self.text(response)?).map_err(RestError::CannotDeserialize)
+    }
+
     pub fn epoch_reward_history(&self, epoch: u32) -> Result<EpochRewardsInfo, RestError> {
-        let content = self.inner.epoch_reward_history(epoch)?;
-        serde_json::from_str(&content).map_err(RestError::CannotDeserialize)
+        self.json(self.raw().epoch_reward_history(epoch))
     }
 
     pub fn updates(&self) -> Result<HashMap<Hash, UpdateProposalStateDef>, RestError> {
-        let content = self.inner.updates()?;
-        serde_json::from_str(&content).map_err(RestError::CannotDeserialize)
+        self.json(self.raw().updates())
     }
 
     pub fn reward_history(&self, length: u32) -> Result<Vec<EpochRewardsInfo>, RestError> {
-        serde_json::from_str(&self.inner.reward_history(length)?)
-            .map_err(RestError::CannotDeserialize)
+        self.json(self.raw().reward_history(length))
     }
 
     pub fn remaining_rewards(&self) -> Result<Value, RestError> {
-        serde_json::from_str(&self.inner.remaining_rewards()?).map_err(RestError::CannotDeserialize)
+        self.json(self.raw().remaining_rewards())
     }
 
     pub fn stake_distribution(&self) -> Result<StakeDistributionDto, RestError> {
-        serde_json::from_str(&self.inner.stake_distribution()?)
-            .map_err(RestError::CannotDeserialize)
+        self.json(self.raw().stake_distribution())
     }
 
     pub fn account_votes(&self, address: Address) -> Result<Option<Vec<AccountVotes>>, RestError> {
-        serde_json::from_str(&self.inner.account_votes(address)?)
-            .map_err(RestError::CannotDeserialize)
+        self.json(self.raw().account_votes(address))
     }
 
     pub fn account_votes_all(&self) -> Result<HashMap<String, Vec<AccountVotes>>, RestError> {
-        serde_json::from_str(&self.inner.account_votes_all()?).map_err(RestError::CannotDeserialize)
+        self.json(self.raw().account_votes_all())
     }
 
     pub fn account_votes_with_plan_id(
@@ -149,30 +194,23 @@ impl JormungandrRest {
         vote_plan_id: VotePlanId,
         address: Address,
     ) -> Result<Option<Vec<u8>>, RestError> {
-        serde_json::from_str(
-            &self
-                .inner
-                .account_votes_with_plan_id(vote_plan_id, address)?,
-        )
-        .map_err(RestError::CannotDeserialize)
+        self.json(self.raw().account_votes_with_plan_id(vote_plan_id, address))
     }
 
     pub fn stake_pools(&self) -> Result<Vec<String>, RestError> {
-        serde_json::from_str(&self.inner.stake_pools()?).map_err(RestError::CannotDeserialize)
+        self.json(self.raw().stake_pools())
     }
 
     pub fn stake_distribution_at(&self, epoch: u32) -> Result<StakeDistributionDto, RestError> {
-        serde_json::from_str(&self.inner.stake_distribution_at(epoch)?)
-            .map_err(RestError::CannotDeserialize)
+        self.json(self.raw().stake_distribution_at(epoch))
     }
 
     pub fn stats(&self) -> Result<NodeStatsDto, RestError> {
-        let stats = &self.inner.stats()?;
-        serde_json::from_str(stats).map_err(RestError::CannotDeserialize)
+        self.json(self.raw().stats())
     }
 
     pub fn account_state(&self, id: &Identifier) -> Result<AccountState, RestError> {
-        serde_json::from_str(&self.inner.account_state(id)?).map_err(RestError::CannotDeserialize)
+        self.json(self.raw().account_state(id))
     }
 
     pub fn account_state_by_pk_raw(&self, bech32_str: &str) -> Result<String, RestError> {
@@ -182,44 +220,43 @@ impl JormungandrRest {
     }
 
     pub fn account_state_by_pk(&self, bech32_str: &str) -> Result<AccountState, RestError> {
-        serde_json::from_str(&self.inner.account_state_by_pk(bech32_str)?)
-            .map_err(RestError::CannotDeserialize)
+        self.json(self.raw().account_state_by_pk(bech32_str))
     }
 
     pub fn network_stats(&self) -> Result<Vec<PeerStats>, RestError> {
-        serde_json::from_str(&self.inner.network_stats()?).map_err(RestError::CannotDeserialize)
+        self.json(self.raw().network_stats())
     }
 
     pub fn p2p_quarantined(&self) -> Result<Vec<PeerRecord>, RestError> {
-        serde_json::from_str(&self.inner.p2p_quarantined()?).map_err(RestError::CannotDeserialize)
+        self.json(self.raw().p2p_quarantined())
     }
 
     pub fn p2p_non_public(&self) -> Result<Vec<PeerRecord>, RestError> {
-        serde_json::from_str(&self.inner.p2p_non_public()?).map_err(RestError::CannotDeserialize)
+        self.json(self.raw().p2p_non_public())
     }
 
     pub fn p2p_available(&self) -> Result<Vec<PeerRecord>, RestError> {
-        serde_json::from_str(&self.inner.p2p_available()?).map_err(RestError::CannotDeserialize)
+        self.json(self.raw().p2p_available())
     }
 
     pub fn p2p_view(&self) -> Result<Vec<String>, RestError> {
-        serde_json::from_str(&self.inner.p2p_view()?).map_err(RestError::CannotDeserialize)
+        self.json(self.raw().p2p_view())
     }
 
     #[cfg(feature = "evm")]
     pub fn evm_address(&self, jor_address: &JorAddress) -> Result<String, RestError> {
-        serde_json::from_str(&self.inner.evm_address(jor_address)?)
-            .map_err(RestError::CannotDeserialize)
+        self.json(self.raw().evm_address(jor_address))
     }
 
     #[cfg(feature = "evm")]
     pub fn jor_address(&self, evm_address: &EvmAddress) -> Result<String, RestError> {
-        serde_json::from_str(&self.inner.jor_address(evm_address)?)
-            .map_err(RestError::CannotDeserialize)
+        self.json(self.raw().jor_address(evm_address))
     }
 
     pub fn tip(&self) -> Result<Hash, RestError> {
-        self.inner.tip()
+        self.text(self.raw().tip())?
+            .parse()
+            .map_err(RestError::HashParseError)
     }
 
     pub fn fragment_logs(&self) -> Result<HashMap<FragmentId, FragmentLog>, RestError> {
@@ -227,19 +264,24 @@ impl JormungandrRest {
     }
 
     pub fn settings(&self) -> Result<SettingsDto, RestError> {
-        serde_json::from_str(&self.inner.settings()?).map_err(RestError::CannotDeserialize)
+        self.json(self.raw().settings())
     }
 
     pub fn leaders_log(&self) -> Result<Vec<LeadershipLog>, RestError> {
-        serde_json::from_str(&self.inner.leaders_log()?).map_err(RestError::CannotDeserialize)
+        self.json(self.raw().leaders_log())
     }
 
     pub fn send_fragment(&self, fragment: Fragment) -> Result<MemPoolCheck, RestError> {
         self.inner.send_fragment(fragment).map_err(Into::into)
     }
 
+    /// Sends the fragment, a server error is reported as
+    /// [`RestError::HttpStatus`] while a rejected fragment is not an error
     pub fn send_raw_fragment(&self, bytes: Vec<u8>) -> Result<(), RestError> {
-        self.inner.send_raw_fragment(bytes)?;
+        let response = self.inner.send_raw_fragment(bytes)?;
+        if response.status().is_server_error() {
+            response.error_for_status()?;
+        }
         Ok(())
     }
 
@@ -255,6 +297,10 @@ impl JormungandrRest {
         self.inner.shutdown().map_err(Into::into)
     }
 
+    pub fn shutdown_graceful(&self, timeout: Duration) -> Result<String, RestError> {
+        self.inner.shutdown_graceful(timeout).map_err(Into::into)
+    }
+
     pub fn block(&self, header_hash: &HeaderId) -> Result<Block, RestError> {
         let bytes = self.block_as_bytes(header_hash)?;
         <Block as chain_core::property::DeserializeFromSlice>::deserialize_from_slice(
@@ -281,8 +327,7 @@ impl JormungandrRest {
     }
 
     pub fn vote_plan_statuses(&self) -> Result<Vec<VotePlanStatus>, RestError> {
-        serde_json::from_str(&self.inner.vote_plan_statuses()?)
-            .map_err(RestError::CannotDeserialize)
+        self.json(self.raw().vote_plan_statuses())
     }
 
     pub fn set_origin<S: Into<String>>(&mut self, origin: S) {
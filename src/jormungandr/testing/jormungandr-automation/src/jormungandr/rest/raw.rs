@@ -10,14 +10,19 @@ use chain_impl_mockchain::account::Identifier as JorAddress;
 use chain_impl_mockchain::{account, fragment::Fragment, header::HeaderId};
 use jormungandr_lib::{
     crypto::account::Identifier,
-    interfaces::{Address, FragmentsBatch, VotePlanId},
+    interfaces::{Address, FragmentsBatch, MessageBatch, VotePlanId},
 };
 use jortestkit::process::Wait;
 use reqwest::{
-    blocking::{Client, Response},
+    blocking::{Client, RequestBuilder, Response},
     header::{HeaderMap, HeaderValue, CONTENT_TYPE},
+    Method, StatusCode,
+};
+use std::{
+    fmt,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
 };
-use std::fmt;
 
 const ORIGIN: &str = "Origin";
 enum ApiVersion {
@@ -34,6 +39,17 @@ impl fmt::Display for ApiVersion {
     }
 }
 
+/// Duration of a request, including all its retries
+#[derive(Debug, Clone)]
+pub struct RequestTiming {
+    pub method: Method,
+    pub url: String,
+    pub attempts: u32,
+    pub duration: Duration,
+    /// status of the last response, `None` if no response was received
+    pub status: Option<StatusCode>,
+}
+
 /// struct intends to return raw reqwest response
 /// can be used to verify requests error codes or
 /// to poll until data is available
@@ -42,6 +58,7 @@ pub struct RawRest {
     uri: String,
     client: Client,
     settings: RestSettings,
+    timings: Arc<Mutex<Vec<RequestTiming>>>,
 }
 
 impl RawRest {
@@ -69,6 +86,7 @@ impl RawRest {
             uri,
             client,
             settings,
+            timings: Default::default(),
         }
     }
 
@@ -92,6 +110,65 @@ impl RawRest {
         self.rest_settings_mut().enable_debug = false;
     }
 
+    /// Timings of the requests sent so far, recorded only if enabled in settings.
+    /// Clones of this client share the same records.
+    pub fn timings(&self) -> Vec<RequestTiming> {
+        self.timings.lock().unwrap().clone()
+    }
+
+    pub fn clear_timings(&self) {
+        self.timings.lock().unwrap().clear();
+    }
+
+    /// Sends the request, retrying it according to the retry policy from settings
+    fn send(&self, request: RequestBuilder) -> Result<Response, reqwest::Error> {
+        let started = Instant::now();
+        let mut attempts = 1;
+        let mut result = Self::try_send(&request);
+        if let Some(policy) = self.settings.retry {
+            let idempotent = request
+                .try_clone()
+                .and_then(|request| request.build().ok())
+                .map_or(false, |built| built.method() == Method::GET);
+            while attempts < policy.max_attempts && Self::is_retryable(&result, idempotent) {
+                std::thread::sleep(policy.backoff(attempts));
+                attempts += 1;
+                result = Self::try_send(&request);
+            }
+        }
+
+        if self.settings.capture_timings {
+            if let Some(built) = request.try_clone().and_then(|request| request.build().ok()) {
+                self.timings.lock().unwrap().push(RequestTiming {
+                    method: built.method().clone(),
+                    url: built.url().to_string(),
+                    attempts,
+                    duration: started.elapsed(),
+                    status: result.as_ref().ok().map(Response::status),
+                });
+            }
+        }
+        result
+    }
+
+    fn try_send(request: &RequestBuilder) -> Result<Response, reqwest::Error> {
+        request
+            .try_clone()
+            .expect("requests with streaming bodies are not supported")
+            .send()
+    }
+
+    /// Only GET requests are retried on timeouts and server errors, since the node
+    /// may have processed the request already: a fragment sent again would
+    /// be reported as already in the log. Any request can be retried when the
+    /// connection could not be established, as it never reached the node.
+    fn is_retryable(result: &Result<Response, reqwest::Error>, idempotent: bool) -> bool {
+        match result {
+            Ok(response) => idempotent && response.status().is_server_error(),
+            Err(error) => error.is_connect() || (idempotent && error.is_timeout()),
+        }
+    }
+
     pub fn epoch_reward_history(&self, epoch: u32) -> Result<Response, reqwest::Error> {
         let request = format!("rewards/epoch/{}", epoch);
         self.get(&request)
@@ -147,7 +224,7 @@ impl RawRest {
             res = res.header(ORIGIN, origin.to_string());
         }
 
-        res.send()
+        self.send(res)
     }
 
     fn path(&self, api_version: ApiVersion, path: &str) -> String {
@@ -183,7 +260,7 @@ impl RawRest {
         let key = hex::encode(account::Identifier::from(pk.clone()).as_ref());
 
         let request = format!("votes/plan/account-votes/{}", key);
-        self.client.get(self.path(ApiVersion::V1, &request)).send()
+        self.send(self.client.get(self.path(ApiVersion::V1, &request)))
     }
 
     pub fn account_votes_with_plan_id(
@@ -195,7 +272,7 @@ impl RawRest {
         let key = hex::encode(account::Identifier::from(pk.clone()).as_ref());
 
         let request = format!("votes/plan/{}/account-votes/{}", vote_plan_id, key);
-        self.client.get(self.path(ApiVersion::V1, &request)).send()
+        self.send(self.client.get(self.path(ApiVersion::V1, &request)))
     }
 
     pub fn stake_distribution_at(&self, epoch: u32) -> Result<Response, reqwest::Error> {
@@ -204,9 +281,10 @@ impl RawRest {
     }
 
     pub fn account_votes_all(&self) -> Result<Response, reqwest::Error> {
-        self.client
-            .get(self.path(ApiVersion::V1, "votes/plan/accounts-votes-all"))
-            .send()
+        self.send(
+            self.client
+                .get(self.path(ApiVersion::V1, "votes/plan/accounts-votes-all")),
+        )
     }
 
     pub fn stats(&self) -> Result<Response, reqwest::Error> {
@@ -249,6 +327,15 @@ impl RawRest {
         self.get("shutdown")
     }
 
+    /// Stops accepting fragments and connections, then waits up to `timeout`
+    /// for the blocks in flight before the node exits
+    pub fn shutdown_graceful(&self, timeout: Duration) -> Result<Response, reqwest::Error> {
+        self.get(&format!(
+            "shutdown?mode=graceful&timeout={}",
+            timeout.as_secs()
+        ))
+    }
+
     pub fn block(&self, header_hash: &HeaderId) -> Result<Response, reqwest::Error> {
         self.get(&format!("block/{}", header_hash))
     }
@@ -271,11 +358,12 @@ impl RawRest {
         path: &str,
         body: Vec<u8>,
     ) -> Result<reqwest::blocking::Response, reqwest::Error> {
-        self.client
-            .post(self.path(ApiVersion::V0, path))
-            .headers(self.construct_headers())
-            .body(body)
-            .send()
+        self.send(
+            self.client
+                .post(self.path(ApiVersion::V0, path))
+                .headers(self.construct_headers())
+                .body(body),
+        )
     }
 
     pub fn send_fragment(&self, fragment: Fragment) -> Result<Response, reqwest::Error> {
@@ -302,22 +390,21 @@ impl RawRest {
             .collect();
 
         for client in clients {
-            client.send()?;
+            self.send(client)?;
         }
         Ok(())
     }
 
     pub fn fragments_logs(&self) -> Result<Response, reqwest::Error> {
-        self.client
-            .get(self.path(ApiVersion::V1, "fragments/logs"))
-            .send()
+        self.send(self.client.get(self.path(ApiVersion::V1, "fragments/logs")))
     }
 
     pub fn fragments_statuses(&self, ids: Vec<String>) -> Result<Response, reqwest::Error> {
-        self.client
-            .get(self.path(ApiVersion::V1, "fragments/statuses"))
-            .query(&[("fragment_ids", ids.join(","))])
-            .send()
+        self.send(
+            self.client
+                .get(self.path(ApiVersion::V1, "fragments/statuses"))
+                .query(&[("fragment_ids", ids.join(","))]),
+        )
     }
 
     pub fn send_fragment_batch(
@@ -325,14 +412,31 @@ impl RawRest {
         fragments: Vec<Fragment>,
         fail_fast: bool,
     ) -> Result<Response, reqwest::Error> {
-        self.client
-            .post(self.path(ApiVersion::V1, "fragments"))
-            .headers(self.construct_headers())
-            .json(&FragmentsBatch {
-                fail_fast,
-                fragments,
-            })
-            .send()
+        self.send(
+            self.client
+                .post(self.path(ApiVersion::V1, "fragments"))
+                .headers(self.construct_headers())
+                .json(&FragmentsBatch {
+                    fail_fast,
+                    fragments,
+                }),
+        )
+    }
+
+    pub fn send_message_batch(
+        &self,
+        fragments: Vec<Fragment>,
+        all_or_nothing: bool,
+    ) -> Result<Response, reqwest::Error> {
+        self.send(
+            self.client
+                .post(self.path(ApiVersion::V0, "message/batch"))
+                .headers(self.construct_headers())
+                .json(&MessageBatch {
+                    all_or_nothing,
+                    fragments,
+                }),
+        )
     }
 
     pub fn vote_plan_statuses(&self) -> Result<Response, reqwest::Error> {
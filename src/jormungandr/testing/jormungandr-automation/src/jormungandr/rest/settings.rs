@@ -1,4 +1,5 @@
 use reqwest::Certificate;
+use std::time::Duration;
 
 #[derive(Debug, Clone, Default)]
 pub struct RestSettings {
@@ -6,6 +7,11 @@ pub struct RestSettings {
     pub use_https: bool,
     pub certificate: Option<Certificate>,
     pub cors: Option<String>,
+    /// Retry requests which failed to connect, and GET requests which timed out
+    /// or got a server error. Requests are sent only once if not set.
+    pub retry: Option<RetryPolicy>,
+    /// Record the duration of every request, see [`super::RawRest::timings`]
+    pub capture_timings: bool,
 }
 
 impl RestSettings {
@@ -15,6 +21,83 @@ impl RestSettings {
             use_https: true,
             certificate: None,
             cors: None,
+            retry: None,
+            capture_timings: false,
         }
     }
+
+    pub fn with_retry(mut self, retry: RetryPolicy) -> Self {
+        self.retry = Some(retry);
+        self
+    }
+
+    pub fn with_timings(mut self) -> Self {
+        self.capture_timings = true;
+        self
+    }
+}
+
+/// Exponential backoff between the attempts of a request
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Total number of attempts, including the first one
+    pub max_attempts: u32,
+    /// Delay before the first retry, doubled for every subsequent one
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 5,
+            initial_backoff: Duration::from_millis(200),
+            max_backoff: Duration::from_secs(5),
+        }
+    }
+}
+
+impl RetryPolicy {
+    pub fn new(max_attempts: u32) -> Self {
+        Self {
+            max_attempts,
+            ..Default::default()
+        }
+    }
+
+    pub fn initial_backoff(mut self, initial_backoff: Duration) -> Self {
+        self.initial_backoff = initial_backoff;
+        self
+    }
+
+    pub fn max_backoff(mut self, max_backoff: Duration) -> Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// Delay to wait after the given failed attempt, counting from 1
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        self.initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff_is_exponential_and_capped() {
+        let policy = RetryPolicy::new(10)
+            .initial_backoff(Duration::from_millis(100))
+            .max_backoff(Duration::from_millis(700));
+
+        assert_eq!(policy.backoff(1), Duration::from_millis(100));
+        assert_eq!(policy.backoff(2), Duration::from_millis(200));
+        assert_eq!(policy.backoff(3), Duration::from_millis(400));
+        assert_eq!(policy.backoff(4), Duration::from_millis(700));
+        assert_eq!(policy.backoff(40), Duration::from_millis(700));
+    }
 }
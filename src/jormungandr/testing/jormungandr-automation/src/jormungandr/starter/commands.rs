@@ -1,10 +1,11 @@
 #![allow(dead_code)]
 use crate::jormungandr::{
-    starter::{JormungandrParams, NodeBlock0},
+    starter::{JormungandrParams, NodeBlock0, NodeWrapper},
     FaketimeConfig, LeadershipMode,
 };
 use jormungandr_lib::crypto::hash::Hash;
 use std::{
+    ffi::OsString,
     path::{Path, PathBuf},
     process::Command,
 };
@@ -17,6 +18,7 @@ pub struct CommandBuilder {
     log_file: Option<PathBuf>,
     rewards_history: bool,
     faketime: Option<FaketimeConfig>,
+    wrapper: Option<(NodeWrapper, PathBuf)>,
 }
 
 impl CommandBuilder {
@@ -29,6 +31,7 @@ impl CommandBuilder {
             log_file: None,
             rewards_history: false,
             faketime: None,
+            wrapper: None,
         }
     }
 
@@ -42,6 +45,12 @@ impl CommandBuilder {
         self
     }
 
+    /// Run the node under the given tool, which writes its outputs in `output_dir`
+    pub fn wrapper(mut self, wrapper: NodeWrapper, output_dir: &Path) -> Self {
+        self.wrapper = Some((wrapper, output_dir.to_path_buf()));
+        self
+    }
+
     pub fn genesis_block_hash(mut self, hash: Hash) -> Self {
         self.genesis_block = Some(NodeBlock0::Hash(hash));
         self
@@ -68,14 +77,19 @@ impl CommandBuilder {
     }
 
     pub fn command(self) -> Command {
-        let mut command = if let Some(faketime) = &self.faketime {
-            let mut cmd = Command::new("faketime");
-            cmd.args(["-f", &format!("{:+}s", faketime.offset)]);
-            cmd.arg(self.bin);
-            cmd
-        } else {
-            Command::new(self.bin)
-        };
+        let mut program: Vec<OsString> = Vec::new();
+        if let Some(faketime) = &self.faketime {
+            program.push("faketime".into());
+            program.push("-f".into());
+            program.push(format!("{:+}s", faketime.offset).into());
+        }
+        if let Some((wrapper, output_dir)) = &self.wrapper {
+            program.extend(wrapper.prefix(output_dir));
+        }
+        program.push(self.bin.into_os_string());
+
+        let mut command = Command::new(&program[0]);
+        command.args(&program[1..]);
 
         if let Some(secret_path) = self.secret {
             command.arg("--secret").arg(secret_path);
@@ -114,11 +128,29 @@ pub fn get_command(
     params: &JormungandrParams,
     bin_path: impl AsRef<Path>,
     leadership_mode: LeadershipMode,
+) -> Command {
+    get_wrapped_command(params, bin_path, leadership_mode, None)
+}
+
+/// Same as [`get_command`], optionally running the node under `wrapper`. Outputs of the
+/// wrapper are written next to the node configuration file.
+pub fn get_wrapped_command(
+    params: &JormungandrParams,
+    bin_path: impl AsRef<Path>,
+    leadership_mode: LeadershipMode,
+    wrapper: Option<&NodeWrapper>,
 ) -> Command {
     let bin_path = bin_path.as_ref();
     let node_config_path = params.node_config_path();
     let secret_path = params.secret_path();
-    let builder = CommandBuilder::new(bin_path).config(&node_config_path);
+    let mut builder = CommandBuilder::new(bin_path).config(&node_config_path);
+    if let Some(wrapper) = wrapper {
+        let output_dir = node_config_path
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_default();
+        builder = builder.wrapper(wrapper.clone(), &output_dir);
+    }
 
     let builder = match (leadership_mode, params.genesis()) {
         (LeadershipMode::Passive, NodeBlock0::Hash(hash)) => builder.genesis_block_hash(*hash),
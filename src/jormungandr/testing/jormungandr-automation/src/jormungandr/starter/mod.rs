@@ -1,10 +1,11 @@
 #![allow(dead_code)]
 
 mod commands;
-pub use commands::{get_command, CommandBuilder};
+pub use commands::{get_command, get_wrapped_command, CommandBuilder};
 
 mod params;
 mod testing_directory;
+mod wrapper;
 
 use crate::{
     jormungandr::{legacy::LegacyConfigError, JormungandrError, JormungandrProcess, RestError},
@@ -26,6 +27,7 @@ use std::{
 };
 pub use testing_directory::TestingDirectory;
 use thiserror::Error;
+pub use wrapper::{NodeWrapper, NodeWrapperError};
 
 #[derive(Debug, Error)]
 pub enum StartupError {
@@ -108,6 +110,7 @@ pub struct Starter {
     temp_dir: Option<TestingDirectory>,
     config: Option<JormungandrParams>,
     benchmark: Option<SpeedBenchmarkDef>,
+    wrapper: Option<NodeWrapper>,
 }
 
 impl Default for Starter {
@@ -121,6 +124,7 @@ impl Default for Starter {
             config: None,
             benchmark: None,
             jormungandr_app_path: None,
+            wrapper: None,
         }
     }
 }
@@ -140,6 +144,20 @@ impl Starter {
         self
     }
 
+    /// Run the node under a tool like valgrind or heaptrack. Startup timeout is scaled
+    /// by the tool slowdown, so it should be set after [`Starter::timeout`].
+    pub fn wrapper(self, wrapper: NodeWrapper) -> Self {
+        self.wrapper_option(Some(wrapper))
+    }
+
+    pub fn wrapper_option(mut self, wrapper: Option<NodeWrapper>) -> Self {
+        if let Some(wrapper) = &wrapper {
+            self.configured_starter.timeout *= wrapper.slowdown_factor();
+        }
+        self.wrapper = wrapper;
+        self
+    }
+
     pub fn timeout(mut self, timeout: Duration) -> Self {
         self.configured_starter.timeout = timeout;
         self
@@ -212,7 +230,7 @@ impl Starter {
             comm,
             temp_dir,
             expected_msg_in_logs,
-            get_command(&params, app, params.leadership()),
+            get_wrapped_command(&params, app, params.leadership(), self.wrapper.as_ref()),
         )
     }
 
@@ -224,7 +242,7 @@ impl Starter {
         self.configured_starter.start_async(
             comm,
             temp_dir,
-            get_command(&params, app, params.leadership()),
+            get_wrapped_command(&params, app, params.leadership(), self.wrapper.as_ref()),
         )
     }
 
@@ -233,7 +251,7 @@ impl Starter {
         let temp_dir = self.temp_dir.take();
         let benchmark = self.start_benchmark_run();
         let params = self.config.ok_or(StartupError::StartParamsNotDefined)?;
-        let command = get_command(&params, app, params.leadership());
+        let command = get_wrapped_command(&params, app, params.leadership(), self.wrapper.as_ref());
         let process = self.configured_starter.start(params, temp_dir, command)?;
         finish_benchmark(benchmark);
         Ok(process)
@@ -242,7 +260,7 @@ impl Starter {
     pub fn start_should_fail_with_message(self, expected_msg: &str) -> Result<(), StartupError> {
         let app = self.get_jormungandr_app_path();
         let params = self.config.ok_or(StartupError::StartParamsNotDefined)?;
-        let command = get_command(&params, app, params.leadership());
+        let command = get_wrapped_command(&params, app, params.leadership(), self.wrapper.as_ref());
         ConfiguredStarter::new(&self.alias).start_with_fail_in_stderr(command, expected_msg);
         Ok(())
     }
@@ -0,0 +1,117 @@
+use serde::Deserialize;
+use std::{
+    ffi::OsString,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum NodeWrapperError {
+    #[error("custom node wrapper needs at least a program to run")]
+    EmptyCustomWrapper,
+}
+
+/// Tool the node is run under, to hunt memory leaks or profile long running nodes.
+/// Outputs of the tools are stored in the node working directory.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NodeWrapper {
+    /// `valgrind --leak-check=full`, writes `valgrind.<pid>.log`
+    Valgrind,
+    /// `heaptrack`, writes `heaptrack.<pid>.gz` (or `.zst`)
+    Heaptrack,
+    /// `perf record -g`, writes `perf.data`
+    Perf,
+    /// Any other prefix, e.g. `["strace", "-f"]`. Output files are not managed.
+    Custom(Vec<String>),
+}
+
+impl NodeWrapper {
+    /// Arguments prepended to the node command, outputs are written in `output_dir`
+    pub fn prefix(&self, output_dir: &Path) -> Vec<OsString> {
+        match self {
+            Self::Valgrind => vec![
+                "valgrind".into(),
+                "--leak-check=full".into(),
+                path_arg("--log-file=", output_dir.join("valgrind.%p.log")),
+            ],
+            Self::Heaptrack => vec![
+                "heaptrack".into(),
+                "-o".into(),
+                output_dir.join("heaptrack").into(),
+            ],
+            Self::Perf => vec![
+                "perf".into(),
+                "record".into(),
+                "-g".into(),
+                "-o".into(),
+                output_dir.join("perf.data").into(),
+            ],
+            Self::Custom(args) => args.iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Node runs slower under the tool, so startup timeouts need to be scaled accordingly
+    pub fn slowdown_factor(&self) -> u32 {
+        match self {
+            Self::Valgrind => 20,
+            Self::Heaptrack => 2,
+            Self::Perf | Self::Custom(_) => 1,
+        }
+    }
+}
+
+fn path_arg(flag: &str, path: PathBuf) -> OsString {
+    let mut arg = OsString::from(flag);
+    arg.push(path);
+    arg
+}
+
+impl FromStr for NodeWrapper {
+    type Err = NodeWrapperError;
+
+    /// Parses `valgrind`, `heaptrack`, `perf` or any whitespace separated command prefix
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "valgrind" => Ok(Self::Valgrind),
+            "heaptrack" => Ok(Self::Heaptrack),
+            "perf" => Ok(Self::Perf),
+            other => {
+                let args: Vec<String> = other.split_whitespace().map(str::to_owned).collect();
+                if args.is_empty() {
+                    return Err(NodeWrapperError::EmptyCustomWrapper);
+                }
+                Ok(Self::Custom(args))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_node_wrapper() {
+        assert_eq!(
+            "valgrind".parse::<NodeWrapper>().unwrap(),
+            NodeWrapper::Valgrind
+        );
+        assert_eq!(
+            "strace -f".parse::<NodeWrapper>().unwrap(),
+            NodeWrapper::Custom(vec!["strace".to_owned(), "-f".to_owned()])
+        );
+        assert!(" ".parse::<NodeWrapper>().is_err());
+    }
+
+    #[test]
+    fn valgrind_writes_log_in_output_dir() {
+        let prefix = NodeWrapper::Valgrind.prefix(Path::new("/tmp/node"));
+        assert_eq!(prefix[0], OsString::from("valgrind"));
+        assert_eq!(
+            prefix.last().unwrap(),
+            &OsString::from("--log-file=/tmp/node/valgrind.%p.log")
+        );
+    }
+}
@@ -0,0 +1,277 @@
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    fs::File,
+    path::{Path, PathBuf},
+};
+use thiserror::Error;
+
+/// Minimal amount of stored samples before a metric is gated.
+pub const DEFAULT_MIN_HISTORY: usize = 5;
+/// Only the latest samples are kept, so baselines follow intended changes.
+pub const DEFAULT_MAX_HISTORY: usize = 30;
+/// Samples more than 3 standard deviations above the mean are regressions.
+pub const DEFAULT_Z_SCORE_THRESHOLD: f64 = 3.0;
+
+#[derive(Debug, Error)]
+pub enum BaselineError {
+    #[error("cannot access baseline file")]
+    Io(#[from] std::io::Error),
+    #[error("cannot (de)serialize baseline file")]
+    Serde(#[from] serde_json::Error),
+    #[error("regression detected for metric '{metric}' of topology '{topology}': {value} vs mean {mean} (std dev: {std_dev}, z-score: {z_score})")]
+    Regression {
+        topology: String,
+        metric: String,
+        value: f64,
+        mean: f64,
+        std_dev: f64,
+        z_score: f64,
+    },
+}
+
+/// Outcome of comparing a sample against the stored history
+#[derive(Debug, Clone, PartialEq)]
+pub enum BaselineVerdict {
+    /// not enough samples collected yet to judge the new one
+    NotEnoughHistory {
+        samples: usize,
+    },
+    Pass {
+        z_score: f64,
+    },
+    Regression {
+        mean: f64,
+        std_dev: f64,
+        z_score: f64,
+    },
+}
+
+/// History of metrics (sync time, propagation latency...) collected from passing runs,
+/// grouped by topology name. For all metrics a lower value is better.
+///
+/// Node resource usage (e.g. CPU) is not collected by the scenarios yet, so only
+/// timing metrics are currently gated.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Baseline {
+    topologies: HashMap<String, HashMap<String, Vec<f64>>>,
+}
+
+impl Baseline {
+    /// Loads the baseline from file, or returns an empty one if the file does not exist yet
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, BaselineError> {
+        if !path.as_ref().exists() {
+            return Ok(Self::default());
+        }
+        Ok(serde_json::from_reader(File::open(path)?)?)
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), BaselineError> {
+        if let Some(parent) = path.as_ref().parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        serde_json::to_writer_pretty(File::create(path)?, self)?;
+        Ok(())
+    }
+
+    pub fn history(&self, topology: &str, metric: &str) -> &[f64] {
+        self.topologies
+            .get(topology)
+            .and_then(|metrics| metrics.get(metric))
+            .map(Vec::as_slice)
+            .unwrap_or(&[])
+    }
+
+    /// Appends sample to metric history, dropping the oldest samples above `max_history`
+    pub fn record(&mut self, topology: &str, metric: &str, value: f64, max_history: usize) {
+        let history = self
+            .topologies
+            .entry(topology.to_string())
+            .or_default()
+            .entry(metric.to_string())
+            .or_default();
+        history.push(value);
+        if history.len() > max_history {
+            history.drain(..history.len() - max_history);
+        }
+    }
+
+    pub fn compare(
+        &self,
+        topology: &str,
+        metric: &str,
+        value: f64,
+        min_history: usize,
+        z_score_threshold: f64,
+    ) -> BaselineVerdict {
+        let history = self.history(topology, metric);
+        if history.len() < min_history.max(2) {
+            return BaselineVerdict::NotEnoughHistory {
+                samples: history.len(),
+            };
+        }
+
+        let count = history.len() as f64;
+        let mean = history.iter().sum::<f64>() / count;
+        let variance = history.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (count - 1.0);
+        let std_dev = variance.sqrt();
+
+        let z_score = if std_dev > 0.0 {
+            (value - mean) / std_dev
+        } else if value > mean {
+            f64::INFINITY
+        } else {
+            0.0
+        };
+
+        if z_score > z_score_threshold {
+            BaselineVerdict::Regression {
+                mean,
+                std_dev,
+                z_score,
+            }
+        } else {
+            BaselineVerdict::Pass { z_score }
+        }
+    }
+}
+
+/// Regression gate backed by a baseline file. Samples are checked against the stored
+/// history and, when they pass, appended to it on [`RegressionGate::finish`].
+pub struct RegressionGate {
+    path: PathBuf,
+    topology: String,
+    baseline: Baseline,
+    min_history: usize,
+    max_history: usize,
+    z_score_threshold: f64,
+    samples: Vec<(String, f64)>,
+}
+
+impl RegressionGate {
+    pub fn new<P: AsRef<Path>, S: Into<String>>(
+        path: P,
+        topology: S,
+    ) -> Result<Self, BaselineError> {
+        Ok(Self {
+            baseline: Baseline::load(&path)?,
+            path: path.as_ref().to_path_buf(),
+            topology: topology.into(),
+            min_history: DEFAULT_MIN_HISTORY,
+            max_history: DEFAULT_MAX_HISTORY,
+            z_score_threshold: DEFAULT_Z_SCORE_THRESHOLD,
+            samples: Vec::new(),
+        })
+    }
+
+    pub fn with_min_history(mut self, min_history: usize) -> Self {
+        self.min_history = min_history;
+        self
+    }
+
+    pub fn with_max_history(mut self, max_history: usize) -> Self {
+        self.max_history = max_history;
+        self
+    }
+
+    pub fn with_z_score_threshold(mut self, z_score_threshold: f64) -> Self {
+        self.z_score_threshold = z_score_threshold;
+        self
+    }
+
+    /// Checks sample against the baseline, failing on a statistically significant regression
+    pub fn check<S: Into<String>>(&mut self, metric: S, value: f64) -> Result<(), BaselineError> {
+        let metric = metric.into();
+        let verdict = self.baseline.compare(
+            &self.topology,
+            &metric,
+            value,
+            self.min_history,
+            self.z_score_threshold,
+        );
+        tracing::debug!(
+            "baseline check [{}] {} = {}: {:?}",
+            self.topology,
+            metric,
+            value,
+            verdict
+        );
+
+        if let BaselineVerdict::Regression {
+            mean,
+            std_dev,
+            z_score,
+        } = verdict
+        {
+            return Err(BaselineError::Regression {
+                topology: self.topology.clone(),
+                metric,
+                value,
+                mean,
+                std_dev,
+                z_score,
+            });
+        }
+        self.samples.push((metric, value));
+        Ok(())
+    }
+
+    /// Stores all checked samples as new baseline history. Should be called only
+    /// when the whole run passed, so failing runs do not skew the baseline.
+    pub fn finish(mut self) -> Result<(), BaselineError> {
+        for (metric, value) in self.samples.drain(..) {
+            self.baseline
+                .record(&self.topology, &metric, value, self.max_history);
+        }
+        self.baseline.save(&self.path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn baseline_with(samples: &[f64]) -> Baseline {
+        let mut baseline = Baseline::default();
+        for sample in samples {
+            baseline.record("star", "sync_time", *sample, DEFAULT_MAX_HISTORY);
+        }
+        baseline
+    }
+
+    #[test]
+    fn not_enough_history_is_not_gated() {
+        let baseline = baseline_with(&[10.0, 11.0]);
+        assert_eq!(
+            baseline.compare("star", "sync_time", 100.0, DEFAULT_MIN_HISTORY, 3.0),
+            BaselineVerdict::NotEnoughHistory { samples: 2 }
+        );
+    }
+
+    #[test]
+    fn outlier_is_regression() {
+        let baseline = baseline_with(&[10.0, 11.0, 9.0, 10.5, 9.5]);
+        assert!(matches!(
+            baseline.compare("star", "sync_time", 10.2, DEFAULT_MIN_HISTORY, 3.0),
+            BaselineVerdict::Pass { .. }
+        ));
+        assert!(matches!(
+            baseline.compare("star", "sync_time", 20.0, DEFAULT_MIN_HISTORY, 3.0),
+            BaselineVerdict::Regression { .. }
+        ));
+        // improvements are never flagged
+        assert!(matches!(
+            baseline.compare("star", "sync_time", 1.0, DEFAULT_MIN_HISTORY, 3.0),
+            BaselineVerdict::Pass { .. }
+        ));
+    }
+
+    #[test]
+    fn history_is_capped() {
+        let mut baseline = Baseline::default();
+        for i in 0..10 {
+            baseline.record("star", "cpu", i as f64, 3);
+        }
+        assert_eq!(baseline.history("star", "cpu"), &[7.0, 8.0, 9.0]);
+    }
+}
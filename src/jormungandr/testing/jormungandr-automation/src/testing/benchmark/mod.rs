@@ -1,5 +1,7 @@
+pub mod baseline;
 pub mod node;
 pub mod sync;
 
+pub use baseline::{Baseline, BaselineError, BaselineVerdict, RegressionGate};
 pub use node::{speed_benchmark_from_log, storage_loading_benchmark_from_log};
 pub use sync::*;
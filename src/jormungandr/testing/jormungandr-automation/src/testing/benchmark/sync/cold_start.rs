@@ -0,0 +1,223 @@
+use super::SyncNode;
+use serde::{Deserialize, Serialize};
+use std::{
+    fs::File,
+    path::Path,
+    time::{Duration, Instant},
+};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ColdStartSyncError {
+    #[error("node '{alias}' reached block height {block_height} out of {target_block_height} before timeout '{timeout:?}'")]
+    Timeout {
+        alias: String,
+        block_height: u32,
+        target_block_height: u32,
+        timeout: Duration,
+    },
+    #[error("node '{alias}' stopped while syncing at block height {block_height}")]
+    NodeStopped { alias: String, block_height: u32 },
+    #[error("node '{alias}' synced at {blocks_per_sec:.2} blocks/s, below the minimum of {min_blocks_per_sec:.2} blocks/s")]
+    TooSlow {
+        alias: String,
+        blocks_per_sec: f64,
+        min_blocks_per_sec: f64,
+    },
+    #[error("cannot access report file")]
+    Io(#[from] std::io::Error),
+    #[error("cannot serialize report")]
+    Serde(#[from] serde_json::Error),
+}
+
+/// Block height of the syncing node at a point of the measurement
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SyncSample {
+    pub elapsed_secs: f64,
+    pub block_height: u32,
+    /// sync speed since the previous sample
+    pub blocks_per_sec: f64,
+}
+
+/// Timings of a node syncing from scratch, exportable to json
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ColdStartSyncReport {
+    pub alias: String,
+    pub target_block_height: u32,
+    pub duration_secs: f64,
+    /// average sync speed over the whole measurement
+    pub blocks_per_sec: f64,
+    pub samples: Vec<SyncSample>,
+}
+
+impl ColdStartSyncReport {
+    fn new(alias: String, target_block_height: u32, heights: &[(Duration, u32)]) -> Self {
+        let mut previous = (Duration::ZERO, 0);
+        let samples = heights
+            .iter()
+            .map(|&(elapsed, block_height)| {
+                let sample = SyncSample {
+                    elapsed_secs: elapsed.as_secs_f64(),
+                    block_height,
+                    blocks_per_sec: blocks_per_sec(
+                        block_height.saturating_sub(previous.1),
+                        elapsed.saturating_sub(previous.0),
+                    ),
+                };
+                previous = (elapsed, block_height);
+                sample
+            })
+            .collect();
+        let (duration, block_height) = heights.last().copied().unwrap_or_default();
+
+        Self {
+            alias,
+            target_block_height,
+            duration_secs: duration.as_secs_f64(),
+            blocks_per_sec: blocks_per_sec(block_height, duration),
+            samples,
+        }
+    }
+
+    pub fn export<P: AsRef<Path>>(&self, path: P) -> Result<(), ColdStartSyncError> {
+        if let Some(parent) = path.as_ref().parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        serde_json::to_writer_pretty(File::create(path)?, self)?;
+        Ok(())
+    }
+
+    /// Fails if the average sync speed is below `min_blocks_per_sec`
+    pub fn assert_min_blocks_per_sec(
+        &self,
+        min_blocks_per_sec: f64,
+    ) -> Result<(), ColdStartSyncError> {
+        if self.blocks_per_sec < min_blocks_per_sec {
+            return Err(ColdStartSyncError::TooSlow {
+                alias: self.alias.clone(),
+                blocks_per_sec: self.blocks_per_sec,
+                min_blocks_per_sec,
+            });
+        }
+        Ok(())
+    }
+}
+
+fn blocks_per_sec(blocks: u32, duration: Duration) -> f64 {
+    if duration.is_zero() {
+        return 0.0;
+    }
+    blocks as f64 / duration.as_secs_f64()
+}
+
+/// Measures how long a fresh node takes to sync up to a given block height.
+///
+/// The clock starts with [`ColdStartSync::start`], which should be called right
+/// before spawning the node, so that bootstrap is part of the measurement.
+#[derive(Debug, Clone)]
+pub struct ColdStartSync {
+    target_block_height: u32,
+    sample_interval: Duration,
+    timeout: Duration,
+}
+
+impl ColdStartSync {
+    pub fn new(target_block_height: u32) -> Self {
+        Self {
+            target_block_height,
+            sample_interval: Duration::from_secs(1),
+            timeout: Duration::from_secs(3600),
+        }
+    }
+
+    pub fn sample_interval(mut self, sample_interval: Duration) -> Self {
+        self.sample_interval = sample_interval;
+        self
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    pub fn start(&self) -> ColdStartSyncMeasurement {
+        ColdStartSyncMeasurement {
+            definition: self.clone(),
+            started: Instant::now(),
+        }
+    }
+}
+
+pub struct ColdStartSyncMeasurement {
+    definition: ColdStartSync,
+    started: Instant,
+}
+
+impl ColdStartSyncMeasurement {
+    /// Samples the block height of the node until it reaches the target
+    pub fn wait_for_sync<A: SyncNode + ?Sized>(
+        self,
+        node: &A,
+    ) -> Result<ColdStartSyncReport, ColdStartSyncError> {
+        let target_block_height = self.definition.target_block_height;
+        let mut heights = Vec::new();
+
+        loop {
+            if !node.is_running() {
+                return Err(ColdStartSyncError::NodeStopped {
+                    alias: node.alias(),
+                    block_height: heights.last().map(|(_, height)| *height).unwrap_or(0),
+                });
+            }
+
+            let block_height = node.last_block_height();
+            let elapsed = self.started.elapsed();
+            heights.push((elapsed, block_height));
+
+            if block_height >= target_block_height {
+                let report = ColdStartSyncReport::new(node.alias(), target_block_height, &heights);
+                println!(
+                    "node '{}' synced {} blocks in {:.2}s ({:.2} blocks/s)",
+                    report.alias, block_height, report.duration_secs, report.blocks_per_sec
+                );
+                return Ok(report);
+            }
+            if elapsed > self.definition.timeout {
+                return Err(ColdStartSyncError::Timeout {
+                    alias: node.alias(),
+                    block_height,
+                    target_block_height,
+                    timeout: self.definition.timeout,
+                });
+            }
+            std::thread::sleep(self.definition.sample_interval);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn report_computes_sync_speed() {
+        let report = ColdStartSyncReport::new(
+            "passive".to_string(),
+            300,
+            &[
+                (Duration::from_secs(2), 0),
+                (Duration::from_secs(4), 200),
+                (Duration::from_secs(5), 300),
+            ],
+        );
+
+        let speeds: Vec<f64> = report.samples.iter().map(|s| s.blocks_per_sec).collect();
+        assert_eq!(speeds, vec![0.0, 100.0, 100.0]);
+        assert_eq!(report.blocks_per_sec, 60.0);
+        assert!(report.assert_min_blocks_per_sec(50.0).is_ok());
+        assert!(matches!(
+            report.assert_min_blocks_per_sec(70.0),
+            Err(ColdStartSyncError::TooSlow { .. })
+        ));
+    }
+}
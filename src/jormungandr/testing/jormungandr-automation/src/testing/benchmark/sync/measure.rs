@@ -67,7 +67,7 @@ pub fn measure_fragment_propagation_speed<A: FragmentNode + Sized>(
     sync_wait: Thresholds<Speed>,
     info: &str,
     report_node_stats_interval: MeasurementReportInterval,
-) -> Result<(), VerificationError> {
+) -> Result<Duration, VerificationError> {
     let benchmark = benchmark_speed(info.to_owned())
         .with_thresholds(sync_wait)
         .start();
@@ -90,11 +90,13 @@ pub fn measure_fragment_propagation_speed<A: FragmentNode + Sized>(
         report_node_stats.increment();
 
         if leaders_ids.is_empty() {
-            benchmark.stop().print();
             break;
         }
     }
-    Ok(())
+
+    let finish = benchmark.stop();
+    finish.print();
+    Ok(finish.speed().clone().into())
 }
 
 pub fn measure_and_log_sync_time<A: SyncNode + ?Sized>(
@@ -103,6 +105,17 @@ pub fn measure_and_log_sync_time<A: SyncNode + ?Sized>(
     info: &str,
     report_node_stats_interval: MeasurementReportInterval,
 ) -> Result<(), VerificationError> {
+    measure_sync_time(nodes, sync_wait, info, report_node_stats_interval).map(|_| ())
+}
+
+/// Same as [`measure_and_log_sync_time`] but returns how long it took for the nodes to sync,
+/// so it can be checked with a `RegressionGate`
+pub fn measure_sync_time<A: SyncNode + ?Sized>(
+    nodes: &[&A],
+    sync_wait: Thresholds<Speed>,
+    info: &str,
+    report_node_stats_interval: MeasurementReportInterval,
+) -> Result<Duration, VerificationError> {
     let benchmark = benchmark_speed(info.to_owned())
         .with_thresholds(sync_wait)
         .start();
@@ -134,13 +147,15 @@ pub fn measure_and_log_sync_time<A: SyncNode + ?Sized>(
             .unwrap_or(true);
 
         if stop {
-            benchmark.stop().print();
-            return Ok(());
+            let finish = benchmark.stop();
+            finish.print();
+            return Ok(finish.speed().clone().into());
         }
     }
 
     // we know it fails, this method is used only for reporting
     let result = ensure_nodes_are_in_sync(SyncWaitParams::ZeroWait, nodes);
-    benchmark.stop().print();
-    result
+    let finish = benchmark.stop();
+    finish.print();
+    result.map(|_| finish.speed().clone().into())
 }
@@ -4,11 +4,15 @@ use crate::testing::{
     Speed, Thresholds,
 };
 
+mod cold_start;
 mod measure;
 mod node;
 mod report;
 mod wait;
 
+pub use cold_start::{
+    ColdStartSync, ColdStartSyncError, ColdStartSyncMeasurement, ColdStartSyncReport, SyncSample,
+};
 use jormungandr_lib::time::Duration as LibsDuration;
 pub use measure::*;
 pub use node::{SyncNode, SyncNodeError, SyncNodeRecord};
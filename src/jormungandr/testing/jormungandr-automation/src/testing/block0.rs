@@ -83,6 +83,11 @@ impl Block0ConfigurationExtension for Block0Configuration {
             reward_params: blockchain_configuration.reward_parameters().unwrap(),
             discrimination: blockchain_configuration.discrimination,
             tx_max_expiry_epochs: blockchain_configuration.tx_max_expiry_epochs.unwrap(),
+            max_fragment_size: blockchain_configuration.max_fragment_size,
+            voting_power_snapshot: blockchain_configuration
+                .voting_power_snapshot
+                .unwrap_or(false),
+            script_addresses: blockchain_configuration.script_addresses.unwrap_or(false),
         }
     }
 
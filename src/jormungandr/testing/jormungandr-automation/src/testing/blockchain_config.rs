@@ -1,5 +1,6 @@
-use chain_impl_mockchain::rewards::{CompoundingType, Limit, Parameters};
+use chain_impl_mockchain::rewards::{CompoundingType, Limit, Parameters, Ratio};
 use jormungandr_lib::interfaces::{BlockchainConfiguration, RewardParams};
+use std::num::NonZeroU32;
 
 pub trait BlockchainConfigurationExtension {
     fn reward_parameters(&self) -> Option<Parameters>;
@@ -51,6 +52,18 @@ impl BlockchainConfigurationExtension for BlockchainConfiguration {
                 reward_drawing_limit_max: reward_drawing,
                 pool_participation_capping: pool_participation,
             }),
+            RewardParams::Fixed {
+                constant,
+                epoch_start,
+            } => Some(Parameters {
+                initial_value: constant,
+                compounding_ratio: Ratio::zero(),
+                compounding_type: CompoundingType::Fixed,
+                epoch_rate: NonZeroU32::new(u32::MAX).unwrap(),
+                epoch_start,
+                reward_drawing_limit_max: reward_drawing,
+                pool_participation_capping: pool_participation,
+            }),
         }
     }
 }
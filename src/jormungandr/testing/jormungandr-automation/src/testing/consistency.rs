@@ -0,0 +1,298 @@
+use crate::jormungandr::{JormungandrProcess, RestError, RetryPolicy};
+use jormungandr_lib::{
+    crypto::{account::Identifier, hash::Hash},
+    interfaces::AccountState,
+};
+use std::fmt;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ConsistencyError {
+    #[error("cannot fetch ledger state of node '{alias}'")]
+    Rest {
+        alias: String,
+        #[source]
+        source: RestError,
+    },
+    #[error("stats of node '{0}' do not contain last block height")]
+    MissingBlockHeight(String),
+    #[error("ledger state diverged between nodes:\n{0}")]
+    Diverged(LedgerDiff),
+}
+
+/// Part of the ledger state of one node, as seen through REST
+#[derive(Debug, Clone)]
+pub struct LedgerSnapshot {
+    pub alias: String,
+    pub tip: Hash,
+    pub block_height: u32,
+    /// state of each requested account, `None` if the node does not know it
+    pub accounts: Vec<(Identifier, Option<AccountState>)>,
+}
+
+impl LedgerSnapshot {
+    /// Sum of the funds held by the requested accounts
+    pub fn total_value(&self) -> u64 {
+        self.accounts
+            .iter()
+            .filter_map(|(_, state)| state.as_ref())
+            .map(|state| u64::from(*state.value()))
+            .sum()
+    }
+
+    fn account(&self, id: &Identifier) -> Option<&AccountState> {
+        self.accounts
+            .iter()
+            .find(|(account, _)| account == id)
+            .and_then(|(_, state)| state.as_ref())
+    }
+}
+
+/// Single disagreement between a node and the reference node
+#[derive(Debug, Clone)]
+pub enum Divergence {
+    TipTooFar {
+        node: String,
+        block_height: u32,
+        reference_block_height: u32,
+    },
+    TotalValue {
+        node: String,
+        value: u64,
+        reference_value: u64,
+    },
+    Account {
+        node: String,
+        account: Identifier,
+        state: Option<Box<AccountState>>,
+        reference_state: Option<Box<AccountState>>,
+    },
+}
+
+impl fmt::Display for Divergence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TipTooFar {
+                node,
+                block_height,
+                reference_block_height,
+            } => write!(
+                f,
+                "[{}] block height {} vs reference {}",
+                node, block_height, reference_block_height
+            ),
+            Self::TotalValue {
+                node,
+                value,
+                reference_value,
+            } => write!(
+                f,
+                "[{}] total value {} vs reference {}",
+                node, value, reference_value
+            ),
+            Self::Account {
+                node,
+                account,
+                state,
+                reference_state,
+            } => write!(
+                f,
+                "[{}] account {}: {:?} vs reference {:?}",
+                node, account, state, reference_state
+            ),
+        }
+    }
+}
+
+/// Structured difference between the ledger states of a set of nodes.
+/// The first node is used as the reference.
+#[derive(Debug, Clone)]
+pub struct LedgerDiff {
+    pub reference: String,
+    pub divergences: Vec<Divergence>,
+}
+
+impl LedgerDiff {
+    pub fn is_empty(&self) -> bool {
+        self.divergences.is_empty()
+    }
+}
+
+impl fmt::Display for LedgerDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "reference node: {}", self.reference)?;
+        for divergence in &self.divergences {
+            writeln!(f, "  {}", divergence)?;
+        }
+        Ok(())
+    }
+}
+
+/// Asserts that all nodes agree on the ledger: they are at most
+/// `max_block_distance` blocks apart and hold the same state for the tracked
+/// accounts. Account states are only compared between nodes which are at the
+/// same tip, since the ledger of a lagging node is legitimately different.
+#[derive(Debug, Clone)]
+pub struct LedgerConsistency {
+    accounts: Vec<Identifier>,
+    max_block_distance: u32,
+}
+
+impl Default for LedgerConsistency {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LedgerConsistency {
+    pub fn new() -> Self {
+        Self {
+            accounts: Vec::new(),
+            max_block_distance: 0,
+        }
+    }
+
+    pub fn with_accounts<I: IntoIterator<Item = Identifier>>(mut self, accounts: I) -> Self {
+        self.accounts.extend(accounts);
+        self
+    }
+
+    pub fn max_block_distance(mut self, max_block_distance: u32) -> Self {
+        self.max_block_distance = max_block_distance;
+        self
+    }
+
+    pub fn snapshot(&self, node: &JormungandrProcess) -> Result<LedgerSnapshot, ConsistencyError> {
+        let alias = node.alias();
+        let rest = node.rest_with_retry(RetryPolicy::default());
+        let rest_error = |source| ConsistencyError::Rest {
+            alias: alias.clone(),
+            source,
+        };
+
+        let tip = rest.tip().map_err(rest_error)?;
+        let block_height = rest
+            .stats()
+            .map_err(rest_error)?
+            .stats
+            .and_then(|stats| stats.last_block_height)
+            .and_then(|height| height.parse().ok())
+            .ok_or_else(|| ConsistencyError::MissingBlockHeight(alias.clone()))?;
+        let accounts = self
+            .accounts
+            .iter()
+            .map(|id| (id.clone(), rest.account_state(id).ok()))
+            .collect();
+
+        Ok(LedgerSnapshot {
+            alias,
+            tip,
+            block_height,
+            accounts,
+        })
+    }
+
+    pub fn diff(&self, snapshots: &[LedgerSnapshot]) -> LedgerDiff {
+        let reference = match snapshots.first() {
+            Some(reference) => reference,
+            None => {
+                return LedgerDiff {
+                    reference: String::new(),
+                    divergences: Vec::new(),
+                }
+            }
+        };
+
+        let mut divergences = Vec::new();
+        for snapshot in snapshots.iter().skip(1) {
+            if snapshot.block_height.abs_diff(reference.block_height) > self.max_block_distance {
+                divergences.push(Divergence::TipTooFar {
+                    node: snapshot.alias.clone(),
+                    block_height: snapshot.block_height,
+                    reference_block_height: reference.block_height,
+                });
+            }
+
+            if snapshot.tip != reference.tip {
+                continue;
+            }
+
+            if snapshot.total_value() != reference.total_value() {
+                divergences.push(Divergence::TotalValue {
+                    node: snapshot.alias.clone(),
+                    value: snapshot.total_value(),
+                    reference_value: reference.total_value(),
+                });
+            }
+            for account in &self.accounts {
+                let state = snapshot.account(account);
+                let reference_state = reference.account(account);
+                if state != reference_state {
+                    divergences.push(Divergence::Account {
+                        node: snapshot.alias.clone(),
+                        account: account.clone(),
+                        state: state.cloned().map(Box::new),
+                        reference_state: reference_state.cloned().map(Box::new),
+                    });
+                }
+            }
+        }
+
+        LedgerDiff {
+            reference: reference.alias.clone(),
+            divergences,
+        }
+    }
+
+    pub fn verify(&self, nodes: &[&JormungandrProcess]) -> Result<(), ConsistencyError> {
+        let snapshots = nodes
+            .iter()
+            .map(|node| self.snapshot(node))
+            .collect::<Result<Vec<_>, _>>()?;
+        let diff = self.diff(&snapshots);
+        if diff.is_empty() {
+            Ok(())
+        } else {
+            Err(ConsistencyError::Diverged(diff))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(alias: &str, tip: Hash, block_height: u32) -> LedgerSnapshot {
+        LedgerSnapshot {
+            alias: alias.to_string(),
+            tip,
+            block_height,
+            accounts: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn nodes_too_far_apart_diverge() {
+        let tip = Hash::from_hex(&"00".repeat(32)).unwrap();
+        let other_tip = Hash::from_hex(&"01".repeat(32)).unwrap();
+        let snapshots = vec![
+            snapshot("leader1", tip, 10),
+            snapshot("leader2", tip, 10),
+            snapshot("passive", other_tip, 8),
+        ];
+
+        assert!(LedgerConsistency::new()
+            .max_block_distance(2)
+            .diff(&snapshots)
+            .is_empty());
+
+        let diff = LedgerConsistency::new()
+            .max_block_distance(1)
+            .diff(&snapshots);
+        assert_eq!(diff.reference, "leader1");
+        assert!(matches!(
+            diff.divergences.as_slice(),
+            [Divergence::TipTooFar { node, .. }] if node == "passive"
+        ));
+    }
+}
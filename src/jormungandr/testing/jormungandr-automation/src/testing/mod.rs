@@ -4,6 +4,7 @@ pub mod block0;
 pub mod blockchain_config;
 pub mod collector;
 pub mod configuration;
+pub mod consistency;
 pub mod keys;
 pub mod observer;
 pub mod panic;
@@ -11,6 +12,7 @@ pub mod process;
 pub mod resources;
 pub mod settings;
 pub mod storage;
+pub mod telemetry;
 pub mod time;
 pub mod verify;
 pub mod vit;
@@ -19,6 +21,7 @@ pub use benchmark::sync::{
     ensure_node_is_in_sync_with_others, ensure_nodes_are_in_sync, MeasurementReportInterval,
     MeasurementReporter, SyncNode, SyncNodeError, SyncWaitParams,
 };
+pub use consistency::{ConsistencyError, LedgerConsistency, LedgerDiff, LedgerSnapshot};
 pub use jortestkit::{
     archive::decompress,
     github::{CachedReleases, GitHubApiBuilder, GitHubApiError, Release},
@@ -33,5 +36,6 @@ pub use jortestkit::{
     web::download_file,
 };
 pub use storage::{BranchCount, StopCriteria, StorageBuilder};
+pub use telemetry::{TelemetryExporter, TelemetryFormat};
 pub use verify::{assert, assert_equals, Error as VerificationError};
 pub use vit::{VoteCastCounter, VotePlanBuilder, VotePlanExtension};
@@ -0,0 +1,175 @@
+use crate::jormungandr::JormungandrProcess;
+use jormungandr_lib::interfaces::NodeStats;
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    thread::JoinHandle,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// Name of the measurement in the Influx line protocol
+const MEASUREMENT: &str = "node_stats";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TelemetryFormat {
+    Csv,
+    /// Influx line protocol, one line per node and sample
+    InfluxLine,
+}
+
+impl TelemetryFormat {
+    fn extension(&self) -> &'static str {
+        match self {
+            TelemetryFormat::Csv => "csv",
+            TelemetryFormat::InfluxLine => "influx",
+        }
+    }
+
+    fn header(&self) -> Option<&'static str> {
+        match self {
+            TelemetryFormat::Csv => Some(
+                "timestamp,node,tip_height,peers_connected,mempool_size,tx_received,tx_rejected",
+            ),
+            TelemetryFormat::InfluxLine => None,
+        }
+    }
+
+    fn line(&self, timestamp: SystemTime, node: &str, stats: &NodeStats) -> String {
+        let tip_height = stats
+            .last_block_height
+            .as_deref()
+            .and_then(|height| height.parse::<u64>().ok())
+            .unwrap_or(0);
+        match self {
+            TelemetryFormat::Csv => format!(
+                "{},{},{},{},{},{},{}",
+                humantime::format_rfc3339_millis(timestamp),
+                node,
+                tip_height,
+                stats.peer_connected_cnt,
+                stats.mempool_total_size,
+                stats.tx_recv_cnt,
+                stats.tx_rejected_cnt
+            ),
+            TelemetryFormat::InfluxLine => format!(
+                "{},node={} tip_height={}i,peers_connected={}i,mempool_size={}i,tx_received={}i,tx_rejected={}i {}",
+                MEASUREMENT,
+                node.replace(' ', "\\ ").replace(',', "\\,"),
+                tip_height,
+                stats.peer_connected_cnt,
+                stats.mempool_total_size,
+                stats.tx_recv_cnt,
+                stats.tx_rejected_cnt,
+                timestamp
+                    .duration_since(UNIX_EPOCH)
+                    .unwrap_or_default()
+                    .as_nanos()
+            ),
+        }
+    }
+}
+
+/// Periodically samples the stats of the nodes of a long running test, like
+/// a soak test, and appends them to a file to be analyzed with standard
+/// tooling once the test is over.
+///
+/// Nodes not responding at the time of a sample are skipped.
+pub struct TelemetryExporter {
+    path: PathBuf,
+    stop: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl TelemetryExporter {
+    /// Starts sampling the nodes every `interval`, in a file named after the
+    /// test in the given directory.
+    pub fn start<P: AsRef<Path>>(
+        directory: P,
+        name: &str,
+        format: TelemetryFormat,
+        interval: Duration,
+        nodes: &[&JormungandrProcess],
+    ) -> io::Result<Self> {
+        let nodes: Vec<_> = nodes
+            .iter()
+            .map(|node| (node.alias(), node.rest()))
+            .collect();
+        let path = directory
+            .as_ref()
+            .join(format!("{}_telemetry.{}", name, format.extension()));
+        let mut writer = BufWriter::new(File::create(&path)?);
+        if let Some(header) = format.header() {
+            writeln!(writer, "{}", header)?;
+            writer.flush()?;
+        }
+
+        let stop = Arc::new(AtomicBool::new(false));
+        let stop_flag = Arc::clone(&stop);
+        let handle = std::thread::spawn(move || {
+            while !stop_flag.load(Ordering::Relaxed) {
+                let timestamp = SystemTime::now();
+                for (alias, rest) in &nodes {
+                    if let Some(stats) = rest.stats().ok().and_then(|dto| dto.stats) {
+                        if let Err(err) =
+                            writeln!(writer, "{}", format.line(timestamp, alias, &stats))
+                        {
+                            eprintln!("cannot write telemetry: {}", err);
+                            return;
+                        }
+                    }
+                }
+                if let Err(err) = writer.flush() {
+                    eprintln!("cannot write telemetry: {}", err);
+                    return;
+                }
+                sleep_unless_stopped(interval, &stop_flag);
+            }
+        });
+
+        Ok(Self {
+            path,
+            stop,
+            handle: Some(handle),
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Stops sampling, after the sample in progress if any
+    pub fn stop(mut self) -> PathBuf {
+        self.stop_and_join();
+        self.path.clone()
+    }
+
+    fn stop_and_join(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+impl Drop for TelemetryExporter {
+    fn drop(&mut self) {
+        self.stop_and_join();
+    }
+}
+
+/// Sleeps by short steps so that stopping the exporter does not wait for
+/// a whole interval
+fn sleep_unless_stopped(interval: Duration, stop: &AtomicBool) {
+    const STEP: Duration = Duration::from_millis(100);
+    let mut remaining = interval;
+    while !remaining.is_zero() && !stop.load(Ordering::Relaxed) {
+        let step = remaining.min(STEP);
+        std::thread::sleep(step);
+        remaining -= step;
+    }
+}
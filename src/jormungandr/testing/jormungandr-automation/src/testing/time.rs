@@ -1,28 +1,41 @@
 use crate::jormungandr::JormungandrRest;
 use chain_impl_mockchain::block::BlockDate as ChainBlockDate;
 use jormungandr_lib::interfaces::BlockDate;
-use std::str::FromStr;
+use std::{
+    str::FromStr,
+    time::{Duration, SystemTime},
+};
+
+/// Interval between the polls of the node tip once the awaited date is due
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
 
 pub fn wait_for_epoch(epoch: u32, rest: JormungandrRest) {
     wait_for_date(ChainBlockDate { epoch, slot_id: 0 }.into(), rest)
 }
 
+/// Waits until the tip of the node reaches the given date.
+///
+/// The start time of the date is computed from the node settings, so the wait
+/// sleeps until then and polls the tip from there on, instead of sleeping by
+/// whole slots. This keeps tests running on short slots precise around
+/// epoch boundaries.
 pub fn wait_for_date(target_block_date: BlockDate, mut rest: JormungandrRest) {
     let settings = rest.settings().unwrap();
-    while is_it_due(get_current_date(&mut rest), target_block_date) {
-        std::thread::sleep(std::time::Duration::from_secs(settings.slot_duration));
-    }
-}
-
-fn is_it_due(current_block_date: BlockDate, target_block_date: BlockDate) -> bool {
+    let slot = u64::from(target_block_date.epoch()) * u64::from(settings.slots_per_epoch)
+        + u64::from(target_block_date.slot());
+    let due = SystemTime::from(settings.block0_time)
+        + Duration::from_secs(settings.slot_duration.saturating_mul(slot));
     println!(
-        "waiting for block date : {}.{}/{}.{}",
-        current_block_date.epoch(),
-        current_block_date.slot(),
+        "waiting for block date : {}.{}",
         target_block_date.epoch(),
         target_block_date.slot()
     );
-    current_block_date < target_block_date
+    if let Ok(remaining) = due.duration_since(SystemTime::now()) {
+        std::thread::sleep(remaining);
+    }
+    while get_current_date(&mut rest) < target_block_date {
+        std::thread::sleep(POLL_INTERVAL);
+    }
 }
 
 pub fn get_current_date(rest: &mut JormungandrRest) -> BlockDate {
@@ -66,6 +66,17 @@ impl VotePlanExtension for VotePlan {
                                                     }}
                                                 }}"#,value)).unwrap();
                         }
+                        chain_impl_mockchain::ledger::governance::TreasuryGovernanceAction::TransferToAccount { value, destination } => {
+                            item["action"] = json::parse(&format!(r#"
+                                                {{
+                                                    "treasury": {{
+                                                        "transfer_to_account": {{
+                                                            "value": {},
+                                                            "destination": "{}"
+                                                        }}
+                                                    }}
+                                                }}"#,value, jormungandr_lib::crypto::account::Identifier::from(destination.clone()))).unwrap();
+                        }
                     }
                 }
                 chain_impl_mockchain::certificate::VoteAction::Parameters { action } => {
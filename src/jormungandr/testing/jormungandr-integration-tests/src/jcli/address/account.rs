@@ -1,3 +1,4 @@
+use assert_fs::{fixture::FileWriteStr, NamedTempFile};
 use chain_addr::Discrimination;
 use jormungandr_automation::jcli::JCli;
 
@@ -37,3 +38,42 @@ pub fn test_account_address_made_of_ed25519_extended_key() {
         .account(&public_key, None, Discrimination::Test);
     assert_ne!(account_address, "", "generated account address is empty");
 }
+
+#[test]
+pub fn test_account_batch_matches_single_account_addresses() {
+    let jcli: JCli = Default::default();
+
+    let public_keys: Vec<String> = (0..3)
+        .map(|_| {
+            let private_key = jcli.key().generate("ed25519Extended");
+            jcli.key().convert_to_public_string(&private_key)
+        })
+        .collect();
+
+    let input = NamedTempFile::new("keys.txt").unwrap();
+    input
+        .write_str(&format!("{}\n\n", public_keys.join("\n")))
+        .unwrap();
+
+    let expected: Vec<String> = public_keys
+        .iter()
+        .map(|public_key| {
+            jcli.address()
+                .account(public_key, None, Discrimination::Test)
+        })
+        .collect();
+
+    let addresses = jcli
+        .address()
+        .account_batch(input.path(), Discrimination::Test);
+    assert_eq!(addresses, expected);
+
+    let entries = jcli
+        .address()
+        .account_batch_json(input.path(), Discrimination::Test);
+    assert_eq!(entries.len(), public_keys.len());
+    for ((entry, public_key), address) in entries.iter().zip(&public_keys).zip(&expected) {
+        assert_eq!(&entry["public_key"], public_key);
+        assert_eq!(&entry["address"], address);
+    }
+}
@@ -42,6 +42,19 @@ pub fn jcli_creates_correct_retirement_certificate() {
     assert_eq!(stake_pool_id, stake_pool_id_from_retirement);
 }
 
+#[test]
+pub fn jcli_shows_retirement_certificate() {
+    let jcli: JCli = Default::default();
+
+    let stake_pool_id = "8c6c2ad3a4a4ba1d2f4b6a2f7ec0bd3d5c6ba2bde2d48d3dbb8ecc00b1b04d6b";
+    let certificate = jcli.certificate().new_stake_pool_retirement(stake_pool_id);
+
+    let description = jcli.certificate().show(certificate);
+    assert_eq!(description["type"], "pool_retirement");
+    assert_eq!(description["pool_id"], stake_pool_id);
+    assert_eq!(description["retirement_time"], 0);
+}
+
 pub fn assert_new_stake_pool_retirement(stake_pool_id: &str) -> String {
     let pool_id = PoolId::from_str(stake_pool_id).unwrap();
     let start_validity = 0u64;
@@ -0,0 +1,91 @@
+use assert_fs::{prelude::*, TempDir};
+use chain_crypto::{Ed25519, RistrettoGroup2HashDh, SumEd25519_12};
+use chain_impl_mockchain::certificate::Certificate as CertificateType;
+use jormungandr_automation::{jcli::JCli, testing::keys::create_new_key_pair};
+use jormungandr_lib::interfaces::Certificate;
+use std::str::FromStr;
+
+#[test]
+pub fn jcli_creates_correct_update_certificate() {
+    let jcli: JCli = Default::default();
+
+    let owner = create_new_key_pair::<Ed25519>();
+    let vrf = create_new_key_pair::<RistrettoGroup2HashDh>();
+
+    let registration = |kes: &str| {
+        jcli.certificate().new_stake_pool_registration(
+            kes,
+            &vrf.identifier().to_bech32_str(),
+            0,
+            1,
+            &owner.identifier().to_bech32_str(),
+            None,
+        )
+    };
+    let current_certificate = registration(
+        &create_new_key_pair::<SumEd25519_12>()
+            .identifier()
+            .to_bech32_str(),
+    );
+    let new_certificate = registration(
+        &create_new_key_pair::<SumEd25519_12>()
+            .identifier()
+            .to_bech32_str(),
+    );
+
+    let temp_dir = TempDir::new().unwrap();
+    let current_file = temp_dir.child("current_certificate");
+    current_file.write_str(&current_certificate).unwrap();
+    let new_file = temp_dir.child("new_certificate");
+    new_file.write_str(&new_certificate).unwrap();
+
+    let stake_pool_id = jcli
+        .certificate()
+        .stake_pool_id(current_file.path())
+        .unwrap();
+
+    let update_certificate = jcli.certificate().new_stake_pool_update(
+        &stake_pool_id,
+        current_file.path(),
+        new_file.path(),
+    );
+    let update_file = temp_dir.child("update_certificate");
+    update_file.write_str(&update_certificate).unwrap();
+    let stake_pool_id_from_update = jcli
+        .certificate()
+        .stake_pool_id(update_file.path())
+        .unwrap();
+    assert_eq!(stake_pool_id, stake_pool_id_from_update);
+
+    let update = match Certificate::from_str(&update_certificate).unwrap().0 {
+        CertificateType::PoolUpdate(update) => update,
+        other => panic!("expected stake pool update certificate, got {:?}", other),
+    };
+    let new_registration = match Certificate::from_str(&new_certificate).unwrap().0 {
+        CertificateType::PoolRegistration(registration) => registration,
+        other => panic!(
+            "expected stake pool registration certificate, got {:?}",
+            other
+        ),
+    };
+    assert_eq!(update.last_pool_reg_hash.to_string(), stake_pool_id);
+    assert_eq!(update.new_pool_reg, new_registration);
+
+    // a later update keeps the identifier of the original registration
+    let next_certificate = registration(
+        &create_new_key_pair::<SumEd25519_12>()
+            .identifier()
+            .to_bech32_str(),
+    );
+    let next_file = temp_dir.child("next_certificate");
+    next_file.write_str(&next_certificate).unwrap();
+    let next_update_certificate =
+        jcli.certificate()
+            .new_stake_pool_update(&stake_pool_id, new_file.path(), next_file.path());
+    let next_update = match Certificate::from_str(&next_update_certificate).unwrap().0 {
+        CertificateType::PoolUpdate(update) => update,
+        other => panic!("expected stake pool update certificate, got {:?}", other),
+    };
+    assert_eq!(next_update.pool_id.to_string(), stake_pool_id);
+    assert_eq!(next_update.last_pool_reg_hash, new_registration.to_id());
+}
@@ -1,4 +1,5 @@
 pub mod from_bytes;
 pub mod generate;
+pub mod sign;
 pub mod to_bytes;
 pub mod to_public;
@@ -0,0 +1,43 @@
+use assert_fs::{prelude::*, NamedTempFile};
+use jormungandr_automation::jcli::JCli;
+
+#[test]
+pub fn test_key_sign_verify_ed25519() {
+    sign_and_verify("ed25519");
+}
+
+#[test]
+pub fn test_key_sign_verify_ed25519bip32() {
+    sign_and_verify("Ed25519Bip32");
+}
+
+#[test]
+pub fn test_key_verify_tampered_message() {
+    let jcli: JCli = Default::default();
+
+    let private_key = jcli.key().generate("ed25519");
+    let public_key = jcli.key().convert_to_public_string(&private_key);
+    let message = NamedTempFile::new("message").unwrap();
+    message.write_str("pool registration").unwrap();
+    let signature = jcli.key().sign(&private_key, message.path());
+
+    message.write_str("pool deregistration").unwrap();
+    jcli.key().verify_expect_fail(
+        public_key,
+        signature,
+        message.path(),
+        "signature verification failed",
+    );
+}
+
+fn sign_and_verify(key_type: &str) {
+    let jcli: JCli = Default::default();
+
+    let private_key = jcli.key().generate(key_type);
+    let public_key = jcli.key().convert_to_public_string(&private_key);
+    let message = NamedTempFile::new("message").unwrap();
+    message.write_str("pool registration").unwrap();
+
+    let signature = jcli.key().sign(&private_key, message.path());
+    jcli.key().verify(public_key, signature, message.path());
+}
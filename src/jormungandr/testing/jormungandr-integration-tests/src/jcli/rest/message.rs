@@ -0,0 +1,77 @@
+use crate::startup;
+use jormungandr_automation::{
+    jcli::JCli,
+    jormungandr::{Block0ConfigurationBuilder, NodeConfigBuilder},
+};
+use jormungandr_lib::interfaces::{FragmentLogsQuery, FragmentOriginKind, FragmentStatusKind};
+use thor::{FragmentSender, FragmentSenderSetup};
+
+#[test]
+pub fn test_fragment_logs_are_filtered_and_paginated() {
+    let jcli: JCli = Default::default();
+    let mut alice = thor::Wallet::default();
+    let bob = thor::Wallet::default();
+    let mut clarice = thor::Wallet::default();
+
+    let (jormungandr, _stake_pools) = startup::start_stake_pool(
+        &[alice.clone()],
+        &[bob.clone()],
+        Block0ConfigurationBuilder::default(),
+        NodeConfigBuilder::default(),
+    )
+    .unwrap();
+
+    let settings = jormungandr.rest().settings().unwrap();
+    let transaction_sender = FragmentSender::from(&settings);
+
+    for _ in 0..2 {
+        transaction_sender
+            .send_transaction(&mut alice, &bob, &jormungandr, 100.into())
+            .unwrap();
+    }
+
+    // clarice has no funds, her transaction gets rejected
+    let rejected = transaction_sender
+        .clone_with_setup(FragmentSenderSetup::ignore_errors())
+        .send_transaction(&mut clarice, &bob, &jormungandr, 100.into())
+        .unwrap();
+
+    let rest_uri = jormungandr.rest_uri();
+    let message = || jcli.rest().v0().message();
+
+    assert_eq!(message().logs(&rest_uri).len(), 3);
+
+    let rejected_logs = message().logs_with_query(
+        &rest_uri,
+        &FragmentLogsQuery {
+            status: Some(FragmentStatusKind::Rejected),
+            ..Default::default()
+        },
+    );
+    assert_eq!(rejected_logs.len(), 1);
+    assert_eq!(
+        rejected_logs[0].fragment_id().to_string(),
+        rejected.fragment_id().to_string()
+    );
+
+    let page = message().logs_with_query(
+        &rest_uri,
+        &FragmentLogsQuery {
+            offset: Some(1),
+            limit: Some(1),
+            status: Some(FragmentStatusKind::InABlock),
+            origin: Some(FragmentOriginKind::Rest),
+        },
+    );
+    assert_eq!(page.len(), 1);
+    assert!(page[0].is_in_a_block());
+
+    let from_jrpc = message().logs_with_query(
+        &rest_uri,
+        &FragmentLogsQuery {
+            origin: Some(FragmentOriginKind::JRpc),
+            ..Default::default()
+        },
+    );
+    assert!(from_jrpc.is_empty());
+}
@@ -1,5 +1,6 @@
 pub mod block;
 pub mod host;
+pub mod message;
 pub mod tip;
 pub mod utxo;
 pub mod vote;
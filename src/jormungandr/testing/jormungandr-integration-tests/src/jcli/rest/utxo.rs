@@ -4,6 +4,7 @@ use jormungandr_automation::{
     jcli::JCli, jormungandr::Block0ConfigurationBuilder,
     testing::block0::Block0ConfigurationExtension,
 };
+use jormungandr_lib::interfaces::UTxOsQuery;
 
 #[test]
 pub fn test_correct_utxos_are_read_from_node() {
@@ -40,3 +41,66 @@ pub fn test_correct_utxos_are_read_from_node() {
         .utxo()
         .assert_contains(&receiver_block0_utxo, &rest_addr);
 }
+
+#[test]
+pub fn test_utxos_are_listed_by_address() {
+    let jcli: JCli = Default::default();
+    let sender_utxo_address = startup::create_new_utxo_address();
+    let receiver_utxo_address = startup::create_new_utxo_address();
+    let temp_dir = TempDir::new().unwrap();
+
+    let test_context = SingleNodeTestBootstrapper::default()
+        .with_block0_config(Block0ConfigurationBuilder::default().with_utxos(vec![
+            receiver_utxo_address.to_initial_fund(100),
+            sender_utxo_address.to_initial_fund(100),
+        ]))
+        .as_bft_leader()
+        .build();
+    let jormungandr = test_context.start_node(temp_dir).unwrap();
+
+    let sender_block0_utxo = test_context
+        .block0_config()
+        .utxo_for_address(&sender_utxo_address.address());
+
+    let rest_addr = jormungandr.rest_uri();
+
+    let all = jcli
+        .rest()
+        .v0()
+        .utxo()
+        .list_with_query(&rest_addr, &UTxOsQuery::default());
+    assert!(all.len() >= 2);
+
+    let page = jcli.rest().v0().utxo().list_with_query(
+        &rest_addr,
+        &UTxOsQuery {
+            offset: Some(1),
+            limit: Some(1),
+            address: None,
+        },
+    );
+    assert_eq!(page.len(), 1);
+    assert_eq!(
+        page[0].transaction_id(),
+        all[1].transaction_id(),
+        "pages follow the order of the full listing"
+    );
+
+    let sender_utxos = jcli.rest().v0().utxo().list_with_query(
+        &rest_addr,
+        &UTxOsQuery {
+            offset: None,
+            limit: None,
+            address: Some(sender_utxo_address.address()),
+        },
+    );
+    assert_eq!(sender_utxos.len(), 1);
+    assert_eq!(
+        sender_utxos[0].transaction_id(),
+        sender_block0_utxo.transaction_id()
+    );
+    assert_eq!(
+        sender_utxos[0].index_in_transaction(),
+        sender_block0_utxo.index_in_transaction()
+    );
+}
@@ -1,3 +1,4 @@
+use chain_impl_mockchain::fee::LinearFee;
 use jormungandr_automation::jcli::JCli;
 use jormungandr_lib::{crypto::hash::Hash, interfaces::BlockDate};
 
@@ -26,3 +27,58 @@ pub fn test_unbalanced_output_utxo_transaction_is_not_finalized() {
         .set_expiry_date(BlockDate::new(1, 0))
         .finalize_expect_fail("not enough input for making transaction");
 }
+
+#[test]
+pub fn test_change_output_balances_utxo_transaction() {
+    let receiver = thor::Wallet::new_utxo(&mut rand::rngs::OsRng);
+    let change = thor::Wallet::new_utxo(&mut rand::rngs::OsRng);
+    let jcli: JCli = Default::default();
+
+    let info = jcli
+        .transaction_builder(*FAKE_GENESIS_HASH)
+        .new_transaction()
+        .add_input(&FAKE_INPUT_TRANSACTION_ID, 0, "100")
+        .add_output(&receiver.address().to_string(), 50.into())
+        .set_expiry_date(BlockDate::new(1, 0))
+        .finalize_with_change(&change.address().to_string(), &LinearFee::new(2, 1, 0))
+        .info("{{ .num_outputs }} {{ .output }}");
+
+    // the fee of 1 input and 2 outputs is 5, leaving 45 of change
+    assert_eq!(info.trim(), "2 95");
+}
+
+#[test]
+pub fn test_change_output_reports_missing_input() {
+    let receiver = thor::Wallet::new_utxo(&mut rand::rngs::OsRng);
+    let change = thor::Wallet::new_utxo(&mut rand::rngs::OsRng);
+    let jcli: JCli = Default::default();
+
+    jcli.transaction_builder(*FAKE_GENESIS_HASH)
+        .new_transaction()
+        .add_input(&FAKE_INPUT_TRANSACTION_ID, 0, "100")
+        .add_output(&receiver.address().to_string(), 99.into())
+        .set_expiry_date(BlockDate::new(1, 0))
+        .finalize_with_change_expect_fail(
+            &change.address().to_string(),
+            &LinearFee::new(2, 1, 0),
+            "the fee with the change output is 5, 4 is missing",
+        );
+}
+
+#[test]
+pub fn test_change_output_not_payable_by_leftover() {
+    let receiver = thor::Wallet::new_utxo(&mut rand::rngs::OsRng);
+    let change = thor::Wallet::new_utxo(&mut rand::rngs::OsRng);
+    let jcli: JCli = Default::default();
+
+    jcli.transaction_builder(*FAKE_GENESIS_HASH)
+        .new_transaction()
+        .add_input(&FAKE_INPUT_TRANSACTION_ID, 0, "100")
+        .add_output(&receiver.address().to_string(), 95.into())
+        .set_expiry_date(BlockDate::new(1, 0))
+        .finalize_with_change_expect_fail(
+            &change.address().to_string(),
+            &LinearFee::new(2, 1, 0),
+            "the 1 left after the outputs and the fee does not cover the fee of a change output",
+        );
+}
@@ -152,3 +152,26 @@ pub fn test_account_transaction_different_lane_is_accepted() {
     );
     transaction_wrapper.seal_with_witness(&witness).to_message();
 }
+
+#[test]
+pub fn test_witness_made_from_request_is_accepted() {
+    let receiver = thor::Wallet::default();
+    let sender = thor::Wallet::default();
+
+    let mut transaction_wrapper = JCli::default().transaction_builder(TestGen::hash().into());
+    transaction_wrapper
+        .new_transaction()
+        .add_account(&sender.address_bech32(Discrimination::Test), &100.into())
+        .add_output(&receiver.address_bech32(Discrimination::Test), 100.into())
+        .set_expiry_date(BlockDate::first().into())
+        .finalize();
+    let witness = transaction_wrapper.create_witness_default(
+        WitnessType::Account,
+        Some(SpendingCounter::new(1, 3).unwrap()),
+    );
+    transaction_wrapper
+        .make_witness_from_request(&witness)
+        .add_witness(&witness)
+        .seal()
+        .to_message();
+}
@@ -16,7 +16,10 @@ use chain_impl_mockchain::{
 use jormungandr_automation::{
     jcli::JCli,
     jormungandr::{
-        explorer::{configuration::ExplorerParams, verifiers::ExplorerVerifier},
+        explorer::{
+            configuration::ExplorerParams, data::certificates_by_type::CertificateType,
+            verifiers::ExplorerVerifier,
+        },
         Block0ConfigurationBuilder,
     },
     testing::{
@@ -92,6 +95,66 @@ pub fn explorer_stake_pool_registration_test() {
     .unwrap();
 }
 
+#[test]
+pub fn explorer_certificates_by_type_test() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut stake_pool_owner = thor::Wallet::default();
+    let stake_pool = StakePool::new(&stake_pool_owner);
+
+    let jormungandr = SingleNodeTestBootstrapper::default()
+        .with_block0_config(
+            Block0ConfigurationBuilder::default().with_wallet(&stake_pool_owner, 1_000_000.into()),
+        )
+        .as_bft_leader()
+        .build()
+        .start_node(temp_dir)
+        .unwrap();
+
+    let settings = jormungandr.rest().settings().unwrap();
+    let fragment_sender = FragmentSender::from(&settings);
+
+    let fragment_builder =
+        FragmentBuilder::from_settings(&settings, BlockDate::first().next_epoch());
+
+    let explorer_process = jormungandr.explorer(ExplorerParams::default()).unwrap();
+    let explorer = explorer_process.client();
+
+    let stake_pool_reg_fragment =
+        fragment_builder.stake_pool_registration(&stake_pool_owner, &stake_pool);
+
+    fragment_sender
+        .send_fragment(
+            &mut stake_pool_owner,
+            stake_pool_reg_fragment.clone(),
+            &jormungandr,
+        )
+        .expect("Error while sending registration certificate for stake pool owner");
+
+    let registrations = explorer
+        .certificates(CertificateType::POOL_REGISTRATION, 10)
+        .unwrap();
+    assert!(
+        registrations.errors.is_none(),
+        "{:?}",
+        registrations.errors.unwrap()
+    );
+    let registrations = registrations.data.unwrap().certificates;
+    assert_eq!(registrations.total_count, 1);
+    assert_eq!(
+        registrations.edges[0].node.id,
+        stake_pool_reg_fragment.hash().to_string()
+    );
+
+    let vote_plans = explorer
+        .certificates(CertificateType::VOTE_PLAN, 10)
+        .unwrap()
+        .data
+        .unwrap()
+        .certificates;
+    assert_eq!(vote_plans.total_count, 0);
+    assert!(vote_plans.edges.is_empty());
+}
+
 #[test]
 pub fn explorer_owner_delegation_test() {
     let temp_dir = TempDir::new().unwrap();
@@ -174,7 +174,10 @@ fn block_at_chain_length(explorer: &Explorer, blocks_from_logs: Vec<Hash>) {
 fn epoch(explorer: &Explorer) {
     let epoch = explorer.epoch(1, 100).unwrap();
 
-    assert_eq!(epoch.data.unwrap().epoch.id, "1", "can't find epoch");
+    let epoch = epoch.data.unwrap().epoch;
+    assert_eq!(epoch.id, "1", "can't find epoch");
+    assert!(epoch.total_fees.is_some(), "missing epoch total fees");
+    assert!(epoch.total_output.is_some(), "missing epoch total output");
 }
 
 struct ExplorerProcess {
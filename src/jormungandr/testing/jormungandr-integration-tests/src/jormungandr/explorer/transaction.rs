@@ -1,5 +1,6 @@
-use crate::startup;
-use chain_impl_mockchain::block::BlockDate;
+use crate::startup::{self, SingleNodeTestBootstrapper};
+use assert_fs::TempDir;
+use chain_impl_mockchain::{block::BlockDate, fragment::Fragment};
 use jormungandr_automation::{
     jcli::JCli,
     jormungandr::{
@@ -54,3 +55,41 @@ pub fn explorer_transaction_test() {
 
     ExplorerVerifier::assert_transaction_certificates(transaction, explorer_transaction).unwrap();
 }
+
+#[test]
+pub fn explorer_block0_initial_funds_transaction_test() {
+    let temp_dir = TempDir::new().unwrap();
+    let sender = thor::Wallet::default();
+    let query_complexity_limit = 140;
+
+    let config =
+        Block0ConfigurationBuilder::default().with_utxos(vec![sender.to_initial_fund(1_000_000)]);
+
+    let test_context = SingleNodeTestBootstrapper::default()
+        .as_bft_leader()
+        .with_block0_config(config)
+        .build();
+    let jormungandr = test_context.start_node(temp_dir).unwrap();
+
+    let params = ExplorerParams::new(query_complexity_limit, None, None);
+    let explorer_process = jormungandr.explorer(params).unwrap();
+    let explorer = explorer_process.client();
+
+    let block0 = test_context.block0_config().to_block();
+    let initial_funds = block0
+        .fragments()
+        .find(|fragment| matches!(fragment, Fragment::Transaction(_)))
+        .expect("no initial funds in block0")
+        .clone();
+
+    let explorer_transaction = explorer
+        .transaction_certificates(initial_funds.hash().into())
+        .expect("Non existing transaction")
+        .data
+        .unwrap()
+        .transaction;
+
+    assert!(explorer_transaction.inputs.is_empty());
+    assert_eq!(explorer_transaction.fee, "0");
+    ExplorerVerifier::assert_transaction_certificates(initial_funds, explorer_transaction).unwrap();
+}
@@ -45,6 +45,7 @@ pub fn handshake_sanity() {
         "Genesis Block"
     );
     assert_eq!(handshake_response.version, 1, "Protocol version");
+    assert_ne!(handshake_response.capabilities, 0, "Capabilities");
 
     let public_key =
         PublicKey::<Ed25519>::from_binary(&handshake_response.node_id).expect("invalid node ID");
@@ -113,6 +114,21 @@ pub fn get_headers_incorrect_hash() {
     );
 }
 
+#[test]
+pub fn tip_subscription() {
+    let setup = setup::client::default();
+
+    let tip = setup.client.tip();
+    let updates = setup.client.tip_updates(2);
+
+    assert!(updates[0].1 >= tip.chain_length());
+    assert!(
+        updates[1].1 > updates[0].1,
+        "tip updates should follow the chain growth: {:?}",
+        updates
+    );
+}
+
 // L1011 GetBlocks correct hash
 #[test]
 pub fn get_blocks_correct_hash() {
@@ -0,0 +1,207 @@
+use chain_core::{packer::Codec, property::DeserializeFromSlice};
+use chain_impl_mockchain::block::{self, Block, BlockDate, BlockVersion, ContentsBuilder};
+use chain_network::{
+    data::block::{Block as NetworkBlock, BlockId},
+    grpc::{
+        fetch::{ChunkError, FetchCoordinator, FetchError},
+        Client,
+    },
+};
+use jormungandr_automation::jormungandr::{
+    grpc::server::{FaultInjection, MockBuilder, MockController},
+    Block0ConfigurationBuilder,
+};
+use std::time::Duration;
+use tonic::transport::Channel;
+
+const BLOCKS: u32 = 6;
+
+struct Peers {
+    controllers: Vec<MockController>,
+    ids: Vec<BlockId>,
+}
+
+impl Peers {
+    /// Starts a mock peer per set of faults, all serving the same chain
+    fn start(faults: Vec<FaultInjection>) -> Self {
+        let block0 = Block0ConfigurationBuilder::default().build().to_block();
+        let blocks = blocks_after(&block0, BLOCKS);
+        let controllers = faults
+            .into_iter()
+            .map(|faults| {
+                let mut controller = MockBuilder::default()
+                    .with_genesis_block(block0.clone())
+                    .with_faults(faults)
+                    .build();
+                for block in &blocks {
+                    controller.set_tip_block(block);
+                }
+                controller
+            })
+            .collect();
+        let ids = blocks.iter().map(id_of).collect();
+        Peers { controllers, ids }
+    }
+
+    fn fetch(&self, ids: &[BlockId], chunk_size: usize) -> Result<Vec<BlockId>, FetchError> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        runtime.block_on(async {
+            let mut clients = Vec::new();
+            for controller in &self.controllers {
+                clients.push(connect(controller).await);
+            }
+            let mut coordinator = FetchCoordinator::new(clients, network_block_id);
+            coordinator.chunk_size(chunk_size);
+            let blocks = coordinator.fetch(ids).await?;
+            Ok(blocks.iter().map(network_block_id).collect())
+        })
+    }
+
+    fn stop(self) {
+        for controller in self.controllers {
+            controller.stop();
+        }
+    }
+}
+
+/// Connects to the mock peer, waiting for its server to be up
+async fn connect(controller: &MockController) -> Client<Channel> {
+    let address = format!("http://{}", controller.address());
+    for _ in 0..50 {
+        if let Ok(client) = Client::connect(address.clone()).await {
+            return client;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    panic!("mock peer at {} is not reachable", address);
+}
+
+fn blocks_after(block0: &Block, count: u32) -> Vec<Block> {
+    let mut parent = block0.header().clone();
+    (1..=count)
+        .map(|slot_id| {
+            let block = block::builder(
+                BlockVersion::Genesis,
+                ContentsBuilder::new().into(),
+                |hdr| {
+                    Ok::<_, ()>(
+                        hdr.set_parent(&parent.hash(), parent.chain_length().increase())
+                            .set_date(BlockDate { epoch: 0, slot_id })
+                            .into_unsigned_header()
+                            .expect("internal error cannot build unsigned block")
+                            .generalize(),
+                    )
+                },
+            )
+            .unwrap();
+            parent = block.header().clone();
+            block
+        })
+        .collect()
+}
+
+fn id_of(block: &Block) -> BlockId {
+    BlockId::try_from(block.header().hash().as_ref()).unwrap()
+}
+
+fn network_block_id(block: &NetworkBlock) -> BlockId {
+    let block = Block::deserialize_from_slice(&mut Codec::new(block.as_bytes()))
+        .expect("mock peer should serve valid blocks");
+    id_of(&block)
+}
+
+#[test]
+pub fn blocks_are_returned_in_the_requested_order() {
+    let peers = Peers::start(vec![FaultInjection::default(); 3]);
+
+    let fetched = peers.fetch(&peers.ids, 1).unwrap();
+    assert_eq!(fetched, peers.ids);
+
+    let mut ids = peers.ids.clone();
+    ids.reverse();
+    let fetched = peers.fetch(&ids, 2).unwrap();
+    assert_eq!(fetched, ids);
+
+    peers.stop();
+}
+
+#[test]
+pub fn chunk_is_retried_after_peer_fails_mid_chunk() {
+    let peers = Peers::start(vec![
+        FaultInjection::default(),
+        FaultInjection::default().fail_blocks_after(1),
+    ]);
+
+    let fetched = peers.fetch(&peers.ids, 2).unwrap();
+    assert_eq!(fetched, peers.ids);
+
+    peers.stop();
+}
+
+#[test]
+pub fn chunk_is_retried_after_peer_sends_wrong_block() {
+    let peers = Peers::start(vec![
+        FaultInjection::default(),
+        FaultInjection::default().reverse_blocks(),
+    ]);
+
+    let fetched = peers.fetch(&peers.ids, 2).unwrap();
+    assert_eq!(fetched, peers.ids);
+
+    peers.stop();
+}
+
+#[test]
+pub fn chunk_is_retried_after_peer_ends_stream_early() {
+    let peers = Peers::start(vec![
+        FaultInjection::default(),
+        FaultInjection::default().end_blocks_after(1),
+    ]);
+
+    let fetched = peers.fetch(&peers.ids, 2).unwrap();
+    assert_eq!(fetched, peers.ids);
+
+    peers.stop();
+}
+
+#[test]
+pub fn fetch_fails_when_all_peers_fail() {
+    let peers = Peers::start(vec![FaultInjection::default().reverse_blocks()]);
+    let chunk = &peers.ids[..3];
+    match peers.fetch(chunk, 3) {
+        Err(FetchError::ChunkFailed {
+            first,
+            count,
+            source: ChunkError::UnexpectedBlock { expected, received },
+        }) => {
+            assert_eq!(first, chunk[0]);
+            assert_eq!(count, 3);
+            assert_eq!(expected, chunk[0]);
+            assert_eq!(received, chunk[2]);
+        }
+        other => panic!("unexpected fetch result {:?}", other),
+    }
+    peers.stop();
+
+    let peers = Peers::start(vec![
+        FaultInjection::default().fail_blocks_after(0),
+        FaultInjection::default().end_blocks_after(1),
+    ]);
+    match peers.fetch(&peers.ids, 2) {
+        Err(FetchError::ChunkFailed {
+            source: ChunkError::Rpc(_) | ChunkError::Incomplete { .. },
+            ..
+        }) => (),
+        other => panic!("unexpected fetch result {:?}", other),
+    }
+    peers.stop();
+
+    let peers = Peers::start(Vec::new());
+    assert!(matches!(
+        peers.fetch(&[BlockId::try_from(&[0; 32][..]).unwrap()], 1),
+        Err(FetchError::NoPeers)
+    ));
+}
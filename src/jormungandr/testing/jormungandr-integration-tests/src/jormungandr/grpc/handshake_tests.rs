@@ -0,0 +1,84 @@
+use chain_network::{
+    data::Capabilities,
+    grpc::{client::Builder, Client, Compression},
+};
+use jormungandr_automation::jormungandr::{grpc::server::MockBuilder, Block0ConfigurationBuilder};
+use std::time::Duration;
+use tonic::transport::Channel;
+
+/// Capabilities negotiated by a client advertising `client` and asking for
+/// compressed block streams, with a mock peer advertising `server`, along
+/// with the block compression the client uses after the handshake
+fn negotiate(
+    client: Capabilities,
+    server: Capabilities,
+) -> (Option<Capabilities>, Option<Compression>) {
+    let block0 = Block0ConfigurationBuilder::default().build().to_block();
+    let controller = MockBuilder::default()
+        .with_genesis_block(block0)
+        .with_capabilities(server.bits())
+        .build();
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap();
+    let negotiated = runtime.block_on(async {
+        let mut builder = Builder::new();
+        builder
+            .capabilities(client)
+            .block_compression(Compression::Gzip);
+        let mut client = connect(&builder, &controller.address()).await;
+        assert_eq!(client.negotiated_capabilities(), None);
+
+        let response = client.handshake(&[0u8; 32]).await.unwrap();
+        assert_eq!(
+            client.negotiated_capabilities(),
+            Some(response.capabilities)
+        );
+        (client.negotiated_capabilities(), client.block_compression())
+    });
+    controller.stop();
+    negotiated
+}
+
+/// Connects to the mock peer, waiting for its server to be up
+async fn connect(builder: &Builder, address: &str) -> Client<Channel> {
+    let address = format!("http://{}", address);
+    for _ in 0..50 {
+        if let Ok(client) = builder.connect(address.clone()).await {
+            return client;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    panic!("mock peer at {} is not reachable", address);
+}
+
+#[test]
+pub fn handshake_negotiates_common_capabilities() {
+    let (negotiated, compression) = negotiate(
+        Capabilities::SUPPORTED,
+        Capabilities::PEERS | Capabilities::BLOCK_COMPRESSION,
+    );
+
+    assert_eq!(
+        negotiated,
+        Some(Capabilities::PEERS | Capabilities::BLOCK_COMPRESSION)
+    );
+    assert_eq!(compression, Some(Compression::Gzip));
+}
+
+#[test]
+pub fn handshake_disables_compression_unsupported_by_server() {
+    let (negotiated, compression) = negotiate(Capabilities::SUPPORTED, Capabilities::empty());
+
+    assert_eq!(negotiated, Some(Capabilities::empty()));
+    assert_eq!(compression, None);
+}
+
+#[test]
+pub fn handshake_only_negotiates_capabilities_advertised_by_client() {
+    let (negotiated, compression) = negotiate(Capabilities::PEERS, Capabilities::SUPPORTED);
+
+    assert_eq!(negotiated, Some(Capabilities::PEERS));
+    assert_eq!(compression, None);
+}
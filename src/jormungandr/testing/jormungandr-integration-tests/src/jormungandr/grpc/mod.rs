@@ -1,3 +1,5 @@
 pub mod client_tests;
+pub mod fetch_tests;
+pub mod handshake_tests;
 pub mod server_tests;
 pub mod setup;
@@ -31,10 +31,12 @@ pub fn wrong_protocol() {
         "Handshake with mock never happened"
     );
 
-    assert!(jormungandr.logger.get_lines().into_iter().any(|x| {
-        x.message() == "protocol handshake with peer failed"
-            && x.reason_contains("unsupported protocol version")
-    }));
+    jormungandr
+        .logger
+        .query()
+        .message("protocol handshake with peer failed")
+        .field_contains("reason", "unsupported protocol version")
+        .assert_some("Handshake failure not logged:");
 }
 
 // L1004 Handshake hash discrepancy
@@ -62,15 +64,13 @@ pub fn wrong_genesis_hash() {
         "Handshake with mock never happened"
     );
 
-    assert!(
-        jormungandr.logger.get_lines().into_iter().any(|x| {
-            x.message() == "connection to peer failed"
-                && x.error_contains("Block0Mismatch")
-                && x.level == LogLevel::INFO
-        }),
-        "Log content: {}",
-        jormungandr.logger.get_log_content()
-    );
+    jormungandr
+        .logger
+        .query()
+        .message("connection to peer failed")
+        .field_contains("error", "Block0Mismatch")
+        .level(LogLevel::INFO)
+        .assert_some("Block0 mismatch not logged:");
 }
 
 // L1002 Handshake compatible
@@ -100,8 +100,10 @@ pub fn handshake_ok() {
         "Handshake with mock never happened"
     );
 
-    assert!(!jormungandr.logger.get_lines().into_iter().any(|x| {
-        x.message() == "protocol handshake with peer failed"
-            && x.reason_contains("unsupported protocol version")
-    }));
+    jormungandr
+        .logger
+        .query()
+        .message("protocol handshake with peer failed")
+        .field_contains("reason", "unsupported protocol version")
+        .assert_none("Compatible handshake:");
 }
@@ -1,3 +1,5 @@
+pub mod upgrade;
+
 use crate::startup::LegacySingleNodeTestBootstrapper;
 use assert_fs::TempDir;
 use chain_impl_mockchain::{
@@ -0,0 +1,321 @@
+use assert_fs::fixture::PathChild;
+use chain_impl_mockchain::accounting::account::SpendingCounter;
+use hersir::{
+    builder::{NetworkBuilder, Node, Topology},
+    config::{BlockchainConfiguration, SessionSettings, SpawnParams, WalletTemplateBuilder},
+};
+use jormungandr_automation::{
+    jormungandr::{
+        download_last_n_releases, get_jormungandr_bin, JormungandrProcess, JormungandrRest,
+        RemoteJormungandr, RestSettings, RetryPolicy, Version,
+    },
+    testing::{ensure_nodes_are_in_sync, SyncWaitParams},
+};
+use std::{
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    thread::{self, JoinHandle},
+    time::Duration,
+};
+use thor::{DummySyncNode, FragmentSender, FragmentSenderSetup, Wallet};
+
+const LEADER_1: &str = "Leader1";
+const LEADER_2: &str = "Leader2";
+const LEADER_3: &str = "Leader3";
+const LEADER_4: &str = "Leader4";
+
+const ALICE: &str = "ALICE";
+const BOB: &str = "BOB";
+const CLARICE: &str = "CLARICE";
+const DAVID: &str = "DAVID";
+
+/// The nodes of the network run the current build when `release` is `None`
+#[derive(Clone)]
+struct Stage {
+    release: Option<(PathBuf, Version)>,
+}
+
+impl Stage {
+    fn spawn_params(&self, alias: &str) -> SpawnParams {
+        match &self.release {
+            Some((app, version)) => SpawnParams::new(alias)
+                .jormungandr(app.clone())
+                .version(version.clone()),
+            None => SpawnParams::new(alias),
+        }
+    }
+
+    fn name(&self) -> String {
+        match &self.release {
+            Some((_, version)) => version.to_string(),
+            None => "current".to_string(),
+        }
+    }
+}
+
+/// Transactions sent in the background to one node, from the start of the
+/// upgrades until they are over. The node is restarted by the upgrades, so
+/// the transactions sent while it is down are expected to fail.
+struct BackgroundLoad {
+    stop: Arc<AtomicBool>,
+    handle: JoinHandle<u32>,
+}
+
+impl BackgroundLoad {
+    fn start(
+        node: RemoteJormungandr,
+        sender: FragmentSender<'static, DummySyncNode>,
+        mut from: Wallet,
+        to: Wallet,
+        pace: Duration,
+    ) -> Self {
+        let stop = Arc::new(AtomicBool::new(false));
+        // the node may be restarting when the counter has to be read back
+        let rest = JormungandrRest::new_with_custom_settings(
+            node.rest().address(),
+            RestSettings::default().with_retry(RetryPolicy::default()),
+        );
+        let handle = {
+            let stop = Arc::clone(&stop);
+            thread::spawn(move || {
+                let mut accepted = 0;
+                while !stop.load(Ordering::Relaxed) {
+                    match sender.send_transaction(&mut from, &to, &node, 1_000.into()) {
+                        Ok(_) => accepted += 1,
+                        Err(_) => {
+                            // the fragment may still have made it into a block
+                            if let Ok(state) = rest.account_state(&from.account_id()) {
+                                from.update_counter(SpendingCounter::from(state.counters()[0]));
+                            }
+                        }
+                    }
+                    thread::sleep(pace);
+                }
+                accepted
+            })
+        };
+        Self { stop, handle }
+    }
+
+    /// Stops the load and returns the number of transactions that made it
+    /// into a block
+    fn stop(self) -> u32 {
+        self.stop.store(true, Ordering::Relaxed);
+        self.handle.join().expect("background load panicked")
+    }
+}
+
+pub struct UpgradePathBuilder {
+    releases: u32,
+    leaders: Vec<&'static str>,
+    transactions_per_step: u32,
+    load_pace: Duration,
+}
+
+impl Default for UpgradePathBuilder {
+    fn default() -> Self {
+        Self {
+            releases: 2,
+            leaders: vec![LEADER_1, LEADER_2, LEADER_3, LEADER_4],
+            transactions_per_step: 10,
+            load_pace: Duration::from_millis(500),
+        }
+    }
+}
+
+impl UpgradePathBuilder {
+    /// Number of releases the path goes through before the current build,
+    /// the network starts on the oldest of them
+    pub fn releases(mut self, releases: u32) -> Self {
+        self.releases = releases;
+        self
+    }
+
+    /// Leaders of the network, the first one is the trusted peer of the others
+    pub fn leaders(mut self, leaders: Vec<&'static str>) -> Self {
+        assert!(
+            leaders.len() >= 2,
+            "an upgrade path needs at least two nodes"
+        );
+        self.leaders = leaders;
+        self
+    }
+
+    /// Number of transactions sent after each node upgrade
+    pub fn transactions_per_step(mut self, transactions_per_step: u32) -> Self {
+        self.transactions_per_step = transactions_per_step;
+        self
+    }
+
+    /// Delay between two transactions of the load running during the upgrades
+    pub fn load_pace(mut self, load_pace: Duration) -> Self {
+        self.load_pace = load_pace;
+        self
+    }
+
+    pub fn build(self) -> UpgradePath {
+        let session_settings = SessionSettings::default();
+        let stages = download_last_n_releases(self.releases)
+            .iter()
+            .rev()
+            .map(|release| {
+                let app = get_jormungandr_bin(release, &session_settings.root.child("jormungandr"));
+                Stage {
+                    release: Some((app, release.version())),
+                }
+            })
+            .chain(std::iter::once(Stage { release: None }))
+            .collect();
+
+        UpgradePath {
+            session_settings,
+            stages,
+            leaders: self.leaders,
+            transactions_per_step: self.transactions_per_step,
+            load_pace: self.load_pace,
+        }
+    }
+}
+
+/// Rolling upgrade of a network from older releases to the current build.
+///
+/// All the nodes start on the oldest release and are upgraded one at a time
+/// to each newer release, while a background load sends transactions to the
+/// trusted peer. After each node upgrade every transaction sent in the step
+/// has to make it into a block and all the nodes have to agree on the tip.
+pub struct UpgradePath {
+    /// holds the downloaded releases, and the network once it runs
+    session_settings: SessionSettings,
+    stages: Vec<Stage>,
+    leaders: Vec<&'static str>,
+    transactions_per_step: u32,
+    load_pace: Duration,
+}
+
+impl UpgradePath {
+    pub fn builder() -> UpgradePathBuilder {
+        UpgradePathBuilder::default()
+    }
+
+    pub fn run(self) {
+        let trusted_peer = self.leaders[0];
+        let topology = self.leaders.iter().skip(1).fold(
+            Topology::default().with_node(Node::new(trusted_peer)),
+            |topology, leader| {
+                topology.with_node(Node::new(*leader).with_trusted_peer(trusted_peer))
+            },
+        );
+
+        let mut controller = NetworkBuilder::default()
+            .session_settings(self.session_settings)
+            .topology(topology)
+            .blockchain_config(
+                BlockchainConfiguration::default().with_leaders(self.leaders.clone()),
+            )
+            .wallet_template(
+                WalletTemplateBuilder::new(ALICE)
+                    .with(2_500_000_000)
+                    .delegated_to(LEADER_2)
+                    .build(),
+            )
+            .wallet_template(
+                WalletTemplateBuilder::new(BOB)
+                    .with(2_000_000_000)
+                    .delegated_to(LEADER_1)
+                    .build(),
+            )
+            .wallet_template(
+                WalletTemplateBuilder::new(CLARICE)
+                    .with(2_000_000_000)
+                    .build(),
+            )
+            .wallet_template(
+                WalletTemplateBuilder::new(DAVID)
+                    .with(2_000_000_000)
+                    .build(),
+            )
+            .build()
+            .unwrap();
+
+        let (first_stage, next_stages) = self.stages.split_first().unwrap();
+        let mut nodes: Vec<JormungandrProcess> = self
+            .leaders
+            .iter()
+            .map(|leader| controller.spawn(first_stage.spawn_params(leader)).unwrap())
+            .collect();
+
+        let mut alice = controller.controlled_wallet(ALICE).unwrap();
+        let mut bob = controller.controlled_wallet(BOB).unwrap();
+        let sender = FragmentSender::from(&controller.settings().block0)
+            .clone_with_setup(FragmentSenderSetup::resend_3_times());
+        let sync_wait = SyncWaitParams::network_size(self.leaders.len() as u64, 2);
+
+        let load = BackgroundLoad::start(
+            nodes[0].to_remote(),
+            sender.clone(),
+            controller.controlled_wallet(CLARICE).unwrap(),
+            controller.controlled_wallet(DAVID).unwrap(),
+            self.load_pace,
+        );
+
+        for stage in next_stages {
+            for (i, leader) in self.leaders.iter().enumerate() {
+                nodes[i].shutdown();
+                nodes[i] = controller.spawn(stage.spawn_params(leader)).unwrap();
+
+                let entry_node = &nodes[(i + 1) % nodes.len()];
+                sender
+                    .send_transactions_round_trip(
+                        self.transactions_per_step,
+                        &mut alice,
+                        &mut bob,
+                        entry_node,
+                        1_000.into(),
+                    )
+                    .unwrap_or_else(|err| {
+                        panic!(
+                            "fragments lost after upgrading {} to {}: {}",
+                            leader,
+                            stage.name(),
+                            err
+                        )
+                    });
+
+                let network: Vec<&JormungandrProcess> = nodes.iter().collect();
+                ensure_nodes_are_in_sync(sync_wait.clone(), network.as_slice()).unwrap_or_else(
+                    |err| {
+                        panic!(
+                            "nodes forked after upgrading {} to {}: {}",
+                            leader,
+                            stage.name(),
+                            err
+                        )
+                    },
+                );
+            }
+        }
+
+        let accepted = load.stop();
+        assert!(
+            accepted > 0,
+            "no transaction of the background load made it into a block"
+        );
+    }
+}
+
+#[test]
+pub fn upgrade_from_two_releases_back() {
+    UpgradePath::builder().build().run()
+}
+
+#[test]
+pub fn upgrade_from_last_release() {
+    UpgradePath::builder()
+        .releases(1)
+        .leaders(vec![LEADER_1, LEADER_2, LEADER_3])
+        .build()
+        .run()
+}
@@ -0,0 +1,132 @@
+use crate::startup;
+use chain_core::property::Fragment as _;
+use chain_impl_mockchain::{block::BlockDate, fragment::Fragment};
+use jormungandr_automation::jormungandr::{
+    Block0ConfigurationBuilder, JormungandrProcess, NodeConfigBuilder,
+};
+use jormungandr_lib::interfaces::{FragmentRejectionReason, FragmentsProcessingSummary};
+use loki::FaultyTransactionBuilder;
+use rstest::*;
+
+#[fixture]
+fn world() -> (JormungandrProcess, Fragment, Fragment, Fragment) {
+    let alice = thor::Wallet::default();
+    let bob = thor::Wallet::default();
+    let clarice = thor::Wallet::default();
+
+    let (jormungandr, _stake_pools) = startup::start_stake_pool(
+        &[alice.clone()],
+        &[bob.clone(), clarice.clone()],
+        Block0ConfigurationBuilder::default(),
+        NodeConfigBuilder::default(),
+    )
+    .unwrap();
+
+    let settings = jormungandr.rest().settings().unwrap();
+    let fragment_builder =
+        thor::FragmentBuilder::from_settings(&settings, BlockDate::first().next_epoch());
+
+    let alice_fragment = fragment_builder
+        .transaction(&alice, bob.address(), 100.into())
+        .unwrap();
+    let bob_fragment = fragment_builder
+        .transaction(&bob, clarice.address(), 100.into())
+        .unwrap();
+    let invalid_fragment =
+        FaultyTransactionBuilder::from_settings(settings, BlockDate::first().next_epoch())
+            .unbalanced(&clarice, &alice);
+
+    (jormungandr, alice_fragment, bob_fragment, invalid_fragment)
+}
+
+#[rstest]
+pub fn message_batch_all_valid(world: (JormungandrProcess, Fragment, Fragment, Fragment)) {
+    let (jormungandr, alice_fragment, bob_fragment, _) = world;
+
+    let response = jormungandr
+        .rest()
+        .raw()
+        .send_message_batch(vec![alice_fragment.clone(), bob_fragment.clone()], true)
+        .unwrap();
+
+    assert_eq!(response.status(), reqwest::StatusCode::OK);
+    let summary: FragmentsProcessingSummary =
+        serde_json::from_str(&response.text().unwrap()).unwrap();
+    assert_eq!(
+        summary.accepted,
+        vec![alice_fragment.id(), bob_fragment.id()]
+    );
+    assert_eq!(summary.rejected, vec![]);
+}
+
+#[rstest]
+pub fn message_batch_all_or_nothing_rejects_whole_batch(
+    world: (JormungandrProcess, Fragment, Fragment, Fragment),
+) {
+    let (jormungandr, alice_fragment, bob_fragment, invalid_fragment) = world;
+
+    let response = jormungandr
+        .rest()
+        .raw()
+        .send_message_batch(
+            vec![
+                alice_fragment.clone(),
+                invalid_fragment.clone(),
+                bob_fragment.clone(),
+            ],
+            true,
+        )
+        .unwrap();
+
+    assert_eq!(response.status(), reqwest::StatusCode::BAD_REQUEST);
+    let summary: FragmentsProcessingSummary =
+        serde_json::from_str(&response.text().unwrap()).unwrap();
+    assert!(summary.accepted.is_empty());
+    let rejected: Vec<_> = summary
+        .rejected
+        .into_iter()
+        .map(|info| (info.id, info.reason))
+        .collect();
+    assert!(rejected.contains(&(
+        invalid_fragment.id(),
+        FragmentRejectionReason::FragmentInvalid
+    )));
+    assert!(rejected.contains(&(
+        alice_fragment.id(),
+        FragmentRejectionReason::BatchFragmentInvalid
+    )));
+    assert!(rejected.contains(&(
+        bob_fragment.id(),
+        FragmentRejectionReason::PreviousFragmentInvalid
+    )));
+}
+
+#[rstest]
+pub fn message_batch_independent_fragments(
+    world: (JormungandrProcess, Fragment, Fragment, Fragment),
+) {
+    let (jormungandr, alice_fragment, bob_fragment, invalid_fragment) = world;
+
+    let response = jormungandr
+        .rest()
+        .raw()
+        .send_message_batch(
+            vec![
+                alice_fragment.clone(),
+                invalid_fragment.clone(),
+                bob_fragment.clone(),
+            ],
+            false,
+        )
+        .unwrap();
+
+    assert_eq!(response.status(), reqwest::StatusCode::BAD_REQUEST);
+    let summary: FragmentsProcessingSummary =
+        serde_json::from_str(&response.text().unwrap()).unwrap();
+    assert_eq!(
+        summary.accepted,
+        vec![alice_fragment.id(), bob_fragment.id()]
+    );
+    assert_eq!(summary.rejected.len(), 1);
+    assert_eq!(summary.rejected[0].id, invalid_fragment.id());
+}
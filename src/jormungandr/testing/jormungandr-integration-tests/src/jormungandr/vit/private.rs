@@ -248,6 +248,14 @@ pub fn jcli_e2e_flow_private_vote() {
         1,
     );
 
+    let aggregated_result = jcli.votes().tally().aggregate(
+        active_plans_file.path(),
+        &vote_plan_id,
+        vec![decryption_share_file.path()],
+        1,
+    );
+    assert_eq!(result, aggregated_result);
+
     let result_file = temp_dir.child("result.json");
     result_file.write_str(&result).unwrap();
 
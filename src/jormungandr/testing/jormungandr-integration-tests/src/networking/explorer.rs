@@ -2,7 +2,10 @@ use hersir::{
     builder::{NetworkBuilder, Node, Topology},
     config::{BlockchainBuilder, SpawnParams, WalletTemplateBuilder},
 };
-use jormungandr_automation::{jormungandr::explorer::configuration::ExplorerParams, testing::time};
+use jormungandr_automation::{
+    jormungandr::explorer::{configuration::ExplorerParams, cross_check::ExplorerCrossCheck},
+    testing::time,
+};
 use jormungandr_lib::interfaces::BlockDate;
 use thor::FragmentSender;
 const LEADER_1: &str = "Leader_1";
@@ -107,20 +110,9 @@ pub fn passive_node_explorer() {
 
     let explorer = passive.explorer(ExplorerParams::default()).unwrap();
 
-    for mem_pool_check in mem_pool_checks {
-        let fragment_id = *mem_pool_check.fragment_id();
-        assert_eq!(
-            explorer
-                .client()
-                .transaction(fragment_id.into())
-                .unwrap()
-                .data
-                .unwrap()
-                .transaction
-                .id,
-            fragment_id.to_string()
-        )
-    }
+    ExplorerCrossCheck::new(explorer.client(), &passive.rest())
+        .transactions_indexed(&mem_pool_checks)
+        .unwrap();
 }
 
 #[test]
@@ -9,4 +9,5 @@ pub mod stake_pool;
 #[cfg(feature = "testnet")]
 pub mod testnet;
 pub mod topology;
+pub mod topology_file;
 pub mod utils;
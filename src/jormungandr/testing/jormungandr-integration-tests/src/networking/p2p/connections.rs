@@ -179,9 +179,10 @@ fn gossip_interval() {
 
     let log_timestamps: Vec<u64> = server
         .logger
-        .get_lines_as_string()
+        .query()
+        .matching("gossiping with peers")
+        .lines()
         .into_iter()
-        .filter(|s| s.contains("gossiping with peers"))
         .map(|t| parse_timestamp(&t))
         .collect();
 
@@ -232,9 +233,10 @@ fn network_stuck_check() {
 
     let log_timestamps: Vec<u64> = client
         .logger
-        .get_lines_as_string()
+        .query()
+        .matching("p2p network have been too quiet for some time")
+        .lines()
         .into_iter()
-        .filter(|s| s.contains("p2p network have been too quiet for some time"))
         .map(|t| parse_timestamp(&t))
         .collect();
 
@@ -361,15 +363,11 @@ fn max_bootstrap_attempts() {
 
     utils::wait(5);
 
-    assert_eq!(
-        client
-            .logger
-            .get_lines_as_string()
-            .into_iter()
-            .filter(|l| l.contains("bootstrap attempt #"))
-            .count(),
-        ATTEMPTS
-    );
+    client
+        .logger
+        .query()
+        .matching("bootstrap attempt #")
+        .assert_count(ATTEMPTS, "Client bootstrap attempts:");
 }
 
 pub fn parse_timestamp(log: &str) -> u64 {
@@ -422,20 +420,16 @@ fn gossip_new_node_bootstrap() {
     super::assert_are_in_network_view(&server1, vec![&server2], "Before second node bootstrap");
     super::assert_connected_cnt(&server1, 1, "Before second node bootstrap");
 
-    let is_gossiping_with_one_node = server1
+    server1
         .logger
-        .get_lines_as_string()
-        .iter()
-        .any(|s| s.contains("received gossip on 1 nodes"));
-
-    let is_gossiping_with_two_nodes = server1
+        .query()
+        .matching("received gossip on 1 nodes")
+        .assert_some("Before second node bootstrap");
+    server1
         .logger
-        .get_lines_as_string()
-        .iter()
-        .any(|s| s.contains("received gossip on 2 nodes"));
-
-    assert!(is_gossiping_with_one_node, "Before second node bootstrap");
-    assert!(!is_gossiping_with_two_nodes, "Before second node bootstrap");
+        .query()
+        .matching("received gossip on 2 nodes")
+        .assert_none("Before second node bootstrap");
 
     let client = controller.spawn(SpawnParams::new(CLIENT)).unwrap();
 
@@ -447,11 +441,9 @@ fn gossip_new_node_bootstrap() {
     );
     super::assert_connected_cnt(&server1, 2, "After second node bootstrap");
 
-    let is_gossiping_with_two_nodes = server1
+    server1
         .logger
-        .get_lines_as_string()
-        .iter()
-        .any(|s| s.contains("received gossip on 2 nodes"));
-
-    assert!(is_gossiping_with_two_nodes, "After second node bootstrap");
+        .query()
+        .matching("received gossip on 2 nodes")
+        .assert_some("After second node bootstrap");
 }
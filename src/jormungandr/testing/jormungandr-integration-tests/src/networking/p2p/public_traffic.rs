@@ -83,15 +83,12 @@ fn public_gossip_rejection() {
 
     utils::wait(20);
 
-    let mut gossip_dropped = false;
-    // internal node should drop gossip from public node
-    for i in _client_internal.logger.get_lines_as_string().into_iter() {
-        if i.contains("nodes dropped from gossip") && i.contains("80.9.12.3") {
-            gossip_dropped = true
-        }
-    }
-
-    assert!(gossip_dropped);
+    _client_internal
+        .logger
+        .query()
+        .matching("nodes dropped from gossip")
+        .matching(r"80\.9\.12\.3")
+        .assert_some("internal node should drop gossip from public node:");
 }
 
 #[test]
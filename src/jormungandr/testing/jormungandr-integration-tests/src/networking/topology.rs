@@ -87,6 +87,12 @@ pub fn fully_connected() {
     )
     .unwrap();
 
+    utils::LedgerConsistency::new()
+        .with_accounts(vec![wallet1.account_id(), wallet2.account_id()])
+        .max_block_distance(1)
+        .verify(&leaders)
+        .unwrap();
+
     utils::measure_single_transaction_propagation_speed(
         &mut controller,
         &mut wallet1,
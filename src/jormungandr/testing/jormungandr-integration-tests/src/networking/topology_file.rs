@@ -0,0 +1,91 @@
+use crate::networking::utils;
+use hersir::{
+    builder::NetworkBuilder,
+    config::{Config, SpawnParams},
+};
+use jormungandr_automation::{
+    jormungandr::JormungandrProcess,
+    testing::{benchmark::MeasurementReportInterval, SyncWaitParams},
+};
+use std::path::{Path, PathBuf};
+use thor::FragmentSender;
+
+/// Environment variable pointing to a directory with topology descriptions (YAML files
+/// in hersir configuration format). When not set, the topologies bundled with the suite are used.
+pub const TOPOLOGY_DIR_ENV: &str = "JORMUNGANDR_TOPOLOGY_DIR";
+
+fn topology_dir() -> PathBuf {
+    std::env::var(TOPOLOGY_DIR_ENV)
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| {
+            Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("resources")
+                .join("topologies")
+        })
+}
+
+fn spawn_params(config: &Config, alias: &str) -> SpawnParams {
+    config
+        .nodes
+        .iter()
+        .find(|node| node.spawn_params.get_alias() == alias)
+        .map(|node| node.spawn_params.clone())
+        .unwrap_or_else(|| panic!("node '{}' is not defined", alias))
+}
+
+fn run_topology(path: &Path) {
+    let name = path.file_stem().unwrap().to_string_lossy().to_string();
+    println!("running topology '{}' from {}", name, path.display());
+
+    let config = Config::from_file(path).unwrap();
+    let mut controller = NetworkBuilder::default()
+        .apply_config(config.clone())
+        .build()
+        .unwrap();
+
+    let nodes: Vec<JormungandrProcess> = config
+        .spawn_order()
+        .unwrap()
+        .iter()
+        .map(|alias| controller.spawn(spawn_params(&config, alias)).unwrap())
+        .collect();
+    let nodes: Vec<&JormungandrProcess> = nodes.iter().collect();
+
+    let mut wallets = controller.controlled_wallets();
+    if let [sender, receiver, ..] = wallets.as_mut_slice() {
+        FragmentSender::from(&controller.settings().block0)
+            .send_transactions_round_trip(10, sender, receiver, nodes[0], 1_000.into())
+            .unwrap();
+    }
+
+    let no_of_nodes = nodes.len() as u64;
+    utils::measure_and_log_sync_time(
+        &nodes,
+        SyncWaitParams::network_size(no_of_nodes, no_of_nodes.saturating_sub(1).max(1)).into(),
+        &format!("{}_sync", name),
+        MeasurementReportInterval::Standard,
+    )
+    .unwrap();
+}
+
+#[test]
+pub fn topologies_from_files() {
+    let dir = topology_dir();
+    let mut paths: Vec<PathBuf> = std::fs::read_dir(&dir)
+        .unwrap_or_else(|e| panic!("cannot read topology directory {}: {}", dir.display(), e))
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| {
+            matches!(
+                path.extension().and_then(|ext| ext.to_str()),
+                Some("yaml") | Some("yml")
+            )
+        })
+        .collect();
+    paths.sort();
+
+    assert!(!paths.is_empty(), "no topology found in {}", dir.display());
+
+    for path in paths {
+        run_topology(&path);
+    }
+}
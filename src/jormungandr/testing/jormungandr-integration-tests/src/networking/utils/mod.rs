@@ -1,19 +1,47 @@
 use hersir::controller::Controller;
+pub use jormungandr_automation::testing::benchmark::{measure_sync_time, RegressionGate};
 pub use jormungandr_automation::testing::{
     assert, assert_equals,
     benchmark::{
         measure_and_log_sync_time, measure_fragment_propagation_speed,
         measure_how_many_nodes_are_running,
     },
-    MeasurementReportInterval, SyncNode, SyncWaitParams,
+    LedgerConsistency, MeasurementReportInterval, SyncNode, SyncWaitParams,
 };
 use jormungandr_automation::{
     jormungandr::FragmentNode,
-    testing::{Speed, Thresholds},
+    testing::{Speed, Thresholds, VerificationError},
 };
 use std::time::Duration;
 use thor::{FragmentSender, Wallet};
 
+/// Environment variable pointing to the directory holding performance baselines.
+/// When not set, measurements are not gated against baselines.
+pub const BASELINE_DIR_ENV: &str = "JORMUNGANDR_BASELINE_DIR";
+
+pub fn regression_gate(topology: &str) -> Option<RegressionGate> {
+    let dir = std::env::var(BASELINE_DIR_ENV).ok()?;
+    let path = std::path::Path::new(&dir).join(format!("{}.json", topology));
+    Some(RegressionGate::new(path, topology).expect("cannot load performance baseline"))
+}
+
+/// Measures how long it takes for the nodes to sync and, when baselines are enabled,
+/// fails on a regression against the history stored under `info`
+pub fn measure_and_gate_sync_time<A: SyncNode + ?Sized>(
+    nodes: &[&A],
+    sync_wait: Thresholds<Speed>,
+    info: &str,
+    report_node_stats_interval: MeasurementReportInterval,
+) -> Result<(), VerificationError> {
+    let sync_time = measure_sync_time(nodes, sync_wait, info, report_node_stats_interval)?;
+    if let Some(mut gate) = regression_gate(info) {
+        gate.check("sync_time_secs", sync_time.as_secs_f64())
+            .unwrap();
+        gate.finish().unwrap();
+    }
+    Ok(())
+}
+
 pub fn wait(seconds: u64) {
     std::thread::sleep(Duration::from_secs(seconds));
 }
@@ -26,7 +54,7 @@ pub fn measure_single_transaction_propagation_speed<A: SyncNode + FragmentNode +
     sync_wait: Thresholds<Speed>,
     info: &str,
     report_node_stats_interval: MeasurementReportInterval,
-) {
+) -> Duration {
     let node = leaders.iter().next().unwrap();
     let check = FragmentSender::from(&controller.settings().block0)
         .send_transaction(wallet1, wallet2, *node, 1_000.into())
@@ -1,7 +1,7 @@
 use crate::networking::utils;
 use chain_impl_mockchain::{chaintypes::ConsensusVersion, milli::Milli, value::Value};
 use hersir::{
-    builder::{NetworkBuilder, Node, Topology},
+    builder::{NetworkBuilder, RelayZone, Topology},
     config::{BlockchainConfiguration, SpawnParams, WalletTemplate},
     controller::Controller,
 };
@@ -9,10 +9,13 @@ use jormungandr_automation::{
     jormungandr::{
         download_last_n_releases, get_jormungandr_bin, JormungandrProcess, PersistenceMode,
     },
-    testing::{benchmark::MeasurementReportInterval, SyncNode, SyncWaitParams},
+    testing::{
+        benchmark::MeasurementReportInterval, SyncNode, SyncWaitParams, TelemetryExporter,
+        TelemetryFormat,
+    },
 };
 use jormungandr_lib::interfaces::{ActiveSlotCoefficient, SlotDuration};
-use std::collections::HashMap;
+use std::{collections::HashMap, time::Duration};
 
 const CORE_NODE: &str = "Core";
 const RELAY_NODE: &str = "Relay";
@@ -42,7 +45,9 @@ fn prepare_real_scenario(
     consensus: ConsensusVersion,
 ) -> Controller {
     let mut builder = NetworkBuilder::default();
-    let mut topology = Topology::default().with_node(Node::new(CORE_NODE));
+    // the core node acts as the first relay, all relays are interconnected
+    let mut topology =
+        Topology::default().with_relay_zone(RelayZone::default().with_relay(CORE_NODE));
 
     let mut blockchain = BlockchainConfiguration::default()
         .with_consensus(consensus)
@@ -51,21 +56,17 @@ fn prepare_real_scenario(
             ActiveSlotCoefficient::new(Milli::from_millis(700)).unwrap(),
         );
 
-    for i in 0..relay_nodes_count {
-        let relay_name = relay_name(i + 1);
-        topology = topology.with_node(Node::new(&relay_name).with_trusted_peer(CORE_NODE));
-        blockchain = blockchain.with_leader(relay_name);
-    }
-
     let mut leader_counter = 1;
     let mut legacy_nodes_counter = 1;
 
     for i in 0..relay_nodes_count {
         let relay_name = relay_name(i + 1);
+        let mut zone = RelayZone::default().with_relay(&relay_name);
+        blockchain = blockchain.with_leader(relay_name);
 
         for _ in 0..nodes_count_per_relay {
             let leader_name = leader_name(leader_counter);
-            topology = topology.with_node(Node::new(&leader_name).with_trusted_peer(&relay_name));
+            zone = zone.with_edge(&leader_name);
 
             blockchain = blockchain.with_leader(leader_name);
 
@@ -74,12 +75,14 @@ fn prepare_real_scenario(
 
         for _ in 0..legacy_nodes_count_per_relay {
             let legacy_name = legacy_name(legacy_nodes_counter);
-            topology = topology.with_node(Node::new(&legacy_name).with_trusted_peer(&relay_name));
+            zone = zone.with_edge(&legacy_name);
 
             blockchain = blockchain.with_leader(legacy_name);
 
             legacy_nodes_counter += 1;
         }
+
+        topology = topology.with_relay_zone(zone);
     }
 
     builder = builder.topology(topology);
@@ -161,7 +164,7 @@ pub fn real_network(
         consensus,
     );
 
-    let _core = controller.spawn(SpawnParams::new(CORE_NODE)).unwrap();
+    let core = controller.spawn(SpawnParams::new(CORE_NODE)).unwrap();
 
     let mut relays = vec![];
     for i in 0..relay_nodes_count {
@@ -200,14 +203,30 @@ pub fn real_network(
         );
     }
 
+    let telemetry_nodes: Vec<&JormungandrProcess> = std::iter::once(&core)
+        .chain(relays.iter())
+        .chain(leaders.iter())
+        .chain(legacy_leaders.iter())
+        .collect();
+    let telemetry = TelemetryExporter::start(
+        controller.working_directory().path(),
+        name,
+        TelemetryFormat::InfluxLine,
+        Duration::from_secs(10),
+        &telemetry_nodes,
+    )
+    .unwrap();
+
     let mut sync_nodes: Vec<&dyn SyncNode> =
         leaders.iter().map(|node| node as &dyn SyncNode).collect();
     sync_nodes.extend(legacy_leaders.iter().map(|node| node as &dyn SyncNode));
 
     utils::measure_how_many_nodes_are_running(&sync_nodes, &format!("{}_bootstrap_score", name));
 
+    let regression_gate = utils::regression_gate(name);
+
     let leaders_count = leaders.len() as u64;
-    utils::measure_and_log_sync_time(
+    let sync_time = utils::measure_sync_time(
         &sync_nodes,
         SyncWaitParams::large_network(leaders_count).into(),
         &format!("{}_sync", name),
@@ -220,7 +239,7 @@ pub fn real_network(
 
     let fragment_nodes: Vec<&JormungandrProcess> = leaders.iter().collect();
 
-    utils::measure_single_transaction_propagation_speed(
+    let propagation_time = utils::measure_single_transaction_propagation_speed(
         &mut controller,
         &mut wallet,
         &wallet2,
@@ -228,5 +247,15 @@ pub fn real_network(
         SyncWaitParams::large_network(leaders_count).into(),
         &format!("{}_single_transaction_propagation", name),
         MeasurementReportInterval::Standard,
-    )
+    );
+
+    if let Some(mut gate) = regression_gate {
+        gate.check("sync_time_secs", sync_time.as_secs_f64())
+            .unwrap();
+        gate.check("propagation_time_secs", propagation_time.as_secs_f64())
+            .unwrap();
+        gate.finish().unwrap();
+    }
+
+    println!("telemetry exported to {}", telemetry.stop().display());
 }
@@ -0,0 +1,78 @@
+use crate::{networking::utils, non_functional::network::*};
+use chain_impl_mockchain::chaintypes::ConsensusVersion;
+use hersir::{
+    builder::{NetworkBuilder, Node, Topology},
+    config::{BlockchainBuilder, SpawnParams},
+};
+use jormungandr_automation::testing::{benchmark::ColdStartSync, SyncNode};
+use std::time::Duration;
+
+/// Number of thousands of blocks the fresh node needs to sync
+const THOUSANDS_OF_BLOCKS: u32 = 2;
+/// Regression threshold for the average sync speed of the fresh node
+const MIN_BLOCKS_PER_SEC: f64 = 50.0;
+
+#[test]
+#[ignore]
+pub fn cold_start_sync_benchmark() {
+    let name = "cold_start_sync";
+    let target_block_height = THOUSANDS_OF_BLOCKS * 1_000;
+
+    let mut controller = NetworkBuilder::default()
+        .topology(
+            Topology::default()
+                .with_node(Node::new(LEADER))
+                .with_node(Node::new(PASSIVE).with_trusted_peer(LEADER)),
+        )
+        .blockchain_config(
+            BlockchainBuilder::default()
+                .consensus(ConsensusVersion::Bft)
+                .slots_per_epoch(60)
+                .slot_duration(1)
+                .build(),
+        )
+        .build()
+        .unwrap();
+
+    let leader = controller
+        .spawn(SpawnParams::new(LEADER).in_memory())
+        .unwrap();
+
+    // one block per slot, leave some margin for the leader to produce them all
+    let production_timeout = Duration::from_secs(target_block_height as u64 * 2);
+    let production_start = std::time::Instant::now();
+    while leader.last_block_height() < target_block_height {
+        assert!(
+            production_start.elapsed() < production_timeout,
+            "leader did not produce {} blocks in {:?}",
+            target_block_height,
+            production_timeout
+        );
+        utils::wait(10);
+    }
+
+    let measurement = ColdStartSync::new(target_block_height)
+        .timeout(Duration::from_secs(600))
+        .start();
+    let passive = controller
+        .spawn(SpawnParams::new(PASSIVE).in_memory().passive())
+        .unwrap();
+    let report = measurement.wait_for_sync(&passive).unwrap();
+
+    report
+        .export(
+            controller
+                .working_directory()
+                .path()
+                .join(format!("{}.json", name)),
+        )
+        .unwrap();
+
+    if let Some(mut gate) = utils::regression_gate(name) {
+        gate.check("sync_time_secs", report.duration_secs).unwrap();
+        gate.finish().unwrap();
+    }
+    report
+        .assert_min_blocks_per_sec(MIN_BLOCKS_PER_SEC)
+        .unwrap();
+}
@@ -2,11 +2,13 @@ use crate::{networking::utils, non_functional::network::*};
 use hersir::{
     builder::{NetworkBuilder, Node, Topology},
     config::{SpawnParams, WalletTemplateBuilder},
+    controller::ChaosPlan,
 };
 use jormungandr_automation::{
     jormungandr::{LeadershipMode, PersistenceMode},
     testing::{benchmark::MeasurementReportInterval, SyncWaitParams},
 };
+use std::time::Duration;
 use thor::FragmentSender;
 #[test]
 pub fn passive_leader_disruption_no_overlap() {
@@ -57,7 +59,7 @@ pub fn passive_leader_disruption_no_overlap() {
         .spawn(SpawnParams::new(PASSIVE).passive())
         .unwrap();
 
-    utils::measure_and_log_sync_time(
+    utils::measure_and_gate_sync_time(
         &[&leader, &passive],
         SyncWaitParams::nodes_restart(5).into(),
         "passive_leader_disruption_no_overlap",
@@ -107,7 +109,7 @@ pub fn passive_leader_disruption_overlap() {
         .spawn(SpawnParams::new(PASSIVE).passive())
         .unwrap();
 
-    utils::measure_and_log_sync_time(
+    utils::measure_and_gate_sync_time(
         &[&leader, &passive],
         SyncWaitParams::nodes_restart(5).into(),
         "passive_leader_disruption_overlap",
@@ -154,7 +156,7 @@ pub fn leader_leader_disruption_overlap() {
     // 4. both nodes are up
     let leader2 = controller.spawn(SpawnParams::new(LEADER_2)).unwrap();
 
-    utils::measure_and_log_sync_time(
+    utils::measure_and_gate_sync_time(
         &[&leader1, &leader2],
         SyncWaitParams::nodes_restart(5).into(),
         "leader_leader_disruption_overlap",
@@ -201,7 +203,7 @@ pub fn leader_leader_disruption_no_overlap() {
     let leader2 = controller.spawn(SpawnParams::new(LEADER_2)).unwrap();
     let leader1 = controller.spawn(SpawnParams::new(LEADER_1)).unwrap();
 
-    utils::measure_and_log_sync_time(
+    utils::measure_and_gate_sync_time(
         &[&leader1, &leader2],
         SyncWaitParams::nodes_restart(5).into(),
         "leader_leader_disruption_no_overlap",
@@ -246,7 +248,7 @@ pub fn point_to_point_disruption() {
 
     leader2.shutdown();
 
-    utils::measure_and_log_sync_time(
+    utils::measure_and_gate_sync_time(
         &[&leader1, &leader3],
         SyncWaitParams::nodes_restart(5).into(),
         "point_to_point_disruption",
@@ -337,7 +339,7 @@ pub fn point_to_point_disruption_overlap() {
     println!("9. all nodes are up");
     let leader2 = controller.spawn(SpawnParams::new(LEADER_2)).unwrap();
 
-    utils::measure_and_log_sync_time(
+    utils::measure_and_gate_sync_time(
         &[&leader1, &leader2, &leader3],
         SyncWaitParams::nodes_restart(5).into(),
         "point_to_point_disruption_overlap",
@@ -405,7 +407,7 @@ pub fn custom_network_disruption() {
         .send_transactions_round_trip(2, &mut wallet1, &mut wallet3, &passive, 1_000.into())
         .unwrap();
 
-    utils::measure_and_log_sync_time(
+    utils::measure_and_gate_sync_time(
         &[&leader1, &leader3, &leader4, &leader5, &passive],
         SyncWaitParams::nodes_restart(5).into(),
         "custom_network_disruption",
@@ -480,7 +482,7 @@ pub fn mesh_disruption() {
         .send_transactions_round_trip(10, &mut wallet1, &mut wallet2, &leader1, 1_000.into())
         .unwrap();
 
-    utils::measure_and_log_sync_time(
+    utils::measure_and_gate_sync_time(
         &[&leader1, &leader2, &leader3, &leader4, &leader5],
         SyncWaitParams::nodes_restart(5).into(),
         "mesh_disruption_sync",
@@ -488,3 +490,64 @@ pub fn mesh_disruption() {
     )
     .unwrap();
 }
+
+#[test]
+pub fn mesh_partition_and_heal() {
+    let mut controller = NetworkBuilder::default()
+        .topology(
+            Topology::default()
+                .with_node(Node::new(LEADER_1))
+                .with_node(Node::new(LEADER_2).with_trusted_peer(LEADER_1))
+                .with_node(Node::new(LEADER_3).with_trusted_peer(LEADER_1))
+                .with_node(
+                    Node::new(LEADER_4)
+                        .with_trusted_peer(LEADER_2)
+                        .with_trusted_peer(LEADER_3),
+                ),
+        )
+        .wallet_template(
+            WalletTemplateBuilder::new(ALICE)
+                .with(2_500_000_000)
+                .delegated_to(LEADER_1)
+                .build(),
+        )
+        .wallet_template(
+            WalletTemplateBuilder::new(BOB)
+                .with(2_000_000_000)
+                .delegated_to(LEADER_3)
+                .build(),
+        )
+        .build()
+        .unwrap();
+
+    let nodes = [LEADER_1, LEADER_2, LEADER_3, LEADER_4]
+        .iter()
+        .map(|alias| {
+            let params = SpawnParams::new(alias);
+            let process = controller.spawn(params.clone()).unwrap();
+            (params, process)
+        })
+        .collect();
+
+    let chaos = ChaosPlan::new()
+        .partition(
+            Duration::from_secs(10),
+            &[LEADER_1, LEADER_2],
+            &[LEADER_3, LEADER_4],
+        )
+        .heal(Duration::from_secs(60))
+        .kill(Duration::from_secs(70), LEADER_4)
+        .start(Duration::from_secs(80), SpawnParams::new(LEADER_4))
+        .run(controller.clone(), nodes);
+
+    let nodes = chaos.join().unwrap();
+    let nodes: Vec<_> = nodes.values().collect();
+
+    utils::measure_and_gate_sync_time(
+        &nodes,
+        SyncWaitParams::nodes_restart(5).into(),
+        "mesh_partition_and_heal",
+        MeasurementReportInterval::Standard,
+    )
+    .unwrap();
+}
@@ -1,4 +1,5 @@
 pub mod big;
+pub mod cold_start;
 pub mod desync;
 pub mod disruption;
 #[cfg(feature = "soak")]
@@ -8,7 +8,7 @@ use hersir::{
 };
 use jormungandr_automation::{
     jormungandr::LogLevel,
-    testing::{ensure_nodes_are_in_sync, SyncWaitParams},
+    testing::{ensure_nodes_are_in_sync, SyncWaitParams, TelemetryExporter, TelemetryFormat},
 };
 use std::time::{Duration, SystemTime};
 use thor::{FragmentSender, FragmentVerifier};
@@ -95,7 +95,7 @@ pub fn relay_soak() {
         .build()
         .unwrap();
 
-    let _core = controller
+    let core = controller
         .spawn(SpawnParams::new(CORE_NODE).in_memory())
         .unwrap();
 
@@ -136,6 +136,18 @@ pub fn relay_soak() {
     let mut wallet6 = controller.controlled_wallet(FILIP).unwrap();
     let mut wallet7 = controller.controlled_wallet(GRACE).unwrap();
 
+    let telemetry = TelemetryExporter::start(
+        controller.working_directory().path(),
+        "relay_soak",
+        TelemetryFormat::Csv,
+        Duration::from_secs(30),
+        &[
+            &core, &relay1, &relay2, &leader1, &leader2, &leader3, &leader4, &leader5, &leader6,
+            &leader7,
+        ],
+    )
+    .unwrap();
+
     let now = SystemTime::now();
 
     let fragment_sender = FragmentSender::from(&controller.settings().block0);
@@ -199,6 +211,8 @@ pub fn relay_soak() {
         ],
     )
     .unwrap();
+
+    println!("telemetry exported to {}", telemetry.stop().display());
 }
 
 #[test]
@@ -233,9 +247,10 @@ fn network_stuck_check() {
 
     let log_timestamps: Vec<u64> = client
         .logger
-        .get_lines_as_string()
+        .query()
+        .matching("p2p network have been too quiet for some time")
+        .lines()
         .into_iter()
-        .filter(|s| s.contains("p2p network have been too quiet for some time"))
         .map(|t| parse_timestamp(&t))
         .collect();
 
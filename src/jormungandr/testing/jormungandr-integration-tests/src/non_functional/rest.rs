@@ -1,10 +1,14 @@
 use crate::startup;
-use jormungandr_automation::jormungandr::{Block0ConfigurationBuilder, NodeConfigBuilder};
+use jormungandr_automation::jormungandr::{
+    Block0ConfigurationBuilder, JormungandrRest, NodeConfigBuilder, RestSettings,
+};
 use jormungandr_lib::interfaces::{ActiveSlotCoefficient, KesUpdateSpeed};
 use jortestkit::load::{self, ConfigurationBuilder as LoadConfigurationBuilder, Monitor};
 use mjolnir::generators::RestRequestGen;
 use std::time::Duration;
 
+const MAX_REQUEST_DURATION: Duration = Duration::from_secs(5);
+
 #[test]
 pub fn rest_load_quick() {
     let faucet = thor::Wallet::default();
@@ -24,8 +28,11 @@ pub fn rest_load_quick() {
 
     jormungandr.steal_temp_dir().unwrap().into_persistent();
 
-    let rest_client = jormungandr.rest();
-    let request = RestRequestGen::new(rest_client);
+    let rest_client = JormungandrRest::new_with_custom_settings(
+        jormungandr.rest_uri(),
+        RestSettings::default().with_timings(),
+    );
+    let request = RestRequestGen::new(rest_client.clone());
     let config = LoadConfigurationBuilder::duration(Duration::from_secs(40))
         .thread_no(5)
         .step_delay(Duration::from_millis(10))
@@ -34,4 +41,22 @@ pub fn rest_load_quick() {
         .build();
     let stats = load::start_sync(request, config, "Jormungandr rest load test");
     assert!((stats.calculate_passrate() as u32) > 95);
+
+    // the clones of the client used by the load share its timings
+    let timings = rest_client.timings();
+    assert!(!timings.is_empty(), "no request timing was recorded");
+    let slowest = timings.iter().max_by_key(|timing| timing.duration).unwrap();
+    println!(
+        "{} requests, slowest: {} {} in {:?}",
+        timings.len(),
+        slowest.method,
+        slowest.url,
+        slowest.duration
+    );
+    assert!(
+        slowest.duration < MAX_REQUEST_DURATION,
+        "{} took {:?}",
+        slowest.url,
+        slowest.duration
+    );
 }
@@ -38,6 +38,7 @@ impl Into<RestSettings> for Connection {
             use_https: self.https,
             certificate: None,
             cors: None,
+            ..Default::default()
         }
     }
 }
@@ -3,7 +3,8 @@ pub use self::{
     export::{FragmentExporter, FragmentExporterError},
     initial_certificates::{signed_delegation_cert, signed_stake_pool_cert, vote_plan_cert},
     persistent_log::{write_into_persistent_log, PersistentLogViewer},
-    sender::{BlockDateGenerator, FragmentSender, FragmentSenderError},
+    recorder::{FragmentRecorder, FragmentRecorderError, RecordedFragment},
+    sender::{BatchFragmentFailure, BlockDateGenerator, FragmentSender, FragmentSenderError},
     setup::{DummySyncNode, FragmentSenderSetup, FragmentSenderSetupBuilder, VerifyStrategy},
     verifier::{ExitStrategy as VerifyExitStrategy, FragmentVerifier, FragmentVerifierError},
 };
@@ -46,6 +47,7 @@ mod chain_sender;
 mod export;
 mod initial_certificates;
 mod persistent_log;
+mod recorder;
 mod sender;
 mod setup;
 mod transaction_utils;
@@ -0,0 +1,282 @@
+use chain_core::{
+    packer::Codec,
+    property::{DeserializeFromSlice, Serialize as _},
+};
+use chain_impl_mockchain::fragment::{Fragment, FragmentId};
+use jormungandr_automation::jormungandr::{
+    FragmentNode, FragmentNodeError, MemPoolCheck, NodeAlias,
+};
+use jormungandr_lib::time::SystemTime;
+use serde::{Deserialize, Serialize};
+use std::{
+    fs::{self, File, OpenOptions},
+    io::{BufRead, BufReader, Write},
+    path::{Path, PathBuf},
+};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum FragmentRecorderError {
+    #[error("cannot open session file {0}")]
+    CannotOpenSessionFile(PathBuf),
+    #[error("malformed entry at line {line} of session file")]
+    MalformedEntry {
+        line: usize,
+        #[source]
+        source: serde_json::Error,
+    },
+    #[error("cannot decode fragment at line {0} of session file")]
+    CannotDecodeFragment(usize),
+    #[error("io error")]
+    IoError(#[from] std::io::Error),
+}
+
+/// Fragment sent to a node, as stored in a recorded session
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordedFragment {
+    /// time at which the fragment was sent
+    pub timestamp: SystemTime,
+    /// alias of the node the fragment was sent to
+    pub node: NodeAlias,
+    /// hex encoded bytes of the fragment
+    pub fragment: String,
+}
+
+impl RecordedFragment {
+    pub fn fragment(&self) -> Option<Fragment> {
+        let bytes = hex::decode(&self.fragment).ok()?;
+        Fragment::deserialize_from_slice(&mut Codec::new(bytes.as_slice())).ok()
+    }
+}
+
+/// Appends every fragment sent to a session file, one json entry per line,
+/// so that the session can be replayed later against another network
+/// started from the same block0.
+pub struct FragmentRecorder {
+    session_file: PathBuf,
+}
+
+impl FragmentRecorder {
+    pub fn new(session_file: PathBuf) -> Result<Self, FragmentRecorderError> {
+        if let Some(parent) = session_file.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        Ok(Self { session_file })
+    }
+
+    pub fn record(
+        &self,
+        fragment: &Fragment,
+        via: &dyn FragmentNode,
+    ) -> Result<(), FragmentRecorderError> {
+        let entry = RecordedFragment {
+            timestamp: SystemTime::now(),
+            node: via.alias(),
+            fragment: hex::encode(fragment.serialize_as_vec().unwrap()),
+        };
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.session_file)
+            .map_err(|_| FragmentRecorderError::CannotOpenSessionFile(self.session_file.clone()))?;
+        let mut line = serde_json::to_string(&entry).unwrap();
+        line.push('\n');
+        file.write_all(line.as_bytes())?;
+        Ok(())
+    }
+
+    /// Reads all entries of a recorded session, in the order they were sent
+    pub fn read<P: AsRef<Path>>(
+        session_file: P,
+    ) -> Result<Vec<RecordedFragment>, FragmentRecorderError> {
+        let session_file = session_file.as_ref();
+        let file = File::open(session_file).map_err(|_| {
+            FragmentRecorderError::CannotOpenSessionFile(session_file.to_path_buf())
+        })?;
+
+        let mut entries = Vec::new();
+        for (idx, line) in BufReader::new(file).lines().enumerate() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let entry: RecordedFragment = serde_json::from_str(&line).map_err(|source| {
+                FragmentRecorderError::MalformedEntry {
+                    line: idx + 1,
+                    source,
+                }
+            })?;
+            if entry.fragment().is_none() {
+                return Err(FragmentRecorderError::CannotDecodeFragment(idx + 1));
+            }
+            entries.push(entry);
+        }
+        Ok(entries)
+    }
+
+    /// Re-sends the recorded fragments, in the recorded order, each to the
+    /// node paired with it. With `keep_timing`, the delays between the
+    /// recorded sends are preserved.
+    pub fn replay<'a, I>(
+        session: I,
+        keep_timing: bool,
+    ) -> Vec<(FragmentId, Result<MemPoolCheck, FragmentNodeError>)>
+    where
+        I: IntoIterator<Item = (&'a RecordedFragment, &'a dyn FragmentNode)>,
+    {
+        let mut results = Vec::new();
+        let mut previous_timestamp = None;
+        for (entry, node) in session {
+            if let (true, Some(previous)) = (keep_timing, previous_timestamp) {
+                if let Ok(delay) = entry.timestamp.duration_since(previous) {
+                    std::thread::sleep(*delay.as_ref());
+                }
+            }
+            previous_timestamp = Some(entry.timestamp);
+
+            // entries are validated when the session file is read
+            let fragment = entry.fragment().unwrap();
+            results.push((fragment.hash(), node.send_fragment(fragment)));
+        }
+        results
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chain_impl_mockchain::{config::ConfigParam, fragment::ConfigParams};
+    use jormungandr_lib::{
+        crypto::hash::Hash,
+        interfaces::{BlockDate, FragmentLog, FragmentsProcessingSummary},
+    };
+    use std::{cell::RefCell, collections::HashMap};
+
+    struct StubNode {
+        alias: NodeAlias,
+        received: RefCell<Vec<FragmentId>>,
+    }
+
+    impl StubNode {
+        fn new(alias: &str) -> Self {
+            Self {
+                alias: alias.to_string(),
+                received: RefCell::new(Vec::new()),
+            }
+        }
+    }
+
+    impl FragmentNode for StubNode {
+        fn alias(&self) -> NodeAlias {
+            self.alias.clone()
+        }
+        fn fragment_logs(&self) -> Result<HashMap<FragmentId, FragmentLog>, FragmentNodeError> {
+            Ok(HashMap::new())
+        }
+        fn send_fragment(&self, fragment: Fragment) -> Result<MemPoolCheck, FragmentNodeError> {
+            self.received.borrow_mut().push(fragment.hash());
+            Ok(MemPoolCheck::new(fragment.hash()))
+        }
+        fn send_batch_fragments(
+            &self,
+            _fragments: Vec<Fragment>,
+            _fail_fast: bool,
+        ) -> Result<FragmentsProcessingSummary, FragmentNodeError> {
+            unimplemented!()
+        }
+        fn log_pending_fragment(&self, _fragment_id: FragmentId) {}
+        fn log_rejected_fragment(&self, _fragment_id: FragmentId, _reason: String) {}
+        fn log_in_block_fragment(
+            &self,
+            _fragment_id: FragmentId,
+            _valid_until: BlockDate,
+            _block: Hash,
+        ) {
+        }
+        fn log_content(&self) -> Vec<String> {
+            Vec::new()
+        }
+    }
+
+    fn fragment(slots_per_epoch: u32) -> Fragment {
+        let mut params = ConfigParams::new();
+        params.push(ConfigParam::SlotsPerEpoch(slots_per_epoch));
+        Fragment::Initial(params)
+    }
+
+    fn session_file(name: &str) -> PathBuf {
+        std::env::temp_dir()
+            .join(format!("thor-recorder-{}", std::process::id()))
+            .join(name)
+    }
+
+    #[test]
+    fn recorded_session_is_replayed_in_order() {
+        let session_file = session_file("session.json");
+        let (alice, bob) = (StubNode::new("alice"), StubNode::new("bob"));
+        let sent = vec![
+            (fragment(10), &alice),
+            (fragment(20), &bob),
+            (fragment(30), &alice),
+        ];
+
+        let recorder = FragmentRecorder::new(session_file.clone()).unwrap();
+        for (fragment, node) in &sent {
+            recorder.record(fragment, *node).unwrap();
+        }
+        let entries = FragmentRecorder::read(&session_file);
+        fs::remove_file(&session_file).unwrap();
+        let entries = entries.unwrap();
+
+        let nodes: Vec<_> = entries.iter().map(|entry| entry.node.as_str()).collect();
+        assert_eq!(nodes, vec!["alice", "bob", "alice"]);
+        for ((fragment, _), entry) in sent.iter().zip(&entries) {
+            assert_eq!(entry.fragment().unwrap().hash(), fragment.hash());
+        }
+
+        let (fresh_alice, fresh_bob) = (StubNode::new("alice"), StubNode::new("bob"));
+        let targets = entries.iter().map(|entry| {
+            if entry.node == "alice" {
+                &fresh_alice as &dyn FragmentNode
+            } else {
+                &fresh_bob as &dyn FragmentNode
+            }
+        });
+        let results = FragmentRecorder::replay(entries.iter().zip(targets), false);
+
+        let replayed: Vec<_> = results.iter().map(|(id, _)| *id).collect();
+        let expected: Vec<_> = sent.iter().map(|(fragment, _)| fragment.hash()).collect();
+        assert_eq!(replayed, expected);
+        assert!(results.iter().all(|(_, result)| result.is_ok()));
+        assert_eq!(
+            *fresh_alice.received.borrow(),
+            vec![expected[0], expected[2]]
+        );
+        assert_eq!(*fresh_bob.received.borrow(), vec![expected[1]]);
+    }
+
+    #[test]
+    fn malformed_session_is_rejected() {
+        let session_file = session_file("malformed.json");
+        let recorder = FragmentRecorder::new(session_file.clone()).unwrap();
+        recorder
+            .record(&fragment(10), &StubNode::new("alice"))
+            .unwrap();
+        let mut file = OpenOptions::new().append(true).open(&session_file).unwrap();
+        let undecodable = RecordedFragment {
+            timestamp: SystemTime::now(),
+            node: "alice".to_string(),
+            fragment: "00".to_string(),
+        };
+        writeln!(file, "{}", serde_json::to_string(&undecodable).unwrap()).unwrap();
+        writeln!(file, "not json").unwrap();
+
+        let result = FragmentRecorder::read(&session_file);
+        fs::remove_file(&session_file).unwrap();
+        assert!(matches!(
+            result,
+            Err(FragmentRecorderError::CannotDecodeFragment(2))
+        ));
+    }
+}
@@ -1,4 +1,4 @@
-use super::{FragmentExporter, FragmentExporterError};
+use super::{FragmentExporter, FragmentExporterError, FragmentRecorder, FragmentRecorderError};
 use crate::{
     DummySyncNode, FragmentBuilder, FragmentSenderSetup, FragmentVerifier, StakePool,
     VerifyExitStrategy, Wallet,
@@ -9,7 +9,7 @@ use chain_impl_mockchain::{
     block::BlockDate,
     certificate::{DecryptedPrivateTally, UpdateProposal, UpdateVote, VotePlan, VoteTallyPayload},
     fee::LinearFee,
-    fragment::Fragment,
+    fragment::{Fragment, FragmentId},
     testing::WitnessMode,
     vote::Choice,
 };
@@ -20,8 +20,8 @@ use jormungandr_automation::{
 use jormungandr_lib::{
     crypto::hash::Hash,
     interfaces::{
-        Address, Block0Configuration, BlockchainConfiguration, FragmentStatus,
-        FragmentsProcessingSummary, SettingsDto, Value,
+        Address, Block0Configuration, BlockchainConfiguration, FragmentRejectionReason,
+        FragmentStatus, FragmentsProcessingSummary, SettingsDto, Value,
     },
     time::SystemTime,
 };
@@ -53,14 +53,54 @@ pub enum FragmentSenderError {
     #[error(transparent)]
     FragmentExporterError(#[from] FragmentExporterError),
     #[error(transparent)]
+    FragmentRecorderError(#[from] FragmentRecorderError),
+    #[error(transparent)]
     FragmentBuilder(#[from] crate::FragmentBuilderError),
+    #[error("{} out of {total} fragments sent to node: {alias} are not in block", failures.len())]
+    BatchNotInBlock {
+        alias: String,
+        total: usize,
+        failures: Vec<BatchFragmentFailure>,
+        #[debug(skip)]
+        logs: Vec<String>,
+    },
+}
+
+/// Reason for a single fragment of a batch not ending up in a block
+#[derive(Debug, Clone)]
+pub enum BatchFragmentFailure {
+    /// The fragment was not accepted into the mempool
+    RejectedByMempool {
+        fragment_id: FragmentId,
+        reason: FragmentRejectionReason,
+    },
+    /// The fragment was accepted into the mempool but rejected when applied to the ledger
+    RejectedByLedger {
+        fragment_id: FragmentId,
+        reason: String,
+    },
+    /// The fragment was still pending (or not in the fragment logs at all) at the deadline
+    DeadlineExceeded {
+        fragment_id: FragmentId,
+        status: Option<FragmentStatus>,
+    },
+}
+
+impl BatchFragmentFailure {
+    pub fn fragment_id(&self) -> &FragmentId {
+        match self {
+            Self::RejectedByMempool { fragment_id, .. }
+            | Self::RejectedByLedger { fragment_id, .. }
+            | Self::DeadlineExceeded { fragment_id, .. } => fragment_id,
+        }
+    }
 }
 
 impl FragmentSenderError {
     pub fn logs(&self) -> impl Iterator<Item = &str> {
         use self::FragmentSenderError::*;
         let maybe_logs = match self {
-            FragmentNotInBlock { logs, .. } => Some(logs),
+            FragmentNotInBlock { logs, .. } | BatchNotInBlock { logs, .. } => Some(logs),
             _ => None,
         };
         maybe_logs
@@ -220,6 +260,74 @@ impl<'a, S: SyncNode + Send> FragmentSender<'a, S> {
         Ok(summary)
     }
 
+    /// Submits all fragments in a single request and waits until every one of them is
+    /// in a block or the deadline is reached. Fails with a per-fragment report if at least
+    /// one fragment was rejected or did not make it into a block in time.
+    pub fn send_batch<A: FragmentNode + SyncNode + Sized + Send>(
+        &self,
+        fragments: Vec<Fragment>,
+        deadline: Duration,
+        node: &A,
+    ) -> Result<Vec<MemPoolCheck>, FragmentSenderError> {
+        let total = fragments.len();
+        let summary = self.send_batch_fragments(fragments, false, node)?;
+
+        let mut failures = Vec::new();
+        let mut checks: Vec<MemPoolCheck> = summary
+            .accepted
+            .into_iter()
+            .map(MemPoolCheck::new)
+            .collect();
+
+        for info in summary.rejected {
+            match info.reason {
+                // fragment was sent before, its fate is decided by the ledger
+                FragmentRejectionReason::FragmentAlreadyInLog => {
+                    checks.push(MemPoolCheck::new(info.id))
+                }
+                reason => failures.push(BatchFragmentFailure::RejectedByMempool {
+                    fragment_id: info.id,
+                    reason,
+                }),
+            }
+        }
+
+        if self.setup.fire_and_forget() || self.setup.ignore_any_errors() {
+            return Ok(checks);
+        }
+
+        let statuses =
+            FragmentVerifier::wait_for_fragments(deadline, Duration::from_secs(1), &checks, node);
+
+        for check in &checks {
+            let fragment_id = *check.fragment_id();
+            match statuses.get(&fragment_id).cloned().flatten() {
+                Some(FragmentStatus::InABlock { .. }) => {}
+                Some(FragmentStatus::Rejected { reason }) => {
+                    failures.push(BatchFragmentFailure::RejectedByLedger {
+                        fragment_id,
+                        reason,
+                    })
+                }
+                status => failures.push(BatchFragmentFailure::DeadlineExceeded {
+                    fragment_id,
+                    status,
+                }),
+            }
+        }
+
+        if failures.is_empty() {
+            Ok(checks)
+        } else {
+            Err(FragmentSenderError::BatchNotInBlock {
+                alias: FragmentNode::alias(node),
+                total,
+                failures,
+                logs: FragmentNode::log_content(node),
+            })
+        }
+    }
+
     pub fn send_transaction<A: FragmentNode + SyncNode + Sized + Send>(
         &self,
         from: &mut Wallet,
@@ -607,6 +715,9 @@ impl<'a, S: SyncNode + Send> FragmentSender<'a, S> {
             FragmentExporter::new(dump_folder.to_path_buf())?
                 .dump_to_file(fragment, sender, via)?;
         }
+        if let Some(session_file) = &self.setup.record_fragments {
+            FragmentRecorder::new(session_file.to_path_buf())?.record(fragment, via)?;
+        }
         Ok(())
     }
 
@@ -621,6 +732,12 @@ impl<'a, S: SyncNode + Send> FragmentSender<'a, S> {
                 exporter.dump_to_file_no_sender(fragment, via)?;
             }
         }
+        if let Some(session_file) = &self.setup.record_fragments {
+            let recorder = FragmentRecorder::new(session_file.to_path_buf())?;
+            for fragment in fragments {
+                recorder.record(fragment, via)?;
+            }
+        }
         Ok(())
     }
 
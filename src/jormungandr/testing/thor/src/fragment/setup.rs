@@ -35,6 +35,8 @@ pub struct FragmentSenderSetup<'a, S: SyncNode + Send> {
     pub ignore_any_errors: bool,
     pub stop_at_error: bool,
     pub dump_fragments: Option<PathBuf>,
+    /// Session file every sent fragment is recorded into, for a later replay
+    pub record_fragments: Option<PathBuf>,
     /// Sender will confirm transaction (increment account counter)
     ///
     pub auto_confirm: bool,
@@ -90,6 +92,7 @@ impl<'a, S: SyncNode + Send> FragmentSenderSetup<'a, S> {
             sync_nodes: Vec::new(),
             ignore_any_errors: false,
             dump_fragments: None,
+            record_fragments: None,
             auto_confirm: true,
             verify_strategy: None,
             fire_and_forget: false,
@@ -219,6 +222,11 @@ impl<'a, S: SyncNode + Send> FragmentSenderSetupBuilder<'a, S> {
         self
     }
 
+    pub fn record_fragments_into(&mut self, session_file: PathBuf) -> &mut Self {
+        self.setup.record_fragments = Some(session_file);
+        self
+    }
+
     pub fn fire_and_forget(&mut self) -> &mut Self {
         self.setup.fire_and_forget = true;
         self
@@ -2,7 +2,10 @@ use chain_impl_mockchain::fragment::FragmentId;
 use jormungandr_automation::jormungandr::{FragmentNode, FragmentNodeError, MemPoolCheck};
 use jormungandr_lib::interfaces::{FragmentLog, FragmentStatus};
 use jortestkit::prelude::Wait;
-use std::{collections::HashMap, time::Duration};
+use std::{
+    collections::HashMap,
+    time::{Duration, Instant},
+};
 
 #[derive(custom_debug::Debug, thiserror::Error)]
 pub enum FragmentVerifierError {
@@ -272,6 +275,44 @@ impl FragmentVerifier {
         })
     }
 
+    /// Polls the node until all given fragments are processed or the deadline is reached.
+    /// Returns the last known status of each fragment, `None` when the fragment never
+    /// showed up in the node fragment logs.
+    pub fn wait_for_fragments<A: FragmentNode + ?Sized>(
+        deadline: Duration,
+        poll_interval: Duration,
+        checks: &[MemPoolCheck],
+        node: &A,
+    ) -> HashMap<FragmentId, Option<FragmentStatus>> {
+        let start = Instant::now();
+        let mut statuses: HashMap<FragmentId, Option<FragmentStatus>> = checks
+            .iter()
+            .map(|check| (*check.fragment_id(), None))
+            .collect();
+
+        loop {
+            if let Ok(fragment_logs) = node.fragment_logs() {
+                for (id, status) in statuses.iter_mut() {
+                    if let Some(log) = fragment_logs.get(id) {
+                        *status = Some(log.status().clone());
+                    }
+                }
+            }
+
+            let all_processed = statuses.values().all(|status| {
+                matches!(
+                    status,
+                    Some(FragmentStatus::Rejected { .. } | FragmentStatus::InABlock { .. })
+                )
+            });
+
+            if all_processed || start.elapsed() >= deadline {
+                return statuses;
+            }
+            std::thread::sleep(poll_interval);
+        }
+    }
+
     pub fn wait_for_all_fragments<A: FragmentNode + ?Sized>(
         duration: Duration,
         node: &A,
@@ -6,10 +6,11 @@ pub mod wallet;
 
 pub use fragment::{
     signed_delegation_cert, signed_stake_pool_cert, vote_plan_cert, write_into_persistent_log,
-    BlockDateGenerator, DummySyncNode, FragmentBuilder, FragmentBuilderError, FragmentChainSender,
-    FragmentExporter, FragmentExporterError, FragmentSender, FragmentSenderError,
-    FragmentSenderSetup, FragmentSenderSetupBuilder, FragmentVerifier, FragmentVerifierError,
-    PersistentLogViewer, TransactionHash, VerifyExitStrategy,
+    BatchFragmentFailure, BlockDateGenerator, DummySyncNode, FragmentBuilder, FragmentBuilderError,
+    FragmentChainSender, FragmentExporter, FragmentExporterError, FragmentRecorder,
+    FragmentRecorderError, FragmentSender, FragmentSenderError, FragmentSenderSetup,
+    FragmentSenderSetupBuilder, FragmentVerifier, FragmentVerifierError, PersistentLogViewer,
+    RecordedFragment, TransactionHash, VerifyExitStrategy,
 };
 pub use stake_pool::StakePool;
 pub use utils::Block0ConfigurationBuilderExtension;
@@ -9,7 +9,7 @@ use crate::{
     FragmentBuilderError,
 };
 use chain_addr::{AddressReadable, Discrimination};
-use chain_crypto::{Ed25519, Ed25519Extended, PublicKey, SecretKey, Signature};
+use chain_crypto::{Blake2b256, Ed25519, Ed25519Extended, PublicKey, SecretKey, Signature};
 pub use chain_impl_mockchain::{
     account::SpendingCounter,
     block::Block,
@@ -39,7 +39,8 @@ use jormungandr_lib::{
     crypto::{account::Identifier as AccountIdentifier, hash::Hash, key::Identifier},
     interfaces::{Address, CommitteeIdDef, Destination, Initial, InitialUTxO, Value},
 };
-use rand_core::{CryptoRng, RngCore};
+use rand_chacha::ChaChaRng;
+use rand_core::{CryptoRng, RngCore, SeedableRng};
 use std::{fs::File, path::Path};
 use thiserror::Error;
 
@@ -110,6 +111,26 @@ impl Wallet {
         Wallet::Account(account::Wallet::generate(rng, discrimination))
     }
 
+    /// Derives the `index`-th account wallet from `seed`. The same seed and index always
+    /// give the same keys, so a set of wallets can be recreated from a recorded seed.
+    ///
+    /// The derivation (blake2b of seed and index, used as rng seed) is meant for test
+    /// fixtures only and is not compatible with any wallet standard.
+    pub fn from_seed_hd(seed: &[u8], index: u32) -> Wallet {
+        Self::from_seed_hd_with_discrimination(seed, index, Discrimination::Test)
+    }
+
+    pub fn from_seed_hd_with_discrimination(
+        seed: &[u8],
+        index: u32,
+        discrimination: Discrimination,
+    ) -> Wallet {
+        let mut bytes = seed.to_vec();
+        bytes.extend_from_slice(&index.to_be_bytes());
+        let mut rng = ChaChaRng::from_seed(*Blake2b256::new(&bytes).as_hash_bytes());
+        Self::new_account_with_discrimination(&mut rng, discrimination)
+    }
+
     pub fn from_existing_account(
         signing_key_bech32: &str,
         spending_counter: Option<SpendingCounter>,
@@ -401,3 +422,26 @@ impl From<account::Wallet> for Wallet {
         Self::Account(account)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn hd_wallets_are_deterministic() {
+        let seed = b"thor hd wallet test seed";
+
+        assert_eq!(
+            Wallet::from_seed_hd(seed, 0).address(),
+            Wallet::from_seed_hd(seed, 0).address()
+        );
+        assert_ne!(
+            Wallet::from_seed_hd(seed, 0).address(),
+            Wallet::from_seed_hd(seed, 1).address()
+        );
+        assert_ne!(
+            Wallet::from_seed_hd(seed, 0).address(),
+            Wallet::from_seed_hd(b"another seed", 0).address()
+        );
+    }
+}
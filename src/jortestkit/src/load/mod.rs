@@ -97,6 +97,48 @@ where
     stats
 }
 
+/// Runs the load like [`start_sync`], polling the status provider at the
+/// status pace of the configuration while the load runs. Requests sent
+/// synchronously are never pending, so the provider is only given the
+/// chance to report on the progress of the load, such as the effects of the
+/// requests on the target.
+pub fn start_sync_with_status<R, S>(
+    request_generator: R,
+    status_provider: S,
+    config: Configuration,
+    title: &str,
+) -> Stats
+where
+    R: RequestGenerator + 'static,
+    S: RequestStatusProvider + Send + 'static,
+{
+    let (tx, rx) = mpsc::channel();
+    let start = Instant::now();
+    let response_collector = ResponseCollector::start(rx);
+    let monitor = MonitorThread::start(
+        response_collector.responses(),
+        config.monitor().clone(),
+        title,
+    );
+    let status_updater = StatusUpdaterThread::spawn(
+        response_collector.responses(),
+        status_provider,
+        config.monitor().clone(),
+        config.fetch_limit(),
+        title,
+        config.shutdown_grace_period(),
+        config.status_pace(),
+    );
+    run_load(request_generator, &config, RequestSendMode::Sync, tx).wait_for_finish();
+    let end = start.elapsed();
+    monitor.stop();
+    status_updater.stop();
+    let resp = response_collector.stop();
+    let stats = Stats::new(Arc::try_unwrap(resp).unwrap().into_inner().unwrap(), end);
+    stats.print_summary(title);
+    stats
+}
+
 pub struct BackgroundLoadProcess {
     executor: Executor,
     monitor: MonitorThread,
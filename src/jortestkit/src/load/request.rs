@@ -30,6 +30,12 @@ pub struct Request {
 pub trait RequestGenerator: Send + Sized {
     fn split(self) -> (Self, Option<Self>);
     fn next(&mut self) -> Result<Request, RequestFailure>;
+    /// Whether the generator has no request left to send. It is checked
+    /// before each request, an exhausted generator ending its share of the
+    /// load before the configured count or duration is reached
+    fn is_exhausted(&self) -> bool {
+        false
+    }
 }
 
 pub struct RayonWrapper<T>(T);
@@ -51,7 +57,7 @@ impl<T: RequestGenerator> UnindexedProducer for RayonWrapper<T> {
     where
         F: Folder<Self::Item>,
     {
-        while !folder.full() {
+        while !folder.full() && !self.0.is_exhausted() {
             folder = folder.consume(self.0.next());
         }
         folder
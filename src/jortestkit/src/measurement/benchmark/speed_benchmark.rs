@@ -90,6 +90,10 @@ impl SpeedBenchmarkFinish {
     pub fn new(definition: SpeedBenchmarkDef, speed: Speed) -> Self {
         Self { definition, speed }
     }
+
+    pub fn speed(&self) -> &Speed {
+        &self.speed
+    }
 }
 
 impl fmt::Display for SpeedBenchmarkFinish {
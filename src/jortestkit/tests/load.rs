@@ -4,6 +4,10 @@ pub use jortestkit::load::{
     RequestStatusProvider, Response,
 };
 use load::Status;
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
 use std::time::Duration;
 
 #[derive(Clone, Debug)]
@@ -108,3 +112,35 @@ pub fn load_sanity_async() {
         "Mock async load",
     );
 }
+
+#[derive(Clone, Debug, Default)]
+pub struct CountingRequestStatusProvider {
+    calls: Arc<AtomicUsize>,
+}
+
+impl RequestStatusProvider for CountingRequestStatusProvider {
+    fn get_statuses(&self, ids: &[Id]) -> Vec<Status> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        assert!(ids.is_empty(), "synchronous requests are never pending");
+        Vec::new()
+    }
+}
+
+#[test]
+pub fn load_sanity_sync_with_status() {
+    let config = ConfigurationBuilder::duration(Duration::from_secs(2))
+        .step_delay(Duration::from_millis(50))
+        .status_pace(Duration::from_millis(100))
+        .monitor(Monitor::Progress(10))
+        .build();
+    let status_provider = CountingRequestStatusProvider::default();
+    let calls = Arc::clone(&status_provider.calls);
+
+    load::start_sync_with_status(
+        SampleRequestGenerator { counter: 1 },
+        status_provider,
+        config,
+        "Mock load with status",
+    );
+    assert!(calls.load(Ordering::SeqCst) > 0);
+}
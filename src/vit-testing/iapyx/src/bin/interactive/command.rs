@@ -1,11 +1,19 @@
+use super::repl::Interactive;
 use super::CliController;
 use bech32::ToBase32;
 use catalyst_toolbox::kedqr::decode;
 use catalyst_toolbox::kedqr::KeyQrCode;
 use catalyst_toolbox::kedqr::KeyQrCodeError;
+use catalyst_toolbox::kedqr::KeyQrCodePayloadError;
+use catalyst_toolbox::kedqr::{BadPinError, QrPin};
+use chain_addr::Discrimination;
+use chain_crypto::bech32::Bech32;
+use chain_crypto::{Ed25519Extended, SecretKey};
 use chain_impl_mockchain::block::BlockDate;
 use iapyx::ControllerBuilderError;
 use iapyx::ControllerError;
+use iapyx::{ProposalsFilter, SortOrder, VoteCounts};
+use iapyx::{Wallet, WalletError};
 use jcli_lib::key::read_bech32;
 use jormungandr_automation::jormungandr::RestError;
 use jormungandr_lib::crypto::hash::Hash;
@@ -53,6 +61,10 @@ pub enum IapyxCommand {
     PendingTransactions,
     /// Allows to manage wallets: add/remove/select operations
     Wallets(Wallets),
+    /// Starts interactive mode: browse proposals, vote and follow votes statuses
+    Interactive(Interactive),
+    /// Generates and reads registration QR codes
+    Qr(Qr),
 }
 
 const DELIMITER: &str = "===================";
@@ -65,6 +77,7 @@ impl IapyxCommand {
     pub fn exec(self, mut model: CliController) -> Result<(), IapyxCommandError> {
         match self {
             IapyxCommand::Wallets(wallets) => wallets.exec(model),
+            IapyxCommand::Qr(qr) => qr.exec(),
             IapyxCommand::Connect(connect) => connect.exec(model),
             IapyxCommand::Proposals(proposals) => proposals.exec(model),
             IapyxCommand::Address => {
@@ -122,6 +135,7 @@ impl IapyxCommand {
                 Ok(())
             }
             IapyxCommand::Votes(votes) => votes.exec(model),
+            IapyxCommand::Interactive(interactive) => interactive.exec(model),
         }
     }
 }
@@ -368,11 +382,107 @@ pub enum IapyxCommandError {
     #[error(transparent)]
     KeyQrCode(#[from] KeyQrCodeError),
     #[error(transparent)]
+    KeyQrCodePayload(#[from] KeyQrCodePayloadError),
+    #[error(transparent)]
     Key(#[from] jcli_lib::key::Error),
     #[error(transparent)]
     Read(#[from] chain_core::property::ReadError),
     #[error(transparent)]
     Io(#[from] std::io::Error),
+    #[error(transparent)]
+    Wallet(#[from] WalletError),
+    #[error(transparent)]
+    BadPin(#[from] BadPinError),
+    #[error(transparent)]
+    SecretKey(#[from] chain_crypto::bech32::Error),
+    #[error("interactive mode error: {0}")]
+    Interactive(String),
+}
+
+#[derive(StructOpt, Debug)]
+pub enum Qr {
+    /// Generates the registration QR code of a wallet
+    Generate {
+        /// Path to the bech32 encoded secret key of the wallet
+        #[structopt(long)]
+        secret: PathBuf,
+
+        /// Pin protecting the QR code
+        #[structopt(short, long)]
+        pin: String,
+
+        /// Output file: an svg or png image, or the hex payload with --payload
+        #[structopt(short, long)]
+        output: PathBuf,
+
+        /// Writes the payload of the QR code instead of an image
+        #[structopt(long)]
+        payload: bool,
+    },
+    /// Reads a registration QR code and prints the account it registers
+    Read {
+        /// Path to the QR code image, or to the payload with --payload
+        #[structopt(name = "INPUT")]
+        input: PathBuf,
+
+        /// Pin protecting the QR code
+        #[structopt(short, long)]
+        pin: String,
+
+        /// Reads the payload of the QR code instead of an image
+        #[structopt(long)]
+        payload: bool,
+
+        /// If true testing discrimination is used, otherwise production
+        #[structopt(short, long)]
+        testing: bool,
+    },
+}
+
+impl Qr {
+    pub fn exec(self) -> Result<(), IapyxCommandError> {
+        match self {
+            Self::Generate {
+                secret,
+                pin,
+                output,
+                payload,
+            } => {
+                let pin = QrPin::from_str(&pin)?;
+                let secret = SecretKey::<Ed25519Extended>::try_from_bech32_str(
+                    jortestkit::file::read_file(secret)?.trim(),
+                )?;
+                if payload {
+                    std::fs::write(&output, iapyx::qr_payload(secret, &pin))?;
+                } else if output.extension().map_or(false, |ext| ext == "svg") {
+                    iapyx::qr_code(secret, &pin).write_svg(&output)?;
+                } else {
+                    iapyx::qr_code(secret, &pin).to_img().save(&output)?;
+                }
+                Ok(())
+            }
+            Self::Read {
+                input,
+                pin,
+                payload,
+                testing,
+            } => {
+                let pin = QrPin::from_str(&pin)?;
+                let wallet = if payload {
+                    Wallet::from_qr_payload(&jortestkit::file::read_file(input)?, &pin)?
+                } else {
+                    Wallet::from_qr_code(input, &pin)?
+                };
+                let discrimination = if testing {
+                    Discrimination::Test
+                } else {
+                    Discrimination::Production
+                };
+                println!("Account: {}", wallet.identifier(discrimination));
+                Ok(())
+            }
+        }
+    }
 }
 
 #[derive(StructOpt, Debug)]
@@ -410,11 +520,49 @@ pub struct Proposals {
     pub limit: Option<usize>,
     #[structopt(default_value = "direct", long)]
     pub voting_group: String,
+    /// Show only proposals of given fund
+    #[structopt(long)]
+    pub fund_id: Option<i32>,
+    /// Show only proposals of given challenge
+    #[structopt(long)]
+    pub challenge_id: Option<i32>,
+    /// Show only proposals with title containing given text (case insensitive)
+    #[structopt(long)]
+    pub search: Option<String>,
+    /// Sort proposals by number of votes cast, most voted first
+    #[structopt(long)]
+    pub sort_by_votes: bool,
 }
+
 impl Proposals {
+    fn filter(&self) -> ProposalsFilter {
+        let mut filter = ProposalsFilter::default();
+        if let Some(fund_id) = self.fund_id {
+            filter = filter.fund_id(fund_id);
+        }
+        if let Some(challenge_id) = self.challenge_id {
+            filter = filter.challenge_id(challenge_id);
+        }
+        if let Some(search) = &self.search {
+            filter = filter.title_contains(search.as_str());
+        }
+        if self.sort_by_votes {
+            filter = filter.sort_by_votes(SortOrder::Descending);
+        }
+        filter
+    }
+
     pub fn exec(self, model: CliController) -> Result<(), IapyxCommandError> {
+        let filter = self.filter();
+        let vote_counts = if filter.needs_vote_counts() {
+            model.vote_counts()?
+        } else {
+            VoteCounts::default()
+        };
+        let proposals = filter.apply(&model.proposals(&self.voting_group)?, &vote_counts);
+
         print_delim();
-        for (id, proposal) in model.proposals(&self.voting_group)?.iter().enumerate() {
+        for (id, proposal) in proposals.iter().enumerate() {
             if let Some(limit) = self.limit {
                 if id >= limit {
                     break;
@@ -527,11 +675,11 @@ impl WalletAddSubcommand {
             }
             Self::QR { qr, pin, testing } => {
                 let img = image::open(qr)?;
-                let bytes: Vec<u8> = pin.chars().map(|x| x.to_digit(10).unwrap() as u8).collect();
-                let secret = KeyQrCode::decode(img, &bytes)?
-                    .get(0)
-                    .unwrap()
-                    .clone()
+                let qr_pin = QrPin::from_str(&pin)?;
+                let secret = KeyQrCode::decode(img, &qr_pin.password)?
+                    .into_iter()
+                    .next()
+                    .ok_or(WalletError::EmptyQrCode)?
                     .leak_secret();
 
                 controller
@@ -539,9 +687,8 @@ impl WalletAddSubcommand {
                     .add_wallet(alias, testing, secret.to_base32(), &pin)?
             }
             Self::Hash { hash, pin, testing } => {
-                let bytes: Vec<u8> = pin.chars().map(|x| x.to_digit(10).unwrap() as u8).collect();
-                let secret = decode(jortestkit::file::read_file(hash)?, &bytes)
-                    .unwrap()
+                let qr_pin = QrPin::from_str(&pin)?;
+                let secret = decode(jortestkit::file::read_file(hash)?.trim(), &qr_pin.password)?
                     .leak_secret();
                 controller
                     .wallets_mut()
@@ -1,4 +1,5 @@
 mod command;
+mod repl;
 
 use bech32::u5;
 use bech32::FromBase32;
@@ -7,6 +8,7 @@ use chain_impl_mockchain::fragment::FragmentId;
 use cocoon::Cocoon;
 pub use command::{IapyxCommand, IapyxCommandError};
 use iapyx::Controller;
+use iapyx::VoteCounts;
 use iapyx::Wallet;
 use jormungandr_automation::jormungandr::RestError;
 use jormungandr_lib::interfaces::AccountState;
@@ -112,6 +114,7 @@ impl CliController {
                 },
                 false,
             ),
+            proposals_cache: Default::default(),
         })
     }
 
@@ -119,6 +122,11 @@ impl CliController {
         self.backend_client.proposals(group).map_err(Into::into)
     }
 
+    pub fn vote_counts(&self) -> Result<VoteCounts, Error> {
+        let vote_plans = self.backend_client.vote_plan_statuses()?;
+        Ok(VoteCounts::from(vote_plans.as_slice()))
+    }
+
     pub fn funds(&self) -> Result<Fund, Error> {
         self.backend_client.funds().map_err(Into::into)
     }
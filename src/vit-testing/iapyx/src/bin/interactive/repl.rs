@@ -0,0 +1,233 @@
+use super::command::IapyxCommandError;
+use super::CliController;
+use chain_impl_mockchain::fragment::FragmentId;
+use iapyx::Controller;
+use jortestkit::prelude::{
+    ConsoleWriter, InteractiveCommandError, InteractiveCommandExec, UserInteraction,
+};
+use std::ffi::OsStr;
+use structopt::{clap::AppSettings, StructOpt};
+use valgrind::ProposalExtension;
+use vit_servicing_station_lib::db::models::proposals::FullProposalInfo;
+use wallet_core::Choice;
+
+#[derive(StructOpt, Debug)]
+pub struct Interactive {
+    /// Pin of the selected wallet
+    #[structopt(long, short)]
+    pub pin: String,
+    #[structopt(default_value = "direct", long)]
+    pub voting_group: String,
+}
+
+impl Interactive {
+    pub fn exec(self, model: CliController) -> Result<(), IapyxCommandError> {
+        let mut controller = model.controller(&self.pin)?;
+        controller.refresh_state()?;
+
+        let mut executor = WalletInteractiveCommandExec {
+            model,
+            controller,
+            voting_group: self.voting_group,
+            votes: Vec::new(),
+        };
+
+        wallet_user_interaction()
+            .interact(&mut executor)
+            .map_err(|err| IapyxCommandError::Interactive(err.to_string()))
+    }
+}
+
+fn wallet_user_interaction() -> UserInteraction {
+    UserInteraction::new(
+        "iapyx".to_string(),
+        "iapyx interactive mode".to_string(),
+        "type command:".to_string(),
+        "exit".to_string(),
+        ">".to_string(),
+        vec![
+            "You can drive the selected wallet against the catalyst backend:".to_string(),
+            "- list funds and proposals,".to_string(),
+            "- show wallet balance,".to_string(),
+            "- cast votes and check their status.".to_string(),
+        ],
+    )
+}
+
+#[derive(StructOpt, Debug)]
+#[structopt(setting = AppSettings::NoBinaryName)]
+pub enum InteractiveCommand {
+    /// Prints current fund with its vote plans and challenges
+    Funds,
+    /// Prints proposals of the voting group, along with the index used to vote on them
+    Proposals {
+        /// Show only proposals with title containing given text (case insensitive)
+        #[structopt(long)]
+        search: Option<String>,
+    },
+    /// Pulls wallet state from the backend and prints its balance
+    Balance,
+    /// Casts a vote on the proposal with given index
+    Vote {
+        /// Index of the proposal, as printed by `proposals`
+        #[structopt(name = "INDEX")]
+        index: usize,
+        /// Choice, usually 'yes' or 'no'
+        #[structopt(name = "CHOICE")]
+        choice: String,
+    },
+    /// Prints statuses of votes cast in this session
+    Status,
+    /// Exit interactive mode
+    Exit,
+}
+
+const DELIMITER: &str = "===================";
+
+fn print_delim() {
+    println!("{}", DELIMITER);
+}
+
+pub struct WalletInteractiveCommandExec {
+    model: CliController,
+    controller: Controller,
+    voting_group: String,
+    /// fragment ids of votes cast in this session, with the proposal title
+    votes: Vec<(FragmentId, String)>,
+}
+
+impl InteractiveCommandExec for WalletInteractiveCommandExec {
+    fn parse_and_exec(
+        &mut self,
+        tokens: Vec<String>,
+        console: ConsoleWriter,
+    ) -> Result<(), InteractiveCommandError> {
+        match InteractiveCommand::from_iter_safe(&mut tokens.iter().map(OsStr::new)) {
+            Ok(interactive) => {
+                if let Err(err) = self.exec(interactive) {
+                    console.format_error(InteractiveCommandError::UserError(err.to_string()));
+                }
+            }
+            Err(err) => console.show_help(InteractiveCommandError::UserError(err.to_string())),
+        }
+        Ok(())
+    }
+}
+
+impl WalletInteractiveCommandExec {
+    fn exec(&mut self, command: InteractiveCommand) -> Result<(), IapyxCommandError> {
+        match command {
+            InteractiveCommand::Funds => {
+                let fund = self.controller.funds()?;
+                print_delim();
+                println!("{}. {}", fund.id, fund.fund_name);
+                println!("- Vote plans:");
+                for vote_plan in &fund.chain_vote_plans {
+                    println!(
+                        "  {} [{}] {}",
+                        vote_plan.chain_voteplan_id,
+                        vote_plan.chain_voteplan_payload,
+                        vote_plan.token_identifier
+                    );
+                }
+                println!("- Challenges:");
+                for challenge in &fund.challenges {
+                    println!("  {}. {}", challenge.id, challenge.title);
+                }
+                print_delim();
+                Ok(())
+            }
+            InteractiveCommand::Proposals { search } => {
+                print_delim();
+                for (idx, proposal) in self.proposals()?.iter().enumerate() {
+                    let title = &proposal.proposal.proposal_title;
+                    if let Some(search) = &search {
+                        if !title.to_lowercase().contains(&search.to_lowercase()) {
+                            continue;
+                        }
+                    }
+                    println!(
+                        "{}. {} [{}]",
+                        idx + 1,
+                        proposal.chain_proposal_id_as_str(),
+                        title
+                    );
+                    println!("{:?}", proposal.proposal.chain_vote_options.0);
+                }
+                print_delim();
+                Ok(())
+            }
+            InteractiveCommand::Balance => {
+                self.controller.refresh_state()?;
+                let account_state = self.controller.get_account_state()?;
+                print_delim();
+                println!("- Value: {}", account_state.value());
+                println!("- Spending counters: {:?}", account_state.counters());
+                println!(
+                    "- Pending transactions: {}",
+                    self.controller.pending_transactions().len()
+                );
+                print_delim();
+                Ok(())
+            }
+            InteractiveCommand::Vote { index, choice } => {
+                let proposals = self.proposals()?;
+                let proposal = index
+                    .checked_sub(1)
+                    .and_then(|idx| proposals.get(idx))
+                    .cloned()
+                    .ok_or_else(|| IapyxCommandError::CannotFindProposal(index.to_string()))?;
+                let choice_value = proposal
+                    .proposal
+                    .chain_vote_options
+                    .0
+                    .get(&choice)
+                    .ok_or_else(|| IapyxCommandError::WrongChoice(choice.clone()))?;
+
+                let id = self
+                    .controller
+                    .vote(&proposal, Choice::new(*choice_value))?;
+                self.model
+                    .wallets_mut()
+                    .wallet_mut()?
+                    .pending_tx
+                    .push(id.into());
+                self.model.save_config()?;
+
+                println!("Vote sent: {}", id);
+                self.votes
+                    .push((id, proposal.proposal.proposal_title.clone()));
+                Ok(())
+            }
+            InteractiveCommand::Status => {
+                let fragment_logs = self.controller.fragment_logs()?;
+                print_delim();
+                if self.votes.is_empty() {
+                    println!("No votes cast in this session");
+                }
+                for (idx, (id, title)) in self.votes.iter().enumerate() {
+                    match fragment_logs.get(id) {
+                        Some(log) => {
+                            println!("{}. {} [{}] -> {:?}", idx + 1, id, title, log.status())
+                        }
+                        None => println!(
+                            "{}. {} [{}] -> not found in fragment logs",
+                            idx + 1,
+                            id,
+                            title
+                        ),
+                    }
+                }
+                print_delim();
+                Ok(())
+            }
+            InteractiveCommand::Exit => Ok(()),
+        }
+    }
+
+    fn proposals(&mut self) -> Result<&[FullProposalInfo], IapyxCommandError> {
+        self.controller
+            .cached_proposals(&self.voting_group)
+            .map_err(Into::into)
+    }
+}
@@ -1,5 +1,6 @@
 use crate::load::build_monitor;
 use crate::load::IapyxLoadCommandError;
+use iapyx::DashboardConfig;
 use iapyx::NodeLoad;
 use iapyx::NodeLoadConfig;
 pub use jortestkit::console::progress_bar::{parse_progress_bar_mode_from_str, ProgressBarMode};
@@ -18,6 +19,11 @@ pub struct BurstDurationIapyxLoadCommand {
     #[structopt(short = "a", long = "address", default_value = "127.0.0.1:8000")]
     pub address: String,
 
+    /// Addresses of backends to fail over to when the main one is unavailable,
+    /// tried in order
+    #[structopt(long = "failover-address")]
+    pub failover_addresses: Vec<String>,
+
     /// Amount of delay (in miliseconds) between requests
     #[structopt(short = "d", long = "delay", default_value = "10000")]
     pub delay: u64,
@@ -84,6 +90,14 @@ pub struct BurstDurationIapyxLoadCommand {
 
     #[structopt(default_value = "direct", long)]
     pub voting_group: String,
+
+    /// Periodically write per proposal votes and tally results to this json file
+    #[structopt(long = "dashboard")]
+    pub dashboard: Option<PathBuf>,
+
+    /// How frequent (in seconds) to update the dashboard
+    #[structopt(long = "dashboard-interval", default_value = "10")]
+    pub dashboard_interval: u64,
 }
 
 impl BurstDurationIapyxLoadCommand {
@@ -111,6 +125,7 @@ impl BurstDurationIapyxLoadCommand {
             batch_size: self.batch_size,
             criterion: self.criterion,
             address: self.address.clone(),
+            failover_addresses: self.failover_addresses.clone(),
             qr_codes_folder: self.qr_codes_folder.clone(),
             reuse_accounts_early: self.reuse_accounts_early,
             reuse_accounts_lazy: self.reuse_accounts_lazy,
@@ -120,6 +135,12 @@ impl BurstDurationIapyxLoadCommand {
             use_https: self.use_https,
             debug: self.debug,
             voting_group: self.voting_group.clone(),
+            dashboard: self.dashboard.clone().map(|path| DashboardConfig {
+                path,
+                interval: Duration::from_secs(self.dashboard_interval),
+            }),
+            shape: None,
+            churn: None,
         }
     }
 }
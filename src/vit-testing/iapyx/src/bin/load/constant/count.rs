@@ -19,6 +19,11 @@ pub struct ConstantCountIapyxLoadCommand {
     #[structopt(short = "a", long = "address", default_value = "127.0.0.1:8000")]
     pub address: String,
 
+    /// Addresses of backends to fail over to when the main one is unavailable,
+    /// tried in order
+    #[structopt(long = "failover-address")]
+    pub failover_addresses: Vec<String>,
+
     /// Amount of delay (in miliseconds) between requests
     #[structopt(short = "d", long = "delay", default_value = "10000")]
     pub delay: u64,
@@ -105,6 +110,7 @@ impl ConstantCountIapyxLoadCommand {
             batch_size: 1,
             criterion: self.criterion,
             address: self.address.clone(),
+            failover_addresses: self.failover_addresses.clone(),
             qr_codes_folder: self.qr_codes_folder.clone(),
             reuse_accounts_lazy: self.reuse_accounts_lazy,
             reuse_accounts_early: self.reuse_accounts_early,
@@ -114,6 +120,9 @@ impl ConstantCountIapyxLoadCommand {
             use_https: self.use_https,
             debug: self.debug,
             voting_group: self.voting_group.clone(),
+            dashboard: None,
+            shape: None,
+            churn: None,
         }
     }
 }
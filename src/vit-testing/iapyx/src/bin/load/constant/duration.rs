@@ -1,5 +1,6 @@
 use crate::load::build_monitor;
 use crate::load::IapyxLoadCommandError;
+use iapyx::LoadShape;
 use iapyx::NodeLoad;
 use iapyx::NodeLoadConfig;
 pub use jortestkit::console::progress_bar::{parse_progress_bar_mode_from_str, ProgressBarMode};
@@ -19,13 +20,24 @@ pub struct ConstDurationIapyxLoadCommand {
     #[structopt(short = "a", long = "address", default_value = "127.0.0.1:8000")]
     pub address: String,
 
+    /// Addresses of backends to fail over to when the main one is unavailable,
+    /// tried in order
+    #[structopt(long = "failover-address")]
+    pub failover_addresses: Vec<String>,
+
     /// Amount of delay (in miliseconds) between requests
     #[structopt(short = "d", long = "delay", default_value = "10000")]
     pub delay: u64,
 
     /// Duration of scenario in seconds
-    #[structopt(long = "duration")]
-    pub duration: u64,
+    #[structopt(long = "duration", required_unless = "shape")]
+    pub duration: Option<u64>,
+
+    /// Path to a JSON file with the shape of the traffic: ramp-up, steady
+    /// rate, spike and ramp-down. The shape replaces the delay and the
+    /// duration
+    #[structopt(long = "shape")]
+    pub shape: Option<PathBuf>,
 
     /// Qr codes source folder
     #[structopt(short = "q", long = "qr-codes-folder")]
@@ -82,7 +94,11 @@ pub struct ConstDurationIapyxLoadCommand {
 
 impl ConstDurationIapyxLoadCommand {
     pub fn exec(&self) -> Result<(), IapyxLoadCommandError> {
-        let config = self.build_config();
+        let shape = match &self.shape {
+            Some(path) => Some(serde_json::from_str(&jortestkit::file::read_file(path)?)?),
+            None => None,
+        };
+        let config = self.build_config(shape);
         let iapyx_load = NodeLoad::new(config);
         if let Some(stats) = iapyx_load.start()? {
             stats.print()
@@ -90,8 +106,10 @@ impl ConstDurationIapyxLoadCommand {
         Ok(())
     }
 
-    fn build_config(&self) -> NodeLoadConfig {
-        let config = ConfigurationBuilder::duration(Duration::from_secs(self.duration))
+    fn build_config(&self, shape: Option<LoadShape>) -> NodeLoadConfig {
+        // the duration of the shape takes over if there is one
+        let duration = Duration::from_secs(self.duration.unwrap_or_default());
+        let config = ConfigurationBuilder::duration(duration)
             .thread_no(self.threads)
             .step_delay(Duration::from_millis(self.delay))
             .fetch_limit(250)
@@ -105,6 +123,7 @@ impl ConstDurationIapyxLoadCommand {
             batch_size: 1,
             criterion: self.criterion,
             address: self.address.clone(),
+            failover_addresses: self.failover_addresses.clone(),
             qr_codes_folder: self.qr_codes_folder.clone(),
             reuse_accounts_early: self.reuse_accounts_early,
             reuse_accounts_lazy: self.reuse_accounts_lazy,
@@ -114,6 +133,9 @@ impl ConstDurationIapyxLoadCommand {
             use_https: self.use_https,
             debug: self.debug,
             voting_group: self.voting_group.clone(),
+            dashboard: None,
+            shape,
+            churn: None,
         }
     }
 }
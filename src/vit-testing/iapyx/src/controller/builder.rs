@@ -5,14 +5,16 @@ use crate::utils::qr::SecretFromQrCode;
 use crate::Controller;
 use crate::Wallet;
 use bech32::FromBase32;
-use catalyst_toolbox::kedqr::KeyQrCode;
+use catalyst_toolbox::kedqr::BadPinError;
 use catalyst_toolbox::kedqr::PinReadMode;
+use catalyst_toolbox::kedqr::QrPin;
 use chain_crypto::Ed25519Extended;
 use chain_crypto::SecretKey;
 use jcli_lib::key::read_bech32;
 use jormungandr_automation::jormungandr::RestError;
 use std::convert::TryInto;
 use std::path::Path;
+use std::str::FromStr;
 use thiserror::Error;
 use valgrind::SettingsExtensions;
 use valgrind::ValgrindClient;
@@ -70,20 +72,8 @@ impl ControllerBuilder {
         qr: P,
         password: &str,
     ) -> Result<Self, Error> {
-        let img = image::open(qr.as_ref())?;
-        let bytes: Vec<u8> = password
-            .chars()
-            .map(|x| x.to_digit(10).unwrap() as u8)
-            .collect();
-        let secret = KeyQrCode::decode(img, &bytes)
-            .unwrap()
-            .get(0)
-            .unwrap()
-            .clone()
-            .leak_secret();
-        self.wallet = Some(Wallet::recover_from_utxo(
-            secret.as_ref().try_into().unwrap(),
-        )?);
+        let pin = QrPin::from_str(password)?;
+        self.wallet = Some(Wallet::from_qr_code(qr, &pin)?);
         Ok(self)
     }
 
@@ -117,6 +107,7 @@ impl ControllerBuilder {
             block_date_generator: expiry::default_block_date_generator(
                 &backend.node_client().settings()?,
             ),
+            proposals_cache: Default::default(),
         })
     }
 }
@@ -143,5 +134,7 @@ pub enum Error {
     #[error(transparent)]
     PinRead(#[from] PinReadError),
     #[error(transparent)]
+    BadPin(#[from] BadPinError),
+    #[error(transparent)]
     Key(#[from] jcli_lib::key::Error),
 }
@@ -1,4 +1,5 @@
 mod builder;
+mod proposals;
 
 use crate::Wallet;
 pub use builder::{ControllerBuilder, Error as ControllerBuilderError};
@@ -10,6 +11,7 @@ use jormungandr_lib::interfaces::Address;
 use jormungandr_lib::interfaces::SettingsDto;
 use jormungandr_lib::interfaces::VotePlanId;
 use jormungandr_lib::interfaces::{AccountState, FragmentLog, FragmentStatus};
+pub use proposals::{ProposalsFilter, SortOrder, VoteCounts};
 use std::collections::HashMap;
 use thiserror::Error;
 use thor::BlockDateGenerator;
@@ -25,6 +27,8 @@ pub struct Controller {
     pub wallet: Wallet,
     pub settings: Settings,
     pub block_date_generator: BlockDateGenerator,
+    /// Proposals by voting group, fetched once from the servicing station
+    pub proposals_cache: HashMap<String, Vec<FullProposalInfo>>,
 }
 
 impl Controller {
@@ -137,6 +141,75 @@ impl Controller {
         self.backend.proposals(group).map_err(Into::into)
     }
 
+    /// Proposals of the voting group, fetched from the servicing station on first use only
+    pub fn cached_proposals(
+        &mut self,
+        group: &str,
+    ) -> Result<&[FullProposalInfo], ControllerError> {
+        if !self.proposals_cache.contains_key(group) {
+            let proposals = self.backend.proposals(group)?;
+            self.proposals_cache.insert(group.to_string(), proposals);
+        }
+        Ok(&self.proposals_cache[group])
+    }
+
+    pub fn clear_proposals_cache(&mut self) {
+        self.proposals_cache.clear();
+    }
+
+    pub fn vote_counts(&self) -> Result<VoteCounts, ControllerError> {
+        let vote_plans = self.backend.vote_plan_statuses()?;
+        Ok(VoteCounts::from(vote_plans.as_slice()))
+    }
+
+    pub fn filter_proposals(
+        &mut self,
+        group: &str,
+        filter: &ProposalsFilter,
+    ) -> Result<Vec<FullProposalInfo>, ControllerError> {
+        let vote_counts = if filter.needs_vote_counts() {
+            self.vote_counts()?
+        } else {
+            VoteCounts::default()
+        };
+        Ok(filter.apply(self.cached_proposals(group)?, &vote_counts))
+    }
+
+    pub fn proposals_by_fund(
+        &mut self,
+        group: &str,
+        fund_id: i32,
+    ) -> Result<Vec<FullProposalInfo>, ControllerError> {
+        self.filter_proposals(group, &ProposalsFilter::default().fund_id(fund_id))
+    }
+
+    pub fn proposals_by_challenge(
+        &mut self,
+        group: &str,
+        challenge_id: i32,
+    ) -> Result<Vec<FullProposalInfo>, ControllerError> {
+        self.filter_proposals(
+            group,
+            &ProposalsFilter::default().challenge_id(challenge_id),
+        )
+    }
+
+    pub fn search_proposals(
+        &mut self,
+        group: &str,
+        text: &str,
+    ) -> Result<Vec<FullProposalInfo>, ControllerError> {
+        self.filter_proposals(group, &ProposalsFilter::default().title_contains(text))
+    }
+
+    pub fn proposals_by_votes(
+        &mut self,
+        group: &str,
+        order: SortOrder,
+    ) -> Result<Vec<FullProposalInfo>, ControllerError> {
+        self.filter_proposals(group, &ProposalsFilter::default().sort_by_votes(order))
+    }
+
     pub fn funds(&self) -> Result<Fund, ControllerError> {
         self.backend.funds().map_err(Into::into)
     }
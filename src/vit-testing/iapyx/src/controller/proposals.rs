@@ -0,0 +1,115 @@
+use jormungandr_lib::interfaces::VotePlanStatus;
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use vit_servicing_station_lib::db::models::proposals::FullProposalInfo;
+
+/// Number of votes cast on each proposal, indexed by vote plan id and proposal index
+#[derive(Debug, Clone, Default)]
+pub struct VoteCounts(HashMap<(String, i64), usize>);
+
+impl VoteCounts {
+    pub fn get(&self, proposal: &FullProposalInfo) -> usize {
+        self.0
+            .get(&(
+                proposal.voteplan.chain_voteplan_id.clone(),
+                proposal.voteplan.chain_proposal_index,
+            ))
+            .copied()
+            .unwrap_or(0)
+    }
+}
+
+impl From<&[VotePlanStatus]> for VoteCounts {
+    fn from(vote_plans: &[VotePlanStatus]) -> Self {
+        Self(
+            vote_plans
+                .iter()
+                .flat_map(|vote_plan| {
+                    let id = vote_plan.id.to_string();
+                    vote_plan.proposals.iter().map(move |proposal| {
+                        ((id.clone(), proposal.index as i64), proposal.votes_cast)
+                    })
+                })
+                .collect(),
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortOrder {
+    Ascending,
+    Descending,
+}
+
+/// Narrows down the list of proposals returned by the servicing station.
+/// All criteria are optional and combined together.
+#[derive(Debug, Clone, Default)]
+pub struct ProposalsFilter {
+    fund_id: Option<i32>,
+    challenge_id: Option<i32>,
+    title: Option<String>,
+    sort_by_votes: Option<SortOrder>,
+}
+
+impl ProposalsFilter {
+    pub fn fund_id(mut self, fund_id: i32) -> Self {
+        self.fund_id = Some(fund_id);
+        self
+    }
+
+    pub fn challenge_id(mut self, challenge_id: i32) -> Self {
+        self.challenge_id = Some(challenge_id);
+        self
+    }
+
+    /// Case insensitive search over proposal titles
+    pub fn title_contains<S: Into<String>>(mut self, text: S) -> Self {
+        self.title = Some(text.into().to_lowercase());
+        self
+    }
+
+    pub fn sort_by_votes(mut self, order: SortOrder) -> Self {
+        self.sort_by_votes = Some(order);
+        self
+    }
+
+    /// Vote counts are fetched from the node only when sorting by them
+    pub fn needs_vote_counts(&self) -> bool {
+        self.sort_by_votes.is_some()
+    }
+
+    pub fn matches(&self, proposal: &FullProposalInfo) -> bool {
+        self.fund_id
+            .map_or(true, |fund_id| proposal.proposal.fund_id == fund_id)
+            && self.challenge_id.map_or(true, |challenge_id| {
+                proposal.proposal.challenge_id == challenge_id
+            })
+            && self.title.as_ref().map_or(true, |title| {
+                proposal
+                    .proposal
+                    .proposal_title
+                    .to_lowercase()
+                    .contains(title)
+            })
+    }
+
+    /// Returns matching proposals, sorted by votes if requested. Proposals with the same
+    /// number of votes keep the order of the servicing station.
+    pub fn apply<'a, I>(&self, proposals: I, vote_counts: &VoteCounts) -> Vec<FullProposalInfo>
+    where
+        I: IntoIterator<Item = &'a FullProposalInfo>,
+    {
+        let mut result: Vec<FullProposalInfo> = proposals
+            .into_iter()
+            .filter(|proposal| self.matches(proposal))
+            .cloned()
+            .collect();
+
+        match self.sort_by_votes {
+            Some(SortOrder::Ascending) => result.sort_by_key(|p| vote_counts.get(p)),
+            Some(SortOrder::Descending) => result.sort_by_key(|p| Reverse(vote_counts.get(p))),
+            None => {}
+        }
+        result
+    }
+}
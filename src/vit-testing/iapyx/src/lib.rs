@@ -5,10 +5,14 @@ mod load;
 pub mod utils;
 mod wallet;
 
-pub use crate::wallet::{Error as WalletError, Wallet};
-pub use controller::{Controller, ControllerBuilder, ControllerBuilderError, ControllerError};
+pub use crate::wallet::{qr_code, qr_payload, Error as WalletError, Wallet};
+pub use controller::{
+    Controller, ControllerBuilder, ControllerBuilderError, ControllerError, ProposalsFilter,
+    SortOrder, VoteCounts,
+};
 pub use load::{
-    ArtificialUserLoad, ArtificialUserLoadError, MultiController, MultiControllerError, NodeLoad,
-    NodeLoadConfig, NodeLoadError, ServicingStationLoad, ServicingStationLoadError,
-    VoteStatusProvider, WalletRequestGen,
+    ArtificialUserLoad, ArtificialUserLoadError, Churn, DashboardConfig, FailoverBackend,
+    LoadShape, MultiController, MultiControllerError, NodeLoad, NodeLoadConfig, NodeLoadError,
+    Registration, ServicingStationLoad, ServicingStationLoadError, VoteStatusProvider,
+    WalletRequestGen,
 };
@@ -0,0 +1,466 @@
+use crate::load::{MultiController, MultiControllerError};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::VecDeque,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+
+/// Wallets leaving and joining the vote while the load is running.
+///
+/// Once `after` has elapsed since the first vote of a thread, a share of its
+/// voting wallets stops voting, and a share of its wallets, held back until
+/// then, registers and starts voting. The registration of a joining wallet
+/// is submitted to the snapshot of the backend, the wallet voting once the
+/// backend reports it as a registered voter; until then its registration
+/// is checked again every `registration_retry`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Churn {
+    /// Percentage of the voting wallets which stop voting midway
+    pub dropout_percent: u8,
+    /// Percentage of the wallets held back at the start which join midway
+    pub join_percent: u8,
+    /// Time from the first vote after which the wallets drop out and join
+    pub after: Duration,
+    /// Delay before checking again the registration of a joining wallet
+    /// unknown to the backend
+    #[serde(default = "default_registration_retry")]
+    pub registration_retry: Duration,
+    /// Registration submitted for the joining wallets
+    #[serde(default)]
+    pub registration: Registration,
+}
+
+fn default_registration_retry() -> Duration {
+    Duration::from_secs(10)
+}
+
+/// Voter entry added to the snapshot for a joining wallet
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Registration {
+    /// Tag of the snapshot the wallet is added to
+    pub snapshot_tag: String,
+    /// Voting group of the wallet
+    pub voting_group: String,
+    /// Voting power of the wallet
+    pub voting_power: u64,
+    /// Api token of the vit servicing station, needed when it enables them
+    #[serde(default)]
+    pub api_token: Option<String>,
+}
+
+impl Default for Registration {
+    fn default() -> Self {
+        Self {
+            snapshot_tag: "daily".to_string(),
+            voting_group: "direct".to_string(),
+            voting_power: 1,
+            api_token: None,
+        }
+    }
+}
+
+/// Backend the joining wallets register with
+pub trait Registrar {
+    /// Submits the registration of the wallet
+    fn register(
+        &mut self,
+        wallet_index: usize,
+        registration: &Registration,
+    ) -> Result<(), MultiControllerError>;
+
+    /// Whether the backend knows the wallet as a registered voter
+    fn is_registered(
+        &mut self,
+        wallet_index: usize,
+        registration: &Registration,
+    ) -> Result<bool, MultiControllerError>;
+}
+
+impl Registrar for MultiController {
+    fn register(
+        &mut self,
+        wallet_index: usize,
+        registration: &Registration,
+    ) -> Result<(), MultiControllerError> {
+        MultiController::register(self, wallet_index, registration)
+    }
+
+    fn is_registered(
+        &mut self,
+        wallet_index: usize,
+        registration: &Registration,
+    ) -> Result<bool, MultiControllerError> {
+        MultiController::is_registered(self, wallet_index, registration)
+    }
+}
+
+/// Number of wallets out of `count` for the given percentage
+fn share(count: usize, percent: u8) -> usize {
+    count * usize::from(percent.min(100)) / 100
+}
+
+#[derive(Debug, Default)]
+struct Counters {
+    dropped_out: AtomicU64,
+    registered: AtomicU64,
+    joined: AtomicU64,
+    unregistered: AtomicU64,
+}
+
+/// Churn counters of the request generators of all the threads
+#[derive(Clone, Debug, Default)]
+pub struct ChurnStats {
+    counters: Arc<Counters>,
+}
+
+impl ChurnStats {
+    /// Number of wallets which stopped voting
+    pub fn dropped_out(&self) -> u64 {
+        self.counters.dropped_out.load(Ordering::Relaxed)
+    }
+
+    /// Number of registrations submitted
+    pub fn registered(&self) -> u64 {
+        self.counters.registered.load(Ordering::Relaxed)
+    }
+
+    /// Number of wallets which registered and started voting
+    pub fn joined(&self) -> u64 {
+        self.counters.joined.load(Ordering::Relaxed)
+    }
+
+    /// Number of registration attempts which failed and of registration
+    /// checks which found the joining wallet unknown to the backend
+    pub fn unregistered(&self) -> u64 {
+        self.counters.unregistered.load(Ordering::Relaxed)
+    }
+}
+
+struct Joiner {
+    index: usize,
+    submitted: bool,
+}
+
+/// Order in which the wallets of a request generator vote, applying the
+/// churn if any
+pub struct WalletRotation {
+    active: Vec<usize>,
+    dropouts: Vec<usize>,
+    joiners: VecDeque<Joiner>,
+    position: usize,
+    churn: Option<Churn>,
+    start: Option<Instant>,
+    churned: bool,
+    next_registration_check: Option<Instant>,
+    stats: ChurnStats,
+}
+
+impl WalletRotation {
+    pub fn new(wallet_count: usize, churn: Option<Churn>, stats: ChurnStats) -> Self {
+        let (join, dropout) = churn.as_ref().map_or((0, 0), |churn| {
+            let join = share(wallet_count, churn.join_percent);
+            (join, share(wallet_count - join, churn.dropout_percent))
+        });
+        let voters = wallet_count - join;
+        Self {
+            active: (0..voters).collect(),
+            dropouts: (0..dropout).collect(),
+            joiners: (voters..wallet_count)
+                .map(|index| Joiner {
+                    index,
+                    submitted: false,
+                })
+                .collect(),
+            position: 0,
+            churn,
+            start: None,
+            churned: false,
+            next_registration_check: None,
+            stats,
+        }
+    }
+
+    /// Rotation with the same churn for another set of wallets, e.g. those
+    /// of a split request generator
+    pub fn for_wallets(&self, wallet_count: usize) -> Self {
+        Self::new(wallet_count, self.churn.clone(), self.stats.clone())
+    }
+
+    pub fn stats(&self) -> ChurnStats {
+        self.stats.clone()
+    }
+
+    /// Whether no wallet votes anymore, none being left to join
+    pub fn is_exhausted(&self) -> bool {
+        self.active.is_empty() && self.joiners.is_empty()
+    }
+
+    /// Index of the next wallet to vote, `None` if the rotation is
+    /// exhausted. While no wallet votes, waits for a joining wallet to
+    /// register.
+    pub fn next<R: Registrar>(&mut self, registrar: &mut R) -> Option<usize> {
+        self.start.get_or_insert_with(Instant::now);
+        while self.active.is_empty() && !self.joiners.is_empty() {
+            std::thread::sleep(self.until_next_step());
+            self.step(registrar);
+        }
+        if self.active.is_empty() {
+            return None;
+        }
+        let index = self.active[self.position % self.active.len()];
+        self.position = (self.position + 1) % self.active.len();
+        // the churn is applied after the vote, so that the rotation is
+        // known to be exhausted before the next one
+        self.step(registrar);
+        Some(index)
+    }
+
+    fn until_next_step(&self) -> Duration {
+        let now = Instant::now();
+        let next_step = match (&self.churn, self.start) {
+            (Some(churn), Some(start)) if !self.churned => Some(start + churn.after),
+            _ => self.next_registration_check,
+        };
+        next_step.map_or(Duration::ZERO, |step| step.saturating_duration_since(now))
+    }
+
+    fn step<R: Registrar>(&mut self, registrar: &mut R) {
+        let churn = match &self.churn {
+            Some(churn) => churn.clone(),
+            None => return,
+        };
+        let start = *self.start.get_or_insert_with(Instant::now);
+        if !self.churned && start.elapsed() >= churn.after {
+            self.churned = true;
+            let dropouts = &self.dropouts;
+            self.active.retain(|index| !dropouts.contains(index));
+            self.stats
+                .counters
+                .dropped_out
+                .fetch_add(dropouts.len() as u64, Ordering::Relaxed);
+        }
+        if self.churned {
+            self.register_next(registrar, &churn);
+        }
+    }
+
+    // a single joining wallet is registered or checked before each vote, so
+    // that the registrations are spread over the load
+    fn register_next<R: Registrar>(&mut self, registrar: &mut R, churn: &Churn) {
+        let now = Instant::now();
+        if self
+            .next_registration_check
+            .map_or(false, |check| now < check)
+        {
+            return;
+        }
+        let mut joiner = match self.joiners.pop_front() {
+            Some(joiner) => joiner,
+            None => return,
+        };
+        if !joiner.submitted {
+            joiner.submitted = registrar
+                .register(joiner.index, &churn.registration)
+                .is_ok();
+            if joiner.submitted {
+                self.stats
+                    .counters
+                    .registered
+                    .fetch_add(1, Ordering::Relaxed);
+            } else {
+                self.stats
+                    .counters
+                    .unregistered
+                    .fetch_add(1, Ordering::Relaxed);
+                self.next_registration_check = Some(now + churn.registration_retry);
+            }
+            self.joiners.push_front(joiner);
+            return;
+        }
+        let registered = registrar
+            .is_registered(joiner.index, &churn.registration)
+            .unwrap_or(false);
+        if registered {
+            self.active.push(joiner.index);
+            self.next_registration_check = None;
+            self.stats.counters.joined.fetch_add(1, Ordering::Relaxed);
+        } else {
+            self.joiners.push_back(joiner);
+            self.next_registration_check = Some(now + churn.registration_retry);
+            self.stats
+                .counters
+                .unregistered
+                .fetch_add(1, Ordering::Relaxed);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    /// Registrar failing the first `fail_submissions` submissions, and
+    /// reporting a submitted registration as unknown for its first
+    /// `unknown_checks` checks
+    #[derive(Default)]
+    struct TestRegistrar {
+        unknown_checks: usize,
+        fail_submissions: usize,
+        submitted: Vec<usize>,
+        checks: Vec<usize>,
+    }
+
+    impl Registrar for TestRegistrar {
+        fn register(
+            &mut self,
+            wallet_index: usize,
+            _registration: &Registration,
+        ) -> Result<(), MultiControllerError> {
+            if self.fail_submissions > 0 {
+                self.fail_submissions -= 1;
+                return Err(MultiControllerError::NoBackend);
+            }
+            self.submitted.push(wallet_index);
+            Ok(())
+        }
+
+        fn is_registered(
+            &mut self,
+            wallet_index: usize,
+            _registration: &Registration,
+        ) -> Result<bool, MultiControllerError> {
+            assert!(self.submitted.contains(&wallet_index));
+            self.checks.push(wallet_index);
+            let checks = self.checks.iter().filter(|i| **i == wallet_index).count();
+            Ok(checks > self.unknown_checks)
+        }
+    }
+
+    fn churn(dropout_percent: u8, join_percent: u8) -> Churn {
+        Churn {
+            dropout_percent,
+            join_percent,
+            after: Duration::ZERO,
+            registration_retry: Duration::ZERO,
+            registration: Registration::default(),
+        }
+    }
+
+    fn votes<R: Registrar>(
+        rotation: &mut WalletRotation,
+        registrar: &mut R,
+        count: usize,
+    ) -> Vec<usize> {
+        std::iter::from_fn(|| rotation.next(registrar))
+            .take(count)
+            .collect()
+    }
+
+    #[test]
+    fn rotation_starts_with_first_wallet() {
+        let mut rotation = WalletRotation::new(3, None, ChurnStats::default());
+        let mut registrar = TestRegistrar::default();
+
+        assert_eq!(
+            votes(&mut rotation, &mut registrar, 7),
+            vec![0, 1, 2, 0, 1, 2, 0]
+        );
+        assert!(registrar.submitted.is_empty());
+    }
+
+    #[test]
+    fn no_churn_before_delay() {
+        let mut churn = churn(50, 50);
+        churn.after = Duration::from_secs(3600);
+        let stats = ChurnStats::default();
+        let mut rotation = WalletRotation::new(4, Some(churn), stats.clone());
+        let mut registrar = TestRegistrar::default();
+
+        assert_eq!(votes(&mut rotation, &mut registrar, 4), vec![0, 1, 0, 1]);
+        assert_eq!(stats.dropped_out(), 0);
+        assert!(registrar.submitted.is_empty());
+    }
+
+    #[test]
+    fn dropouts_stop_and_joiners_register() {
+        let stats = ChurnStats::default();
+        // 2 joiners (8, 9) out of 10, 2 dropouts (0, 1) out of the 8 voters
+        let mut rotation = WalletRotation::new(10, Some(churn(25, 20)), stats.clone());
+        let mut registrar = TestRegistrar::default();
+
+        let votes = votes(&mut rotation, &mut registrar, 30);
+
+        // the first vote is cast before the churn applies
+        assert_eq!(votes[0], 0);
+        assert!(votes[1..].iter().all(|index| *index > 1));
+        let voters: HashSet<_> = votes.iter().cloned().collect();
+        assert!(voters.contains(&8) && voters.contains(&9));
+        assert_eq!(registrar.submitted, vec![8, 9]);
+        assert_eq!(stats.dropped_out(), 2);
+        assert_eq!(stats.registered(), 2);
+        assert_eq!(stats.joined(), 2);
+        assert_eq!(stats.unregistered(), 0);
+    }
+
+    #[test]
+    fn unknown_registrations_are_checked_again() {
+        let stats = ChurnStats::default();
+        let mut rotation = WalletRotation::new(4, Some(churn(0, 50)), stats.clone());
+        let mut registrar = TestRegistrar {
+            unknown_checks: 2,
+            fail_submissions: 1,
+            ..Default::default()
+        };
+
+        let votes = votes(&mut rotation, &mut registrar, 20);
+
+        assert!(votes.contains(&2) && votes.contains(&3));
+        assert_eq!(registrar.submitted, vec![2, 3]);
+        assert_eq!(
+            registrar.checks.iter().filter(|index| **index == 2).count(),
+            3
+        );
+        assert_eq!(stats.registered(), 2);
+        assert_eq!(stats.joined(), 2);
+        // one failed submission and two unknown registrations per wallet
+        assert_eq!(stats.unregistered(), 5);
+    }
+
+    #[test]
+    fn joiners_register_while_no_wallet_votes() {
+        let stats = ChurnStats::default();
+        let mut rotation = WalletRotation::new(2, Some(churn(0, 100)), stats.clone());
+        let mut registrar = TestRegistrar::default();
+
+        assert!(!rotation.is_exhausted());
+        assert_eq!(rotation.next(&mut registrar), Some(0));
+        assert_eq!(registrar.checks, vec![0]);
+        assert_eq!(stats.joined(), 1);
+    }
+
+    #[test]
+    fn exhausted_once_all_wallets_dropped_out() {
+        let stats = ChurnStats::default();
+        let mut rotation = WalletRotation::new(2, Some(churn(100, 0)), stats.clone());
+        let mut registrar = TestRegistrar::default();
+
+        assert!(!rotation.is_exhausted());
+        assert_eq!(rotation.next(&mut registrar), Some(0));
+        assert!(rotation.is_exhausted());
+        assert_eq!(rotation.next(&mut registrar), None);
+        assert_eq!(stats.dropped_out(), 2);
+    }
+
+    #[test]
+    fn empty_rotation_is_exhausted() {
+        let mut rotation = WalletRotation::new(0, Some(churn(50, 50)), ChurnStats::default());
+
+        assert!(rotation.is_exhausted());
+        assert_eq!(rotation.next(&mut TestRegistrar::default()), None);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::load::MultiControllerError;
+use crate::load::{Churn, DashboardConfig, LoadShape, MultiControllerError};
 use crate::utils::qr::PinReadModeSettings;
 use crate::MultiController;
 use jormungandr_automation::jormungandr::RestSettings;
@@ -14,6 +14,9 @@ pub struct Config {
     pub batch_size: usize,
     pub criterion: Option<u8>,
     pub address: String,
+    /// Backends taking over when the one at `address` becomes unavailable
+    #[serde(default)]
+    pub failover_addresses: Vec<String>,
     pub debug: bool,
     pub use_https: bool,
     pub read_pin_from_filename: bool,
@@ -23,6 +26,16 @@ pub struct Config {
     pub qr_codes_folder: Option<PathBuf>,
     pub secrets_folder: Option<PathBuf>,
     pub voting_group: String,
+    #[serde(default)]
+    pub dashboard: Option<DashboardConfig>,
+    /// Shape of the traffic, replacing the delay between the requests and
+    /// the duration of the load configuration. Only applies to votes sent
+    /// one by one, the load is refused with a batch size above 1
+    #[serde(default)]
+    pub shape: Option<LoadShape>,
+    /// Wallets dropping out and joining while the load is running
+    #[serde(default)]
+    pub churn: Option<Churn>,
 }
 
 impl Config {
@@ -34,6 +47,13 @@ impl Config {
         }
     }
 
+    /// Primary backend address followed by the failover ones
+    pub fn backend_addresses(&self) -> Vec<String> {
+        std::iter::once(self.address.clone())
+            .chain(self.failover_addresses.iter().cloned())
+            .collect()
+    }
+
     pub fn build_multi_controller(&self) -> Result<MultiController, Error> {
         if let Some(qr_codes) = &self.qr_codes_folder {
             let qr_codes: Vec<PathBuf> = std::fs::read_dir(qr_codes)
@@ -43,7 +63,7 @@ impl Config {
                 .collect();
 
             MultiController::recover_from_qrs(
-                &self.address,
+                &self.backend_addresses(),
                 &qr_codes,
                 PinReadModeSettings {
                     from_filename: self.read_pin_from_filename,
@@ -58,8 +78,12 @@ impl Config {
                 .into_iter()
                 .map(|x| x.unwrap().path())
                 .collect();
-            MultiController::recover_from_sks(&self.address, &secrets, self.rest_settings())
-                .map_err(Into::into)
+            MultiController::recover_from_sks(
+                &self.backend_addresses(),
+                &secrets,
+                self.rest_settings(),
+            )
+            .map_err(Into::into)
         } else {
             Err(Error::CannotFindPrivateKeyRoot)
         }
@@ -0,0 +1,261 @@
+use jormungandr_automation::jormungandr::RestError as NodeRestError;
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+use valgrind::{Error as BackendError, ProxyClientError, ValgrindClient};
+
+/// Wallet backends (proxy instances) exposing the same network, of which
+/// only one is used at a time.
+///
+/// Selection is sticky: requests go to the active backend for as long as
+/// it answers. When it is unreachable, times out or responds with a server
+/// error, the next backend passing the health check becomes the active one
+/// and the request is retried on it. Some requests do not tell a server
+/// error apart from an unexpected answer, those failures are confirmed with
+/// a health check of the backend. Clones share the selection, so all load
+/// threads, sending votes or checking their statuses, move to the new
+/// backend together.
+#[derive(Clone)]
+pub struct FailoverBackend {
+    backends: Arc<Vec<ValgrindClient>>,
+    active: Arc<AtomicUsize>,
+}
+
+impl FailoverBackend {
+    /// # Panics
+    ///
+    /// Panics if `backends` is empty.
+    pub fn new(backends: Vec<ValgrindClient>) -> Self {
+        assert!(!backends.is_empty(), "at least one backend is required");
+        Self {
+            backends: Arc::new(backends),
+            active: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    /// Backend currently used
+    pub fn active(&self) -> &ValgrindClient {
+        &self.backends[self.active.load(Ordering::Acquire)]
+    }
+
+    pub fn enable_logs(&mut self) {
+        for backend in Arc::make_mut(&mut self.backends) {
+            backend.enable_logs();
+        }
+    }
+
+    pub fn disable_logs(&mut self) {
+        for backend in Arc::make_mut(&mut self.backends) {
+            backend.disable_logs();
+        }
+    }
+
+    pub fn set_vit_api_token(&mut self, token: String) {
+        for backend in Arc::make_mut(&mut self.backends) {
+            backend.set_vit_api_token(token.clone());
+        }
+    }
+
+    /// Runs the request on the active backend, failing over to the other
+    /// backends if it is unavailable. Errors unrelated to the availability
+    /// of the backend, like a rejected fragment, are returned as is.
+    pub fn call<T, F>(&self, request: F) -> Result<T, BackendError>
+    where
+        F: Fn(&ValgrindClient) -> Result<T, BackendError>,
+    {
+        let mut attempts = 1;
+        loop {
+            let index = self.active.load(Ordering::Acquire);
+            let backend = &self.backends[index];
+            match request(backend) {
+                Err(err) if attempts < self.backends.len() && is_unavailable(backend, &err) => {
+                    if !self.fail_over(index) {
+                        return Err(err);
+                    }
+                    attempts += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    fn fail_over(&self, failed: usize) -> bool {
+        // other thread already moved away from the failed backend
+        if self.active.load(Ordering::Acquire) != failed {
+            return true;
+        }
+        let count = self.backends.len();
+        let healthy = (1..count)
+            .map(|offset| (failed + offset) % count)
+            .find(|candidate| self.backends[*candidate].health().is_ok());
+
+        match healthy {
+            Some(candidate) => {
+                if self
+                    .active
+                    .compare_exchange(failed, candidate, Ordering::AcqRel, Ordering::Acquire)
+                    .is_ok()
+                {
+                    eprintln!(
+                        "backend #{} is unavailable, failing over to backend #{}",
+                        failed, candidate
+                    );
+                }
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+fn is_unavailable(backend: &ValgrindClient, err: &BackendError) -> bool {
+    match availability(err) {
+        Availability::Unavailable => true,
+        Availability::Available => false,
+        Availability::Unknown => backend.health().is_err(),
+    }
+}
+
+enum Availability {
+    Unavailable,
+    Available,
+    /// The backend answered something unexpected, which may be the error
+    /// page of a failed backend
+    Unknown,
+}
+
+fn availability(err: &BackendError) -> Availability {
+    let from_status = |server_error: bool| {
+        if server_error {
+            Availability::Unavailable
+        } else {
+            Availability::Available
+        }
+    };
+    match err {
+        BackendError::NodeConnection(err) => match err {
+            NodeRestError::Connection(_) | NodeRestError::Timeout(_) => Availability::Unavailable,
+            NodeRestError::HttpStatus { status, .. }
+            | NodeRestError::NonSuccessErrorCode { status, .. } => {
+                from_status(status.is_server_error())
+            }
+            NodeRestError::CannotDeserialize(_) | NodeRestError::RequestError(_) => {
+                Availability::Unknown
+            }
+            _ => Availability::Available,
+        },
+        BackendError::ProxyConnection(err) => match err {
+            ProxyClientError::Request(_) | ProxyClientError::ServerIsNotUp(_) => {
+                Availability::Unavailable
+            }
+            ProxyClientError::StatusCode(status) => from_status(status.is_server_error()),
+            ProxyClientError::CannotDeserializeResponse { .. } => Availability::Unknown,
+        },
+        _ => Availability::Available,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::StatusCode;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+
+    /// Address of a backend answering every request with an empty success
+    fn healthy_backend() -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut reader = BufReader::new(stream.unwrap());
+                let mut line = String::new();
+                while reader.read_line(&mut line).unwrap_or(0) > 2 {
+                    line.clear();
+                }
+                let _ = reader.get_mut().write_all(
+                    b"HTTP/1.1 200 OK\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+                );
+            }
+        });
+        format!("http://{}", address)
+    }
+
+    /// Address of a backend that is down
+    fn dead_backend() -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        format!("http://{}", listener.local_addr().unwrap())
+    }
+
+    fn failover_backend(addresses: &[String]) -> FailoverBackend {
+        FailoverBackend::new(
+            addresses
+                .iter()
+                .map(|address| ValgrindClient::new(address.clone(), Default::default()).unwrap())
+                .collect(),
+        )
+    }
+
+    fn health(backend: &ValgrindClient) -> Result<(), BackendError> {
+        backend.health().map_err(Into::into)
+    }
+
+    #[test]
+    fn clones_fail_over_together() {
+        let backend = failover_backend(&[dead_backend(), healthy_backend()]);
+        let mut status_backend = backend.clone();
+        status_backend.disable_logs();
+
+        assert!(backend.call(health).is_ok());
+        assert_eq!(backend.active.load(Ordering::Acquire), 1);
+        assert_eq!(status_backend.active.load(Ordering::Acquire), 1);
+        assert!(status_backend.call(health).is_ok());
+    }
+
+    #[test]
+    fn error_is_returned_when_no_backend_is_healthy() {
+        let backend = failover_backend(&[dead_backend(), dead_backend()]);
+        assert!(matches!(
+            backend.call(health),
+            Err(BackendError::ProxyConnection(ProxyClientError::Request(_)))
+        ));
+        assert_eq!(backend.active.load(Ordering::Acquire), 0);
+    }
+
+    #[test]
+    fn server_errors_make_the_backend_unavailable() {
+        let error = |status| {
+            BackendError::NodeConnection(NodeRestError::NonSuccessErrorCode {
+                response: String::new(),
+                status,
+                checks: Vec::new(),
+            })
+        };
+        let healthy = ValgrindClient::new(healthy_backend(), Default::default()).unwrap();
+        let dead = ValgrindClient::new(dead_backend(), Default::default()).unwrap();
+
+        assert!(is_unavailable(&healthy, &error(StatusCode::BAD_GATEWAY)));
+        assert!(!is_unavailable(&dead, &error(StatusCode::BAD_REQUEST)));
+        assert!(is_unavailable(
+            &healthy,
+            &BackendError::ProxyConnection(ProxyClientError::StatusCode(
+                StatusCode::SERVICE_UNAVAILABLE
+            ))
+        ));
+    }
+
+    #[test]
+    fn unexpected_answers_are_checked_with_the_health_of_the_backend() {
+        let error = || {
+            BackendError::NodeConnection(NodeRestError::CannotDeserialize(
+                serde_json::from_str::<u32>("<html>").unwrap_err(),
+            ))
+        };
+        let healthy = ValgrindClient::new(healthy_backend(), Default::default()).unwrap();
+        let dead = ValgrindClient::new(dead_backend(), Default::default()).unwrap();
+
+        assert!(!is_unavailable(&healthy, &error()));
+        assert!(is_unavailable(&dead, &error()));
+    }
+}
@@ -1,11 +1,19 @@
+mod churn;
 mod config;
+mod failover;
 mod multi_controller;
 mod request_generators;
 mod scenario;
+mod shape;
 mod status_provider;
 
+pub use churn::{Churn, ChurnStats, Registrar, Registration, WalletRotation};
 pub use config::{ArtificialUserLoadConfig, NodeLoadConfig};
+pub use failover::FailoverBackend;
 pub use multi_controller::{MultiController, MultiControllerError};
 pub use request_generators::{ServicingStationRequestGen, WalletRequestGen};
 pub use scenario::*;
-pub use status_provider::{Error as StatusProviderError, VoteStatusProvider};
+pub use shape::{LoadShape, Schedule};
+pub use status_provider::{
+    DashboardConfig, Error as StatusProviderError, ProposalProgress, VoteStatusProvider,
+};
@@ -1,3 +1,4 @@
+use crate::load::{FailoverBackend, Registration};
 use crate::utils::qr::read_qrs;
 use crate::utils::qr::PinReadError;
 use crate::utils::qr::PinReadModeSettings;
@@ -6,12 +7,15 @@ use bech32::FromBase32;
 use chain_impl_mockchain::{block::BlockDate, fragment::FragmentId};
 use jcli_lib::key::read_bech32;
 pub use jormungandr_automation::jormungandr::RestSettings;
+use snapshot_lib::{SnapshotInfo, VoterHIR};
 use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
 use thiserror::Error;
 use valgrind::ProposalExtension;
 use valgrind::SettingsExtensions;
 use valgrind::ValgrindClient;
 use vit_servicing_station_lib::db::models::proposals::FullProposalInfo;
+use vit_servicing_station_lib::v0::endpoints::snapshot::SnapshotInfoInput;
 use wallet::Settings;
 use wallet_core::{Choice, Value};
 
@@ -19,21 +23,39 @@ unsafe impl Send for Wallet {}
 use std::convert::TryInto;
 
 pub struct MultiController {
-    pub(super) backend: ValgrindClient,
+    pub(super) backend: FailoverBackend,
     pub(super) wallets: Vec<Wallet>,
     pub(super) settings: Settings,
 }
 
+/// Connects to all given wallet backends, enabling failover between them
+fn connect(
+    backend_addresses: &[String],
+    backend_settings: RestSettings,
+) -> Result<FailoverBackend, MultiControllerError> {
+    if backend_addresses.is_empty() {
+        return Err(MultiControllerError::NoBackend);
+    }
+    let backends = backend_addresses
+        .iter()
+        .map(|address| ValgrindClient::new(address.to_string(), backend_settings.clone()))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(FailoverBackend::new(backends))
+}
+
 impl MultiController {
+    /// Recovers wallets from qr codes. Requests are sent to the first of the
+    /// backend addresses, the others being used when it becomes unavailable
     pub fn recover_from_qrs<P: AsRef<Path>>(
-        wallet_backend_address: &str,
+        backend_addresses: &[String],
         qrs: &[P],
         pin_mode: PinReadModeSettings,
         backend_settings: RestSettings,
     ) -> Result<Self, MultiControllerError> {
-        let mut backend =
-            ValgrindClient::new(wallet_backend_address.to_string(), backend_settings)?;
-        let settings = backend.settings()?.into_wallet_settings();
+        let mut backend = connect(backend_addresses, backend_settings)?;
+        let settings = backend
+            .call(|backend| backend.settings())?
+            .into_wallet_settings();
 
         backend.enable_logs();
         let wallets = read_qrs(qrs, pin_mode, true)
@@ -48,13 +70,17 @@ impl MultiController {
         })
     }
 
+    /// Recovers wallets from private keys. Requests are sent to the first of
+    /// the backend addresses, the others being used when it becomes unavailable
     pub fn recover_from_sks<P: AsRef<Path>>(
-        proxy_address: &str,
+        backend_addresses: &[String],
         private_keys: &[P],
         backend_settings: RestSettings,
     ) -> Result<Self, MultiControllerError> {
-        let backend = ValgrindClient::new(proxy_address.to_string(), backend_settings)?;
-        let settings = backend.settings()?.into_wallet_settings();
+        let backend = connect(backend_addresses, backend_settings)?;
+        let settings = backend
+            .call(|backend| backend.settings())?
+            .into_wallet_settings();
         let wallets = private_keys
             .iter()
             .map(|x| {
@@ -73,10 +99,12 @@ impl MultiController {
     }
 
     pub fn proposals(&self, group: &str) -> Result<Vec<FullProposalInfo>, MultiControllerError> {
-        self.backend.proposals(group).map_err(Into::into)
+        self.backend
+            .call(|backend| backend.proposals(group))
+            .map_err(Into::into)
     }
 
-    pub(crate) fn backend(&self) -> &ValgrindClient {
+    pub(crate) fn backend(&self) -> &FailoverBackend {
         &self.backend
     }
 
@@ -84,7 +112,9 @@ impl MultiController {
         let backend = self.backend().clone();
         let count = self.wallets.len();
         for (idx, wallet) in self.wallets.iter_mut().enumerate() {
-            let account_state = backend.account_state(wallet.id()).unwrap();
+            let account_state = backend
+                .call(|backend| backend.account_state(wallet.id()))
+                .unwrap();
             println!("{}/{} Updating account state", idx + 1, count);
             wallet.set_state((*account_state.value()).into(), account_state.counters());
         }
@@ -93,7 +123,9 @@ impl MultiController {
     pub fn update_wallet_state(&mut self, wallet_index: usize) {
         let backend = self.backend().clone();
         let wallet = self.wallets.get_mut(wallet_index).unwrap();
-        let account_state = backend.account_state(wallet.id()).unwrap();
+        let account_state = backend
+            .call(|backend| backend.account_state(wallet.id()))
+            .unwrap();
         wallet.set_state((*account_state.value()).into(), account_state.counters());
     }
     pub fn update_wallet_state_if(
@@ -121,8 +153,9 @@ impl MultiController {
             choice,
             &valid_until,
         )?;
+        let tx = tx.to_vec();
         self.backend()
-            .send_fragment(tx.to_vec())
+            .call(|backend| backend.send_fragment(tx.clone()))
             .map_err(Into::into)
     }
 
@@ -134,11 +167,13 @@ impl MultiController {
         valid_until: &BlockDate,
     ) -> Result<Vec<FragmentId>, MultiControllerError> {
         let wallet = self.wallets.get_mut(wallet_index).unwrap();
-        let account_state = self.backend.account_state(wallet.id())?;
+        let account_state = self
+            .backend
+            .call(|backend| backend.account_state(wallet.id()))?;
 
         let mut counters = account_state.counters();
         let settings = self.settings.clone();
-        let txs = votes_data
+        let txs: Vec<Vec<u8>> = votes_data
             .into_iter()
             .map(|(p, c)| {
                 wallet.set_state((*account_state.value()).into(), counters);
@@ -158,7 +193,7 @@ impl MultiController {
             .collect();
 
         self.backend()
-            .send_fragments_at_once(txs, use_v1)
+            .call(|backend| backend.send_fragments_at_once(txs.clone(), use_v1))
             .map_err(Into::into)
     }
 
@@ -176,7 +211,9 @@ impl MultiController {
 
     pub fn refresh_wallet(&mut self, wallet_index: usize) -> Result<(), MultiControllerError> {
         let wallet = self.wallets.get_mut(wallet_index).unwrap();
-        let account_state = self.backend.account_state(wallet.id())?;
+        let account_state = self
+            .backend
+            .call(|backend| backend.account_state(wallet.id()))?;
         let value: u64 = (*account_state.value()).into();
         wallet.set_state(Value(value), account_state.counters());
         Ok(())
@@ -188,7 +225,52 @@ impl MultiController {
 
     pub fn is_converted(&mut self, wallet_index: usize) -> Result<bool, MultiControllerError> {
         let wallet = self.wallets.get_mut(wallet_index).unwrap();
-        self.backend.account_exists(wallet.id()).map_err(Into::into)
+        self.backend
+            .call(|backend| backend.account_exists(wallet.id()))
+            .map_err(Into::into)
+    }
+
+    /// Submits the registration of the wallet, adding it as a voter to the
+    /// snapshot of the backend
+    pub fn register(
+        &self,
+        wallet_index: usize,
+        registration: &Registration,
+    ) -> Result<(), MultiControllerError> {
+        let content = SnapshotInfoInput {
+            snapshot: vec![SnapshotInfo {
+                contributions: Vec::new(),
+                hir: VoterHIR {
+                    voting_key: self.wallets[wallet_index].voting_key(),
+                    voting_group: registration.voting_group.clone(),
+                    voting_power: registration.voting_power.into(),
+                },
+            }],
+            update_timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap()
+                .as_secs() as i64,
+        };
+        self.backend
+            .call(|backend| backend.put_snapshot_info(&registration.snapshot_tag, content.clone()))
+            .map_err(Into::into)
+    }
+
+    /// Whether the snapshot of the backend holds the registration of the
+    /// wallet
+    pub fn is_registered(
+        &self,
+        wallet_index: usize,
+        registration: &Registration,
+    ) -> Result<bool, MultiControllerError> {
+        let voting_key = self.wallets[wallet_index].voting_key().to_hex();
+        let info = self
+            .backend
+            .call(|backend| backend.voter_info(&registration.snapshot_tag, &voting_key))?;
+        Ok(info
+            .voter_info
+            .iter()
+            .any(|power| power.voting_group == registration.voting_group))
     }
 }
 
@@ -213,4 +295,8 @@ pub enum MultiControllerError {
     WalletTime(#[from] wallet::time::Error),
     #[error("not enough proposals")]
     NotEnoughProposals,
+    #[error("no wallet backend address given")]
+    NoBackend,
+    #[error("no wallet is voting")]
+    NoVotingWallet,
 }
@@ -1,4 +1,4 @@
-use crate::load::{MultiController, MultiControllerError};
+use crate::load::{Churn, ChurnStats, MultiController, MultiControllerError, WalletRotation};
 use crate::utils::expiry;
 use crate::Wallet;
 use jormungandr_automation::testing::VoteCastCounter;
@@ -20,7 +20,7 @@ pub struct BatchWalletRequestGen {
     proposals: Vec<FullProposalInfo>,
     options: Vec<u8>,
     use_v1: bool,
-    wallet_index: usize,
+    rotation: WalletRotation,
     update_account_before_vote: bool,
     vote_cast_counter: VoteCastCounter,
     block_date_generator: BlockDateGenerator,
@@ -43,8 +43,12 @@ impl BatchWalletRequestGen {
 
         println!("direct vote plans: {:?}", voting_groups_vote_plans_ids);
 
-        let vote_plans = multi_controller.backend().vote_plan_statuses()?;
-        let settings = multi_controller.backend().settings()?;
+        let vote_plans = multi_controller
+            .backend()
+            .call(|backend| backend.vote_plan_statuses())?;
+        let settings = multi_controller
+            .backend()
+            .call(|backend| backend.settings())?;
 
         let options = proposals[0]
             .proposal
@@ -66,11 +70,15 @@ impl BatchWalletRequestGen {
         Ok(Self {
             batch_size,
             use_v1,
+            rotation: WalletRotation::new(
+                multi_controller.wallet_count(),
+                None,
+                ChurnStats::default(),
+            ),
             multi_controller,
             rand: OsRng,
             proposals,
             options,
-            wallet_index: 0,
             update_account_before_vote,
             vote_cast_counter,
             settings: settings.clone().into_wallet_settings(),
@@ -78,18 +86,28 @@ impl BatchWalletRequestGen {
         })
     }
 
+    /// Makes a share of the wallets drop out and join midway, the counters
+    /// being shared with the generators split from this one
+    pub fn with_churn(mut self, churn: Churn, stats: ChurnStats) -> Self {
+        if let Some(token) = &churn.registration.api_token {
+            self.multi_controller
+                .backend
+                .set_vit_api_token(token.clone());
+        }
+        self.rotation =
+            WalletRotation::new(self.multi_controller.wallet_count(), Some(churn), stats);
+        self
+    }
+
     pub fn next_usize(&mut self) -> usize {
         self.rand.next_u32() as usize
     }
 
     pub fn random_votes(&mut self) -> Result<Vec<Option<Id>>, MultiControllerError> {
-        let wallet_index = {
-            self.wallet_index += 1;
-            if self.wallet_index >= self.multi_controller.wallet_count() {
-                self.wallet_index = 0;
-            }
-            self.wallet_index
-        };
+        let wallet_index = self
+            .rotation
+            .next(&mut self.multi_controller)
+            .ok_or(MultiControllerError::NoVotingWallet)?;
 
         // update state of wallet only before first vote.
         // Then rely on mechanism of spending counter auto-update
@@ -155,7 +173,11 @@ impl RequestGenerator for BatchWalletRequestGen {
             return (self, None);
         }
         let wallets = self.multi_controller.wallets.split_off(wallets_len / 2);
+        self.rotation = self
+            .rotation
+            .for_wallets(self.multi_controller.wallets.len());
         let new_gen = Self {
+            rotation: self.rotation.for_wallets(wallets.len()),
             rand: self.rand,
             multi_controller: MultiController {
                 wallets,
@@ -166,7 +188,6 @@ impl RequestGenerator for BatchWalletRequestGen {
             options: self.options.clone(),
             use_v1: self.use_v1,
             batch_size: self.batch_size,
-            wallet_index: 0,
             update_account_before_vote: self.update_account_before_vote,
             vote_cast_counter: self.vote_cast_counter.clone(),
             settings: self.settings.clone(),
@@ -186,4 +207,8 @@ impl RequestGenerator for BatchWalletRequestGen {
             Err(e) => Err(RequestFailure::General(format!("{:?}", e))),
         }
     }
+
+    fn is_exhausted(&self) -> bool {
+        self.rotation.is_exhausted()
+    }
 }
@@ -1,4 +1,6 @@
-use crate::load::{MultiController, MultiControllerError};
+use crate::load::{
+    Churn, ChurnStats, MultiController, MultiControllerError, Schedule, WalletRotation,
+};
 use crate::utils::expiry;
 use crate::Wallet;
 use chain_impl_mockchain::fragment::FragmentId;
@@ -6,7 +8,7 @@ use jormungandr_automation::testing::VoteCastCounter;
 use jortestkit::load::{Request, RequestFailure, RequestGenerator};
 use rand::seq::SliceRandom;
 use rand_core::OsRng;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 use thor::BlockDateGenerator;
 use valgrind::SettingsExtensions;
 use vit_servicing_station_lib::db::models::proposals::FullProposalInfo;
@@ -18,11 +20,15 @@ pub struct WalletRequestGen {
     multi_controller: MultiController,
     proposals: Vec<FullProposalInfo>,
     options: Vec<u8>,
-    wallet_index: usize,
+    rotation: WalletRotation,
     update_account_before_vote: bool,
     vote_cast_counter: VoteCastCounter,
     block_date_generator: BlockDateGenerator,
     settings: Settings,
+    schedule: Option<Schedule>,
+    /// Offset in the schedule of the next vote, claimed ahead so that the
+    /// generator knows when the shape is over
+    next_slot: Option<Duration>,
 }
 
 impl WalletRequestGen {
@@ -32,8 +38,12 @@ impl WalletRequestGen {
         group: &str,
     ) -> Result<Self, super::RequestGenError> {
         let proposals = multi_controller.proposals(group)?;
-        let vote_plans = multi_controller.backend().vote_plan_statuses()?;
-        let settings = multi_controller.backend().settings()?;
+        let vote_plans = multi_controller
+            .backend()
+            .call(|backend| backend.vote_plan_statuses())?;
+        let settings = multi_controller
+            .backend()
+            .call(|backend| backend.settings())?;
         let options = proposals[0]
             .proposal
             .chain_vote_options
@@ -51,26 +61,50 @@ impl WalletRequestGen {
         );
 
         Ok(Self {
+            rotation: WalletRotation::new(
+                multi_controller.wallet_count(),
+                None,
+                ChurnStats::default(),
+            ),
             multi_controller,
             proposals,
             options,
-            wallet_index: 0,
             update_account_before_vote,
             vote_cast_counter,
             rand: OsRng,
             settings: settings.clone().into_wallet_settings(),
             block_date_generator: expiry::default_block_date_generator(&settings),
+            schedule: None,
+            next_slot: None,
         })
     }
 
+    /// Sends the votes at the times given by the schedule, shared with
+    /// the generators split from this one
+    pub fn with_schedule(mut self, schedule: Schedule) -> Self {
+        self.next_slot = schedule.claim();
+        self.schedule = Some(schedule);
+        self
+    }
+
+    /// Makes a share of the wallets drop out and join midway, the counters
+    /// being shared with the generators split from this one
+    pub fn with_churn(mut self, churn: Churn, stats: ChurnStats) -> Self {
+        if let Some(token) = &churn.registration.api_token {
+            self.multi_controller
+                .backend
+                .set_vit_api_token(token.clone());
+        }
+        self.rotation =
+            WalletRotation::new(self.multi_controller.wallet_count(), Some(churn), stats);
+        self
+    }
+
     pub fn random_vote(&mut self) -> Result<FragmentId, MultiControllerError> {
-        let index = {
-            self.wallet_index += 1;
-            if self.wallet_index >= self.multi_controller.wallet_count() {
-                self.wallet_index = 0;
-            }
-            self.wallet_index
-        };
+        let index = self
+            .rotation
+            .next(&mut self.multi_controller)
+            .ok_or(MultiControllerError::NoVotingWallet)?;
 
         // update state of wallet only before first vote.
         // Then relay on mechanism of spending counter auto-update
@@ -104,7 +138,11 @@ impl RequestGenerator for WalletRequestGen {
             return (self, None);
         }
         let wallets = self.multi_controller.wallets.split_off(wallets_len / 2);
+        self.rotation = self
+            .rotation
+            .for_wallets(self.multi_controller.wallets.len());
         let new_gen = Self {
+            rotation: self.rotation.for_wallets(wallets.len()),
             rand: self.rand,
             multi_controller: MultiController {
                 wallets,
@@ -113,17 +151,25 @@ impl RequestGenerator for WalletRequestGen {
             },
             proposals: self.proposals.clone(),
             options: self.options.clone(),
-            wallet_index: 0,
             update_account_before_vote: self.update_account_before_vote,
             vote_cast_counter: self.vote_cast_counter.clone(),
             settings: self.settings.clone(),
             block_date_generator: self.block_date_generator.clone(),
+            next_slot: self.schedule.as_ref().and_then(Schedule::claim),
+            schedule: self.schedule.clone(),
         };
 
         (self, Some(new_gen))
     }
 
     fn next(&mut self) -> Result<Request, RequestFailure> {
+        if let Some(schedule) = &self.schedule {
+            let offset = self
+                .next_slot
+                .ok_or_else(|| RequestFailure::General("the load shape is over".to_string()))?;
+            schedule.wait_until(offset);
+            self.next_slot = schedule.claim();
+        }
         let start = Instant::now();
         match self.random_vote() {
             Ok(v) => Ok(Request {
@@ -133,4 +179,9 @@ impl RequestGenerator for WalletRequestGen {
             Err(e) => Err(RequestFailure::General(format!("{:?}", e))),
         }
     }
+
+    fn is_exhausted(&self) -> bool {
+        let shape_over = self.schedule.is_some() && self.next_slot.is_none();
+        shape_over || self.rotation.is_exhausted()
+    }
 }
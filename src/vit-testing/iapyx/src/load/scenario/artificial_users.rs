@@ -27,7 +27,7 @@ impl ArtificialUserLoad {
             multi_controller.update_wallets_state();
         }
 
-        let node_client = multi_controller.backend().node_client();
+        let node_client = multi_controller.backend().active().node_client();
 
         let transactions = BatchWalletRequestGen::new(
             multi_controller,
@@ -2,9 +2,11 @@ use crate::load::multi_controller::MultiControllerError;
 use crate::load::request_generators::RequestGenError;
 use crate::load::request_generators::{BatchWalletRequestGen, WalletRequestGen};
 use crate::load::status_provider::VoteStatusProvider;
-use crate::load::NodeLoadConfig;
+use crate::load::{ChurnStats, LoadShape, NodeLoadConfig, Schedule};
 use crate::NodeLoadError;
+use jortestkit::load::{Configuration, ConfigurationBuilder};
 use jortestkit::measurement::EfficiencyBenchmarkFinish;
+use std::time::Duration;
 use thiserror::Error;
 
 pub struct NodeLoad {
@@ -17,8 +19,9 @@ impl NodeLoad {
     }
 
     pub fn start(self) -> Result<Option<EfficiencyBenchmarkFinish>, NodeLoadError> {
-        let backend = self.config.address.clone();
-
+        if self.config.shape.is_some() && self.config.batch_size > 1 {
+            return Err(Error::ShapeWithBatches(self.config.batch_size));
+        }
         let mut multicontroller = self.config.build_multi_controller()?;
 
         if self.config.reuse_accounts_early {
@@ -27,33 +30,77 @@ impl NodeLoad {
 
         let measurement_name = "iapyx load test";
 
+        let mut status_provider =
+            VoteStatusProvider::new(multicontroller.backend().clone(), self.config.debug);
+        if let Some(dashboard) = self.config.dashboard.clone() {
+            status_provider = status_provider.with_dashboard(dashboard);
+        }
+
+        let churn_stats = ChurnStats::default();
+
         let stats = if self.config.batch_size > 1 {
+            let mut request_gen = BatchWalletRequestGen::new(
+                multicontroller,
+                self.config.batch_size,
+                self.config.use_v1,
+                self.config.reuse_accounts_lazy,
+                &self.config.voting_group,
+            )?;
+            if let Some(churn) = self.config.churn.clone() {
+                request_gen = request_gen.with_churn(churn, churn_stats.clone());
+            }
             jortestkit::load::start_async(
-                BatchWalletRequestGen::new(
-                    multicontroller,
-                    self.config.batch_size,
-                    self.config.use_v1,
-                    self.config.reuse_accounts_lazy,
-                    &self.config.voting_group,
-                )?,
-                VoteStatusProvider::new(backend, self.config.debug)?,
+                request_gen,
+                status_provider,
                 self.config.config,
                 measurement_name,
             )
         } else {
-            jortestkit::load::start_sync(
-                WalletRequestGen::new(
-                    multicontroller,
-                    self.config.reuse_accounts_lazy,
-                    &self.config.voting_group,
-                )?,
-                self.config.config,
+            let mut request_gen = WalletRequestGen::new(
+                multicontroller,
+                self.config.reuse_accounts_lazy,
+                &self.config.voting_group,
+            )?;
+            let mut config = self.config.config;
+            let schedule = self.config.shape.as_ref().map(|shape| {
+                config = shaped_configuration(&config, shape);
+                Schedule::new(shape)
+            });
+            if let Some(schedule) = &schedule {
+                request_gen = request_gen.with_schedule(schedule.clone());
+            }
+            if let Some(churn) = self.config.churn.clone() {
+                request_gen = request_gen.with_churn(churn, churn_stats.clone());
+            }
+            let stats = jortestkit::load::start_sync_with_status(
+                request_gen,
+                status_provider,
+                config,
                 measurement_name,
-            )
+            );
+            if let (Some(schedule), Some(shape)) = (schedule, &self.config.shape) {
+                println!(
+                    "{} of {} votes sent later than the tolerance of {:?}",
+                    schedule.late(),
+                    shape.total_requests(),
+                    shape.tolerance
+                );
+            }
+            stats
         };
 
         stats.print_summary(measurement_name);
 
+        if self.config.churn.is_some() {
+            println!(
+                "{} wallets dropped out, {} registered, {} joined, {} registrations failed or were not known yet",
+                churn_stats.dropped_out(),
+                churn_stats.registered(),
+                churn_stats.joined(),
+                churn_stats.unregistered()
+            );
+        }
+
         if let Some(threshold) = self.config.criterion {
             return Ok(Some(stats.measure(measurement_name, threshold.into())));
         }
@@ -61,6 +108,21 @@ impl NodeLoad {
     }
 }
 
+/// The load configuration with the duration of the shape, and no delay
+/// between the requests as they are timed by the shape schedule
+fn shaped_configuration(config: &Configuration, shape: &LoadShape) -> Configuration {
+    let mut builder = ConfigurationBuilder::duration(shape.duration())
+        .thread_no(config.thread_no())
+        .step_delay(Duration::ZERO)
+        .monitor(config.monitor().clone())
+        .shutdown_grace_period(config.shutdown_grace_period())
+        .status_pace(config.status_pace());
+    if let Some(fetch_limit) = config.fetch_limit() {
+        builder = builder.fetch_limit(fetch_limit);
+    }
+    builder.build()
+}
+
 #[derive(Error, Debug)]
 pub enum Error {
     #[error("config error")]
@@ -69,6 +131,8 @@ pub enum Error {
     MultiControllerError(#[from] MultiControllerError),
     #[error("request gen error")]
     RequestGen(#[from] RequestGenError),
+    #[error("a load shape only applies to votes sent one by one, not in batches of {0}")]
+    ShapeWithBatches(usize),
     #[error("request gen error")]
     StatusProvider(#[from] crate::load::StatusProviderError),
 }
@@ -0,0 +1,261 @@
+use serde::{Deserialize, Serialize};
+use std::{
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+/// Shape of the traffic sent by a load, in requests per second across all
+/// the threads: a linear ramp-up to the steady rate, optionally a spike in
+/// the middle of the steady phase, then a linear ramp-down.
+///
+/// The shape replaces the fixed delay between the requests of each thread,
+/// and its duration replaces the one of the load strategy.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct LoadShape {
+    /// Duration of the ramp-up from no traffic to the steady rate
+    pub warmup: Duration,
+    /// Requests per second after the warm up
+    pub steady_tps: f64,
+    /// Duration of the steady phase, including the spike
+    pub steady: Duration,
+    /// Factor applied to the steady rate during the spike
+    #[serde(default = "default_spike_multiplier")]
+    pub spike_multiplier: f64,
+    /// Duration of the spike, none by default
+    #[serde(default)]
+    pub spike: Duration,
+    /// Duration of the ramp-down from the steady rate to no traffic
+    pub cooldown: Duration,
+    /// Delay after its scheduled time beyond which a request is reported
+    /// as late
+    #[serde(default = "default_tolerance")]
+    pub tolerance: Duration,
+}
+
+fn default_spike_multiplier() -> f64 {
+    1.0
+}
+
+fn default_tolerance() -> Duration {
+    Duration::from_millis(100)
+}
+
+impl LoadShape {
+    pub fn duration(&self) -> Duration {
+        self.warmup + self.steady + self.cooldown
+    }
+
+    /// Phases of the shape, in order, during which the rate changes
+    /// linearly
+    fn segments(&self) -> Vec<Segment> {
+        let spike = self.spike.min(self.steady);
+        let before_spike = (self.steady - spike) / 2;
+        let after_spike = self.steady - spike - before_spike;
+        let steady = self.steady_tps;
+        let spiked = self.steady_tps * self.spike_multiplier;
+        vec![
+            Segment::new(self.warmup, 0.0, steady),
+            Segment::new(before_spike, steady, steady),
+            Segment::new(spike, spiked, spiked),
+            Segment::new(after_spike, steady, steady),
+            Segment::new(self.cooldown, steady, 0.0),
+        ]
+    }
+
+    /// Total number of requests sent over the shape
+    pub fn total_requests(&self) -> u64 {
+        self.segments().iter().map(Segment::requests).sum::<f64>() as u64
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Segment {
+    duration: f64,
+    start_rate: f64,
+    end_rate: f64,
+}
+
+impl Segment {
+    fn new(duration: Duration, start_rate: f64, end_rate: f64) -> Self {
+        Self {
+            duration: duration.as_secs_f64(),
+            start_rate,
+            end_rate,
+        }
+    }
+
+    fn requests(&self) -> f64 {
+        (self.start_rate + self.end_rate) * self.duration / 2.0
+    }
+
+    /// Time into the segment at which `n` requests, at most all the
+    /// requests of the segment, have been sent
+    fn time_of(&self, n: f64) -> f64 {
+        let a = self.start_rate;
+        let slope = (self.end_rate - a) / self.duration;
+        if slope.abs() < f64::EPSILON {
+            return n / a;
+        }
+        // solve a * t + slope * t^2 / 2 = n
+        let discriminant = (a * a + 2.0 * slope * n).max(0.0);
+        ((-a + discriminant.sqrt()) / slope).clamp(0.0, self.duration)
+    }
+}
+
+/// Hands out the times at which to send the requests to follow a load
+/// shape, to the request generators of all the threads.
+#[derive(Clone)]
+pub struct Schedule {
+    inner: Arc<ScheduleInner>,
+}
+
+struct ScheduleInner {
+    segments: Vec<Segment>,
+    start: Mutex<Option<Instant>>,
+    next: AtomicU64,
+    late: AtomicU64,
+    tolerance: Duration,
+}
+
+impl Schedule {
+    pub fn new(shape: &LoadShape) -> Self {
+        Self {
+            inner: Arc::new(ScheduleInner {
+                segments: shape.segments(),
+                start: Mutex::new(None),
+                next: AtomicU64::new(0),
+                late: AtomicU64::new(0),
+                tolerance: shape.tolerance,
+            }),
+        }
+    }
+
+    /// Reserves the next request of the shape, returning its offset from
+    /// the start of the schedule, or `None` if the shape is over
+    pub fn claim(&self) -> Option<Duration> {
+        let n = self.inner.next.fetch_add(1, Ordering::SeqCst);
+        self.offset_of(n)
+    }
+
+    /// Waits until the time of a request claimed with `claim`. The schedule
+    /// starts when the first request is waited for.
+    pub fn wait_until(&self, offset: Duration) {
+        let start = *self
+            .inner
+            .start
+            .lock()
+            .unwrap()
+            .get_or_insert_with(Instant::now);
+        let scheduled = start + offset;
+        let now = Instant::now();
+        if now < scheduled {
+            std::thread::sleep(scheduled - now);
+        } else if now - scheduled > self.inner.tolerance {
+            self.inner.late.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Offset from the start of the schedule of the request at index `n`
+    fn offset_of(&self, n: u64) -> Option<Duration> {
+        // the request at index n is sent once n + 1 requests are due
+        let mut remaining = (n + 1) as f64;
+        let mut elapsed = 0.0;
+        for segment in &self.inner.segments {
+            let requests = segment.requests();
+            if remaining <= requests {
+                return Some(Duration::from_secs_f64(
+                    elapsed + segment.time_of(remaining),
+                ));
+            }
+            remaining -= requests;
+            elapsed += segment.duration;
+        }
+        None
+    }
+
+    /// Number of requests sent later than the tolerance of the shape
+    pub fn late(&self) -> u64 {
+        self.inner.late.load(Ordering::Relaxed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 1e-6,
+            "{} is not {}",
+            actual,
+            expected
+        );
+    }
+
+    fn shape() -> LoadShape {
+        LoadShape {
+            warmup: Duration::from_secs(10),
+            steady_tps: 10.0,
+            steady: Duration::from_secs(10),
+            spike_multiplier: 2.0,
+            spike: Duration::from_secs(2),
+            cooldown: Duration::from_secs(10),
+            tolerance: default_tolerance(),
+        }
+    }
+
+    #[test]
+    fn time_of_constant_segment() {
+        let segment = Segment::new(Duration::from_secs(10), 5.0, 5.0);
+        assert_close(segment.requests(), 50.0);
+        assert_close(segment.time_of(10.0), 2.0);
+        assert_close(segment.time_of(50.0), 10.0);
+    }
+
+    #[test]
+    fn time_of_ramps() {
+        let up = Segment::new(Duration::from_secs(10), 0.0, 10.0);
+        assert_close(up.requests(), 50.0);
+        assert_close(up.time_of(12.5), 5.0);
+        assert_close(up.time_of(50.0), 10.0);
+
+        let down = Segment::new(Duration::from_secs(10), 10.0, 0.0);
+        assert_close(down.requests(), 50.0);
+        assert_close(down.time_of(37.5), 5.0);
+        assert_close(down.time_of(50.0), 10.0);
+    }
+
+    #[test]
+    fn offsets_follow_the_phases() {
+        let shape = shape();
+        // 50 requests in the warm up, 80 at the steady rate,
+        // 40 in the spike and 50 in the cool down
+        assert_eq!(shape.total_requests(), 220);
+        let schedule = Schedule::new(&shape);
+        let offset = |n| schedule.offset_of(n).unwrap().as_secs_f64();
+
+        assert_close(offset(49), 10.0);
+        assert_close(offset(89), 14.0);
+        assert_close(offset(129), 16.0);
+        assert_close(offset(169), 20.0);
+        assert_close(offset(219), 30.0);
+        assert!(schedule.offset_of(220).is_none());
+    }
+
+    #[test]
+    fn claims_end_with_the_shape() {
+        let shape = shape();
+        let schedule = Schedule::new(&shape);
+        let mut last = Duration::ZERO;
+        for _ in 0..shape.total_requests() {
+            let offset = schedule.claim().unwrap();
+            assert!(offset >= last);
+            last = offset;
+        }
+        assert!(schedule.claim().is_none());
+        assert_eq!(schedule.late(), 0);
+    }
+}
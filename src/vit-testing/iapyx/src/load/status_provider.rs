@@ -1,31 +1,140 @@
+use crate::load::FailoverBackend;
 use chain_impl_mockchain::fragment::FragmentId;
-use jormungandr_lib::interfaces::FragmentStatus;
+use jormungandr_lib::interfaces::{FragmentStatus, PrivateTallyState, Tally, VotePlanStatus};
 use jortestkit::load::RequestStatusProvider;
 use jortestkit::load::{Id, Status};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
 use thiserror::Error;
-use valgrind::ValgrindClient;
+
+/// Where and how often [`VoteStatusProvider`] writes the tally progress dashboard
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DashboardConfig {
+    pub path: PathBuf,
+    pub interval: Duration,
+}
+
+/// Tally progress of single proposal, as written in the dashboard file
+#[derive(Debug, Serialize)]
+pub struct ProposalProgress {
+    pub vote_plan_id: String,
+    pub index: u8,
+    pub votes_cast: usize,
+    /// Per option results, available only once the tally is public or decrypted
+    pub results: Option<Vec<u64>>,
+}
+
+impl ProposalProgress {
+    /// Dashboard content: proposal id to its progress
+    pub fn from_vote_plans(vote_plans: &[VotePlanStatus]) -> BTreeMap<String, ProposalProgress> {
+        vote_plans
+            .iter()
+            .flat_map(|vote_plan| {
+                vote_plan.proposals.iter().map(move |proposal| {
+                    let results = match &proposal.tally {
+                        Tally::Public { result }
+                        | Tally::Private {
+                            state: PrivateTallyState::Decrypted { result },
+                        } => Some(result.results()),
+                        Tally::Private { .. } => None,
+                    };
+                    (
+                        proposal.proposal_id.to_string(),
+                        ProposalProgress {
+                            vote_plan_id: vote_plan.id.to_string(),
+                            index: proposal.index,
+                            votes_cast: proposal.votes_cast,
+                            results,
+                        },
+                    )
+                })
+            })
+            .collect()
+    }
+}
 
 pub struct VoteStatusProvider {
-    backend: ValgrindClient,
+    backend: FailoverBackend,
+    dashboard: Option<DashboardConfig>,
+    last_dashboard_update: Mutex<Option<Instant>>,
 }
 
 impl VoteStatusProvider {
-    pub fn new(backend_address: String, debug: bool) -> Result<Self, Error> {
-        let mut backend = ValgrindClient::new(backend_address, Default::default())?;
+    /// Verifies fragments against the active backend. Pass a clone of the
+    /// backend the votes are sent to, so that the statuses are checked on
+    /// the backend the votes fail over to.
+    pub fn new(mut backend: FailoverBackend, debug: bool) -> Self {
         if debug {
             backend.enable_logs();
         } else {
             backend.disable_logs();
         }
-        Ok(Self { backend })
+        Self {
+            backend,
+            dashboard: None,
+            last_dashboard_update: Mutex::new(None),
+        }
+    }
+
+    /// Besides verifying fragments, polls the backend for vote plans and writes
+    /// per proposal tally progress as json in the configured file
+    pub fn with_dashboard(mut self, dashboard: DashboardConfig) -> Self {
+        self.dashboard = Some(dashboard);
+        self
     }
+
+    fn update_dashboard(&self) {
+        let dashboard = match &self.dashboard {
+            Some(dashboard) => dashboard,
+            None => return,
+        };
+        let mut last_update = self.last_dashboard_update.lock().unwrap();
+        if matches!(*last_update, Some(last) if last.elapsed() < dashboard.interval) {
+            return;
+        }
+        *last_update = Some(Instant::now());
+
+        // dashboard is a best effort helper, it should never interrupt the load test
+        let result = self
+            .backend
+            .call(|backend| backend.vote_plan_statuses())
+            .map_err(Error::Backend)
+            .and_then(|vote_plans| {
+                write_dashboard(
+                    &dashboard.path,
+                    &ProposalProgress::from_vote_plans(&vote_plans),
+                )
+            });
+        if let Err(err) = result {
+            eprintln!("cannot update vote progress dashboard: {}", err);
+        }
+    }
+}
+
+/// Writes to a temporary file first, so readers never see partial content
+fn write_dashboard(
+    path: &Path,
+    progress: &BTreeMap<String, ProposalProgress>,
+) -> Result<(), Error> {
+    let tmp = path.with_extension("tmp");
+    std::fs::write(&tmp, serde_json::to_vec_pretty(progress)?)?;
+    std::fs::rename(tmp, path)?;
+    Ok(())
 }
 
 impl RequestStatusProvider for VoteStatusProvider {
     fn get_statuses(&self, ids: &[Id]) -> Vec<Status> {
+        self.update_dashboard();
+        if ids.is_empty() {
+            return Vec::new();
+        }
+        let ids: Vec<String> = ids.iter().map(|id| id.to_string()).collect();
         match self
             .backend
-            .fragments_statuses(ids.iter().map(|id| id.to_string()).collect())
+            .call(|backend| backend.fragments_statuses(ids.clone()))
         {
             Ok(fragments_statuses) => fragments_statuses
                 .iter()
@@ -64,4 +173,8 @@ pub enum Error {
     PinRead(#[from] crate::utils::qr::PinReadError),
     #[error("wallet time error")]
     WalletTime(#[from] wallet::time::Error),
+    #[error("cannot write dashboard")]
+    Io(#[from] std::io::Error),
+    #[error("cannot serialize dashboard")]
+    Serialization(#[from] serde_json::Error),
 }
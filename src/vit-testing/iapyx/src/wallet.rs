@@ -1,9 +1,12 @@
+use catalyst_toolbox::kedqr::{self, KeyQrCode, KeyQrCodeError, KeyQrCodePayloadError, QrPin};
 use chain_addr::{AddressReadable, Discrimination};
+use chain_crypto::{Ed25519, Ed25519Extended, PublicKey, SecretKey};
 use chain_impl_mockchain::account::SpendingCounterIncreasing;
 use chain_impl_mockchain::{block::BlockDate, fragment::FragmentId};
 use hdkeygen::account::AccountId;
+use jormungandr_lib::crypto::account::Identifier;
 use jormungandr_lib::interfaces::AccountIdentifier;
-use std::str::FromStr;
+use std::{path::Path, str::FromStr};
 use thiserror::Error;
 use wallet::Settings;
 use wallet_core::Proposal;
@@ -21,6 +24,14 @@ pub enum Error {
     BackendError(#[from] valgrind::Error),
     #[error("cannot send vote")]
     CannotSendVote(String),
+    #[error("cannot read QR code image")]
+    CannotReadQrCode(#[from] image::ImageError),
+    #[error("QR code does not contain any key")]
+    EmptyQrCode,
+    #[error("cannot decode QR code")]
+    QrCode(#[from] KeyQrCodeError),
+    #[error("cannot decode QR code payload")]
+    QrPayload(#[from] KeyQrCodePayloadError),
 }
 
 pub struct Wallet {
@@ -45,6 +56,23 @@ impl Wallet {
         })
     }
 
+    /// Recovers the wallet registered with the given QR code image
+    pub fn from_qr_code<P: AsRef<Path>>(qr: P, pin: &QrPin) -> Result<Self, Error> {
+        let img = image::open(qr.as_ref())?;
+        let secret = KeyQrCode::decode(img, &pin.password)?
+            .into_iter()
+            .next()
+            .ok_or(Error::EmptyQrCode)?;
+        Self::recover(secret.leak_secret().as_ref())
+    }
+
+    /// Recovers the wallet registered with the given QR code payload, the hex
+    /// encoded content of the QR code
+    pub fn from_qr_payload(payload: &str, pin: &QrPin) -> Result<Self, Error> {
+        let secret = kedqr::decode(payload.trim(), &pin.password)?;
+        Self::recover(secret.leak_secret().as_ref())
+    }
+
     pub fn account(&self, discrimination: chain_addr::Discrimination) -> chain_addr::Address {
         self.inner.account(discrimination)
     }
@@ -53,6 +81,11 @@ impl Wallet {
         self.inner.id()
     }
 
+    /// Key the wallet votes with, under which it is registered in the snapshot
+    pub fn voting_key(&self) -> Identifier {
+        PublicKey::<Ed25519>::from(self.id()).into()
+    }
+
     pub fn confirm_all_transactions(&mut self) {
         for id in self.pending_transactions() {
             self.confirm_transaction(id)
@@ -112,8 +145,63 @@ impl Wallet {
     }
 }
 
+/// Registration QR code of the wallet with the given secret key, protected
+/// with the PIN
+pub fn qr_code(secret: SecretKey<Ed25519Extended>, pin: &QrPin) -> KeyQrCode {
+    KeyQrCode::generate(secret, &pin.password)
+}
+
+/// Payload of the registration QR code of the wallet with the given secret
+/// key, protected with the PIN
+pub fn qr_payload(secret: SecretKey<Ed25519Extended>, pin: &QrPin) -> String {
+    kedqr::generate(secret, &pin.password)
+}
+
 impl std::fmt::Debug for Wallet {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.identifier(Discrimination::Production))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn secret() -> SecretKey<Ed25519Extended> {
+        SecretKey::generate(rand::thread_rng())
+    }
+
+    fn identifier(wallet: &Wallet) -> String {
+        wallet.identifier(Discrimination::Test).to_string()
+    }
+
+    #[test]
+    fn qr_payload_round_trip() {
+        let secret = secret();
+        let pin = QrPin::from_str("1234").unwrap();
+        let expected = Wallet::recover(secret.clone().leak_secret().as_ref()).unwrap();
+
+        let payload = qr_payload(secret, &pin);
+        let wallet = Wallet::from_qr_payload(&format!("{}\n", payload), &pin).unwrap();
+        assert_eq!(identifier(&wallet), identifier(&expected));
+
+        let wrong_pin = QrPin::from_str("4321").unwrap();
+        assert!(Wallet::from_qr_payload(&payload, &wrong_pin).is_err());
+    }
+
+    #[test]
+    fn qr_code_round_trip() {
+        let secret = secret();
+        let pin = QrPin::from_str("1234").unwrap();
+        let expected = Wallet::recover(secret.clone().leak_secret().as_ref()).unwrap();
+
+        let path = std::env::temp_dir().join(format!("iapyx-qr-{}.png", std::process::id()));
+        qr_code(secret, &pin).to_img().save(&path).unwrap();
+        let wallet = Wallet::from_qr_code(&path, &pin);
+        let wrong_pin = Wallet::from_qr_code(&path, &QrPin::from_str("4321").unwrap());
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(identifier(&wallet.unwrap()), identifier(&expected));
+        assert!(wrong_pin.is_err());
+    }
+}
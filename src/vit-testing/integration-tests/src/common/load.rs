@@ -150,6 +150,7 @@ pub fn build_load_config(
         config,
         criterion: Some(100),
         address: address.to_string(),
+        failover_addresses: Vec::new(),
         qr_codes_folder: Some(qr_codes_folder),
         secrets_folder: None,
         global_pin: "".to_string(),
@@ -159,6 +160,9 @@ pub fn build_load_config(
         use_https: false,
         debug: false,
         voting_group: "direct".to_string(),
+        dashboard: None,
+        shape: None,
+        churn: None,
     }
 }
 
@@ -185,6 +189,7 @@ pub fn build_load_config_count(
         config,
         criterion: Some(100),
         address: address.to_string(),
+        failover_addresses: Vec::new(),
         qr_codes_folder: Some(qr_codes_folder),
         secrets_folder: None,
         global_pin: "".to_string(),
@@ -194,5 +199,8 @@ pub fn build_load_config_count(
         use_https: false,
         debug: false,
         voting_group: "direct".to_string(),
+        dashboard: None,
+        shape: None,
+        churn: None,
     }
 }
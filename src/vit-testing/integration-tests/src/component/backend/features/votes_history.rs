@@ -38,7 +38,7 @@ pub fn votes_history_reflects_casted_votes() {
             role,
         }]))
         .vote_timing(vote_timing.into())
-        .slot_duration_in_seconds(2)
+        .fast_slots()
         .proposals_count(300)
         .voting_power(31_000)
         .private(true)
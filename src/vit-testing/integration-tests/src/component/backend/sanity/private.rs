@@ -49,7 +49,7 @@ pub fn private_vote_e2e_flow() -> std::result::Result<(), Error> {
                 role,
             },
         ]))
-        .slot_duration_in_seconds(2)
+        .fast_slots()
         .proposals_count(1)
         .vote_timing(vote_timing.into())
         .voting_power(8_000)
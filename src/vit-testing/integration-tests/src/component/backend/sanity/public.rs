@@ -47,7 +47,7 @@ pub fn public_vote_multiple_vote_plans() -> std::result::Result<(), Error> {
                 role,
             },
         ]))
-        .slot_duration_in_seconds(2)
+        .fast_slots()
         .vote_timing(vote_timing.into())
         .proposals_count(300)
         .voting_power(8_000)
@@ -73,6 +73,11 @@ async fn main() {
             server_stub.http_node_address(),
         ));
 
+        let admin = warp::path!("admin" / ..).and(reverse_proxy_filter(
+            "".to_string(),
+            server_stub.http_vit_address(),
+        ));
+
         let block0_content = server_stub.block0();
 
         let block0 = warp::path!("block0").map(move || Ok(block0_content.clone()));
@@ -90,6 +95,7 @@ async fn main() {
                 .or(explorer)
                 .or(vote)
                 .or(node)
+                .or(admin)
                 .or(block0),
         )
     };
@@ -24,7 +24,9 @@ use url::Url;
 use vit_servicing_station_lib::db::models::challenges::Challenge;
 use vit_servicing_station_lib::db::models::community_advisors_reviews::AdvisorReview;
 use vit_servicing_station_lib::db::models::funds::Fund;
+use vit_servicing_station_lib::v0::endpoints::snapshot::SnapshotInfoInput;
 use vit_servicing_station_tests::common::clients::RestClient as VitRestClient;
+use vit_servicing_station_tests::common::snapshot::{Snapshot, VoterInfo};
 pub use vit_station::{RestError as VitStationRestError, VitStationRestClient};
 use wallet::AccountId;
 
@@ -162,6 +164,26 @@ impl ValgrindClient {
         self.vit_client.clone()
     }
 
+    /// Token sent to the vit servicing station, required by its admin
+    /// endpoints when the api tokens are enabled
+    pub fn set_vit_api_token(&mut self, token: String) {
+        self.vit_client.set_api_token(token);
+    }
+
+    /// Adds the entries to the snapshot with the given tag
+    pub fn put_snapshot_info(&self, tag: &str, content: SnapshotInfoInput) -> Result<(), Error> {
+        Ok(self.vit_client.put_snapshot_info(&Snapshot {
+            tag: tag.to_string(),
+            content,
+        })?)
+    }
+
+    /// Voting power of the voting key, hex encoded, in each voting group of
+    /// the snapshot with the given tag
+    pub fn voter_info(&self, tag: &str, voting_key: &str) -> Result<VoterInfo, Error> {
+        Ok(self.vit_client.voter_info(tag, voting_key)?)
+    }
+
     pub fn block0(&self) -> Result<Vec<u8>, Error> {
         Ok(self.proxy_client.block0().map(Into::into)?)
     }
@@ -68,6 +68,13 @@ impl ConfigBuilder {
         self.config.blockchain.slot_duration = slot_duration;
         self
     }
+
+    /// Shortest slots the node allows, so that the tests waiting for epoch
+    /// boundaries, like the vote tally ones, do not wait longer than needed
+    pub fn fast_slots(self) -> Self {
+        self.slot_duration_in_seconds(1)
+    }
+
     pub fn vote_timing(mut self, vote_timing: VoteTime) -> Self {
         self.config.vote_plan.vote_time = vote_timing;
         self
@@ -293,6 +293,9 @@ impl LedgerState {
             treasury_tax: params.treasury_params(),
             reward_params: params.reward_params(),
             tx_max_expiry_epochs: params.transaction_max_expiry_epochs,
+            max_fragment_size: params.max_fragment_size,
+            voting_power_snapshot: params.voting_power_snapshot,
+            script_addresses: params.script_addresses,
         }
     }
 
@@ -353,6 +356,8 @@ fn is_fragment_valid(fragment: &Fragment) -> bool {
         Fragment::Evm(_) => false,
         Fragment::EvmMapping(ref tx) => is_transaction_valid(tx),
         Fragment::MintToken(ref tx) => is_transaction_valid(tx),
+        Fragment::Anchor(ref tx) => is_transaction_valid(tx),
+        Fragment::MultisigDeclarationUpdate(ref tx) => is_transaction_valid(tx),
         Fragment::Transaction(ref tx) => is_transaction_valid(tx),
         Fragment::StakeDelegation(ref tx) => is_transaction_valid(tx),
         Fragment::OwnerStakeDelegation(ref tx) => is_transaction_valid(tx),